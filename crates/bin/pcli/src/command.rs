@@ -1,9 +1,11 @@
 pub use debug::DebugCmd;
 pub use init::InitCmd;
+pub use migrate::MigrateCmd;
 pub use query::QueryCmd;
 pub use threshold::ThresholdCmd;
 pub use tx::TxCmd;
 pub use validator::ValidatorCmd;
+pub use verify::VerifyCmd;
 pub use view::ViewCmd;
 
 use self::ceremony::CeremonyCmd;
@@ -11,11 +13,13 @@ use self::ceremony::CeremonyCmd;
 mod ceremony;
 mod debug;
 mod init;
+mod migrate;
 mod query;
 mod threshold;
 mod tx;
 mod utils;
 mod validator;
+mod verify;
 mod view;
 
 // Note on display_order:
@@ -40,6 +44,9 @@ pub enum Command {
     /// custody, hardware wallets) may be added in the future.
     #[clap(display_order = 100)]
     Init(InitCmd),
+    /// Upgrade `pcli` data written by an older version, after a breaking release.
+    #[clap(subcommand, display_order = 150)]
+    Migrate(MigrateCmd),
     /// Query the public chain state, like the validator set.
     ///
     /// This command has two modes: it can be used to query raw bytes of
@@ -50,6 +57,9 @@ pub enum Command {
     /// View your private chain state, like account balances.
     #[clap(subcommand, display_order = 300, visible_alias = "v")]
     View(ViewCmd),
+    /// Verify evidence others have shared, like payment receipts, against the public chain state.
+    #[clap(subcommand, display_order = 350)]
+    Verify(VerifyCmd),
     /// Create and broadcast a transaction.
     #[clap(subcommand, display_order = 400, visible_alias = "tx")]
     Transaction(TxCmd),
@@ -72,8 +82,10 @@ impl Command {
     pub fn offline(&self) -> bool {
         match self {
             Command::Init(_) => true,
+            Command::Migrate(_) => true,
             Command::Transaction(cmd) => cmd.offline(),
             Command::View(cmd) => cmd.offline(),
+            Command::Verify(cmd) => cmd.offline(),
             Command::Validator(cmd) => cmd.offline(),
             Command::Query(cmd) => cmd.offline(),
             Command::Debug(cmd) => cmd.offline(),
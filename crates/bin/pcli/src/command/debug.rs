@@ -1,12 +1,75 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use penumbra_proto::DomainType;
 use serde::Serialize;
 use std::path::PathBuf;
 use std::process::Command;
 
+use crate::{config::PcliConfig, replay::Replay, CONFIG_FILE_NAME};
+
+mod decrypt_note;
+mod explain_balance;
+mod parse_address;
+mod tx_diff;
+
 #[derive(Debug, clap::Subcommand)]
 pub enum DebugCmd {
     /// Emit debugging info, useful for requesting support
     Info,
+    /// Rebuild a transaction from the inputs recorded with `--save-replay`, reproducing the same
+    /// proofs, or the same error.
+    ReplayPlan {
+        /// The recording to replay.
+        file: Utf8PathBuf,
+    },
+    /// Show the differences between two transactions, such as a planned transaction previewed
+    /// before signing and the transaction finally signed by a hardware or threshold signer.
+    ///
+    /// Each file may contain a transaction view in JSON, as printed by `pcli view tx --raw`, or a
+    /// transaction in JSON or binary encoding, of which only the public parts can be compared.
+    TxDiff {
+        /// The transaction to compare against.
+        old: Utf8PathBuf,
+        /// The transaction to compare.
+        new: Utf8PathBuf,
+    },
+    /// Check an address, or another bech32-encoded string like a full viewing key, validator
+    /// identity key or position ID, without connecting to a node.
+    ///
+    /// Prints what kind of string it is, or diagnoses what's wrong with it, such as an unknown
+    /// prefix, a typo, or truncation.
+    ParseAddress {
+        /// The string to check.
+        address: String,
+    },
+    /// Decrypt a note payload, such as one exported from a block explorer, and print the note's
+    /// contents, to investigate funds a wallet didn't find.
+    ///
+    /// Only the given key is used, so this can be run with a key disclosed for one note without
+    /// access to the wallet.
+    DecryptNote {
+        /// The note payload of an output or swap claim, as hex-encoded protobuf.
+        #[clap(long)]
+        payload: String,
+        /// A hex-encoded payload key for the note, or a full viewing key, whose incoming viewing
+        /// key decrypts the notes sent to the wallet.
+        #[clap(long)]
+        key: String,
+    },
+    /// Explain why a transaction plan doesn't balance, such as one whose build fails with an
+    /// invalid binding signature.
+    ///
+    /// Prints the value each action provides and requires, and which actions involve any asset
+    /// left over. Given the transaction built from the plan, also finds the actions whose value
+    /// commitments don't match their planned balances.
+    ExplainBalance {
+        /// The plan, as a recording made with `--save-replay`, or a transaction plan in JSON or
+        /// binary encoding.
+        file: Utf8PathBuf,
+        /// The transaction built from the plan, in JSON or binary encoding.
+        #[clap(long)]
+        transaction: Option<Utf8PathBuf>,
+    },
 }
 
 impl DebugCmd {
@@ -14,7 +77,7 @@ impl DebugCmd {
         true
     }
 
-    pub fn exec(&self, data_dir: PathBuf) -> Result<()> {
+    pub async fn exec(&self, data_dir: PathBuf) -> Result<()> {
         match self {
             DebugCmd::Info => {
                 let debug_info = DebugInfo::new(data_dir);
@@ -24,6 +87,48 @@ impl DebugCmd {
                 println!("{d}");
                 Ok(())
             }
+            DebugCmd::ReplayPlan { file } => {
+                let config_path = Utf8PathBuf::try_from(data_dir.join(CONFIG_FILE_NAME))?;
+                let config = PcliConfig::load(config_path)?;
+                let Replay {
+                    plan,
+                    witness_data,
+                    auth_data,
+                } = Replay::load(file)?;
+
+                println!("replaying build of transaction plan from {file}...");
+                let tx = penumbra_wallet::build_with(
+                    &config.full_viewing_key,
+                    plan,
+                    &witness_data,
+                    &auth_data,
+                )
+                .await
+                .context("replayed build failed")?;
+                println!(
+                    "rebuilt transaction {} [{} actions, {} proofs, {} bytes]",
+                    tx.id(),
+                    tx.actions().count(),
+                    tx.num_proofs(),
+                    tx.encode_to_vec().len()
+                );
+                Ok(())
+            }
+            DebugCmd::TxDiff { old, new } => {
+                let old = tx_diff::load_view(old)?;
+                let new = tx_diff::load_view(new)?;
+                tx_diff::print_diff(&old, &new)
+            }
+            DebugCmd::ParseAddress { address } => parse_address::parse_address(address),
+            DebugCmd::DecryptNote { payload, key } => decrypt_note::decrypt_note(payload, key),
+            DebugCmd::ExplainBalance { file, transaction } => {
+                let plan = explain_balance::load_plan(file)?;
+                let transaction = transaction
+                    .as_deref()
+                    .map(explain_balance::load_transaction)
+                    .transpose()?;
+                explain_balance::explain_balance(&plan, transaction.as_ref())
+            }
         }
     }
 }
@@ -0,0 +1,126 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+use penumbra_asset::asset;
+use penumbra_keys::{FullViewingKey, PayloadKey};
+use penumbra_proto::DomainType;
+use penumbra_shielded_pool::{Note, NotePayload};
+
+/// Key material that can decrypt a note.
+#[derive(Debug)]
+pub enum DecryptionKey {
+    /// The payload key of one particular note, as disclosed by its sender or recipient.
+    Payload(PayloadKey),
+    /// A full viewing key, whose incoming viewing key decrypts every note sent to the wallet.
+    FullViewingKey(Box<FullViewingKey>),
+}
+
+impl FromStr for DecryptionKey {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Ok(fvk) = FullViewingKey::from_str(s) {
+            return Ok(DecryptionKey::FullViewingKey(Box::new(fvk)));
+        }
+        let bytes = hex::decode(s)
+            .context("key must be a full viewing key, or a hex-encoded payload key")?;
+        Ok(DecryptionKey::Payload(bytes.try_into()?))
+    }
+}
+
+/// Decodes a hex-encoded note payload, as found in an output or a swap claim.
+pub fn parse_payload(payload: &str) -> Result<NotePayload> {
+    let bytes = hex::decode(payload.trim()).context("payload must be hex-encoded")?;
+    NotePayload::decode(bytes.as_slice()).context("payload is not an encoded note payload")
+}
+
+/// Decrypts a note payload with the given key.
+pub fn decrypt(payload: &NotePayload, key: &DecryptionKey) -> Result<Note> {
+    let note = match key {
+        DecryptionKey::Payload(payload_key) => Note::decrypt_with_payload_key(
+            &payload.encrypted_note,
+            payload_key,
+            &payload.ephemeral_key,
+        ),
+        DecryptionKey::FullViewingKey(fvk) => Note::decrypt(
+            &payload.encrypted_note,
+            fvk.incoming(),
+            &payload.ephemeral_key,
+        ),
+    };
+    note.map_err(|_| {
+        anyhow::anyhow!(
+            "the note can't be decrypted with this key: either it wasn't sent to this wallet, \
+             or the payload key belongs to another note"
+        )
+    })
+}
+
+/// Decrypts a note payload and prints the note's contents.
+pub fn decrypt_note(payload: &str, key: &str) -> Result<()> {
+    let payload = parse_payload(payload)?;
+    let key: DecryptionKey = key.parse()?;
+    let note = decrypt(&payload, &key)?;
+
+    let assets = asset::Cache::with_known_assets();
+    println!("address: {}", note.address());
+    if let DecryptionKey::FullViewingKey(fvk) = &key {
+        println!(
+            "account: {}",
+            fvk.view_address(note.address()).display_short()
+        );
+    }
+    println!("value: {}", note.value().format(&assets));
+    println!("asset id: {}", note.asset_id());
+    println!("note commitment: {}", payload.note_commitment);
+
+    // Funds can't be found by scanning if the note doesn't match its commitment on chain.
+    if note.commit() != payload.note_commitment {
+        println!("warning: the decrypted note doesn't match the payload's note commitment");
+    }
+    if note.amount() == 0u64.into() {
+        println!("note: the note has a zero amount, and is ignored when scanning");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::Value;
+    use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn fvk() -> FullViewingKey {
+        SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0))
+            .full_viewing_key()
+            .clone()
+    }
+
+    #[test]
+    fn decrypts_with_fvk_or_payload_key() {
+        let fvk = fvk();
+        let value: Value = "1penumbra".parse().expect("valid value");
+        let note = Note::generate(&mut OsRng, &fvk.payment_address(0.into()).0, value);
+        let payload =
+            parse_payload(&hex::encode(note.payload().encode_to_vec())).expect("payload decodes");
+
+        let key: DecryptionKey = fvk.to_string().parse().expect("fvk parses");
+        assert_eq!(decrypt(&payload, &key).expect("decrypts").value(), value);
+
+        let shared_secret = fvk
+            .incoming()
+            .key_agreement_with(&payload.ephemeral_key)
+            .expect("valid ephemeral key");
+        let payload_key = PayloadKey::derive(&shared_secret, &payload.ephemeral_key);
+        let key: DecryptionKey = hex::encode(payload_key.to_vec())
+            .parse()
+            .expect("payload key parses");
+        assert_eq!(decrypt(&payload, &key).expect("decrypts").value(), value);
+
+        let other: DecryptionKey = fvk().to_string().parse().expect("fvk parses");
+        assert!(decrypt(&payload, &other).is_err());
+    }
+}
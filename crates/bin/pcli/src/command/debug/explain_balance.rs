@@ -0,0 +1,206 @@
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use decaf377::Fr;
+
+use penumbra_asset::{asset, Balance};
+use penumbra_proto::DomainType;
+use penumbra_transaction::{ActionPlan, IsAction, Transaction, TransactionPlan};
+
+use crate::replay::Replay;
+
+/// Loads a transaction plan from `path`, which may contain a recording made with
+/// `--save-replay`, or a transaction plan in JSON or binary protobuf encoding.
+pub fn load_plan(path: &Utf8Path) -> Result<TransactionPlan> {
+    let bytes = std::fs::read(path).with_context(|| format!("cannot read {path}"))?;
+    if let Ok(plan) = serde_json::from_slice::<TransactionPlan>(&bytes) {
+        return Ok(plan);
+    }
+    if let Ok(replay) = Replay::decode(&bytes) {
+        return Ok(replay.plan);
+    }
+    TransactionPlan::decode(&bytes[..]).with_context(|| {
+        format!("{path} does not contain a replay recording or a transaction plan")
+    })
+}
+
+/// Loads a transaction from `path`, in JSON or binary protobuf encoding.
+pub fn load_transaction(path: &Utf8Path) -> Result<Transaction> {
+    let bytes = std::fs::read(path).with_context(|| format!("cannot read {path}"))?;
+    match serde_json::from_slice::<Transaction>(&bytes) {
+        Ok(transaction) => Ok(transaction),
+        Err(_) => Transaction::decode(&bytes[..])
+            .with_context(|| format!("{path} does not contain a transaction")),
+    }
+}
+
+/// Prints the value flowing into and out of each action of `plan`, and explains why its balance
+/// doesn't cancel out, if it doesn't.
+///
+/// If the `transaction` built from the plan is given, the value commitment of each of its actions
+/// is also checked against the action's planned balance, to find actions that were built with a
+/// different balance than they were planned with, which breaks the binding signature.
+pub fn explain_balance(plan: &TransactionPlan, transaction: Option<&Transaction>) -> Result<()> {
+    let cache = asset::Cache::with_known_assets();
+    if let Some(transaction) = transaction {
+        anyhow::ensure!(
+            transaction.transaction_body.actions.len() == plan.actions.len(),
+            "the transaction has {} actions, but the plan has {}, so it wasn't built from this plan",
+            transaction.transaction_body.actions.len(),
+            plan.actions.len()
+        );
+    }
+
+    let mut total = Balance::zero();
+    let mut synthetic_blinding = Fr::from(0u64);
+    let mut built_commitment = decaf377::Element::default();
+    let mut mismatched = Vec::new();
+    println!("actions:");
+    for (i, action) in plan.actions.iter().enumerate() {
+        let balance = action.balance();
+        let blinding = action.value_blinding();
+        print!(
+            "  #{i} {}: {}",
+            action_name(action),
+            format_balance(&balance, &cache)
+        );
+        if let Some(transaction) = transaction {
+            let built = transaction.transaction_body.actions[i].balance_commitment();
+            built_commitment += built.0;
+            if built != balance.commit(blinding) {
+                print!("  <- value commitment doesn't match the planned balance");
+                mismatched.push(i);
+            }
+        }
+        println!();
+        total += balance;
+        synthetic_blinding += blinding;
+    }
+    let fee = &plan.transaction_parameters.fee;
+    println!("  fee: {}", format_balance(&fee.balance(), &cache));
+    total += fee.balance();
+
+    let mut balanced = total.is_zero();
+    if balanced {
+        println!("the planned balance cancels out");
+    } else {
+        println!(
+            "error: the planned balance doesn't cancel out, leaving {}",
+            format_balance(&total, &cache)
+        );
+        for value in total.provided() {
+            println!(
+                "  {} is provided but not consumed; it's provided by {}",
+                value.format(&cache),
+                actions_touching(plan, value.asset_id)
+            );
+        }
+        for value in total.required() {
+            println!(
+                "  {} is required but not provided; it's required by {}",
+                value.format(&cache),
+                actions_touching(plan, value.asset_id)
+            );
+        }
+    }
+
+    if let Some(transaction) = transaction {
+        let built_fee = transaction.transaction_parameters().fee;
+        built_commitment += built_fee.commit(Fr::from(0u64)).0;
+        if built_fee != *fee {
+            balanced = false;
+            println!(
+                "error: the transaction's fee is {}, but the plan's is {}",
+                built_fee.format(&cache),
+                fee.format(&cache)
+            );
+        }
+        for i in &mismatched {
+            balanced = false;
+            println!(
+                "error: action #{i} was built with a different value commitment than its planned balance and blinding factor give"
+            );
+        }
+        // The binding signature verifies exactly when the value commitments of the actions and
+        // the fee sum to a commitment to zero, under the sum of the actions' blinding factors.
+        if built_commitment == Balance::zero().commit(synthetic_blinding).0 {
+            println!(
+                "the transaction's value commitments cancel out, so its binding signature is valid"
+            );
+        } else {
+            println!("error: the transaction's value commitments don't cancel out, so its binding signature is invalid");
+        }
+    }
+
+    anyhow::ensure!(balanced, "transaction is not balanced");
+    Ok(())
+}
+
+/// Formats a balance as the values it provides and requires.
+fn format_balance(balance: &Balance, cache: &asset::Cache) -> String {
+    if balance.is_zero() {
+        return "nothing".to_string();
+    }
+    balance
+        .provided()
+        .map(|value| format!("+{}", value.format(cache)))
+        .chain(
+            balance
+                .required()
+                .map(|value| format!("-{}", value.format(cache))),
+        )
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Lists the actions of `plan` whose balances include the asset `id`, or the fee.
+fn actions_touching(plan: &TransactionPlan, id: asset::Id) -> String {
+    let mut actions: Vec<String> = plan
+        .actions
+        .iter()
+        .enumerate()
+        .filter(|(_, action)| {
+            let balance = action.balance();
+            let mut values = balance.provided().chain(balance.required());
+            values.any(|value| value.asset_id == id)
+        })
+        .map(|(i, action)| format!("#{i} {}", action_name(action)))
+        .collect();
+    if plan.transaction_parameters.fee.asset_id() == id {
+        actions.push("the fee".to_string());
+    }
+    match actions.as_slice() {
+        [] => "no action".to_string(),
+        _ => actions.join(", "),
+    }
+}
+
+fn action_name(action: &ActionPlan) -> &'static str {
+    use ActionPlan::*;
+
+    match action {
+        Spend(_) => "spend",
+        Output(_) => "output",
+        Delegate(_) => "delegate",
+        Undelegate(_) => "undelegate",
+        UndelegateClaim(_) => "undelegate claim",
+        ValidatorDefinition(_) => "validator definition",
+        Swap(_) => "swap",
+        SwapClaim(_) => "swap claim",
+        IbcAction(_) => "IBC action",
+        ProposalSubmit(_) => "proposal submit",
+        ProposalWithdraw(_) => "proposal withdraw",
+        DelegatorVote(_) => "delegator vote",
+        ValidatorVote(_) => "validator vote",
+        ProposalDepositClaim(_) => "proposal deposit claim",
+        PositionOpen(_) => "position open",
+        PositionClose(_) => "position close",
+        PositionWithdraw(_) => "position withdraw",
+        CommunityPoolSpend(_) => "community pool spend",
+        CommunityPoolOutput(_) => "community pool output",
+        CommunityPoolDeposit(_) => "community pool deposit",
+        Ics20Withdrawal(_) => "ICS-20 withdrawal",
+        FeeGrant(_) => "fee grant",
+        FeeGrantUse(_) => "fee grant use",
+        FeeGrantReclaim(_) => "fee grant reclaim",
+    }
+}
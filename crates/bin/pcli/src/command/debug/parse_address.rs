@@ -0,0 +1,296 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+use bech32::{FromBase32, Variant};
+use penumbra_asset::asset;
+use penumbra_dex::lp::position;
+use penumbra_keys::{keys::SpendKey, Address, FullViewingKey};
+use penumbra_proto::serializers::bech32str;
+use penumbra_stake::{GovernanceKey, IdentityKey};
+
+/// The characters of the bech32 alphabet, in the order of their values.
+const CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// The number of characters in a bech32 checksum.
+const CHECKSUM_LEN: usize = 6;
+
+/// A kind of bech32-encoded string used by Penumbra.
+struct Kind {
+    name: &'static str,
+    hrp: &'static str,
+    variant: Variant,
+    len_bytes: usize,
+    /// Whether the string is secret, and shouldn't have been shared.
+    secret: bool,
+    /// Checks that the string decodes to a valid value of this kind.
+    validate: fn(&str) -> Result<()>,
+}
+
+impl Kind {
+    /// The number of characters in a correctly encoded string of this kind.
+    fn encoded_len(&self) -> usize {
+        self.hrp.len() + 1 + (self.len_bytes * 8 + 4) / 5 + CHECKSUM_LEN
+    }
+}
+
+const KINDS: &[Kind] = &[
+    Kind {
+        name: "address",
+        hrp: bech32str::address::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 80,
+        secret: false,
+        validate: |s| Address::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "compat address (for IBC clients that don't support bech32m)",
+        hrp: bech32str::compat_address::BECH32_PREFIX,
+        variant: Variant::Bech32,
+        len_bytes: 80,
+        secret: false,
+        validate: |s| Address::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "full viewing key",
+        hrp: bech32str::full_viewing_key::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 64,
+        secret: false,
+        validate: |s| FullViewingKey::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "spend key",
+        hrp: bech32str::spend_key::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 32,
+        secret: true,
+        validate: |s| SpendKey::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "wallet id",
+        hrp: bech32str::wallet_id::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 32,
+        secret: false,
+        validate: |_| Ok(()),
+    },
+    Kind {
+        name: "validator identity key",
+        hrp: bech32str::validator_identity_key::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 32,
+        secret: false,
+        validate: |s| IdentityKey::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "validator governance key",
+        hrp: bech32str::validator_governance_key::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 32,
+        secret: false,
+        validate: |s| GovernanceKey::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "position id",
+        hrp: bech32str::lp_id::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 32,
+        secret: false,
+        validate: |s| position::Id::from_str(s).map(|_| ()),
+    },
+    Kind {
+        name: "asset id",
+        hrp: bech32str::asset_id::BECH32_PREFIX,
+        variant: Variant::Bech32m,
+        len_bytes: 32,
+        secret: false,
+        validate: |s| asset::Id::from_str(s).map(|_| ()),
+    },
+];
+
+/// Validates `input` as any of the bech32-encoded strings used by Penumbra, printing what it is,
+/// or what seems to be wrong with it.
+///
+/// Returns an error if the string isn't valid.
+pub fn parse_address(input: &str) -> Result<()> {
+    let s = input.trim();
+    if s != input {
+        println!("note: ignoring leading or trailing whitespace");
+    }
+
+    let lowercase = s.to_lowercase();
+    if s != lowercase && s != s.to_uppercase() {
+        println!("error: the string mixes upper and lower case, which bech32 doesn't allow");
+        anyhow::bail!("invalid bech32 string");
+    }
+    let s = lowercase.as_str();
+
+    let Some((hrp, data)) = s.rsplit_once('1') else {
+        println!("error: the string has no `1` separating its prefix from its data");
+        anyhow::bail!("invalid bech32 string");
+    };
+
+    let Some(kind) = KINDS.iter().find(|kind| kind.hrp == hrp) else {
+        println!("error: unknown prefix `{hrp}`");
+        if let Some(kind) = KINDS
+            .iter()
+            .min_by_key(|kind| edit_distance(kind.hrp, hrp))
+            .filter(|kind| edit_distance(kind.hrp, hrp) <= 3)
+        {
+            println!(
+                "hint: did you mean `{}`, the prefix of a {}?",
+                kind.hrp, kind.name
+            );
+        }
+        anyhow::bail!("invalid bech32 string");
+    };
+    println!("prefix: {hrp} ({})", kind.name);
+
+    if let Some(bad) = data.chars().find(|c| !CHARSET.contains(*c)) {
+        println!(
+            "error: `{bad}` is not a bech32 character{}",
+            match bad {
+                'b' | 'i' | 'o' => ", which excludes `1`, `b`, `i` and `o` to avoid confusion",
+                _ => "",
+            }
+        );
+        anyhow::bail!("invalid {}", kind.name);
+    }
+
+    match bech32::decode(s) {
+        Ok((_, data, variant)) => {
+            if variant != kind.variant {
+                println!(
+                    "error: encoded as {variant:?}, but a {} is encoded as {:?}",
+                    kind.name, kind.variant
+                );
+                anyhow::bail!("invalid {}", kind.name);
+            }
+            let bytes = Vec::<u8>::from_base32(&data)?;
+            if bytes.len() != kind.len_bytes {
+                println!(
+                    "error: the checksum is valid, but the data is {} bytes long, and a {} is {} bytes long",
+                    bytes.len(),
+                    kind.name,
+                    kind.len_bytes
+                );
+                anyhow::bail!("invalid {}", kind.name);
+            }
+            if let Err(e) = (kind.validate)(s) {
+                println!(
+                    "error: the encoding is valid, but it isn't a valid {}: {e:#}",
+                    kind.name
+                );
+                anyhow::bail!("invalid {}", kind.name);
+            }
+            println!("valid {}", kind.name);
+            if kind.secret {
+                println!(
+                    "warning: this is a secret key, which gives control of your funds; don't share it"
+                );
+            }
+            Ok(())
+        }
+        Err(bech32::Error::InvalidChecksum) => {
+            diagnose_checksum(kind, s);
+            anyhow::bail!("invalid {}", kind.name);
+        }
+        Err(e) => {
+            println!("error: {e}");
+            anyhow::bail!("invalid {}", kind.name);
+        }
+    }
+}
+
+/// Works out the likely cause of a checksum failure in `s`, a string of the given `kind`.
+fn diagnose_checksum(kind: &Kind, s: &str) {
+    let expected = kind.encoded_len();
+    if s.len() < expected {
+        println!(
+            "error: invalid checksum, and the string is {} characters too short for a {}; it was probably truncated when copied",
+            expected - s.len(),
+            kind.name
+        );
+        return;
+    }
+    if s.len() > expected {
+        println!(
+            "error: invalid checksum, and the string is {} characters too long for a {}; it may have been pasted twice, or joined to other text",
+            s.len() - expected,
+            kind.name
+        );
+        return;
+    }
+
+    // The string has the right length, so look for typos: strings within one substitution of
+    // `s` that have a valid checksum.
+    let data_start = kind.hrp.len() + 1;
+    let mut corrections = Vec::new();
+    for (i, original) in s.char_indices().skip(data_start) {
+        for replacement in CHARSET.chars().filter(|c| *c != original) {
+            let mut candidate = s.to_string();
+            candidate.replace_range(i..i + 1, &replacement.to_string());
+            if bech32::decode(&candidate).is_ok() {
+                corrections.push((i, original, replacement));
+            }
+        }
+    }
+    match corrections.as_slice() {
+        [] => println!(
+            "error: invalid checksum; the string differs from any valid {} in more than one character",
+            kind.name
+        ),
+        corrections => {
+            println!("error: invalid checksum; the string is one character away from a valid encoding");
+            for (i, original, replacement) in corrections {
+                println!(
+                    "hint: character {} may be `{replacement}` rather than `{original}`",
+                    i + 1
+                );
+            }
+        }
+    }
+}
+
+/// The number of single-character insertions, deletions or substitutions turning `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "penumbra147mfall0zr6am5r45qkwht7xqqrdsp50czde7empv7yq2nk3z8yyfh9k9520ddgswkmzar22vhz9dwtuem7uxw0qytfpv7lk3q9dp8ccaw2fn5c838rfackazmgf3ahh09cxmz";
+
+    #[test]
+    fn accepts_valid_address() {
+        assert_eq!(ADDRESS.len(), KINDS[0].encoded_len());
+        assert!(parse_address(ADDRESS).is_ok());
+        assert!(parse_address(&format!(" {ADDRESS}\n")).is_ok());
+    }
+
+    #[test]
+    fn rejects_damaged_address() {
+        assert!(parse_address(&ADDRESS[..ADDRESS.len() - 4]).is_err());
+        assert!(parse_address(&ADDRESS.replacen("47mf", "47mg", 1)).is_err());
+        assert!(parse_address(&ADDRESS.replacen("penumbra", "penumbre", 1)).is_err());
+    }
+
+    #[test]
+    fn edit_distance_counts_typos() {
+        assert_eq!(edit_distance("penumbra", "penumbra"), 0);
+        assert_eq!(edit_distance("penumbra", "penumbr"), 1);
+        assert_eq!(edit_distance("penumbravalid", "penumbravaild"), 2);
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use serde_json::Value as Json;
+
+use penumbra_asset::asset;
+use penumbra_proto::DomainType;
+use penumbra_transaction::{Transaction, TransactionPerspective, TransactionView};
+
+/// Loads a transaction view from `path`, which may contain either a JSON transaction view, as
+/// printed by `pcli view tx --raw`, or a transaction, in JSON or binary protobuf encoding.
+///
+/// Transactions are viewed without any key material, so only their public parts can be
+/// compared.
+pub fn load_view(path: &Utf8Path) -> Result<TransactionView> {
+    let bytes = std::fs::read(path).with_context(|| format!("cannot read {path}"))?;
+    if let Ok(view) = serde_json::from_slice::<TransactionView>(&bytes) {
+        return Ok(view);
+    }
+    let transaction = match serde_json::from_slice::<Transaction>(&bytes) {
+        Ok(transaction) => transaction,
+        Err(_) => Transaction::decode(&bytes[..]).with_context(|| {
+            format!("{path} does not contain a transaction view or a transaction")
+        })?,
+    };
+    Ok(transaction.view_from_perspective(&TransactionPerspective::default()))
+}
+
+/// Prints the differences between two transaction views.
+pub fn print_diff(old: &TransactionView, new: &TransactionView) -> Result<()> {
+    let cache = asset::Cache::with_known_assets();
+    let old_params = &old.body_view.transaction_parameters;
+    let new_params = &new.body_view.transaction_parameters;
+    let mut identical = true;
+
+    if old_params.fee != new_params.fee {
+        identical = false;
+        println!(
+            "~ fee: {} -> {}",
+            old_params.fee.0.format(&cache),
+            new_params.fee.0.format(&cache)
+        );
+    }
+    if old_params.chain_id != new_params.chain_id {
+        identical = false;
+        println!(
+            "~ chain id: {} -> {}",
+            old_params.chain_id, new_params.chain_id
+        );
+    }
+    if old_params.expiry_height != new_params.expiry_height {
+        identical = false;
+        println!(
+            "~ expiry height: {} -> {}",
+            old_params.expiry_height, new_params.expiry_height
+        );
+    }
+
+    let old_memo = serde_json::to_value(&old.body_view.memo_view)?;
+    let new_memo = serde_json::to_value(&new.body_view.memo_view)?;
+    for change in diff_json("memo", &old_memo, &new_memo) {
+        identical = false;
+        println!("~ {change}");
+    }
+
+    let old_actions = old
+        .body_view
+        .action_views
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    let new_actions = new
+        .body_view
+        .action_views
+        .iter()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut unchanged = 0;
+    for edit in diff_actions(&old_actions, &new_actions) {
+        match edit {
+            ActionEdit::Unchanged => unchanged += 1,
+            ActionEdit::Removed(i) => {
+                println!("- action {i} ({})", action_kind(&old_actions[i]));
+            }
+            ActionEdit::Added(j) => {
+                println!("+ action {j} ({})", action_kind(&new_actions[j]));
+            }
+            ActionEdit::Changed(i, j) => {
+                println!("~ action {i} -> {j} ({})", action_kind(&new_actions[j]));
+                for change in diff_json("", &old_actions[i], &new_actions[j]) {
+                    println!("    {change}");
+                }
+            }
+        }
+    }
+    identical &= unchanged == old_actions.len() && unchanged == new_actions.len();
+
+    if identical {
+        println!("The transactions are identical, as far as they can be viewed");
+    } else {
+        println!("{unchanged} actions unchanged");
+    }
+    Ok(())
+}
+
+/// How an action changed from one transaction to the other.
+#[derive(Debug, PartialEq, Eq)]
+enum ActionEdit {
+    Unchanged,
+    /// The old action at the given index was removed.
+    Removed(usize),
+    /// The new action at the given index was added.
+    Added(usize),
+    /// The old action at the first index was changed into the new action at the second.
+    Changed(usize, usize),
+}
+
+/// Aligns the two lists of actions, matching as many identical actions as possible in order.
+///
+/// Between matched actions, the removed and added actions of the same kind are paired up as
+/// changed, in order, so that e.g. an output whose amount was altered shows as one change.
+fn diff_actions(old: &[Json], new: &[Json]) -> Vec<ActionEdit> {
+    // The longest common subsequence of the two lists, by dynamic programming.
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            pair_changes(old, new, &mut removed, &mut added, &mut edits);
+            edits.push(ActionEdit::Unchanged);
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            added.push(j);
+            j += 1;
+        } else {
+            removed.push(i);
+            i += 1;
+        }
+    }
+    pair_changes(old, new, &mut removed, &mut added, &mut edits);
+    edits
+}
+
+/// Pairs up the removed and added actions between two matched actions, emitting their edits.
+fn pair_changes(
+    old: &[Json],
+    new: &[Json],
+    removed: &mut Vec<usize>,
+    added: &mut Vec<usize>,
+    edits: &mut Vec<ActionEdit>,
+) {
+    let mut added_left: Vec<Option<usize>> = added.drain(..).map(Some).collect();
+    for i in removed.drain(..) {
+        let same_kind = added_left
+            .iter_mut()
+            .find(|j| j.map_or(false, |j| action_kind(&old[i]) == action_kind(&new[j])));
+        match same_kind.and_then(Option::take) {
+            Some(j) => edits.push(ActionEdit::Changed(i, j)),
+            None => edits.push(ActionEdit::Removed(i)),
+        }
+    }
+    edits.extend(added_left.into_iter().flatten().map(ActionEdit::Added));
+}
+
+/// Returns the kind of an action, e.g. `spend`, from its JSON encoding.
+fn action_kind(action: &Json) -> &str {
+    action
+        .as_object()
+        .and_then(|fields| fields.keys().next())
+        .map_or("unknown", String::as_str)
+}
+
+/// Lists the differences between two JSON values, one per changed field, as
+/// `path: old -> new`.
+fn diff_json(path: &str, old: &Json, new: &Json) -> Vec<String> {
+    let mut changes = Vec::new();
+    diff_json_into(path, old, new, &mut changes);
+    changes
+}
+
+fn diff_json_into(path: &str, old: &Json, new: &Json, changes: &mut Vec<String>) {
+    let join = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+    match (old, new) {
+        _ if old == new => {}
+        (Json::Object(old), Json::Object(new)) => {
+            let keys: std::collections::BTreeSet<_> = old.keys().chain(new.keys()).collect();
+            for key in keys {
+                let missing = Json::Null;
+                diff_json_into(
+                    &join(key),
+                    old.get(key).unwrap_or(&missing),
+                    new.get(key).unwrap_or(&missing),
+                    changes,
+                );
+            }
+        }
+        (Json::Array(old), Json::Array(new)) if old.len() == new.len() => {
+            for (i, (old, new)) in old.iter().zip(new).enumerate() {
+                diff_json_into(&format!("{path}[{i}]"), old, new, changes);
+            }
+        }
+        _ => changes.push(format!("{path}: {old} -> {new}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn actions_are_aligned() {
+        let old = vec![
+            json!({"spend": {"note": 1}}),
+            json!({"output": {"amount": 5}}),
+            json!({"swap": {"pair": "a"}}),
+        ];
+        let new = vec![
+            json!({"spend": {"note": 1}}),
+            json!({"output": {"amount": 6}}),
+            json!({"spend": {"note": 2}}),
+        ];
+        assert_eq!(
+            diff_actions(&old, &new),
+            vec![
+                ActionEdit::Unchanged,
+                ActionEdit::Changed(1, 1),
+                ActionEdit::Removed(2),
+                ActionEdit::Added(2),
+            ]
+        );
+        assert_eq!(
+            diff_json("", &old[1], &new[1]),
+            vec!["output.amount: 5 -> 6".to_string()]
+        );
+    }
+}
@@ -5,6 +5,7 @@ use std::{
 
 use anyhow::Result;
 use camino::Utf8PathBuf;
+use penumbra_compact_block::TrustedCheckpoint;
 use penumbra_custody::threshold;
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
 use rand_core::OsRng;
@@ -31,6 +32,10 @@ pub struct InitCmd {
             parse(try_from_str = Url::parse),
         )]
     grpc_url: Url,
+    /// The URL of another gRPC endpoint serving the same chain, to fail over to if the one in
+    /// use goes down. Can be repeated, in order of preference.
+    #[clap(long = "fallback-grpc-url", parse(try_from_str = Url::parse))]
+    fallback_grpc_urls: Vec<Url>,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -56,7 +61,20 @@ pub enum InitSubCmd {
 pub enum SoftKmsInitCmd {
     /// Generate a new seed phrase and import its corresponding key.
     #[clap(display_order = 100)]
-    Generate,
+    Generate {
+        /// The block height the wallet is created at.
+        ///
+        /// Blocks before the `--checkpoint` will not be scanned, which speeds up the
+        /// initial sync. Use `pcli view reset --from-genesis` to scan them anyways.
+        #[clap(long)]
+        birthday: Option<u64>,
+        /// The checkpoint to start syncing from, as `HEIGHT:ANCHOR`, at or before the birthday.
+        ///
+        /// Get it from a node you trust with `pcli query chain checkpoint`: the node
+        /// being synced from can't prove on its own that a checkpoint is genuine.
+        #[clap(long, requires = "birthday")]
+        checkpoint: Option<TrustedCheckpoint>,
+    },
     /// Import a spend key from an existing seed phrase.
     #[clap(display_order = 200)]
     ImportPhrase {
@@ -67,13 +85,39 @@ pub enum SoftKmsInitCmd {
         /// - you need to replicate legacy derivation for some reason.
         #[clap(long, action)]
         legacy_raw_bip39_derivation: bool,
+        /// The block height the wallet was created at, if known.
+        ///
+        /// Blocks before the `--checkpoint` will not be scanned, so any funds
+        /// received before it will not be found.
+        #[clap(long)]
+        birthday: Option<u64>,
+        /// The checkpoint to start syncing from, as `HEIGHT:ANCHOR`, at or before the birthday.
+        ///
+        /// Get it from a node you trust with `pcli query chain checkpoint`: the node
+        /// being synced from can't prove on its own that a checkpoint is genuine.
+        #[clap(long, requires = "birthday")]
+        checkpoint: Option<TrustedCheckpoint>,
     },
 }
 
 impl SoftKmsInitCmd {
+    fn birthday_height(&self) -> Option<u64> {
+        match self {
+            SoftKmsInitCmd::Generate { birthday, .. } => *birthday,
+            SoftKmsInitCmd::ImportPhrase { birthday, .. } => *birthday,
+        }
+    }
+
+    fn checkpoint(&self) -> Option<TrustedCheckpoint> {
+        match self {
+            SoftKmsInitCmd::Generate { checkpoint, .. } => *checkpoint,
+            SoftKmsInitCmd::ImportPhrase { checkpoint, .. } => *checkpoint,
+        }
+    }
+
     fn spend_key(&self) -> Result<SpendKey> {
         Ok(match self {
-            SoftKmsInitCmd::Generate => {
+            SoftKmsInitCmd::Generate { .. } => {
                 let seed_phrase = SeedPhrase::generate(OsRng);
 
                 // xxx: Something better should be done here, this is in danger of being
@@ -87,6 +131,7 @@ impl SoftKmsInitCmd {
             }
             SoftKmsInitCmd::ImportPhrase {
                 legacy_raw_bip39_derivation,
+                ..
             } => {
                 let mut seed_phrase = String::new();
                 // The `rpassword` crate doesn't support reading from stdin, so we check
@@ -146,7 +191,12 @@ pub enum ThresholdInitCmd {
     },
 }
 
-fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()> {
+fn exec_deal(
+    threshold: u16,
+    home: Vec<Utf8PathBuf>,
+    grpc_url: Url,
+    fallback_grpc_urls: Vec<Url>,
+) -> Result<()> {
     if threshold < 2 {
         anyhow::bail!("threshold must be >= 2");
     }
@@ -160,8 +210,17 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
             custody: CustodyConfig::Threshold(config),
             full_viewing_key,
             grpc_url: grpc_url.clone(),
+            fallback_grpc_urls: fallback_grpc_urls.clone(),
             view_url: None,
+            view_auth_token: None,
+            view_ca_cert: None,
             disable_warning: false,
+            birthday_height: None,
+            checkpoint: None,
+            encrypt_view: false,
+            sync_accounts: None,
+            sync_assets: None,
+            registry: None,
         };
         println!("  Writing signer {} config to {}", i, path);
         std::fs::create_dir_all(path)?;
@@ -173,7 +232,12 @@ fn exec_deal(threshold: u16, home: Vec<Utf8PathBuf>, grpc_url: Url) -> Result<()
 impl InitCmd {
     pub async fn exec(&self, home_dir: impl AsRef<camino::Utf8Path>) -> Result<()> {
         if let InitSubCmd::Threshold(ThresholdInitCmd::Deal { threshold, home }) = &self.subcmd {
-            exec_deal(threshold.clone(), home.clone(), self.grpc_url.clone())?;
+            exec_deal(
+                threshold.clone(),
+                home.clone(),
+                self.grpc_url.clone(),
+                self.fallback_grpc_urls.clone(),
+            )?;
             return Ok(());
         }
         let home_dir = home_dir.as_ref();
@@ -220,12 +284,26 @@ impl InitCmd {
             }
         };
 
+        let (birthday_height, checkpoint) = match &self.subcmd {
+            InitSubCmd::SoftKms(cmd) => (cmd.birthday_height(), cmd.checkpoint()),
+            _ => (None, None),
+        };
+
         let config = PcliConfig {
             custody,
             full_viewing_key,
             grpc_url: self.grpc_url.clone(),
+            fallback_grpc_urls: self.fallback_grpc_urls.clone(),
             view_url: None,
+            view_auth_token: None,
+            view_ca_cert: None,
             disable_warning: false,
+            birthday_height,
+            checkpoint,
+            encrypt_view: false,
+            sync_accounts: None,
+            sync_assets: None,
+            registry: None,
         };
 
         // Create the config directory, if
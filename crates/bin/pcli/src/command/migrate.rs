@@ -0,0 +1,155 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use penumbra_custody::soft_kms::Config as SoftKmsConfig;
+use penumbra_keys::keys::SpendKey;
+use penumbra_view::{Migration, Storage};
+use url::Url;
+
+use crate::config::{CustodyConfig, PcliConfig};
+
+/// The file older versions of `pcli` kept the spend key in, before the config file existed.
+const LEGACY_CUSTODY_FILE_NAME: &str = "custody.json";
+
+#[derive(Debug, clap::Subcommand)]
+pub enum MigrateCmd {
+    /// Upgrade the wallet in the home directory to the formats used by this version of `pcli`.
+    ///
+    /// The config, custody and view data are copied to a backup directory first, so that the
+    /// previous version of `pcli` can still be used with them if needed.
+    Wallet(MigrateWalletCmd),
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct MigrateWalletCmd {
+    /// The GRPC URL to use when creating a config for a wallet that predates config files.
+    #[clap(
+        long,
+        default_value = "https://grpc.testnet.penumbra.zone",
+        env = "PENUMBRA_NODE_PD_URL",
+        parse(try_from_str = Url::parse)
+    )]
+    grpc_url: Url,
+}
+
+impl MigrateCmd {
+    /// Like `init`, this takes the home dir directly, since the wallet in it may not be loadable
+    /// before it is migrated. It should not be invoked when there's a view service running.
+    pub async fn exec(&self, data_path: impl AsRef<Utf8Path>) -> Result<()> {
+        match self {
+            MigrateCmd::Wallet(cmd) => cmd.exec(data_path.as_ref()).await,
+        }
+    }
+}
+
+impl MigrateWalletCmd {
+    async fn exec(&self, data_path: &Utf8Path) -> Result<()> {
+        let backup_path = backup(data_path)?;
+        println!("Backed up the wallet to {backup_path}");
+
+        let config_path = data_path.join(crate::CONFIG_FILE_NAME);
+        let legacy_custody_path = data_path.join(LEGACY_CUSTODY_FILE_NAME);
+        if !config_path.exists() && legacy_custody_path.is_file() {
+            let spend_key = load_legacy_spend_key(&legacy_custody_path)?;
+            let config = PcliConfig {
+                grpc_url: self.grpc_url.clone(),
+                fallback_grpc_urls: Vec::new(),
+                view_url: None,
+                view_auth_token: None,
+                view_ca_cert: None,
+                disable_warning: false,
+                full_viewing_key: spend_key.full_viewing_key().clone(),
+                birthday_height: None,
+                checkpoint: None,
+                encrypt_view: false,
+                sync_accounts: None,
+                sync_assets: None,
+                registry: None,
+                custody: CustodyConfig::SoftKms(SoftKmsConfig::from(spend_key)),
+            };
+            config.save(&config_path)?;
+            std::fs::remove_file(&legacy_custody_path)?;
+            println!("Moved the spend key from {legacy_custody_path} into {config_path}");
+        }
+
+        let config = PcliConfig::load(&config_path)
+            .context("the config could not be migrated; restore it from the backup, or run 'pcli init' to recreate it")?;
+
+        let view_path = data_path.join(crate::VIEW_FILE_NAME);
+        if config.view_url.is_some() || !view_path.is_file() {
+            println!("No local view data to migrate");
+        } else if config.encrypt_view {
+            println!("View data at {view_path} is encrypted, so it can't be migrated; if it can't be loaded, run 'pcli view reset' and resync");
+        } else {
+            match Storage::migrate(&view_path).await? {
+                Migration::UpToDate => println!("View data at {view_path} is up to date"),
+                Migration::Migrated { client_version } => println!(
+                    "Migrated view data at {view_path} from client version {client_version}"
+                ),
+                Migration::Incompatible {
+                    client_version,
+                    tables,
+                } => {
+                    std::fs::remove_file(&view_path)?;
+                    println!(
+                        "View data from client version {client_version} can't be migrated, because the layout of {} changed; deleted it, so the next sync will rescan the chain",
+                        tables.join(", ")
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies every wallet file in the home directory into a new backup directory inside it,
+/// returning the backup directory.
+fn backup(data_path: &Utf8Path) -> Result<camino::Utf8PathBuf> {
+    let view_wal = format!("{}-wal", crate::VIEW_FILE_NAME);
+    let view_shm = format!("{}-shm", crate::VIEW_FILE_NAME);
+    let files = [
+        crate::CONFIG_FILE_NAME,
+        LEGACY_CUSTODY_FILE_NAME,
+        crate::VIEW_FILE_NAME,
+        &view_wal,
+        &view_shm,
+    ]
+    .into_iter()
+    .filter(|file| data_path.join(file).is_file())
+    .collect::<Vec<_>>();
+    if files.is_empty() {
+        anyhow::bail!("No wallet found at {data_path}, so there is nothing to migrate");
+    }
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let backup_path = data_path
+        .join("backups")
+        .join(format!("migrate-{timestamp}"));
+    std::fs::create_dir_all(&backup_path)?;
+    for file in files {
+        std::fs::copy(data_path.join(file), backup_path.join(file))
+            .with_context(|| format!("failed to back up {file}"))?;
+    }
+
+    Ok(backup_path)
+}
+
+/// Reads the spend key from a legacy custody file, which held it either as a Bech32 string or
+/// in its protobuf JSON encoding.
+fn load_legacy_spend_key(path: &Utf8Path) -> Result<SpendKey> {
+    #[derive(serde::Deserialize)]
+    struct LegacyCustody {
+        spend_key: serde_json::Value,
+    }
+
+    let contents = std::fs::read_to_string(path)?;
+    let custody: LegacyCustody =
+        serde_json::from_str(&contents).with_context(|| format!("failed to parse {path}"))?;
+    match custody.spend_key {
+        serde_json::Value::String(spend_key) => spend_key.parse::<SpendKey>(),
+        spend_key => Ok(serde_json::from_value(spend_key)?),
+    }
+    .with_context(|| format!("failed to parse the spend key in {path}"))
+}
@@ -1,10 +1,11 @@
 use anyhow::{anyhow, Context, Result};
 
 mod shielded_pool;
-use colored_json::ToColoredJson;
 use shielded_pool::ShieldedPool;
 mod tx;
 use tx::Tx;
+mod tx_status;
+use tx_status::TxStatus;
 mod chain;
 use chain::ChainCmd;
 mod dex;
@@ -18,19 +19,10 @@ pub(super) use validator::ValidatorCmd;
 mod ibc_query;
 use ibc_query::IbcCmd;
 
-use crate::App;
-
-#[derive(Clone, clap::ValueEnum, Debug)]
-pub enum OutputFormat {
-    Json,
-    Base64,
-}
-
-impl Default for OutputFormat {
-    fn default() -> Self {
-        Self::Json
-    }
-}
+use crate::{
+    output::{Json, Text},
+    App,
+};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum QueryCmd {
@@ -44,6 +36,8 @@ pub enum QueryCmd {
     ShieldedPool(ShieldedPool),
     /// Queries a transaction by hash.
     Tx(Tx),
+    /// Queries whether a transaction was included in a block, or why it was rejected.
+    TxStatus(TxStatus),
     /// Queries information about the chain.
     #[clap(subcommand)]
     Chain(ChainCmd),
@@ -92,6 +86,10 @@ impl QueryCmd {
             return tx.exec(app).await;
         }
 
+        if let QueryCmd::TxStatus(tx_status) = self {
+            return tx_status.exec(app).await;
+        }
+
         if let QueryCmd::Chain(chain) = self {
             return chain.exec(app).await;
         }
@@ -129,14 +127,12 @@ impl QueryCmd {
                 .into_inner()
                 .compact_block
                 .ok_or_else(|| anyhow!("compact block missing from response"))?;
-            let json = serde_json::to_string_pretty(&compact_block)?;
-
-            println!("{}", json.to_colored_json_auto()?);
-            return Ok(());
+            return app.print(&Json(compact_block));
         }
 
         let key = match self {
             QueryCmd::Tx(_)
+            | QueryCmd::TxStatus(_)
             | QueryCmd::Chain(_)
             | QueryCmd::Validator(_)
             | QueryCmd::Dex(_)
@@ -167,14 +163,14 @@ impl QueryCmd {
             .value
             .context(format!("key not found! key={}", key))?;
 
-        self.display_value(&value.value)?;
-        Ok(())
+        self.display_value(app, &value.value)
     }
 
     pub fn offline(&self) -> bool {
         match self {
             QueryCmd::Dex { .. } | QueryCmd::CommunityPool { .. } => false,
             QueryCmd::Tx { .. }
+            | QueryCmd::TxStatus { .. }
             | QueryCmd::Chain { .. }
             | QueryCmd::Validator { .. }
             | QueryCmd::ShieldedPool { .. }
@@ -185,13 +181,12 @@ impl QueryCmd {
         }
     }
 
-    fn display_value(&self, bytes: &[u8]) -> Result<()> {
+    fn display_value(&self, app: &App, bytes: &[u8]) -> Result<()> {
         match self {
-            QueryCmd::Key { .. } => {
-                println!("{}", hex::encode(bytes));
-            }
-            QueryCmd::ShieldedPool(sp) => sp.display_value(bytes)?,
+            QueryCmd::Key { .. } => app.print(&Text(hex::encode(bytes))),
+            QueryCmd::ShieldedPool(sp) => sp.display_value(app, bytes),
             QueryCmd::Tx { .. }
+            | QueryCmd::TxStatus { .. }
             | QueryCmd::Chain { .. }
             | QueryCmd::Validator { .. }
             | QueryCmd::Dex { .. }
@@ -202,8 +197,6 @@ impl QueryCmd {
                 unreachable!("query is special cased")
             }
         }
-
-        Ok(())
     }
 }
 
@@ -2,10 +2,15 @@ use anyhow::{anyhow, Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_app::params::AppParameters;
+use penumbra_compact_block::{SyncCheckpoint, TrustedCheckpoint};
 use penumbra_proto::{
     core::app::v1::{
         query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
     },
+    core::component::compact_block::v1::{
+        query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
+        SyncCheckpointRequest,
+    },
     core::component::sct::v1::{
         query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
     },
@@ -17,10 +22,14 @@ use penumbra_proto::{
     },
 };
 use penumbra_stake::validator;
+use serde::Serialize;
 
 // TODO: remove this subcommand and merge into `pcli q`
 
-use crate::App;
+use crate::{
+    output::{Output, Text},
+    App,
+};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ChainCmd {
@@ -32,8 +41,18 @@ pub enum ChainCmd {
         #[clap(short, long)]
         verbose: bool,
     },
+    /// Display the latest epoch-end checkpoint, as `HEIGHT:ANCHOR`.
+    ///
+    /// Run this against a node you trust, and pass the result to `pcli init --checkpoint`
+    /// to skip scanning the blocks before it.
+    Checkpoint {
+        /// Display the latest checkpoint at or before this height instead.
+        #[clap(long)]
+        height: Option<u64>,
+    },
 }
 
+#[derive(Serialize)]
 pub struct Stats {
     current_block_height: u64,
     current_epoch: u64,
@@ -46,7 +65,7 @@ pub struct Stats {
 }
 
 impl ChainCmd {
-    pub async fn print_app_params(&self, app: &mut App) -> Result<()> {
+    pub async fn get_app_params(&self, app: &mut App) -> Result<AppParameters> {
         let mut client = AppQueryServiceClient::new(app.pd_channel().await?);
         let params: AppParameters = client
             .app_parameters(tonic::Request::new(AppParametersRequest {}))
@@ -56,64 +75,7 @@ impl ChainCmd {
             .ok_or_else(|| anyhow::anyhow!("empty AppParametersResponse message"))?
             .try_into()?;
 
-        println!("Chain Parameters:");
-        let mut table = Table::new();
-        table.load_preset(presets::NOTHING);
-        table
-            .set_header(vec!["", ""])
-            .add_row(vec!["Chain ID", &params.chain_id])
-            .add_row(vec![
-                "Epoch Duration",
-                &format!("{}", params.sct_params.epoch_duration),
-            ])
-            .add_row(vec![
-                "Unbonding Epochs",
-                &format!("{}", params.stake_params.unbonding_epochs),
-            ])
-            .add_row(vec![
-                "Active Validator Limit",
-                &format!("{}", params.stake_params.active_validator_limit),
-            ])
-            .add_row(vec![
-                "Base Reward Rate (bps^2)",
-                &format!("{}", params.stake_params.base_reward_rate),
-            ])
-            .add_row(vec![
-                "Slashing Penalty (Misbehavior) (bps^2)",
-                &format!("{}", params.stake_params.slashing_penalty_misbehavior),
-            ])
-            .add_row(vec![
-                "Slashing Penalty (Downtime) (bps^2)",
-                &format!("{}", params.stake_params.slashing_penalty_downtime),
-            ])
-            .add_row(vec![
-                "Signed Blocks Window (blocks)",
-                &format!("{}", params.stake_params.signed_blocks_window_len),
-            ])
-            .add_row(vec![
-                "Missed Blocks Max",
-                &format!("{}", params.stake_params.missed_blocks_maximum),
-            ])
-            .add_row(vec![
-                "Proposal Deposit Amount (upenumbra)",
-                &format!("{}", params.governance_params.proposal_deposit_amount),
-            ])
-            .add_row(vec![
-                "IBC Enabled",
-                &format!("{}", params.ibc_params.ibc_enabled),
-            ])
-            .add_row(vec![
-                "Inbound ICS-20 Enabled",
-                &format!("{}", params.ibc_params.inbound_ics20_transfers_enabled),
-            ])
-            .add_row(vec![
-                "Outbound ICS-20 Enabled",
-                &format!("{}", params.ibc_params.outbound_ics20_transfers_enabled),
-            ]);
-
-        println!("{table}");
-
-        Ok(())
+        Ok(params)
     }
 
     pub async fn get_stats(&self, app: &mut App) -> Result<Stats> {
@@ -190,57 +152,153 @@ impl ChainCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         match self {
             ChainCmd::Params => {
-                self.print_app_params(app).await?;
+                let params = self.get_app_params(app).await?;
+                app.print(&ChainParams(params))
             }
             // TODO: we could implement this as an RPC call using the metrics
             // subsystems once #829 is complete
             // OR (hdevalence): fold it into pcli q
             ChainCmd::Info { verbose } => {
-                if *verbose {
-                    self.print_app_params(app).await?;
-                }
+                let params = if *verbose {
+                    Some(ChainParams(self.get_app_params(app).await?))
+                } else {
+                    None
+                };
 
                 let stats = self.get_stats(app).await?;
 
-                println!("Chain Info:");
-                let mut table = Table::new();
-                table.load_preset(presets::NOTHING);
-                table
-                    .set_header(vec!["", ""])
-                    .add_row(vec![
-                        "Current Block Height",
-                        &format!("{}", stats.current_block_height),
-                    ])
-                    .add_row(vec!["Current Epoch", &format!("{}", stats.current_epoch)])
-                    .add_row(vec![
-                        "Total Validators",
-                        &format!("{}", stats.total_validators),
-                    ])
-                    .add_row(vec![
-                        "Active Validators",
-                        &format!("{}", stats.active_validators),
-                    ])
-                    .add_row(vec![
-                        "Inactive Validators",
-                        &format!("{}", stats.inactive_validators),
-                    ])
-                    .add_row(vec![
-                        "Jailed Validators",
-                        &format!("{}", stats.jailed_validators),
-                    ])
-                    .add_row(vec![
-                        "Tombstoned Validators",
-                        &format!("{}", stats.tombstoned_validators),
-                    ])
-                    .add_row(vec![
-                        "Disabled Validators",
-                        &format!("{}", stats.disabled_validators),
-                    ]);
-
-                println!("{table}");
+                app.print(&ChainInfo { params, stats })
             }
-        };
+            ChainCmd::Checkpoint { height } => {
+                let mut client = CompactBlockQueryServiceClient::new(app.pd_channel().await?);
+                let checkpoint: SyncCheckpoint = client
+                    .sync_checkpoint(tonic::Request::new(SyncCheckpointRequest {
+                        max_height: height.unwrap_or_default(),
+                    }))
+                    .await?
+                    .into_inner()
+                    .try_into()?;
+
+                app.print(&Text(TrustedCheckpoint::from(&checkpoint).to_string()))
+            }
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct ChainParams(AppParameters);
+
+impl Output for ChainParams {
+    fn render(&self) -> Result<String> {
+        let params = &self.0;
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table
+            .set_header(vec!["", ""])
+            .add_row(vec!["Chain ID", &params.chain_id])
+            .add_row(vec![
+                "Epoch Duration",
+                &format!("{}", params.sct_params.epoch_duration),
+            ])
+            .add_row(vec![
+                "Unbonding Epochs",
+                &format!("{}", params.stake_params.unbonding_epochs),
+            ])
+            .add_row(vec![
+                "Active Validator Limit",
+                &format!("{}", params.stake_params.active_validator_limit),
+            ])
+            .add_row(vec![
+                "Base Reward Rate (bps^2)",
+                &format!("{}", params.stake_params.base_reward_rate),
+            ])
+            .add_row(vec![
+                "Slashing Penalty (Misbehavior) (bps^2)",
+                &format!("{}", params.stake_params.slashing_penalty_misbehavior),
+            ])
+            .add_row(vec![
+                "Slashing Penalty (Downtime) (bps^2)",
+                &format!("{}", params.stake_params.slashing_penalty_downtime),
+            ])
+            .add_row(vec![
+                "Signed Blocks Window (blocks)",
+                &format!("{}", params.stake_params.signed_blocks_window_len),
+            ])
+            .add_row(vec![
+                "Missed Blocks Max",
+                &format!("{}", params.stake_params.missed_blocks_maximum),
+            ])
+            .add_row(vec![
+                "Proposal Deposit Amount (upenumbra)",
+                &format!("{}", params.governance_params.proposal_deposit_amount),
+            ])
+            .add_row(vec![
+                "IBC Enabled",
+                &format!("{}", params.ibc_params.ibc_enabled),
+            ])
+            .add_row(vec![
+                "Inbound ICS-20 Enabled",
+                &format!("{}", params.ibc_params.inbound_ics20_transfers_enabled),
+            ])
+            .add_row(vec![
+                "Outbound ICS-20 Enabled",
+                &format!("{}", params.ibc_params.outbound_ics20_transfers_enabled),
+            ]);
+
+        Ok(format!("Chain Parameters:\n{table}"))
+    }
+}
+
+#[derive(Serialize)]
+pub struct ChainInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params: Option<ChainParams>,
+    #[serde(flatten)]
+    stats: Stats,
+}
+
+impl Output for ChainInfo {
+    fn render(&self) -> Result<String> {
+        let stats = &self.stats;
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table
+            .set_header(vec!["", ""])
+            .add_row(vec![
+                "Current Block Height",
+                &format!("{}", stats.current_block_height),
+            ])
+            .add_row(vec!["Current Epoch", &format!("{}", stats.current_epoch)])
+            .add_row(vec![
+                "Total Validators",
+                &format!("{}", stats.total_validators),
+            ])
+            .add_row(vec![
+                "Active Validators",
+                &format!("{}", stats.active_validators),
+            ])
+            .add_row(vec![
+                "Inactive Validators",
+                &format!("{}", stats.inactive_validators),
+            ])
+            .add_row(vec![
+                "Jailed Validators",
+                &format!("{}", stats.jailed_validators),
+            ])
+            .add_row(vec![
+                "Tombstoned Validators",
+                &format!("{}", stats.tombstoned_validators),
+            ])
+            .add_row(vec![
+                "Disabled Validators",
+                &format!("{}", stats.disabled_validators),
+            ]);
 
-        Ok(())
+        let info = format!("Chain Info:\n{table}");
+        match &self.params {
+            Some(params) => Ok(format!("{}\n{info}", params.render()?)),
+            None => Ok(info),
+        }
     }
 }
@@ -1,4 +1,7 @@
-use crate::App;
+use crate::{
+    output::{Output, ValueOutput},
+    App,
+};
 use anyhow::{Context, Result};
 use futures::TryStreamExt;
 use penumbra_asset::Value;
@@ -7,7 +10,7 @@ use penumbra_proto::{
     penumbra::core::component::community_pool::v1::query_service_client::QueryServiceClient as CommunityPoolQueryServiceClient,
 };
 use penumbra_view::ViewClient;
-use std::io::{stdout, Write};
+use serde::Serialize;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum CommunityPoolCmd {
@@ -49,18 +52,33 @@ impl CommunityPoolCmd {
             .context("cannot process Community Pool balance data")?;
 
         let asset_cache = app.view().assets().await?;
-        let mut writer = stdout();
-        for balance_response in balances {
-            let balance: Value = balance_response
-                .balance
-                .expect("balance should always be set")
-                .try_into()
-                .context("cannot parse balance")?;
-            let value_str = balance.format(&asset_cache);
+        let balances = balances
+            .into_iter()
+            .map(|balance_response| {
+                let balance: Value = balance_response
+                    .balance
+                    .expect("balance should always be set")
+                    .try_into()
+                    .context("cannot parse balance")?;
+                Ok(ValueOutput::new(balance, &asset_cache))
+            })
+            .collect::<Result<Vec<_>>>()?;
 
-            writeln!(writer, "{value_str}")?;
-        }
+        app.print(&CommunityPoolBalance(balances))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct CommunityPoolBalance(Vec<ValueOutput>);
 
-        Ok(())
+impl Output for CommunityPoolBalance {
+    fn render(&self) -> Result<String> {
+        Ok(self
+            .0
+            .iter()
+            .map(ValueOutput::to_string)
+            .collect::<Vec<_>>()
+            .join("\n"))
     }
 }
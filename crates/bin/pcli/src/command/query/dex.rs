@@ -1,5 +1,4 @@
 use anyhow::{Context, Result};
-use comfy_table::{presets, Table};
 use futures::{Stream, StreamExt, TryStreamExt};
 use std::pin::Pin;
 
@@ -12,8 +11,9 @@ use penumbra_proto::core::component::{
     dex::v1::{
         query_service_client::QueryServiceClient as DexQueryServiceClient,
         simulation_service_client::SimulationServiceClient, ArbExecutionRequest,
-        BatchSwapOutputDataRequest, LiquidityPositionByIdRequest, LiquidityPositionsByPriceRequest,
-        LiquidityPositionsRequest, SimulateTradeRequest, SwapExecutionRequest,
+        BatchSwapOutputDataRequest, DexStatusRequest, LiquidityPositionByIdRequest,
+        LiquidityPositionsByPriceRequest, LiquidityPositionsRequest, SimulateTradeRequest,
+        SwapExecutionRequest,
     },
     shielded_pool::v1::{
         query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
@@ -21,10 +21,13 @@ use penumbra_proto::core::component::{
     },
 };
 use penumbra_view::ViewClient;
+use serde::Serialize;
 use tonic::transport::Channel;
 
 use crate::{
-    command::utils::{self, render_positions},
+    command::utils::render_positions,
+    output::{Json, Output},
+    render::{self, Values},
     App,
 };
 
@@ -90,6 +93,8 @@ pub enum DexCmd {
         #[clap(long, display_order = 100)]
         into: String,
     },
+    /// Display whether opening positions and swapping are paused by governance.
+    Status,
 }
 
 impl DexCmd {
@@ -218,92 +223,21 @@ impl DexCmd {
     pub async fn print_swap_execution(
         &self,
         app: &mut App,
-        swap_execution: &SwapExecution,
+        swap_execution: SwapExecution,
     ) -> Result<()> {
-        let cache = app.view().assets().await?;
-
-        println!(
-            "{} => {} via:",
-            swap_execution.input.format(&cache),
-            swap_execution.output.format(&cache),
-        );
-
-        // Try to make a nice table of execution traces. To do this, first find
-        // the max length of any subtrace:
-        let max_trace_len = swap_execution
-            .traces
-            .iter()
-            .map(|trace| trace.len())
-            .max()
-            .unwrap_or(0);
-
-        // Spacer | trace hops | trace price
-        let column_count = 1 + max_trace_len + 1;
-
-        let mut table = Table::new();
-        table.load_preset(presets::NOTHING);
-        let mut headers = vec![""; column_count];
-        headers[1] = "Trace";
-        headers[column_count - 1] = "Subprice";
-        table.set_header(headers);
-
-        let price_string = |input: Value, output: Value| -> String {
-            use penumbra_dex::lp::SellOrder;
-            format!(
-                "{}/{}",
-                SellOrder {
-                    offered: output,
-                    desired: input,
-                    fee: 0,
-                }
-                .price_str(&cache)
-                .expect("assets are known"),
-                // kind of hacky, this is assuming coincidency between price_str calcs
-                // and this code
-                Value {
-                    asset_id: output.asset_id,
-                    amount: cache
-                        .get(&output.asset_id)
-                        .expect("asset ID should exist in the cache")
-                        .default_unit()
-                        .unit_amount(),
-                }
-                .format(&cache)
-            )
-        };
-
-        for trace in &swap_execution.traces {
-            let mut row = vec![String::new(); column_count];
-            // Put all but the last element of the trace in the columns, left-to-right
-            for i in 0..(trace.len() - 1) {
-                row[1 + i] = format!("{} =>", trace[i].format(&cache));
-            }
-            // Right-align the last element of the trace, in case subtraces have different lengths
-            row[column_count - 2] = trace
-                .last()
-                .context("trace should have elements")?
-                .format(&cache)
-                .to_string();
-            // Print the price in the last column.
-            row[column_count - 1] = price_string(
-                *trace.first().context("trace should have elements")?,
-                *trace.last().context("trace should have elements")?,
-            );
-
-            table.add_row(row);
-        }
-
-        println!("{}", table);
-
-        Ok(())
+        let assets = app.view().assets().await?;
+        app.print(&SwapExecutionOutput {
+            execution: swap_execution,
+            assets,
+        })
     }
 
-    pub async fn print_batch_outputs(
+    pub async fn get_batch_outputs_with_metadata(
         &self,
         app: &mut App,
         height: &u64,
         trading_pair: &TradingPair,
-    ) -> Result<()> {
+    ) -> Result<BatchOutputs> {
         let mut client = ShieldedPoolQueryServiceClient::new(app.pd_channel().await?);
 
         let outputs = self.get_batch_outputs(app, height, trading_pair).await?;
@@ -327,43 +261,11 @@ impl DexCmd {
             .context("denom metadata for asset 2 not found")?
             .try_into()?;
 
-        let unit_1 = asset_1.default_unit();
-        let unit_2 = asset_2.default_unit();
-
-        let consumed_1 = outputs.delta_1 - outputs.unfilled_1;
-        let consumed_2 = outputs.delta_2 - outputs.unfilled_2;
-
-        println!("Batch Swap Outputs for height {}:", outputs.height);
-        println!(
-            "Trade {} => {}",
-            unit_1.format_value(outputs.delta_1),
-            unit_2
-        );
-        println!(
-            "\tOutput:         {} for {}",
-            unit_2.format_value(outputs.lambda_2),
-            unit_1.format_value(consumed_1)
-        );
-        println!(
-            "\tUnfilled Input: {}",
-            unit_1.format_value(outputs.unfilled_1)
-        );
-        println!(
-            "Trade {} => {}",
-            unit_2.format_value(outputs.delta_2),
-            unit_1
-        );
-        println!(
-            "\tOutput:         {} for {}",
-            unit_1.format_value(outputs.lambda_1),
-            unit_2.format_value(consumed_2)
-        );
-        println!(
-            "\tUnfilled Input: {}",
-            unit_2.format_value(outputs.unfilled_2)
-        );
-
-        Ok(())
+        Ok(BatchOutputs {
+            outputs,
+            asset_1,
+            asset_2,
+        })
     }
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
@@ -372,7 +274,10 @@ impl DexCmd {
                 height,
                 trading_pair,
             } => {
-                self.print_batch_outputs(app, height, trading_pair).await?;
+                let outputs = self
+                    .get_batch_outputs_with_metadata(app, height, trading_pair)
+                    .await?;
+                app.print(&outputs)?;
             }
             DexCmd::SwapExecution {
                 height,
@@ -380,19 +285,19 @@ impl DexCmd {
             } => {
                 let swap_execution = self.get_swap_execution(app, height, trading_pair).await?;
 
-                self.print_swap_execution(app, &swap_execution).await?;
+                self.print_swap_execution(app, swap_execution).await?;
             }
             DexCmd::ArbExecution { height } => {
                 let swap_execution = self.get_arb_execution(app, height).await?;
 
-                self.print_swap_execution(app, &swap_execution).await?;
+                self.print_swap_execution(app, swap_execution).await?;
             }
             DexCmd::Simulate { input, into } => {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
                 let swap_execution = self.get_simulated_execution(app, input, into.id()).await?;
-                self.print_swap_execution(app, &swap_execution).await?;
+                self.print_swap_execution(app, swap_execution).await?;
             }
             DexCmd::AllPositions { include_closed } => {
                 let client = DexQueryServiceClient::new(app.pd_channel().await?);
@@ -405,7 +310,10 @@ impl DexCmd {
 
                 let positions = positions_stream.try_collect::<Vec<_>>().await?;
 
-                println!("{}", utils::render_positions(&asset_cache, &positions));
+                app.print(&Positions {
+                    positions,
+                    assets: asset_cache,
+                })?;
             }
             DexCmd::Positions {
                 trading_pair,
@@ -418,7 +326,10 @@ impl DexCmd {
                     .try_collect::<Vec<_>>()
                     .await?;
                 let asset_cache = app.view().assets().await?;
-                println!("{}", render_positions(&asset_cache, &positions));
+                app.print(&Positions {
+                    positions,
+                    assets: asset_cache,
+                })?;
             }
             DexCmd::Position { id, raw } => {
                 let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
@@ -434,47 +345,236 @@ impl DexCmd {
                     .try_into()?;
 
                 if *raw {
-                    println!("{}", serde_json::to_string_pretty(&position)?);
+                    app.print(&Json(position))?;
                 } else {
-                    let asset_cache = app.view().assets().await?;
-                    let mut table = Table::new();
-                    table.load_preset(presets::NOTHING);
-                    table.add_row(vec!["ID".to_string(), id.to_string()]);
-                    table.add_row(vec!["State".to_string(), position.state.to_string()]);
-                    table.add_row(vec![
-                        "Reserves 1".to_string(),
-                        Value {
-                            asset_id: position.phi.pair.asset_1(),
-                            amount: position.reserves.r1,
-                        }
-                        .format(&asset_cache),
-                    ]);
-                    table.add_row(vec![
-                        "Reserves 2".to_string(),
-                        Value {
-                            asset_id: position.phi.pair.asset_2(),
-                            amount: position.reserves.r2,
-                        }
-                        .format(&asset_cache),
-                    ]);
-                    table.add_row(vec![
-                        "Fee".to_string(),
-                        format!("{}bps", position.phi.component.fee),
-                    ]);
-                    table.add_row(vec![
-                        "p".to_string(),
-                        position.phi.component.p.value().to_string(),
-                    ]);
-                    table.add_row(vec![
-                        "q".to_string(),
-                        position.phi.component.q.value().to_string(),
-                    ]);
-                    table.add_row(vec!["Nonce".to_string(), hex::encode(position.nonce)]);
-                    println!("{}", table);
+                    let assets = app.view().assets().await?;
+                    app.print(&PositionOutput { position, assets })?;
                 }
             }
+            DexCmd::Status => {
+                let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+                let status = client.dex_status(DexStatusRequest {}).await?.into_inner();
+                app.print(&DexStatus {
+                    paused: status.is_paused,
+                })?;
+            }
         };
 
         Ok(())
     }
 }
+
+/// The execution of a swap, along the routes it was split across.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct SwapExecutionOutput {
+    execution: SwapExecution,
+    #[serde(skip)]
+    assets: asset::Cache,
+}
+
+impl Output for SwapExecutionOutput {
+    fn render(&self) -> Result<String> {
+        let swap_execution = &self.execution;
+        let cache = &self.assets;
+        let values = Values::new(cache);
+        let price_impact = swap_execution
+            .price_impact()
+            .map(|impact| format!(" (price impact: {:.2}%)", f64::from(impact) * 100.0))
+            .unwrap_or_default();
+        let summary = format!(
+            "{} => {}{} via:",
+            values.value(swap_execution.input),
+            values.value(swap_execution.output),
+            price_impact,
+        );
+
+        // Try to make a nice table of execution traces. To do this, first find
+        // the max length of any subtrace:
+        let max_trace_len = swap_execution
+            .traces
+            .iter()
+            .map(|trace| trace.len())
+            .max()
+            .unwrap_or(0);
+
+        // Spacer | trace hops | trace price
+        let column_count = 1 + max_trace_len + 1;
+
+        let mut headers = vec![""; column_count];
+        headers[1] = "Trace";
+        headers[column_count - 1] = "Subprice";
+        let mut table = render::table_with_header(headers);
+
+        let price_string = |input: Value, output: Value| -> String {
+            use penumbra_dex::lp::SellOrder;
+            format!(
+                "{}/{}",
+                SellOrder {
+                    offered: output,
+                    desired: input,
+                    fee: 0,
+                }
+                .price_str(cache)
+                .expect("assets are known"),
+                // kind of hacky, this is assuming coincidency between price_str calcs
+                // and this code
+                values.amount(
+                    cache
+                        .get(&output.asset_id)
+                        .expect("asset ID should exist in the cache")
+                        .default_unit()
+                        .unit_amount(),
+                    output.asset_id,
+                )
+            )
+        };
+
+        for trace in &swap_execution.traces {
+            let mut row = vec![String::new(); column_count];
+            // Put all but the last element of the trace in the columns, left-to-right
+            for i in 0..(trace.len() - 1) {
+                row[1 + i] = format!("{} =>", values.value(trace[i]));
+            }
+            // Right-align the last element of the trace, in case subtraces have different lengths
+            row[column_count - 2] =
+                values.value(*trace.last().context("trace should have elements")?);
+            // Print the price in the last column.
+            row[column_count - 1] = price_string(
+                *trace.first().context("trace should have elements")?,
+                *trace.last().context("trace should have elements")?,
+            );
+
+            table.add_row(row);
+        }
+
+        Ok(format!("{summary}\n{table}"))
+    }
+}
+
+/// The outputs of a batch swap, with the metadata of both assets of the pair.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct BatchOutputs {
+    outputs: BatchSwapOutputData,
+    #[serde(skip)]
+    asset_1: Metadata,
+    #[serde(skip)]
+    asset_2: Metadata,
+}
+
+impl Output for BatchOutputs {
+    fn render(&self) -> Result<String> {
+        let outputs = &self.outputs;
+        let unit_1 = self.asset_1.default_unit();
+        let unit_2 = self.asset_2.default_unit();
+
+        let consumed_1 = outputs.delta_1 - outputs.unfilled_1;
+        let consumed_2 = outputs.delta_2 - outputs.unfilled_2;
+
+        let mut lines = vec![format!("Batch Swap Outputs for height {}:", outputs.height)];
+        lines.push(format!(
+            "Trade {} => {}",
+            unit_1.format_value(outputs.delta_1),
+            unit_2
+        ));
+        lines.push(format!(
+            "\tOutput:         {} for {}",
+            unit_2.format_value(outputs.lambda_2),
+            unit_1.format_value(consumed_1)
+        ));
+        lines.push(format!(
+            "\tUnfilled Input: {}",
+            unit_1.format_value(outputs.unfilled_1)
+        ));
+        lines.push(format!(
+            "Trade {} => {}",
+            unit_2.format_value(outputs.delta_2),
+            unit_1
+        ));
+        lines.push(format!(
+            "\tOutput:         {} for {}",
+            unit_1.format_value(outputs.lambda_1),
+            unit_2.format_value(consumed_2)
+        ));
+        lines.push(format!(
+            "\tUnfilled Input: {}",
+            unit_2.format_value(outputs.unfilled_2)
+        ));
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct Positions {
+    positions: Vec<Position>,
+    #[serde(skip)]
+    assets: asset::Cache,
+}
+
+impl Output for Positions {
+    fn render(&self) -> Result<String> {
+        Ok(render_positions(&self.assets, &self.positions))
+    }
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct PositionOutput {
+    position: Position,
+    #[serde(skip)]
+    assets: asset::Cache,
+}
+
+impl Output for PositionOutput {
+    fn render(&self) -> Result<String> {
+        let position = &self.position;
+        let values = Values::new(&self.assets);
+        let mut table = render::table();
+        table.add_row(vec!["ID".to_string(), position.id().to_string()]);
+        table.add_row(vec!["State".to_string(), position.state.to_string()]);
+        table.add_row(vec![
+            "Reserves 1".to_string(),
+            values.amount(position.reserves.r1, position.phi.pair.asset_1()),
+        ]);
+        table.add_row(vec![
+            "Reserves 2".to_string(),
+            values.amount(position.reserves.r2, position.phi.pair.asset_2()),
+        ]);
+        table.add_row(vec![
+            "Fee".to_string(),
+            format!("{}bps", position.phi.component.fee),
+        ]);
+        table.add_row(vec![
+            "p".to_string(),
+            position.phi.component.p.value().to_string(),
+        ]);
+        table.add_row(vec![
+            "q".to_string(),
+            position.phi.component.q.value().to_string(),
+        ]);
+        table.add_row(vec!["Nonce".to_string(), hex::encode(position.nonce)]);
+        Ok(table.to_string())
+    }
+}
+
+#[derive(Serialize)]
+pub struct DexStatus {
+    paused: bool,
+}
+
+impl Output for DexStatus {
+    fn render(&self) -> Result<String> {
+        if self.paused {
+            Ok(
+                "The dex is paused: no new positions or swaps are accepted.\n\
+                Existing positions can still be closed and withdrawn, and swaps claimed."
+                    .to_string(),
+            )
+        } else {
+            Ok("The dex is active.".to_string())
+        }
+    }
+}
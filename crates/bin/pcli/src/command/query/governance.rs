@@ -1,22 +1,32 @@
-use std::{
-    collections::BTreeMap,
-    io::{stdout, Write},
-};
+use std::collections::BTreeMap;
 
 use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
 use futures::TryStreamExt;
-use penumbra_governance::Vote;
-use penumbra_proto::core::component::governance::v1::{
-    query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
-    AllTalliedDelegatorVotesForProposalRequest, ProposalDataRequest, ProposalListRequest,
-    ProposalListResponse, ValidatorVotesRequest, ValidatorVotesResponse,
-    VotingPowerAtProposalStartRequest,
+use penumbra_governance::{
+    proposal::{Proposal, ProposalToml},
+    ProposalPayload, Vote,
+};
+use penumbra_proto::core::{
+    app::v1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParameters,
+        SimulateParameterChangeRequest,
+    },
+    component::governance::v1::{
+        query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
+        AllTalliedDelegatorVotesForProposalRequest, ProposalDataRequest, ProposalListRequest,
+        ProposalListResponse, ProposalState, ValidatorVotesRequest, ValidatorVotesResponse,
+        VotingPowerAtProposalStartRequest,
+    },
 };
 use penumbra_stake::IdentityKey;
 use serde::Serialize;
 use serde_json::json;
 
-use crate::App;
+use crate::{
+    output::{Json, Output, Toml},
+    render, App,
+};
 
 #[derive(Debug, clap::Subcommand)]
 pub enum GovernanceCmd {
@@ -34,6 +44,15 @@ pub enum GovernanceCmd {
         #[clap(subcommand)]
         query: PerProposalCmd,
     },
+    /// Check a parameter change proposal against the current app parameters, without
+    /// submitting it.
+    ///
+    /// Prints the parameters the change would result in, and why it would be rejected, if it
+    /// would be.
+    SimulateParameterChange {
+        /// The proposal file, in the format written by `pcli tx proposal template parameter-change`.
+        file: Utf8PathBuf,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -54,6 +73,33 @@ impl GovernanceCmd {
 
         let mut client = GovernanceQueryServiceClient::new(app.pd_channel().await?);
         match self {
+            GovernanceCmd::SimulateParameterChange { file } => {
+                let proposal_string = std::fs::read_to_string(file)
+                    .with_context(|| format!("can't read proposal file {file}"))?;
+                let proposal_toml: ProposalToml =
+                    toml::from_str(&proposal_string).context("can't parse proposal file")?;
+                let proposal: Proposal = proposal_toml
+                    .try_into()
+                    .context("can't parse proposal file")?;
+                let ProposalPayload::ParameterChange { old, new } = proposal.payload else {
+                    anyhow::bail!("proposal {} is not a parameter change", proposal.id);
+                };
+
+                let response = AppQueryServiceClient::new(app.pd_channel().await?)
+                    .simulate_parameter_change(SimulateParameterChangeRequest {
+                        old: Some((*old).into()),
+                        new: Some((*new).into()),
+                    })
+                    .await?
+                    .into_inner();
+                app.print(&ParameterChangeSimulation {
+                    app_parameters: response
+                        .app_parameters
+                        .context("missing app parameters in response")?,
+                    errors: response.errors,
+                    warnings: response.warnings,
+                })
+            }
             GovernanceCmd::ListProposals { inactive } => {
                 let proposals: Vec<ProposalListResponse> = client
                     .proposal_list(ProposalListRequest {
@@ -65,25 +111,24 @@ impl GovernanceCmd {
                     .try_collect::<Vec<_>>()
                     .await
                     .context("cannot process proposal list data")?;
-                let mut writer = stdout();
-                for proposal_response in proposals {
-                    let proposal = proposal_response
-                        .proposal
-                        .expect("proposal should always be set");
-                    let proposal_title = proposal.title;
+                let proposals = proposals
+                    .into_iter()
+                    .map(|proposal_response| {
+                        let proposal = proposal_response
+                            .proposal
+                            .expect("proposal should always be set");
+                        let state = proposal_response
+                            .state
+                            .expect("proposal state should always be set");
 
-                    let proposal_state = proposal_response
-                        .state
-                        .expect("proposal state should always be set");
-
-                    let proposal_id = proposal.id;
-
-                    writeln!(
-                        writer,
-                        "#{proposal_id} {proposal_state:?}    {proposal_title}"
-                    )?;
-                }
-                Ok(())
+                        ProposalSummary {
+                            id: proposal.id,
+                            state,
+                            title: proposal.title,
+                        }
+                    })
+                    .collect();
+                app.print(&ProposalList(proposals))
             }
             GovernanceCmd::Proposal { proposal_id, query } => {
                 match query {
@@ -95,11 +140,11 @@ impl GovernanceCmd {
                             })
                             .await?
                             .into_inner();
-                        toml(
-                            &proposal
+                        app.print(&Toml(
+                            proposal
                                 .proposal
                                 .expect("proposal should always be populated"),
-                        )?;
+                        ))?;
                     }
                     PerProposalCmd::State => {
                         let proposal = client
@@ -109,11 +154,11 @@ impl GovernanceCmd {
                             })
                             .await?
                             .into_inner();
-                        json(
-                            &proposal
+                        app.print(&Json(
+                            proposal
                                 .state
                                 .expect("proposal state should always be populated"),
-                        )?;
+                        ))?;
                     }
                     PerProposalCmd::Period => {
                         let proposal = client
@@ -129,7 +174,7 @@ impl GovernanceCmd {
                             "voting_start_block": start,
                             "voting_end_block": end,
                         });
-                        json(&period)?;
+                        app.print(&Json(period))?;
                     }
                     PerProposalCmd::Tally => {
                         let validator_votes: Vec<ValidatorVotesResponse> = client
@@ -242,10 +287,10 @@ impl GovernanceCmd {
                             });
                         }
 
-                        json(&json!({
+                        app.print(&Json(json!({
                         "total": json_tally(&total),
                         "details": all_votes_and_power,
-                        }))?;
+                        })))?;
                     }
                 };
                 Ok(())
@@ -254,11 +299,59 @@ impl GovernanceCmd {
     }
 }
 
-fn json<T: Serialize>(value: &T) -> Result<()> {
-    let mut writer = stdout();
-    serde_json::to_writer_pretty(&mut writer, value)?;
-    writer.write_all(b"\n")?;
-    Ok(())
+/// The proposals, by number.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct ProposalList(Vec<ProposalSummary>);
+
+#[derive(Serialize)]
+pub struct ProposalSummary {
+    id: u64,
+    state: ProposalState,
+    title: String,
+}
+
+impl Output for ProposalList {
+    fn render(&self) -> Result<String> {
+        let mut table = render::table_with_header(["ID", "State", "Title"]);
+        for proposal in &self.0 {
+            table.add_row(vec![
+                format!("#{}", proposal.id),
+                format!("{:?}", proposal.state),
+                proposal.title.clone(),
+            ]);
+        }
+        Ok(table.to_string())
+    }
+}
+
+/// The outcome of a simulated parameter change.
+#[derive(Serialize)]
+pub struct ParameterChangeSimulation {
+    app_parameters: AppParameters,
+    errors: Vec<String>,
+    warnings: Vec<String>,
+}
+
+impl Output for ParameterChangeSimulation {
+    fn render(&self) -> Result<String> {
+        let mut lines = vec![toml::to_string_pretty(&self.app_parameters)?
+            .trim_end()
+            .to_string()];
+        lines.push(String::new());
+        lines.extend(self.errors.iter().map(|error| format!("error: {error}")));
+        lines.extend(
+            self.warnings
+                .iter()
+                .map(|warning| format!("warning: {warning}")),
+        );
+        lines.push(if self.errors.is_empty() {
+            "The parameter change would be accepted.".to_string()
+        } else {
+            "The parameter change would be rejected.".to_string()
+        });
+        Ok(lines.join("\n"))
+    }
 }
 
 fn json_tally(tally: &penumbra_governance::Tally) -> serde_json::Value {
@@ -274,10 +367,3 @@ fn json_tally(tally: &penumbra_governance::Tally) -> serde_json::Value {
     }
     map.into()
 }
-
-fn toml<T: Serialize>(value: &T) -> Result<()> {
-    let mut writer = stdout();
-    let string = toml::to_string_pretty(value)?;
-    writer.write_all(string.as_bytes())?;
-    Ok(())
-}
@@ -1,7 +1,6 @@
 use std::time::SystemTime;
 
 use anyhow::Result;
-use colored_json::ToColoredJson;
 use comfy_table::Table;
 use ibc_proto::ibc::core::channel::v1::query_client::QueryClient as ChannelQueryClient;
 use ibc_proto::ibc::core::channel::v1::{
@@ -16,8 +15,20 @@ use ibc_proto::ibc::core::connection::v1::{
 use ibc_types::core::channel::channel::State;
 use ibc_types::lightclients::tendermint::client_state::ClientState as TendermintClientState;
 use ibc_types::lightclients::tendermint::consensus_state::ConsensusState as TendermintConsensusState;
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use penumbra_proto::core::component::shielded_pool::v1::{
+    query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient, Ics20FlowsRequest,
+};
+
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
 
-use crate::App;
+use crate::{
+    output::{Json, Output},
+    registry::Registry,
+    App,
+};
 
 /// Queries the chain for IBC data. Results will be printed in JSON.
 /// The singular subcommands require identifiers, whereas the plural subcommands
@@ -50,6 +61,11 @@ pub enum IbcCmd {
     },
     /// Queries for info on all IBC channels.
     Channels {},
+    /// Queries for the total amounts of an asset transferred into and out of Penumbra over IBC.
+    /// Requires the denomination of the asset, e.g. "transfer/channel-0/uosmo".
+    Flows { denom: String },
+    /// Shows the counterparty chains listed in the configured chain registry.
+    Registry {},
 }
 
 struct ChannelInfo {
@@ -78,8 +94,7 @@ impl IbcCmd {
                         anyhow::bail!("Client id not found: {}", client_id);
                     }
                 };
-                let client_state_json = serde_json::to_string_pretty(&client_state)?;
-                println!("{}", client_state_json.to_colored_json_auto()?);
+                app.print(&Json(client_state))?;
             }
             IbcCmd::Clients {} => {
                 let mut ibc_client = ClientQueryClient::new(app.pd_channel().await?);
@@ -97,8 +112,7 @@ impl IbcCmd {
                     .map(TendermintClientState::try_from)
                     .collect::<Result<Vec<_>, _>>()?;
 
-                let clients_json = serde_json::to_string_pretty(&client_states)?;
-                println!("{}", clients_json.to_colored_json_auto()?);
+                app.print(&Json(client_states))?;
             }
             IbcCmd::Connection { connection_id } => {
                 let mut ibc_client = ConnectionQueryClient::new(app.pd_channel().await?);
@@ -110,8 +124,7 @@ impl IbcCmd {
                 if connection.is_none() {
                     anyhow::bail!("Could not find '{c}'");
                 }
-                let connection_json = serde_json::to_string_pretty(&connection)?;
-                println!("{}", connection_json.to_colored_json_auto()?);
+                app.print(&Json(connection))?;
             }
             IbcCmd::Connections {} => {
                 let mut ibc_client = ConnectionQueryClient::new(app.pd_channel().await?);
@@ -120,10 +133,10 @@ impl IbcCmd {
                     pagination: None,
                 };
                 let connections = ibc_client.connections(req).await?.into_inner().connections;
-                let connections_json = serde_json::to_string_pretty(&connections)?;
-                println!("{}", connections_json.to_colored_json_auto()?);
+                app.print(&Json(connections))?;
             }
             IbcCmd::Channel { port, channel_id } => {
+                let registry = app.registry().await?;
                 let mut channel_client = ChannelQueryClient::new(app.pd_channel().await?);
                 let mut connection_client = ConnectionQueryClient::new(app.pd_channel().await?);
                 let mut client_client = ClientQueryClient::new(app.pd_channel().await?);
@@ -131,7 +144,7 @@ impl IbcCmd {
                 let channel = channel_client
                     .channel(QueryChannelRequest {
                         port_id: port.to_string(),
-                        channel_id: format!("channel-{channel_id}"),
+                        channel_id: channel_id.to_string(),
                     })
                     .await?
                     .into_inner()
@@ -169,17 +182,7 @@ impl IbcCmd {
                 let tendermint_consensus_state =
                     TendermintConsensusState::try_from(channel_consensus_state)?;
 
-                let mut table = Table::new();
-                table.set_header(vec![
-                    "Channel ID",
-                    "Port",
-                    "Counterparty",
-                    "Counterparty Channel ID",
-                    "State",
-                    "Client ID",
-                    "Client Height",
-                ]);
-                let mut state_str = State::from_i32(channel.state).unwrap().to_string();
+                let mut state = State::from_i32(channel.state).unwrap().to_string();
 
                 let current_time: time::OffsetDateTime = SystemTime::now().into();
                 let current_time_tm: tendermint::Time = current_time.try_into()?;
@@ -187,25 +190,31 @@ impl IbcCmd {
                 let time_elapsed =
                     current_time_tm.duration_since(tendermint_consensus_state.timestamp)?;
                 if client_state.expired(time_elapsed) {
-                    state_str = "CLIENT EXPIRED".to_string();
+                    state = "CLIENT EXPIRED".to_string();
                 }
-                table.add_row(vec![
-                    channel_id.to_string(),
-                    port.to_string(),
-                    client_state.chain_id.to_string(),
-                    channel
+
+                let counterparty_name = counterparty_name(
+                    registry.as_ref(),
+                    &format!("channel-{channel_id}"),
+                    client_state.chain_id.as_str(),
+                );
+                app.print(&Channels(vec![ChannelSummary {
+                    channel_id: channel_id.to_string(),
+                    port: port.to_string(),
+                    counterparty_chain_id: client_state.chain_id.to_string(),
+                    counterparty_name,
+                    counterparty_channel_id: channel
                         .counterparty
                         .ok_or_else(|| anyhow::anyhow!("counterparty not found"))?
                         .channel_id
                         .to_string(),
-                    state_str,
-                    connection.client_id.to_string(),
-                    client_state.latest_height.to_string(),
-                ]);
-
-                println!("{table}")
+                    state,
+                    client_id: connection.client_id.to_string(),
+                    client_height: client_state.latest_height.to_string(),
+                }]))?;
             }
             IbcCmd::Channels {} => {
+                let registry = app.registry().await?;
                 let mut channel_client = ChannelQueryClient::new(app.pd_channel().await?);
                 let mut connection_client = ConnectionQueryClient::new(app.pd_channel().await?);
                 let mut client_client = ClientQueryClient::new(app.pd_channel().await?);
@@ -258,46 +267,164 @@ impl IbcCmd {
                     });
                 }
 
-                let mut table = Table::new();
-                table.set_header(vec![
-                    "Channel ID",
-                    "Port",
-                    "Counterparty",
-                    "Counterparty Channel ID",
-                    "State",
-                    "Client ID",
-                    "Client Height",
-                ]);
-
+                let mut summaries = vec![];
                 for info in channel_infos {
-                    let mut state_str = State::from_i32(info.channel.state).unwrap().to_string();
+                    let mut state = State::from_i32(info.channel.state).unwrap().to_string();
                     let current_time: time::OffsetDateTime = SystemTime::now().into();
                     let current_time_tm: tendermint::Time = current_time.try_into()?;
 
                     let time_elapsed =
                         current_time_tm.duration_since(info.consensus_state.timestamp)?;
                     if info.client.expired(time_elapsed) {
-                        state_str = "CLIENT EXPIRED".to_string();
+                        state = "CLIENT EXPIRED".to_string();
                     }
-                    table.add_row(vec![
-                        info.channel.channel_id.to_string(),
-                        info.channel.port_id,
-                        info.client.chain_id.to_string(),
-                        info.channel
+                    let counterparty_name = counterparty_name(
+                        registry.as_ref(),
+                        &info.channel.channel_id,
+                        info.client.chain_id.as_str(),
+                    );
+                    summaries.push(ChannelSummary {
+                        channel_id: info.channel.channel_id.to_string(),
+                        port: info.channel.port_id,
+                        counterparty_chain_id: info.client.chain_id.to_string(),
+                        counterparty_name,
+                        counterparty_channel_id: info
+                            .channel
                             .counterparty
                             .ok_or_else(|| anyhow::anyhow!("counterparty not found"))?
                             .channel_id
                             .to_string(),
-                        state_str,
-                        info.connection.client_id.to_string(),
-                        info.client.latest_height.to_string(),
-                    ]);
+                        state,
+                        client_id: info.connection.client_id.to_string(),
+                        client_height: info.client.latest_height.to_string(),
+                    });
                 }
 
-                println!("{table}")
+                app.print(&Channels(summaries))?;
             }
+            IbcCmd::Flows { denom } => {
+                let unit = asset::REGISTRY.parse_unit(denom);
+                let mut client = ShieldedPoolQueryServiceClient::new(app.pd_channel().await?);
+                let flows = client
+                    .ics20_flows(Ics20FlowsRequest {
+                        asset_id: Some(unit.id().into()),
+                    })
+                    .await?
+                    .into_inner();
+                let inflow: Amount = flows
+                    .inflow
+                    .ok_or_else(|| anyhow::anyhow!("missing inflow"))?
+                    .try_into()?;
+                let outflow: Amount = flows
+                    .outflow
+                    .ok_or_else(|| anyhow::anyhow!("missing outflow"))?
+                    .try_into()?;
+                app.print(&Ics20Flows {
+                    unit,
+                    inflow,
+                    outflow,
+                })?;
+            }
+            IbcCmd::Registry {} => match app.registry().await? {
+                Some(registry) => app.print(&registry)?,
+                None => anyhow::bail!("no chain registry is configured"),
+            },
         }
 
         Ok(())
     }
 }
+
+/// The registry's name for the chain at the end of a channel, if the registry agrees with the
+/// channel's client about which chain that is.
+fn counterparty_name(
+    registry: Option<&Registry>,
+    channel_id: &str,
+    chain_id: &str,
+) -> Option<String> {
+    registry?
+        .chain_by_channel(channel_id)
+        .filter(|chain| chain.chain_id == chain_id)
+        .map(|chain| chain.display_name.clone())
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct Channels(Vec<ChannelSummary>);
+
+#[derive(Serialize)]
+pub struct ChannelSummary {
+    channel_id: String,
+    port: String,
+    counterparty_chain_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counterparty_name: Option<String>,
+    counterparty_channel_id: String,
+    state: String,
+    client_id: String,
+    client_height: String,
+}
+
+impl Output for Channels {
+    fn render(&self) -> Result<String> {
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Channel ID",
+            "Port",
+            "Counterparty",
+            "Counterparty Channel ID",
+            "State",
+            "Client ID",
+            "Client Height",
+        ]);
+        for channel in &self.0 {
+            table.add_row(vec![
+                channel.channel_id.clone(),
+                channel.port.clone(),
+                match &channel.counterparty_name {
+                    Some(name) => format!("{name} ({})", channel.counterparty_chain_id),
+                    None => channel.counterparty_chain_id.clone(),
+                },
+                channel.counterparty_channel_id.clone(),
+                channel.state.clone(),
+                channel.client_id.clone(),
+                channel.client_height.clone(),
+            ]);
+        }
+        Ok(table.to_string())
+    }
+}
+
+/// The total amounts of an asset transferred into and out of Penumbra over IBC, in base units.
+#[serde_as]
+#[derive(Serialize)]
+pub struct Ics20Flows {
+    #[serde(rename = "denom")]
+    #[serde_as(as = "DisplayFromStr")]
+    unit: asset::Unit,
+    #[serde_as(as = "DisplayFromStr")]
+    inflow: Amount,
+    #[serde_as(as = "DisplayFromStr")]
+    outflow: Amount,
+}
+
+impl Output for Ics20Flows {
+    fn render(&self) -> Result<String> {
+        let unit = &self.unit;
+        let (inflow, outflow) = (self.inflow, self.outflow);
+        let net = if inflow >= outflow {
+            format!("{}{}", unit.format_value(inflow - outflow), unit)
+        } else {
+            format!("-{}{}", unit.format_value(outflow - inflow), unit)
+        };
+
+        let mut table = Table::new();
+        table.set_header(vec!["Inflow", "Outflow", "Net Bridged In"]);
+        table.add_row(vec![
+            format!("{}{}", unit.format_value(inflow), unit),
+            format!("{}{}", unit.format_value(outflow), unit),
+            net,
+        ]);
+        Ok(table.to_string())
+    }
+}
@@ -1,9 +1,10 @@
 use anyhow::Result;
-use colored_json::prelude::*;
 use penumbra_proto::DomainType;
 use penumbra_sct::{CommitmentSource, NullificationInfo, Nullifier};
 use penumbra_tct::StateCommitment;
 
+use crate::{output::Json, App};
+
 #[derive(Debug, clap::Subcommand)]
 pub enum ShieldedPool {
     /// Queries the state commitment tree anchor for a given height.
@@ -42,25 +43,23 @@ impl ShieldedPool {
         }
     }
 
-    pub fn display_value(&self, bytes: &[u8]) -> Result<()> {
-        let json = match self {
+    pub fn display_value(&self, app: &App, bytes: &[u8]) -> Result<()> {
+        match self {
             ShieldedPool::Anchor { .. } => {
                 let anchor = penumbra_tct::Root::decode(bytes)?;
-                serde_json::to_string_pretty(&anchor)?
+                app.print(&Json(anchor))
             }
             ShieldedPool::CompactBlock { .. } => {
                 unreachable!("should be handled at outer level via rpc");
             }
             ShieldedPool::Commitment { .. } => {
                 let commitment_source = CommitmentSource::decode(bytes)?;
-                serde_json::to_string_pretty(&commitment_source)?
+                app.print(&Json(commitment_source))
             }
             ShieldedPool::Nullifier { .. } => {
                 let note_source = NullificationInfo::decode(bytes)?;
-                serde_json::to_string_pretty(&note_source)?
+                app.print(&Json(note_source))
             }
-        };
-        println!("{}", json.to_colored_json_auto()?);
-        Ok(())
+        }
     }
 }
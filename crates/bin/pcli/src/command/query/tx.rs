@@ -1,18 +1,30 @@
 use anyhow::Result;
-use colored_json::prelude::*;
-use penumbra_proto::{util::tendermint_proxy::v1::GetTxRequest, DomainType};
+use penumbra_proto::{
+    util::tendermint_proxy::v1::{GetTxInclusionProofRequest, GetTxRequest},
+    DomainType,
+};
 use penumbra_transaction::Transaction;
+use penumbra_view::verify_tx_inclusion;
+use serde_json::json;
 
-use crate::App;
-
-use super::OutputFormat;
+use crate::{
+    output::{Json, Text},
+    App,
+};
 
 /// Queries the chain for a transaction by hash.
 #[derive(Debug, clap::Args)]
 pub struct Tx {
-    /// The format to output the transaction in.
-    #[clap(short, long, value_enum, default_value_t)]
-    output: OutputFormat,
+    /// Output the transaction's encoding in base64, instead of the transaction as JSON.
+    #[clap(long, conflicts_with = "prove")]
+    base64: bool,
+    /// Verify a proof that the transaction was included in a block, and output the block and the
+    /// events the transaction emitted, instead of the transaction.
+    ///
+    /// The proof is checked against the block's header, but whether the header is canonical is
+    /// taken on trust from the node.
+    #[clap(long)]
+    prove: bool,
     /// The hex-formatted transaction hash to query.
     hash: String,
 }
@@ -21,6 +33,40 @@ impl Tx {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         let mut client = app.tendermint_proxy_client().await?;
 
+        if self.prove {
+            let rsp = client
+                .get_tx_inclusion_proof(GetTxInclusionProofRequest {
+                    hash: hex::decode(self.hash.clone())?,
+                })
+                .await?
+                .into_inner();
+            let inclusion = verify_tx_inclusion(&rsp)?;
+            let events: Vec<_> = rsp
+                .events
+                .iter()
+                .map(|event| {
+                    let attributes: serde_json::Map<_, _> = event
+                        .attributes
+                        .iter()
+                        .map(|tag| {
+                            (
+                                String::from_utf8_lossy(&tag.key).into_owned(),
+                                String::from_utf8_lossy(&tag.value).into_owned().into(),
+                            )
+                        })
+                        .collect();
+                    json!({ "type": event.r#type, "attributes": attributes })
+                })
+                .collect();
+            return app.print(&Json(json!({
+                "transaction_id": inclusion.transaction_id.to_string(),
+                "height": inclusion.height,
+                "index": inclusion.index,
+                "block_hash": inclusion.signed_header.header.hash().to_string(),
+                "events": events,
+            })));
+        }
+
         let rsp = client
             .get_tx(GetTxRequest {
                 hash: hex::decode(self.hash.clone())?,
@@ -29,19 +75,13 @@ impl Tx {
             .await?;
 
         let rsp = rsp.into_inner();
-        let tx = Transaction::decode(rsp.tx.as_slice())?;
 
-        match self.output {
-            OutputFormat::Json => {
-                let tx_json = serde_json::to_string_pretty(&tx)?;
-                println!("{}", tx_json.to_colored_json_auto()?);
-            }
-            OutputFormat::Base64 => {
-                use base64::{display::Base64Display, engine::general_purpose::STANDARD};
-                println!("{}", Base64Display::new(&rsp.tx, &STANDARD));
-            }
+        if self.base64 {
+            use base64::{display::Base64Display, engine::general_purpose::STANDARD};
+            app.print(&Text(Base64Display::new(&rsp.tx, &STANDARD).to_string()))
+        } else {
+            let tx = Transaction::decode(rsp.tx.as_slice())?;
+            app.print(&Json(tx))
         }
-
-        Ok(())
     }
 }
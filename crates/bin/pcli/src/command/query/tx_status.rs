@@ -0,0 +1,114 @@
+use anyhow::Result;
+use penumbra_app::rejection::{RejectionReason, TransactionRejection};
+use penumbra_asset::asset;
+use penumbra_proto::{
+    core::app::v1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient,
+        TransactionRejectionRequest,
+    },
+    util::tendermint_proxy::v1::GetTxRequest,
+};
+use penumbra_transaction::txhash::TransactionId;
+use serde::Serialize;
+
+use crate::{output::Output, App};
+
+/// Queries whether a transaction was included in a block, or why it was rejected.
+#[derive(Debug, clap::Args)]
+pub struct TxStatus {
+    /// The hex-formatted transaction hash to query.
+    hash: String,
+}
+
+impl TxStatus {
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let id = self.hash.parse::<TransactionId>()?;
+
+        let mut client = app.tendermint_proxy_client().await?;
+        // The proxy fails the request if the transaction isn't in a block.
+        if let Ok(rsp) = client
+            .get_tx(GetTxRequest {
+                hash: id.0.to_vec(),
+                prove: false,
+            })
+            .await
+        {
+            return app.print(&Status::Included {
+                height: rsp.into_inner().height,
+            });
+        }
+
+        let mut client = AppQueryServiceClient::new(app.pd_channel().await?);
+        let rejection = client
+            .transaction_rejection(TransactionRejectionRequest {
+                tx_id: Some(id.into()),
+            })
+            .await?
+            .into_inner()
+            .rejection
+            .map(TryInto::try_into)
+            .transpose()?;
+
+        match rejection {
+            Some(rejection) => app.print(&Status::Rejected(rejection)),
+            None => app.print(&Status::Unknown),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Status {
+    Included { height: u64 },
+    Rejected(TransactionRejection),
+    Unknown,
+}
+
+const UNKNOWN: &str = "Not found. The transaction may still be pending, or it may have been sent \
+                       to a different node than the one being queried.";
+
+impl Output for Status {
+    fn render(&self) -> Result<String> {
+        let rejection = match self {
+            Status::Included { height } => return Ok(format!("Included in block {height}.")),
+            Status::Unknown => return Ok(UNKNOWN.to_string()),
+            Status::Rejected(rejection) => rejection,
+        };
+
+        let assets = asset::Cache::with_known_assets();
+        let mut lines = vec![format!(
+            "Rejected at height {}: {}",
+            rejection.height, rejection.error
+        )];
+        for reason in &rejection.reasons {
+            lines.push(match reason {
+                RejectionReason::SpentNullifier {
+                    nullifier,
+                    spend_height,
+                } => format!(
+                    "- nullifier {nullifier} was already spent at height {spend_height}, \
+                     so the note was spent by another transaction"
+                ),
+                RejectionReason::InvalidAnchor {
+                    anchor,
+                    recent_anchors,
+                } => format!(
+                    "- anchor {anchor} is not a known state commitment tree root, \
+                     sync and rebuild the transaction; recent anchors: {}",
+                    recent_anchors
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                RejectionReason::InsufficientFee { fee, minimum_fee } => format!(
+                    "- the fee of {} is less than the minimum fee of {} at current gas prices",
+                    fee.0.format(&assets),
+                    minimum_fee.0.format(&assets)
+                ),
+            });
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
@@ -2,18 +2,23 @@ use std::{fs::File, io::Write};
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use penumbra_num::Amount;
 use penumbra_proto::core::component::stake::v1::{
     query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
+    ValidatorRateHistoryRequest,
 };
 use penumbra_stake::{
+    rate::RateData,
     validator::{self, ValidatorToml},
     IdentityKey,
 };
+use serde::Serialize;
 
-use crate::App;
+use crate::{
+    output::{Output, Toml},
+    render, App,
+};
 
 // TODO: replace this with something more standard for the `query` subcommand
 #[derive(Debug, clap::Subcommand)]
@@ -35,6 +40,18 @@ pub enum ValidatorCmd {
         /// The identity key of the validator to fetch.
         identity_key: String,
     },
+    /// Show the validator's delegation token exchange rate in each epoch, and the yield a
+    /// delegation earned over that window.
+    RateHistory {
+        /// The identity key of the validator to fetch the rates of.
+        identity_key: String,
+        /// The first epoch to show the rate for.
+        #[clap(long, default_value = "0")]
+        start_epoch: u64,
+        /// The epoch to stop before [default: the current epoch, inclusive].
+        #[clap(long)]
+        end_epoch: Option<u64>,
+    },
 }
 
 impl ValidatorCmd {
@@ -75,84 +92,10 @@ impl ValidatorCmd {
                     bv.cmp(&av)
                 });
 
-                let total_voting_power = validators
-                    .iter()
-                    .filter_map(|v| {
-                        if let validator::State::Active = v.status.state {
-                            Some(v.status.voting_power.value())
-                        } else {
-                            None
-                        }
-                    })
-                    .sum::<u128>() as f64;
-
-                let mut table = Table::new();
-                table.load_preset(presets::NOTHING);
-                table.set_header(vec![
-                    "Voting Power",
-                    "Share",
-                    "Commission",
-                    "State",
-                    "Bonding State",
-                    "Validator Info",
-                ]);
-
-                for v in validators {
-                    let voting_power = (v.status.voting_power.value() as f64) * 1e-6; // apply udelegation factor
-                    let active_voting_power = if matches!(v.status.state, validator::State::Active)
-                    {
-                        v.status.voting_power.value() as f64
-                    } else {
-                        0.0
-                    };
-                    let power_percent = 100.0 * active_voting_power / total_voting_power;
-                    let commission_bps = v
-                        .validator
-                        .funding_streams
-                        .as_ref()
-                        .iter()
-                        .map(|fs| fs.rate_bps())
-                        .sum::<u16>();
-
-                    table.add_row(vec![
-                        format!("{voting_power:.3}"),
-                        format!("{power_percent:.2}%"),
-                        format!("{commission_bps}bps"),
-                        v.status.state.to_string(),
-                        v.status.bonding_state.to_string(),
-                        // TODO: consider rewriting this with term colors
-                        // at some point, when we get around to it
-                        v.validator.identity_key.to_string().red().to_string(),
-                    ]);
-                    table.add_row(vec![
-                        "".into(),
-                        "".into(),
-                        "".into(),
-                        "".into(),
-                        "".into(),
-                        v.validator.name.to_string().bright_green().to_string(),
-                    ]);
-                    if *detailed {
-                        table.add_row(vec![
-                            "".into(),
-                            "".into(),
-                            "".into(),
-                            "".into(),
-                            "".into(),
-                            format!("  {}", v.validator.description),
-                        ]);
-                        table.add_row(vec![
-                            "".into(),
-                            "".into(),
-                            "".into(),
-                            "".into(),
-                            "".into(),
-                            format!("  {}", v.validator.website),
-                        ]);
-                    }
-                }
-
-                println!("{table}");
+                app.print(&ValidatorList {
+                    validators,
+                    detailed: *detailed,
+                })?;
             }
             ValidatorCmd::Definition { file, identity_key } => {
                 let identity_key = identity_key.parse::<IdentityKey>()?;
@@ -206,11 +149,172 @@ impl ValidatorCmd {
                         .write_all(toml::to_string_pretty(&validator)?.as_bytes())
                         .context("could not write file")?;
                 } else {
-                    println!("{}", toml::to_string_pretty(&validator)?);
+                    app.print(&Toml(validator))?;
                 }
             }
+            ValidatorCmd::RateHistory {
+                identity_key,
+                start_epoch,
+                end_epoch,
+            } => {
+                let identity_key = identity_key.parse::<IdentityKey>()?;
+                if end_epoch == &Some(0) {
+                    anyhow::bail!("the end epoch must be after the start epoch");
+                }
+
+                let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+                let rates = client
+                    .validator_rate_history(ValidatorRateHistoryRequest {
+                        identity_key: Some(identity_key.into()),
+                        start_epoch_index: *start_epoch,
+                        end_epoch_index: end_epoch.unwrap_or_default(),
+                    })
+                    .await?
+                    .into_inner()
+                    .try_collect::<Vec<_>>()
+                    .await?
+                    .into_iter()
+                    .map(|response| {
+                        response
+                            .data
+                            .context("missing rate data in response")?
+                            .try_into()
+                    })
+                    .collect::<Result<Vec<RateData>>>()?;
+
+                app.print(&RateHistory(rates))?;
+            }
         }
 
         Ok(())
     }
 }
+
+/// The validators, sorted by voting power with the active ones first.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct ValidatorList {
+    validators: Vec<validator::Info>,
+    #[serde(skip)]
+    detailed: bool,
+}
+
+impl Output for ValidatorList {
+    fn render(&self) -> Result<String> {
+        let total_voting_power = self
+            .validators
+            .iter()
+            .filter_map(|v| {
+                if let validator::State::Active = v.status.state {
+                    Some(v.status.voting_power.value())
+                } else {
+                    None
+                }
+            })
+            .sum::<u128>() as f64;
+
+        let mut table = render::table_with_header([
+            "Voting Power",
+            "Share",
+            "Commission",
+            "State",
+            "Bonding State",
+            "Validator Info",
+        ]);
+
+        for v in &self.validators {
+            let voting_power = (v.status.voting_power.value() as f64) * 1e-6; // apply udelegation factor
+            let active_voting_power = if matches!(v.status.state, validator::State::Active) {
+                v.status.voting_power.value() as f64
+            } else {
+                0.0
+            };
+            let power_percent = 100.0 * active_voting_power / total_voting_power;
+            let commission_bps = v
+                .validator
+                .funding_streams
+                .as_ref()
+                .iter()
+                .map(|fs| fs.rate_bps())
+                .sum::<u16>();
+
+            table.add_row(vec![
+                format!("{voting_power:.3}"),
+                format!("{power_percent:.2}%"),
+                format!("{commission_bps}bps"),
+                v.status.state.to_string(),
+                v.status.bonding_state.to_string(),
+                // TODO: consider rewriting this with term colors
+                // at some point, when we get around to it
+                v.validator.identity_key.to_string().red().to_string(),
+            ]);
+            table.add_row(vec![
+                "".into(),
+                "".into(),
+                "".into(),
+                "".into(),
+                "".into(),
+                v.validator.name.to_string().bright_green().to_string(),
+            ]);
+            if self.detailed {
+                table.add_row(vec![
+                    "".into(),
+                    "".into(),
+                    "".into(),
+                    "".into(),
+                    "".into(),
+                    format!("  {}", v.validator.description),
+                ]);
+                table.add_row(vec![
+                    "".into(),
+                    "".into(),
+                    "".into(),
+                    "".into(),
+                    "".into(),
+                    format!("  {}", v.validator.website),
+                ]);
+            }
+        }
+
+        Ok(table.to_string())
+    }
+}
+
+/// A validator's rate data in each epoch, in order of epoch index.
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct RateHistory(Vec<RateData>);
+
+impl Output for RateHistory {
+    fn render(&self) -> Result<String> {
+        let (Some(first), Some(last)) = (self.0.first(), self.0.last()) else {
+            return Ok("No rate data for this validator in the given epochs.".to_string());
+        };
+        // Rates are represented with an implicit scaling factor of 1_0000_0000.
+        let rate = |amount: Amount| amount.value() as f64 / 1_0000_0000.0;
+        let first_exchange_rate = rate(first.validator_exchange_rate);
+
+        let mut table =
+            render::table_with_header(["Epoch", "Exchange Rate", "Reward Rate", "Yield"]);
+        render::align_right(&mut table, [1, 2, 3]);
+        for rate_data in &self.0 {
+            let exchange_rate = rate(rate_data.validator_exchange_rate);
+            table.add_row(vec![
+                rate_data.epoch_index.to_string(),
+                format!("{exchange_rate:.8}"),
+                format!("{:.4}%", 100.0 * rate(rate_data.validator_reward_rate)),
+                format!(
+                    "{:.4}%",
+                    100.0 * (exchange_rate / first_exchange_rate - 1.0)
+                ),
+            ]);
+        }
+
+        Ok(format!(
+            "{table}\n\nA delegation held from epoch {} to epoch {} earned {:.4}%.",
+            first.epoch_index,
+            last.epoch_index,
+            100.0 * (rate(last.validator_exchange_rate) / first_exchange_rate - 1.0)
+        ))
+    }
+}
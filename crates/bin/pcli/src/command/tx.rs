@@ -3,34 +3,29 @@ use std::{
     fs::File,
     io::{Read, Write},
     str::FromStr,
-    time::{SystemTime, UNIX_EPOCH},
+    time::Duration,
 };
 
 use anyhow::{Context, Result};
 use ark_ff::UniformRand;
 use decaf377::{Fq, Fr};
-use ibc_proto::ibc::core::client::v1::{
-    query_client::QueryClient as IbcClientQueryClient, QueryClientStateRequest,
-};
-use ibc_proto::ibc::core::connection::v1::query_client::QueryClient as IbcConnectionQueryClient;
-use ibc_proto::ibc::core::{
-    channel::v1::{query_client::QueryClient as IbcChannelQueryClient, QueryChannelRequest},
-    connection::v1::QueryConnectionRequest,
-};
-use ibc_types::core::{
-    channel::{ChannelId, PortId},
-    client::Height as IbcHeight,
-};
-use ibc_types::lightclients::tendermint::client_state::ClientState as TendermintClientState;
-use rand_core::OsRng;
-use regex::Regex;
+use decaf377_rdsa::VerificationKey;
+use ibc_types::core::{channel::ChannelId, client::Height as IbcHeight};
+use rand_core::{OsRng, RngCore};
 
 use liquidity_position::PositionCmd;
-use penumbra_asset::{asset, asset::Metadata, Value, STAKING_TOKEN_ASSET_ID};
-use penumbra_dex::{lp::position, swap_claim::SwapClaimPlan};
-use penumbra_fee::Fee;
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_dex::{
+    lp::{position, DisplayPrice, Reserves},
+    swap::RoutingPreference,
+    swap_claim::SwapClaimPlan,
+};
+use penumbra_fee::{
+    grant::{sponsor_key, FeeGrant, FeeGrantId, FeeGrantReclaimPlan},
+    Fee,
+};
 use penumbra_governance::{proposal::ProposalToml, proposal_state::State as ProposalState, Vote};
-use penumbra_keys::keys::AddressIndex;
+use penumbra_keys::{keys::AddressIndex, Address};
 use penumbra_num::Amount;
 use penumbra_proto::{
     core::component::{
@@ -52,20 +47,30 @@ use penumbra_proto::{
         },
     },
     view::v1::GasPricesRequest,
+    DomainType,
 };
 use penumbra_shielded_pool::Ics20Withdrawal;
 use penumbra_stake::rate::RateData;
 use penumbra_stake::{DelegationToken, IdentityKey, Penalty, UnbondingToken, UndelegateClaimPlan};
-use penumbra_transaction::{gas::swap_claim_gas_cost, memo::MemoPlaintext};
+use penumbra_transaction::memo::MemoPlaintext;
 use penumbra_view::ViewClient;
 use penumbra_wallet::plan::{self, Planner};
 use proposal::ProposalCmd;
 
-use crate::App;
+use crate::{
+    error::{Classify, ErrorKind},
+    App,
+};
 
+mod bulk_position;
+mod exit_guard;
 mod liquidity_position;
+mod price_impact;
 mod proposal;
 mod replicate;
+mod rotate_key;
+mod unbonding;
+mod withdrawal;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum TxCmd {
@@ -78,11 +83,24 @@ pub enum TxCmd {
         /// The amounts to send, written as typed values 1.87penumbra, 12cubes, etc.
         values: Vec<String>,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0", display_order = 300)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 301)]
+        allow_cross_account: bool,
         /// Optional. Set the transaction's memo field to the provided text.
         #[clap(long)]
         memo: Option<String>,
+        /// Optional. Also encrypt the memo to the given address, e.g. an auditor's, by sending it
+        /// a zero-value output. Can be repeated to add several viewers.
+        #[clap(long = "memo-viewer")]
+        memo_viewers: Vec<String>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -96,8 +114,17 @@ pub enum TxCmd {
         /// The amount of stake to delegate.
         amount: String,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0", display_order = 300)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 301)]
+        allow_cross_account: bool,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -108,11 +135,24 @@ pub enum TxCmd {
         /// The amount of delegation tokens to undelegate.
         amount: String,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0", display_order = 300)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 301)]
+        allow_cross_account: bool,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
+        /// If undelegating near the end of the current epoch would finish unbonding an epoch
+        /// sooner, wait until then before submitting the undelegation.
+        #[clap(long, display_order = 400)]
+        wait_for_epoch_end: bool,
     },
     /// Claim any undelegations that have finished unbonding.
     #[clap(display_order = 200)]
@@ -137,19 +177,53 @@ pub enum TxCmd {
         #[clap(long, display_order = 100)]
         into: String,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0", display_order = 300)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 301)]
+        allow_cross_account: bool,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
+        /// Submit the swap bundled with its claim, leaving it to the view service to claim
+        /// the swap as soon as its batch has executed.
+        #[clap(long, display_order = 400)]
+        bundle_claim: bool,
+        /// Ask for confirmation before swapping if simulating the swap shows a price impact of
+        /// more than this many percent.
+        #[clap(long, default_value = "5", display_order = 500)]
+        max_price_impact: f64,
+        /// Swap without asking for confirmation, even if the price impact is too high.
+        #[clap(short, long, display_order = 501)]
+        yes: bool,
+        /// Prefer routing through fewer hops unless a longer route gives a price better by more
+        /// than this many basis points per extra hop, instead of the chain's default.
+        #[clap(long, display_order = 600)]
+        multi_hop_penalty_bps: Option<u32>,
     },
     /// Vote on a governance proposal in your role as a delegator (see also: `pcli validator vote`).
     #[clap(display_order = 400)]
     Vote {
         /// Only spend funds and vote with staked delegation tokens originally received by the given
         /// account.
-        #[clap(long, default_value = "0", global = true, display_order = 300)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            global = true,
+            display_order = 300
+        )]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, global = true, display_order = 301)]
+        allow_cross_account: bool,
         #[clap(subcommand)]
         vote: VoteCmd,
         /// The selected fee tier to multiply the fee amount by.
@@ -165,7 +239,65 @@ pub enum TxCmd {
         /// The amounts to send, written as typed values 1.87penumbra, 12cubes, etc.
         values: Vec<String>,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0", display_order = 300)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
+        source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 301)]
+        allow_cross_account: bool,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+    },
+    /// Grant another wallet an allowance for paying its transaction fees.
+    ///
+    /// The allowance is escrowed until it is used up, or until the sponsoring account
+    /// reclaims what remains of it with `pcli tx fee-grant-reclaim`.
+    #[clap(display_order = 650)]
+    FeeGrant {
+        /// The key to grant the allowance to, as shown by `pcli view fee-grantee`.
+        grantee: String,
+        /// The allowance, written as a typed value 1.87penumbra.
+        #[clap(long)]
+        allowance: String,
+        /// The last block height at which the allowance can be used.
+        #[clap(long)]
+        expiry_height: u64,
+        /// Only spend funds originally received by the given account.
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
+        source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 301)]
+        allow_cross_account: bool,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+    },
+    /// Revoke a fee grant made by this wallet, returning the unused allowance.
+    ///
+    /// This can be done at any time, before or after the grant expires.
+    #[clap(display_order = 660)]
+    FeeGrantReclaim {
+        /// The ID of the grant, as printed by `pcli tx fee-grant`.
+        grant_id: String,
+        /// The account the grant was made from.
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 300
+        )]
         source: u32,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
@@ -184,6 +316,36 @@ pub enum TxCmd {
     /// Currently, only zero-fee sweep transactions are implemented.
     #[clap(display_order = 990)]
     Sweep,
+    /// Move all of the wallet's funds to a new wallet, for instance because the wallet's spend
+    /// key may have been compromised.
+    ///
+    /// Each account's notes are moved to the same account of the new wallet, one asset at a time,
+    /// in transactions spaced out by random delays so that their timing doesn't link them. The
+    /// progress is saved in the home directory: run the command again, without `--to-wallet`, to
+    /// resume an interrupted rotation. Once no notes are left, the rotation is verified against
+    /// the wallet's transaction history.
+    #[clap(display_order = 995)]
+    RotateKey {
+        /// The home directory of the new wallet, as created by `pcli --home <DIR> init`.
+        #[clap(long)]
+        to_wallet: Option<camino::Utf8PathBuf>,
+        /// The most to pay in fees across all of the rotation's transactions, e.g. `1penumbra`.
+        #[clap(long)]
+        fee_budget: Option<String>,
+        /// The shortest wait between transactions, in seconds; a random wait up to as long again
+        /// is added.
+        #[clap(long, default_value_t = 300)]
+        delay: u64,
+        /// The most notes to spend in each transaction.
+        #[clap(long, default_value_t = 8)]
+        notes_per_transaction: usize,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+        /// Start moving the funds without asking for confirmation.
+        #[clap(short, long)]
+        yes: bool,
+    },
 
     /// Perform an ICS-20 withdrawal, moving funds from the Penumbra chain
     /// to a counterparty chain.
@@ -212,16 +374,57 @@ pub enum TxCmd {
         /// height, e.g. `5-1000000` means "chain revision 5, block height of 1000000".
         /// You must know the chain id of the counterparty chain beforehand, e.g. `osmosis-testnet-5`,
         /// to know the revision number.
+        ///
+        /// If unset, the timeout height is `--timeout-blocks` after the counterparty's latest
+        /// height known to Penumbra.
         #[clap(long, display_order = 100)]
         timeout_height: Option<IbcHeight>,
-        /// Timestamp, specified in epoch time, after which the withdrawal will be considered
-        /// invalid if not already relayed.
-        #[clap(long, default_value = "0", display_order = 150)]
-        timeout_timestamp: u64,
+        /// How many counterparty blocks the withdrawal may wait to be relayed, if
+        /// `--timeout-height` isn't set. The default is about two days.
+        #[clap(
+            long,
+            default_value_t = withdrawal::DEFAULT_TIMEOUT_BLOCKS,
+            conflicts_with = "timeout_height",
+            display_order = 110
+        )]
+        timeout_blocks: u64,
+        /// Timestamp, in nanoseconds since the Unix epoch, after which the withdrawal will be
+        /// considered invalid if not already relayed.
+        ///
+        /// If unset, the timeout is `--timeout-secs` from now.
+        #[clap(long, display_order = 150)]
+        timeout_timestamp: Option<u64>,
+        /// How many seconds the withdrawal may wait to be relayed, if `--timeout-timestamp` isn't
+        /// set. The default is two days.
+        #[clap(
+            long,
+            default_value_t = withdrawal::DEFAULT_TIMEOUT_SECS,
+            conflicts_with = "timeout_timestamp",
+            display_order = 151
+        )]
+        timeout_secs: u64,
+        /// Withdraw without checking that the channel is open and its counterparty's client is
+        /// live, e.g. when a relayer is about to update an expired client.
+        #[clap(long, display_order = 155)]
+        skip_channel_check: bool,
+
+        /// Withdraw even if the chain registry doesn't list the channel, or the destination
+        /// address doesn't belong to the chain the registry says the channel leads to.
+        #[clap(long, display_order = 160)]
+        skip_registry_check: bool,
 
         /// Only withdraw funds from the specified wallet id within Penumbra.
-        #[clap(long, default_value = "0", display_order = 200)]
+        #[clap(
+            long = "from-account",
+            alias = "source",
+            default_value = "0",
+            display_order = 200
+        )]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long, display_order = 201)]
+        allow_cross_account: bool,
 
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
@@ -307,6 +510,7 @@ impl TxCmd {
         match self {
             TxCmd::Send { .. } => false,
             TxCmd::Sweep { .. } => false,
+            TxCmd::RotateKey { .. } => false,
             TxCmd::Swap { .. } => false,
             TxCmd::Delegate { .. } => false,
             TxCmd::Undelegate { .. } => false,
@@ -314,6 +518,8 @@ impl TxCmd {
             TxCmd::Vote { .. } => false,
             TxCmd::Proposal(proposal_cmd) => proposal_cmd.offline(),
             TxCmd::CommunityPoolDeposit { .. } => false,
+            TxCmd::FeeGrant { .. } => false,
+            TxCmd::FeeGrantReclaim { .. } => false,
             TxCmd::Position(lp_cmd) => lp_cmd.offline(),
             TxCmd::Withdraw { .. } => false,
         }
@@ -336,17 +542,30 @@ impl TxCmd {
                 values,
                 to,
                 source: from,
+                allow_cross_account,
                 memo,
+                memo_viewers,
                 fee_tier,
             } => {
                 // Parse all of the values provided.
                 let values = values
                     .iter()
-                    .map(|v| v.parse())
-                    .collect::<Result<Vec<Value>, _>>()?;
+                    .map(|v| Value::parse(v))
+                    .collect::<Result<Vec<Value>>>()
+                    .classify(ErrorKind::User)?;
                 let to = to
                     .parse()
-                    .map_err(|_| anyhow::anyhow!("address is invalid"))?;
+                    .map_err(|_| anyhow::anyhow!("address is invalid"))
+                    .classify(ErrorKind::User)?;
+                let memo_viewers = memo_viewers
+                    .iter()
+                    .map(|viewer| {
+                        viewer
+                            .parse()
+                            .map_err(|_| anyhow::anyhow!("memo viewer address {viewer} is invalid"))
+                    })
+                    .collect::<Result<Vec<Address>>>()
+                    .classify(ErrorKind::User)?;
 
                 let return_address = app
                     .config
@@ -361,10 +580,14 @@ impl TxCmd {
 
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 for value in values.iter().cloned() {
                     planner.output(value, to);
                 }
+                for viewer in memo_viewers {
+                    planner.memo_viewer(viewer);
+                }
                 let plan = planner
                     .memo(memo_plaintext)?
                     .plan(
@@ -380,17 +603,20 @@ impl TxCmd {
             TxCmd::CommunityPoolDeposit {
                 values,
                 source,
+                allow_cross_account,
                 fee_tier,
             } => {
                 let values = values
                     .iter()
-                    .map(|v| v.parse())
-                    .collect::<Result<Vec<Value>, _>>()?;
+                    .map(|v| Value::parse(v))
+                    .collect::<Result<Vec<Value>>>()
+                    .classify(ErrorKind::User)?;
 
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 for value in values {
                     planner.community_pool_deposit(value);
                 }
@@ -404,6 +630,92 @@ impl TxCmd {
                     .await?;
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::FeeGrant {
+                grantee,
+                allowance,
+                expiry_height,
+                source,
+                allow_cross_account,
+                fee_tier,
+            } => {
+                let grantee_bytes: [u8; 32] = hex::decode(grantee)?
+                    .try_into()
+                    .map_err(|_| anyhow::anyhow!("grantee key must be 32 bytes"))?;
+                let grantee =
+                    VerificationKey::try_from(grantee_bytes).context("invalid grantee key")?;
+                let allowance = Value::parse(allowance).classify(ErrorKind::User)?;
+                if allowance.asset_id != *STAKING_TOKEN_ASSET_ID {
+                    anyhow::bail!("fee grant allowances must be in the staking token");
+                }
+
+                let mut nonce = [0u8; 32];
+                OsRng.fill_bytes(&mut nonce);
+                let grant = FeeGrant {
+                    grantee,
+                    allowance: Fee(allowance),
+                    expiry_height: *expiry_height,
+                    nonce,
+                    sponsor: sponsor_key(&app.config.full_viewing_key, *source),
+                };
+                let id = grant.id();
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
+                    .fee_grant(grant);
+                let plan = planner
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+                app.build_and_submit_transaction(plan).await?;
+                println!("Fee grant ID: {id}");
+            }
+            TxCmd::FeeGrantReclaim {
+                grant_id,
+                source,
+                fee_tier,
+            } => {
+                let id: FeeGrantId = grant_id.parse().classify(ErrorKind::User)?;
+
+                use penumbra_proto::cnidarium::v1::query_service_client::QueryServiceClient;
+                let mut client = QueryServiceClient::new(app.pd_channel().await?);
+                let value = client
+                    .key_value(penumbra_proto::cnidarium::v1::KeyValueRequest {
+                        key: penumbra_fee::state_key::grants::by_id(&id),
+                        ..Default::default()
+                    })
+                    .await?
+                    .into_inner()
+                    .value
+                    .ok_or_else(|| anyhow::anyhow!("fee grant {id} does not exist"))?;
+                let grant = FeeGrant::decode(value.value.as_slice())?;
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into())
+                    .fee_grant_reclaim(FeeGrantReclaimPlan::new(
+                        &app.config.full_viewing_key,
+                        *source,
+                        id,
+                        grant.allowance,
+                    ));
+                let plan = planner
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::Sweep => loop {
                 let plans = plan::sweep(
                     app.view
@@ -423,14 +735,63 @@ impl TxCmd {
                     break;
                 }
             },
+            TxCmd::RotateKey {
+                to_wallet,
+                fee_budget,
+                delay,
+                notes_per_transaction,
+                fee_tier,
+                yes,
+            } => {
+                let fee_budget = match fee_budget {
+                    Some(fee_budget) => {
+                        let fee_budget = Value::parse(fee_budget).classify(ErrorKind::User)?;
+                        if fee_budget.asset_id != *STAKING_TOKEN_ASSET_ID {
+                            anyhow::bail!("the fee budget must be in the staking token");
+                        }
+                        Some(fee_budget.amount)
+                    }
+                    None => None,
+                };
+                let options = rotate_key::RotateOptions {
+                    to_wallet: to_wallet.clone(),
+                    fee_budget,
+                    delay: Duration::from_secs(*delay),
+                    notes_per_transaction: *notes_per_transaction,
+                    fee_tier: (*fee_tier).into(),
+                    yes: *yes,
+                };
+                rotate_key::rotate(app, options).await?;
+            }
             TxCmd::Swap {
                 input,
                 into,
                 source,
+                allow_cross_account,
                 fee_tier,
+                bundle_claim,
+                max_price_impact,
+                yes,
+                multi_hop_penalty_bps,
             } => {
-                let input = input.parse::<Value>()?;
+                let input = Value::parse(input).classify(ErrorKind::User)?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
+                let routing_preference = multi_hop_penalty_bps
+                    .map(RoutingPreference::with_multi_hop_penalty_bps)
+                    .transpose()
+                    .classify(ErrorKind::User)?;
+
+                if !price_impact::confirm_price_impact(
+                    app,
+                    input,
+                    into.id(),
+                    *max_price_impact,
+                    *yes,
+                )
+                .await?
+                {
+                    return Ok(());
+                }
 
                 let fvk = app.config.full_viewing_key.clone();
 
@@ -442,21 +803,23 @@ impl TxCmd {
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices.clone())
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 // The swap claim requires a pre-paid fee, however gas costs might change in the meantime.
                 // This shouldn't be an issue, since the planner will account for the difference and add additional
                 // spends alongside the swap claim transaction as necessary.
                 //
-                // Regardless, we apply a gas adjustment factor of 2.0 up-front to reduce the likelihood of
-                // requiring an additional spend at the time of claim.
-                //
-                // Since the swap claim fee needs to be passed in to the planner to build the swap (it is
-                // part of the `SwapPlaintext`), we can't use the planner to estimate the fee and need to
-                // call the helper method directly.
-                let estimated_claim_fee = Fee::from_staking_token_amount(
-                    Amount::from(2u32) * gas_prices.fee(&swap_claim_gas_cost()),
-                );
-                planner.swap(input, into.id(), estimated_claim_fee, claim_address)?;
+                // Regardless, the estimate includes a margin to reduce the likelihood of requiring an
+                // additional spend at the time of claim, and whatever the claim doesn't use is returned
+                // as change.
+                let estimated_claim_fee = planner.swap_claim_fee();
+                planner.swap_with_routing_preference(
+                    input,
+                    into.id(),
+                    estimated_claim_fee,
+                    claim_address,
+                    routing_preference,
+                )?;
 
                 let plan = planner
                     .plan(app.view(), AddressIndex::new(*source))
@@ -471,6 +834,33 @@ impl TxCmd {
                     .swap_plaintext
                     .clone();
 
+                if *bundle_claim {
+                    // Submit the `Swap` transaction bundled with its claim, and wait for the
+                    // view service to submit the claim once the batch has executed.
+                    let transaction = app.build_transaction(plan).await?;
+                    app.submit_swap_bundle(transaction).await?;
+                    let swap_record = app
+                        .view()
+                        .swap_by_commitment(swap_plaintext.swap_commitment())
+                        .await?;
+                    println!("Swap submitted and batch confirmed! Waiting for the claim...");
+                    app.view().await_nullifier(swap_record.nullifier).await?;
+                    println!("Swap claimed.");
+                    if let Some((_, id)) = app
+                        .view()
+                        .transaction_by_nullifier(swap_record.nullifier)
+                        .await?
+                    {
+                        let claim = app.view().transaction_info_by_hash(id).await?;
+                        print_claim_fee_report(
+                            &swap_plaintext.claim_fee,
+                            &claim.transaction.transaction_parameters().fee,
+                            &app.view().assets().await?,
+                        );
+                    }
+                    return Ok(());
+                }
+
                 // Submit the `Swap` transaction, waiting for confirmation,
                 // at which point the swap will be available for claiming.
                 app.build_and_submit_transaction(plan).await?;
@@ -508,10 +898,12 @@ impl TxCmd {
                     .app_params()
                     .await?;
 
+                let reserved_fee = swap_plaintext.claim_fee.clone();
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 let plan = planner
                     .swap_claim(SwapClaimPlan {
                         swap_plaintext,
@@ -524,27 +916,31 @@ impl TxCmd {
                     .plan(app.view(), AddressIndex::new(*source))
                     .await
                     .context("can't plan swap claim")?;
+                let paid_fee = plan.transaction_parameters.fee.clone();
 
                 // Submit the `SwapClaim` transaction.
                 // BUG: this doesn't wait for confirmation, see
                 // https://github.com/penumbra-zone/penumbra/pull/2091/commits/128b24a6303c2f855a708e35f9342987f1dd34ec
                 app.build_and_submit_transaction(plan).await?;
+                print_claim_fee_report(&reserved_fee, &paid_fee, &asset_cache);
             }
             TxCmd::Delegate {
                 to,
                 amount,
                 source,
+                allow_cross_account,
                 fee_tier,
             } => {
                 let unbonded_amount = {
-                    let Value { amount, asset_id } = amount.parse::<Value>()?;
+                    let Value { amount, asset_id } =
+                        Value::parse(amount).classify(ErrorKind::User)?;
                     if asset_id != *STAKING_TOKEN_ASSET_ID {
                         anyhow::bail!("staking can only be done with the staking token");
                     }
                     amount
                 };
 
-                let to = to.parse::<IdentityKey>()?;
+                let to = to.parse::<IdentityKey>().classify(ErrorKind::User)?;
 
                 let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
                 let rate_data: RateData = client
@@ -556,7 +952,8 @@ impl TxCmd {
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 let plan = planner
                     .delegate(unbonded_amount, rate_data)
                     .plan(app.view(), AddressIndex::new(*source))
@@ -568,12 +965,14 @@ impl TxCmd {
             TxCmd::Undelegate {
                 amount,
                 source,
+                allow_cross_account,
                 fee_tier,
+                wait_for_epoch_end,
             } => {
                 let delegation_value @ Value {
                     amount: _,
                     asset_id,
-                } = amount.parse::<Value>()?;
+                } = Value::parse(amount).classify(ErrorKind::User)?;
 
                 // TODO: it's awkward that we can't just pull the denom out of the `amount` string we were already given
                 let delegation_token: DelegationToken = app
@@ -588,6 +987,24 @@ impl TxCmd {
 
                 let from = delegation_token.validator();
 
+                let mut schedule = unbonding::fetch_schedule(app, from).await?;
+                let margin = unbonding::EPOCH_END_MARGIN_BLOCKS;
+                if schedule.worth_waiting(margin) {
+                    if *wait_for_epoch_end {
+                        schedule =
+                            unbonding::wait_for_epoch_end(app, from, schedule, margin).await?;
+                    } else {
+                        println!(
+                            "Epoch {} ends in about {} blocks; undelegating then would unbond \
+                             an epoch sooner. Pass --wait-for-epoch-end to wait until then.",
+                            schedule.epoch.index,
+                            schedule.blocks_until_epoch_end(),
+                        );
+                    }
+                }
+                println!("{}", schedule.describe());
+
+                // Fetch the rate only now, in case we waited into a new epoch.
                 let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
                 let rate_data: RateData = client
                     .current_validator_rate(tonic::Request::new(from.into()))
@@ -598,7 +1015,8 @@ impl TxCmd {
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
 
                 let plan = planner
                     .undelegate(delegation_value.amount, rate_data)
@@ -706,6 +1124,7 @@ impl TxCmd {
             TxCmd::Proposal(ProposalCmd::Submit {
                 file,
                 source,
+                allow_cross_account,
                 deposit_amount,
                 fee_tier,
             }) => {
@@ -714,8 +1133,9 @@ impl TxCmd {
                 proposal_file
                     .read_to_string(&mut proposal_string)
                     .context("can't read proposal file")?;
-                let proposal_toml: ProposalToml =
-                    toml::from_str(&proposal_string).context("can't parse proposal file")?;
+                let proposal_toml: ProposalToml = toml::from_str(&proposal_string)
+                    .context("can't parse proposal file")
+                    .classify(ErrorKind::User)?;
                 let proposal = proposal_toml
                     .try_into()
                     .context("can't parse proposal file")?;
@@ -723,7 +1143,8 @@ impl TxCmd {
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 let plan = planner
                     .proposal_submit(proposal, Amount::from(*deposit_amount))
                     .plan(
@@ -739,12 +1160,14 @@ impl TxCmd {
                 proposal_id,
                 reason,
                 source,
+                allow_cross_account,
                 fee_tier,
             }) => {
                 let mut planner = Planner::new(OsRng);
                 planner
                     .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
                 let plan = planner
                     .proposal_withdraw(*proposal_id, reason.clone())
                     .plan(
@@ -784,6 +1207,7 @@ impl TxCmd {
             TxCmd::Proposal(ProposalCmd::DepositClaim {
                 proposal_id,
                 source,
+                allow_cross_account,
                 fee_tier,
             }) => {
                 let mut client = GovernanceQueryServiceClient::new(app.pd_channel().await?);
@@ -825,6 +1249,7 @@ impl TxCmd {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
                     .proposal_deposit_claim(*proposal_id, deposit_amount, outcome)
                     .plan(
                         app.view
@@ -839,6 +1264,7 @@ impl TxCmd {
             TxCmd::Vote {
                 vote,
                 source,
+                allow_cross_account,
                 fee_tier,
             } => {
                 let (proposal_id, vote): (u64, Vote) = (*vote).into();
@@ -882,6 +1308,7 @@ impl TxCmd {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
                     .delegator_vote(
                         proposal_id,
                         start_block_height,
@@ -910,6 +1337,7 @@ impl TxCmd {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier(order.fee_tier().into())
+                    .allow_cross_account(order.allow_cross_account())
                     .position_open(position)
                     .plan(
                         app.view
@@ -918,120 +1346,240 @@ impl TxCmd {
                         source,
                     )
                     .await?;
+                for id in plan.opened_position_ids() {
+                    println!("opening position {id}");
+                }
                 app.build_and_submit_transaction(plan).await?;
             }
-            TxCmd::Withdraw {
-                to,
-                value,
-                timeout_height,
-                timeout_timestamp,
-                channel,
+            TxCmd::Position(PositionCmd::Open {
+                price,
+                base_reserves,
+                quote_reserves,
+                fee_bps,
                 source,
+                allow_cross_account,
                 fee_tier,
-            } => {
-                let destination_chain_address = to;
-
-                let (ephemeral_return_address, _) = app
-                    .config
-                    .full_viewing_key
-                    .ephemeral_address(OsRng, AddressIndex::from(*source));
-
-                let timeout_height = match timeout_height {
-                    Some(h) => h.clone(),
-                    None => {
-                        // look up the height for the counterparty and add 2 days of block time
-                        // (assuming 10 seconds per block) to it
-
-                        // look up the client state from the channel by looking up channel id -> connection id -> client state
-                        let mut ibc_channel_client =
-                            IbcChannelQueryClient::new(app.pd_channel().await?);
-
-                        let req = QueryChannelRequest {
-                            port_id: PortId::transfer().to_string(),
-                            channel_id: format!("channel-{}", channel),
-                        };
-
-                        let channel = ibc_channel_client
-                            .channel(req)
-                            .await?
-                            .into_inner()
-                            .channel
-                            .ok_or_else(|| anyhow::anyhow!("channel not found"))?;
-
-                        let connection_id = channel.connection_hops[0].clone();
-
-                        let mut ibc_connection_client =
-                            IbcConnectionQueryClient::new(app.pd_channel().await?);
+            }) => {
+                let [price, units] = price.as_slice() else {
+                    anyhow::bail!("--price must be given, e.g. `--price 1.25 penumbra/gm`");
+                };
+                let price = DisplayPrice::parse(price, units).classify(ErrorKind::User)?;
+                let reserves_of = |reserves: &Option<String>,
+                                   unit: &asset::Unit|
+                 -> Result<Amount> {
+                    let Some(reserves) = reserves else {
+                        return Ok(Amount::zero());
+                    };
+                    let value = Value::parse(reserves).classify(ErrorKind::User)?;
+                    if value.asset_id != unit.id() {
+                        anyhow::bail!("reserves {reserves} must be of {unit}, to match the price");
+                    }
+                    Ok(value.amount)
+                };
+                let reserves = Reserves {
+                    r1: reserves_of(base_reserves, &price.base)?,
+                    r2: reserves_of(quote_reserves, &price.quote)?,
+                };
 
-                        let req = QueryConnectionRequest {
-                            connection_id: connection_id.clone(),
-                        };
-                        let connection = ibc_connection_client
-                            .connection(req)
-                            .await?
-                            .into_inner()
-                            .connection
-                            .ok_or_else(|| anyhow::anyhow!("connection not found"))?;
-
-                        let mut ibc_client_client =
-                            IbcClientQueryClient::new(app.pd_channel().await?);
-                        let req = QueryClientStateRequest {
-                            client_id: connection.client_id,
-                        };
-                        let client_state = ibc_client_client
-                            .client_state(req)
-                            .await?
-                            .into_inner()
-                            .client_state
-                            .ok_or_else(|| anyhow::anyhow!("client state not found"))?;
+                let plan = Planner::new(OsRng)
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
+                    .position_open_at_price(&price, *fee_bps, reserves)?
+                    .plan(app.view(), AddressIndex::new(*source))
+                    .await?;
 
-                        let tm_client_state = TendermintClientState::try_from(client_state)?;
+                let position = &plan
+                    .position_openings()
+                    .next()
+                    .expect("position opening must be present")
+                    .position;
+                let (p, q) = price.coefficients()?;
+                println!(
+                    "opening position {} at {} (trading function p = {p}, q = {q})",
+                    position.id(),
+                    DisplayPrice::format_position(position, &price.base, &price.quote)
+                        .expect("position trades between the price's assets"),
+                );
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::Position(PositionCmd::SingleSided {
+                input,
+                pair_with,
+                fee_bps,
+                source,
+                allow_cross_account,
+                fee_tier,
+            }) => {
+                let input = Value::parse(input).classify(ErrorKind::User)?;
+                let counter_asset = asset::REGISTRY.parse_unit(pair_with.as_str()).base().id();
+                let asset_cache = app.view().assets().await?;
 
-                        let last_update_height = tm_client_state.latest_height;
+                let (claim_address, _dtk_d) = app
+                    .config
+                    .full_viewing_key
+                    .incoming()
+                    .payment_address(AddressIndex::new(*source));
 
-                        // 10 seconds per block, 2 days
-                        let timeout_n_blocks = ((24 * 60 * 60) / 10) * 2;
+                // See the comment on `Swap` about estimating the claim fee up-front.
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices.clone())
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account);
+                let estimated_claim_fee = planner.swap_claim_fee();
+                let plan = planner
+                    .single_sided_swap(input, counter_asset, estimated_claim_fee, claim_address)?
+                    .plan(app.view(), AddressIndex::new(*source))
+                    .await
+                    .context("can't plan swap transaction")?;
+                let swap_plaintext = plan
+                    .swap_plans()
+                    .next()
+                    .expect("swap plan must be present")
+                    .swap_plaintext
+                    .clone();
+                let swapped = swap_plaintext.delta_1_i + swap_plaintext.delta_2_i;
 
-                        IbcHeight {
-                            revision_number: last_update_height.revision_number,
-                            revision_height: last_update_height.revision_height + timeout_n_blocks,
-                        }
+                println!(
+                    "[1/3] Swapping {} into {}...",
+                    Value {
+                        amount: swapped,
+                        asset_id: input.asset_id
                     }
+                    .format(&asset_cache),
+                    pair_with
+                );
+                app.build_and_submit_transaction(plan).await?;
+
+                let swap_record = app
+                    .view()
+                    .swap_by_commitment(swap_plaintext.swap_commitment())
+                    .await?;
+                let trading_pair = swap_record.output_data.trading_pair;
+                let pro_rata_outputs = swap_record
+                    .output_data
+                    .pro_rata_outputs((swap_plaintext.delta_1_i, swap_plaintext.delta_2_i));
+                let (refunded, received) = if trading_pair.asset_1() == input.asset_id {
+                    pro_rata_outputs
+                } else {
+                    (pro_rata_outputs.1, pro_rata_outputs.0)
+                };
+                // Any part of the swap that couldn't be filled is refunded, and goes into the
+                // position alongside the half of the input that was never swapped.
+                let kept = Value {
+                    amount: input.amount - swapped + refunded,
+                    asset_id: input.asset_id,
+                };
+                let received = Value {
+                    amount: received,
+                    asset_id: counter_asset,
                 };
 
-                // get the current time on the local machine
-                let current_time_u64_ms = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .expect("Time went backwards")
-                    .as_nanos() as u64;
+                println!(
+                    "[2/3] Swap executed, claiming {}...",
+                    received.format(&asset_cache)
+                );
+                let params = app.view().app_params().await?;
+                let reserved_fee = swap_plaintext.claim_fee.clone();
+                let plan = Planner::new(OsRng)
+                    .set_gas_prices(gas_prices.clone())
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
+                    .swap_claim(SwapClaimPlan {
+                        swap_plaintext,
+                        position: swap_record.position,
+                        output_data: swap_record.output_data,
+                        epoch_duration: params.sct_params.epoch_duration,
+                        proof_blinding_r: Fq::rand(&mut OsRng),
+                        proof_blinding_s: Fq::rand(&mut OsRng),
+                    })
+                    .plan(app.view(), AddressIndex::new(*source))
+                    .await
+                    .context("can't plan swap claim")?;
+                let paid_fee = plan.transaction_parameters.fee.clone();
+                app.build_and_submit_transaction(plan).await?;
+                // Wait for the view service to see the claim, so that its outputs can be spent.
+                app.view().await_nullifier(swap_record.nullifier).await?;
+                print_claim_fee_report(&reserved_fee, &paid_fee, &asset_cache);
 
-                let mut timeout_timestamp = *timeout_timestamp;
-                if timeout_timestamp == 0u64 {
-                    // add 2 days to current time
-                    timeout_timestamp = current_time_u64_ms + 1.728e14 as u64;
+                println!(
+                    "[3/3] Opening a position with {} and {}...",
+                    kept.format(&asset_cache),
+                    received.format(&asset_cache)
+                );
+                // The claimed funds are in the wallet by now, so if opening the position
+                // fails, they can still be paired by hand.
+                let plan = Planner::new(OsRng)
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
+                    .single_sided_position_open(kept, received, *fee_bps)
+                    .context("can't open position, try `pcli tx position order` instead")?
+                    .plan(app.view(), AddressIndex::new(*source))
+                    .await
+                    .context("can't plan position, try `pcli tx position order` instead")?;
+                for id in plan.opened_position_ids() {
+                    println!("opening position {id}");
                 }
+                app.build_and_submit_transaction(plan).await?;
+            }
+            TxCmd::Withdraw {
+                to,
+                value,
+                timeout_height,
+                timeout_blocks,
+                timeout_timestamp,
+                timeout_secs,
+                skip_channel_check,
+                channel,
+                skip_registry_check,
+                source,
+                allow_cross_account,
+                fee_tier,
+            } => {
+                let destination_chain_address = to;
 
-                fn parse_denom_and_amount(value_str: &str) -> anyhow::Result<(Amount, Metadata)> {
-                    let denom_re = Regex::new(r"^([0-9.]+)(.+)$").context("denom regex invalid")?;
-                    if let Some(captures) = denom_re.captures(value_str) {
-                        let numeric_str = captures.get(1).expect("matched regex").as_str();
-                        let denom_str = captures.get(2).expect("matched regex").as_str();
-
-                        let display_denom = asset::REGISTRY.parse_unit(denom_str);
-                        let amount = display_denom.parse_value(numeric_str)?;
-                        let denom = display_denom.base();
-
-                        Ok((amount, denom))
-                    } else {
-                        Err(anyhow::anyhow!("could not parse value"))
+                if !*skip_registry_check {
+                    if let Some(registry) = app.registry().await? {
+                        let chain = registry
+                            .check_withdrawal(&format!("channel-{channel}"), to)
+                            .context(
+                                "withdrawal destination doesn't match the chain registry, \
+                                 use --skip-registry-check to withdraw anyway",
+                            )?;
+                        println!(
+                            "withdrawing to {} ({}) over channel-{channel}",
+                            chain.display_name, chain.chain_id
+                        );
                     }
                 }
 
-                let (amount, denom) = parse_denom_and_amount(value)?;
+                // Check the channel before generating the return address, so that failed checks
+                // don't use up an ephemeral address.
+                let status = withdrawal::ChannelStatus::fetch(app, *channel).await?;
+                if !*skip_channel_check {
+                    status
+                        .check()
+                        .context("use --skip-channel-check to withdraw anyway")?;
+                }
+                let timeout_height =
+                    status.timeout_height(timeout_height.as_ref(), *timeout_blocks)?;
+                let timeout_timestamp =
+                    withdrawal::timeout_timestamp(*timeout_timestamp, *timeout_secs)?;
+
+                // Generate the return address through the view service, so that it's
+                // recorded for auditing.
+                let ephemeral_return_address = app
+                    .view()
+                    .ephemeral_address(AddressIndex::from(*source))
+                    .await?;
+
+                let (Value { amount, .. }, unit) = Value::parse_with_unit(value)?;
 
                 let withdrawal = Ics20Withdrawal {
                     destination_chain_address: destination_chain_address.to_string(),
-                    denom,
+                    denom: unit.base(),
                     amount,
                     timeout_height,
                     timeout_time: timeout_timestamp,
@@ -1043,6 +1591,7 @@ impl TxCmd {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
                     .ics20_withdrawal(withdrawal)
                     .plan(
                         app.view
@@ -1056,11 +1605,13 @@ impl TxCmd {
             TxCmd::Position(PositionCmd::Close {
                 position_id,
                 source,
+                allow_cross_account,
                 fee_tier,
             }) => {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
                     .position_close(*position_id)
                     .plan(
                         app.view
@@ -1073,112 +1624,74 @@ impl TxCmd {
             }
             TxCmd::Position(PositionCmd::CloseAll {
                 source,
+                allow_cross_account,
                 trading_pair,
                 fee_tier,
+                yes,
             }) => {
-                let view: &mut dyn ViewClient = app
-                    .view
-                    .as_mut()
-                    .context("view service must be initialized")?;
-
-                let owned_position_ids = view
-                    .owned_position_ids(Some(position::State::Opened), *trading_pair)
-                    .await?;
-
-                if owned_position_ids.is_empty() {
-                    println!("No open positions are available to close.");
-                    return Ok(());
-                }
-
-                let mut planner = Planner::new(OsRng);
-                planner
-                    .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
-
-                for position_id in owned_position_ids {
-                    // Close the position
-                    planner.position_close(position_id);
-                }
-
-                let final_plan = planner
-                    .plan(
-                        app.view
-                            .as_mut()
-                            .context("view service must be initialized")?,
-                        AddressIndex::new(*source),
-                    )
-                    .await?;
-                app.build_and_submit_transaction(final_plan).await?;
+                let options = bulk_position::BulkOptions {
+                    source: *source,
+                    allow_cross_account: *allow_cross_account,
+                    fee_tier: (*fee_tier).into(),
+                    yes: *yes,
+                };
+                bulk_position::close_or_withdraw_all(
+                    app,
+                    bulk_position::BulkAction::Close,
+                    *trading_pair,
+                    options,
+                )
+                .await?;
+            }
+            TxCmd::Position(PositionCmd::ExitGuard {
+                position_id,
+                below,
+                above,
+                interval,
+                source,
+                allow_cross_account,
+                fee_tier,
+            }) => {
+                let range = exit_guard::PriceRange::parse(below.as_deref(), above.as_deref())
+                    .classify(ErrorKind::User)?;
+                let options = exit_guard::CloseOptions {
+                    source: *source,
+                    allow_cross_account: *allow_cross_account,
+                    fee_tier: (*fee_tier).into(),
+                };
+                exit_guard::guard_position(
+                    app,
+                    *position_id,
+                    &range,
+                    Duration::from_secs(*interval),
+                    options,
+                )
+                .await?;
             }
             TxCmd::Position(PositionCmd::WithdrawAll {
                 source,
+                allow_cross_account,
                 trading_pair,
                 fee_tier,
+                yes,
             }) => {
-                let view: &mut dyn ViewClient = app
-                    .view
-                    .as_mut()
-                    .context("view service must be initialized")?;
-
-                let owned_position_ids = view
-                    .owned_position_ids(Some(position::State::Closed), *trading_pair)
-                    .await?;
-
-                if owned_position_ids.is_empty() {
-                    println!("No closed positions are available to withdraw.");
-                    return Ok(());
-                }
-
-                let mut planner = Planner::new(OsRng);
-                planner
-                    .set_gas_prices(gas_prices)
-                    .set_fee_tier((*fee_tier).into());
-
-                let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
-
-                for position_id in owned_position_ids {
-                    // Withdraw the position
-
-                    // Fetch the information regarding the position from the view service.
-                    let position = client
-                        .liquidity_position_by_id(LiquidityPositionByIdRequest {
-                            position_id: Some(position_id.into()),
-                        })
-                        .await?
-                        .into_inner();
-
-                    let reserves = position
-                        .data
-                        .clone()
-                        .expect("missing position metadata")
-                        .reserves
-                        .expect("missing position reserves");
-                    let pair = position
-                        .data
-                        .expect("missing position")
-                        .phi
-                        .expect("missing position trading function")
-                        .pair
-                        .expect("missing trading function pair");
-                    planner.position_withdraw(
-                        position_id,
-                        reserves.try_into().expect("invalid reserves"),
-                        pair.try_into().expect("invalid pair"),
-                    );
-                }
-
-                let final_plan = planner
-                    .plan(
-                        app.view
-                            .as_mut()
-                            .context("view service must be initialized")?,
-                        AddressIndex::new(*source),
-                    )
-                    .await?;
-                app.build_and_submit_transaction(final_plan).await?;
+                let options = bulk_position::BulkOptions {
+                    source: *source,
+                    allow_cross_account: *allow_cross_account,
+                    fee_tier: (*fee_tier).into(),
+                    yes: *yes,
+                };
+                bulk_position::close_or_withdraw_all(
+                    app,
+                    bulk_position::BulkAction::Withdraw,
+                    *trading_pair,
+                    options,
+                )
+                .await?;
             }
             TxCmd::Position(PositionCmd::Withdraw {
                 source,
+                allow_cross_account,
                 position_id,
                 fee_tier,
             }) => {
@@ -1209,6 +1722,7 @@ impl TxCmd {
                 let plan = Planner::new(OsRng)
                     .set_gas_prices(gas_prices)
                     .set_fee_tier((*fee_tier).into())
+                    .allow_cross_account(*allow_cross_account)
                     .position_withdraw(*position_id, reserves.try_into()?, pair.try_into()?)
                     .plan(
                         app.view
@@ -1227,3 +1741,32 @@ impl TxCmd {
         Ok(())
     }
 }
+
+/// Prints how much of a swap's pre-paid claim fee its claim paid, and how much of it was returned
+/// as change.
+fn print_claim_fee_report(reserved: &Fee, paid: &Fee, asset_cache: &asset::Cache) {
+    let difference = if reserved.asset_id() != paid.asset_id() {
+        String::new()
+    } else if let Some(excess) = reserved.amount().checked_sub(&paid.amount()) {
+        let excess = Value {
+            amount: excess,
+            asset_id: reserved.asset_id(),
+        };
+        format!(", {} returned as change", excess.format(asset_cache))
+    } else {
+        let shortfall = Value {
+            amount: paid.amount() - reserved.amount(),
+            asset_id: paid.asset_id(),
+        };
+        format!(
+            ", {} more paid from the wallet",
+            shortfall.format(asset_cache)
+        )
+    };
+    println!(
+        "Claim fee: {} reserved, {} paid{}",
+        reserved.0.format(asset_cache),
+        paid.0.format(asset_cache),
+        difference
+    );
+}
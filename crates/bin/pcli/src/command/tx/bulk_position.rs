@@ -0,0 +1,201 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use dialoguer::Confirm;
+use futures::TryStreamExt;
+use rand_core::OsRng;
+
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_dex::{
+    lp::position::{self, Position},
+    TradingPair,
+};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
+use penumbra_proto::core::component::dex::v1::{
+    query_service_client::QueryServiceClient as DexQueryServiceClient,
+    LiquidityPositionsByIdRequest,
+};
+use penumbra_transaction::TransactionPlan;
+use penumbra_view::ViewClient;
+use penumbra_wallet::plan::Planner;
+
+use crate::App;
+
+/// The most positions closed or withdrawn by one transaction, which keeps each transaction well
+/// within the size of a block.
+const POSITIONS_PER_TRANSACTION: usize = 16;
+
+/// What to do with each of the wallet's positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    /// Close every open position.
+    Close,
+    /// Withdraw the reserves of every closed position.
+    Withdraw,
+}
+
+impl BulkAction {
+    /// The state of the positions the action applies to.
+    fn state(&self) -> position::State {
+        match self {
+            BulkAction::Close => position::State::Opened,
+            BulkAction::Withdraw => position::State::Closed,
+        }
+    }
+
+    fn add_to(&self, planner: &mut Planner<OsRng>, position: &Position) {
+        match self {
+            BulkAction::Close => {
+                planner.position_close(position.id());
+            }
+            BulkAction::Withdraw => {
+                planner.position_withdraw(
+                    position.id(),
+                    position.reserves.clone(),
+                    position.phi.pair,
+                );
+            }
+        }
+    }
+}
+
+/// How to pay for the transactions, and whether to ask before broadcasting them.
+#[derive(Debug, Clone, Copy)]
+pub struct BulkOptions {
+    pub source: u32,
+    pub allow_cross_account: bool,
+    pub fee_tier: penumbra_fee::FeeTier,
+    pub yes: bool,
+}
+
+/// Closes or withdraws all of the wallet's positions, optionally only those on `trading_pair`.
+///
+/// The positions are split between as many transactions as needed. Before anything is broadcast,
+/// this shows how many transactions it takes, the reserves they hold and the fees they pay, and
+/// asks for confirmation unless `options.yes` is set.
+pub async fn close_or_withdraw_all(
+    app: &mut App,
+    action: BulkAction,
+    trading_pair: Option<TradingPair>,
+    options: BulkOptions,
+) -> Result<()> {
+    let ids = app
+        .view()
+        .owned_position_ids(Some(action.state()), trading_pair)
+        .await?;
+    if ids.is_empty() {
+        match action {
+            BulkAction::Close => println!("No open positions are available to close."),
+            BulkAction::Withdraw => println!("No closed positions are available to withdraw."),
+        }
+        return Ok(());
+    }
+
+    let mut dex = DexQueryServiceClient::new(app.pd_channel().await?);
+    let positions: Vec<Position> = dex
+        .liquidity_positions_by_id(LiquidityPositionsByIdRequest {
+            position_id: ids.iter().copied().map(Into::into).collect(),
+        })
+        .await?
+        .into_inner()
+        .map_err(anyhow::Error::from)
+        .and_then(|response| async move {
+            response
+                .data
+                .context("missing position in response")?
+                .try_into()
+        })
+        .try_collect()
+        .await
+        .context("can't fetch the wallet's positions")?;
+    let chunks = positions
+        .chunks(POSITIONS_PER_TRANSACTION)
+        .collect::<Vec<_>>();
+
+    // Plan every transaction up front, to find the fees. Each is planned again just before it's
+    // broadcast, since paying the fee for one can spend the notes another was planned with.
+    let mut fees = Amount::zero();
+    for chunk in &chunks {
+        fees += plan(app, action, chunk, options)
+            .await?
+            .transaction_parameters
+            .fee
+            .amount();
+    }
+
+    let asset_cache = app.view().assets().await?;
+    let (verb, reserves) = match action {
+        BulkAction::Close => ("close", "reserves to withdraw once closed"),
+        BulkAction::Withdraw => ("withdraw", "reserves recovered"),
+    };
+    println!(
+        "This will {verb} {} positions in {} transactions.",
+        positions.len(),
+        chunks.len()
+    );
+    println!("Total {reserves}:");
+    for value in total_reserves(&positions) {
+        println!("  {}", value.format(&asset_cache));
+    }
+    let fees = Value {
+        amount: fees,
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+    println!("Total fees: {}", fees.format(&asset_cache));
+    if !options.yes
+        && !Confirm::new()
+            .with_prompt("Broadcast the transactions?")
+            .interact()
+            .context("can't ask for confirmation, pass --yes to broadcast without asking")?
+    {
+        return Ok(());
+    }
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let plan = plan(app, action, chunk, options).await?;
+        app.build_and_submit_transaction(plan).await?;
+        println!(
+            "Submitted transaction {} of {} for {} positions.",
+            i + 1,
+            chunks.len(),
+            chunk.len()
+        );
+    }
+    Ok(())
+}
+
+async fn plan(
+    app: &mut App,
+    action: BulkAction,
+    positions: &[Position],
+    options: BulkOptions,
+) -> Result<TransactionPlan> {
+    let mut planner = Planner::new(OsRng);
+    planner
+        .set_gas_prices(app.view().gas_prices().await?)
+        .set_fee_tier(options.fee_tier)
+        .allow_cross_account(options.allow_cross_account);
+    for position in positions {
+        action.add_to(&mut planner, position);
+    }
+    planner
+        .plan(app.view(), AddressIndex::new(options.source))
+        .await
+        .context("can't plan transaction")
+}
+
+/// The total reserves of the positions, of each asset.
+fn total_reserves(positions: &[Position]) -> Vec<Value> {
+    let mut totals = BTreeMap::<asset::Id, Amount>::new();
+    for position in positions {
+        let pair = position.phi.pair;
+        *totals.entry(pair.asset_1()).or_default() += position.reserves.r1;
+        *totals.entry(pair.asset_2()).or_default() += position.reserves.r2;
+    }
+    totals
+        .into_iter()
+        .filter(|(_, amount)| *amount > Amount::zero())
+        .map(|(asset_id, amount)| Value { amount, asset_id })
+        .collect()
+}
@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use rand_core::OsRng;
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{
+    lp::position::{self, Position},
+    BatchSwapOutputData, TradingPair,
+};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_num::Amount;
+use penumbra_proto::{
+    core::component::dex::v1::{
+        query_service_client::QueryServiceClient as DexQueryServiceClient,
+        BatchSwapOutputDataRequest, LiquidityPositionByIdRequest,
+    },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+    },
+};
+use penumbra_view::ViewClient;
+use penumbra_wallet::plan::Planner;
+
+use crate::App;
+
+/// The range of prices a guarded position may keep trading in.
+///
+/// Prices are quoted in one asset of the position's pair, per unit of the other, e.g. a range
+/// quoted in `gm` on a `gm:penumbra` position is in gm per penumbra.
+#[derive(Debug, Clone)]
+pub struct PriceRange {
+    /// The asset the prices are quoted in.
+    pub quote: asset::Id,
+    /// Close the position if the price falls below this amount of the quote asset.
+    pub below: Option<Amount>,
+    /// Close the position if the price rises above this amount of the quote asset.
+    pub above: Option<Amount>,
+}
+
+impl PriceRange {
+    /// Parses the bounds of the range, which are typed values of the quote asset, e.g. `1.2gm`.
+    pub fn parse(below: Option<&str>, above: Option<&str>) -> Result<Self> {
+        let below = below.map(Value::parse).transpose()?;
+        let above = above.map(Value::parse).transpose()?;
+        let quote = match (below, above) {
+            (Some(below), Some(above)) if below.asset_id != above.asset_id => {
+                anyhow::bail!("both bounds must be quoted in the same asset")
+            }
+            (Some(bound), _) | (None, Some(bound)) => bound.asset_id,
+            (None, None) => anyhow::bail!("at least one of the bounds must be given"),
+        };
+        Ok(Self {
+            quote,
+            below: below.map(|below| below.amount),
+            above: above.map(|above| above.amount),
+        })
+    }
+
+    fn contains(&self, price: f64) -> bool {
+        self.below
+            .map_or(true, |below| price >= below.value() as f64)
+            && self
+                .above
+                .map_or(true, |above| price <= above.value() as f64)
+    }
+}
+
+/// Returns the prices the batch swaps in each direction executed at, quoted in base units of
+/// `quote` per base unit of the other asset of the pair.
+fn executed_prices(output: &BatchSwapOutputData, quote: asset::Id) -> Vec<f64> {
+    let mut prices = Vec::new();
+
+    // The prices of asset 1, in asset 2.
+    let filled_1 = output.delta_1 - output.unfilled_1;
+    if filled_1 > Amount::zero() && output.lambda_2 > Amount::zero() {
+        prices.push(output.lambda_2.value() as f64 / filled_1.value() as f64);
+    }
+    let filled_2 = output.delta_2 - output.unfilled_2;
+    if filled_2 > Amount::zero() && output.lambda_1 > Amount::zero() {
+        prices.push(filled_2.value() as f64 / output.lambda_1.value() as f64);
+    }
+
+    if quote == output.trading_pair.asset_1() {
+        prices.iter_mut().for_each(|price| *price = 1.0 / *price);
+    }
+    prices
+}
+
+/// How to pay for closing the position, once it leaves its price range.
+#[derive(Debug, Clone, Copy)]
+pub struct CloseOptions {
+    pub source: u32,
+    pub allow_cross_account: bool,
+    pub fee_tier: penumbra_fee::FeeTier,
+}
+
+/// Watches the batch swaps on the position's trading pair, polling every `interval`, and closes
+/// the position as soon as one executes at a price outside of `range`.
+///
+/// Returns once the position is closed, whether by this or otherwise.
+pub async fn guard_position(
+    app: &mut App,
+    position_id: position::Id,
+    range: &PriceRange,
+    interval: Duration,
+    options: CloseOptions,
+) -> Result<()> {
+    let mut dex = DexQueryServiceClient::new(app.pd_channel().await?);
+    let position = fetch_position(&mut dex, position_id).await?;
+    let trading_pair: TradingPair = position.phi.pair.into();
+    let base = if range.quote == trading_pair.asset_1() {
+        trading_pair.asset_2()
+    } else if range.quote == trading_pair.asset_2() {
+        trading_pair.asset_1()
+    } else {
+        anyhow::bail!("the price range must be quoted in one of the position's assets");
+    };
+
+    // The bounds are per unit of the base asset, rather than per base unit of it.
+    let asset_cache = app.view().assets().await?;
+    let unit_amount = |asset_id: &asset::Id| {
+        asset_cache
+            .get(asset_id)
+            .map_or(1, |metadata| metadata.default_unit().unit_amount().value()) as f64
+    };
+    let base_unit = unit_amount(&base);
+    let quote_unit = unit_amount(&range.quote);
+    let quote_denom = asset_cache.get(&range.quote).map_or_else(
+        || range.quote.to_string(),
+        |metadata| metadata.default_unit().to_string(),
+    );
+
+    let mut tendermint = TendermintProxyServiceClient::new(app.pd_channel().await?);
+    let mut next_height = latest_height(&mut tendermint).await? + 1;
+    println!("Guarding position {position_id} from height {next_height}");
+
+    loop {
+        let position = fetch_position(&mut dex, position_id).await?;
+        if position.state != position::State::Opened {
+            println!(
+                "Position {position_id} is {}, so no longer needs guarding",
+                position.state
+            );
+            return Ok(());
+        }
+
+        let current_height = latest_height(&mut tendermint).await?;
+        for height in next_height..=current_height {
+            let output = match dex
+                .batch_swap_output_data(BatchSwapOutputDataRequest {
+                    height,
+                    trading_pair: Some(trading_pair.into()),
+                })
+                .await
+            {
+                Ok(response) => response,
+                // There were no swaps on the pair in this block.
+                Err(status) if status.code() == tonic::Code::NotFound => continue,
+                Err(status) => return Err(status.into()),
+            };
+            let output: BatchSwapOutputData = output
+                .into_inner()
+                .try_into()
+                .context("cannot parse batch swap output data")?;
+
+            for price in executed_prices(&output, range.quote) {
+                let price = price * base_unit;
+                if range.contains(price) {
+                    continue;
+                }
+
+                println!(
+                    "[height {height}] batch swap executed at {:.6}{quote_denom}, outside of the \
+                    range; closing position {position_id}",
+                    price / quote_unit
+                );
+                let plan = Planner::new(OsRng)
+                    .set_gas_prices(app.view().gas_prices().await?)
+                    .set_fee_tier(options.fee_tier)
+                    .allow_cross_account(options.allow_cross_account)
+                    .position_close(position_id)
+                    .plan(app.view(), AddressIndex::new(options.source))
+                    .await
+                    .context("can't plan position close")?;
+                app.build_and_submit_transaction(plan).await?;
+                println!(
+                    "Closed position {position_id}; withdraw its reserves with `pcli tx position withdraw {position_id}`"
+                );
+                return Ok(());
+            }
+        }
+        next_height = next_height.max(current_height + 1);
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+async fn fetch_position(
+    dex: &mut DexQueryServiceClient<tonic::transport::Channel>,
+    position_id: position::Id,
+) -> Result<Position> {
+    dex.liquidity_position_by_id(LiquidityPositionByIdRequest {
+        position_id: Some(position_id.into()),
+    })
+    .await?
+    .into_inner()
+    .data
+    .ok_or_else(|| anyhow::anyhow!("position {position_id} not found"))?
+    .try_into()
+}
+
+async fn latest_height(
+    tendermint: &mut TendermintProxyServiceClient<tonic::transport::Channel>,
+) -> Result<u64> {
+    Ok(tendermint
+        .get_status(GetStatusRequest::default())
+        .await?
+        .into_inner()
+        .sync_info
+        .ok_or_else(|| anyhow::anyhow!("missing sync_info"))?
+        .latest_block_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn executed_prices_are_quoted() {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .unwrap()
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .unwrap()
+            .id();
+        let trading_pair = TradingPair::new(gm, gn);
+        // 100 of asset 1 were sold for 200 of asset 2, at a price of 2.
+        let output = BatchSwapOutputData {
+            delta_1: 110u64.into(),
+            delta_2: 0u64.into(),
+            lambda_1: 0u64.into(),
+            lambda_2: 200u64.into(),
+            unfilled_1: 10u64.into(),
+            unfilled_2: 0u64.into(),
+            height: 1,
+            trading_pair,
+            epoch_starting_height: 0,
+        };
+
+        assert_eq!(executed_prices(&output, trading_pair.asset_2()), vec![2.0]);
+        assert_eq!(executed_prices(&output, trading_pair.asset_1()), vec![0.5]);
+    }
+}
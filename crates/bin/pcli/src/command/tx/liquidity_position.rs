@@ -1,10 +1,10 @@
 use anyhow::Result;
 
-use penumbra_asset::asset;
+use penumbra_asset::{asset, Value};
 use penumbra_dex::{
     lp::{
         position::{self, Position},
-        BuyOrder, SellOrder,
+        BuyOrder, Reserves, SellOrder,
     },
     TradingPair,
 };
@@ -17,23 +17,68 @@ pub enum PositionCmd {
     /// Open a new liquidity position based on order details and credits an open position NFT.
     #[clap(display_order = 100, subcommand)]
     Order(OrderCmd),
+    /// Open a new liquidity position at a price given in display units, e.g. `--price 1.25
+    /// penumbra/gm` for a price of 1.25 gm per penumbra.
+    ///
+    /// The price is converted exactly into the position's trading function, and the price the
+    /// position trades at is converted back and shown before the position is opened.
+    #[clap(display_order = 150)]
+    Open {
+        /// The price of one unit of the base asset in the quote asset, followed by the units as
+        /// base/quote, e.g. `1.25 penumbra/gm`.
+        #[clap(long, number_of_values = 2, value_names = &["PRICE", "BASE/QUOTE"])]
+        price: Vec<String>,
+        /// The reserves of the base asset to provision the position with, e.g. `100penumbra`.
+        #[clap(long)]
+        base_reserves: Option<String>,
+        /// The reserves of the quote asset to provision the position with, e.g. `125gm`.
+        #[clap(long)]
+        quote_reserves: Option<String>,
+        /// The fee spread of the position, in basis points.
+        #[clap(long, default_value_t = 0u32)]
+        fee_bps: u32,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
+        source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Debits an all opened position NFTs associated with a specific source and credits closed position NFTs.
+    ///
+    /// The positions are split between as many transactions as needed, and the total reserves
+    /// and fees are shown for confirmation before any is broadcast.
     CloseAll {
         /// Only spend funds originally received by the given address index.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
-        /// Only close positions for the given trading pair.
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
         #[clap(long)]
+        allow_cross_account: bool,
+        /// Only close positions for the given trading pair, e.g. `gm:penumbra`.
+        #[clap(long, alias = "pair")]
         trading_pair: Option<TradingPair>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
+        /// Broadcast without asking for confirmation after previewing the transactions.
+        #[clap(short, long)]
+        yes: bool,
     },
     /// Debits an opened position NFT and credits a closed position NFT.
     Close {
         /// Only spend funds originally received by the given address index.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// The [`position::Id`] of the position to close.
         position_id: position::Id,
         /// The selected fee tier to multiply the fee amount by.
@@ -41,22 +86,36 @@ pub enum PositionCmd {
         fee_tier: FeeTier,
     },
     /// Debits all closed position NFTs associated with a specific account and credits withdrawn position NFTs and the final reserves.
+    ///
+    /// The positions are split between as many transactions as needed, and the total reserves
+    /// recovered and fees are shown for confirmation before any is broadcast.
     WithdrawAll {
         /// Only spend funds originally received by the given address index.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
-        /// Only withdraw positions for the given trading pair.
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
         #[clap(long)]
+        allow_cross_account: bool,
+        /// Only withdraw positions for the given trading pair, e.g. `gm:penumbra`.
+        #[clap(long, alias = "pair")]
         trading_pair: Option<TradingPair>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
+        /// Broadcast without asking for confirmation after previewing the transactions.
+        #[clap(short, long)]
+        yes: bool,
     },
     /// Debits a closed position NFT and credits a withdrawn position NFT and the final reserves.
     Withdraw {
         /// Only spend funds originally received by the given address index.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// The [`position::Id`] of the position to withdraw.
         position_id: position::Id,
         /// The selected fee tier to multiply the fee amount by.
@@ -64,6 +123,59 @@ pub enum PositionCmd {
         fee_tier: FeeTier,
     },
 
+    /// Watches an open position, and closes it as soon as a batch swap on its trading pair
+    /// executes at a price outside of the given range.
+    ///
+    /// Prices are typed values quoted in one asset of the pair, per unit of the other, e.g. on a
+    /// gm:penumbra position, `--below 1.2gm` closes the position once penumbra trades for less
+    /// than 1.2gm. This keeps running until the position is closed.
+    ExitGuard {
+        /// The [`position::Id`] of the position to guard.
+        position_id: position::Id,
+        /// Close the position if the price falls below this, e.g. `1.2gm`.
+        #[clap(long)]
+        below: Option<String>,
+        /// Close the position if the price rises above this, e.g. `1.5gm`.
+        #[clap(long)]
+        above: Option<String>,
+        /// How often to check for new batch swaps, in seconds.
+        #[clap(long, default_value = "5")]
+        interval: u64,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
+        source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+    },
+    /// Provide liquidity with a single asset, by swapping half of it into the counter-asset and
+    /// opening a position with both halves once the swap has been claimed.
+    ///
+    /// This submits three transactions in turn: the swap, its claim, and the position.
+    SingleSided {
+        /// The amount to provide, written as a typed value 100penumbra, 12cubes, etc.
+        input: String,
+        /// The denomination to pair the input with, e.g. `gm`.
+        #[clap(long)]
+        pair_with: String,
+        /// The fee spread of the position, in basis points.
+        #[clap(long, default_value_t = 0u32)]
+        fee_bps: u32,
+        /// Only spend funds originally received by the given address index.
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
+        source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, value_enum, default_value_t)]
+        fee_tier: FeeTier,
+    },
     /// Debits a withdrawn position NFT and credits a claimed position NFT and any liquidity incentives.
     #[clap(hide(true))] // remove when reward claims exist
     RewardClaim {},
@@ -76,10 +188,13 @@ impl PositionCmd {
     pub fn offline(&self) -> bool {
         match self {
             PositionCmd::Order(_) => false,
+            PositionCmd::Open { .. } => false,
             PositionCmd::Close { .. } => false,
             PositionCmd::CloseAll { .. } => false,
             PositionCmd::Withdraw { .. } => false,
             PositionCmd::WithdrawAll { .. } => false,
+            PositionCmd::ExitGuard { .. } => false,
+            PositionCmd::SingleSided { .. } => false,
             PositionCmd::RewardClaim { .. } => false,
             PositionCmd::Replicate(replicate) => replicate.offline(),
         }
@@ -96,11 +211,19 @@ pub enum OrderCmd {
         /// resulting position, though this is less useful for buy/sell orders than passive LPs.
         buy_order: String,
         /// Only spend funds originally received by the given address index.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// When set, tags the position as being a limit-sell order.
         #[clap(long)]
         limit_order: bool,
+        /// Closes the position at the end of any block in which its reserves of the given asset
+        /// are below the given amount, e.g. `10penumbra`.
+        #[clap(long, value_name = "VALUE")]
+        close_below: Option<Value>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -113,11 +236,19 @@ pub enum OrderCmd {
         /// resulting position, though this is less useful for buy/sell orders than passive LPs.
         sell_order: String,
         /// Only spend funds originally received by the given address index.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// When set, tags the position as being a limit-sell order.
         #[clap(long)]
         limit_order: bool,
+        /// Closes the position at the end of any block in which its reserves of the given asset
+        /// are below the given amount, e.g. `10penumbra`.
+        #[clap(long, value_name = "VALUE")]
+        close_below: Option<Value>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -132,6 +263,19 @@ impl OrderCmd {
         }
     }
 
+    pub fn allow_cross_account(&self) -> bool {
+        match self {
+            OrderCmd::Buy {
+                allow_cross_account,
+                ..
+            } => *allow_cross_account,
+            OrderCmd::Sell {
+                allow_cross_account,
+                ..
+            } => *allow_cross_account,
+        }
+    }
+
     pub fn fee_tier(&self) -> FeeTier {
         match self {
             OrderCmd::Buy { fee_tier, .. } => *fee_tier,
@@ -146,6 +290,13 @@ impl OrderCmd {
         }
     }
 
+    pub fn close_below(&self) -> Option<Value> {
+        match self {
+            OrderCmd::Buy { close_below, .. } => *close_below,
+            OrderCmd::Sell { close_below, .. } => *close_below,
+        }
+    }
+
     pub fn as_position<R: CryptoRngCore>(
         &self,
         // Preserved since we'll need it after denom metadata refactor
@@ -170,6 +321,24 @@ impl OrderCmd {
             position.close_on_fill = true;
         }
 
+        if let Some(threshold) = self.close_below() {
+            let pair = position.phi.pair;
+            let close_below = if threshold.asset_id == pair.asset_1() {
+                Reserves {
+                    r1: threshold.amount,
+                    r2: 0u64.into(),
+                }
+            } else if threshold.asset_id == pair.asset_2() {
+                Reserves {
+                    r1: 0u64.into(),
+                    r2: threshold.amount,
+                }
+            } else {
+                anyhow::bail!("the auto-close threshold must be in one of the order's assets");
+            };
+            position.close_below = Some(close_below);
+        }
+
         Ok(position)
     }
 }
@@ -0,0 +1,89 @@
+use anyhow::{Context, Result};
+use dialoguer::Confirm;
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::SwapExecution;
+use penumbra_proto::core::component::dex::v1::{
+    simulate_trade_request::{routing::Setting, Routing},
+    simulation_service_client::SimulationServiceClient,
+    SimulateTradeRequest,
+};
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+/// Simulates a swap against the current state of the DEX, and asks for confirmation before
+/// swapping if the price impact is more than `max_impact_percent`.
+///
+/// Returns whether the swap should go ahead. With `yes`, the warning is still printed, but the
+/// swap always goes ahead.
+pub async fn confirm_price_impact(
+    app: &mut App,
+    input: Value,
+    into: asset::Id,
+    max_impact_percent: f64,
+    yes: bool,
+) -> Result<bool> {
+    let mut client = SimulationServiceClient::new(app.pd_channel().await?);
+    let execution: SwapExecution = client
+        .simulate_trade(SimulateTradeRequest {
+            input: Some(input.into()),
+            output: Some(into.into()),
+            routing: Some(Routing {
+                setting: Some(Setting::Default(Default::default())),
+            }),
+        })
+        .await
+        .context("can't simulate swap")?
+        .into_inner()
+        .output
+        .context("proto response missing swap execution")?
+        .try_into()
+        .context("cannot parse simulation response")?;
+    let asset_cache = app.view().assets().await?;
+
+    // Prices are quoted as input per unit of output, so the price impact is how much worse the
+    // average price of the whole trade is than the price of its first, best-priced trace.
+    let (Some(spot_price), Some(price_impact)) = (execution.min_price(), execution.price_impact())
+    else {
+        println!("warning: the DEX has no liquidity to fill this swap, so it will be refunded.");
+        return Ok(yes || confirm("Swap anyway?")?);
+    };
+    let spot_price = f64::from(spot_price);
+    let impact_percent = f64::from(price_impact) * 100.0;
+    let spot_output = Value {
+        amount: (((input.amount.value() as f64) / spot_price) as u128).into(),
+        asset_id: into,
+    };
+
+    println!(
+        "expected output: {} (at spot price: {}, price impact: {:.2}%)",
+        execution.output.format(&asset_cache),
+        spot_output.format(&asset_cache),
+        impact_percent,
+    );
+    if execution.input.amount < input.amount {
+        println!(
+            "warning: only {} of the input can be filled, the rest will be refunded.",
+            execution.input.format(&asset_cache)
+        );
+    }
+
+    if impact_percent <= max_impact_percent && execution.input.amount == input.amount {
+        return Ok(true);
+    }
+    if impact_percent > max_impact_percent {
+        println!(
+            "warning: the price impact is more than the maximum of {max_impact_percent}% set by --max-price-impact."
+        );
+    }
+
+    Ok(yes || confirm("Swap anyway?")?)
+}
+
+fn confirm(prompt: &str) -> Result<bool> {
+    Confirm::new()
+        .with_prompt(prompt)
+        .interact()
+        .context("can't ask for confirmation, pass --yes to swap without asking")
+}
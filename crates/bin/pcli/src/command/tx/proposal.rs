@@ -24,8 +24,12 @@ pub enum ProposalCmd {
         #[clap(long)]
         file: camino::Utf8PathBuf,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// The amount of the staking token to deposit alongside the proposal.
         #[clap(long)]
         deposit_amount: u64,
@@ -42,8 +46,12 @@ pub enum ProposalCmd {
         #[clap(long)]
         reason: String,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -57,8 +65,12 @@ pub enum ProposalCmd {
         /// The proposal id to claim the deposit for.
         proposal_id: u64,
         /// Only spend funds originally received by the given account.
-        #[clap(long, default_value = "0")]
+        #[clap(long = "from-account", alias = "source", default_value = "0")]
         source: u32,
+        /// Also spend funds received by the wallet's other accounts if the source account's
+        /// funds are not enough, which links the accounts on-chain.
+        #[clap(long)]
+        allow_cross_account: bool,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, value_enum, default_value_t)]
         fee_tier: FeeTier,
@@ -93,11 +105,15 @@ impl ProposalKindCmd {
         let description = "A longer description (at most 10,000 characters)".to_string();
         let payload = match self {
             ProposalKindCmd::Signaling => ProposalPayload::Signaling { commit: None },
-            ProposalKindCmd::Emergency => ProposalPayload::Emergency { halt_chain: false },
+            ProposalKindCmd::Emergency => ProposalPayload::Emergency {
+                halt_chain: false,
+                pause_dex: false,
+            },
             ProposalKindCmd::ParameterChange => ProposalPayload::ParameterChange {
                 old: Box::new(app_params.as_changed_params()),
                 new: Box::new(ChangedAppParameters {
                     community_pool_params: None,
+                    dex_params: None,
                     distributions_params: None,
                     ibc_params: None,
                     fee_params: None,
@@ -59,8 +59,17 @@ pub struct ConstantProduct {
 
     #[clap(short, long, hide(true))]
     pub debug_file: Option<PathBuf>,
-    #[clap(long, default_value = "0", hide(true))]
+    #[clap(
+        long = "from-account",
+        alias = "source",
+        default_value = "0",
+        hide(true)
+    )]
     pub source: u32,
+    /// Also spend funds received by the wallet's other accounts if the source account's
+    /// funds are not enough, which links the accounts on-chain.
+    #[clap(long, hide(true))]
+    pub allow_cross_account: bool,
 }
 
 impl ConstantProduct {
@@ -78,6 +87,12 @@ impl ConstantProduct {
             current_price.try_into()?,
             self.fee_bps,
         )?;
+        let report = dex_utils::replicate::xyk::approximation_report(
+            &pair,
+            &self.input,
+            current_price.try_into()?,
+            &positions,
+        )?;
 
         let (amount_start, amount_end) =
             positions
@@ -128,6 +143,14 @@ impl ConstantProduct {
             "{}",
             crate::command::utils::render_positions(&asset_cache, &positions),
         );
+        println!(
+            "Compared to a constant-product pool, their value will be off by {:.2}% on average, and by at most {:.2}%, for prices up to {}{} per {}",
+            100.0 * report.tracking_error(),
+            100.0 * report.max_relative_error(),
+            report.samples.last().map_or(0.0, |sample| sample.price),
+            pair.end,
+            pair.start,
+        );
 
         if let Some(debug_file) = &self.debug_file {
             Self::write_debug_data(
@@ -160,7 +183,9 @@ impl ConstantProduct {
             .try_into()?;
 
         let mut planner = Planner::new(OsRng);
-        planner.set_gas_prices(gas_prices);
+        planner
+            .set_gas_prices(gas_prices)
+            .allow_cross_account(self.allow_cross_account);
         positions.iter().for_each(|position| {
             planner.position_open(position.clone());
         });
@@ -173,6 +198,9 @@ impl ConstantProduct {
                 AddressIndex::new(self.source),
             )
             .await?;
+        for id in plan.opened_position_ids() {
+            println!("opening position {id}");
+        }
         let tx_id = app.build_and_submit_transaction(plan).await?;
         println!("posted with transaction id: {tx_id}");
 
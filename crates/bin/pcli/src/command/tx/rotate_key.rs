@@ -0,0 +1,577 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use dialoguer::Confirm;
+use rand::Rng;
+use rand_core::OsRng;
+use serde::{Deserialize, Serialize};
+
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_keys::{keys::AddressIndex, FullViewingKey};
+use penumbra_num::Amount;
+use penumbra_proto::view::v1::NotesRequest;
+use penumbra_shielded_pool::OutputView;
+use penumbra_tct::StateCommitment;
+use penumbra_transaction::{txhash::TransactionId, ActionView, TransactionPlan};
+use penumbra_view::{SpendableNoteRecord, SweepTooSmall, ViewClient};
+use penumbra_wallet::plan::Planner;
+
+use crate::{config::PcliConfig, App};
+
+/// The file the progress of a key rotation is kept in, in the `pcli` home directory.
+pub const ROTATION_FILE_NAME: &str = "key-rotation.json";
+
+/// How to move the funds, and whether to ask before starting.
+#[derive(Debug, Clone)]
+pub struct RotateOptions {
+    /// The home directory of the wallet to move the funds to.
+    pub to_wallet: Option<Utf8PathBuf>,
+    /// The most the rotation may pay in fees in total, across runs.
+    pub fee_budget: Option<Amount>,
+    /// The shortest wait between transactions; a random wait up to as long again is added.
+    pub delay: Duration,
+    pub notes_per_transaction: usize,
+    pub fee_tier: penumbra_fee::FeeTier,
+    pub yes: bool,
+}
+
+/// The progress of moving all of a wallet's funds to a new wallet.
+///
+/// It's saved after every transaction, so that an interrupted rotation resumes where it stopped
+/// and keeps counting the fees it already paid against the budget.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rotation {
+    /// The full viewing key of the wallet the funds are moved to.
+    pub destination: FullViewingKey,
+    /// The fees paid by the rotation's transactions so far.
+    pub fees_paid: Amount,
+    pub transactions: Vec<RotationTransaction>,
+    /// The notes of the staking token left behind, because they're worth less than the fee of
+    /// moving them.
+    #[serde(default)]
+    pub dust: Vec<StateCommitment>,
+    /// Whether the rotation was verified to be complete.
+    pub completed: bool,
+}
+
+/// A transaction moving the notes of one asset, held by one account, to the new wallet.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RotationTransaction {
+    pub id: TransactionId,
+    pub account: u32,
+    pub asset_id: asset::Id,
+    pub fee: Amount,
+}
+
+impl Rotation {
+    fn load(path: &Utf8Path) -> Result<Option<Self>> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let rotation = serde_json::from_slice(&std::fs::read(path)?)
+            .with_context(|| format!("failed to parse the key rotation progress in {path}"))?;
+        Ok(Some(rotation))
+    }
+
+    /// Saves the progress, replacing the file only once it's fully written.
+    fn save(&self, path: &Utf8Path) -> Result<()> {
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&tmp, path)
+            .with_context(|| format!("failed to save the key rotation progress to {path}"))
+    }
+
+    fn is_dust(&self, record: &SpendableNoteRecord) -> bool {
+        self.dust.contains(&record.note_commitment)
+    }
+
+    /// The notes still to be moved, leaving out the ones already left behind as dust.
+    fn pending(&self, notes: Vec<SpendableNoteRecord>) -> Vec<SpendableNoteRecord> {
+        notes
+            .into_iter()
+            .filter(|record| !self.is_dust(record))
+            .collect()
+    }
+}
+
+/// The notes of one asset, held by one account, moved by the next transaction of the rotation.
+#[derive(Debug)]
+struct Batch {
+    account: u32,
+    asset_id: asset::Id,
+    notes: Vec<SpendableNoteRecord>,
+}
+
+impl Batch {
+    fn amount(&self) -> Amount {
+        self.notes.iter().map(|record| record.note.amount()).sum()
+    }
+}
+
+/// Splits the wallet's notes into the transactions that move them, in the order to send them.
+///
+/// Each transaction moves one asset of one account, so that it reveals no more about the wallet
+/// than an ordinary send. In each account, the staking token goes last, since the transactions
+/// moving other assets spend it for their fees, and the ones moving it pay their own.
+fn batches(notes: Vec<SpendableNoteRecord>, notes_per_transaction: usize) -> Vec<Batch> {
+    let mut by_account = BTreeMap::<u32, BTreeMap<_, Vec<SpendableNoteRecord>>>::new();
+    for record in notes {
+        by_account
+            .entry(record.address_index.account)
+            .or_default()
+            .entry((
+                record.note.asset_id() == *STAKING_TOKEN_ASSET_ID,
+                record.note.asset_id(),
+            ))
+            .or_default()
+            .push(record);
+    }
+
+    let mut batches = Vec::new();
+    for (account, by_asset) in by_account {
+        for ((_, asset_id), mut records) in by_asset {
+            // Largest first, so that the notes too small to pay for moving themselves end up
+            // together in the last transaction.
+            records.sort_by(|a, b| b.note.amount().cmp(&a.note.amount()));
+            for chunk in records.chunks(notes_per_transaction.max(1)) {
+                batches.push(Batch {
+                    account,
+                    asset_id,
+                    notes: chunk.to_vec(),
+                });
+            }
+        }
+    }
+    batches
+}
+
+/// Moves every note in the wallet to the same account of another wallet, for instance because
+/// the wallet's spend key may have been compromised.
+///
+/// The funds are moved in many small transactions, spaced out by random delays, and the progress
+/// is saved in the home directory, so that the command can be run again to resume. Once no notes
+/// are left, the rotation is verified against the wallet's transaction history.
+pub async fn rotate(app: &mut App, options: RotateOptions) -> Result<()> {
+    let path = app.home.join(ROTATION_FILE_NAME);
+    let destination = match &options.to_wallet {
+        Some(home) => {
+            let config =
+                PcliConfig::load(home.join(crate::CONFIG_FILE_NAME)).with_context(|| {
+                    format!("can't load the wallet to move the funds to from {home}")
+                })?;
+            Some(config.full_viewing_key)
+        }
+        None => None,
+    };
+    let mut rotation = match (Rotation::load(&path)?, destination) {
+        (Some(rotation), Some(destination)) if rotation.destination != destination => {
+            anyhow::bail!(
+                "a key rotation to another wallet was already started; finish it, or delete {path} to start over"
+            );
+        }
+        (Some(rotation), _) => {
+            println!(
+                "Resuming the key rotation started earlier, after {} transactions.",
+                rotation.transactions.len()
+            );
+            rotation
+        }
+        (None, Some(destination)) => Rotation {
+            destination,
+            fees_paid: Amount::zero(),
+            transactions: Vec::new(),
+            dust: Vec::new(),
+            completed: false,
+        },
+        (None, None) => {
+            anyhow::bail!("no key rotation was started; pass --to-wallet with the new wallet's home directory")
+        }
+    };
+    if rotation.destination == app.config.full_viewing_key {
+        anyhow::bail!("the funds must be moved to a wallet with a different spend key");
+    }
+    if rotation.completed {
+        println!("The key rotation already completed.");
+        return verify(app, &rotation).await;
+    }
+
+    let asset_cache = app.view().assets().await?;
+    let notes = app.view().notes(NotesRequest::default()).await?;
+    let pending = batches(rotation.pending(notes), options.notes_per_transaction);
+    if !pending.is_empty() {
+        let mut totals = BTreeMap::<asset::Id, Amount>::new();
+        for batch in &pending {
+            *totals.entry(batch.asset_id).or_default() += batch.amount();
+        }
+        println!(
+            "This will move the wallet's funds to the new wallet in about {} transactions, waiting {} to {} seconds between them:",
+            pending.len(),
+            options.delay.as_secs(),
+            2 * options.delay.as_secs(),
+        );
+        for (asset_id, amount) in totals {
+            println!("  {}", Value { amount, asset_id }.format(&asset_cache));
+        }
+        if let Some(budget) = options.fee_budget {
+            let budget = Value {
+                amount: budget,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
+            println!(
+                "Fees are limited to {} in total.",
+                budget.format(&asset_cache)
+            );
+        }
+        if !options.yes
+            && !Confirm::new()
+                .with_prompt("Start moving the funds?")
+                .interact()
+                .context("can't ask for confirmation, pass --yes to start without asking")?
+        {
+            return Ok(());
+        }
+    }
+
+    loop {
+        let notes = app.view().notes(NotesRequest::default()).await?;
+        let Some(batch) = batches(rotation.pending(notes), options.notes_per_transaction)
+            .into_iter()
+            .next()
+        else {
+            break;
+        };
+        let value = Value {
+            amount: batch.amount(),
+            asset_id: batch.asset_id,
+        };
+
+        let plan = match plan(app, &rotation.destination, &batch, &options).await {
+            Ok(plan) => plan,
+            Err(error) => {
+                // The last notes of the staking token can be worth less than the fee of moving
+                // them, which no later run would change, so they're left behind.
+                if let Some(too_small) = error.downcast_ref::<SweepTooSmall>() {
+                    rotation
+                        .dust
+                        .extend(batch.notes.iter().map(|record| record.note_commitment));
+                    rotation.save(&path)?;
+                    println!(
+                        "Leaving {} behind in account {}, since moving it would cost a fee of {}.",
+                        value.format(&asset_cache),
+                        batch.account,
+                        Value {
+                            amount: too_small.fee,
+                            asset_id: *STAKING_TOKEN_ASSET_ID,
+                        }
+                        .format(&asset_cache)
+                    );
+                    continue;
+                }
+                return Err(error.context(format!(
+                    "can't plan moving {} from account {}",
+                    value.format(&asset_cache),
+                    batch.account
+                )));
+            }
+        };
+        let fee = plan.transaction_parameters.fee.amount();
+        if let Some(budget) = options.fee_budget {
+            if rotation.fees_paid + fee > budget {
+                rotation.save(&path)?;
+                anyhow::bail!(
+                    "the next transaction would exceed the fee budget, after paying {} in fees; run again with a larger --fee-budget to continue",
+                    Value {
+                        amount: rotation.fees_paid,
+                        asset_id: *STAKING_TOKEN_ASSET_ID,
+                    }
+                    .format(&asset_cache)
+                );
+            }
+        }
+
+        let id = app.build_and_submit_transaction(plan).await?;
+        rotation.fees_paid += fee;
+        rotation.transactions.push(RotationTransaction {
+            id,
+            account: batch.account,
+            asset_id: batch.asset_id,
+            fee,
+        });
+        rotation.save(&path)?;
+        println!(
+            "Moved {} from account {} in transaction {id}.",
+            value.format(&asset_cache),
+            batch.account
+        );
+
+        // Space the transactions out at random, so that their timing doesn't link them.
+        let delay = options.delay + options.delay.mul_f64(OsRng.gen::<f64>());
+        println!(
+            "Waiting {} seconds before the next transaction...",
+            delay.as_secs()
+        );
+        tokio::time::sleep(delay).await;
+    }
+
+    verify(app, &rotation).await?;
+    rotation.completed = true;
+    rotation.save(&path)?;
+    Ok(())
+}
+
+async fn plan(
+    app: &mut App,
+    destination: &FullViewingKey,
+    batch: &Batch,
+    options: &RotateOptions,
+) -> Result<TransactionPlan> {
+    let source = AddressIndex::new(batch.account);
+    let (destination, _dtk) = destination.payment_address(source);
+    let mut planner = Planner::new(OsRng);
+    planner
+        .set_gas_prices(app.view().gas_prices().await?)
+        .set_fee_tier(options.fee_tier);
+
+    // The staking token pays the fee out of the notes being moved, and no change is left.
+    if batch.asset_id == *STAKING_TOKEN_ASSET_ID {
+        return planner
+            .plan_sweep(app.view(), source, batch.notes.clone(), destination)
+            .await;
+    }
+
+    // Other assets are moved whole, and the fee is paid with the account's staking token.
+    for record in &batch.notes {
+        planner.spend(record.note.clone(), record.position);
+    }
+    planner.output(
+        Value {
+            amount: batch.amount(),
+            asset_id: batch.asset_id,
+        },
+        destination,
+    );
+    planner.plan(app.view(), source).await
+}
+
+/// Checks that no notes but the dust are left in the wallet, and that every transaction of the
+/// rotation is on chain and moved funds to the new wallet.
+async fn verify(app: &mut App, rotation: &Rotation) -> Result<()> {
+    let asset_cache = app.view().assets().await?;
+
+    let mut problems = Vec::new();
+    let mut dust = Amount::zero();
+    for record in app.view().notes(NotesRequest::default()).await? {
+        if rotation.is_dust(&record) {
+            dust += record.note.amount();
+            continue;
+        }
+        problems.push(format!(
+            "account {} still holds {}",
+            record.address_index.account,
+            record.note.value().format(&asset_cache)
+        ));
+    }
+    for tx in &rotation.transactions {
+        let info = app
+            .view()
+            .transaction_info_by_hash(tx.id)
+            .await
+            .with_context(|| format!("transaction {} is not in the wallet's history", tx.id))?;
+        let moved = info
+            .view
+            .body_view
+            .action_views
+            .iter()
+            .any(|action_view| match action_view {
+                ActionView::Output(OutputView::Visible { note, .. }) => rotation
+                    .destination
+                    .address_index(&note.address.address())
+                    .is_some(),
+                _ => false,
+            });
+        if !moved {
+            problems.push(format!(
+                "transaction {} sent nothing to the new wallet",
+                tx.id
+            ));
+        }
+    }
+    if !app.view().unclaimed_swaps().await?.is_empty() {
+        problems
+            .push("the wallet has unclaimed swaps; claim them with 'pcli tx sweep'".to_string());
+    }
+    let positions = app.view().owned_position_ids(None, None).await?;
+    if !positions.is_empty() {
+        problems.push(format!(
+            "the wallet owns {} liquidity positions, which only its spend key can close and withdraw",
+            positions.len()
+        ));
+    }
+
+    if !problems.is_empty() {
+        for problem in &problems {
+            println!("incomplete: {problem}");
+        }
+        anyhow::bail!("the key rotation is not complete");
+    }
+
+    let fees = Value {
+        amount: rotation.fees_paid,
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+    println!(
+        "Verified the key rotation: {} transactions moved all funds to the new wallet, paying {} in fees.",
+        rotation.transactions.len(),
+        fees.format(&asset_cache)
+    );
+    if dust == Amount::zero() {
+        println!("The old spend key no longer controls any funds; use the new wallet from now on.");
+    } else {
+        let dust = Value {
+            amount: dust,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        };
+        println!(
+            "The old spend key only controls {}, worth less than the fees of moving it; use the new wallet from now on.",
+            dust.format(&asset_cache)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use penumbra_keys::test_keys;
+    use penumbra_sct::{CommitmentSource, Nullifier};
+    use penumbra_shielded_pool::Note;
+
+    fn record(account: u32, asset_id: asset::Id, amount: u64) -> SpendableNoteRecord {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: amount.into(),
+                asset_id,
+            },
+        );
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            nullifier: Nullifier::derive(
+                test_keys::FULL_VIEWING_KEY.nullifier_key(),
+                0u64.into(),
+                &note.commit(),
+            ),
+            note,
+            address_index: AddressIndex::new(account),
+            height_created: 1,
+            height_spent: None,
+            position: 0u64.into(),
+            source: CommitmentSource::transaction(),
+            return_address: None,
+        }
+    }
+
+    #[test]
+    fn batches_move_the_staking_token_last_in_each_account() {
+        let other = asset::Id(decaf377::Fq::from(1u64));
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let notes = vec![
+            record(1, staking, 5),
+            record(0, staking, 1),
+            record(0, staking, 3),
+            record(0, staking, 2),
+            record(0, other, 7),
+        ];
+
+        let batches = batches(notes, 2);
+        let summary = batches
+            .iter()
+            .map(|batch| (batch.account, batch.asset_id, batch.amount()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                (0, other, 7u64.into()),
+                (0, staking, 5u64.into()),
+                (0, staking, 1u64.into()),
+                (1, staking, 5u64.into()),
+            ]
+        );
+    }
+
+    fn rotation() -> Rotation {
+        Rotation {
+            destination: test_keys::FULL_VIEWING_KEY.clone(),
+            fees_paid: Amount::zero(),
+            transactions: Vec::new(),
+            dust: Vec::new(),
+            completed: false,
+        }
+    }
+
+    #[test]
+    fn dust_is_not_moved_again() {
+        let staking = *STAKING_TOKEN_ASSET_ID;
+        let notes = vec![
+            record(0, staking, 1000),
+            record(0, staking, 2),
+            record(0, staking, 1),
+        ];
+
+        // The smallest notes were too small to pay for moving themselves.
+        let mut rotation = rotation();
+        rotation
+            .dust
+            .extend(notes[1..].iter().map(|record| record.note_commitment));
+
+        let batches = batches(rotation.pending(notes), 2);
+        let summary = batches
+            .iter()
+            .map(|batch| (batch.account, batch.asset_id, batch.amount()))
+            .collect::<Vec<_>>();
+        assert_eq!(summary, vec![(0, staking, 1000u64.into())]);
+    }
+
+    #[test]
+    fn rotation_resumes_from_saved_progress() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = Utf8PathBuf::try_from(dir.path().join(ROTATION_FILE_NAME))?;
+        assert!(Rotation::load(&path)?.is_none());
+
+        let dust = record(0, *STAKING_TOKEN_ASSET_ID, 1);
+        let mut rotation = rotation();
+        rotation.fees_paid = 30u64.into();
+        rotation.transactions.push(RotationTransaction {
+            id: TransactionId([1; 32]),
+            account: 0,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+            fee: 30u64.into(),
+        });
+        rotation.dust.push(dust.note_commitment);
+        rotation.save(&path)?;
+
+        let resumed = Rotation::load(&path)?.expect("progress was saved");
+        assert_eq!(resumed.destination, rotation.destination);
+        assert_eq!(resumed.fees_paid, rotation.fees_paid);
+        assert_eq!(resumed.transactions.len(), 1);
+        assert_eq!(resumed.transactions[0].id, rotation.transactions[0].id);
+        assert!(resumed.is_dust(&dust));
+        assert!(!resumed.completed);
+
+        // Progress saved before dust was recorded still resumes, with no dust.
+        let mut json = serde_json::to_value(&rotation)?;
+        json.as_object_mut()
+            .expect("a rotation is a JSON object")
+            .remove("dust");
+        std::fs::write(&path, serde_json::to_vec(&json)?)?;
+        let resumed = Rotation::load(&path)?.expect("progress was saved");
+        assert!(resumed.dust.is_empty());
+        assert_eq!(resumed.fees_paid, rotation.fees_paid);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+
+use penumbra_proto::{
+    core::component::{
+        sct::v1::{
+            query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
+        },
+        stake::v1::{
+            query_service_client::QueryServiceClient as StakeQueryServiceClient,
+            ValidatorStatusRequest,
+        },
+    },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+    },
+};
+use penumbra_sct::epoch::Epoch;
+use penumbra_stake::{
+    validator::{BondingState, Status},
+    IdentityKey,
+};
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+/// How many blocks before the scheduled end of an epoch `--wait-for-epoch-end` submits an
+/// undelegation, so that it's still included in the epoch.
+pub const EPOCH_END_MARGIN_BLOCKS: u64 = 10;
+
+/// How often to check the chain's height while waiting for the end of an epoch.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// When an undelegation made at the current height finishes unbonding.
+///
+/// An undelegation can be claimed from an epoch counted from the epoch it was made in, so one
+/// made late in an epoch unbonds in less time than one made early in it.
+#[derive(Debug, Clone)]
+pub struct UnbondingSchedule {
+    pub current_height: u64,
+    /// The current epoch.
+    pub epoch: Epoch,
+    pub epoch_duration: u64,
+    /// The epoch an undelegation made in the current epoch can be claimed from.
+    pub claim_epoch: u64,
+    /// The epoch an undelegation made in the next epoch could be claimed from.
+    pub next_claim_epoch: u64,
+}
+
+impl UnbondingSchedule {
+    pub fn new(
+        current_height: u64,
+        epoch: Epoch,
+        epoch_duration: u64,
+        bonding_state: &BondingState,
+        unbonding_epochs: u64,
+    ) -> Self {
+        Self {
+            current_height,
+            epoch,
+            epoch_duration,
+            claim_epoch: bonding_state.unbonding_epoch(epoch.index, unbonding_epochs),
+            next_claim_epoch: bonding_state.unbonding_epoch(epoch.index + 1, unbonding_epochs),
+        }
+    }
+
+    /// The number of blocks after the current one until the scheduled end of the epoch.
+    pub fn blocks_until_epoch_end(&self) -> u64 {
+        self.epoch
+            .scheduled_end_height(self.epoch_duration)
+            .saturating_sub(self.current_height)
+    }
+
+    /// The height the claim epoch is scheduled to start at, unless an epoch ends early.
+    pub fn claim_height(&self) -> u64 {
+        match self.claim_epoch.saturating_sub(self.epoch.index) {
+            0 => self.current_height,
+            epochs => self.epoch.start_height + epochs * self.epoch_duration,
+        }
+    }
+
+    /// Whether undelegating in the last `margin` blocks of the epoch, rather than now, would
+    /// unbond the same tokens in materially less time.
+    ///
+    /// That's the case when the claim epoch depends on the epoch of the undelegation, and more
+    /// than `margin` blocks of the epoch are left.
+    pub fn worth_waiting(&self, margin: u64) -> bool {
+        self.next_claim_epoch > self.claim_epoch && self.blocks_until_epoch_end() > margin
+    }
+
+    /// Describes when an undelegation made now can be claimed.
+    pub fn describe(&self) -> String {
+        if self.claim_epoch <= self.epoch.index {
+            return "The validator is unbonded, so the undelegation can be claimed immediately."
+                .to_string();
+        }
+        let claim_height = self.claim_height();
+        format!(
+            "Undelegating in epoch {}: the undelegation can be claimed from epoch {}, \
+             scheduled to start at height {} ({} blocks from now).",
+            self.epoch.index,
+            self.claim_epoch,
+            claim_height,
+            claim_height.saturating_sub(self.current_height),
+        )
+    }
+}
+
+/// Fetches the unbonding schedule of an undelegation from `validator` made at the chain's current
+/// height.
+pub async fn fetch_schedule(app: &mut App, validator: IdentityKey) -> Result<UnbondingSchedule> {
+    let channel = app.pd_channel().await?;
+    let current_height = latest_height(&mut TendermintProxyServiceClient::new(channel.clone()))
+        .await
+        .context("can't get the chain's height")?;
+    let epoch: Epoch = SctQueryServiceClient::new(channel.clone())
+        .epoch_by_height(EpochByHeightRequest {
+            height: current_height,
+        })
+        .await?
+        .into_inner()
+        .epoch
+        .context("unable to get epoch for current height")?
+        .into();
+    let status: Status = StakeQueryServiceClient::new(channel)
+        .validator_status(ValidatorStatusRequest {
+            identity_key: Some(validator.into()),
+        })
+        .await?
+        .into_inner()
+        .status
+        .context("no status returned for validator")?
+        .try_into()?;
+    let app_params = app.view().app_params().await?;
+
+    Ok(UnbondingSchedule::new(
+        current_height,
+        epoch,
+        app_params.sct_params.epoch_duration,
+        &status.bonding_state,
+        app_params.stake_params.unbonding_epochs,
+    ))
+}
+
+/// Waits until the last `margin` blocks of the current epoch, returning the schedule of an
+/// undelegation made then.
+///
+/// If the epoch ends early, this returns as soon as the new epoch is seen.
+pub async fn wait_for_epoch_end(
+    app: &mut App,
+    validator: IdentityKey,
+    schedule: UnbondingSchedule,
+    margin: u64,
+) -> Result<UnbondingSchedule> {
+    let end_height = schedule.epoch.scheduled_end_height(schedule.epoch_duration);
+    println!(
+        "Waiting until height {} to undelegate, {margin} blocks before epoch {} ends...",
+        end_height.saturating_sub(margin),
+        schedule.epoch.index,
+    );
+
+    loop {
+        let current = fetch_schedule(app, validator).await?;
+        if current.epoch.index != schedule.epoch.index {
+            println!("Epoch {} ended early.", schedule.epoch.index);
+            return Ok(current);
+        }
+        if current.blocks_until_epoch_end() <= margin {
+            return Ok(current);
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+async fn latest_height(
+    tendermint: &mut TendermintProxyServiceClient<tonic::transport::Channel>,
+) -> Result<u64> {
+    Ok(tendermint
+        .get_status(GetStatusRequest::default())
+        .await?
+        .into_inner()
+        .sync_info
+        .ok_or_else(|| anyhow::anyhow!("missing sync_info"))?
+        .latest_block_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(current_height: u64, bonding_state: BondingState) -> UnbondingSchedule {
+        let epoch = Epoch {
+            index: 10,
+            start_height: 1000,
+        };
+        UnbondingSchedule::new(current_height, epoch, 100, &bonding_state, 2)
+    }
+
+    #[test]
+    fn waiting_only_helps_when_the_claim_epoch_would_move() {
+        let early = schedule(1010, BondingState::Bonded);
+        assert_eq!(early.claim_epoch, 12);
+        assert_eq!(early.claim_height(), 1200);
+        assert_eq!(early.blocks_until_epoch_end(), 89);
+        assert!(early.worth_waiting(EPOCH_END_MARGIN_BLOCKS));
+
+        let late = schedule(1095, BondingState::Bonded);
+        assert!(!late.worth_waiting(EPOCH_END_MARGIN_BLOCKS));
+
+        // An unbonding validator's pool unbonds at a fixed epoch, however late the undelegation.
+        let unbonding = schedule(
+            1010,
+            BondingState::Unbonding {
+                unbonds_at_epoch: 11,
+            },
+        );
+        assert_eq!(unbonding.claim_epoch, 11);
+        assert!(!unbonding.worth_waiting(EPOCH_END_MARGIN_BLOCKS));
+
+        let unbonded = schedule(1010, BondingState::Unbonded);
+        assert_eq!(unbonded.claim_height(), 1010);
+        assert!(!unbonded.worth_waiting(EPOCH_END_MARGIN_BLOCKS));
+    }
+}
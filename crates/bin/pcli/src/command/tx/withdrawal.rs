@@ -0,0 +1,192 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use ibc_proto::ibc::core::{
+    channel::v1::{
+        query_client::QueryClient as IbcChannelQueryClient, QueryChannelConsensusStateRequest,
+        QueryChannelRequest,
+    },
+    client::v1::{query_client::QueryClient as IbcClientQueryClient, QueryClientStateRequest},
+    connection::v1::{
+        query_client::QueryClient as IbcConnectionQueryClient, QueryConnectionRequest,
+    },
+};
+use ibc_types::{
+    core::{
+        channel::{channel::State, PortId},
+        client::Height as IbcHeight,
+    },
+    lightclients::tendermint::{
+        client_state::ClientState as TendermintClientState,
+        consensus_state::ConsensusState as TendermintConsensusState,
+    },
+};
+
+use crate::App;
+
+/// The default number of counterparty blocks after which an unrelayed withdrawal times out: two
+/// days, at ten seconds per block.
+pub const DEFAULT_TIMEOUT_BLOCKS: u64 = 2 * 24 * 60 * 60 / 10;
+
+/// The default time after which an unrelayed withdrawal times out, in seconds: two days.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 2 * 24 * 60 * 60;
+
+/// The state of a transfer channel, and of the light client Penumbra tracks its counterparty
+/// with.
+pub struct ChannelStatus {
+    pub channel_id: String,
+    pub state: State,
+    pub client_id: String,
+    pub client_state: TendermintClientState,
+    /// How long ago the counterparty's latest consensus state known to the client was made.
+    pub client_age: Duration,
+}
+
+impl ChannelStatus {
+    /// Looks up the transfer channel `channel-{channel}`, its connection and its client.
+    pub async fn fetch(app: &mut App, channel: u64) -> Result<Self> {
+        let channel_id = format!("channel-{channel}");
+
+        let mut channel_client = IbcChannelQueryClient::new(app.pd_channel().await?);
+        let channel_end = channel_client
+            .channel(QueryChannelRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: channel_id.clone(),
+            })
+            .await
+            .with_context(|| format!("cannot look up {channel_id}"))?
+            .into_inner()
+            .channel
+            .with_context(|| {
+                format!("{channel_id} doesn't exist, see `pcli query ibc channels` for channels")
+            })?;
+        let state = State::from_i32(channel_end.state)
+            .with_context(|| format!("{channel_id} has an unknown state"))?;
+
+        let connection_id = channel_end
+            .connection_hops
+            .first()
+            .with_context(|| format!("{channel_id} has no connection"))?
+            .clone();
+        let mut connection_client = IbcConnectionQueryClient::new(app.pd_channel().await?);
+        let connection = connection_client
+            .connection(QueryConnectionRequest {
+                connection_id: connection_id.clone(),
+            })
+            .await?
+            .into_inner()
+            .connection
+            .with_context(|| format!("connection {connection_id} not found"))?;
+
+        let mut client_client = IbcClientQueryClient::new(app.pd_channel().await?);
+        let client_state = client_client
+            .client_state(QueryClientStateRequest {
+                client_id: connection.client_id.clone(),
+            })
+            .await?
+            .into_inner()
+            .client_state
+            .with_context(|| format!("client {} not found", connection.client_id))?;
+        let client_state = TendermintClientState::try_from(client_state)?;
+
+        let consensus_state = channel_client
+            .channel_consensus_state(QueryChannelConsensusStateRequest {
+                port_id: PortId::transfer().to_string(),
+                channel_id: channel_id.clone(),
+                revision_number: client_state.latest_height.revision_number,
+                revision_height: client_state.latest_height.revision_height,
+            })
+            .await?
+            .into_inner()
+            .consensus_state
+            .with_context(|| format!("consensus state not found for {channel_id}"))?;
+        let consensus_state = TendermintConsensusState::try_from(consensus_state)?;
+        let now: tendermint::Time = time::OffsetDateTime::from(SystemTime::now()).try_into()?;
+        let client_age = now
+            .duration_since(consensus_state.timestamp)
+            .unwrap_or_default();
+
+        Ok(Self {
+            channel_id,
+            state,
+            client_id: connection.client_id,
+            client_state,
+            client_age,
+        })
+    }
+
+    /// Checks that a withdrawal over the channel can be relayed, rather than timing out: that
+    /// the channel is open, and that the counterparty's client is neither frozen nor expired.
+    pub fn check(&self) -> Result<()> {
+        let channel_id = &self.channel_id;
+        let chain_id = &self.client_state.chain_id;
+        if self.state != State::Open {
+            anyhow::bail!(
+                "{channel_id} to {chain_id} is {}, not open, so withdrawals over it can't be relayed",
+                self.state
+            );
+        }
+        if self.client_state.is_frozen() {
+            anyhow::bail!(
+                "client {} for {chain_id} is frozen after misbehaviour, so withdrawals over {channel_id} can't be relayed",
+                self.client_id
+            );
+        }
+        if self.client_state.expired(self.client_age) {
+            anyhow::bail!(
+                "client {} for {chain_id} expired after not being updated for {}s, so withdrawals over {channel_id} can't be relayed until it's recovered by governance",
+                self.client_id,
+                self.client_age.as_secs()
+            );
+        }
+        Ok(())
+    }
+
+    /// The timeout height for a withdrawal: either `height`, which must not already have
+    /// passed, or `blocks` after the counterparty's latest height known to the client.
+    pub fn timeout_height(&self, height: Option<&IbcHeight>, blocks: u64) -> Result<IbcHeight> {
+        let latest = self.client_state.latest_height;
+        match height {
+            Some(height) if *height <= latest => anyhow::bail!(
+                "timeout height {height} has already passed on {}, whose latest known height is {latest}",
+                self.client_state.chain_id
+            ),
+            Some(height) => Ok(*height),
+            None => Ok(IbcHeight {
+                revision_number: latest.revision_number,
+                revision_height: latest.revision_height + blocks,
+            }),
+        }
+    }
+}
+
+/// The timeout timestamp for a withdrawal, in nanoseconds since the Unix epoch: either
+/// `timestamp`, which must be in the future, or `secs` seconds from now.
+pub fn timeout_timestamp(timestamp: Option<u64>, secs: u64) -> Result<u64> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("time went backwards")
+        .as_nanos() as u64;
+    match timestamp {
+        Some(timestamp) if timestamp <= now => anyhow::bail!(
+            "timeout timestamp {timestamp} has already passed, it must be in nanoseconds since the Unix epoch"
+        ),
+        Some(timestamp) => Ok(timestamp),
+        None => Ok(now + secs * 1_000_000_000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timeout_timestamp_must_be_in_the_future() {
+        assert!(timeout_timestamp(Some(1), DEFAULT_TIMEOUT_SECS).is_err());
+        assert_eq!(timeout_timestamp(Some(u64::MAX), 0).unwrap(), u64::MAX);
+
+        let default = timeout_timestamp(None, DEFAULT_TIMEOUT_SECS).unwrap();
+        let now = timeout_timestamp(None, 0).unwrap();
+        assert!(default >= now + (DEFAULT_TIMEOUT_SECS - 1) * 1_000_000_000);
+    }
+}
@@ -1,23 +1,12 @@
-use comfy_table::{presets, Table};
-use penumbra_asset::{asset, Value};
+use penumbra_asset::asset;
 use penumbra_dex::lp::position::Position;
 
+use crate::render::{self, Values};
+
 pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position]) -> String {
-    let mut table = Table::new();
-    table.load_preset(presets::NOTHING);
-    table.set_header(vec!["ID", "State", "Fee", "Sell Price", "Reserves"]);
-    table
-        .get_column_mut(2)
-        .expect("column 2 exists")
-        .set_cell_alignment(comfy_table::CellAlignment::Right);
-    table
-        .get_column_mut(3)
-        .expect("column 3 exists")
-        .set_cell_alignment(comfy_table::CellAlignment::Right);
-    table
-        .get_column_mut(4)
-        .expect("column 4 exists")
-        .set_cell_alignment(comfy_table::CellAlignment::Right);
+    let values = Values::new(asset_cache);
+    let mut table = render::table_with_header(["ID", "State", "Fee", "Sell Price", "Reserves"]);
+    render::align_right(&mut table, [2, 3, 4]);
 
     for position in positions {
         let trading_pair = position.phi.pair;
@@ -35,7 +24,7 @@ pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position
                             "{}",
                             sell_order.price_str(asset_cache).expect("assets are known"),
                         ),
-                        sell_order.offered.format(asset_cache),
+                        values.value(sell_order.offered),
                     ]);
                 } else if let Some((sell_order_1, sell_order_2)) = position.interpret_as_mixed() {
                     table.add_row(vec![
@@ -48,7 +37,7 @@ pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position
                                 .price_str(asset_cache)
                                 .expect("assets are known"),
                         ),
-                        sell_order_1.offered.format(asset_cache),
+                        values.value(sell_order_1.offered),
                     ]);
                     table.add_row(vec![
                         // Add a mark indicating this row is associated with the same position.
@@ -62,7 +51,7 @@ pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position
                                 .price_str(asset_cache)
                                 .expect("assets are known"),
                         ),
-                        sell_order_2.offered.format(asset_cache),
+                        values.value(sell_order_2.offered),
                     ]);
                 } else {
                     table.add_row(vec![
@@ -78,22 +67,14 @@ pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position
                     position.state.to_string(),
                     format!("{}bps", position.phi.component.fee),
                     format!("Unknown asset"),
-                    Value {
-                        amount: position.reserves.r1,
-                        asset_id: position.phi.pair.asset_1(),
-                    }
-                    .format(asset_cache),
+                    values.amount(position.reserves.r1, position.phi.pair.asset_1()),
                 ]);
                 table.add_row(vec![
                     String::new(),
                     String::new(),
                     format!("{}bps", position.phi.component.fee),
                     format!("Unknown asset"),
-                    Value {
-                        amount: position.reserves.r2,
-                        asset_id: position.phi.pair.asset_2(),
-                    }
-                    .format(asset_cache),
+                    values.amount(position.reserves.r2, position.phi.pair.asset_2()),
                 ]);
             }
         }
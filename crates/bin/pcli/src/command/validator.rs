@@ -1,9 +1,12 @@
 use std::{
     fs::File,
     io::{Read, Write},
+    time::Duration,
 };
 
 use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
+use futures::TryStreamExt;
 use rand_core::OsRng;
 use serde_json::Value;
 
@@ -11,19 +14,36 @@ use penumbra_fee::Fee;
 use penumbra_governance::{
     ValidatorVote, ValidatorVoteBody, ValidatorVoteReason, Vote, MAX_VALIDATOR_VOTE_REASON_LENGTH,
 };
-use penumbra_keys::keys::AddressIndex;
+use penumbra_keys::keys::{AddressIndex, SpendKey};
 use penumbra_proto::{
-    core::component::stake::v1::Validator as ProtoValidator, DomainType, Message,
+    core::component::{
+        governance::v1::{
+            query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
+            ProposalListRequest, ValidatorVotesRequest,
+        },
+        stake::v1::{
+            query_service_client::QueryServiceClient as StakeQueryServiceClient,
+            Validator as ProtoValidator, ValidatorInfoRequest, ValidatorUptimeRequest,
+        },
+    },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+    },
+    DomainType, Message,
 };
 use penumbra_stake::{
     validator,
     validator::{Validator, ValidatorToml},
-    FundingStream, FundingStreams, GovernanceKey, IdentityKey,
+    FundingStream, FundingStreams, GovernanceKey, IdentityKey, Uptime,
 };
 use penumbra_wallet::plan;
+use tonic::transport::Channel;
+use watch::{watch_proposals, WatchPolicy};
 
 use crate::{config::CustodyConfig, App};
 
+mod watch;
+
 #[derive(Debug, clap::Subcommand)]
 pub enum ValidatorCmd {
     /// Display the validator identity key derived from this wallet's spend seed.
@@ -50,6 +70,21 @@ pub enum ValidatorCmd {
         #[clap(long, default_value = "", global = true, display_order = 400)]
         reason: String,
     },
+    /// Display an overview of your validator: its definition, voting power and bonding state,
+    /// recent uptime, and the active proposals it has yet to vote on.
+    Dashboard,
+    /// Watch for proposals awaiting your vote, alerting through the hooks in a policy file, and
+    /// optionally abstaining automatically if no vote was cast shortly before voting ends.
+    ///
+    /// This runs until interrupted.
+    WatchProposals {
+        /// The TOML file containing the watch policy.
+        #[clap(long)]
+        policy: camino::Utf8PathBuf,
+        /// How often to check for proposals, in seconds.
+        #[clap(long, default_value = "60")]
+        interval: u64,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -99,6 +134,8 @@ impl ValidatorCmd {
                 DefinitionCmd::Template { .. } | DefinitionCmd::Fetch { .. },
             ) => true,
             ValidatorCmd::Vote { .. } => false,
+            ValidatorCmd::Dashboard => false,
+            ValidatorCmd::WatchProposals { .. } => false,
         }
     }
 
@@ -178,52 +215,15 @@ impl ValidatorCmd {
                 vote,
                 reason,
             } => {
-                // TODO: support submitting a separate governance key.
-                let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
-                // Currently this is always just copied from the identity key
-                let governance_key = GovernanceKey(identity_key.0);
-
                 let (proposal, vote): (u64, Vote) = (*vote).into();
-
-                if reason.len() > MAX_VALIDATOR_VOTE_REASON_LENGTH {
-                    anyhow::bail!("validator vote reason is too long, max 1024 bytes");
-                }
-
-                // Construct the vote body
-                let body = ValidatorVoteBody {
-                    proposal,
-                    vote,
-                    identity_key,
-                    governance_key,
-                    reason: ValidatorVoteReason(reason.clone()),
-                };
-
-                // TODO: support signing with a separate governance key
-                let governance_auth_key = sk.spend_auth_key();
-
-                // Generate an authorizing signature with the governance key for the vote body
-                let body_bytes = body.encode_to_vec();
-                let auth_sig = governance_auth_key.sign(OsRng, &body_bytes);
-
-                let vote = ValidatorVote { body, auth_sig };
-
-                // Construct a new transaction and include the validator definition.
-                let fee = Fee::from_staking_token_amount((*fee).into());
-
-                let plan = plan::validator_vote(
-                    app.view
-                        .as_mut()
-                        .context("view service must be initialized")?,
-                    OsRng,
-                    vote,
-                    fee,
-                    AddressIndex::new(*source),
-                )
-                .await?;
-                app.build_and_submit_transaction(plan).await?;
+                cast_vote(app, &sk, proposal, vote, reason, *fee, *source).await?;
 
                 println!("Cast validator vote");
             }
+            ValidatorCmd::WatchProposals { policy, interval } => {
+                let policy = WatchPolicy::load(policy)?;
+                watch_proposals(app, &sk, &policy, Duration::from_secs(*interval)).await?;
+            }
             ValidatorCmd::Definition(DefinitionCmd::Template {
                 file,
                 tendermint_validator_keyfile,
@@ -316,6 +316,10 @@ impl ValidatorCmd {
                     println!("{}", &template_str);
                 }
             }
+            ValidatorCmd::Dashboard => {
+                let identity_key = IdentityKey(fvk.spend_verification_key().clone());
+                dashboard(app, identity_key).await?;
+            }
             ValidatorCmd::Definition(DefinitionCmd::Fetch { file }) => {
                 let identity_key = IdentityKey(fvk.spend_verification_key().clone());
                 super::query::ValidatorCmd::Definition {
@@ -331,6 +335,237 @@ impl ValidatorCmd {
     }
 }
 
+/// Print an overview of the given validator, gathered from the staking and governance
+/// query services.
+async fn dashboard(app: &mut App, identity_key: IdentityKey) -> Result<()> {
+    let channel = app.pd_channel().await?;
+
+    let current_block_height = TendermintProxyServiceClient::new(channel.clone())
+        .get_status(GetStatusRequest::default())
+        .await?
+        .into_inner()
+        .sync_info
+        .ok_or_else(|| anyhow::anyhow!("missing sync_info"))?
+        .latest_block_height;
+
+    let mut stake_client = StakeQueryServiceClient::new(channel.clone());
+    let info = stake_client
+        .validator_info(ValidatorInfoRequest {
+            show_inactive: true,
+            ..Default::default()
+        })
+        .await?
+        .into_inner()
+        .try_collect::<Vec<_>>()
+        .await?
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<Result<Vec<validator::Info>, _>>()?
+        .into_iter()
+        .find(|info| info.validator.identity_key == identity_key)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "validator {identity_key} has no definition on chain; upload one with `pcli validator definition upload`"
+            )
+        })?;
+
+    // Uptime is only tracked for validators that have been active.
+    let uptime: Option<Uptime> = match stake_client
+        .validator_uptime(ValidatorUptimeRequest {
+            identity_key: Some(identity_key.into()),
+        })
+        .await
+    {
+        Ok(response) => response
+            .into_inner()
+            .uptime
+            .map(TryInto::try_into)
+            .transpose()?,
+        Err(status) if status.code() == tonic::Code::NotFound => None,
+        Err(status) => return Err(status.into()),
+    };
+
+    let votes_due = votes_due(channel, identity_key).await?;
+
+    let mut table = Table::new();
+    table.load_preset(presets::NOTHING);
+    table.add_row(vec!["Name".to_string(), info.validator.name.clone()]);
+    table.add_row(vec!["Identity Key".to_string(), identity_key.to_string()]);
+    table.add_row(vec!["Website".to_string(), info.validator.website.clone()]);
+    table.add_row(vec![
+        "Enabled".to_string(),
+        info.validator.enabled.to_string(),
+    ]);
+    table.add_row(vec![
+        "Sequence Number".to_string(),
+        info.validator.sequence_number.to_string(),
+    ]);
+    table.add_row(vec!["State".to_string(), info.status.state.to_string()]);
+    table.add_row(vec![
+        "Bonding State".to_string(),
+        info.status.bonding_state.to_string(),
+    ]);
+    table.add_row(vec![
+        "Voting Power".to_string(),
+        // apply udelegation factor
+        format!("{:.3}", (info.status.voting_power.value() as f64) * 1e-6),
+    ]);
+    table.add_row(vec![
+        "Uptime".to_string(),
+        match uptime {
+            Some(uptime) => format!(
+                "missed {} of the last {} blocks, as of height {}",
+                uptime.num_missed_blocks(),
+                uptime.window_len(),
+                uptime.as_of_block_height()
+            ),
+            None => "not tracked".to_string(),
+        },
+    ]);
+    println!("{table}");
+
+    println!("\nFunding streams:");
+    let mut table = Table::new();
+    table.load_preset(presets::NOTHING);
+    table.set_header(vec!["Rate", "Recipient"]);
+    for stream in info.validator.funding_streams.iter() {
+        let recipient = match stream {
+            FundingStream::ToAddress { address, .. } => address.to_string(),
+            FundingStream::ToCommunityPool { .. } => "Community Pool".to_string(),
+        };
+        table.add_row(vec![format!("{}bps", stream.rate_bps()), recipient]);
+    }
+    println!("{table}");
+
+    if votes_due.is_empty() {
+        println!("\nNo active proposals are awaiting your vote.");
+    } else {
+        println!("\nActive proposals awaiting your vote (see `pcli validator vote`):");
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec!["Proposal", "Voting Ends", "Blocks Left", "Title"]);
+        for VoteDue {
+            proposal_id,
+            title,
+            end_block_height,
+        } in votes_due
+        {
+            table.add_row(vec![
+                format!("#{proposal_id}"),
+                end_block_height.to_string(),
+                end_block_height
+                    .saturating_sub(current_block_height)
+                    .to_string(),
+                title,
+            ]);
+        }
+        println!("{table}");
+    }
+
+    Ok(())
+}
+
+/// An active proposal the validator hasn't voted on yet.
+struct VoteDue {
+    proposal_id: u64,
+    title: String,
+    end_block_height: u64,
+}
+
+/// List the active proposals the given validator hasn't voted on yet.
+async fn votes_due(channel: Channel, identity_key: IdentityKey) -> Result<Vec<VoteDue>> {
+    let mut governance_client = GovernanceQueryServiceClient::new(channel);
+    let proposals = governance_client
+        .proposal_list(ProposalListRequest {
+            inactive: false,
+            ..Default::default()
+        })
+        .await?
+        .into_inner()
+        .try_collect::<Vec<_>>()
+        .await?;
+    let mut votes_due = Vec::new();
+    for response in proposals {
+        let proposal = response
+            .proposal
+            .ok_or_else(|| anyhow::anyhow!("missing proposal"))?;
+        let voted = governance_client
+            .validator_votes(ValidatorVotesRequest {
+                proposal_id: proposal.id,
+                ..Default::default()
+            })
+            .await?
+            .into_inner()
+            .try_collect::<Vec<_>>()
+            .await?
+            .into_iter()
+            .any(|vote| vote.identity_key == Some(identity_key.into()));
+        if !voted {
+            votes_due.push(VoteDue {
+                proposal_id: proposal.id,
+                title: proposal.title,
+                end_block_height: response.end_block_height,
+            });
+        }
+    }
+    Ok(votes_due)
+}
+
+/// Sign a validator vote with the wallet's spend key, and submit it in a transaction.
+async fn cast_vote(
+    app: &mut App,
+    sk: &SpendKey,
+    proposal: u64,
+    vote: Vote,
+    reason: &str,
+    fee: u64,
+    source: u32,
+) -> Result<()> {
+    // TODO: support submitting a separate governance key.
+    let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
+    // Currently this is always just copied from the identity key
+    let governance_key = GovernanceKey(identity_key.0);
+
+    if reason.len() > MAX_VALIDATOR_VOTE_REASON_LENGTH {
+        anyhow::bail!("validator vote reason is too long, max 1024 bytes");
+    }
+
+    // Construct the vote body
+    let body = ValidatorVoteBody {
+        proposal,
+        vote,
+        identity_key,
+        governance_key,
+        reason: ValidatorVoteReason(reason.to_string()),
+    };
+
+    // TODO: support signing with a separate governance key
+    let governance_auth_key = sk.spend_auth_key();
+
+    // Generate an authorizing signature with the governance key for the vote body
+    let body_bytes = body.encode_to_vec();
+    let auth_sig = governance_auth_key.sign(OsRng, &body_bytes);
+
+    let vote = ValidatorVote { body, auth_sig };
+
+    // Construct a new transaction and include the validator vote.
+    let fee = Fee::from_staking_token_amount(fee.into());
+
+    let plan = plan::validator_vote(
+        app.view
+            .as_mut()
+            .context("view service must be initialized")?,
+        OsRng,
+        vote,
+        fee,
+        AddressIndex::new(source),
+    )
+    .await?;
+    app.build_and_submit_transaction(plan).await?;
+
+    Ok(())
+}
+
 /// Generate a new ED25519 keypair for use with Tendermint.
 fn generate_new_tendermint_keypair() -> anyhow::Result<tendermint::PrivateKey> {
     let signing_key = ed25519_consensus::SigningKey::new(OsRng);
@@ -0,0 +1,185 @@
+use std::{collections::BTreeSet, time::Duration};
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use serde::Deserialize;
+
+use penumbra_governance::Vote;
+use penumbra_keys::keys::SpendKey;
+use penumbra_proto::util::tendermint_proxy::v1::{
+    tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+};
+use penumbra_stake::IdentityKey;
+
+use super::{cast_vote, votes_due, VoteDue};
+use crate::App;
+
+/// How a validator operator wants to be alerted about proposals, and whether to abstain
+/// automatically, as read from the policy file given to `pcli validator watch-proposals`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WatchPolicy {
+    /// Commands to run for every alert, each given as a program followed by its arguments.
+    ///
+    /// The alert is described to the command in the `PENUMBRA_ALERT` (one of `new`, `reminder`,
+    /// or `abstained`), `PENUMBRA_PROPOSAL_ID`, `PENUMBRA_PROPOSAL_TITLE`,
+    /// `PENUMBRA_PROPOSAL_END_HEIGHT` and `PENUMBRA_CURRENT_HEIGHT` environment variables.
+    #[serde(default)]
+    pub hooks: Vec<Vec<String>>,
+    /// How many blocks before voting ends to send a reminder, if no vote was cast.
+    #[serde(default = "default_remind_blocks")]
+    pub remind_blocks: u64,
+    /// If set, abstain this many blocks before voting ends, if no vote was cast.
+    #[serde(default)]
+    pub auto_abstain_blocks: Option<u64>,
+    /// The reason given with automatic abstentions.
+    #[serde(default = "default_abstain_reason")]
+    pub abstain_reason: String,
+    /// The fee for automatic abstentions (paid in upenumbra).
+    #[serde(default)]
+    pub fee: u64,
+    /// Only spend funds originally received by this account for automatic abstentions.
+    #[serde(default)]
+    pub source: u32,
+}
+
+fn default_remind_blocks() -> u64 {
+    // About a day, at five seconds per block.
+    17_280
+}
+
+fn default_abstain_reason() -> String {
+    "Abstained automatically, as no vote was cast before the deadline.".to_string()
+}
+
+impl WatchPolicy {
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read policy file {path}"))?;
+        toml::from_str(&contents).with_context(|| format!("cannot parse policy file {path}"))
+    }
+}
+
+/// What an alert is about.
+#[derive(Debug, Clone, Copy)]
+enum Alert {
+    /// A proposal awaiting a vote was seen for the first time.
+    New,
+    /// Voting on a proposal ends soon, and no vote was cast.
+    Reminder,
+    /// No vote was cast shortly before voting ended, so the validator abstained.
+    Abstained,
+}
+
+impl Alert {
+    fn name(&self) -> &'static str {
+        match self {
+            Alert::New => "new",
+            Alert::Reminder => "reminder",
+            Alert::Abstained => "abstained",
+        }
+    }
+}
+
+/// Poll for proposals awaiting the validator's vote every `interval`, until interrupted.
+pub async fn watch_proposals(
+    app: &mut App,
+    sk: &SpendKey,
+    policy: &WatchPolicy,
+    interval: Duration,
+) -> Result<()> {
+    let identity_key = IdentityKey(*sk.full_viewing_key().spend_verification_key());
+    let mut announced = BTreeSet::new();
+    let mut reminded = BTreeSet::new();
+    let mut abstained = BTreeSet::new();
+
+    loop {
+        let channel = app.pd_channel().await?;
+        let current_height = TendermintProxyServiceClient::new(channel.clone())
+            .get_status(GetStatusRequest::default())
+            .await?
+            .into_inner()
+            .sync_info
+            .ok_or_else(|| anyhow::anyhow!("missing sync_info"))?
+            .latest_block_height;
+
+        for due in votes_due(channel, identity_key).await? {
+            let blocks_left = due.end_block_height.saturating_sub(current_height);
+
+            if announced.insert(due.proposal_id) {
+                alert(policy, Alert::New, &due, current_height).await;
+            }
+            if blocks_left <= policy.remind_blocks && reminded.insert(due.proposal_id) {
+                alert(policy, Alert::Reminder, &due, current_height).await;
+            }
+            if policy
+                .auto_abstain_blocks
+                .map_or(false, |blocks| blocks_left <= blocks)
+                && !abstained.contains(&due.proposal_id)
+            {
+                // The vote is planned with the wallet's notes, so catch up with the chain first.
+                app.sync().await?;
+                match cast_vote(
+                    app,
+                    sk,
+                    due.proposal_id,
+                    Vote::Abstain,
+                    &policy.abstain_reason,
+                    policy.fee,
+                    policy.source,
+                )
+                .await
+                {
+                    Ok(()) => {
+                        abstained.insert(due.proposal_id);
+                        alert(policy, Alert::Abstained, &due, current_height).await;
+                    }
+                    // Keep watching, so that the vote is retried on the next poll.
+                    Err(e) => {
+                        eprintln!("failed to abstain on proposal #{}: {e:#}", due.proposal_id)
+                    }
+                }
+            }
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Print the alert, and run each of the policy's hooks for it.
+///
+/// Failing hooks are reported, but don't stop the watch.
+async fn alert(policy: &WatchPolicy, alert: Alert, due: &VoteDue, current_height: u64) {
+    let message = match alert {
+        Alert::New => "awaits your vote",
+        Alert::Reminder => "still awaits your vote, and voting ends soon",
+        Alert::Abstained => "had no vote cast, so you abstained",
+    };
+    println!(
+        "[height {current_height}] proposal #{} ({}) {message}; voting ends at height {}",
+        due.proposal_id, due.title, due.end_block_height
+    );
+
+    for hook in &policy.hooks {
+        let Some((program, args)) = hook.split_first() else {
+            continue;
+        };
+        let status = tokio::process::Command::new(program)
+            .args(args)
+            .env("PENUMBRA_ALERT", alert.name())
+            .env("PENUMBRA_PROPOSAL_ID", due.proposal_id.to_string())
+            .env("PENUMBRA_PROPOSAL_TITLE", &due.title)
+            .env(
+                "PENUMBRA_PROPOSAL_END_HEIGHT",
+                due.end_block_height.to_string(),
+            )
+            .env("PENUMBRA_CURRENT_HEIGHT", current_height.to_string())
+            .status()
+            .await;
+        match status {
+            Ok(status) if status.success() => {}
+            Ok(status) => eprintln!("alert hook {program} exited with {status}"),
+            Err(e) => eprintln!("failed to run alert hook {program}: {e}"),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use penumbra_asset::{asset, Value};
+use penumbra_keys::Address;
+use penumbra_proto::{util::tendermint_proxy::v1::GetTxRequest, DomainType};
+use penumbra_transaction::{PaymentReceipt, Transaction};
+use serde::Serialize;
+
+use crate::{
+    output::{Output, ValueOutput},
+    App,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum VerifyCmd {
+    /// Verify a payment receipt, exported by the sender with `pcli view receipt`, against the
+    /// transaction on chain.
+    ///
+    /// This decrypts the receipted output with the key disclosed by the receipt, so it needs no
+    /// keys of the sender or the recipient, and prints who the output paid and how much. If the
+    /// payment is claimed to have been a given value or to a given address, those are checked
+    /// too.
+    Receipt {
+        /// The receipt, in JSON.
+        file: Utf8PathBuf,
+        /// Check that the payment was of this value, e.g. `10penumbra`.
+        #[clap(long)]
+        value: Option<Value>,
+        /// Check that the payment was to this address.
+        #[clap(long)]
+        address: Option<Address>,
+    },
+}
+
+impl VerifyCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            // Verification fetches the transaction from the node, but doesn't use the view.
+            VerifyCmd::Receipt { .. } => true,
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            VerifyCmd::Receipt {
+                file,
+                value,
+                address,
+            } => {
+                let receipt: PaymentReceipt = serde_json::from_str(
+                    &std::fs::read_to_string(file)
+                        .with_context(|| format!("cannot read receipt from {file}"))?,
+                )
+                .with_context(|| format!("{file} is not a payment receipt"))?;
+                let id = receipt.transaction_id;
+
+                let rsp = app
+                    .tendermint_proxy_client()
+                    .await?
+                    .get_tx(GetTxRequest {
+                        hash: id.0.to_vec(),
+                        prove: false,
+                    })
+                    .await
+                    .with_context(|| format!("cannot fetch transaction {id} from fullnode"))?
+                    .into_inner();
+                let transaction = Transaction::decode(rsp.tx.as_slice())?;
+                let note = receipt.verify(&transaction)?;
+
+                let assets = asset::Cache::with_known_assets();
+                if let Some(value) = value {
+                    anyhow::ensure!(
+                        note.value() == *value,
+                        "receipt shows a payment of {}, not {}",
+                        note.value().format(&assets),
+                        value.format(&assets)
+                    );
+                }
+                if let Some(address) = address {
+                    anyhow::ensure!(
+                        note.address() == *address,
+                        "receipt shows a payment to {}, not {address}",
+                        note.address()
+                    );
+                }
+
+                app.print(&VerifiedReceipt {
+                    transaction_id: id.to_string(),
+                    height: rsp.height,
+                    address: note.address().to_string(),
+                    value: ValueOutput::new(note.value(), &assets),
+                })
+            }
+        }
+    }
+}
+
+/// The payment shown by a verified receipt.
+#[derive(Debug, Serialize)]
+struct VerifiedReceipt {
+    transaction_id: String,
+    height: u64,
+    address: String,
+    value: ValueOutput,
+}
+
+impl Output for VerifiedReceipt {
+    fn render(&self) -> Result<String> {
+        Ok(format!(
+            "Verified: transaction {} at height {} paid {} to {}",
+            self.transaction_id, self.height, self.value, self.address
+        ))
+    }
+}
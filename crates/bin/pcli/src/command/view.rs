@@ -2,20 +2,44 @@ use anyhow::Result;
 
 use address::AddressCmd;
 use balance::BalanceCmd;
+use encrypt::EncryptCmd;
+use ephemeral_addresses::EphemeralAddressesCmd;
+use export_addresses::ExportAddressesCmd;
+use fee_grantee::FeeGranteeCmd;
+use frontier::FrontierCmd;
+use history::HistoryFormat;
+use lp::LpCmd;
+use receipt::ReceiptCmd;
+use rescan::RescanCmd;
+use spent_by::SpentByCmd;
 use staked::StakedCmd;
+use sync_filter::SyncFilterCmd;
 use transaction_hashes::TransactionHashesCmd;
 use tx::TxCmd;
+use tx_many::TxManyCmd;
 use wallet_id::WalletIdCmd;
 
-use crate::App;
+use crate::{config::PcliConfig, App};
 
 mod address;
 mod balance;
+mod encrypt;
+mod ephemeral_addresses;
+mod export_addresses;
+mod fee_grantee;
+mod frontier;
+mod history;
+mod lp;
+mod receipt;
+mod rescan;
+mod spent_by;
 mod staked;
+mod sync_filter;
 mod wallet_id;
 
 pub mod transaction_hashes;
 mod tx;
+mod tx_many;
 
 #[derive(Debug, clap::Subcommand)]
 pub enum ViewCmd {
@@ -23,12 +47,46 @@ pub enum ViewCmd {
     WalletId(WalletIdCmd),
     /// View one of your addresses, either by numerical index, or a random ephemeral one.
     Address(AddressCmd),
+    /// List the ephemeral addresses handed out by the view service, and the transactions that
+    /// used them, to audit address reuse.
+    EphemeralAddresses(EphemeralAddressesCmd),
+    /// Derives a sequence of deposit addresses for some accounts, and exports them with their
+    /// detection data, as CSV or JSON.
+    ///
+    /// The same addresses are derived every time, and only the full viewing key is needed, so
+    /// an exchange can pre-generate deposit addresses on a cold system.
+    ExportAddresses(ExportAddressesCmd),
     /// View your account balances.
     Balance(BalanceCmd),
     /// View your staked delegation tokens.
     Staked(StakedCmd),
+    /// View the key sponsors can make fee grants to, for one of your accounts.
+    FeeGrantee(FeeGranteeCmd),
+    /// View your liquidity positions.
+    #[clap(subcommand)]
+    Lp(LpCmd),
     /// Deletes all scanned data and local state, while leaving keys untouched.
     Reset(Reset),
+    /// Forgets scanned data from a given height onwards and scans it again.
+    ///
+    /// This is useful after importing keys, or to recover from errors in detection,
+    /// without discarding everything scanned before that height.
+    Rescan(RescanCmd),
+    /// Encrypts the local view database with a passphrase.
+    ///
+    /// Existing view data is encrypted in place, and the passphrase is asked for whenever the
+    /// view database is opened from then on.
+    Encrypt(EncryptCmd),
+    /// Restricts the view service to storing notes of some accounts and assets.
+    ///
+    /// This saves storage for special-purpose wallets, such as a liquidity bot that only
+    /// trades one pair. Stored notes the new filter excludes are forgotten, and if it allows
+    /// notes the old one didn't, they're backfilled by rescanning from the wallet birthday.
+    SyncFilter(SyncFilterCmd),
+    /// Exports or imports the wallet's synced state, to move a wallet to another machine without
+    /// rescanning the chain.
+    #[clap(subcommand)]
+    Frontier(FrontierCmd),
     /// Synchronizes the client, privately scanning the chain state.
     ///
     /// `pcli` syncs automatically prior to any action requiring chain state,
@@ -39,6 +97,16 @@ pub enum ViewCmd {
     ListTransactionHashes(TransactionHashesCmd),
     /// Displays a transaction's details by hash.
     Tx(TxCmd),
+    /// Displays the details of many transactions, by hashes listed in a file.
+    ///
+    /// The transactions are fetched concurrently, and printed in the order of the file, or as a
+    /// single JSON array with `--output json`.
+    TxMany(TxManyCmd),
+    /// Exports a receipt for a payment made by one of your transactions, which anyone can check
+    /// with `pcli verify receipt`, without any of your keys.
+    Receipt(ReceiptCmd),
+    /// Shows which transaction spent one of your notes, by note commitment or nullifier.
+    SpentBy(SpentByCmd),
 }
 
 impl ViewCmd {
@@ -46,12 +114,25 @@ impl ViewCmd {
         match self {
             ViewCmd::WalletId(wallet_id_cmd) => wallet_id_cmd.offline(),
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
+            ViewCmd::EphemeralAddresses(ephemeral_addresses_cmd) => {
+                ephemeral_addresses_cmd.offline()
+            }
+            ViewCmd::ExportAddresses(export_addresses_cmd) => export_addresses_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
             ViewCmd::Staked(staked_cmd) => staked_cmd.offline(),
+            ViewCmd::FeeGrantee(fee_grantee_cmd) => fee_grantee_cmd.offline(),
+            ViewCmd::Lp(lp_cmd) => lp_cmd.offline(),
             ViewCmd::Reset(_) => true,
+            ViewCmd::Rescan(rescan_cmd) => rescan_cmd.offline(),
+            ViewCmd::Encrypt(_) => true,
+            ViewCmd::SyncFilter(sync_filter_cmd) => sync_filter_cmd.offline(),
+            ViewCmd::Frontier(frontier_cmd) => frontier_cmd.offline(),
             ViewCmd::Sync => false,
             ViewCmd::ListTransactionHashes(transactions_cmd) => transactions_cmd.offline(),
             ViewCmd::Tx(tx_cmd) => tx_cmd.offline(),
+            ViewCmd::TxMany(tx_many_cmd) => tx_many_cmd.offline(),
+            ViewCmd::Receipt(receipt_cmd) => receipt_cmd.offline(),
+            ViewCmd::SpentBy(spent_by_cmd) => spent_by_cmd.offline(),
         }
     }
 
@@ -61,17 +142,32 @@ impl ViewCmd {
 
         match self {
             ViewCmd::WalletId(wallet_id_cmd) => {
-                wallet_id_cmd.exec(&full_viewing_key)?;
+                app.print(&wallet_id_cmd.exec(&full_viewing_key)?)?;
             }
             ViewCmd::Tx(tx_cmd) => {
                 tx_cmd.exec(app).await?;
             }
-            ViewCmd::ListTransactionHashes(transactions_cmd) => {
-                let view_client = app.view();
-                transactions_cmd
-                    .exec(&full_viewing_key, view_client)
-                    .await?;
+            ViewCmd::TxMany(tx_many_cmd) => {
+                tx_many_cmd.exec(app).await?;
+            }
+            ViewCmd::Receipt(receipt_cmd) => {
+                receipt_cmd.exec(app).await?;
             }
+            ViewCmd::SpentBy(spent_by_cmd) => {
+                spent_by_cmd.exec(app).await?;
+            }
+            ViewCmd::ListTransactionHashes(transactions_cmd) => match transactions_cmd.format {
+                Some(HistoryFormat::Csv) => {
+                    print!("{}", transactions_cmd.export_csv(app.view()).await?);
+                }
+                None => {
+                    let view_client = app.view();
+                    let transactions = transactions_cmd
+                        .exec(&full_viewing_key, view_client)
+                        .await?;
+                    app.print(&transactions)?;
+                }
+            },
             ViewCmd::Sync => {
                 // We set needs_sync() -> true, so by this point, we have
                 // already synchronized the wallet above, so we can just return.
@@ -79,19 +175,50 @@ impl ViewCmd {
             ViewCmd::Reset(_reset) => {
                 // The wallet has already been reset by a short-circuiting path.
             }
+            ViewCmd::Rescan(_rescan_cmd) => {
+                // The scanned data was invalidated before the view service was started,
+                // and we have already rescanned it above, so we can just return.
+            }
+            ViewCmd::Encrypt(_encrypt_cmd) => {
+                // The view data has already been encrypted by a short-circuiting path.
+            }
+            ViewCmd::SyncFilter(_sync_filter_cmd) => {
+                // The filter was updated before the view service was started, which applied it,
+                // and we have already synced with it above, so we can just return.
+            }
+            ViewCmd::Frontier(frontier_cmd) => {
+                frontier_cmd.exec(app.view()).await?;
+            }
             ViewCmd::Address(address_cmd) => {
-                address_cmd.exec(&full_viewing_key)?;
+                let address = address_cmd.exec(app, &full_viewing_key).await?;
+                app.print(&address)?;
+            }
+            ViewCmd::FeeGrantee(fee_grantee_cmd) => {
+                app.print(&fee_grantee_cmd.exec(&full_viewing_key)?)?;
+            }
+            ViewCmd::EphemeralAddresses(ephemeral_addresses_cmd) => {
+                let view_client = app.view();
+                let ephemeral_addresses = ephemeral_addresses_cmd.exec(view_client).await?;
+                app.print(&ephemeral_addresses)?;
+            }
+            ViewCmd::ExportAddresses(export_addresses_cmd) => {
+                export_addresses_cmd.exec(app, &full_viewing_key)?;
             }
             ViewCmd::Balance(balance_cmd) => {
                 let view_client = app.view();
-                balance_cmd.exec(view_client).await?;
+                let balance = balance_cmd.exec(view_client).await?;
+                app.print(&balance)?;
+            }
+            ViewCmd::Lp(lp_cmd) => {
+                lp_cmd.exec(app).await?;
             }
             ViewCmd::Staked(staked_cmd) => {
                 let channel = app.pd_channel().await?;
                 let view_client = app.view();
-                staked_cmd
+                let staked = staked_cmd
                     .exec(&full_viewing_key, view_client, channel)
                     .await?;
+                app.print(&staked)?;
             }
         }
 
@@ -100,11 +227,26 @@ impl ViewCmd {
 }
 
 #[derive(Debug, clap::Parser)]
-pub struct Reset;
+pub struct Reset {
+    /// Also forget the wallet's birthday and checkpoint, so that the next sync scans from genesis.
+    #[clap(long)]
+    from_genesis: bool,
+}
 
 impl Reset {
     pub fn exec(&self, data_path: impl AsRef<camino::Utf8Path>) -> Result<()> {
         tracing::info!("resetting client state");
+        if self.from_genesis {
+            let config_path = data_path.as_ref().join(crate::CONFIG_FILE_NAME);
+            let mut config = PcliConfig::load(&config_path)?;
+            let cleared_birthday = config.birthday_height.take().is_some();
+            let cleared_checkpoint = config.checkpoint.take().is_some();
+            if cleared_birthday || cleared_checkpoint {
+                config.save(&config_path)?;
+                println!("Cleared wallet birthday, the next sync will scan from genesis");
+            }
+        }
+
         let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
         if view_path.is_file() {
             std::fs::remove_file(&view_path)?;
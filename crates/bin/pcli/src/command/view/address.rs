@@ -1,8 +1,14 @@
 use anyhow::Result;
 use base64::Engine;
-use rand_core::OsRng;
+use serde::Serialize;
 
 use penumbra_keys::{Address, FullViewingKey};
+use penumbra_view::ViewClient;
+
+use crate::{
+    output::{Output, Text},
+    App,
+};
 
 #[derive(Debug, clap::Parser)]
 pub struct AddressCmd {
@@ -10,6 +16,8 @@ pub struct AddressCmd {
     #[clap(default_value = "0")]
     address_or_index: String,
     /// Generate an ephemeral address instead of an indexed one.
+    ///
+    /// It's generated by the view service, which records it for `pcli view ephemeral-addresses`.
     #[clap(short, long)]
     ephemeral: bool,
     /// Output in base64 format, instead of the default bech32.
@@ -22,31 +30,31 @@ pub struct AddressCmd {
 
 impl AddressCmd {
     /// Determine if this command requires a network sync before it executes.
+    ///
+    /// Ephemeral addresses are generated by the view service, so that it can record them.
     pub fn offline(&self) -> bool {
-        true
+        !self.ephemeral
     }
 
-    pub fn exec(&self, fvk: &FullViewingKey) -> Result<()> {
+    pub async fn exec(&self, app: &mut App, fvk: &FullViewingKey) -> Result<AddressOutput> {
         let index: Result<u32, _> = self.address_or_index.parse();
 
         if let Ok(index) = index {
             //index provided
 
-            let (address, _dtk) = match self.ephemeral {
-                false => fvk.incoming().payment_address(index.into()),
-                true => fvk.incoming().ephemeral_address(OsRng, index.into()),
+            let address = match self.ephemeral {
+                false => fvk.incoming().payment_address(index.into()).0,
+                true => app.view().ephemeral_address(index.into()).await?,
             };
 
-            if self.base64 {
-                println!(
-                    "{}",
-                    base64::engine::general_purpose::STANDARD.encode(address.to_vec()),
-                );
+            let encoded = if self.base64 {
+                base64::engine::general_purpose::STANDARD.encode(address.to_vec())
             } else if self.compat {
-                println!("{}", address.compat_encoding());
+                address.compat_encoding()
             } else {
-                println!("{}", address);
+                address.to_string()
             };
+            Ok(AddressOutput::Address(Text(encoded)))
         } else {
             //address or nothing provided
 
@@ -55,19 +63,59 @@ impl AddressCmd {
                 .parse()
                 .map_err(|_| anyhow::anyhow!("Provided address is invalid."))?;
 
-            match fvk.address_index(&address) {
-                Some(address_index) => println!(
-                    "Address is viewable with this full viewing key. Account index is {0}. {1}",
-                    address_index.account,
-                    match address_index.randomizer != [0u8; 12] {
-                        true => "Address is ephemeral.",
-                        false => "",
-                    }
-                ),
-                None => println!("Address is not viewable with this full viewing key."),
-            }
+            let address_index = fvk.address_index(&address);
+            Ok(AddressOutput::Ownership(Ownership {
+                viewable: address_index.is_some(),
+                account: address_index.map(|index| index.account),
+                ephemeral: address_index.map(|index| index.randomizer != [0u8; 12]),
+                sequence_number: address_index
+                    .and_then(|index| index.sequence_number())
+                    .filter(|&n| n != 0),
+            }))
         }
+    }
+}
+
+/// Either an address, or whether a given address belongs to the wallet.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum AddressOutput {
+    Address(Text),
+    Ownership(Ownership),
+}
+
+/// Whether an address is viewable with the wallet's full viewing key.
+#[derive(Debug, Serialize)]
+pub struct Ownership {
+    viewable: bool,
+    account: Option<u32>,
+    ephemeral: Option<bool>,
+    /// The sequence number, for addresses made by `pcli view export-addresses`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sequence_number: Option<u64>,
+}
 
-        Ok(())
+impl Output for AddressOutput {
+    fn render(&self) -> Result<String> {
+        match self {
+            AddressOutput::Address(address) => address.render(),
+            AddressOutput::Ownership(Ownership {
+                account: Some(account),
+                ephemeral,
+                sequence_number,
+                ..
+            }) => Ok(format!(
+                "Address is viewable with this full viewing key. Account index is {0}. {1}",
+                account,
+                match (ephemeral, sequence_number) {
+                    (_, Some(n)) => format!("Address has sequence number {n}."),
+                    (Some(true), _) => "Address is ephemeral.".to_string(),
+                    _ => String::new(),
+                }
+            )),
+            AddressOutput::Ownership(_) => {
+                Ok("Address is not viewable with this full viewing key.".to_string())
+            }
+        }
     }
 }
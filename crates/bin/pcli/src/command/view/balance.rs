@@ -1,9 +1,14 @@
 use anyhow::Result;
-use comfy_table::{presets, Table};
 
 use penumbra_keys::AddressView;
 use penumbra_sct::CommitmentSource;
 use penumbra_view::ViewClient;
+use serde::Serialize;
+
+use crate::{
+    output::{Output, ValueOutput},
+    render,
+};
 
 #[derive(Debug, clap::Args)]
 pub struct BalanceCmd {
@@ -17,18 +22,11 @@ impl BalanceCmd {
         false
     }
 
-    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<BalanceOutput> {
         let asset_cache = view.assets().await?;
 
-        // Initialize the table
-        let mut table = Table::new();
-        table.load_preset(presets::NOTHING);
-
-        let notes = view.unspent_notes_by_account_and_asset().await?;
-
         if self.by_note {
-            table.set_header(vec!["Account", "Value", "Source", "Sender"]);
-
+            let notes = view.unspent_notes_by_account_and_asset().await?;
             let rows = notes
                 .iter()
                 .flat_map(|(index, notes_by_asset)| {
@@ -53,47 +51,90 @@ impl BalanceCmd {
                  */
                 ;
 
-            for (index, value, source, return_address) in rows {
-                table.add_row(vec![
-                    format!("# {}", index),
-                    value.format(&asset_cache),
-                    format_source(&source),
-                    format_return_address(&return_address),
-                ]);
-            }
-
-            println!("{table}");
-
-            return Ok(());
-        } else {
-            table.set_header(vec!["Account", "Amount"]);
-
-            let rows = notes
-                .iter()
-                .flat_map(|(index, notes_by_asset)| {
-                    // Sum the notes for each asset:
-                    notes_by_asset.iter().map(|(asset, notes)| {
-                        let sum: u128 = notes
-                            .iter()
-                            .map(|record| u128::from(record.note.amount()))
-                            .sum();
-                        (*index, asset.value(sum.into()))
-                    })
+            Ok(BalanceOutput::Notes(
+                rows.map(|(index, value, source, return_address)| NoteBalance {
+                    account: index,
+                    value: ValueOutput::new(value, &asset_cache),
+                    source: format_source(&source),
+                    sender: format_return_address(&return_address),
                 })
+                .collect(),
+            ))
+        } else {
+            // The view service sums up the notes for each account and asset.
+            let rows = view
+                .balance_matrix()
+                .await?
+                .into_iter()
+                .map(|(index, value_view, _note_count)| (index.account, value_view.value()))
                 // Exclude withdrawn LPNFTs.
                 .filter(|(_, value)| match asset_cache.get(&value.asset_id) {
                     None => true,
                     Some(denom) => !denom.is_withdrawn_position_nft(),
                 });
 
-            for (index, value) in rows {
-                table.add_row(vec![format!("# {}", index), value.format(&asset_cache)]);
-            }
+            Ok(BalanceOutput::Totals(
+                rows.map(|(index, value)| AccountBalance {
+                    account: index,
+                    value: ValueOutput::new(value, &asset_cache),
+                })
+                .collect(),
+            ))
+        }
+    }
+}
+
+/// The wallet's balances, either summed per account and asset, or listed per note.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum BalanceOutput {
+    Totals(Vec<AccountBalance>),
+    Notes(Vec<NoteBalance>),
+}
 
-            println!("{table}");
+#[derive(Debug, Serialize)]
+pub struct AccountBalance {
+    account: u32,
+    value: ValueOutput,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NoteBalance {
+    account: u32,
+    value: ValueOutput,
+    source: String,
+    sender: String,
+}
 
-            return Ok(());
+impl Output for BalanceOutput {
+    fn render(&self) -> Result<String> {
+        // Initialize the table
+        let mut table = render::table();
+
+        match self {
+            BalanceOutput::Totals(balances) => {
+                table.set_header(vec!["Account", "Amount"]);
+                for balance in balances {
+                    table.add_row(vec![
+                        format!("# {}", balance.account),
+                        balance.value.to_string(),
+                    ]);
+                }
+            }
+            BalanceOutput::Notes(notes) => {
+                table.set_header(vec!["Account", "Value", "Source", "Sender"]);
+                for note in notes {
+                    table.add_row(vec![
+                        format!("# {}", note.account),
+                        note.value.to_string(),
+                        note.source.clone(),
+                        note.sender.clone(),
+                    ]);
+                }
+            }
         }
+
+        Ok(table.to_string())
     }
 }
 
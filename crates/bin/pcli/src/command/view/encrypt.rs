@@ -0,0 +1,49 @@
+use anyhow::Result;
+use camino::Utf8Path;
+
+use penumbra_view::Storage;
+
+use crate::config::{prompt_view_passphrase, PcliConfig};
+
+#[derive(Debug, clap::Parser)]
+pub struct EncryptCmd {}
+
+impl EncryptCmd {
+    /// Encrypts the existing view data, if any, and records in the config that the view
+    /// database is encrypted, so that a database created later is encrypted too.
+    ///
+    /// Like `view reset`, this takes the home dir directly, and should not be invoked
+    /// when there's a view service running.
+    pub async fn exec(&self, data_path: impl AsRef<Utf8Path>) -> Result<()> {
+        let config_path = data_path.as_ref().join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+        if config.view_url.is_some() {
+            anyhow::bail!("encryption is only supported with a local view service");
+        }
+        if config.encrypt_view {
+            anyhow::bail!("the view database is already encrypted");
+        }
+
+        let passphrase = prompt_view_passphrase("Enter new view database passphrase: ")?;
+        if passphrase.is_empty() {
+            anyhow::bail!("the passphrase must not be empty");
+        }
+        if std::env::var("PENUMBRA_PCLI_VIEW_PASSPHRASE").is_err()
+            && prompt_view_passphrase("Confirm passphrase: ")? != passphrase
+        {
+            anyhow::bail!("the passphrases don't match");
+        }
+
+        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
+        if view_path.is_file() {
+            Storage::encrypt(&view_path, passphrase).await?;
+            println!("Encrypted view data at {view_path}");
+        } else {
+            println!("No view data exists yet, it will be encrypted when it's created");
+        }
+
+        config.encrypt_view = true;
+        config.save(&config_path)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::Result;
+use comfy_table::{presets, Table};
+use penumbra_keys::Address;
+use penumbra_transaction::txhash::TransactionId;
+use penumbra_view::ViewClient;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::output::Output;
+
+#[derive(Debug, clap::Args)]
+pub struct EphemeralAddressesCmd {
+    /// Only list the ephemeral addresses of this account.
+    #[clap(long)]
+    pub account: Option<u32>,
+}
+
+impl EphemeralAddressesCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<EphemeralAddresses> {
+        let records = view.ephemeral_addresses(self.account).await?;
+
+        Ok(EphemeralAddresses(
+            records
+                .into_iter()
+                .map(|record| EphemeralAddress {
+                    account: record.address_index.account,
+                    height_generated: record.height_generated,
+                    address: record.address,
+                    transactions: record.transactions,
+                })
+                .collect(),
+        ))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct EphemeralAddresses(Vec<EphemeralAddress>);
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub struct EphemeralAddress {
+    account: u32,
+    height_generated: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    address: Address,
+    #[serde_as(as = "Vec<DisplayFromStr>")]
+    transactions: Vec<TransactionId>,
+}
+
+impl Output for EphemeralAddresses {
+    fn render(&self) -> Result<String> {
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec!["Account", "Generated", "Address", "Transactions"]);
+
+        for record in &self.0 {
+            let transactions = if record.transactions.is_empty() {
+                "unused".to_string()
+            } else {
+                record
+                    .transactions
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            table.add_row(vec![
+                format!("# {}", record.account),
+                format!("{}", record.height_generated),
+                record.address.display_short_form(),
+                transactions,
+            ]);
+        }
+
+        Ok(table.to_string())
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use penumbra_keys::FullViewingKey;
+use serde::Serialize;
+
+use crate::{output::Output, App};
+
+/// Derives a sequence of deposit addresses for some accounts, to hand out from another system.
+///
+/// The addresses only depend on the wallet's full viewing key, so they can be derived on an
+/// offline machine, and the same list is derived every time. Funds sent to them are found by the
+/// view service like those sent to any other address of the wallet, and `pcli view address`
+/// reports the account and sequence number of each.
+#[derive(Debug, clap::Args)]
+pub struct ExportAddressesCmd {
+    /// The accounts to derive addresses for. May be given more than once.
+    #[clap(long = "account", required = true)]
+    accounts: Vec<u32>,
+    /// How many addresses to derive for each account.
+    #[clap(long, default_value = "100")]
+    count: u64,
+    /// The sequence number of the first address to derive.
+    ///
+    /// Sequence number 0 is the account's default address, the one shown by `pcli view address`,
+    /// so deposit addresses start from 1 unless this is set.
+    #[clap(long, default_value = "1")]
+    start: u64,
+    /// Also export each address's detection key, which lets its holder detect, with false
+    /// positives, which transactions pay the address.
+    #[clap(long)]
+    detection_keys: bool,
+    /// Write the addresses to this file, rather than printing them.
+    #[clap(long)]
+    file: Option<Utf8PathBuf>,
+    /// The format of the file given with `--file`.
+    #[clap(long, value_enum, default_value_t, requires = "file")]
+    format: ExportFormat,
+}
+
+/// The format of an address export file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// One address per line, with a header line naming the columns.
+    #[default]
+    Csv,
+    /// A JSON array of addresses.
+    Json,
+}
+
+impl ExportAddressesCmd {
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub fn exec(&self, app: &mut App, fvk: &FullViewingKey) -> Result<()> {
+        let end = self
+            .start
+            .checked_add(self.count)
+            .context("--start plus --count overflows")?;
+
+        let mut addresses = Vec::new();
+        for &account in &self.accounts {
+            for (index, address, dtk) in fvk
+                .incoming()
+                .sequential_addresses(account, self.start..end)
+            {
+                addresses.push(ExportedAddress {
+                    account,
+                    sequence_number: index
+                        .sequence_number()
+                        .expect("sequential index has a sequence number"),
+                    address_index: hex::encode(index.to_bytes()),
+                    address: address.to_string(),
+                    diversifier: hex::encode(address.diversifier().as_ref()),
+                    clue_key: hex::encode(address.clue_key().0),
+                    detection_key: self.detection_keys.then(|| hex::encode(dtk.to_bytes())),
+                });
+            }
+        }
+        let addresses = ExportedAddresses(addresses);
+
+        match &self.file {
+            Some(file) => {
+                let contents = match self.format {
+                    ExportFormat::Csv => addresses.to_csv(),
+                    ExportFormat::Json => serde_json::to_string_pretty(&addresses)?,
+                };
+                std::fs::write(file, contents)
+                    .with_context(|| format!("cannot write addresses to {file}"))?;
+                println!("wrote {} addresses to {file}", addresses.0.len());
+            }
+            None => app.print(&addresses)?,
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct ExportedAddresses(Vec<ExportedAddress>);
+
+/// A derived address, with what's needed to match payments to it.
+#[derive(Debug, Serialize)]
+pub struct ExportedAddress {
+    account: u32,
+    sequence_number: u64,
+    /// The hex-encoded address index, from which the address can be derived again.
+    address_index: String,
+    address: String,
+    diversifier: String,
+    clue_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detection_key: Option<String>,
+}
+
+impl ExportedAddresses {
+    /// Encodes the addresses as CSV. None of the fields can contain commas or quotes, so no
+    /// escaping is needed.
+    fn to_csv(&self) -> String {
+        let with_detection_keys = self.0.iter().any(|a| a.detection_key.is_some());
+        let mut csv =
+            String::from("account,sequence_number,address_index,address,diversifier,clue_key");
+        if with_detection_keys {
+            csv.push_str(",detection_key");
+        }
+        csv.push('\n');
+
+        for a in &self.0 {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}",
+                a.account, a.sequence_number, a.address_index, a.address, a.diversifier, a.clue_key
+            ));
+            if let Some(detection_key) = &a.detection_key {
+                csv.push(',');
+                csv.push_str(detection_key);
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl Output for ExportedAddresses {
+    fn render(&self) -> Result<String> {
+        Ok(self.to_csv().trim_end().to_string())
+    }
+}
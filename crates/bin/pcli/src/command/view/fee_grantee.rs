@@ -0,0 +1,25 @@
+use anyhow::Result;
+
+use penumbra_fee::grant::grantee_key;
+use penumbra_keys::FullViewingKey;
+
+use crate::output::Text;
+
+#[derive(Debug, clap::Parser)]
+pub struct FeeGranteeCmd {
+    /// The account to show the fee grantee key for.
+    #[clap(default_value = "0")]
+    account: u32,
+}
+
+impl FeeGranteeCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    pub fn exec(&self, fvk: &FullViewingKey) -> Result<Text> {
+        let key = grantee_key(fvk, self.account);
+        Ok(Text(hex::encode(key.to_bytes())))
+    }
+}
@@ -0,0 +1,62 @@
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+
+use penumbra_proto::DomainType;
+use penumbra_view::{ViewClient, WalletFrontier};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum FrontierCmd {
+    /// Writes the wallet's synced state commitment tree, notes and swaps to a file.
+    Export {
+        /// The file to write the frontier to.
+        file: Utf8PathBuf,
+    },
+    /// Replaces the wallet's synced state with a frontier exported from another machine, then
+    /// resumes syncing from its height instead of rescanning the chain.
+    ///
+    /// Transaction history from before the frontier isn't carried over.
+    Import {
+        /// The file to read the frontier from.
+        file: Utf8PathBuf,
+    },
+}
+
+impl FrontierCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    /// Whether the wallet should be synced before the command runs.
+    ///
+    /// Importing a frontier replaces the scanned state, so syncing first would only be wasted,
+    /// and could carry the wallet past the frontier's height.
+    pub fn needs_sync(&self) -> bool {
+        matches!(self, FrontierCmd::Export { .. })
+    }
+
+    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+        match self {
+            FrontierCmd::Export { file } => {
+                let frontier = view.export_frontier().await?;
+                std::fs::write(file, frontier.encode_to_vec())
+                    .with_context(|| format!("could not write frontier to {file}"))?;
+                println!(
+                    "Exported frontier at height {} with {} notes and {} swaps to {file}",
+                    frontier.height,
+                    frontier.notes.len(),
+                    frontier.swaps.len()
+                );
+            }
+            FrontierCmd::Import { file } => {
+                let bytes = std::fs::read(file)
+                    .with_context(|| format!("could not read frontier from {file}"))?;
+                let frontier = WalletFrontier::decode(bytes.as_slice())
+                    .with_context(|| format!("invalid frontier in {file}"))?;
+                let height = view.import_frontier(frontier).await?;
+                println!("Imported frontier, syncing will resume from height {height}");
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+//! Exports the movements of funds in the wallet's transactions, for importing into accounting
+//! software.
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{swap::SwapView, swap_claim::SwapClaimView};
+use penumbra_keys::AddressView;
+use penumbra_shielded_pool::{note::NoteView, OutputView, SpendView};
+use penumbra_transaction::ActionView;
+use penumbra_view::TransactionInfo;
+
+use crate::output::ValueOutput;
+
+/// A format the transaction history can be exported in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum HistoryFormat {
+    /// One row per movement of funds, with a header line naming the columns.
+    Csv,
+}
+
+/// A movement of funds by one of the wallet's transactions.
+#[derive(Debug)]
+pub struct HistoryRow {
+    height: u64,
+    /// The time of the block, as RFC 3339, if the view service recorded it.
+    time: Option<String>,
+    transaction_hash: String,
+    /// The kind of movement: `spend`, `output`, `swap`, `swap_claim` or `fee`.
+    kind: &'static str,
+    value: ValueOutput,
+    /// The address the funds were spent from or sent to, as the account it belongs to if it's
+    /// one of ours.
+    address: Option<String>,
+}
+
+const HEADER: &str = "height,time,transaction_hash,kind,amount,asset_id,denom,formatted,address";
+
+/// The movements of funds visible in a transaction: notes spent and created, swap inputs, and
+/// the fee, if the wallet paid it.
+pub fn history_rows(tx_info: &TransactionInfo, assets: &asset::Cache) -> Vec<HistoryRow> {
+    let row = |kind, value: Value, address: Option<&AddressView>| HistoryRow {
+        height: tx_info.height,
+        time: tx_info.block_time.map(|time| time.to_rfc3339()),
+        transaction_hash: hex::encode(tx_info.id),
+        kind,
+        value: ValueOutput::new(value, assets),
+        address: address.map(AddressView::display_short),
+    };
+    let note_row = |kind, note: &NoteView| row(kind, note.value.value(), Some(&note.address));
+
+    let mut rows = Vec::new();
+    let mut paid_fee = false;
+    for action_view in &tx_info.view.body_view.action_views {
+        match action_view {
+            ActionView::Spend(SpendView::Visible { note, .. }) => {
+                paid_fee = true;
+                rows.push(note_row("spend", note));
+            }
+            ActionView::Output(OutputView::Visible { note, .. }) => {
+                rows.push(note_row("output", note));
+            }
+            ActionView::Swap(SwapView::Visible { swap_plaintext, .. }) => {
+                let claim_address = tx_info
+                    .perspective
+                    .view_address(swap_plaintext.claim_address);
+                let inputs = [
+                    (
+                        swap_plaintext.delta_1_i,
+                        swap_plaintext.trading_pair.asset_1(),
+                    ),
+                    (
+                        swap_plaintext.delta_2_i,
+                        swap_plaintext.trading_pair.asset_2(),
+                    ),
+                ];
+                for (amount, asset_id) in inputs {
+                    if amount.value() > 0 {
+                        rows.push(row(
+                            "swap",
+                            Value { amount, asset_id },
+                            Some(&claim_address),
+                        ));
+                    }
+                }
+            }
+            ActionView::SwapClaim(SwapClaimView::Visible {
+                output_1, output_2, ..
+            }) => {
+                for output in [output_1, output_2] {
+                    if output.value.amount().value() > 0 {
+                        rows.push(note_row("swap_claim", output));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Only the wallet that funded the transaction paid its fee.
+    if paid_fee {
+        let fee = tx_info.view.body_view.fee_view.fee();
+        rows.push(row("fee", fee.value(), None));
+    }
+
+    rows
+}
+
+/// Encodes rows of the transaction history as CSV.
+pub fn to_csv(rows: &[HistoryRow]) -> String {
+    let mut csv = String::from(HEADER);
+    csv.push('\n');
+
+    for row in rows {
+        let fields = [
+            row.height.to_string(),
+            row.time.clone().unwrap_or_default(),
+            row.transaction_hash.clone(),
+            row.kind.to_string(),
+            row.value.amount.clone(),
+            row.value.asset_id.clone(),
+            row.value.denom.clone().unwrap_or_default(),
+            row.value.formatted.clone(),
+            row.address.clone().unwrap_or_default(),
+        ];
+        let fields = fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quotes a CSV field if it contains a delimiter, a quote or a line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!(csv_field("[account 0]"), "[account 0]");
+        assert_eq!(csv_field("1,5"), "\"1,5\"");
+        assert_eq!(csv_field("a \"b\""), "\"a \"\"b\"\"\"");
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use futures::TryStreamExt;
+use rand_core::OsRng;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::lp::{
+    position::{self, Position},
+    LpNft,
+};
+use penumbra_keys::keys::AddressIndex;
+use penumbra_proto::core::component::dex::v1::{
+    query_service_client::QueryServiceClient as DexQueryServiceClient, LiquidityPositionByIdRequest,
+};
+use penumbra_view::{PositionUpdate, ViewClient};
+use penumbra_wallet::plan::Planner;
+
+use crate::{
+    command::tx::FeeTier,
+    output::{Output, ValueOutput},
+    render, App,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum LpCmd {
+    /// List positions that are closed but were never withdrawn, including positions that the
+    /// DEX closed on its own, such as those closed to avoid an overflow while routing.
+    Stale(StaleCmd),
+    /// Watch positions by ID, printing each one's state and reserves as of the latest synced
+    /// block, and again whenever it changes, until interrupted.
+    ///
+    /// The positions don't need to be open yet, so the IDs printed when opening a position can
+    /// be watched before its transaction is confirmed.
+    Watch(WatchCmd),
+}
+
+impl LpCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            LpCmd::Stale(stale_cmd) => stale_cmd.offline(),
+            LpCmd::Watch(watch_cmd) => watch_cmd.offline(),
+        }
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        match self {
+            LpCmd::Stale(stale_cmd) => stale_cmd.exec(app).await,
+            LpCmd::Watch(watch_cmd) => watch_cmd.exec(app).await,
+        }
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct StaleCmd {
+    /// Only list positions whose LPNFT the wallet has held for at least this many blocks.
+    #[clap(long, default_value = "0")]
+    min_age: u64,
+    /// Withdraw all of the listed positions, in a single transaction.
+    #[clap(long)]
+    withdraw: bool,
+    /// Only spend funds originally received by the given account, to pay for the withdrawal.
+    #[clap(long = "from-account", alias = "source", default_value = "0")]
+    source: u32,
+    /// Also spend funds received by the wallet's other accounts if the source account's
+    /// funds are not enough, which links the accounts on-chain.
+    #[clap(long)]
+    allow_cross_account: bool,
+    /// The selected fee tier to multiply the fee amount by.
+    #[clap(short, long, value_enum, default_value_t)]
+    fee_tier: FeeTier,
+}
+
+impl StaleCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+        let view = app.view();
+        let asset_cache = view.assets().await?;
+        let sync_height = view.status().await?.full_sync_height;
+
+        // Positions the DEX closed on its own are still recorded as opened by the view service,
+        // since the wallet never closed them, so the chain has the final say.
+        let mut candidates = view
+            .owned_position_ids(Some(position::State::Opened), None)
+            .await?;
+        candidates.extend(
+            view.owned_position_ids(Some(position::State::Closed), None)
+                .await?,
+        );
+
+        // The height at which the wallet received each LPNFT it still holds.
+        let lpnft_heights: BTreeMap<asset::Id, u64> = view
+            .unspent_notes_by_asset_and_address()
+            .await?
+            .into_iter()
+            .filter_map(|(asset_id, notes_by_address)| {
+                notes_by_address
+                    .values()
+                    .flatten()
+                    .map(|note| note.height_created)
+                    .max()
+                    .map(|height| (asset_id, height))
+            })
+            .collect();
+
+        let mut stale = Vec::new();
+        for id in candidates {
+            let position: Position = client
+                .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                    position_id: Some(id.into()),
+                })
+                .await?
+                .into_inner()
+                .data
+                .context("missing position data")?
+                .try_into()?;
+            if position.state != position::State::Closed {
+                continue;
+            }
+
+            // Prefer the closed LPNFT, which the wallet holds if it closed the position itself.
+            let (closed_by_dex, height) = match (
+                lpnft_heights.get(&LpNft::new(id, position::State::Closed).asset_id()),
+                lpnft_heights.get(&LpNft::new(id, position::State::Opened).asset_id()),
+            ) {
+                (Some(height), _) => (false, *height),
+                (None, Some(height)) => (true, *height),
+                // The wallet no longer holds the position's LPNFT, so it can't withdraw it.
+                (None, None) => continue,
+            };
+            let age = sync_height.saturating_sub(height);
+            if age < self.min_age {
+                continue;
+            }
+
+            stale.push(StalePosition {
+                id,
+                reserves: [
+                    Value {
+                        amount: position.reserves.r1,
+                        asset_id: position.phi.pair.asset_1(),
+                    },
+                    Value {
+                        amount: position.reserves.r2,
+                        asset_id: position.phi.pair.asset_2(),
+                    },
+                ]
+                .map(|value| ValueOutput::new(value, &asset_cache)),
+                closed_by_dex,
+                age,
+                position,
+            });
+        }
+
+        app.print(&StalePositions(stale.clone()))?;
+
+        if !self.withdraw || stale.is_empty() {
+            return Ok(());
+        }
+
+        let gas_prices = app.view().gas_prices().await?;
+        let mut planner = Planner::new(OsRng);
+        planner
+            .set_gas_prices(gas_prices)
+            .set_fee_tier(self.fee_tier.into())
+            .allow_cross_account(self.allow_cross_account);
+        for StalePosition {
+            id,
+            closed_by_dex,
+            position,
+            ..
+        } in stale
+        {
+            // The wallet's LPNFT has to be exchanged for a closed one before withdrawing, which
+            // works even though the position itself is already closed.
+            if closed_by_dex {
+                planner.position_close(id);
+            }
+            planner.position_withdraw(id, position.reserves, position.phi.pair);
+        }
+        let plan = planner
+            .plan(app.view(), AddressIndex::new(self.source))
+            .await?;
+        app.build_and_submit_transaction(plan).await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct StalePositions(Vec<StalePosition>);
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize)]
+pub struct StalePosition {
+    #[serde_as(as = "DisplayFromStr")]
+    id: position::Id,
+    reserves: [ValueOutput; 2],
+    /// Whether the DEX closed the position, rather than the wallet.
+    closed_by_dex: bool,
+    /// How many blocks the wallet has held the position's LPNFT for.
+    age: u64,
+    #[serde(skip)]
+    position: Position,
+}
+
+impl Output for StalePositions {
+    fn render(&self) -> Result<String> {
+        if self.0.is_empty() {
+            return Ok("No stale positions.".to_string());
+        }
+
+        let mut table =
+            render::table_with_header(["Position", "Reserves", "Closed By", "Age (blocks)"]);
+        for stale in &self.0 {
+            table.add_row(vec![
+                stale.id.to_string(),
+                format!("{}, {}", stale.reserves[0], stale.reserves[1]),
+                if stale.closed_by_dex { "dex" } else { "you" }.to_string(),
+                stale.age.to_string(),
+            ]);
+        }
+
+        Ok(table.to_string())
+    }
+}
+
+#[derive(Debug, clap::Args)]
+pub struct WatchCmd {
+    /// The IDs of the positions to watch.
+    #[clap(required = true)]
+    position_ids: Vec<position::Id>,
+}
+
+impl WatchCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let asset_cache = app.view().assets().await?;
+        let mut updates = app
+            .view()
+            .watch_positions(self.position_ids.clone())
+            .await?;
+        while let Some(update) = updates.try_next().await? {
+            app.print(&PositionStatus::new(update, &asset_cache))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub struct PositionStatus {
+    #[serde_as(as = "DisplayFromStr")]
+    id: position::Id,
+    height: u64,
+    /// The state of the position, absent if it hasn't been opened yet.
+    state: Option<String>,
+    reserves: Option<[ValueOutput; 2]>,
+}
+
+impl PositionStatus {
+    fn new(update: PositionUpdate, asset_cache: &asset::Cache) -> Self {
+        let PositionUpdate {
+            position_id,
+            position,
+            height,
+        } = update;
+        Self {
+            id: position_id,
+            height,
+            state: position.as_ref().map(|position| position.state.to_string()),
+            reserves: position.map(|position| {
+                [
+                    Value {
+                        amount: position.reserves.r1,
+                        asset_id: position.phi.pair.asset_1(),
+                    },
+                    Value {
+                        amount: position.reserves.r2,
+                        asset_id: position.phi.pair.asset_2(),
+                    },
+                ]
+                .map(|value| ValueOutput::new(value, asset_cache))
+            }),
+        }
+    }
+}
+
+impl Output for PositionStatus {
+    fn render(&self) -> Result<String> {
+        Ok(match (&self.state, &self.reserves) {
+            (Some(state), Some(reserves)) => format!(
+                "[height {}] position {} {}, reserves {}, {}",
+                self.height, self.id, state, reserves[0], reserves[1],
+            ),
+            _ => format!(
+                "[height {}] position {} not opened yet",
+                self.height, self.id
+            ),
+        })
+    }
+}
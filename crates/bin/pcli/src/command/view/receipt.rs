@@ -0,0 +1,96 @@
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use penumbra_shielded_pool::Note;
+use penumbra_transaction::{Action, PaymentReceipt};
+use penumbra_view::ViewClient;
+
+use crate::{output::Json, App};
+
+use super::tx::parse_hash;
+
+/// Exports a receipt for a payment made by one of your transactions.
+///
+/// The receipt discloses the payment's note, and nothing else about the transaction, so that the
+/// recipient or anyone else can check it with `pcli verify receipt`.
+#[derive(Debug, clap::Args)]
+pub struct ReceiptCmd {
+    /// The hex-formatted hash of the transaction that made the payment.
+    hash: String,
+    /// The index, among all of the transaction's actions, of the output that made the payment.
+    ///
+    /// If unset, the transaction must have exactly one output paying an address outside this
+    /// wallet, like a transaction made with `pcli tx send`.
+    #[clap(long)]
+    action: Option<usize>,
+    /// Write the receipt to this file, rather than printing it.
+    #[clap(long)]
+    file: Option<Utf8PathBuf>,
+}
+
+impl ReceiptCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let hash = parse_hash(&self.hash)?;
+        let tx_info = app
+            .view()
+            .transaction_info_by_hash(hash)
+            .await
+            .with_context(|| format!("transaction {hash} not found in view service"))?;
+        let transaction = &tx_info.transaction;
+
+        let note_commitment = match self.action {
+            Some(index) => match transaction.actions().nth(index) {
+                Some(Action::Output(output)) => output.body.note_payload.note_commitment,
+                Some(_) => anyhow::bail!("action {index} of transaction {hash} is not an output"),
+                None => anyhow::bail!("transaction {hash} has no action {index}"),
+            },
+            None => {
+                // Find the outputs that didn't return change to, or otherwise pay, this wallet.
+                let fvk = &app.config.full_viewing_key;
+                let payments = transaction
+                    .outputs()
+                    .filter_map(|output| {
+                        let payload = &output.body.note_payload;
+                        let payload_key = tx_info
+                            .perspective
+                            .payload_keys
+                            .get(&payload.note_commitment)?;
+                        let note = Note::decrypt_with_payload_key(
+                            &payload.encrypted_note,
+                            payload_key,
+                            &payload.ephemeral_key,
+                        )
+                        .ok()?;
+                        fvk.address_index(&note.address())
+                            .is_none()
+                            .then_some(payload.note_commitment)
+                    })
+                    .collect::<Vec<_>>();
+                match payments[..] {
+                    [note_commitment] => note_commitment,
+                    [] => anyhow::bail!("transaction {hash} made no payments outside this wallet"),
+                    _ => anyhow::bail!(
+                        "transaction {hash} made {} payments outside this wallet, choose one with --action",
+                        payments.len()
+                    ),
+                }
+            }
+        };
+
+        let receipt = PaymentReceipt::new(transaction, &tx_info.perspective, note_commitment)
+            .context("cannot make a receipt for the payment")?;
+        match &self.file {
+            Some(file) => {
+                std::fs::write(file, serde_json::to_string_pretty(&receipt)?)
+                    .with_context(|| format!("cannot write receipt to {file}"))?;
+                println!("wrote receipt for transaction {hash} to {file}");
+            }
+            None => app.print(&Json(&receipt))?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::Result;
+use camino::Utf8Path;
+
+use penumbra_view::{RescanStart, Storage};
+
+use crate::config::PcliConfig;
+
+#[derive(Debug, clap::Parser)]
+pub struct RescanCmd {
+    /// The height to rescan from; data detected before this height is kept.
+    #[clap(long)]
+    pub from: u64,
+    /// Only forget data belonging to this account.
+    #[clap(long)]
+    pub account: Option<u32>,
+}
+
+impl RescanCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    /// Invalidates the scanned data in the requested range, so that the following sync
+    /// re-scans it.
+    ///
+    /// Like `view reset`, this takes the home dir directly, and should not be invoked
+    /// when there's a view service running.
+    pub async fn exec(&self, data_path: impl AsRef<Utf8Path>) -> Result<()> {
+        let config = PcliConfig::load(data_path.as_ref().join(crate::CONFIG_FILE_NAME))?;
+        if config.view_url.is_some() {
+            anyhow::bail!("rescan is only supported with a local view service");
+        }
+
+        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
+        if !view_path.is_file() {
+            anyhow::bail!("No view data exists at {view_path}, so there is nothing to rescan");
+        }
+
+        let storage = Storage::load(&view_path, config.view_passphrase()?).await?;
+        match storage
+            .rescan(config.grpc_url, self.from, self.account)
+            .await?
+        {
+            RescanStart::Unchanged => println!(
+                "Nothing has been synced from height {}, so there is nothing to rescan",
+                self.from
+            ),
+            RescanStart::EpochEnd(height) => println!(
+                "Cleared view data from height {}, resuming from checkpoint at height {height}",
+                self.from
+            ),
+            RescanStart::Genesis => println!(
+                "Cleared view data from height {}, rebuilding from genesis",
+                self.from
+            ),
+        }
+
+        Ok(())
+    }
+}
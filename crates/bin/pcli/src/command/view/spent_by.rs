@@ -0,0 +1,60 @@
+use anyhow::{Context, Result};
+use penumbra_asset::asset;
+use penumbra_sct::Nullifier;
+use penumbra_tct::StateCommitment;
+use penumbra_view::ViewClient;
+
+use crate::App;
+
+/// Shows which transaction spent one of your notes.
+///
+/// This is useful for tracking down where funds went, or which of two transactions spent a note
+/// that both tried to use.
+#[derive(Debug, clap::Args)]
+pub struct SpentByCmd {
+    /// The hex-formatted commitment of the note.
+    #[clap(required_unless_present = "nullifier", parse(try_from_str = StateCommitment::parse_hex))]
+    note_commitment: Option<StateCommitment>,
+    /// Look up the hex-formatted nullifier of a note, rather than its commitment.
+    #[clap(long, conflicts_with = "note_commitment", parse(try_from_str = Nullifier::parse_hex))]
+    nullifier: Option<Nullifier>,
+}
+
+impl SpentByCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let view = app.view();
+
+        let nullifier = match (self.note_commitment, self.nullifier) {
+            (_, Some(nullifier)) => nullifier,
+            (Some(note_commitment), None) => {
+                let record = view
+                    .note_by_commitment(note_commitment)
+                    .await
+                    .with_context(|| format!("note {note_commitment} not found in view service"))?;
+                let assets = asset::Cache::with_known_assets();
+                println!(
+                    "note {note_commitment}: {} to account {}, created at height {}",
+                    record.note.value().format(&assets),
+                    record.address_index.account,
+                    record.height_created,
+                );
+                record.nullifier
+            }
+            (None, None) => anyhow::bail!("a note commitment or a nullifier is required"),
+        };
+
+        match view.transaction_by_nullifier(nullifier).await? {
+            Some((height, id)) => println!("spent by transaction {id} at height {height}"),
+            None if view.nullifier_status(nullifier).await? => {
+                println!("spent by a transaction the view service hasn't fetched")
+            }
+            None => println!("unspent"),
+        }
+
+        Ok(())
+    }
+}
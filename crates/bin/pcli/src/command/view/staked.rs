@@ -1,7 +1,6 @@
 use std::collections::BTreeMap;
 
 use anyhow::Result;
-use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use tonic::transport::Channel;
 
@@ -10,8 +9,15 @@ use penumbra_keys::FullViewingKey;
 use penumbra_proto::core::component::stake::v1::{
     query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
 };
-use penumbra_stake::{validator, DelegationToken};
+use penumbra_stake::{validator, DelegationToken, IdentityKey};
 use penumbra_view::ViewClient;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    output::{Output, ValueOutput},
+    render,
+};
 
 #[derive(Debug, clap::Parser)]
 pub struct StakedCmd {}
@@ -26,7 +32,7 @@ impl StakedCmd {
         _fvk: &FullViewingKey,
         view_client: &mut impl ViewClient,
         pd_channel: Channel,
-    ) -> Result<()> {
+    ) -> Result<StakedOutput> {
         let asset_cache = view_client.assets().await?;
 
         let mut client = StakeQueryServiceClient::new(pd_channel);
@@ -46,14 +52,7 @@ impl StakedCmd {
 
         let notes = view_client.unspent_notes_by_asset_and_address().await?;
         let mut total = 0u128;
-
-        let mut table = Table::new();
-        table.load_preset(presets::NOTHING);
-        table.set_header(vec!["Name", "Value", "Exch. Rate", "Tokens"]);
-        table
-            .get_column_mut(1)
-            .expect("column 1 exists")
-            .set_cell_alignment(comfy_table::CellAlignment::Right);
+        let mut delegations = Vec::new();
 
         for (asset_id, notes_by_address) in notes.iter() {
             let dt = if let Some(Ok(dt)) = asset_cache
@@ -92,12 +91,13 @@ impl StakedCmd {
                 validator_exchange_rate / 1_0000_0000.0
             };
 
-            table.add_row(vec![
-                info.validator.name.clone(),
-                unbonded.format(&asset_cache),
-                format!("{rate:.4}"),
-                delegation.format(&asset_cache),
-            ]);
+            delegations.push(Delegation {
+                validator: info.validator.name.clone(),
+                identity_key: info.validator.identity_key,
+                value: ValueOutput::new(unbonded, &asset_cache),
+                exchange_rate: rate,
+                tokens: ValueOutput::new(delegation, &asset_cache),
+            });
 
             total += u128::from(unbonded.amount);
         }
@@ -115,26 +115,66 @@ impl StakedCmd {
 
         total += u128::from(unbonded.amount);
 
-        table.add_row(vec![
-            "Unbonded Stake".to_string(),
-            unbonded.format(&asset_cache),
-            format!("{:.4}", 1.0),
-            unbonded.format(&asset_cache),
-        ]);
-
         let total = Value {
             amount: total.into(),
             asset_id: *STAKING_TOKEN_ASSET_ID,
         };
 
+        Ok(StakedOutput {
+            delegations,
+            unbonded: ValueOutput::new(unbonded, &asset_cache),
+            total: ValueOutput::new(total, &asset_cache),
+        })
+    }
+}
+
+/// The wallet's delegations, valued in the staking token.
+#[derive(Debug, Serialize)]
+pub struct StakedOutput {
+    delegations: Vec<Delegation>,
+    unbonded: ValueOutput,
+    total: ValueOutput,
+}
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub struct Delegation {
+    validator: String,
+    #[serde_as(as = "DisplayFromStr")]
+    identity_key: IdentityKey,
+    value: ValueOutput,
+    exchange_rate: f64,
+    tokens: ValueOutput,
+}
+
+impl Output for StakedOutput {
+    fn render(&self) -> Result<String> {
+        let mut table = render::table_with_header(["Name", "Value", "Exch. Rate", "Tokens"]);
+        render::align_right(&mut table, [1]);
+
+        for delegation in &self.delegations {
+            table.add_row(vec![
+                delegation.validator.clone(),
+                delegation.value.to_string(),
+                format!("{:.4}", delegation.exchange_rate),
+                delegation.tokens.to_string(),
+            ]);
+        }
+
+        table.add_row(vec![
+            "Unbonded Stake".to_string(),
+            self.unbonded.to_string(),
+            format!("{:.4}", 1.0),
+            self.unbonded.to_string(),
+        ]);
+
         table.add_row(vec![
             "Total".to_string(),
-            total.format(&asset_cache),
+            self.total.to_string(),
             String::new(),
             String::new(),
         ]);
-        println!("{table}");
 
-        Ok(())
+        Ok(table.to_string())
     }
 }
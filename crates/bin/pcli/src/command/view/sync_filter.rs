@@ -0,0 +1,87 @@
+use anyhow::Result;
+use camino::Utf8Path;
+
+use penumbra_asset::asset;
+
+use crate::config::PcliConfig;
+
+#[derive(Debug, clap::Parser)]
+pub struct SyncFilterCmd {
+    /// Only store notes sent to this account. Can be given multiple times.
+    #[clap(long = "account", conflicts_with = "all_accounts")]
+    pub accounts: Vec<u32>,
+    /// Only store notes of this asset, given as a denom or an asset ID. Can be given multiple
+    /// times.
+    #[clap(long = "asset", conflicts_with = "all_assets")]
+    pub assets: Vec<String>,
+    /// Store notes sent to any account.
+    #[clap(long)]
+    pub all_accounts: bool,
+    /// Store notes of any asset.
+    #[clap(long)]
+    pub all_assets: bool,
+}
+
+impl SyncFilterCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    /// Updates the sync filter in the config. The view service applies it when it next starts,
+    /// forgetting notes it no longer allows and rescanning for notes it newly allows.
+    ///
+    /// Like `view rescan`, this takes the home dir directly, so that the view service started
+    /// afterwards uses the new filter.
+    pub fn exec(&self, data_path: impl AsRef<Utf8Path>) -> Result<()> {
+        let config_path = data_path.as_ref().join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+        if config.view_url.is_some() {
+            anyhow::bail!("sync filters are only supported with a local view service");
+        }
+
+        if self.all_accounts {
+            config.sync_accounts = None;
+        } else if !self.accounts.is_empty() {
+            config.sync_accounts = Some(self.accounts.iter().copied().collect());
+        }
+        if self.all_assets {
+            config.sync_assets = None;
+        } else if !self.assets.is_empty() {
+            config.sync_assets = Some(self.assets.iter().map(|asset| parse_asset(asset)).collect());
+        }
+        config.save(&config_path)?;
+
+        match &config.sync_accounts {
+            Some(accounts) => println!(
+                "Storing notes sent to accounts: {}",
+                accounts
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => println!("Storing notes sent to any account"),
+        }
+        match &config.sync_assets {
+            Some(assets) => println!(
+                "Storing notes of assets: {}",
+                assets
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            None => println!("Storing notes of any asset"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses an asset ID, or failing that, the ID of the base unit of a denom.
+fn parse_asset(asset: &str) -> asset::Id {
+    asset
+        .parse()
+        .unwrap_or_else(|_| asset::REGISTRY.parse_unit(asset).base().id())
+}
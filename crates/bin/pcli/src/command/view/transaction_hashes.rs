@@ -1,8 +1,14 @@
 use anyhow::Result;
 use comfy_table::{presets, Table};
-use penumbra_keys::FullViewingKey;
+use penumbra_keys::{Address, FullViewingKey};
 use penumbra_transaction::MemoView;
 use penumbra_view::ViewClient;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::output::Output;
+
+use super::history::{self, HistoryFormat};
 
 #[derive(Debug, clap::Args)]
 pub struct TransactionHashesCmd {
@@ -10,6 +16,10 @@ pub struct TransactionHashesCmd {
     pub start_height: Option<u64>,
     #[clap(short, long)]
     pub end_height: Option<u64>,
+    /// Rather than listing the transactions, export the funds each one moved, with one row per
+    /// note spent or created, swap input and fee paid, for importing into accounting software.
+    #[clap(long, value_enum)]
+    pub format: Option<HistoryFormat>,
 }
 
 impl TransactionHashesCmd {
@@ -17,36 +27,98 @@ impl TransactionHashesCmd {
         false
     }
 
-    pub async fn exec<V: ViewClient>(&self, _fvk: &FullViewingKey, view: &mut V) -> Result<()> {
-        // Initialize the table
+    pub async fn exec<V: ViewClient>(
+        &self,
+        _fvk: &FullViewingKey,
+        view: &mut V,
+    ) -> Result<TransactionHashes> {
+        let txs = view
+            .transaction_info(self.start_height, self.end_height)
+            .await?;
 
-        let mut table = Table::new();
-        table.load_preset(presets::NOTHING);
+        Ok(TransactionHashes(
+            txs.into_iter()
+                .map(|tx_info| {
+                    let (return_address, memo) = match tx_info.view.body_view.memo_view {
+                        Some(MemoView::Visible { plaintext, .. }) => (
+                            Some(plaintext.return_address.address()),
+                            Some(plaintext.text),
+                        ),
+                        _ => (None, None),
+                    };
+                    TransactionHash {
+                        height: tx_info.height,
+                        block_time: tx_info.block_time,
+                        hash: hex::encode(tx_info.id),
+                        return_address,
+                        memo,
+                    }
+                })
+                .collect(),
+        ))
+    }
+}
 
+impl TransactionHashesCmd {
+    /// Exports the funds moved by the transactions in the height range as CSV.
+    pub async fn export_csv<V: ViewClient>(&self, view: &mut V) -> Result<String> {
+        let assets = view.assets().await?;
         let txs = view
             .transaction_info(self.start_height, self.end_height)
             .await?;
 
-        table.set_header(vec!["Height", "Transaction Hash", "Return Address", "Memo"]);
+        let rows = txs
+            .iter()
+            .flat_map(|tx_info| history::history_rows(tx_info, &assets))
+            .collect::<Vec<_>>();
+        Ok(history::to_csv(&rows))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct TransactionHashes(Vec<TransactionHash>);
+
+#[serde_as]
+#[derive(Debug, Serialize)]
+pub struct TransactionHash {
+    height: u64,
+    /// The time of the block, if the view service recorded it.
+    block_time: Option<tendermint::Time>,
+    hash: String,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    return_address: Option<Address>,
+    memo: Option<String>,
+}
 
-        for tx_info in txs {
-            let (return_address, memo) = match tx_info.view.body_view.memo_view {
-                Some(MemoView::Visible { plaintext, .. }) => (
-                    plaintext.return_address.address().display_short_form(),
-                    plaintext.text,
-                ),
-                _ => (String::new(), String::new()),
-            };
+impl Output for TransactionHashes {
+    fn render(&self) -> Result<String> {
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Height",
+            "Time",
+            "Transaction Hash",
+            "Return Address",
+            "Memo",
+        ]);
+
+        for tx in &self.0 {
+            let return_address = tx
+                .return_address
+                .map(|address| address.display_short_form())
+                .unwrap_or_default();
             table.add_row(vec![
-                format!("{}", tx_info.height),
-                format!("{}", hex::encode(tx_info.id)),
-                format!("{}", return_address),
-                format!("{}", memo),
+                tx.height.to_string(),
+                tx.block_time
+                    .map(|time| time.to_rfc3339())
+                    .unwrap_or_default(),
+                tx.hash.clone(),
+                return_address,
+                tx.memo.clone().unwrap_or_default(),
             ]);
         }
 
-        println!("{table}");
-
-        Ok(())
+        Ok(table.to_string())
     }
 }
@@ -1,18 +1,54 @@
 use anyhow::{Context, Result};
-use penumbra_proto::{util::tendermint_proxy::v1::GetTxRequest, DomainType};
-use penumbra_transaction::Transaction;
+use futures::TryStreamExt;
+use penumbra_asset::asset::Cache;
+use penumbra_governance::{Proposal, ProposalPayload};
+use penumbra_proto::{
+    core::component::governance::v1::{
+        query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
+        ProposalListRequest,
+    },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetTxRequest,
+    },
+    DomainType,
+};
+use penumbra_transaction::{
+    txhash::TransactionId, Action, ActionPlan, Transaction, TransactionPlan,
+};
 use penumbra_view::{TransactionInfo, ViewClient};
+use tonic::transport::Channel;
 
-use crate::App;
+use crate::{
+    output::{Json, OutputFormat},
+    App,
+};
+
+use super::history::{self, HistoryFormat};
 
 /// Queries the chain for a transaction by hash.
 #[derive(Debug, clap::Args)]
 pub struct TxCmd {
     /// The hex-formatted transaction hash to query.
     hash: String,
-    /// If set, print the raw transaction view rather than a formatted table.
-    #[clap(long)]
+    /// How to print the transaction view.
+    ///
+    /// Defaults to `raw` with `--output json`, and to `terminal` otherwise.
+    #[clap(long, value_enum)]
+    format: Option<Rendering>,
+    /// Print the raw transaction view, like `--format raw`.
+    #[clap(long, conflicts_with = "format")]
     raw: bool,
+    /// Print the transaction view as an HTML fragment, like `--format html`.
+    #[clap(long, conflicts_with_all = &["format", "raw"])]
+    html: bool,
+    /// Print the transaction view as JSON, with a description of each action, like
+    /// `--format json`.
+    #[clap(long, conflicts_with_all = &["format", "raw", "html"])]
+    summary: bool,
+    /// Rather than printing the transaction view, export the funds the transaction moved, with
+    /// one row per note spent or created, swap input and fee paid.
+    #[clap(long, value_enum, conflicts_with_all = &["format", "raw", "html", "summary"])]
+    export: Option<HistoryFormat>,
 }
 
 impl TxCmd {
@@ -20,56 +56,208 @@ impl TxCmd {
         false
     }
     pub async fn exec(&self, app: &mut App) -> Result<()> {
-        let hash = self
-            .hash
-            // We have to convert to uppercase because `tendermint::Hash` only accepts uppercase :(
-            .to_uppercase()
-            .parse()
-            .context("invalid transaction hash")?;
+        let hash = parse_hash(&self.hash)?;
+        let rendering = match self.format {
+            Some(rendering) => rendering,
+            None if self.raw => Rendering::Raw,
+            None if self.html => Rendering::Html,
+            None if self.summary => Rendering::Json,
+            None => Rendering::default_for(app.output),
+        };
 
         // Retrieve Transaction from the view service first, or else the fullnode
         let tx_info = if let Ok(tx_info) = app.view().transaction_info_by_hash(hash).await {
             tx_info
         } else {
-            if !self.raw {
+            if rendering == Rendering::Terminal {
                 println!("Transaction not found in view service, fetching from fullnode...");
             } else {
                 tracing::info!("Transaction not found in view service, fetching from fullnode...");
             }
             // Fall back to fetching from fullnode
-            let mut client = app.tendermint_proxy_client().await?;
-            let rsp = client
-                .get_tx(GetTxRequest {
-                    hash: hex::decode(self.hash.clone())?,
-                    prove: false,
-                })
-                .await?;
-
-            let rsp = rsp.into_inner();
-            let tx = Transaction::decode(rsp.tx.as_slice())?;
-            let txp = Default::default();
-            let txv = tx.view_from_perspective(&txp);
-
-            TransactionInfo {
-                height: rsp.height,
-                id: hash,
-                transaction: tx,
-                perspective: txp,
-                view: txv,
-            }
+            fetch_from_node(app.tendermint_proxy_client().await?, hash).await?
         };
 
-        if self.raw {
-            use colored_json::prelude::*;
-            println!(
+        let assets = app.view().assets().await?;
+        if let Some(HistoryFormat::Csv) = self.export {
+            print!(
                 "{}",
-                serde_json::to_string_pretty(&tx_info.view)?.to_colored_json_auto()?
+                history::to_csv(&history::history_rows(&tx_info, &assets))
             );
-        } else {
-            use crate::transaction_view_ext::TransactionViewExt;
-            tx_info.view.render_terminal();
+            return Ok(());
+        }
+        render(app, &assets, &tx_info, rendering).await
+    }
+}
+
+/// Parses a hex-formatted transaction hash.
+pub(super) fn parse_hash(hash: &str) -> Result<TransactionId> {
+    hash.trim()
+        // We have to convert to uppercase because `tendermint::Hash` only accepts uppercase :(
+        .to_uppercase()
+        .parse()
+        .with_context(|| format!("invalid transaction hash {hash}"))
+}
+
+/// Fetches a transaction from the fullnode, viewing it without any perspective.
+pub(super) async fn fetch_from_node(
+    mut client: TendermintProxyServiceClient<Channel>,
+    hash: TransactionId,
+) -> Result<TransactionInfo> {
+    let rsp = client
+        .get_tx(GetTxRequest {
+            hash: hash.0.to_vec(),
+            prove: false,
+        })
+        .await
+        .with_context(|| format!("cannot fetch transaction {hash} from fullnode"))?;
+
+    let rsp = rsp.into_inner();
+    let tx = Transaction::decode(rsp.tx.as_slice())?;
+    let txp = Default::default();
+    let txv = tx.view_from_perspective(&txp);
+
+    Ok(TransactionInfo {
+        height: rsp.height,
+        id: hash,
+        transaction: tx,
+        perspective: txp,
+        view: txv,
+        block_time: None,
+    })
+}
+
+/// How a transaction's view is printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub(super) enum Rendering {
+    /// As a table.
+    Terminal,
+    /// As an HTML fragment.
+    Html,
+    /// As JSON, with a description of each action along with the full transaction view.
+    Json,
+    /// As the raw transaction view, in JSON.
+    Raw,
+}
+
+impl Rendering {
+    /// The rendering used when none is chosen, which follows the output format.
+    pub(super) fn default_for(output: OutputFormat) -> Self {
+        match output {
+            OutputFormat::Json => Rendering::Raw,
+            _ => Rendering::Terminal,
+        }
+    }
+}
+
+/// Prints a transaction's view in the given rendering.
+pub(super) async fn render(
+    app: &mut App,
+    assets: &Cache,
+    tx_info: &TransactionInfo,
+    rendering: Rendering,
+) -> Result<()> {
+    use crate::transaction_view_ext::TransactionViewExt;
+    match rendering {
+        Rendering::Terminal => {
+            let community_pool_proposal = community_pool_proposal_of(app, tx_info).await?;
+            tx_info
+                .view
+                .render_terminal(assets, community_pool_proposal)
+        }
+        Rendering::Html => {
+            let community_pool_proposal = community_pool_proposal_of(app, tx_info).await?;
+            print!(
+                "{}",
+                tx_info.view.render_html(assets, community_pool_proposal)
+            )
         }
+        Rendering::Json => {
+            let json = render_json(app, assets, tx_info).await?;
+            app.print(&Json(json))?
+        }
+        Rendering::Raw => app.print(&Json(&tx_info.view))?,
+    }
+    Ok(())
+}
+
+/// Renders a transaction's view as JSON, as printed with [`Rendering::Json`].
+pub(super) async fn render_json(
+    app: &mut App,
+    assets: &Cache,
+    tx_info: &TransactionInfo,
+) -> Result<serde_json::Value> {
+    use crate::transaction_view_ext::TransactionViewExt;
+    let community_pool_proposal = community_pool_proposal_of(app, tx_info).await?;
+    Ok(tx_info.view.render_json(assets, community_pool_proposal))
+}
 
-        Ok(())
+/// Finds the Community Pool spend proposal that enacted a transaction, if it has Community Pool
+/// actions at all.
+async fn community_pool_proposal_of(
+    app: &mut App,
+    tx_info: &TransactionInfo,
+) -> Result<Option<u64>> {
+    if tx_info.transaction.community_pool_spends().count() > 0 {
+        community_pool_proposal(app, &tx_info.transaction).await
+    } else {
+        Ok(None)
     }
 }
+
+/// Finds the Community Pool spend proposal whose transaction plan produced the given
+/// transaction's Community Pool actions, if any.
+async fn community_pool_proposal(app: &mut App, transaction: &Transaction) -> Result<Option<u64>> {
+    let community_pool_actions = |actions: Vec<Action>| {
+        actions
+            .into_iter()
+            .filter(|action| {
+                matches!(
+                    action,
+                    Action::CommunityPoolSpend(_)
+                        | Action::CommunityPoolOutput(_)
+                        | Action::CommunityPoolDeposit(_)
+                )
+            })
+            .map(|action| action.encode_to_vec())
+            .collect::<Vec<_>>()
+    };
+    let expected = community_pool_actions(transaction.actions().cloned().collect());
+
+    let proposals = GovernanceQueryServiceClient::new(app.pd_channel().await?)
+        .proposal_list(ProposalListRequest {
+            inactive: true,
+            ..Default::default()
+        })
+        .await?
+        .into_inner()
+        .try_collect::<Vec<_>>()
+        .await?;
+    for response in proposals {
+        let proposal: Proposal = response
+            .proposal
+            .ok_or_else(|| anyhow::anyhow!("missing proposal"))?
+            .try_into()?;
+        let ProposalPayload::CommunityPoolSpend { transaction_plan } = &proposal.payload else {
+            continue;
+        };
+        let Ok(plan) = TransactionPlan::decode(&transaction_plan[..]) else {
+            continue;
+        };
+        let planned = plan
+            .actions
+            .into_iter()
+            .filter_map(|action| match action {
+                ActionPlan::CommunityPoolSpend(x) => Some(Action::CommunityPoolSpend(x)),
+                ActionPlan::CommunityPoolOutput(x) => Some(Action::CommunityPoolOutput(x)),
+                ActionPlan::CommunityPoolDeposit(x) => Some(Action::CommunityPoolDeposit(x)),
+                _ => None,
+            })
+            .collect();
+        if community_pool_actions(planned) == expected {
+            return Ok(Some(proposal.id));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,113 @@
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use futures::{StreamExt, TryStreamExt};
+use penumbra_view::ViewClient;
+
+use crate::{output::Json, App};
+
+use super::tx::{fetch_from_node, parse_hash, render, render_json, Rendering};
+
+/// Queries the chain for the transactions whose hashes are listed in a file.
+#[derive(Debug, clap::Args)]
+pub struct TxManyCmd {
+    /// A file listing hex-formatted transaction hashes, one per line, or `-` to read them from
+    /// stdin.
+    ///
+    /// Blank lines, and lines starting with `#`, are ignored.
+    file: Utf8PathBuf,
+    /// How to print the transaction views. With `raw` and `json`, they're printed as a single
+    /// JSON array.
+    ///
+    /// Defaults to `raw` with `--output json`, and to `terminal` otherwise.
+    #[clap(long, value_enum)]
+    format: Option<Rendering>,
+    /// Print a JSON array of the raw transaction views, like `--format raw`.
+    #[clap(long, conflicts_with = "format")]
+    raw: bool,
+    /// Print the transaction views as HTML fragments, like `--format html`.
+    #[clap(long, conflicts_with_all = &["format", "raw"])]
+    html: bool,
+    /// The number of transactions to fetch at once.
+    #[clap(long, default_value_t = 8)]
+    concurrency: usize,
+}
+
+impl TxManyCmd {
+    pub fn offline(&self) -> bool {
+        false
+    }
+
+    pub async fn exec(&self, app: &mut App) -> Result<()> {
+        let contents = if self.file == "-" {
+            std::io::read_to_string(std::io::stdin()).context("cannot read hashes from stdin")?
+        } else {
+            std::fs::read_to_string(&self.file)
+                .with_context(|| format!("cannot read hashes from {}", self.file))?
+        };
+        let hashes = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(parse_hash)
+            .collect::<Result<Vec<_>>>()?;
+        let rendering = match self.format {
+            Some(rendering) => rendering,
+            None if self.raw => Rendering::Raw,
+            None if self.html => Rendering::Html,
+            None => Rendering::default_for(app.output),
+        };
+
+        // Look every transaction up in the view service first, falling back to the fullnode for
+        // those it doesn't know, and keep the order of the file.
+        let node = app.tendermint_proxy_client().await?;
+        let lookups = hashes
+            .into_iter()
+            .map(|hash| {
+                let from_view = app.view().transaction_info_by_hash(hash);
+                let node = node.clone();
+                async move {
+                    match from_view.await {
+                        Ok(tx_info) => Ok(tx_info),
+                        Err(_) => {
+                            tracing::info!(%hash, "transaction not found in view service, fetching from fullnode");
+                            fetch_from_node(node, hash).await
+                        }
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        let tx_infos = futures::stream::iter(lookups)
+            .buffered(self.concurrency.max(1))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        match rendering {
+            Rendering::Raw => {
+                let views = tx_infos
+                    .iter()
+                    .map(|tx_info| &tx_info.view)
+                    .collect::<Vec<_>>();
+                app.print(&Json(&views))?;
+            }
+            Rendering::Json => {
+                let assets = app.view().assets().await?;
+                let mut views = Vec::with_capacity(tx_infos.len());
+                for tx_info in &tx_infos {
+                    views.push(render_json(app, &assets, tx_info).await?);
+                }
+                app.print(&Json(&views))?;
+            }
+            Rendering::Terminal | Rendering::Html => {
+                let assets = app.view().assets().await?;
+                for tx_info in &tx_infos {
+                    if rendering == Rendering::Terminal {
+                        println!("Transaction {} at height {}:", tx_info.id, tx_info.height);
+                    }
+                    render(app, &assets, tx_info, rendering).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@ use anyhow::Result;
 
 use penumbra_keys::FullViewingKey;
 
+use crate::output::Text;
+
 #[derive(Debug, clap::Parser)]
 pub struct WalletIdCmd {}
 
@@ -11,10 +13,7 @@ impl WalletIdCmd {
         true
     }
 
-    pub fn exec(&self, fvk: &FullViewingKey) -> Result<()> {
-        let wallet_id = fvk.wallet_id();
-        println!("{wallet_id}");
-
-        Ok(())
+    pub fn exec(&self, fvk: &FullViewingKey) -> Result<Text> {
+        Ok(Text(fvk.wallet_id().to_string()))
     }
 }
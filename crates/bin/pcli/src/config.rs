@@ -1,12 +1,21 @@
-use std::path::Path;
+use std::{
+    collections::BTreeSet,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use url::Url;
 
+use penumbra_asset::asset;
+use penumbra_compact_block::TrustedCheckpoint;
 use penumbra_custody::{soft_kms::Config as SoftKmsConfig, threshold::Config as ThresholdConfig};
 use penumbra_keys::FullViewingKey;
+use penumbra_view::SyncFilter;
+
+use crate::registry::RegistryConfig;
 
 /// Configuration data for `pcli`.
 #[serde_as]
@@ -14,14 +23,58 @@ use penumbra_keys::FullViewingKey;
 pub struct PcliConfig {
     /// The URL of the gRPC endpoint used to talk to pd.
     pub grpc_url: Url,
+    /// The URLs of other pd gRPC endpoints serving the same chain, in order of preference.
+    ///
+    /// If any are set, the healthiest endpoint is used, preferring `grpc_url`, and the view
+    /// service fails over to another when syncing from the one in use fails.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_grpc_urls: Vec<Url>,
     /// If set, use a remote view service instead of local synchronization.
     pub view_url: Option<Url>,
+    /// If set, sent as a bearer token to authenticate to the remote view service.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_auth_token: Option<String>,
+    /// If set, the PEM-encoded CA certificate used to verify the remote view service's
+    /// TLS certificate, for instance when it is self-signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view_ca_cert: Option<PathBuf>,
     /// Disable the scary "you will lose all your money" warning.
     #[serde(default, skip_serializing_if = "is_default")]
     pub disable_warning: bool,
     /// The FVK used for viewing chain data.
     #[serde_as(as = "DisplayFromStr")]
     pub full_viewing_key: FullViewingKey,
+    /// The height the wallet was created at, if known.
+    ///
+    /// The view service skips scanning blocks before this height.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub birthday_height: Option<u64>,
+    /// The checkpoint the view service starts syncing from, skipping the blocks before it.
+    ///
+    /// Its anchor comes from a source the user trusts, rather than the node being synced from.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checkpoint: Option<TrustedCheckpoint>,
+    /// Whether the local view database is encrypted with a passphrase.
+    ///
+    /// Set by `pcli view encrypt`.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub encrypt_view: bool,
+    /// If set, the local view service only stores notes sent to these accounts.
+    ///
+    /// Set by `pcli view sync-filter`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_accounts: Option<BTreeSet<u32>>,
+    /// If set, the local view service only stores notes of these assets.
+    ///
+    /// Set by `pcli view sync-filter`.
+    #[serde_as(as = "Option<BTreeSet<DisplayFromStr>>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sync_assets: Option<BTreeSet<asset::Id>>,
+    /// If set, the chain registry used to check IBC withdrawal destinations and to name
+    /// counterparty chains.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<RegistryConfig>,
     /// The custody backend to use.
     pub custody: CustodyConfig,
 }
@@ -40,6 +93,40 @@ impl PcliConfig {
         std::fs::write(path, contents)?;
         Ok(())
     }
+
+    /// The filter restricting which notes the local view service stores.
+    pub fn sync_filter(&self) -> SyncFilter {
+        SyncFilter {
+            accounts: self.sync_accounts.clone(),
+            assets: self.sync_assets.clone(),
+        }
+    }
+
+    /// The passphrase the local view database is encrypted with, if it's encrypted.
+    ///
+    /// The passphrase is read from the `PENUMBRA_PCLI_VIEW_PASSPHRASE` environment variable if
+    /// it's set, and otherwise prompted for.
+    pub fn view_passphrase(&self) -> Result<Option<String>> {
+        if !self.encrypt_view {
+            return Ok(None);
+        }
+        prompt_view_passphrase("Enter view database passphrase: ").map(Some)
+    }
+}
+
+/// Reads a view database passphrase from the environment, or prompts for it with `prompt`.
+pub fn prompt_view_passphrase(prompt: &str) -> Result<String> {
+    if let Ok(passphrase) = std::env::var("PENUMBRA_PCLI_VIEW_PASSPHRASE") {
+        return Ok(passphrase);
+    }
+    // The `rpassword` crate doesn't support reading from stdin, so non-interactive use has to
+    // go through the environment.
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!(
+            "the view database is encrypted, so set PENUMBRA_PCLI_VIEW_PASSPHRASE to its passphrase"
+        );
+    }
+    Ok(rpassword::prompt_password(prompt)?)
 }
 
 /// The custody backend to use.
@@ -80,9 +167,18 @@ mod tests {
     fn toml_config() {
         let config = PcliConfig {
             grpc_url: Url::parse("https://grpc.testnet.penumbra.zone").unwrap(),
+            fallback_grpc_urls: Vec::new(),
             disable_warning: false,
             view_url: None,
+            view_auth_token: None,
+            view_ca_cert: None,
             full_viewing_key: penumbra_keys::test_keys::FULL_VIEWING_KEY.clone(),
+            birthday_height: None,
+            checkpoint: None,
+            encrypt_view: false,
+            sync_accounts: None,
+            sync_assets: None,
+            registry: None,
             custody: CustodyConfig::SoftKms(SoftKmsConfig::from(
                 penumbra_keys::test_keys::SPEND_KEY.clone(),
             )),
@@ -91,6 +187,19 @@ mod tests {
         let mut config2 = config.clone();
         config2.custody = CustodyConfig::ViewOnly;
         config2.disable_warning = true;
+        config2.birthday_height = Some(1000);
+        config2.checkpoint = Some(TrustedCheckpoint {
+            height: 719,
+            anchor: penumbra_tct::Tree::new().root(),
+        });
+        config2.encrypt_view = true;
+        config2.sync_accounts = Some([0, 1].into());
+        config2.sync_assets = Some([*penumbra_asset::STAKING_TOKEN_ASSET_ID].into());
+        config2.registry = Some(RegistryConfig {
+            url: Url::parse("https://registry.example.com/penumbra-1.json").unwrap(),
+            verification_key: [1; 32],
+            max_age_secs: 3600,
+        });
 
         let toml_config = toml::to_string_pretty(&config).unwrap();
         let toml_config2 = toml::to_string_pretty(&config2).unwrap();
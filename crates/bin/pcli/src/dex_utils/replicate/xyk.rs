@@ -3,7 +3,7 @@ use anyhow::Context;
 use ndarray::Array;
 use penumbra_asset::Value;
 use penumbra_dex::{
-    lp::{position::Position, Reserves},
+    lp::{position::Position, replication::ReplicationReport, Reserves},
     DirectedUnitPair,
 };
 use penumbra_num::{fixpoint::U128x128, Amount};
@@ -13,6 +13,9 @@ use tracing::field;
 /// The number of positions that is used to replicate the xyk CFMM.
 pub(crate) const NUM_POOLS_PRECISION: usize = 30;
 
+/// The number of prices per position at which the replication is compared with the xyk CFMM.
+const REPORT_SAMPLES_PER_TICK: usize = 4;
+
 /// Maximum number of iteration that we allow GS to perform.
 const GAUS_SEIDEL_MAX_ITERATION: usize = 10_000;
 
@@ -28,25 +31,7 @@ pub fn replicate(
     current_price: U128x128,
     fee_bps: u32,
 ) -> anyhow::Result<Vec<Position>> {
-    // First, we find the pool invariant using human display units. This means that we
-    // only need to care about scaling into proper denom units right before posting the
-    // positions. On the other hand, we have to unscale the values that we are given.
-    let fp_raw_r1 = U128x128::from(raw_r1.amount.value());
-    let r1_scaling_factor = U128x128::from(pair.start.unit_amount());
-
-    let fp_r1 = (fp_raw_r1 / r1_scaling_factor).context("scaling factor can't be 0")?;
-    let fp_r2 = (current_price * fp_r1).context("should not overflow when multiplying by price")?;
-
-    tracing::debug!(
-        %fp_r1,
-        %fp_r2,
-        "computed respective quantities"
-    );
-
-    let xyk_invariant = (fp_r1 * fp_r2).expect("no overflow when computing curve invariant!");
-
-    let xyk_invariant: f64 = xyk_invariant.try_into()?;
-    tracing::debug!(?xyk_invariant, "computed the total invariant for the PVF");
+    let xyk_invariant = invariant(pair, raw_r1, current_price)?;
 
     let f64_current_price: f64 = current_price.try_into()?;
 
@@ -173,6 +158,55 @@ pub fn replicate(
     Ok(positions)
 }
 
+/// Computes the invariant of the xyk curve through the supplied liquidity at the current price.
+fn invariant(
+    pair: &DirectedUnitPair,
+    raw_r1: &Value,
+    current_price: U128x128,
+) -> anyhow::Result<f64> {
+    // First, we find the pool invariant using human display units. This means that we
+    // only need to care about scaling into proper denom units right before posting the
+    // positions. On the other hand, we have to unscale the values that we are given.
+    let fp_raw_r1 = U128x128::from(raw_r1.amount.value());
+    let r1_scaling_factor = U128x128::from(pair.start.unit_amount());
+
+    let fp_r1 = (fp_raw_r1 / r1_scaling_factor).context("scaling factor can't be 0")?;
+    let fp_r2 = (current_price * fp_r1).context("should not overflow when multiplying by price")?;
+
+    tracing::debug!(
+        %fp_r1,
+        %fp_r2,
+        "computed respective quantities"
+    );
+
+    let xyk_invariant = (fp_r1 * fp_r2).expect("no overflow when computing curve invariant!");
+
+    let xyk_invariant: f64 = xyk_invariant.try_into()?;
+    tracing::debug!(?xyk_invariant, "computed the total invariant for the PVF");
+    Ok(xyk_invariant)
+}
+
+/// Reports how closely `positions` replicate the xyk curve through the supplied liquidity at the
+/// current price, sampling prices between the positions' ticks as well as at them.
+pub fn approximation_report(
+    pair: &DirectedUnitPair,
+    raw_r1: &Value,
+    current_price: U128x128,
+    positions: &[Position],
+) -> anyhow::Result<ReplicationReport> {
+    let xyk_invariant = invariant(pair, raw_r1, current_price)?;
+    let f64_current_price: f64 = current_price.try_into()?;
+    ReplicationReport::new(
+        pair,
+        positions,
+        |price| portfolio_value_function(xyk_invariant, price),
+        sample_prices(
+            f64_current_price,
+            REPORT_SAMPLES_PER_TICK * NUM_POOLS_PRECISION,
+        ),
+    )
+}
+
 pub fn solve(
     alpha: &[f64],
     k: f64,
@@ -0,0 +1,185 @@
+//! Classifying the errors commands fail with, so that scripts can tell them apart.
+//!
+//! Each kind of error exits with its own code, which stays the same between releases, and with
+//! `--error-format json`, the error is printed to stderr as a JSON object naming its kind.
+
+use std::{
+    fmt::{self, Debug, Display, Formatter},
+    io,
+    process::ExitCode,
+};
+
+use serde::Serialize;
+
+/// The kinds of error a command can fail with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    /// Anything not classified otherwise, including bugs. Exits with code 1.
+    Internal,
+    /// The command was given bad arguments, configuration or input files. Exits with code 2,
+    /// like invalid command lines do.
+    User,
+    /// A node or the view service couldn't be reached, or didn't respond. Exits with code 3.
+    Network,
+    /// The custody service refused to authorize a transaction. Exits with code 4.
+    Custody,
+    /// The chain rejected a transaction. Exits with code 5.
+    Chain,
+}
+
+impl ErrorKind {
+    /// The code the process exits with after an error of this kind.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            ErrorKind::Internal => 1,
+            ErrorKind::User => 2,
+            ErrorKind::Network => 3,
+            ErrorKind::Custody => 4,
+            ErrorKind::Chain => 5,
+        }
+    }
+
+    /// Marks an error as being of this kind.
+    pub fn wrap(self, error: impl Into<anyhow::Error>) -> anyhow::Error {
+        Classified {
+            kind: self,
+            error: error.into(),
+        }
+        .into()
+    }
+}
+
+/// The format in which a failed command prints its error.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ErrorFormat {
+    /// A message, followed by its causes.
+    #[default]
+    Text,
+    /// A JSON object with the error's kind, exit code, message and causes.
+    Json,
+}
+
+/// An error known to be of a certain kind.
+struct Classified {
+    kind: ErrorKind,
+    error: anyhow::Error,
+}
+
+impl Display for Classified {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Only the outermost message, since the causes are reached through `source`.
+        Display::fmt(&*self.error, f)
+    }
+}
+
+impl Debug for Classified {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for Classified {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Marks the error of a result as being of a certain kind.
+pub trait Classify<T> {
+    fn classify(self, kind: ErrorKind) -> anyhow::Result<T>;
+}
+
+impl<T, E: Into<anyhow::Error>> Classify<T> for Result<T, E> {
+    fn classify(self, kind: ErrorKind) -> anyhow::Result<T> {
+        self.map_err(|error| kind.wrap(error))
+    }
+}
+
+/// Finds the kind of an error: the innermost kind it was marked with, or otherwise, whether it
+/// was caused by failing to reach a service.
+pub fn classify(error: &anyhow::Error) -> ErrorKind {
+    if let Some(kind) = error
+        .chain()
+        .filter_map(|cause| cause.downcast_ref::<Classified>())
+        .map(|classified| classified.kind)
+        .last()
+    {
+        return kind;
+    }
+
+    let unreachable = error.chain().any(|cause| {
+        if let Some(status) = cause.downcast_ref::<tonic::Status>() {
+            matches!(
+                status.code(),
+                tonic::Code::Unavailable | tonic::Code::DeadlineExceeded
+            )
+        } else if let Some(error) = cause.downcast_ref::<io::Error>() {
+            matches!(
+                error.kind(),
+                io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::NotConnected
+                    | io::ErrorKind::TimedOut
+            )
+        } else {
+            cause.is::<tonic::transport::Error>()
+        }
+    });
+    if unreachable {
+        ErrorKind::Network
+    } else {
+        ErrorKind::Internal
+    }
+}
+
+/// Prints the error a command failed with, returning the code to exit with.
+pub fn report(error: &anyhow::Error, format: ErrorFormat) -> ExitCode {
+    let kind = classify(error);
+    match format {
+        ErrorFormat::Text => eprintln!("Error: {error:?}"),
+        ErrorFormat::Json => eprintln!(
+            "{}",
+            serde_json::json!({
+                "kind": kind,
+                "exit_code": kind.exit_code(),
+                "message": error.to_string(),
+                "causes": error.chain().skip(1).map(|cause| cause.to_string()).collect::<Vec<_>>(),
+            })
+        ),
+    }
+    ExitCode::from(kind.exit_code())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+
+    use super::*;
+
+    #[test]
+    fn the_innermost_kind_wins() {
+        let error = Err::<(), _>(anyhow::anyhow!("rejected"))
+            .classify(ErrorKind::Chain)
+            .context("error broadcasting transaction")
+            .classify(ErrorKind::Network)
+            .unwrap_err();
+        assert_eq!(classify(&error), ErrorKind::Chain);
+        // Classifying an error doesn't repeat its message.
+        assert_eq!(
+            format!("{error:#}"),
+            "error broadcasting transaction: rejected"
+        );
+    }
+
+    #[test]
+    fn unreachable_services_are_network_errors() {
+        let error = anyhow::Error::from(tonic::Status::unavailable("connection refused"))
+            .context("can't fetch gas prices");
+        assert_eq!(classify(&error), ErrorKind::Network);
+
+        let error = anyhow::Error::from(tonic::Status::invalid_argument("bad address"));
+        assert_eq!(classify(&error), ErrorKind::Internal);
+    }
+}
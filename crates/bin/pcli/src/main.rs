@@ -1,27 +1,33 @@
 #![deny(clippy::unwrap_used)]
 #![allow(clippy::clone_on_copy)]
 
-use std::fs;
+use std::{fs, process::ExitCode};
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use clap::Parser;
 use futures::StreamExt;
 
 use command::*;
 use config::PcliConfig;
+use error::ErrorKind;
 use opt::Opt;
 use penumbra_proto::box_grpc_svc::BoxGrpcService;
 use penumbra_proto::{
     custody::v1::custody_service_client::CustodyServiceClient,
-    view::v1::view_service_client::ViewServiceClient,
+    util::tendermint_proxy::v1::GetStatusRequest, view::v1::view_service_client::ViewServiceClient,
 };
 use penumbra_view::ViewClient;
 
 mod command;
 mod config;
 mod dex_utils;
+mod error;
 mod network;
 mod opt;
+mod output;
+mod registry;
+mod render;
+mod replay;
 mod terminal;
 mod transaction_view_ext;
 mod warning;
@@ -37,6 +43,18 @@ pub struct App {
     pub view: Option<ViewServiceClient<BoxGrpcService>>,
     pub custody: CustodyServiceClient<BoxGrpcService>,
     pub config: PcliConfig,
+    /// The format in which commands print their results.
+    pub output: output::OutputFormat,
+    /// If set, the directory into which the inputs to each transaction build are recorded.
+    pub save_replay: Option<camino::Utf8PathBuf>,
+    /// If set, the key under which this command's transactions are broadcast idempotently.
+    pub idempotency_key: Option<String>,
+    /// How many transactions this command has broadcast so far.
+    pub broadcasts: usize,
+    /// The home directory, where cached data such as the chain registry is kept.
+    pub home: camino::Utf8PathBuf,
+    /// Whether to fetch the chain registry again, even if the cached copy is fresh.
+    pub refresh_registry: bool,
 }
 
 impl App {
@@ -44,6 +62,65 @@ impl App {
         self.view.as_mut().expect("view service initialized")
     }
 
+    /// Checks that the view service is tracking the same chain, and the same version of its
+    /// protocol, as the fullnode, so that pointing them at different networks fails clearly rather
+    /// than with notes and transactions that don't make sense.
+    async fn handshake(&mut self) -> Result<()> {
+        let view = match ViewClient::handshake(
+            self.view.as_mut().expect("view service initialized"),
+        )
+        .await
+        {
+            Ok(view) => view,
+            // A view service from before the handshake was added can't be checked.
+            Err(e)
+                if e.downcast_ref::<tonic::Status>()
+                    .is_some_and(|status| status.code() == tonic::Code::Unimplemented) =>
+            {
+                tracing::warn!("view service does not support the handshake, not checking it");
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+        let node_info = self
+            .tendermint_proxy_client()
+            .await?
+            .get_status(GetStatusRequest {})
+            .await?
+            .into_inner()
+            .node_info
+            .context("fullnode did not report its node info")?;
+        tracing::debug!(
+            chain_id = %view.chain_id,
+            app_version = view.app_version,
+            storage_schema_hash = %view.storage_schema_hash,
+            "view service handshake"
+        );
+
+        if view.chain_id != node_info.network {
+            return Err(ErrorKind::User.wrap(anyhow!(
+                "the view service is synchronizing with chain {:?}, but the fullnode at {} is on chain {:?}: check grpc_url and view_url in the config, or reset the view data to start over on the new chain",
+                view.chain_id,
+                self.config.grpc_url,
+                node_info.network,
+            )));
+        }
+        let node_app_version = node_info
+            .protocol_version
+            .map(|version| version.app)
+            .unwrap_or_default();
+        if view.app_version != node_app_version {
+            return Err(ErrorKind::User.wrap(anyhow!(
+                "the view service understands version {} of the protocol, but the fullnode at {} runs version {}: upgrade whichever is older",
+                view.app_version,
+                self.config.grpc_url,
+                node_app_version,
+            )));
+        }
+
+        Ok(())
+    }
+
     async fn sync(&mut self) -> Result<()> {
         let mut status_stream =
             ViewClient::status_stream(self.view.as_mut().expect("view service initialized"))
@@ -83,14 +160,21 @@ impl App {
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() -> ExitCode {
     // Display a warning message to the user so they don't get upset when all their tokens are lost.
     if std::env::var("PCLI_UNLEASH_DANGER").is_err() {
         warning::display();
     }
 
-    let mut opt = Opt::parse();
+    let opt = Opt::parse();
+    let error_format = opt.error_format;
+    match run(opt).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => error::report(&e, error_format),
+    }
+}
 
+async fn run(mut opt: Opt) -> Result<()> {
     // Initialize tracing here, rather than when converting into an `App`, so
     // that tracing is set up even for wallet commands that don't build the `App`.
     opt.init_tracing();
@@ -107,22 +191,54 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Likewise, the migrate command takes the home dir directly, since the data it migrates may
+    // not be loadable until it has run.
+    if let Command::Migrate(migrate_cmd) = &opt.cmd {
+        migrate_cmd.exec(opt.home.as_path()).await?;
+        return Ok(());
+    }
+
     // The view reset command takes the home dir directly, and should not be invoked when there's a
     // view service running.
     if let Command::View(ViewCmd::Reset(reset)) = &opt.cmd {
         reset.exec(opt.home.as_path())?;
         return Ok(());
     }
+    // The view encrypt command likewise rewrites the view data, so it must run before the view
+    // service opens it.
+    if let Command::View(ViewCmd::Encrypt(encrypt)) = &opt.cmd {
+        encrypt.exec(opt.home.as_path()).await?;
+        return Ok(());
+    }
+    // The view rescan command likewise invalidates the view data before the view service starts,
+    // but then falls through so that the sync below re-scans it.
+    if let Command::View(ViewCmd::Rescan(rescan)) = &opt.cmd {
+        rescan.exec(opt.home.as_path()).await?;
+    }
+    // The view sync-filter command likewise updates the filter before the view service starts,
+    // which applies it, then falls through to sync with it.
+    if let Command::View(ViewCmd::SyncFilter(sync_filter)) = &opt.cmd {
+        sync_filter.exec(opt.home.as_path())?;
+    }
     // The debug command takes the home dir directly
     if let Command::Debug(debug_cmd) = &opt.cmd {
         let dd = opt.home.into_std_path_buf();
-        debug_cmd.exec(dd)?;
+        debug_cmd.exec(dd).await?;
         return Ok(());
     }
 
     let (mut app, cmd) = opt.into_app().await?;
 
-    if !cmd.offline() {
+    if app.view.is_some() {
+        app.handshake().await?;
+    }
+
+    // Importing a frontier replaces the synced state, so it mustn't be synced first.
+    let needs_sync = match &cmd {
+        Command::View(ViewCmd::Frontier(frontier_cmd)) => frontier_cmd.needs_sync(),
+        cmd => !cmd.offline(),
+    };
+    if needs_sync {
         app.sync().await?;
     }
 
@@ -133,8 +249,10 @@ async fn main() -> Result<()> {
     match &cmd {
         Command::Init(_) => unreachable!("init command already executed"),
         Command::Debug(_) => unreachable!("debug command already executed"),
+        Command::Migrate(_) => unreachable!("migrate command already executed"),
         Command::Transaction(tx_cmd) => tx_cmd.exec(&mut app).await?,
         Command::View(view_cmd) => view_cmd.exec(&mut app).await?,
+        Command::Verify(cmd) => cmd.exec(&mut app).await?,
         Command::Validator(cmd) => cmd.exec(&mut app).await?,
         Command::Query(cmd) => cmd.exec(&mut app).await?,
         Command::Ceremony(cmd) => cmd.exec(&mut app).await?,
@@ -1,18 +1,29 @@
 use anyhow::Context;
 use futures::{FutureExt, TryStreamExt};
+use penumbra_custody::{AuthorizeRequest, CustodyClient};
 use penumbra_fee::GasPrices;
 use penumbra_proto::{
     util::tendermint_proxy::v1::tendermint_proxy_service_client::TendermintProxyServiceClient,
     view::v1::broadcast_transaction_response::Status as BroadcastStatus,
     view::v1::GasPricesRequest, DomainType,
 };
-use penumbra_transaction::{gas::GasCost, txhash::TransactionId, Transaction, TransactionPlan};
-use penumbra_view::ViewClient;
+use penumbra_transaction::{
+    gas::GasCost, txhash::TransactionId, AuthorizationData, Transaction, TransactionPlan,
+};
+use penumbra_view::{PrivacyWarning, ViewClient};
 use std::future::Future;
 use tonic::transport::{Channel, ClientTlsConfig};
 use tracing::instrument;
 
-use crate::App;
+use crate::{
+    error::{Classify, ErrorKind},
+    output::{Json, OutputFormat},
+    replay::Replay,
+    App,
+};
+
+/// How many times to try broadcasting a transaction with an idempotency key, before giving up.
+const IDEMPOTENT_BROADCAST_ATTEMPTS: usize = 3;
 
 impl App {
     pub async fn build_and_submit_transaction(
@@ -29,6 +40,7 @@ impl App {
             .gas_prices
             .expect("gas prices must be available")
             .try_into()?;
+        self.preview_privacy(&plan).await?;
         let transaction = self.build_transaction(plan).await?;
         let gas_cost = transaction.gas_cost();
         let fee = gas_prices.fee(&gas_cost);
@@ -41,20 +53,71 @@ impl App {
         self.submit_transaction(transaction).await
     }
 
+    /// Warns about the ways `plan` would be easy to link to the transactions that funded it,
+    /// before it's built.
+    async fn preview_privacy(&mut self, plan: &TransactionPlan) -> anyhow::Result<()> {
+        let view = self.view();
+        let current_height = view.status().await?.full_sync_height;
+        let mut spent = Vec::new();
+        for spend in plan.spend_plans() {
+            spent.push(view.note_by_commitment(spend.note.commit()).await?);
+        }
+
+        let warnings =
+            PrivacyWarning::analyze(plan, &spent, &self.config.full_viewing_key, current_height);
+        if warnings.is_empty() {
+            return Ok(());
+        }
+        match self.output {
+            OutputFormat::Table => {
+                for warning in &warnings {
+                    println!("privacy warning: this transaction {warning}");
+                }
+            }
+            OutputFormat::Json => self.print(&Json(serde_json::json!({
+                "privacy_warnings": warnings,
+            })))?,
+        }
+        Ok(())
+    }
+
     pub fn build_transaction(
         &mut self,
         plan: TransactionPlan,
     ) -> impl Future<Output = anyhow::Result<Transaction>> + '_ {
         println!("building transaction...");
         let start = std::time::Instant::now();
-        let tx = penumbra_wallet::build_transaction(
-            &self.config.full_viewing_key,
-            self.view.as_mut().expect("view service initialized"),
-            &mut self.custody,
-            plan,
-        );
         async move {
-            let tx = tx.await?;
+            // Authorize the plan separately from witnessing it, so that the custody service
+            // refusing it can be told apart from other failures.
+            let auth_data: AuthorizationData = self
+                .custody
+                .authorize(AuthorizeRequest {
+                    plan: plan.clone(),
+                    pre_authorizations: Vec::new(),
+                })
+                .await
+                .classify(ErrorKind::Custody)?
+                .data
+                .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
+                .try_into()?;
+            let witness_data = self.view().witness(&plan).await?;
+            if let Some(dir) = &self.save_replay {
+                let replay = Replay {
+                    plan: plan.clone(),
+                    witness_data: witness_data.clone(),
+                    auth_data: auth_data.clone(),
+                };
+                let path = replay.save(dir)?;
+                println!("recorded transaction build inputs to {path}");
+            }
+            let tx = penumbra_wallet::build_with(
+                &self.config.full_viewing_key,
+                plan,
+                &witness_data,
+                &auth_data,
+            )
+            .await?;
             let elapsed = start.elapsed();
             println!(
                 "finished proving in {}.{:03} seconds [{} actions, {} proofs, {} bytes]",
@@ -72,12 +135,90 @@ impl App {
     pub async fn submit_transaction(
         &mut self,
         transaction: Transaction,
+    ) -> anyhow::Result<TransactionId> {
+        self.broadcast_and_confirm(transaction, false).await
+    }
+
+    /// Submits a transaction containing swaps to the network, leaving it to the view
+    /// service to claim the swaps once their batch has executed.
+    pub async fn submit_swap_bundle(
+        &mut self,
+        transaction: Transaction,
+    ) -> anyhow::Result<TransactionId> {
+        self.broadcast_and_confirm(transaction, true).await
+    }
+
+    /// Returns the idempotency key for the next transaction this command broadcasts, if any.
+    ///
+    /// Commands may broadcast several transactions, so each is keyed by its position in the
+    /// sequence, which is the same when the command is rerun.
+    fn next_idempotency_key(&mut self) -> Option<String> {
+        let key = self.idempotency_key.as_ref()?;
+        let key = format!("{key}/{}", self.broadcasts);
+        self.broadcasts += 1;
+        Some(key)
+    }
+
+    async fn broadcast_and_confirm(
+        &mut self,
+        transaction: Transaction,
+        claim_swaps: bool,
+    ) -> anyhow::Result<TransactionId> {
+        let Some(idempotency_key) = self.next_idempotency_key() else {
+            return self
+                .try_broadcast_and_confirm(transaction, claim_swaps, None)
+                .await;
+        };
+
+        // With an idempotency key, retrying can't send the transaction twice.
+        let mut attempt = 1;
+        loop {
+            match self
+                .try_broadcast_and_confirm(
+                    transaction.clone(),
+                    claim_swaps,
+                    Some(idempotency_key.clone()),
+                )
+                .await
+            {
+                Err(e) if attempt < IDEMPOTENT_BROADCAST_ATTEMPTS => {
+                    println!(
+                        "{e:#}; retrying with idempotency key {idempotency_key} ({attempt}/{IDEMPOTENT_BROADCAST_ATTEMPTS})"
+                    );
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn try_broadcast_and_confirm(
+        &mut self,
+        transaction: Transaction,
+        claim_swaps: bool,
+        idempotency_key: Option<String>,
     ) -> anyhow::Result<TransactionId> {
         println!("broadcasting transaction and awaiting confirmation...");
-        let mut rsp = self.view().broadcast_transaction(transaction, true).await?;
+        let mut rsp = match idempotency_key {
+            Some(idempotency_key) => {
+                self.view()
+                    .broadcast_idempotent(transaction, true, claim_swaps, idempotency_key)
+                    .await?
+            }
+            None if claim_swaps => self.view().broadcast_swap_bundle(transaction, true).await?,
+            None => self.view().broadcast_transaction(transaction, true).await?,
+        };
 
         let id = (async move {
-            while let Some(rsp) = rsp.try_next().await? {
+            // Once the transaction has been sent, a failure is the chain rejecting it, unless the
+            // view service couldn't reach the node.
+            while let Some(rsp) = rsp.try_next().await.map_err(|status| {
+                let kind = match status.code() {
+                    tonic::Code::Unavailable | tonic::Code::DeadlineExceeded => ErrorKind::Network,
+                    _ => ErrorKind::Chain,
+                };
+                kind.wrap(status)
+            })? {
                 match rsp.status {
                     Some(status) => match status {
                         BroadcastStatus::BroadcastSuccess(bs) => {
@@ -129,9 +270,18 @@ impl App {
         transaction: Transaction,
     ) -> anyhow::Result<()> {
         println!("broadcasting transaction without confirmation...");
-        self.view()
-            .broadcast_transaction(transaction, false)
-            .await?;
+        match self.next_idempotency_key() {
+            Some(idempotency_key) => {
+                self.view()
+                    .broadcast_idempotent(transaction, false, false, idempotency_key)
+                    .await?
+            }
+            None => {
+                self.view()
+                    .broadcast_transaction(transaction, false)
+                    .await?
+            }
+        };
 
         Ok(())
     }
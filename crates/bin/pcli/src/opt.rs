@@ -1,22 +1,29 @@
 use crate::{
     config::{CustodyConfig, PcliConfig},
+    error::{Classify, ErrorFormat, ErrorKind},
+    output::OutputFormat,
     terminal::ActualTerminal,
     App, Command,
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
 use penumbra_custody::soft_kms::SoftKms;
-use penumbra_proto::box_grpc_svc;
+use penumbra_proto::box_grpc_svc::{self, BoxGrpcService};
 use penumbra_proto::{
     custody::v1::{
         custody_service_client::CustodyServiceClient, custody_service_server::CustodyServiceServer,
     },
     view::v1::{view_service_client::ViewServiceClient, view_service_server::ViewServiceServer},
 };
-use penumbra_view::ViewServer;
+use penumbra_view::{NodePool, Storage, ViewServer};
 use std::io::IsTerminal as _;
+use tonic::{
+    codegen::http,
+    transport::{Certificate, ClientTlsConfig},
+};
+use tower::{util::BoxCloneService, ServiceBuilder};
 use tracing_subscriber::EnvFilter;
 
 #[derive(Debug, Parser)]
@@ -27,6 +34,42 @@ pub struct Opt {
     /// The home directory used to store configuration and data.
     #[clap(long, default_value_t = default_home(), env = "PENUMBRA_PCLI_HOME")]
     pub home: Utf8PathBuf,
+    /// The format in which query and view commands print their results.
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t,
+        env = "PENUMBRA_PCLI_OUTPUT"
+    )]
+    pub output: OutputFormat,
+    /// The format in which a failed command prints its error. Whatever the format, the exit code
+    /// tells apart user, network, custody and chain errors.
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t,
+        env = "PENUMBRA_PCLI_ERROR_FORMAT"
+    )]
+    pub error_format: ErrorFormat,
+    /// Records the inputs to each transaction build into this directory, so that the build can be
+    /// reproduced with `pcli debug replay-plan`. Recordings reveal the contents of the transaction,
+    /// so only share them with people you'd show the transaction to.
+    #[clap(long, global = true, env = "PENUMBRA_PCLI_SAVE_REPLAY")]
+    pub save_replay: Option<Utf8PathBuf>,
+    /// Broadcasts transactions under this idempotency key, so that rerunning a command with the
+    /// same key after it timed out re-sends the transactions it already broadcast, instead of
+    /// sending new ones. Use a fresh key for each command.
+    #[clap(long, global = true)]
+    pub idempotency_key: Option<String>,
+    /// Fetches the chain registry again, even if the cached copy is still fresh.
+    #[clap(long, global = true)]
+    pub refresh_registry: bool,
+    /// Ignores the configured chain registry, neither checking withdrawal destinations nor naming
+    /// counterparty chains.
+    #[clap(long, global = true, conflicts_with = "refresh_registry")]
+    pub no_registry: bool,
 }
 
 impl Opt {
@@ -49,11 +92,14 @@ impl Opt {
 
     pub fn load_config(&self) -> Result<PcliConfig> {
         let path = self.home.join(crate::CONFIG_FILE_NAME);
-        PcliConfig::load(path)
+        PcliConfig::load(path).classify(ErrorKind::User)
     }
 
     pub async fn into_app(self) -> Result<(App, Command)> {
-        let config = self.load_config()?;
+        let mut config = self.load_config()?;
+        if self.no_registry {
+            config.registry = None;
+        }
 
         // Build the custody service...
         let custody = match &config.custody {
@@ -78,6 +124,12 @@ impl Opt {
             }
         };
 
+        // ...pick which fullnode to talk to...
+        let nodes = NodePool::new(config.grpc_url.clone(), config.fallback_grpc_urls.clone());
+        if !self.cmd.offline() && nodes.has_fallbacks() {
+            config.grpc_url = nodes.select().await.classify(ErrorKind::Network)?;
+        }
+
         // ...and the view service...
         let view = match (self.cmd.offline(), &config.view_url) {
             // In offline mode, don't construct a view service at all.
@@ -86,20 +138,45 @@ impl Opt {
                 // Use a remote view service.
                 tracing::info!(%view_url, "using remote view service");
 
-                let ep = tonic::transport::Endpoint::new(view_url.to_string())?;
-                Some(ViewServiceClient::new(box_grpc_svc::connect(ep).await?))
+                let mut ep = tonic::transport::Endpoint::new(view_url.to_string())?;
+                if view_url.scheme() == "https" {
+                    let mut tls = ClientTlsConfig::new();
+                    if let Some(ca_cert) = &config.view_ca_cert {
+                        let pem = std::fs::read(ca_cert).with_context(|| {
+                            format!("could not read view service CA certificate {ca_cert:?}")
+                        })?;
+                        tls = tls.ca_certificate(Certificate::from_pem(pem));
+                    }
+                    ep = ep.tls_config(tls)?;
+                }
+
+                let mut svc = box_grpc_svc::connect(ep).await?;
+                if let Some(token) = &config.view_auth_token {
+                    svc = with_bearer_token(svc, token)?;
+                }
+                Some(ViewServiceClient::new(svc))
             }
             (false, None) => {
                 // Use an in-memory view service.
                 let path = self.home.join(crate::VIEW_FILE_NAME);
                 tracing::info!(%path, "using local view service");
 
-                let svc = ViewServer::load_or_initialize(
+                let storage = Storage::load_or_initialize(
                     Some(path),
                     &config.full_viewing_key,
                     config.grpc_url.clone(),
+                    config.birthday_height,
+                    config.checkpoint,
+                    config.view_passphrase()?,
                 )
                 .await?;
+                if storage
+                    .set_sync_filter(config.grpc_url.clone(), config.sync_filter())
+                    .await?
+                {
+                    eprintln!("The sync filter now allows more notes, rescanning to backfill them");
+                }
+                let svc = ViewServer::with_nodes(storage, nodes).await?;
 
                 // Now build the view and custody clients, doing gRPC with ourselves
                 let svc = ViewServiceServer::new(svc);
@@ -111,11 +188,32 @@ impl Opt {
             view,
             custody,
             config,
+            output: self.output,
+            save_replay: self.save_replay,
+            idempotency_key: self.idempotency_key,
+            broadcasts: 0,
+            home: self.home,
+            refresh_registry: self.refresh_registry,
         };
         Ok((app, self.cmd))
     }
 }
 
+/// Attaches an `authorization` header carrying the given bearer token to every request.
+fn with_bearer_token(svc: BoxGrpcService, token: &str) -> Result<BoxGrpcService> {
+    let header: http::HeaderValue = format!("Bearer {token}")
+        .parse()
+        .context("view auth token must be printable ASCII")?;
+    let svc = ServiceBuilder::new()
+        .map_request(move |mut req: http::Request<_>| {
+            req.headers_mut()
+                .insert(http::header::AUTHORIZATION, header.clone());
+            req
+        })
+        .service(svc);
+    Ok(BoxCloneService::new(svc))
+}
+
 fn default_home() -> Utf8PathBuf {
     let path = ProjectDirs::from("zone", "penumbra", "pcli")
         .expect("Failed to get platform data dir")
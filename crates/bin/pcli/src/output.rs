@@ -0,0 +1,105 @@
+//! Printing the results of commands, either for people or for scripts.
+
+use anyhow::Result;
+use colored_json::ToColoredJson;
+use penumbra_asset::{asset, Value};
+use serde::Serialize;
+
+use crate::App;
+
+/// The format in which commands print their results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables and text.
+    #[default]
+    Table,
+    /// Pretty-printed JSON, for scripting.
+    Json,
+}
+
+/// The result of a command, which can be rendered for people or serialized for scripts.
+pub trait Output: Serialize {
+    /// Renders the result for people, usually as a table.
+    fn render(&self) -> Result<String>;
+}
+
+impl App {
+    /// Prints the result of a command in the format chosen with `--output`.
+    pub fn print(&self, output: &impl Output) -> Result<()> {
+        match self.output {
+            OutputFormat::Table => println!("{}", output.render()?),
+            OutputFormat::Json => {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(output)?.to_colored_json_auto()?
+                )
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A result that has no tabular form, and is printed as JSON in either format.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct Json<T>(pub T);
+
+impl<T: Serialize> Output for Json<T> {
+    fn render(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.0)?.to_colored_json_auto()?)
+    }
+}
+
+/// A result that is rendered as TOML for people, so that it can be edited and resubmitted.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct Toml<T>(pub T);
+
+impl<T: Serialize> Output for Toml<T> {
+    fn render(&self) -> Result<String> {
+        Ok(toml::to_string_pretty(&self.0)?.trim_end().to_string())
+    }
+}
+
+/// A result that is a single line of text, such as a key or an address.
+#[derive(Debug, Serialize)]
+#[serde(transparent)]
+pub struct Text(pub String);
+
+impl Output for Text {
+    fn render(&self) -> Result<String> {
+        Ok(self.0.clone())
+    }
+}
+
+/// A value, both in base units, for scripts, and formatted in the asset's display units.
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueOutput {
+    /// The amount in base units, as a decimal string, since it may not fit in a JSON number.
+    pub amount: String,
+    /// The asset ID.
+    pub asset_id: String,
+    /// The base denomination of the asset, if it is known.
+    pub denom: Option<String>,
+    /// The value, formatted in the best display unit of the asset.
+    pub formatted: String,
+}
+
+impl ValueOutput {
+    pub fn new(value: Value, assets: &asset::Cache) -> Self {
+        Self {
+            amount: value.amount.to_string(),
+            asset_id: value.asset_id.to_string(),
+            denom: assets
+                .get(&value.asset_id)
+                .map(|metadata| metadata.base_denom().denom),
+            formatted: value.format(assets),
+        }
+    }
+}
+
+impl std::fmt::Display for ValueOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.formatted)
+    }
+}
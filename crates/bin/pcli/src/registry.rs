@@ -0,0 +1,241 @@
+//! A signed registry of the chains Penumbra is connected to over IBC, used to check withdrawal
+//! destinations and to show counterparty chains by name.
+//!
+//! The registry is published as a JSON document, signed with an Ed25519 key that `pcli` is
+//! configured to trust. It's cached in the `pcli` home directory, and fetched again once the
+//! cached copy is older than the configured maximum age.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use comfy_table::Table;
+use serde::{Deserialize, Serialize};
+use serde_with::{hex::Hex, serde_as};
+use url::Url;
+
+use crate::{output::Output, App};
+
+/// The name of the file the registry is cached in, in the `pcli` home directory.
+pub const REGISTRY_FILE_NAME: &str = "registry.json";
+
+/// Where to fetch the registry from, and the key it must be signed with.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RegistryConfig {
+    /// The URL the signed registry is published at.
+    pub url: Url,
+    /// The hex-encoded Ed25519 verification key the registry must be signed with.
+    #[serde_as(as = "Hex")]
+    pub verification_key: [u8; 32],
+    /// How long a cached copy of the registry is used before it's fetched again, in seconds.
+    #[serde(default = "default_max_age_secs")]
+    pub max_age_secs: u64,
+}
+
+fn default_max_age_secs() -> u64 {
+    24 * 60 * 60
+}
+
+/// A registry, as published: the registry's JSON encoding, and a signature over its bytes.
+///
+/// The registry is kept encoded, so that the signature is checked against exactly the bytes
+/// that were signed.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SignedRegistry {
+    pub registry: String,
+    #[serde_as(as = "Hex")]
+    pub signature: [u8; 64],
+}
+
+impl SignedRegistry {
+    /// Checks the signature with `verification_key`, and decodes the registry.
+    pub fn verify(&self, verification_key: &[u8; 32]) -> Result<Registry> {
+        let vk = ed25519_consensus::VerificationKey::try_from(*verification_key)
+            .context("invalid registry verification key")?;
+        vk.verify(&self.signature.into(), self.registry.as_bytes())
+            .context("registry signature is invalid")?;
+        serde_json::from_str(&self.registry).context("cannot parse registry")
+    }
+}
+
+/// The chains Penumbra is connected to over IBC.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Registry {
+    /// The chain ID of the Penumbra chain the registry describes.
+    pub chain_id: String,
+    pub chains: Vec<Chain>,
+}
+
+/// A counterparty chain, and the channel used to reach it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Chain {
+    /// The counterparty's chain ID, e.g. `osmosis-1`.
+    pub chain_id: String,
+    /// The counterparty's name, for display, e.g. `Osmosis`.
+    pub display_name: String,
+    /// The Bech32 prefix of the counterparty's addresses, e.g. `osmo`.
+    pub address_prefix: String,
+    /// The ID of the transfer channel on Penumbra, e.g. `channel-0`.
+    pub channel_id: String,
+    /// The ID of the transfer channel on the counterparty.
+    pub counterparty_channel_id: String,
+    /// The denoms to prefer when showing assets from this chain, e.g. `uosmo`.
+    #[serde(default)]
+    pub preferred_denoms: Vec<String>,
+}
+
+impl Registry {
+    /// The chain reached over the Penumbra transfer channel `channel_id`.
+    pub fn chain_by_channel(&self, channel_id: &str) -> Option<&Chain> {
+        self.chains.iter().find(|c| c.channel_id == channel_id)
+    }
+
+    /// Checks that an ICS-20 withdrawal over `channel_id` to `address` goes where it's meant to:
+    /// that the channel is registered, and that the address belongs to the chain at its end.
+    pub fn check_withdrawal(&self, channel_id: &str, address: &str) -> Result<&Chain> {
+        let chain = self.chain_by_channel(channel_id).with_context(|| {
+            format!("{channel_id} is not a registered channel, so the destination can't be checked")
+        })?;
+        let (prefix, _, _) = bech32::decode(address)
+            .with_context(|| format!("{address} is not a valid {} address", chain.display_name))?;
+        if prefix != chain.address_prefix {
+            anyhow::bail!(
+                "{channel_id} leads to {} ({}), whose addresses start with {}, not {prefix}",
+                chain.display_name,
+                chain.chain_id,
+                chain.address_prefix,
+            );
+        }
+        Ok(chain)
+    }
+}
+
+impl Output for Registry {
+    fn render(&self) -> Result<String> {
+        let mut table = Table::new();
+        table.set_header(vec![
+            "Chain",
+            "Chain ID",
+            "Channel ID",
+            "Counterparty Channel ID",
+            "Address Prefix",
+            "Preferred Denoms",
+        ]);
+        for chain in &self.chains {
+            table.add_row(vec![
+                chain.display_name.clone(),
+                chain.chain_id.clone(),
+                chain.channel_id.clone(),
+                chain.counterparty_channel_id.clone(),
+                chain.address_prefix.clone(),
+                chain.preferred_denoms.join(", "),
+            ]);
+        }
+        Ok(table.to_string())
+    }
+}
+
+impl App {
+    /// The chain registry, if one is configured.
+    ///
+    /// The cached copy is used while it's fresh, and otherwise the registry is fetched again. If
+    /// fetching it fails, a stale cached copy is used with a warning.
+    pub async fn registry(&self) -> Result<Option<Registry>> {
+        let Some(config) = &self.config.registry else {
+            return Ok(None);
+        };
+        let cache = self.home.join(REGISTRY_FILE_NAME);
+
+        let cached = read_cache(&cache, &config.verification_key);
+        if let Ok((registry, age)) = &cached {
+            if !self.refresh_registry && age.as_secs() < config.max_age_secs {
+                return Ok(Some(registry.clone()));
+            }
+        }
+
+        match fetch(&config.url).await {
+            Ok(signed) => {
+                let registry = signed.verify(&config.verification_key)?;
+                std::fs::write(&cache, serde_json::to_string_pretty(&signed)?)
+                    .with_context(|| format!("cannot cache registry at {cache}"))?;
+                Ok(Some(registry))
+            }
+            Err(e) => match cached {
+                Ok((registry, _)) => {
+                    tracing::warn!(?e, "failed to fetch registry, using cached copy");
+                    Ok(Some(registry))
+                }
+                Err(_) => Err(e),
+            },
+        }
+    }
+}
+
+/// Reads and verifies the cached registry, returning it with its age.
+fn read_cache(path: &Utf8Path, verification_key: &[u8; 32]) -> Result<(Registry, Duration)> {
+    let age = std::fs::metadata(path)?
+        .modified()?
+        .elapsed()
+        .unwrap_or_default();
+    let signed: SignedRegistry = serde_json::from_slice(&std::fs::read(path)?)?;
+    Ok((signed.verify(verification_key)?, age))
+}
+
+async fn fetch(url: &Url) -> Result<SignedRegistry> {
+    reqwest::get(url.clone())
+        .await
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("cannot fetch registry from {url}"))?
+        .json()
+        .await
+        .with_context(|| format!("cannot parse registry from {url}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use bech32::ToBase32;
+
+    use super::*;
+
+    fn registry() -> Registry {
+        Registry {
+            chain_id: "penumbra-1".to_string(),
+            chains: vec![Chain {
+                chain_id: "osmosis-1".to_string(),
+                display_name: "Osmosis".to_string(),
+                address_prefix: "osmo".to_string(),
+                channel_id: "channel-0".to_string(),
+                counterparty_channel_id: "channel-1000".to_string(),
+                preferred_denoms: vec!["uosmo".to_string()],
+            }],
+        }
+    }
+
+    #[test]
+    fn signed_registry_verifies() {
+        let sk = ed25519_consensus::SigningKey::new(rand_core::OsRng);
+        let encoded = serde_json::to_string(&registry()).unwrap();
+        let mut signed = SignedRegistry {
+            signature: sk.sign(encoded.as_bytes()).to_bytes(),
+            registry: encoded,
+        };
+        let vk = sk.verification_key().to_bytes();
+        assert_eq!(signed.verify(&vk).unwrap().chains.len(), 1);
+
+        signed.registry = signed.registry.replace("channel-0", "channel-9");
+        assert!(signed.verify(&vk).is_err());
+    }
+
+    #[test]
+    fn withdrawal_destination_must_match_channel() {
+        let registry = registry();
+        let osmo = bech32::encode("osmo", [0u8; 20].to_base32(), bech32::Variant::Bech32).unwrap();
+        let cosmos =
+            bech32::encode("cosmos", [0u8; 20].to_base32(), bech32::Variant::Bech32).unwrap();
+        assert!(registry.check_withdrawal("channel-0", &osmo).is_ok());
+        assert!(registry.check_withdrawal("channel-0", &cosmos).is_err());
+        assert!(registry.check_withdrawal("channel-1", &osmo).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+//! Rendering of command results for the terminal.
+//!
+//! These helpers are shared between commands, so that values, addresses and tables look the same
+//! wherever they're printed.
+
+use comfy_table::{presets, CellAlignment, Table};
+use penumbra_asset::asset::{Cache, Id};
+use penumbra_asset::Value;
+use penumbra_fee::Fee;
+use penumbra_num::Amount;
+
+/// A table with no borders, the style of every table `pcli` prints.
+pub fn table() -> Table {
+    let mut table = Table::new();
+    table.load_preset(presets::NOTHING);
+    table
+}
+
+/// A [`table`] with a header row.
+pub fn table_with_header<T: ToString>(header: impl IntoIterator<Item = T>) -> Table {
+    let mut table = table();
+    table.set_header(header.into_iter().map(|cell| cell.to_string()));
+    table
+}
+
+/// Aligns the given columns of the table to the right, as for numbers.
+pub fn align_right(table: &mut Table, columns: impl IntoIterator<Item = usize>) {
+    for column in columns {
+        table
+            .get_column_mut(column)
+            .unwrap_or_else(|| panic!("column {column} exists"))
+            .set_cell_alignment(CellAlignment::Right);
+    }
+}
+
+/// Formats values using the metadata of known assets.
+#[derive(Clone, Copy)]
+pub struct Values<'a> {
+    assets: &'a Cache,
+}
+
+impl<'a> Values<'a> {
+    pub fn new(assets: &'a Cache) -> Self {
+        Self { assets }
+    }
+
+    /// Formats a value in the best unit of its asset, or in base units of its asset ID if the
+    /// asset is unknown.
+    pub fn value(&self, value: Value) -> String {
+        value.format(self.assets)
+    }
+
+    /// Formats an amount of an asset, like [`Values::value`].
+    pub fn amount(&self, amount: Amount, asset_id: Id) -> String {
+        self.value(Value { amount, asset_id })
+    }
+
+    /// Formats a fee, like [`Values::value`].
+    pub fn fee(&self, fee: &Fee) -> String {
+        self.value(fee.0)
+    }
+}
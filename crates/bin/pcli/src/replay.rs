@@ -0,0 +1,77 @@
+//! Recording the inputs to transaction building, so that failed builds can be reproduced.
+
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use penumbra_proto::{core::transaction::v1 as pb, DomainType, Message};
+use penumbra_transaction::{AuthorizationData, TransactionPlan, WitnessData};
+
+/// Everything needed to build a transaction again, exactly as it was built the first time.
+///
+/// The plan fixes all of the randomness used in proving, and the witness and authorization data
+/// pin down the rest, so replaying a recording reproduces the same proofs, or the same error.
+#[derive(Clone, Debug)]
+pub struct Replay {
+    pub plan: TransactionPlan,
+    pub witness_data: WitnessData,
+    pub auth_data: AuthorizationData,
+}
+
+impl Replay {
+    /// Encodes the recording as the length-delimited plan, witness data, and authorization data.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        // Encoding into a `Vec` can't fail, since it grows as needed.
+        self.plan
+            .to_proto()
+            .encode_length_delimited(&mut bytes)
+            .expect("can encode into a vec");
+        self.witness_data
+            .to_proto()
+            .encode_length_delimited(&mut bytes)
+            .expect("can encode into a vec");
+        self.auth_data
+            .to_proto()
+            .encode_length_delimited(&mut bytes)
+            .expect("can encode into a vec");
+        bytes
+    }
+
+    pub fn decode(mut bytes: &[u8]) -> Result<Self> {
+        let plan = pb::TransactionPlan::decode_length_delimited(&mut bytes)
+            .context("can't decode transaction plan")?
+            .try_into()?;
+        let witness_data = pb::WitnessData::decode_length_delimited(&mut bytes)
+            .context("can't decode witness data")?
+            .try_into()?;
+        let auth_data = pb::AuthorizationData::decode_length_delimited(&mut bytes)
+            .context("can't decode authorization data")?
+            .try_into()?;
+        anyhow::ensure!(bytes.is_empty(), "unexpected trailing data after recording");
+
+        Ok(Self {
+            plan,
+            witness_data,
+            auth_data,
+        })
+    }
+
+    /// Writes the recording into `dir`, named after the effect hash of the transaction.
+    pub fn save(&self, dir: &Utf8Path) -> Result<Utf8PathBuf> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("can't create replay directory {dir}"))?;
+        let name = match &self.auth_data.effect_hash {
+            Some(effect_hash) => hex::encode(effect_hash.as_ref()),
+            None => "unauthorized".to_string(),
+        };
+        let path = dir.join(format!("{name}.bin"));
+        std::fs::write(&path, self.encode())
+            .with_context(|| format!("can't write replay recording to {path}"))?;
+        Ok(path)
+    }
+
+    pub fn load(path: &Utf8Path) -> Result<Self> {
+        let bytes = std::fs::read(path)
+            .with_context(|| format!("can't read replay recording from {path}"))?;
+        Self::decode(&bytes).with_context(|| format!("can't parse replay recording {path}"))
+    }
+}
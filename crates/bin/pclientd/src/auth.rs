@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tonic::{
+    service::Interceptor,
+    transport::{Identity, ServerTlsConfig},
+    Request, Status,
+};
+
+/// TLS configuration for the gRPC endpoint.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TlsConfig {
+    /// Path to the PEM-encoded certificate chain to serve.
+    pub cert_path: PathBuf,
+    /// Path to the PEM-encoded private key for the certificate.
+    pub key_path: PathBuf,
+}
+
+impl TlsConfig {
+    /// Loads the certificate and key into a tonic TLS config.
+    pub fn load(&self) -> Result<ServerTlsConfig> {
+        let cert = std::fs::read(&self.cert_path)
+            .with_context(|| format!("could not read TLS certificate {:?}", self.cert_path))?;
+        let key = std::fs::read(&self.key_path)
+            .with_context(|| format!("could not read TLS key {:?}", self.key_path))?;
+        Ok(ServerTlsConfig::new().identity(Identity::from_pem(cert, key)))
+    }
+}
+
+/// A gRPC interceptor requiring requests to carry an `authorization: Bearer <token>` header.
+///
+/// If no token is configured, all requests are let through.
+#[derive(Clone, Debug, Default)]
+pub struct BearerAuth {
+    /// The hash of the expected token, so that comparisons take the same time
+    /// regardless of how much of the token a client got right.
+    token_hash: Option<Arc<[u8; 32]>>,
+}
+
+impl BearerAuth {
+    pub fn new(token: Option<&str>) -> Self {
+        Self {
+            token_hash: token.map(|token| Arc::new(Sha256::digest(token.as_bytes()).into())),
+        }
+    }
+}
+
+impl Interceptor for BearerAuth {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let Some(expected) = &self.token_hash else {
+            return Ok(request);
+        };
+
+        let provided = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .ok_or_else(|| Status::unauthenticated("missing bearer token"))?;
+
+        let provided: [u8; 32] = Sha256::digest(provided.as_bytes()).into();
+        if provided
+            .iter()
+            .zip(expected.iter())
+            .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+            != 0
+        {
+            return Err(Status::unauthenticated("invalid bearer token"));
+        }
+
+        Ok(request)
+    }
+}
@@ -8,6 +8,7 @@ use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use penumbra_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_custody::soft_kms::{self, SoftKms};
 use penumbra_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
@@ -19,7 +20,7 @@ use penumbra_proto::{
     custody::v1::custody_service_server::CustodyServiceServer,
     view::v1::view_service_server::ViewServiceServer,
 };
-use penumbra_view::{Storage, ViewServer};
+use penumbra_view::{NodePool, Storage, ViewServer};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -30,7 +31,10 @@ use std::str::FromStr;
 use tonic::transport::Server;
 use url::Url;
 
+mod auth;
+pub mod metrics;
 mod proxy;
+pub use auth::{BearerAuth, TlsConfig};
 pub use proxy::{
     AppQueryProxy, ChainQueryProxy, CompactBlockQueryProxy, DexQueryProxy, DexSimulationProxy,
     GovernanceQueryProxy, SctQueryProxy, ShieldedPoolQueryProxy, StakeQueryProxy,
@@ -47,10 +51,24 @@ pub struct PclientdConfig {
     pub full_viewing_key: FullViewingKey,
     /// The URL of the gRPC endpoint used to talk to pd.
     pub grpc_url: Url,
+    /// The URLs of other pd gRPC endpoints serving the same chain, in order of preference,
+    /// to fail over to when the one in use is unhealthy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallback_grpc_urls: Vec<Url>,
     /// The address to bind to serve gRPC.
     pub bind_addr: SocketAddr,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// If set, clients must present this token as a bearer token in the `authorization`
+    /// header to use the view and custody services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_token: Option<String>,
+    /// If set, serve gRPC over TLS rather than plaintext.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    /// If set, the address to bind to serve Prometheus metrics.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics_bind_addr: Option<SocketAddr>,
 }
 
 impl PclientdConfig {
@@ -159,12 +177,12 @@ impl Opt {
             .into_inner()
             .try_into()?;
 
-        Storage::initialize(Some(self.sqlite_path()), fvk.clone(), params).await
+        Storage::initialize(Some(self.sqlite_path()), fvk.clone(), params, None).await
     }
 
     async fn load_or_init_sqlite(&self, fvk: &FullViewingKey, grpc_url: &Url) -> Result<Storage> {
         if self.sqlite_path().exists() {
-            Ok(Storage::load(self.sqlite_path()).await?)
+            Ok(Storage::load(self.sqlite_path(), None).await?)
         } else {
             self.init_sqlite(fvk, grpc_url).await
         }
@@ -273,7 +291,11 @@ impl Opt {
                     kms_config,
                     full_viewing_key,
                     grpc_url: grpc_url.clone(),
+                    fallback_grpc_urls: Vec::new(),
                     bind_addr: *bind_addr,
+                    auth_token: None,
+                    tls: None,
+                    metrics_bind_addr: None,
                 };
 
                 let encoded = toml::to_string_pretty(&client_config)
@@ -290,10 +312,16 @@ impl Opt {
                 Ok(())
             }
             Command::Start {} => {
-                let config = PclientdConfig::load(opt.config_path()).context(
+                let mut config = PclientdConfig::load(opt.config_path()).context(
                     "Failed to load pclientd config file. Have you run `pclientd init` with a FVK?",
                 )?;
 
+                let nodes =
+                    NodePool::new(config.grpc_url.clone(), config.fallback_grpc_urls.clone());
+                if nodes.has_fallbacks() {
+                    config.grpc_url = nodes.select().await?;
+                }
+
                 tracing::info!(?opt.home, ?config.bind_addr, %config.grpc_url, "starting pclientd");
                 let storage = opt
                     .load_or_init_sqlite(&config.full_viewing_key, &config.grpc_url)
@@ -305,6 +333,28 @@ impl Opt {
                         .connect()
                         .await?;
 
+                if let Some(metrics_bind_addr) = config.metrics_bind_addr {
+                    // This spawns the HTTP service that lets Prometheus pull metrics from `pclientd`.
+                    PrometheusBuilder::new()
+                        .with_http_listener(metrics_bind_addr)
+                        .set_buckets_for_metric(
+                            Matcher::Full(metrics::PCLIENTD_GRPC_REQUEST_DURATION.to_string()),
+                            metrics::GRPC_REQUEST_DURATION_BUCKETS,
+                        )?
+                        .install()
+                        .with_context(|| {
+                            format!(
+                                "failed to serve metrics; make sure {metrics_bind_addr} is available"
+                            )
+                        })?;
+                    metrics::register_metrics();
+                    tokio::spawn(metrics::poll_sync_metrics(
+                        storage.clone(),
+                        opt.sqlite_path(),
+                        proxy_channel.clone(),
+                    ));
+                }
+
                 let app_query_proxy = AppQueryProxy(proxy_channel.clone());
                 let governance_query_proxy = GovernanceQueryProxy(proxy_channel.clone());
                 let dex_query_proxy = DexQueryProxy(proxy_channel.clone());
@@ -317,14 +367,39 @@ impl Opt {
                 let compact_block_query_proxy = CompactBlockQueryProxy(proxy_channel.clone());
                 let tendermint_proxy_proxy = TendermintProxyProxy(proxy_channel.clone());
 
-                let view_service =
-                    ViewServiceServer::new(ViewServer::new(storage, config.grpc_url).await?);
+                // The proxied services only expose public chain data, but the view and
+                // custody services must not be reachable by anyone who can reach the socket.
+                let auth = BearerAuth::new(config.auth_token.as_deref());
+                if config.auth_token.is_none() && !config.bind_addr.ip().is_loopback() {
+                    tracing::warn!(
+                        ?config.bind_addr,
+                        "serving view and custody services on a non-loopback address without an auth token"
+                    );
+                }
+
+                // Queue transactions accepted for broadcast in storage, so that they're re-sent
+                // until they land even if pclientd restarts in the meantime.
+                let view_server = ViewServer::with_nodes(storage, nodes)
+                    .await?
+                    .with_broadcast_queue();
+                let view_service = ViewServiceServer::with_interceptor(view_server, auth.clone());
                 let custody_service = config.kms_config.as_ref().map(|kms_config| {
-                    CustodyServiceServer::new(SoftKms::new(kms_config.spend_key.clone().into()))
+                    CustodyServiceServer::with_interceptor(
+                        SoftKms::new(kms_config.spend_key.clone().into()),
+                        auth.clone(),
+                    )
                 });
 
-                let server = Server::builder()
+                let mut server = Server::builder();
+                if let Some(tls) = &config.tls {
+                    server = server
+                        .tls_config(tls.load()?)
+                        .context("could not configure TLS")?;
+                }
+
+                let server = server
                     .accept_http1(true)
+                    .layer(metrics::GrpcMetricsLayer)
                     .add_service(tonic_web::enable(view_service))
                     .add_optional_service(custody_service.map(tonic_web::enable))
                     .add_service(tonic_web::enable(app_query_proxy))
@@ -0,0 +1,169 @@
+//! Crate-specific metrics functionality.
+//!
+//! This module re-exports the contents of the `metrics` crate.  This is
+//! effectively a way to monkey-patch the functions in this module into the
+//! `metrics` crate, at least from the point of view of the other code in this
+//! crate.
+//!
+//! Code in this crate that wants to use metrics should `use crate::metrics;`,
+//! so that this module shadows the `metrics` crate.
+//!
+//! This trick is probably good to avoid in general, because it could be
+//! confusing, but in this limited case, it seems like a clean option.
+
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use camino::Utf8PathBuf;
+use penumbra_proto::util::tendermint_proxy::v1::{
+    tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
+};
+use penumbra_view::Storage;
+use tonic::{codegen::http, transport::Channel};
+
+pub use metrics::*;
+
+/// Registers all metrics used by this crate, and by the view service.
+pub fn register_metrics() {
+    penumbra_view::register_metrics();
+
+    describe_gauge!(
+        PCLIENTD_LATEST_BLOCK_HEIGHT,
+        Unit::Count,
+        "The latest block height reported by the fullnode"
+    );
+    describe_gauge!(
+        PCLIENTD_SYNC_HEIGHT_LAG,
+        Unit::Count,
+        "The number of blocks the view service has yet to scan to catch up with the fullnode"
+    );
+    describe_gauge!(
+        PCLIENTD_STORAGE_SIZE_BYTES,
+        Unit::Bytes,
+        "The size of the view service's database on disk"
+    );
+    describe_counter!(
+        PCLIENTD_GRPC_REQUESTS_TOTAL,
+        Unit::Count,
+        "The total number of gRPC requests served, labeled by method"
+    );
+    describe_histogram!(
+        PCLIENTD_GRPC_REQUEST_DURATION,
+        Unit::Seconds,
+        "The time taken to respond to gRPC requests, labeled by method"
+    );
+}
+
+pub const PCLIENTD_LATEST_BLOCK_HEIGHT: &str = "penumbra_pclientd_latest_block_height";
+pub const PCLIENTD_SYNC_HEIGHT_LAG: &str = "penumbra_pclientd_sync_height_lag";
+pub const PCLIENTD_STORAGE_SIZE_BYTES: &str = "penumbra_pclientd_storage_size_bytes";
+pub const PCLIENTD_GRPC_REQUESTS_TOTAL: &str = "penumbra_pclientd_grpc_requests_total";
+pub const PCLIENTD_GRPC_REQUEST_DURATION: &str = "penumbra_pclientd_grpc_request_duration_seconds";
+
+/// Histogram buckets for gRPC request durations, in seconds.
+///
+/// These span cheap queries answered from local storage up to transaction builds,
+/// which can take tens of seconds while proving.
+pub const GRPC_REQUEST_DURATION_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0,
+];
+
+/// How often to poll the fullnode and the database for the gauges that aren't
+/// updated as a side effect of syncing.
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Periodically records the sync lag and the size of the database.
+///
+/// Errors are logged rather than returned, so that a flaky fullnode connection
+/// doesn't take down the metrics endpoint.
+pub async fn poll_sync_metrics(storage: Storage, sqlite_path: Utf8PathBuf, channel: Channel) {
+    let mut client = TendermintProxyServiceClient::new(channel);
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+
+        let latest_block_height = match client.get_status(GetStatusRequest {}).await {
+            Ok(rsp) => rsp
+                .into_inner()
+                .sync_info
+                .map(|sync_info| sync_info.latest_block_height),
+            Err(e) => {
+                tracing::warn!(?e, "could not fetch fullnode status for metrics");
+                None
+            }
+        };
+        let sync_height = match storage.last_sync_height().await {
+            Ok(sync_height) => sync_height,
+            Err(e) => {
+                tracing::warn!(?e, "could not fetch sync height for metrics");
+                None
+            }
+        };
+
+        if let Some(latest_block_height) = latest_block_height {
+            gauge!(PCLIENTD_LATEST_BLOCK_HEIGHT).set(latest_block_height as f64);
+            let lag = latest_block_height.saturating_sub(sync_height.map_or(0, |h| h + 1));
+            gauge!(PCLIENTD_SYNC_HEIGHT_LAG).set(lag as f64);
+        }
+
+        // SQLite keeps recent writes in a separate write-ahead log until checkpointing.
+        let size: u64 = [sqlite_path.to_string(), format!("{sqlite_path}-wal")]
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+        gauge!(PCLIENTD_STORAGE_SIZE_BYTES).set(size as f64);
+    }
+}
+
+/// A [`tower::Layer`] recording the count and latency of gRPC requests.
+///
+/// For streaming methods, the latency is the time until the stream is opened.
+#[derive(Clone, Debug, Default)]
+pub struct GrpcMetricsLayer;
+
+impl<S> tower::Layer<S> for GrpcMetricsLayer {
+    type Service = GrpcMetrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcMetrics { inner }
+    }
+}
+
+/// The service produced by [`GrpcMetricsLayer`].
+#[derive(Clone, Debug)]
+pub struct GrpcMetrics<S> {
+    inner: S,
+}
+
+impl<S, ReqBody> tower::Service<http::Request<ReqBody>> for GrpcMetrics<S>
+where
+    S: tower::Service<http::Request<ReqBody>>,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<S::Response, S::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        // gRPC requests are routed by path, which is `/<package>.<Service>/<Method>`.
+        let method = req.uri().path().to_owned();
+        let start = Instant::now();
+        let response = self.inner.call(req);
+        Box::pin(async move {
+            let response = response.await;
+            counter!(PCLIENTD_GRPC_REQUESTS_TOTAL, "method" => method.clone()).increment(1);
+            histogram!(PCLIENTD_GRPC_REQUEST_DURATION, "method" => method)
+                .record(start.elapsed().as_secs_f64());
+            response
+        })
+    }
+}
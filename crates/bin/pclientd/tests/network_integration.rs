@@ -37,11 +37,15 @@ fn generate_config() -> anyhow::Result<PclientdConfig> {
         grpc_url: std::env::var("PENUMBRA_NODE_PD_URL")
             .unwrap_or_else(|_| "http://127.0.0.1:8080".to_owned())
             .parse()?,
+        fallback_grpc_urls: Vec::new(),
         bind_addr: "127.0.0.1:8081".parse()?,
         kms_config: Some(soft_kms::Config {
             spend_key: test_keys::SPEND_KEY.clone(),
             auth_policy: Vec::new(),
         }),
+        auth_token: None,
+        tls: None,
+        metrics_bind_addr: None,
     })
 }
 
@@ -186,6 +190,8 @@ async fn transaction_send_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            claim_swaps: false,
+            idempotency_key: String::new(),
         })
         .await?
         .into_inner();
@@ -370,6 +376,8 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            claim_swaps: false,
+            idempotency_key: String::new(),
         })
         .await?
         .into_inner();
@@ -484,6 +492,8 @@ async fn swap_claim_flow() -> anyhow::Result<()> {
         .broadcast_transaction(BroadcastTransactionRequest {
             transaction: Some(tx),
             await_detection: true,
+            claim_swaps: false,
+            idempotency_key: String::new(),
         })
         .await?
         .into_inner();
@@ -6,12 +6,20 @@ use {
     url::Url,
 };
 
+use crate::keys::ConsensusKeyFormat;
+
 #[derive(Debug, Parser)]
 #[clap(name = "pd", about = "The Penumbra daemon.", version)]
 pub struct Opt {
     /// Enable Tokio Console support.
     #[clap(long)]
     pub tokio_console: bool,
+    /// Per-component log levels, as comma-separated directives such as `dex=debug,stake=info`.
+    ///
+    /// These take precedence over `RUST_LOG`. Components can be named by their short names,
+    /// and other directives are passed on as they are.
+    #[clap(long, env = "PENUMBRA_PD_LOG_FILTER")]
+    pub log_filter: Option<String>,
     /// Command to run.
     #[clap(subcommand)]
     pub cmd: RootCommand,
@@ -63,6 +71,14 @@ pub enum RootCommand {
         /// ratelimits. This option has no effect if `--grpc-auto-https` is not set.
         #[clap(long, display_order = 201)]
         acme_staging: bool,
+        /// Limit how many requests each public gRPC method handles at once and per second,
+        /// as configured in this file, refusing requests beyond the limits with
+        /// `RESOURCE_EXHAUSTED`.
+        ///
+        /// Requests from the addresses the file exempts, by default the loopback addresses,
+        /// are never refused. Without this option, requests are not limited.
+        #[clap(long, value_name = "FILE", display_order = 202)]
+        rpc_limits: Option<PathBuf>,
         /// Bind the metrics endpoint to this socket.
         #[clap(
             short,
@@ -96,6 +112,43 @@ pub enum RootCommand {
         /// But, it is a potential DoS vector, so it is disabled by default.
         #[clap(short, long, display_order = 500)]
         enable_expensive_rpc: bool,
+
+        /// Serve the log filter at `/log-filter` on the gRPC server's address, and allow replacing
+        /// it at runtime by `PUT`ting new directives there, e.g. `dex=debug,stake=info`.
+        /// Anyone who can reach the endpoint can change the log levels, so it is disabled by default.
+        #[clap(long, display_order = 600)]
+        enable_log_filter_rpc: bool,
+
+        /// Don't cache the responses of hot read-only RPCs, such as the validator set and the
+        /// chain parameters, which are otherwise served from memory until the next block.
+        #[clap(long, display_order = 601)]
+        disable_rpc_cache: bool,
+
+        /// Profile how long each component takes to execute each block, recording the timings in
+        /// the `penumbra_pd_block_phase_duration_seconds` metric.
+        #[clap(long, display_order = 700)]
+        profile_blocks: bool,
+        /// Also write each block's profile to this directory, as `<height>.folded`, in the
+        /// collapsed stack format read by flame graph tools. Implies `--profile-blocks`.
+        #[clap(long, value_name = "DIR", display_order = 701)]
+        profile_dir: Option<PathBuf>,
+
+        /// Refuse to admit transactions into the local mempool that withdraw funds over IBC to
+        /// any of the addresses listed in this file, one per line.
+        ///
+        /// This only affects which transactions this node relays and proposes: transactions
+        /// included in blocks proposed by other validators are executed regardless.
+        #[clap(long, value_name = "FILE", display_order = 800)]
+        screening_deny_list: Option<PathBuf>,
+
+        /// Monitor a remote signer (e.g. `tmkms` or `horcrux`) signing for this validator,
+        /// following the policy in this file, which sets when to fail over and which hooks
+        /// to run to do so.
+        ///
+        /// The validator's signatures are checked in the commits of the CometBFT node at
+        /// `--cometbft-addr`, and recorded in the `penumbra_pd_remote_signer_*` metrics.
+        #[clap(long, value_name = "FILE", display_order = 900)]
+        remote_signer_policy: Option<PathBuf>,
     },
     /// Generate, join, or reset a testnet.
     Testnet {
@@ -131,6 +184,60 @@ pub enum RootCommand {
         /// unless the migration logic overrides it.
         genesis_start: Option<tendermint::time::Time>,
     },
+    /// Runs an in-process chain, driven by randomized transactions between simulated
+    /// wallets, checking invariants after every block.
+    ///
+    /// Useful as a long-running soak test, or to replay a scenario from its seed.
+    #[cfg(feature = "simulate")]
+    Simulate {
+        /// The number of blocks to simulate. If unset, runs until interrupted.
+        #[clap(long, display_order = 100)]
+        blocks: Option<u64>,
+        /// The seed for the randomized scenario. If unset, a random seed is chosen and logged,
+        /// so that a failing run can be replayed.
+        #[clap(long, display_order = 200)]
+        seed: Option<u64>,
+        /// The maximum number of transactions to include in each block.
+        #[clap(long, default_value = "4", display_order = 300)]
+        max_txs_per_block: usize,
+        /// The number of simulated wallets.
+        #[clap(long, default_value = "3", display_order = 400)]
+        wallets: usize,
+    },
+    /// Manage the keys used by this node.
+    Keys {
+        #[clap(subcommand)]
+        keys_cmd: KeysCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum KeysCommand {
+    /// Import an existing CometBFT validator consensus key, e.g. when migrating a validator
+    /// from another chain.
+    ///
+    /// Reads a `priv_validator_key.json`, a base64 `softsign` key file, or a remote signer's
+    /// TOML config pointing at one, validates it, and installs it as this node's
+    /// `priv_validator_key.json`.
+    ImportConsensus {
+        /// The file to import the key from.
+        #[clap(long, value_name = "FILE", display_order = 100)]
+        from: PathBuf,
+        /// The format of the file to import.
+        #[clap(long, value_enum, default_value = "auto", display_order = 200)]
+        format: ConsensusKeyFormat,
+        /// The home directory of the CometBFT instance to install the key into.
+        /// If unset, defaults to ~/.penumbra/testnet_data/node0/cometbft.
+        #[clap(long, env = "PENUMBRA_PD_COMETBFT_HOME", display_order = 300)]
+        cometbft_home: Option<PathBuf>,
+        /// Only import the key if its public key matches this `penumbravalconspub` key,
+        /// e.g. the consensus key in the validator's on-chain definition.
+        #[clap(long, value_name = "CONSENSUS_KEY", display_order = 400)]
+        expected_key: Option<String>,
+        /// Replace an existing, different consensus key.
+        #[clap(long, display_order = 500)]
+        force: bool,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -159,6 +266,11 @@ pub enum TestnetCommand {
         /// Path to JSON file containing initial validator configs [default: latest testnet].
         #[clap(long, parse(from_os_str))]
         validators_input_file: Option<PathBuf>,
+        /// The intended genesis supply of some assets, as comma-separated values, e.g.
+        /// `1000000penumbra,5000gm`. Generation fails unless the allocations of each of
+        /// these assets add up to exactly its intended supply.
+        #[clap(long)]
+        intended_supply: Option<String>,
         /// Testnet name [default: latest testnet].
         #[clap(long)]
         chain_id: Option<String>,
@@ -212,6 +324,10 @@ pub enum TestnetCommand {
         /// When generating Tendermint config, use this socket to bind the Tendermint P2P service.
         #[clap(long, env = "PENUMBRA_PD_TM_P2P_BIND", default_value = "0.0.0.0:26656")]
         tendermint_p2p_bind: SocketAddr,
+        /// Have CometBFT listen on this socket for a remote signer, such as `tmkms` or
+        /// `horcrux`, to sign on the validator's behalf, rather than signing with a local key.
+        #[clap(long, env = "PENUMBRA_PD_TM_PRIV_VALIDATOR_LADDR")]
+        priv_validator_laddr: Option<SocketAddr>,
     },
 
     /// Reset all `pd` testnet state.
@@ -0,0 +1,332 @@
+//! Importing existing CometBFT validator consensus keys into a `pd` node's configuration.
+//!
+//! Operators migrating a validator from another CometBFT chain usually hold their consensus key
+//! in one of a few formats: CometBFT's own `priv_validator_key.json`, a base64 key file as used
+//! by the `softsign` provider of a remote signer such as `tmkms`, or the remote signer's TOML
+//! config pointing at such a key file. This module reads any of those, checks that the key is
+//! well-formed, and writes it out as the node's `priv_validator_key.json`.
+
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use penumbra_proto::serializers::bech32str::{self, validator_consensus_key::BECH32_PREFIX};
+use serde::Deserialize;
+use tendermint::{PrivateKey, PublicKey};
+use tendermint_config::PrivValidatorKey;
+
+use crate::testnet::generate::TestnetValidator;
+
+/// The formats a consensus key can be imported from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum ConsensusKeyFormat {
+    /// Guess the format from the file's extension and contents.
+    Auto,
+    /// A CometBFT `priv_validator_key.json` file.
+    PrivValidatorKey,
+    /// A base64-encoded Ed25519 key file, as used by a remote signer's `softsign` provider.
+    Softsign,
+    /// A remote signer's TOML config, whose `softsign` provider points at the key file.
+    RemoteSigner,
+}
+
+/// The subset of a remote signer's (e.g. `tmkms`) TOML config needed to locate a consensus key.
+#[derive(Debug, Deserialize)]
+struct RemoteSignerConfig {
+    #[serde(default)]
+    providers: RemoteSignerProviders,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RemoteSignerProviders {
+    #[serde(default)]
+    softsign: Vec<SoftsignProvider>,
+    /// Any other providers, e.g. `yubihsm` or `ledgertm`, whose keys can't be exported.
+    #[serde(flatten)]
+    other: toml::Table,
+}
+
+#[derive(Debug, Deserialize)]
+struct SoftsignProvider {
+    #[serde(default = "default_softsign_key_type")]
+    key_type: String,
+    path: PathBuf,
+}
+
+fn default_softsign_key_type() -> String {
+    "consensus".to_string()
+}
+
+/// Encode a consensus public key as a `penumbravalconspub` Bech32m string.
+pub fn encode_consensus_key(public_key: &PublicKey) -> String {
+    bech32str::encode(&public_key.to_bytes(), BECH32_PREFIX, bech32str::Bech32m)
+}
+
+/// Decode a `penumbravalconspub` Bech32m string into a consensus public key.
+pub fn decode_consensus_key(encoded: &str) -> anyhow::Result<PublicKey> {
+    let bytes = bech32str::decode(encoded, BECH32_PREFIX, bech32str::Bech32m)?;
+    PublicKey::from_raw_ed25519(&bytes)
+        .ok_or_else(|| anyhow::anyhow!("consensus key is not a valid Ed25519 public key"))
+}
+
+/// Read a consensus key from `path`, in the given `format`.
+pub fn read_consensus_key(path: &Path, format: ConsensusKeyFormat) -> anyhow::Result<PrivateKey> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("could not read consensus key from {}", path.display()))?;
+
+    let format = match format {
+        ConsensusKeyFormat::Auto => detect_format(path, &contents),
+        format => format,
+    };
+    tracing::debug!(?format, path = %path.display(), "reading consensus key");
+
+    match format {
+        ConsensusKeyFormat::PrivValidatorKey => parse_priv_validator_key(&contents),
+        ConsensusKeyFormat::Softsign => parse_softsign_key(&contents),
+        ConsensusKeyFormat::RemoteSigner => {
+            let key_path = softsign_key_path(&contents)?;
+            // Relative key paths are relative to the config file, not our working directory.
+            let key_path = match path.parent() {
+                Some(dir) if key_path.is_relative() => dir.join(key_path),
+                _ => key_path,
+            };
+            let key = fs::read_to_string(&key_path).with_context(|| {
+                format!("could not read softsign key file {}", key_path.display())
+            })?;
+            parse_softsign_key(&key)
+        }
+        ConsensusKeyFormat::Auto => unreachable!("format was detected above"),
+    }
+}
+
+fn detect_format(path: &Path, contents: &str) -> ConsensusKeyFormat {
+    if path.extension().is_some_and(|ext| ext == "toml") {
+        ConsensusKeyFormat::RemoteSigner
+    } else if contents.trim_start().starts_with('{') {
+        ConsensusKeyFormat::PrivValidatorKey
+    } else {
+        ConsensusKeyFormat::Softsign
+    }
+}
+
+fn parse_priv_validator_key(contents: &str) -> anyhow::Result<PrivateKey> {
+    let key: PrivValidatorKey = serde_json::from_str(contents)
+        .context("could not parse file as a CometBFT priv_validator_key.json")?;
+    let private_key = ed25519_only(key.priv_key)?;
+
+    // The address and public key are derived from the private key, so a mismatch means the file
+    // was edited by hand or assembled from different keys: refuse rather than guess which is right.
+    let public_key = private_key.public_key();
+    if key.pub_key != public_key {
+        anyhow::bail!("pub_key does not match the private key in priv_validator_key.json");
+    }
+    if key.address != tendermint::account::Id::from(public_key) {
+        anyhow::bail!("address does not match the public key in priv_validator_key.json");
+    }
+    Ok(private_key)
+}
+
+fn parse_softsign_key(contents: &str) -> anyhow::Result<PrivateKey> {
+    let bytes = BASE64
+        .decode(contents.trim())
+        .context("softsign key file is not valid base64")?;
+    let private_key = match bytes.len() {
+        32 => PrivateKey::Ed25519(bytes.as_slice().try_into()?),
+        // Some tools write the 32-byte seed followed by the 32-byte public key.
+        64 => {
+            let private_key = PrivateKey::Ed25519((&bytes[..32]).try_into()?);
+            if private_key.public_key().to_bytes() != bytes[32..] {
+                anyhow::bail!("softsign keypair's public half does not match its private half");
+            }
+            private_key
+        }
+        n => anyhow::bail!("softsign key file holds {n} bytes, expected an Ed25519 key"),
+    };
+    Ok(private_key)
+}
+
+fn softsign_key_path(contents: &str) -> anyhow::Result<PathBuf> {
+    let config: RemoteSignerConfig =
+        toml::from_str(contents).context("could not parse remote signer config")?;
+    let mut consensus = config
+        .providers
+        .softsign
+        .into_iter()
+        .filter(|p| p.key_type == "consensus");
+
+    match (consensus.next(), consensus.next()) {
+        (Some(provider), None) => Ok(provider.path),
+        (Some(_), Some(_)) => {
+            anyhow::bail!("remote signer config has several softsign consensus keys, import the key file directly")
+        }
+        (None, _) if !config.providers.other.is_empty() => anyhow::bail!(
+            "remote signer config only has hardware-backed providers ({}), whose keys cannot be exported",
+            config
+                .providers
+                .other
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        (None, _) => anyhow::bail!("remote signer config has no softsign consensus key"),
+    }
+}
+
+fn ed25519_only(key: PrivateKey) -> anyhow::Result<PrivateKey> {
+    match key {
+        PrivateKey::Ed25519(_) => Ok(key),
+        _ => anyhow::bail!("only Ed25519 consensus keys are supported"),
+    }
+}
+
+/// Write `private_key` as the `priv_validator_key.json` of the CometBFT instance at `cometbft_home`.
+///
+/// An existing key is only replaced if `force` is set. The validator's signing state is created if
+/// it is missing, but never reset, since that would allow the imported key to double-sign.
+pub fn write_consensus_key(
+    cometbft_home: &Path,
+    private_key: PrivateKey,
+    force: bool,
+) -> anyhow::Result<PublicKey> {
+    let private_key = ed25519_only(private_key)?;
+    let public_key = private_key.public_key();
+
+    let config_dir = cometbft_home.join("config");
+    let data_dir = cometbft_home.join("data");
+    fs::create_dir_all(&config_dir)?;
+    fs::create_dir_all(&data_dir)?;
+
+    let key_path = config_dir.join("priv_validator_key.json");
+    if key_path.exists() {
+        let existing = fs::read_to_string(&key_path)
+            .ok()
+            .and_then(|c| serde_json::from_str::<PrivValidatorKey>(&c).ok());
+        match existing {
+            Some(existing) if existing.pub_key == public_key => {
+                tracing::info!(path = %key_path.display(), "consensus key is already installed");
+                return Ok(public_key);
+            }
+            _ if !force => anyhow::bail!(
+                "{} already holds a different consensus key, pass --force to replace it",
+                key_path.display()
+            ),
+            _ => tracing::warn!(path = %key_path.display(), "replacing existing consensus key"),
+        }
+    }
+
+    let priv_validator_key = PrivValidatorKey {
+        address: public_key.into(),
+        pub_key: public_key,
+        priv_key: private_key,
+    };
+    tracing::debug!(path = %key_path.display(), "writing validator private key");
+    File::create(&key_path)?
+        .write_all(serde_json::to_string_pretty(&priv_validator_key)?.as_bytes())?;
+
+    let state_path = data_dir.join("priv_validator_state.json");
+    if !state_path.exists() {
+        tracing::debug!(path = %state_path.display(), "writing validator state");
+        File::create(&state_path)?.write_all(TestnetValidator::initial_state().as_bytes())?;
+    }
+
+    Ok(public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> PrivateKey {
+        PrivateKey::Ed25519([7u8; 32].as_slice().try_into().expect("32 bytes"))
+    }
+
+    #[test]
+    fn consensus_key_bech32m_roundtrip() -> anyhow::Result<()> {
+        let public_key = test_key().public_key();
+        let encoded = encode_consensus_key(&public_key);
+        assert!(encoded.starts_with(BECH32_PREFIX));
+        assert_eq!(decode_consensus_key(&encoded)?, public_key);
+        Ok(())
+    }
+
+    #[test]
+    fn softsign_key_formats() -> anyhow::Result<()> {
+        let key = test_key();
+        let seed = key
+            .ed25519_signing_key()
+            .expect("ed25519")
+            .as_bytes()
+            .to_vec();
+        let public = key.public_key().to_bytes();
+
+        let from_seed = parse_softsign_key(&BASE64.encode(&seed))?;
+        assert_eq!(from_seed.public_key(), key.public_key());
+
+        let keypair = [seed.clone(), public].concat();
+        let from_keypair = parse_softsign_key(&format!("{}\n", BASE64.encode(&keypair)))?;
+        assert_eq!(from_keypair.public_key(), key.public_key());
+
+        let mismatched = [seed.clone(), vec![0u8; 32]].concat();
+        assert!(parse_softsign_key(&BASE64.encode(mismatched)).is_err());
+        assert!(parse_softsign_key(&BASE64.encode(&seed[..31])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn priv_validator_key_must_be_consistent() -> anyhow::Result<()> {
+        let key = test_key();
+        let valid = PrivValidatorKey {
+            address: key.public_key().into(),
+            pub_key: key.public_key(),
+            priv_key: test_key(),
+        };
+        let parsed = parse_priv_validator_key(&serde_json::to_string(&valid)?)?;
+        assert_eq!(parsed.public_key(), key.public_key());
+
+        let other = PrivateKey::Ed25519([9u8; 32].as_slice().try_into()?);
+        let tampered = PrivValidatorKey {
+            pub_key: other.public_key(),
+            ..valid
+        };
+        assert!(parse_priv_validator_key(&serde_json::to_string(&tampered)?).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn remote_signer_config_locates_softsign_key() -> anyhow::Result<()> {
+        let config = r#"
+            [[chain]]
+            id = "penumbra-1"
+
+            [[validator]]
+            chain_id = "penumbra-1"
+            addr = "tcp://127.0.0.1:26659"
+
+            [[providers.softsign]]
+            chain_ids = ["penumbra-1"]
+            key_type = "account"
+            path = "account.key"
+
+            [[providers.softsign]]
+            chain_ids = ["penumbra-1"]
+            path = "secrets/consensus.key"
+        "#;
+        assert_eq!(
+            softsign_key_path(config)?,
+            PathBuf::from("secrets/consensus.key")
+        );
+
+        let hardware_only = r#"
+            [[providers.yubihsm]]
+            adapter = { type = "usb" }
+        "#;
+        let err = softsign_key_path(hardware_only).unwrap_err().to_string();
+        assert!(err.contains("yubihsm"));
+        Ok(())
+    }
+}
@@ -8,7 +8,14 @@
 mod metrics;
 
 pub mod cli;
+pub mod keys;
+pub mod logging;
 pub mod migrate;
+pub mod remote_signer;
+pub mod rpc_cache;
+pub mod rpc_limits;
+#[cfg(feature = "simulate")]
+pub mod simulate;
 pub mod testnet;
 pub mod zipserve;
 
@@ -0,0 +1,104 @@
+//! Log filtering for `pd`, configurable per component and adjustable at runtime.
+
+use axum::{http::StatusCode, routing::get, Router};
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// The components whose log levels can be set by their short names.
+const COMPONENTS: &[&str] = &[
+    "app",
+    "community_pool",
+    "compact_block",
+    "dex",
+    "distributions",
+    "fee",
+    "funding",
+    "governance",
+    "ibc",
+    "sct",
+    "shielded_pool",
+    "stake",
+];
+
+/// A handle for replacing the log filter of a running `pd`.
+pub type FilterHandle = reload::Handle<EnvFilter, Registry>;
+
+/// Expands a log filter in the `RUST_LOG` directive syntax, so that directives can name
+/// components by their short names.
+///
+/// For instance, `dex=debug,stake=info` becomes `penumbra_dex=debug,penumbra_stake=info`.
+/// Directives for any other target are left as they are.
+pub fn expand_component_directives(filter: &str) -> String {
+    filter
+        .split(',')
+        .map(|directive| {
+            let directive = directive.trim();
+            let target_len = directive
+                .find(|c| c == '=' || c == '[')
+                .unwrap_or(directive.len());
+            let (target, rest) = directive.split_at(target_len);
+            let component = target.replace('-', "_");
+            if COMPONENTS.contains(&component.as_str()) {
+                format!("penumbra_{component}{rest}")
+            } else {
+                directive.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Builds the log filter from `RUST_LOG`, defaulting to `info`, overridden by the given
+/// per-component directives.
+pub fn env_filter(log_filter: Option<&str>) -> anyhow::Result<EnvFilter> {
+    let base = std::env::var("RUST_LOG").unwrap_or_else(|_| "info".to_string());
+    let filter = match log_filter {
+        Some(log_filter) => format!("{base},{}", expand_component_directives(log_filter)),
+        None => base,
+    };
+    Ok(EnvFilter::try_new(filter)?)
+}
+
+/// Returns a router serving the current log filter at `/log-filter`, which can be replaced at
+/// runtime by `PUT`ting new directives to the same path.
+pub fn router(handle: FilterHandle) -> Router {
+    let get_handle = handle.clone();
+    let get_filter = move || {
+        let handle = get_handle.clone();
+        async move {
+            handle
+                .with_current(|filter| filter.to_string())
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+        }
+    };
+    let put_filter = move |directives: String| {
+        let handle = handle.clone();
+        async move {
+            let filter = EnvFilter::try_new(expand_component_directives(directives.trim()))
+                .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+            let description = filter.to_string();
+            handle
+                .reload(filter)
+                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            tracing::info!(filter = %description, "replaced log filter");
+            Ok::<_, (StatusCode, String)>(description)
+        }
+    };
+    Router::new().route("/log-filter", get(get_filter).put(put_filter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_component_names() {
+        assert_eq!(
+            expand_component_directives("dex=debug, shielded-pool=trace,stake[validator]=info"),
+            "penumbra_dex=debug,penumbra_shielded_pool=trace,penumbra_stake[validator]=info"
+        );
+        assert_eq!(
+            expand_component_directives("info,tower_abci=warn,dexter=debug"),
+            "info,tower_abci=warn,dexter=debug"
+        );
+    }
+}
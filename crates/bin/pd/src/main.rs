@@ -15,8 +15,12 @@ use ibc_proto::ibc::core::client::v1::query_server::QueryServer as ClientQuerySe
 use ibc_proto::ibc::core::connection::v1::query_server::QueryServer as ConnectionQueryServer;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::{
-    cli::{Opt, RootCommand, TestnetCommand},
+    cli::{KeysCommand, Opt, RootCommand, TestnetCommand},
+    keys,
     migrate::Migration::SimpleMigration,
+    remote_signer::RemoteSignerPolicy,
+    rpc_cache::RpcCacheLayer,
+    rpc_limits::{RpcLimitLayer, RpcLimiter, RpcLimits},
     testnet::{
         config::{get_testnet_dir, parse_tm_address, url_has_necessary_parts},
         generate::TestnetConfig,
@@ -34,13 +38,17 @@ use tendermint_config::net::Address as TendermintAddress;
 use tokio::runtime;
 use tonic::transport::Server;
 use tower_http::cors::CorsLayer;
-use tracing_subscriber::{prelude::*, EnvFilter};
+use tracing_subscriber::prelude::*;
 use url::Url;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Validate options immediately.
-    let Opt { tokio_console, cmd } = <Opt as clap::Parser>::parse();
+    let Opt {
+        tokio_console,
+        log_filter,
+        cmd,
+    } = <Opt as clap::Parser>::parse();
 
     // Instantiate tracing layers.
     // The MetricsLayer handles enriching metrics output with labels from tracing spans.
@@ -49,8 +57,10 @@ async fn main() -> anyhow::Result<()> {
     let fmt_layer = tracing_subscriber::fmt::layer()
         .with_ansi(std::io::stdout().is_terminal())
         .with_target(true);
-    // The `EnvFilter` layer is used to filter events based on `RUST_LOG`.
-    let filter_layer = EnvFilter::try_from_default_env().or_else(|_| EnvFilter::try_new("info"))?;
+    // The `EnvFilter` layer is used to filter events based on `RUST_LOG` and `--log-filter`.
+    // It is wrapped in a reload layer, so that the filter can be replaced at runtime.
+    let (filter_layer, filter_handle) =
+        tracing_subscriber::reload::Layer::new(pd::logging::env_filter(log_filter.as_deref())?);
 
     // Register the tracing subscribers, conditionally enabling tokio console support
     let registry = tracing_subscriber::registry()
@@ -74,9 +84,16 @@ async fn main() -> anyhow::Result<()> {
             grpc_bind,
             grpc_auto_https,
             acme_staging,
+            rpc_limits,
             metrics_bind,
             cometbft_addr,
             enable_expensive_rpc,
+            enable_log_filter_rpc,
+            disable_rpc_cache,
+            profile_blocks,
+            profile_dir,
+            screening_deny_list,
+            remote_signer_policy,
         } => {
             // Use the given `grpc_bind` address if one was specified. If not, we will choose a
             // default depending on whether or not `grpc_auto_https` was set. See the
@@ -122,16 +139,47 @@ async fn main() -> anyhow::Result<()> {
                 ?grpc_bind,
                 ?grpc_auto_https,
                 ?acme_staging,
+                ?rpc_limits,
                 ?metrics_bind,
                 %cometbft_addr,
                 ?enable_expensive_rpc,
+                ?enable_log_filter_rpc,
+                ?disable_rpc_cache,
+                ?profile_blocks,
+                ?profile_dir,
+                ?screening_deny_list,
+                ?remote_signer_policy,
                 "starting pd"
             );
 
-            let tm_proxy = TendermintProxy::new(cometbft_addr);
+            if profile_blocks || profile_dir.is_some() {
+                penumbra_app::profile::enable(profile_dir)?;
+            }
+
+            // Load the limits and policy up front, so that a bad file stops pd from starting.
+            let rpc_limits = rpc_limits.map(|path| RpcLimits::load(&path)).transpose()?;
+
+            let remote_signer_policy = remote_signer_policy
+                .map(|path| RemoteSignerPolicy::load(&path))
+                .transpose()?;
+
+            let tm_proxy = TendermintProxy::new(cometbft_addr.clone());
+            // Shared between the mempool and the app's query service, which serves it.
+            let rejections = penumbra_app::rejection::RejectionLog::default();
+            let screening = match screening_deny_list {
+                Some(path) => {
+                    let deny_list = penumbra_app::screening::DenyList::load(path)?;
+                    tracing::info!(addresses = deny_list.len(), "screening IBC withdrawals");
+                    penumbra_app::screening::Screening::new(deny_list)
+                }
+                None => Default::default(),
+            };
             let abci_server = tokio::task::Builder::new()
                 .name("abci_server")
-                .spawn(penumbra_app::server::new(storage.clone()).listen_tcp(abci_bind))
+                .spawn(
+                    penumbra_app::server::new(storage.clone(), rejections.clone(), screening)
+                        .listen_tcp(abci_bind),
+                )
                 .expect("failed to spawn abci server");
 
             let ibc = penumbra_ibc::component::rpc::IbcQuery::<PenumbraHost>::new(storage.clone());
@@ -195,6 +243,7 @@ async fn main() -> anyhow::Result<()> {
                 ))))
                 .add_service(we(AppQueryServiceServer::new(AppServer::new(
                     storage.clone(),
+                    rejections,
                 ))))
                 .add_service(we(CompactBlockQueryServiceServer::new(
                     CompactBlockServer::new(storage.clone()),
@@ -237,16 +286,24 @@ async fn main() -> anyhow::Result<()> {
             let node_status = pd::zipserve::router("/", pd::NODE_STATUS_ARCHIVE_BYTES);
 
             // Now we drop down a layer of abstraction, from tonic to axum, and merge handlers.
-            let router = grpc_server
-                .into_router()
-                .merge(frontend)
-                .merge(node_status)
+            let mut router = grpc_server.into_router().merge(frontend).merge(node_status);
+            if enable_log_filter_rpc {
+                router = router.merge(pd::logging::router(filter_handle));
+            }
+            if !disable_rpc_cache {
+                router = router.layer(RpcCacheLayer::new(storage.clone()));
+            }
+            if let Some(rpc_limits) = rpc_limits {
+                router = router.layer(RpcLimitLayer::new(RpcLimiter::new(rpc_limits)));
+            }
+            let router = router
                 // Set rather permissive CORS headers for pd's gRPC: the service
                 // should be accessible from arbitrary web contexts, such as localhost,
                 // or any FQDN that wants to reference its data.
                 .layer(CorsLayer::permissive());
 
-            let make_svc = router.into_make_service();
+            // Serve with the client's address, which the rpc limits use for exemptions.
+            let make_svc = router.into_make_service_with_connect_info::<std::net::SocketAddr>();
 
             // Now start the GRPC server, initializing an ACME client to use as a certificate
             // resolver if auto-https has been enabled.
@@ -304,6 +361,17 @@ async fn main() -> anyhow::Result<()> {
 
             pd::register_metrics();
 
+            if let Some(policy) = remote_signer_policy {
+                tokio::task::Builder::new()
+                    .name("remote_signer_monitor")
+                    .spawn(async move {
+                        if let Err(e) = pd::remote_signer::monitor(cometbft_addr, policy).await {
+                            tracing::error!(?e, "remote signer monitor stopped");
+                        }
+                    })
+                    .expect("failed to spawn remote signer monitor");
+            }
+
             // We error out if a service errors, rather than keep running.
             // A special attempt is made to detect whether binding to target socket failed;
             // if so, we report that error explicitly, otherwise we fall back to reporting
@@ -362,6 +430,7 @@ async fn main() -> anyhow::Result<()> {
                     external_address,
                     tendermint_rpc_bind,
                     tendermint_p2p_bind,
+                    priv_validator_laddr,
                 },
             testnet_dir,
         } => {
@@ -399,6 +468,7 @@ async fn main() -> anyhow::Result<()> {
                 external_address,
                 tendermint_rpc_bind,
                 tendermint_p2p_bind,
+                priv_validator_laddr,
             )
             .await?;
         }
@@ -413,6 +483,7 @@ async fn main() -> anyhow::Result<()> {
                     active_validator_limit,
                     allocations_input_file,
                     validators_input_file,
+                    intended_supply,
                     chain_id,
                     preserve_chain_id,
                     external_addresses,
@@ -459,6 +530,17 @@ async fn main() -> anyhow::Result<()> {
 
             let external_addresses = external_addresses?;
 
+            let intended_supply = match intended_supply {
+                Some(values) => values
+                    .split(',')
+                    .map(|v| {
+                        v.parse()
+                            .context(format!("Failed to parse intended supply: {v}"))
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?,
+                None => Vec::new(),
+            };
+
             // Build and write local configs based on input flags.
             tracing::info!(?chain_id, "Generating network config");
             let t = TestnetConfig::generate(
@@ -473,6 +555,7 @@ async fn main() -> anyhow::Result<()> {
                 epoch_duration,
                 unbonding_epochs,
                 proposal_voting_blocks,
+                intended_supply,
             )?;
             tracing::info!(
                 n_validators = t.validators.len(),
@@ -521,6 +604,57 @@ async fn main() -> anyhow::Result<()> {
                 .await
                 .context("failed to upgrade state")?;
         }
+
+        #[cfg(feature = "simulate")]
+        RootCommand::Simulate {
+            blocks,
+            seed,
+            max_txs_per_block,
+            wallets,
+        } => {
+            pd::simulate::Simulation {
+                seed: seed.unwrap_or_else(|| OsRng.gen()),
+                blocks,
+                max_txs_per_block,
+                wallets,
+            }
+            .run()
+            .await
+            .context("simulation failed")?;
+        }
+
+        RootCommand::Keys {
+            keys_cmd:
+                KeysCommand::ImportConsensus {
+                    from,
+                    format,
+                    cometbft_home,
+                    expected_key,
+                    force,
+                },
+        } => {
+            let private_key = keys::read_consensus_key(&from, format)?;
+            let public_key = private_key.public_key();
+            if let Some(expected_key) = expected_key {
+                let expected_key =
+                    keys::decode_consensus_key(&expected_key).context("invalid --expected-key")?;
+                if expected_key != public_key {
+                    anyhow::bail!(
+                        "imported consensus key {} does not match the expected key",
+                        keys::encode_consensus_key(&public_key)
+                    );
+                }
+            }
+
+            let cometbft_home = cometbft_home
+                .unwrap_or_else(|| get_testnet_dir(None).join("node0").join("cometbft"));
+            keys::write_consensus_key(&cometbft_home, private_key, force)?;
+            println!(
+                "Imported consensus key {} into {}",
+                keys::encode_consensus_key(&public_key),
+                cometbft_home.display()
+            );
+        }
     }
     Ok(())
 }
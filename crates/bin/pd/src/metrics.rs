@@ -21,4 +21,61 @@ pub use metrics::*;
 pub fn register_metrics() {
     // This will register metrics for all components.
     penumbra_app::register_metrics();
+
+    describe_gauge!(
+        REMOTE_SIGNER_SIGNING,
+        Unit::Count,
+        "Whether the validator signed the latest commit, when monitoring a remote signer"
+    );
+    describe_gauge!(
+        REMOTE_SIGNER_CONSECUTIVE_MISSED,
+        Unit::Count,
+        "The number of consecutive blocks the validator has missed signing"
+    );
+    describe_counter!(
+        REMOTE_SIGNER_SIGNED_BLOCKS,
+        Unit::Count,
+        "The number of blocks the validator signed, as seen by the remote signer monitor"
+    );
+    describe_counter!(
+        REMOTE_SIGNER_MISSED_BLOCKS,
+        Unit::Count,
+        "The number of blocks the validator missed signing, as seen by the remote signer monitor"
+    );
+    describe_counter!(
+        REMOTE_SIGNER_FAILOVERS,
+        Unit::Count,
+        "The number of remote signer failover hooks triggered"
+    );
+    describe_counter!(
+        REMOTE_SIGNER_CHECK_ERRORS,
+        Unit::Count,
+        "The number of failed attempts to check the validator's signatures with CometBFT"
+    );
+    describe_counter!(
+        RPC_REFUSED_REQUESTS,
+        Unit::Count,
+        "The number of gRPC requests refused for exceeding the configured rpc limits"
+    );
+    describe_counter!(
+        RPC_CACHE_HITS,
+        Unit::Count,
+        "The number of gRPC requests served from the response cache"
+    );
+    describe_counter!(
+        RPC_CACHE_MISSES,
+        Unit::Count,
+        "The number of cacheable gRPC requests not found in the response cache"
+    );
 }
+
+pub const REMOTE_SIGNER_SIGNING: &str = "penumbra_pd_remote_signer_signing";
+pub const REMOTE_SIGNER_CONSECUTIVE_MISSED: &str =
+    "penumbra_pd_remote_signer_consecutive_missed_blocks";
+pub const REMOTE_SIGNER_SIGNED_BLOCKS: &str = "penumbra_pd_remote_signer_signed_blocks_total";
+pub const REMOTE_SIGNER_MISSED_BLOCKS: &str = "penumbra_pd_remote_signer_missed_blocks_total";
+pub const REMOTE_SIGNER_FAILOVERS: &str = "penumbra_pd_remote_signer_failovers_total";
+pub const REMOTE_SIGNER_CHECK_ERRORS: &str = "penumbra_pd_remote_signer_check_errors_total";
+pub const RPC_REFUSED_REQUESTS: &str = "penumbra_pd_rpc_refused_requests_total";
+pub const RPC_CACHE_HITS: &str = "penumbra_pd_rpc_cache_hits_total";
+pub const RPC_CACHE_MISSES: &str = "penumbra_pd_rpc_cache_misses_total";
@@ -0,0 +1,272 @@
+//! Monitoring of off-box consensus signing, for validators whose CometBFT instance delegates
+//! signing to a remote signer (e.g. `tmkms` or a `horcrux` cluster) over `priv_validator_laddr`.
+//!
+//! `pd` can't see the privval connection itself, so signer health is judged by its effect: whether
+//! the validator's signature is in each block's commit. After too many consecutive missed blocks,
+//! the monitor runs the next of the operator's failover hooks, e.g. to start a standby signer.
+
+use std::{path::Path, time::Duration};
+
+use anyhow::Context;
+use serde::Deserialize;
+use tendermint::{account, block::CommitSig};
+use tendermint_rpc::{Client, HttpClient};
+use url::Url;
+
+use crate::metrics;
+
+/// How to monitor the remote signer and fail over from it, as read from the file given to
+/// `pd start --remote-signer-policy`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerPolicy {
+    /// How often to check the latest commit, in seconds.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// How many consecutive blocks the validator may miss before failing over.
+    #[serde(default = "default_failover_after_missed_blocks")]
+    pub failover_after_missed_blocks: u64,
+    /// Commands to run to fail over, each given as a program followed by its arguments.
+    ///
+    /// The first is run when the signer first stops signing, the second if signing still hasn't
+    /// resumed after as many further missed blocks, and so on, so that they can promote standby
+    /// signers in order of preference. The stage is given to each command in the
+    /// `PENUMBRA_FAILOVER_STAGE` environment variable, alongside `PENUMBRA_MISSED_BLOCKS` and
+    /// `PENUMBRA_CURRENT_HEIGHT`.
+    #[serde(default)]
+    pub failover_hooks: Vec<Vec<String>>,
+    /// Commands to run once the validator signs again after a failover.
+    #[serde(default)]
+    pub recovery_hooks: Vec<Vec<String>>,
+}
+
+fn default_poll_interval_secs() -> u64 {
+    5
+}
+
+fn default_failover_after_missed_blocks() -> u64 {
+    5
+}
+
+impl RemoteSignerPolicy {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read remote signer policy {}", path.display()))?;
+        let policy: Self = toml::from_str(&contents)
+            .with_context(|| format!("cannot parse remote signer policy {}", path.display()))?;
+        if policy.failover_after_missed_blocks == 0 {
+            anyhow::bail!("failover_after_missed_blocks must be at least 1");
+        }
+        Ok(policy)
+    }
+}
+
+/// What the monitor should do after observing a block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    /// Run the failover hook for this stage, counting from zero.
+    Failover { stage: usize },
+    /// Signing resumed after a failover.
+    Recovered,
+}
+
+/// The signing record of the validator, as seen by the monitor.
+#[derive(Debug, Default)]
+struct SigningState {
+    last_height: u64,
+    consecutive_missed: u64,
+    /// The number of failover stages started since the validator last signed.
+    stages_started: usize,
+}
+
+impl SigningState {
+    /// Record whether the validator signed the commit for `height`.
+    fn observe(
+        &mut self,
+        policy: &RemoteSignerPolicy,
+        height: u64,
+        signed: bool,
+    ) -> Option<Action> {
+        if height <= self.last_height {
+            return None;
+        }
+        self.last_height = height;
+
+        if signed {
+            self.consecutive_missed = 0;
+            let recovered = self.stages_started > 0;
+            self.stages_started = 0;
+            return recovered.then_some(Action::Recovered);
+        }
+
+        self.consecutive_missed += 1;
+        let due = self.consecutive_missed / policy.failover_after_missed_blocks;
+        let due = usize::try_from(due).unwrap_or(usize::MAX);
+        if due > self.stages_started && self.stages_started < policy.failover_hooks.len() {
+            let stage = self.stages_started;
+            self.stages_started += 1;
+            Some(Action::Failover { stage })
+        } else {
+            None
+        }
+    }
+}
+
+/// Watch the validator's signatures in the commits of the CometBFT node at `cometbft_addr`,
+/// recording metrics and running the policy's hooks, until the process exits.
+///
+/// Errors talking to CometBFT are logged and retried, rather than stopping the monitor.
+pub async fn monitor(cometbft_addr: Url, policy: RemoteSignerPolicy) -> anyhow::Result<()> {
+    let client = HttpClient::new(cometbft_addr.as_str())
+        .with_context(|| format!("invalid CometBFT address {cometbft_addr}"))?;
+    let interval = Duration::from_secs(policy.poll_interval_secs);
+    let mut state = SigningState::default();
+
+    loop {
+        match check(&client).await {
+            Ok(Some((height, signed))) => {
+                if height > state.last_height {
+                    let (signing, counter) = if signed {
+                        (1.0, metrics::REMOTE_SIGNER_SIGNED_BLOCKS)
+                    } else {
+                        (0.0, metrics::REMOTE_SIGNER_MISSED_BLOCKS)
+                    };
+                    metrics::gauge!(metrics::REMOTE_SIGNER_SIGNING).set(signing);
+                    metrics::counter!(counter).increment(1);
+                }
+                let action = state.observe(&policy, height, signed);
+                metrics::gauge!(metrics::REMOTE_SIGNER_CONSECUTIVE_MISSED)
+                    .set(state.consecutive_missed as f64);
+                if let Some(action) = action {
+                    act(&policy, action, &state).await;
+                }
+            }
+            // Not in the active set, so no signatures are expected.
+            Ok(None) => {}
+            Err(e) => {
+                metrics::counter!(metrics::REMOTE_SIGNER_CHECK_ERRORS).increment(1);
+                tracing::warn!(?e, "failed to check remote signer");
+            }
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Returns the height of the latest commit and whether the validator signed it, or `None` if
+/// the validator has no voting power.
+async fn check(client: &HttpClient) -> anyhow::Result<Option<(u64, bool)>> {
+    let validator = client.status().await?.validator_info;
+    if validator.power.value() == 0 {
+        return Ok(None);
+    }
+    let commit = client.latest_commit().await?.signed_header.commit;
+    let signed = signed_by(&commit.signatures, validator.address);
+    Ok(Some((commit.height.value(), signed)))
+}
+
+fn signed_by(signatures: &[CommitSig], address: account::Id) -> bool {
+    signatures.iter().any(|sig| {
+        matches!(
+            sig,
+            CommitSig::BlockIdFlagCommit { validator_address, .. } if *validator_address == address
+        )
+    })
+}
+
+/// Log the action, and run each of its hooks.
+///
+/// Failing hooks are reported, but don't stop the monitor.
+async fn act(policy: &RemoteSignerPolicy, action: Action, state: &SigningState) {
+    let hooks = match action {
+        Action::Failover { stage } => {
+            metrics::counter!(metrics::REMOTE_SIGNER_FAILOVERS).increment(1);
+            tracing::error!(
+                stage,
+                missed_blocks = state.consecutive_missed,
+                height = state.last_height,
+                "validator stopped signing, failing over"
+            );
+            &policy.failover_hooks[stage..=stage]
+        }
+        Action::Recovered => {
+            tracing::info!(height = state.last_height, "validator is signing again");
+            &policy.recovery_hooks[..]
+        }
+    };
+
+    for hook in hooks {
+        let Some((program, args)) = hook.split_first() else {
+            continue;
+        };
+        let mut command = tokio::process::Command::new(program);
+        command
+            .args(args)
+            .env(
+                "PENUMBRA_MISSED_BLOCKS",
+                state.consecutive_missed.to_string(),
+            )
+            .env("PENUMBRA_CURRENT_HEIGHT", state.last_height.to_string());
+        if let Action::Failover { stage } = action {
+            command.env("PENUMBRA_FAILOVER_STAGE", stage.to_string());
+        }
+        match command.status().await {
+            Ok(status) if status.success() => {}
+            Ok(status) => tracing::warn!(%program, %status, "remote signer hook failed"),
+            Err(e) => tracing::warn!(%program, ?e, "failed to run remote signer hook"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(stages: usize) -> RemoteSignerPolicy {
+        RemoteSignerPolicy {
+            poll_interval_secs: 1,
+            failover_after_missed_blocks: 3,
+            failover_hooks: vec![vec!["true".to_string()]; stages],
+            recovery_hooks: vec![],
+        }
+    }
+
+    #[test]
+    fn fails_over_in_stages_then_recovers() {
+        let policy = policy(2);
+        let mut state = SigningState::default();
+        let mut actions = vec![];
+        for height in 1..=10 {
+            actions.extend(state.observe(&policy, height, false));
+        }
+        assert_eq!(
+            actions,
+            vec![Action::Failover { stage: 0 }, Action::Failover { stage: 1 }]
+        );
+        assert_eq!(state.observe(&policy, 11, true), Some(Action::Recovered));
+        assert_eq!(state.observe(&policy, 12, true), None);
+        assert_eq!(state.consecutive_missed, 0);
+    }
+
+    #[test]
+    fn repeated_heights_are_ignored() {
+        let policy = policy(1);
+        let mut state = SigningState::default();
+        for _ in 0..10 {
+            assert_eq!(state.observe(&policy, 1, false), None);
+        }
+        assert_eq!(state.consecutive_missed, 1);
+    }
+
+    #[test]
+    fn signing_resets_missed_blocks() {
+        let policy = policy(1);
+        let mut state = SigningState::default();
+        state.observe(&policy, 1, false);
+        state.observe(&policy, 2, false);
+        assert_eq!(state.observe(&policy, 3, true), None);
+        state.observe(&policy, 4, false);
+        state.observe(&policy, 5, false);
+        assert_eq!(state.stages_started, 0);
+    }
+}
@@ -0,0 +1,455 @@
+//! Caching of responses to hot read-only gRPC methods.
+//!
+//! Wallets ask for the same few things over and over: the validator set with its rates, the
+//! metadata of the assets they hold, and the chain parameters. None of these change within a
+//! block, so their responses are cached by the height of the state they were read from, and the
+//! cache is dropped as soon as a new block is committed. A response is only cached if it
+//! succeeded, so a transient error isn't served for the rest of the block. Requests and responses
+//! larger than [`MAX_BODY_SIZE`] are passed through without caching.
+
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use axum::{
+    body::{boxed, Body, Bytes, HttpBody},
+    response::Response,
+};
+use cnidarium::Storage;
+use futures::{future, stream, StreamExt};
+use http::{header::CONTENT_TYPE, HeaderMap, HeaderValue, Request, StatusCode};
+use tower::{Layer, Service};
+
+use crate::metrics;
+
+/// The methods whose responses are cached.
+pub const CACHED_METHODS: &[&str] = &[
+    "/penumbra.core.app.v1.QueryService/AppParameters",
+    "/penumbra.core.component.fee.v1.QueryService/CurrentGasPrices",
+    "/penumbra.core.component.shielded_pool.v1.QueryService/AssetMetadataById",
+    "/penumbra.core.component.stake.v1.QueryService/CurrentValidatorRate",
+    "/penumbra.core.component.stake.v1.QueryService/ValidatorInfo",
+    "/penumbra.core.component.stake.v1.QueryService/ValidatorStatus",
+];
+
+/// The most responses cached for a height, so that requests for made-up assets can't grow the
+/// cache without bound.
+const MAX_ENTRIES: usize = 4096;
+
+/// The largest request or response body that is buffered to be cached, so that the cache can't
+/// be made to hold arbitrarily large bodies in memory.
+pub const MAX_BODY_SIZE: usize = 1 << 20;
+
+/// A request, as far as its response is concerned.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct CacheKey {
+    path: String,
+    /// Distinguishes gRPC from gRPC-web requests, whose responses are encoded differently.
+    content_type: Option<HeaderValue>,
+    body: Bytes,
+}
+
+/// A complete response, with its body and trailers buffered.
+#[derive(Clone, Debug)]
+struct CachedResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    data: Bytes,
+    trailers: Option<HeaderMap>,
+}
+
+impl CachedResponse {
+    /// Whether this is a successful gRPC response, which can be served again.
+    fn is_ok(&self) -> bool {
+        if self.status != StatusCode::OK {
+            return false;
+        }
+        let status = self
+            .headers
+            .get("grpc-status")
+            .or_else(|| self.trailers.as_ref()?.get("grpc-status"))
+            .map(|status| status.as_bytes().to_vec())
+            .or_else(|| grpc_web_status(&self.data));
+        status.as_deref() == Some(b"0")
+    }
+
+    fn response(self) -> Response {
+        let mut response = Response::new(boxed(BufferedBody {
+            data: Some(self.data).filter(|data| !data.is_empty()),
+            trailers: self.trailers,
+        }));
+        *response.status_mut() = self.status;
+        *response.headers_mut() = self.headers;
+        response
+    }
+}
+
+/// Finds the `grpc-status` in the trailers frame of a binary gRPC-web response body.
+fn grpc_web_status(mut data: &[u8]) -> Option<Vec<u8>> {
+    while data.len() >= 5 {
+        let flags = data[0];
+        let len = u32::from_be_bytes([data[1], data[2], data[3], data[4]]) as usize;
+        let frame = data.get(5..5 + len)?;
+        if flags & 0x80 != 0 {
+            return frame
+                .split(|b| *b == b'\n')
+                .filter_map(|line| {
+                    let line = std::str::from_utf8(line).ok()?;
+                    let (name, value) = line.split_once(':')?;
+                    name.trim()
+                        .eq_ignore_ascii_case("grpc-status")
+                        .then(|| value.trim().as_bytes().to_vec())
+                })
+                .next();
+        }
+        data = &data[5 + len..];
+    }
+    None
+}
+
+/// The responses cached for the height `version`.
+#[derive(Debug, Default)]
+struct CacheState {
+    version: u64,
+    entries: BTreeMap<CacheKey, CachedResponse>,
+}
+
+impl CacheState {
+    /// Moves the cache to `version`, dropping responses for any other height.
+    fn advance(&mut self, version: u64) {
+        if self.version != version {
+            self.version = version;
+            self.entries.clear();
+        }
+    }
+
+    fn get(&mut self, version: u64, key: &CacheKey) -> Option<CachedResponse> {
+        self.advance(version);
+        self.entries.get(key).cloned()
+    }
+
+    fn insert(&mut self, version: u64, key: CacheKey, response: CachedResponse) {
+        // A response read at an older height than the cache's is stale, so it's not kept.
+        if version < self.version {
+            return;
+        }
+        self.advance(version);
+        if self.entries.len() < MAX_ENTRIES {
+            self.entries.insert(key, response);
+        }
+    }
+}
+
+/// A layer caching the responses of [`CACHED_METHODS`] until the next block.
+#[derive(Clone)]
+pub struct RpcCacheLayer {
+    storage: Storage,
+    state: Arc<Mutex<CacheState>>,
+}
+
+impl RpcCacheLayer {
+    pub fn new(storage: Storage) -> Self {
+        Self {
+            storage,
+            state: Default::default(),
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcCacheLayer {
+    type Service = RpcCacheService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcCacheService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// The service produced by [`RpcCacheLayer`].
+#[derive(Clone)]
+pub struct RpcCacheService<S> {
+    inner: S,
+    layer: RpcCacheLayer,
+}
+
+impl<S> Service<Request<Body>> for RpcCacheService<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a fresh clone in its place.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        let path = req.uri().path().to_string();
+        let content_type = req.headers().get(CONTENT_TYPE).cloned();
+        // gRPC-web text responses are base64-encoded, so their status can't be checked cheaply.
+        let cacheable = CACHED_METHODS.contains(&path.as_str())
+            && content_type.as_ref().map_or(false, |content_type| {
+                content_type.as_bytes().starts_with(b"application/grpc")
+                    && !content_type
+                        .as_bytes()
+                        .starts_with(b"application/grpc-web-text")
+            });
+        if !cacheable {
+            return Box::pin(inner.call(req));
+        }
+
+        let layer = self.layer.clone();
+        Box::pin(async move {
+            let (parts, body) = req.into_parts();
+            let body = match buffer(body).await {
+                Ok(Buffered::Complete(body, _)) => body,
+                Ok(Buffered::TooLarge(read, rest)) => {
+                    let body = Body::wrap_stream(
+                        stream::once(future::ready(Ok::<_, <Body as HttpBody>::Error>(read)))
+                            .chain(rest),
+                    );
+                    return inner.call(Request::from_parts(parts, body)).await;
+                }
+                Err(e) => {
+                    let status = tonic::Status::internal(format!("cannot read request: {e}"));
+                    return Ok(status.to_http().map(boxed));
+                }
+            };
+            let key = CacheKey {
+                path: path.clone(),
+                content_type,
+                body: body.clone(),
+            };
+
+            let version = layer.storage.latest_version();
+            let hit = layer
+                .state
+                .lock()
+                .expect("rpc cache lock is not poisoned")
+                .get(version, &key);
+            if let Some(hit) = hit {
+                metrics::counter!(metrics::RPC_CACHE_HITS, "method" => path).increment(1);
+                return Ok(hit.response());
+            }
+            metrics::counter!(metrics::RPC_CACHE_MISSES, "method" => path.clone()).increment(1);
+
+            let response = inner
+                .call(Request::from_parts(parts, Body::from(body)))
+                .await?;
+            let (parts, body) = response.into_parts();
+            let (data, trailers) = match buffer(body).await {
+                Ok(Buffered::Complete(data, trailers)) => (data, trailers),
+                Ok(Buffered::TooLarge(read, rest)) => {
+                    tracing::debug!(%path, "rpc response is too large to cache");
+                    let body = PrefixedBody {
+                        prefix: Some(read),
+                        rest,
+                    };
+                    return Ok(Response::from_parts(parts, boxed(body)));
+                }
+                Err(e) => {
+                    tracing::debug!(%path, error = %e, "cannot buffer rpc response");
+                    let status = tonic::Status::internal(format!("cannot read response: {e}"));
+                    return Ok(status.to_http().map(boxed));
+                }
+            };
+            let response = CachedResponse {
+                status: parts.status,
+                headers: parts.headers,
+                data,
+                trailers,
+            };
+            if response.is_ok() {
+                layer
+                    .state
+                    .lock()
+                    .expect("rpc cache lock is not poisoned")
+                    .insert(version, key, response.clone());
+            }
+            Ok(response.response())
+        })
+    }
+}
+
+/// A body read by [`buffer`].
+enum Buffered<B> {
+    /// The whole body's data, and its trailers.
+    Complete(Bytes, Option<HeaderMap>),
+    /// The data read before the body grew larger than [`MAX_BODY_SIZE`], and the rest of it.
+    TooLarge(Bytes, B),
+}
+
+/// Reads a body to its end, unless it's larger than [`MAX_BODY_SIZE`].
+async fn buffer<B>(mut body: B) -> Result<Buffered<B>, B::Error>
+where
+    B: HttpBody<Data = Bytes> + Unpin,
+{
+    let mut data = Vec::new();
+    while let Some(chunk) = body.data().await {
+        data.extend_from_slice(&chunk?);
+        if data.len() > MAX_BODY_SIZE {
+            return Ok(Buffered::TooLarge(data.into(), body));
+        }
+    }
+    let trailers = body.trailers().await?;
+    Ok(Buffered::Complete(data.into(), trailers))
+}
+
+/// A body whose first data was already read, followed by the rest of the body.
+struct PrefixedBody<B> {
+    prefix: Option<Bytes>,
+    rest: B,
+}
+
+impl<B> HttpBody for PrefixedBody<B>
+where
+    B: HttpBody<Data = Bytes> + Unpin,
+{
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        if let Some(prefix) = self.prefix.take() {
+            return Poll::Ready(Some(Ok(prefix)));
+        }
+        Pin::new(&mut self.rest).poll_data(cx)
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        Pin::new(&mut self.rest).poll_trailers(cx)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.prefix.is_none() && self.rest.is_end_stream()
+    }
+}
+
+/// A body whose data and trailers are already in memory.
+struct BufferedBody {
+    data: Option<Bytes>,
+    trailers: Option<HeaderMap>,
+}
+
+impl HttpBody for BufferedBody {
+    type Data = Bytes;
+    type Error = Infallible;
+
+    fn poll_data(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        Poll::Ready(self.data.take().map(Ok))
+    }
+
+    fn poll_trailers(
+        mut self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        Poll::Ready(Ok(self.trailers.take()))
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.data.is_none() && self.trailers.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(body: &'static [u8]) -> CacheKey {
+        CacheKey {
+            path: CACHED_METHODS[0].to_string(),
+            content_type: Some(HeaderValue::from_static("application/grpc")),
+            body: Bytes::from_static(body),
+        }
+    }
+
+    fn response(grpc_status: &'static str) -> CachedResponse {
+        let mut trailers = HeaderMap::new();
+        trailers.insert("grpc-status", HeaderValue::from_static(grpc_status));
+        CachedResponse {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            data: Bytes::from_static(b"response"),
+            trailers: Some(trailers),
+        }
+    }
+
+    #[test]
+    fn responses_are_dropped_at_the_next_height() {
+        let mut state = CacheState::default();
+        state.insert(10, key(b"a"), response("0"));
+        assert!(state.get(10, &key(b"a")).is_some());
+        assert!(state.get(10, &key(b"b")).is_none());
+        assert!(state.get(11, &key(b"a")).is_none());
+
+        // A response read before the latest block isn't cached.
+        state.insert(10, key(b"a"), response("0"));
+        assert!(state.get(11, &key(b"a")).is_none());
+    }
+
+    #[test]
+    fn only_successful_responses_are_cacheable() {
+        assert!(response("0").is_ok());
+        assert!(!response("14").is_ok());
+
+        let mut grpc_web = response("0");
+        grpc_web.trailers = None;
+        grpc_web.data =
+            Bytes::from_static(b"\x00\x00\x00\x00\x01x\x80\x00\x00\x00\x0fgrpc-status:0\r\n");
+        assert!(grpc_web.is_ok());
+        grpc_web.data = Bytes::from_static(b"\x80\x00\x00\x00\x0fgrpc-status:5\r\n");
+        assert!(!grpc_web.is_ok());
+    }
+
+    #[tokio::test]
+    async fn large_bodies_are_passed_through_whole() -> anyhow::Result<()> {
+        let small = vec![1u8; 16];
+        match buffer(Body::from(small.clone())).await? {
+            Buffered::Complete(data, _) => assert_eq!(data, small),
+            Buffered::TooLarge(..) => panic!("a small body is buffered"),
+        }
+
+        let chunks = vec![vec![1u8; MAX_BODY_SIZE], vec![2u8; 16], vec![3u8; 16]];
+        let body = Body::wrap_stream(stream::iter(
+            chunks.clone().into_iter().map(Ok::<_, std::io::Error>),
+        ));
+        let (read, rest) = match buffer(body).await? {
+            Buffered::TooLarge(read, rest) => (read, rest),
+            Buffered::Complete(..) => panic!("a large body is not buffered"),
+        };
+        assert_eq!(read.len(), MAX_BODY_SIZE + 16);
+
+        let mut body = PrefixedBody {
+            prefix: Some(read),
+            rest,
+        };
+        let mut data = Vec::new();
+        while let Some(chunk) = body.data().await {
+            data.extend_from_slice(&chunk?);
+        }
+        assert_eq!(data, chunks.concat());
+
+        Ok(())
+    }
+}
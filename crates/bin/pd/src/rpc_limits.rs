@@ -0,0 +1,519 @@
+//! Load-shedding for `pd`'s public gRPC services.
+//!
+//! A public fullnode serves anyone who can reach it, and an indexer scraping compact blocks or
+//! hammering a query can starve the node of the CPU and storage bandwidth it needs to process
+//! blocks. The limits here bound how many requests each method handles at once and how often it
+//! can be called, and refuse requests beyond that with `RESOURCE_EXHAUSTED`, rather than queueing
+//! them, so that clients back off and retry. Requests from exempt addresses, such as the node's
+//! own tooling on loopback, skip the limits entirely, so they're served first under load.
+
+use std::{
+    collections::BTreeMap,
+    convert::Infallible,
+    future::Future,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::Path,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use anyhow::Context as _;
+use axum::{
+    body::{boxed, Body, BoxBody, Bytes, HttpBody},
+    extract::ConnectInfo,
+    response::Response,
+};
+use http::{HeaderMap, Request};
+use pin_project_lite::pin_project;
+use serde::Deserialize;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tower::{Layer, Service};
+
+use crate::metrics;
+
+/// Limits on the public gRPC services, as read from the file given to `pd start --rpc-limits`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RpcLimits {
+    /// The most requests handled at once across all methods, leaving the rest of the node's
+    /// resources to block processing.
+    pub total_concurrency: Option<usize>,
+    /// The limits of each method that isn't configured in `methods`.
+    #[serde(default)]
+    pub default: MethodLimits,
+    /// The limits of particular methods or services, keyed by the prefix of their request paths,
+    /// e.g. `/penumbra.core.component.compact_block.v1.QueryService/CompactBlockRange` for one
+    /// method or `/penumbra.core.component.dex.v1.QueryService/` for all of a service's methods.
+    ///
+    /// A request is limited by the longest key its path starts with, and the requests limited by
+    /// the same key share its limits.
+    #[serde(default)]
+    pub methods: BTreeMap<String, MethodLimits>,
+    /// Addresses whose requests skip all limits, so that the node's own tooling is served even
+    /// when public traffic is being shed. Defaults to the loopback addresses.
+    #[serde(default = "default_exempt")]
+    pub exempt: Vec<IpAddr>,
+}
+
+/// The limits of a method, each unlimited if unset.
+#[derive(Debug, Default, Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MethodLimits {
+    /// The most requests handled at once. A streaming request counts until its stream ends.
+    pub concurrency: Option<usize>,
+    /// The most requests handled per second, on average.
+    pub rate: Option<u32>,
+    /// The most requests handled in a burst above `rate`. Defaults to one second's worth.
+    pub burst: Option<u32>,
+}
+
+fn default_exempt() -> Vec<IpAddr> {
+    vec![
+        IpAddr::V4(Ipv4Addr::LOCALHOST),
+        IpAddr::V6(Ipv6Addr::LOCALHOST),
+    ]
+}
+
+impl RpcLimits {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read rpc limits {}", path.display()))?;
+        let limits: Self = toml::from_str(&contents)
+            .with_context(|| format!("cannot parse rpc limits {}", path.display()))?;
+        if limits.total_concurrency == Some(0) {
+            anyhow::bail!("total_concurrency must be at least 1");
+        }
+        let methods = limits.methods.iter().map(|(key, m)| (key.as_str(), m));
+        for (key, method) in std::iter::once(("default", &limits.default)).chain(methods) {
+            if method.concurrency == Some(0) || method.rate == Some(0) || method.burst == Some(0) {
+                anyhow::bail!("the limits of {key} must be at least 1, or unset");
+            }
+        }
+        Ok(limits)
+    }
+}
+
+/// A token bucket, refilled at `rate` tokens per second up to `burst` tokens.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: u32, burst: u32, now: Instant) -> Self {
+        Self {
+            rate: rate.into(),
+            burst: burst.into(),
+            tokens: burst.into(),
+            last: now,
+        }
+    }
+
+    /// Takes a token, if there is one as of `now`.
+    fn try_take(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.last = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The state of the limits of a method, or of the methods sharing a key.
+#[derive(Debug)]
+struct Limit {
+    concurrency: Option<(usize, Arc<Semaphore>)>,
+    bucket: Option<(u32, Mutex<TokenBucket>)>,
+}
+
+impl Limit {
+    fn new(limits: &MethodLimits, now: Instant) -> Self {
+        Self {
+            concurrency: limits.concurrency.map(|n| (n, Arc::new(Semaphore::new(n)))),
+            bucket: limits.rate.map(|rate| {
+                let burst = limits.burst.unwrap_or(rate);
+                (rate, Mutex::new(TokenBucket::new(rate, burst, now)))
+            }),
+        }
+    }
+}
+
+/// Why a request was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Refusal {
+    Total,
+    Concurrency,
+    Rate,
+}
+
+impl Refusal {
+    fn reason(&self) -> &'static str {
+        match self {
+            Refusal::Total => "total_concurrency",
+            Refusal::Concurrency => "concurrency",
+            Refusal::Rate => "rate",
+        }
+    }
+}
+
+/// Admits or refuses requests according to [`RpcLimits`].
+#[derive(Debug)]
+pub struct RpcLimiter {
+    exempt: Vec<IpAddr>,
+    total: Option<(usize, Arc<Semaphore>)>,
+    default: MethodLimits,
+    /// The configured keys, longest first, so that the first match is the longest.
+    configured: Vec<(String, Arc<Limit>)>,
+    /// The limits of each method limited by `default`, created as the methods are first called.
+    unconfigured: Mutex<BTreeMap<String, Arc<Limit>>>,
+    /// The limits shared by the methods called after [`MAX_UNCONFIGURED_METHODS`] others, so
+    /// that requests to made-up paths can't grow `unconfigured` without bound.
+    overflow: Arc<Limit>,
+}
+
+/// The most methods given their own default limits.
+const MAX_UNCONFIGURED_METHODS: usize = 1024;
+
+impl RpcLimiter {
+    pub fn new(limits: RpcLimits) -> Self {
+        let now = Instant::now();
+        let mut configured = limits
+            .methods
+            .iter()
+            .map(|(key, method)| (key.clone(), Arc::new(Limit::new(method, now))))
+            .collect::<Vec<_>>();
+        configured.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then(a.cmp(b)));
+        Self {
+            exempt: limits.exempt,
+            total: limits
+                .total_concurrency
+                .map(|n| (n, Arc::new(Semaphore::new(n)))),
+            default: limits.default,
+            configured,
+            unconfigured: Default::default(),
+            overflow: Arc::new(Limit::new(&limits.default, now)),
+        }
+    }
+
+    fn limit(&self, path: &str, now: Instant) -> Arc<Limit> {
+        if let Some((_, limit)) = self
+            .configured
+            .iter()
+            .find(|(key, _)| path.starts_with(key.as_str()))
+        {
+            return limit.clone();
+        }
+        let mut unconfigured = self
+            .unconfigured
+            .lock()
+            .expect("rpc limits lock is not poisoned");
+        if let Some(limit) = unconfigured.get(path) {
+            return limit.clone();
+        }
+        if unconfigured.len() >= MAX_UNCONFIGURED_METHODS {
+            return self.overflow.clone();
+        }
+        let limit = Arc::new(Limit::new(&self.default, now));
+        unconfigured.insert(path.to_string(), limit.clone());
+        limit
+    }
+
+    /// Admits a request to `path` from `remote`, returning the permits to hold until it's
+    /// finished, or refuses it with a message for the client.
+    fn admit(
+        &self,
+        path: &str,
+        remote: Option<IpAddr>,
+        now: Instant,
+    ) -> Result<Vec<OwnedSemaphorePermit>, (Refusal, String)> {
+        if remote.map_or(false, |remote| self.exempt.contains(&remote)) {
+            return Ok(vec![]);
+        }
+
+        let mut permits = vec![];
+        if let Some((n, total)) = &self.total {
+            permits.push(total.clone().try_acquire_owned().map_err(|_| {
+                (
+                    Refusal::Total,
+                    format!("the node is handling its limit of {n} requests, retry later"),
+                )
+            })?);
+        }
+
+        let limit = self.limit(path, now);
+        if let Some((n, concurrency)) = &limit.concurrency {
+            permits.push(concurrency.clone().try_acquire_owned().map_err(|_| {
+                (
+                    Refusal::Concurrency,
+                    format!("{path} is handling its limit of {n} requests, retry later"),
+                )
+            })?);
+        }
+        if let Some((rate, bucket)) = &limit.bucket {
+            if !bucket
+                .lock()
+                .expect("rpc limits lock is not poisoned")
+                .try_take(now)
+            {
+                return Err((
+                    Refusal::Rate,
+                    format!("{path} is limited to {rate} requests per second, retry later"),
+                ));
+            }
+        }
+        Ok(permits)
+    }
+}
+
+/// A layer applying an [`RpcLimiter`] to gRPC requests.
+///
+/// The client's address is taken from axum's [`ConnectInfo`], so the router must be served with
+/// `into_make_service_with_connect_info::<SocketAddr>()` for exemptions to apply.
+#[derive(Clone, Debug)]
+pub struct RpcLimitLayer {
+    limiter: Arc<RpcLimiter>,
+}
+
+impl RpcLimitLayer {
+    pub fn new(limiter: RpcLimiter) -> Self {
+        Self {
+            limiter: Arc::new(limiter),
+        }
+    }
+}
+
+impl<S> Layer<S> for RpcLimitLayer {
+    type Service = RpcLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// The service produced by [`RpcLimitLayer`].
+#[derive(Clone, Debug)]
+pub struct RpcLimitService<S> {
+    inner: S,
+    limiter: Arc<RpcLimiter>,
+}
+
+impl<S> Service<Request<Body>> for RpcLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response, Error = Infallible> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = Response;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response, Infallible>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        // Take the service that was driven to readiness, leaving a fresh clone in its place.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        // Only gRPC and gRPC-web requests are limited, not the static frontends.
+        let is_grpc = req
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .map_or(false, |content_type| {
+                content_type.starts_with("application/grpc")
+            });
+        if !is_grpc {
+            return Box::pin(inner.call(req));
+        }
+
+        let remote = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let path = req.uri().path().to_string();
+        match self.limiter.admit(&path, remote, Instant::now()) {
+            Ok(permits) => Box::pin(async move {
+                let response = inner.call(req).await?;
+                // Hold the permits until the response body, which may be a stream, is done.
+                Ok(response.map(|body| boxed(PermitBody::new(body, permits))))
+            }),
+            Err((refusal, message)) => {
+                tracing::debug!(%path, ?remote, reason = refusal.reason(), "refusing rpc");
+                metrics::counter!(
+                    metrics::RPC_REFUSED_REQUESTS,
+                    "method" => path,
+                    "reason" => refusal.reason()
+                )
+                .increment(1);
+                let response = tonic::Status::resource_exhausted(message)
+                    .to_http()
+                    .map(boxed);
+                Box::pin(async move { Ok(response) })
+            }
+        }
+    }
+}
+
+pin_project! {
+    /// A response body that holds a request's permits until it's finished.
+    struct PermitBody {
+        #[pin]
+        inner: BoxBody,
+        permits: Vec<OwnedSemaphorePermit>,
+    }
+}
+
+impl PermitBody {
+    fn new(inner: BoxBody, permits: Vec<OwnedSemaphorePermit>) -> Self {
+        Self { inner, permits }
+    }
+}
+
+impl HttpBody for PermitBody {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_data(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Data, Self::Error>>> {
+        self.project().inner.poll_data(cx)
+    }
+
+    fn poll_trailers(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<HeaderMap>, Self::Error>> {
+        let this = self.project();
+        let trailers = this.inner.poll_trailers(cx);
+        if trailers.is_ready() {
+            this.permits.clear();
+        }
+        trailers
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn limits(methods: &str) -> RpcLimits {
+        toml::from_str(methods).expect("limits parse")
+    }
+
+    const COMPACT_BLOCK_RANGE: &str =
+        "/penumbra.core.component.compact_block.v1.QueryService/CompactBlockRange";
+    const REMOTE: Option<IpAddr> = Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)));
+
+    #[test]
+    fn token_bucket_refills_at_rate() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2, 3, start);
+        assert!((0..3).all(|_| bucket.try_take(start)));
+        assert!(!bucket.try_take(start));
+        assert!(bucket.try_take(start + Duration::from_millis(500)));
+        assert!(!bucket.try_take(start + Duration::from_millis(500)));
+        // Refilling stops at the burst size.
+        let later = start + Duration::from_secs(60);
+        assert!((0..3).all(|_| bucket.try_take(later)));
+        assert!(!bucket.try_take(later));
+    }
+
+    #[test]
+    fn concurrency_is_limited_until_permits_are_released() {
+        let limiter = RpcLimiter::new(limits(
+            r#"
+            [methods."/penumbra.core.component.compact_block.v1.QueryService/"]
+            concurrency = 1
+            "#,
+        ));
+        let now = Instant::now();
+        let permits = limiter
+            .admit(COMPACT_BLOCK_RANGE, REMOTE, now)
+            .expect("first request is admitted");
+        let (refusal, _) = limiter
+            .admit(COMPACT_BLOCK_RANGE, REMOTE, now)
+            .expect_err("second request is refused");
+        assert_eq!(refusal, Refusal::Concurrency);
+        drop(permits);
+        assert!(limiter.admit(COMPACT_BLOCK_RANGE, REMOTE, now).is_ok());
+    }
+
+    #[test]
+    fn longest_key_applies_and_default_is_per_method() {
+        let limiter = RpcLimiter::new(limits(
+            r#"
+            [default]
+            rate = 1
+            [methods."/penumbra.core.component.compact_block.v1.QueryService/"]
+            rate = 100
+            [methods."/penumbra.core.component.compact_block.v1.QueryService/CompactBlockRange"]
+            rate = 2
+            "#,
+        ));
+        let now = Instant::now();
+        assert!(limiter.admit(COMPACT_BLOCK_RANGE, REMOTE, now).is_ok());
+        assert!(limiter.admit(COMPACT_BLOCK_RANGE, REMOTE, now).is_ok());
+        assert_eq!(
+            limiter
+                .admit(COMPACT_BLOCK_RANGE, REMOTE, now)
+                .expect_err("third request is refused")
+                .0,
+            Refusal::Rate
+        );
+
+        // Each method without limits of its own gets its own default limits.
+        assert!(limiter.admit("/a.Service/One", REMOTE, now).is_ok());
+        assert!(limiter.admit("/a.Service/Two", REMOTE, now).is_ok());
+        assert!(limiter.admit("/a.Service/One", REMOTE, now).is_err());
+    }
+
+    #[test]
+    fn exempt_addresses_skip_limits() {
+        let limiter = RpcLimiter::new(limits(
+            r#"
+            total_concurrency = 1
+            [default]
+            rate = 1
+            "#,
+        ));
+        let now = Instant::now();
+        let local = Some(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        let _held = limiter
+            .admit(COMPACT_BLOCK_RANGE, REMOTE, now)
+            .expect("first request is admitted");
+        assert_eq!(
+            limiter
+                .admit("/a.Service/One", REMOTE, now)
+                .expect_err("public request over the total is refused")
+                .0,
+            Refusal::Total
+        );
+        for _ in 0..10 {
+            assert!(limiter.admit(COMPACT_BLOCK_RANGE, local, now).is_ok());
+        }
+    }
+}
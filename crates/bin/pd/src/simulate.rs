@@ -0,0 +1,279 @@
+//! An in-process Penumbra chain driven by randomized transactions between simulated wallets.
+//!
+//! The simulation runs the full application behind a mock consensus engine, and syncs a mock
+//! client for each wallet after every block. Every choice the simulation makes is drawn from a
+//! seeded RNG, so a failing run can be replayed by passing the same seed.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::Context;
+use cnidarium::{Snapshot, TempStorage};
+use penumbra_app::server::consensus::Consensus;
+use penumbra_asset::asset;
+use penumbra_genesis::AppState;
+use penumbra_keys::{
+    keys::{AddressIndex, Bip44Path, SeedPhrase, SpendKey},
+    test_keys,
+};
+use penumbra_mock_client::MockClient;
+use penumbra_mock_consensus::TestNode;
+use penumbra_num::Amount;
+use penumbra_proto::DomainType;
+use penumbra_sct::{
+    component::{clock::EpochRead as _, tree::SctRead as _},
+    Nullifier,
+};
+use penumbra_shielded_pool::{component::SupplyRead as _, note, Note, OutputPlan, SpendPlan};
+use penumbra_tct::Position;
+use penumbra_transaction::{
+    memo::MemoPlaintext, plan::MemoPlan, Transaction, TransactionParameters, TransactionPlan,
+};
+use rand::{seq::SliceRandom, Rng};
+use rand_chacha::ChaChaRng;
+use rand_core::SeedableRng;
+
+/// Parameters for a simulation run.
+pub struct Simulation {
+    /// The seed all randomized choices are drawn from.
+    pub seed: u64,
+    /// The number of blocks to simulate, or `None` to run until interrupted.
+    pub blocks: Option<u64>,
+    /// The maximum number of transactions to include in each block.
+    pub max_txs_per_block: usize,
+    /// The number of simulated wallets, including the one holding the genesis allocations.
+    pub wallets: usize,
+}
+
+impl Simulation {
+    /// Runs the simulation, returning an error as soon as an invariant is violated.
+    pub async fn run(self) -> anyhow::Result<()> {
+        let Self {
+            seed,
+            blocks,
+            max_txs_per_block,
+            wallets,
+        } = self;
+        anyhow::ensure!(wallets > 0, "the simulation needs at least one wallet");
+        tracing::info!(
+            seed,
+            ?blocks,
+            max_txs_per_block,
+            wallets,
+            "starting simulation"
+        );
+
+        let mut rng = ChaChaRng::seed_from_u64(seed);
+
+        // The default genesis allocates all of its notes to the test wallet, so it is always
+        // the first simulated wallet; the others start out empty.
+        let storage = TempStorage::new().await?;
+        let mut node = TestNode::builder()
+            .single_validator()
+            .app_state(serde_json::to_vec(&AppState::default())?)
+            .init_chain(Consensus::new(storage.as_ref().clone()))
+            .await?;
+
+        let mut keys = vec![test_keys::SPEND_KEY.clone()];
+        keys.extend((1..wallets).map(|_| {
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(&mut rng), &Bip44Path::new(0))
+        }));
+        let mut clients = Vec::with_capacity(wallets);
+        for sk in keys {
+            clients.push(MockClient::new(sk).with_sync_to_storage(&storage).await?);
+        }
+
+        let snapshot = storage.latest_snapshot();
+        let mut height = snapshot.get_block_height().await?;
+        let mut spendable = spendable_notes(&clients, &snapshot).await?;
+        // Transfers between simulated wallets pay no fees under the default genesis, so the
+        // wallets' combined holdings should never change.
+        let genesis_holdings = holdings(&spendable);
+
+        while blocks.map_or(true, |blocks| height < blocks) {
+            let txs = random_transactions(&mut rng, &clients, &spendable, max_txs_per_block)
+                .await
+                .with_context(|| format!("failed to build transactions (seed {seed})"))?;
+            node.block()
+                .with_data(txs.iter().map(|tx| tx.encode_to_vec()).collect())
+                .execute()
+                .await?;
+
+            let snapshot = storage.latest_snapshot();
+            spendable =
+                check_invariants(height + 1, &mut clients, &snapshot, &txs, &genesis_holdings)
+                    .await
+                    .with_context(|| {
+                        format!("invariant violated at height {} (seed {seed})", height + 1)
+                    })?;
+            height += 1;
+
+            tracing::info!(
+                height,
+                txs = txs.len(),
+                notes = spendable.iter().map(Vec::len).sum::<usize>(),
+                "simulated block"
+            );
+        }
+
+        tracing::info!(seed, height, "simulation finished");
+        Ok(())
+    }
+}
+
+/// Builds a random set of transfers between the simulated wallets, spending each note at most
+/// once.
+async fn random_transactions(
+    rng: &mut ChaChaRng,
+    clients: &[MockClient],
+    spendable: &[Vec<(Note, Position)>],
+    max_txs_per_block: usize,
+) -> anyhow::Result<Vec<Transaction>> {
+    let mut reserved = BTreeSet::<note::StateCommitment>::new();
+    let mut txs = Vec::new();
+
+    for _ in 0..rng.gen_range(0..=max_txs_per_block) {
+        let sender = rng.gen_range(0..clients.len());
+        let available = spendable[sender]
+            .iter()
+            .filter(|(note, _)| !reserved.contains(&note.commit()))
+            .collect::<Vec<_>>();
+        let Some(&first) = available.choose(rng) else {
+            continue;
+        };
+
+        // Sometimes merge a second note of the same asset into the transfer.
+        let mut inputs = vec![first];
+        if rng.gen_bool(0.5) {
+            let candidates = available
+                .iter()
+                .copied()
+                .filter(|(note, _)| {
+                    note.asset_id() == first.0.asset_id() && note.commit() != first.0.commit()
+                })
+                .collect::<Vec<_>>();
+            if let Some(&second) = candidates.choose(rng) {
+                inputs.push(second);
+            }
+        }
+
+        let asset_id = first.0.asset_id();
+        let total: u128 = inputs.iter().map(|(note, _)| note.amount().value()).sum();
+        let sent = rng.gen_range(0..=total);
+
+        let recipient = rng.gen_range(0..clients.len());
+        let (to, _) = clients[recipient]
+            .fvk
+            .payment_address(AddressIndex::new(rng.gen_range(0..4)));
+        let (change, _) = clients[sender].fvk.payment_address(AddressIndex::new(0));
+
+        let mut actions = Vec::new();
+        for (note, position) in &inputs {
+            reserved.insert(note.commit());
+            actions.push(SpendPlan::new(rng, note.clone(), *position).into());
+        }
+        actions.push(OutputPlan::new(rng, value(asset_id, sent), to).into());
+        actions.push(OutputPlan::new(rng, value(asset_id, total - sent), change).into());
+
+        let mut plan = TransactionPlan {
+            actions,
+            memo: Some(MemoPlan::new(rng, MemoPlaintext::blank_memo(change))?),
+            detection_data: None,
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        };
+        plan.populate_detection_data(&mut *rng, 0);
+
+        txs.push(clients[sender].witness_auth_build(&plan).await?);
+    }
+
+    Ok(txs)
+}
+
+/// Syncs every wallet to the new block and checks the chain and the wallets against each
+/// other, returning the wallets' spendable notes.
+async fn check_invariants(
+    expected_height: u64,
+    clients: &mut [MockClient],
+    snapshot: &Snapshot,
+    txs: &[Transaction],
+    genesis_holdings: &BTreeMap<asset::Id, Amount>,
+) -> anyhow::Result<Vec<Vec<(Note, Position)>>> {
+    let height = snapshot.get_block_height().await?;
+    anyhow::ensure!(
+        height == expected_height,
+        "chain is at height {height}, expected {expected_height}"
+    );
+
+    // Syncing checks each wallet's state commitment tree against the chain's anchor.
+    for client in clients.iter_mut() {
+        client.sync_to_latest(snapshot.clone()).await?;
+    }
+
+    // Every transaction the simulation submits is valid, so all of them must have been applied.
+    for tx in txs {
+        for nullifier in tx.spent_nullifiers() {
+            anyhow::ensure!(
+                snapshot.spend_info(nullifier).await?.is_some(),
+                "transaction {} was not applied",
+                tx.id()
+            );
+        }
+    }
+
+    let spendable = spendable_notes(clients, snapshot).await?;
+    let holdings = holdings(&spendable);
+    anyhow::ensure!(
+        &holdings == genesis_holdings,
+        "wallet holdings {holdings:?} differ from genesis holdings {genesis_holdings:?}"
+    );
+    for (asset_id, amount) in &holdings {
+        let supply = snapshot.token_supply(asset_id).await?.unwrap_or_default();
+        anyhow::ensure!(
+            *amount <= supply,
+            "wallets hold {amount} of {asset_id}, exceeding its supply of {supply}"
+        );
+    }
+
+    Ok(spendable)
+}
+
+/// Returns the notes each wallet can spend, i.e. its positioned notes whose nullifiers have not
+/// been revealed.
+async fn spendable_notes(
+    clients: &[MockClient],
+    state: &Snapshot,
+) -> anyhow::Result<Vec<Vec<(Note, Position)>>> {
+    let mut spendable = Vec::with_capacity(clients.len());
+    for client in clients {
+        let mut notes = Vec::new();
+        for (commitment, note) in &client.notes {
+            let Some(position) = client.position(*commitment) else {
+                continue;
+            };
+            let nullifier = Nullifier::derive(client.fvk.nullifier_key(), position, commitment);
+            if state.spend_info(nullifier).await?.is_none() {
+                notes.push((note.clone(), position));
+            }
+        }
+        spendable.push(notes);
+    }
+    Ok(spendable)
+}
+
+/// Sums the given notes by asset.
+fn holdings(spendable: &[Vec<(Note, Position)>]) -> BTreeMap<asset::Id, Amount> {
+    let mut holdings = BTreeMap::new();
+    for (note, _) in spendable.iter().flatten() {
+        *holdings.entry(note.asset_id()).or_default() += note.amount();
+    }
+    holdings
+}
+
+fn value(asset_id: asset::Id, amount: u128) -> penumbra_asset::Value {
+    penumbra_asset::Value {
+        amount: Amount::from(amount),
+        asset_id,
+    }
+}
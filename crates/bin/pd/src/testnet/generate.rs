@@ -4,6 +4,7 @@
 use crate::testnet::config::{get_testnet_dir, TestnetTendermintConfig, ValidatorKeys};
 use anyhow::{Context, Result};
 use penumbra_app::params::AppParameters;
+use penumbra_asset::{asset, Value};
 use penumbra_governance::genesis::Content as GovernanceContent;
 use penumbra_keys::{keys::SpendKey, Address};
 use penumbra_sct::genesis::Content as SctContent;
@@ -18,6 +19,7 @@ use penumbra_stake::{
 };
 use serde::{de, Deserialize};
 use std::{
+    collections::{BTreeMap, BTreeSet},
     fmt,
     fs::File,
     io::Read,
@@ -69,6 +71,7 @@ impl TestnetConfig {
         epoch_duration: Option<u64>,
         unbonding_epochs: Option<u64>,
         proposal_voting_blocks: Option<u64>,
+        intended_supply: Vec<Value>,
     ) -> anyhow::Result<TestnetConfig> {
         let external_addresses = external_addresses.unwrap_or_default();
 
@@ -83,6 +86,7 @@ impl TestnetConfig {
         for v in testnet_validators.iter() {
             allocations.push(v.delegation_allocation()?);
         }
+        Self::check_intended_supply(&allocations, &intended_supply)?;
 
         // Convert to domain type, for use with other Penumbra interfaces.
         // We do this conversion once and store it in the struct for convenience.
@@ -176,6 +180,91 @@ impl TestnetConfig {
         }
     }
 
+    /// Checks that the allocations of each asset in `intended_supply` add up to exactly the
+    /// intended amount, so that a mistake in an allocations file can't silently change the
+    /// genesis supply.
+    fn check_intended_supply(
+        allocations: &[Allocation],
+        intended_supply: &[Value],
+    ) -> anyhow::Result<()> {
+        let cache = asset::Cache::with_known_assets();
+        let mut totals = BTreeMap::<asset::Id, u128>::new();
+        for allocation in allocations {
+            *totals.entry(allocation.unit().id()).or_default() += allocation.amount().value();
+        }
+
+        for intended in intended_supply {
+            let allocated = Value {
+                amount: totals
+                    .get(&intended.asset_id)
+                    .copied()
+                    .unwrap_or_default()
+                    .into(),
+                asset_id: intended.asset_id,
+            };
+            if allocated.amount != intended.amount {
+                anyhow::bail!(
+                    "genesis allocations total {}, but the intended supply is {}",
+                    allocated.format(&cache),
+                    intended.format(&cache),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Summarizes the genesis allocations by asset, in a form meant to be reviewed by the
+    /// people coordinating the genesis.
+    pub fn allocation_report(&self) -> String {
+        let penumbra_genesis::AppState::Content(app_state) = &self.genesis.app_state else {
+            return "genesis is a checkpoint, and has no allocations\n".to_string();
+        };
+        let allocations = &app_state.shielded_pool_content.allocations;
+
+        #[derive(Default)]
+        struct Totals {
+            amount: u128,
+            largest: u128,
+            count: usize,
+            recipients: BTreeSet<Address>,
+        }
+        let mut by_asset = BTreeMap::<String, (asset::Unit, Totals)>::new();
+        for allocation in allocations {
+            let unit = allocation.denom().default_unit();
+            let amount = allocation.amount().value();
+            let (_, totals) = by_asset
+                .entry(unit.to_string())
+                .or_insert_with(|| (unit, Totals::default()));
+            totals.amount += amount;
+            totals.largest = totals.largest.max(amount);
+            totals.count += 1;
+            totals.recipients.insert(allocation.address);
+        }
+
+        let mut report = format!(
+            "Genesis allocations for {}: {} allocations of {} assets\n\n",
+            app_state.chain_id,
+            allocations.len(),
+            by_asset.len()
+        );
+        for (denom, (unit, totals)) in by_asset {
+            // The share of the largest allocation in the total, in basis points.
+            let largest_bps = (totals.largest * 10_000)
+                .checked_div(totals.amount)
+                .unwrap_or_default();
+            report.push_str(&format!(
+                "{}{denom}\n  {} allocations to {} addresses, the largest being {}{denom} ({}.{:02}% of the total)\n",
+                unit.format_value(totals.amount.into()),
+                totals.count,
+                totals.recipients.len(),
+                unit.format_value(totals.largest.into()),
+                largest_bps / 100,
+                largest_bps % 100,
+            ));
+        }
+        report
+    }
+
     /// Create a full genesis configuration for inclusion in the tendermint
     /// genesis config.
     fn make_genesis_content(
@@ -329,6 +418,11 @@ impl TestnetConfig {
             }
             tm_config.write_config(node_dir, v, &self.genesis)?;
         }
+
+        let report_path = self.testnet_dir.join("allocations-report.txt");
+        std::fs::write(&report_path, self.allocation_report())
+            .with_context(|| format!("failed to write allocation report to {report_path:?}"))?;
+        tracing::info!(?report_path, "wrote allocation report");
         Ok(())
     }
 }
@@ -349,6 +443,7 @@ pub fn testnet_generate(
     validators_input_file: Option<PathBuf>,
     allocations_input_file: Option<PathBuf>,
     proposal_voting_blocks: Option<u64>,
+    intended_supply: Vec<Value>,
 ) -> anyhow::Result<()> {
     tracing::info!(?chain_id, "Generating network config");
     let t = TestnetConfig::generate(
@@ -363,6 +458,7 @@ pub fn testnet_generate(
         epoch_duration,
         unbonding_epochs,
         proposal_voting_blocks,
+        intended_supply,
     )?;
     tracing::info!(
         n_validators = t.validators.len(),
@@ -388,6 +484,10 @@ impl TestnetAllocation {
     ///   amount,denom,address
     ///
     /// Typically these CSV files are generated by Galileo.
+    ///
+    /// Allocations can't be vested or locked up, since there is no component to enforce such
+    /// schedules, so files with vesting or lockup columns are rejected rather than having those
+    /// columns silently ignored.
     pub fn from_csv(csv_filepath: PathBuf) -> Result<Vec<Allocation>> {
         let allocations_file = File::open(&csv_filepath)
             .with_context(|| format!("cannot open file {csv_filepath:?}"))?;
@@ -396,6 +496,14 @@ impl TestnetAllocation {
     /// Import allocations from a reader object that emits CSV.
     pub fn from_reader(csv_input: impl Read) -> Result<Vec<Allocation>> {
         let mut rdr = csv::Reader::from_reader(csv_input);
+        if let Some(column) = rdr.headers()?.iter().find(|header| {
+            let header = header.to_lowercase();
+            header.contains("vesting") || header.contains("lockup")
+        }) {
+            anyhow::bail!(
+                "allocations file has a {column:?} column, but vesting and lockup schedules are not supported"
+            );
+        }
         let mut res = vec![];
         for (line, result) in rdr.deserialize().enumerate() {
             let record: TestnetAllocation = result?;
@@ -652,6 +760,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn reject_allocations_with_vesting_schedules() {
+        let csv_content = r#"
+"amount","denom","address","vesting_end_height"
+"100000","upenumbra","penumbra1rqcd3hfvkvc04c4c9vc0ac87lh4y0z8l28k4xp6d0cnd5jc6f6k0neuzp6zdwtpwyfpswtdzv9jzqtpjn5t6wh96pfx3flq2dhqgc42u7c06kj57dl39w2xm6tg0wh4zc8kjjk","1000"
+"#;
+        let result = TestnetAllocation::from_reader(csv_content.as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn check_allocations_against_intended_supply() -> anyhow::Result<()> {
+        let csv_content = r#"
+"amount","denom","address"
+"100000","upenumbra","penumbra1rqcd3hfvkvc04c4c9vc0ac87lh4y0z8l28k4xp6d0cnd5jc6f6k0neuzp6zdwtpwyfpswtdzv9jzqtpjn5t6wh96pfx3flq2dhqgc42u7c06kj57dl39w2xm6tg0wh4zc8kjjk"
+"50000","upenumbra","penumbra1xq2e9x7uhfzezwunvazdamlxepf4jr5htsuqnzlsahuayyqxjjwg9lk0aytwm6wfj3jy29rv2kdpen57903s8wxv3jmqwj6m6v5jgn6y2cypfd03rke652k8wmavxra7e9wkrg"
+"#;
+        let allos = TestnetAllocation::from_reader(csv_content.as_bytes())?;
+
+        TestnetConfig::check_intended_supply(&allos, &["150000upenumbra".parse()?])?;
+        assert!(
+            TestnetConfig::check_intended_supply(&allos, &["100000upenumbra".parse()?]).is_err()
+        );
+        assert!(TestnetConfig::check_intended_supply(&allos, &["1gm".parse()?]).is_err());
+        Ok(())
+    }
+
     #[test]
     /// Generate a config suitable for local testing: no custom address information, no additional
     /// validators at genesis.
@@ -668,6 +803,7 @@ mod tests {
             None,
             None,
             None,
+            vec![],
         )?;
         assert_eq!(testnet_config.name, "test-chain-1234");
         assert_eq!(testnet_config.genesis.validators.len(), 0);
@@ -697,6 +833,7 @@ mod tests {
             None,
             None,
             None,
+            vec![],
         )?;
         assert_eq!(testnet_config.name, "test-chain-4567");
         assert_eq!(testnet_config.genesis.validators.len(), 0);
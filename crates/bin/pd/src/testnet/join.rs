@@ -21,6 +21,7 @@ pub async fn testnet_join(
     external_address: Option<TendermintAddress>,
     tm_rpc_bind: SocketAddr,
     tm_p2p_bind: SocketAddr,
+    priv_validator_laddr: Option<SocketAddr>,
 ) -> anyhow::Result<()> {
     let mut node_dir = output_dir;
     node_dir.push("node0");
@@ -56,13 +57,21 @@ pub async fn testnet_join(
     peers.extend(new_peers);
     tracing::info!(?peers, "Network peers for inclusion in generated configs");
 
-    let tm_config = TestnetTendermintConfig::new(
+    let mut tm_config = TestnetTendermintConfig::new(
         node_name,
         peers,
         external_address,
         Some(tm_rpc_bind),
         Some(tm_p2p_bind),
     )?;
+    if let Some(laddr) = priv_validator_laddr {
+        // CometBFT ignores its local `priv_validator_key.json` once a remote signer is configured.
+        tracing::info!(%laddr, "configuring CometBFT to listen for a remote signer");
+        tm_config.0.priv_validator_laddr = Some(parse_tm_address(
+            None,
+            &Url::parse(format!("tcp://{}", laddr).as_str())?,
+        )?);
+    }
 
     let tv = TestnetValidator::default();
     tm_config.write_config(node_dir, &tv, &genesis)?;
@@ -25,6 +25,8 @@ impl ActionHandler for Action {
             Action::SwapClaim(action) => action.check_stateless(context).await,
             Action::Spend(action) => action.check_stateless(context).await,
             Action::DelegatorVote(action) => action.check_stateless(context).await,
+            Action::FeeGrantUse(action) => action.check_stateless(context).await,
+            Action::FeeGrantReclaim(action) => action.check_stateless(context).await,
             // These actions don't require a context
             Action::Delegate(action) => action.check_stateless(()).await,
             Action::Undelegate(action) => action.check_stateless(()).await,
@@ -50,6 +52,7 @@ impl ActionHandler for Action {
             Action::CommunityPoolSpend(action) => action.check_stateless(()).await,
             Action::CommunityPoolOutput(action) => action.check_stateless(()).await,
             Action::CommunityPoolDeposit(action) => action.check_stateless(()).await,
+            Action::FeeGrant(action) => action.check_stateless(()).await,
         }
     }
 
@@ -86,6 +89,9 @@ impl ActionHandler for Action {
             Action::CommunityPoolSpend(action) => action.check_stateful(state).await,
             Action::CommunityPoolOutput(action) => action.check_stateful(state).await,
             Action::CommunityPoolDeposit(action) => action.check_stateful(state).await,
+            Action::FeeGrant(action) => action.check_stateful(state).await,
+            Action::FeeGrantUse(action) => action.check_stateful(state).await,
+            Action::FeeGrantReclaim(action) => action.check_stateful(state).await,
         }
     }
 
@@ -118,6 +124,9 @@ impl ActionHandler for Action {
             Action::CommunityPoolSpend(action) => action.execute(state).await,
             Action::CommunityPoolOutput(action) => action.execute(state).await,
             Action::CommunityPoolDeposit(action) => action.execute(state).await,
+            Action::FeeGrant(action) => action.execute(state).await,
+            Action::FeeGrantUse(action) => action.execute(state).await,
+            Action::FeeGrantReclaim(action) => action.execute(state).await,
         }
     }
 }
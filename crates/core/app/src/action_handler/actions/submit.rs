@@ -72,7 +72,10 @@ impl ActionHandler for ProposalSubmit {
         use penumbra_governance::ProposalPayload::*;
         match payload {
             Signaling { commit: _ } => { /* all signaling proposals are valid */ }
-            Emergency { halt_chain: _ } => { /* all emergency proposals are valid */ }
+            Emergency {
+                halt_chain: _,
+                pause_dex: _,
+            } => { /* all emergency proposals are valid */ }
             ParameterChange { old, new } => {
                 // Since the changed app parameters is a differential, we need to construct
                 // a complete AppParameters:
@@ -117,6 +120,14 @@ impl ActionHandler for ProposalSubmit {
                             // actions because they could cause recursion.
                             anyhow::bail!("invalid action in Community Pool spend proposal (not allowed to manipulate proposals from within proposals)")
                         }
+                        FeeGrantUse(_) => {
+                            // Fee grant uses must be signed by the grantee, which a proposal can't do.
+                            anyhow::bail!("invalid action in Community Pool spend proposal (fee grant uses require authorization)")
+                        }
+                        FeeGrantReclaim(_) => {
+                            // Fee grant reclaims must be signed by the sponsor, which a proposal can't do.
+                            anyhow::bail!("invalid action in Community Pool spend proposal (fee grant reclaims require authorization)")
+                        }
                         ValidatorDefinition(_)
                         | IbcAction(_)
                         | ValidatorVote(_)
@@ -126,7 +137,8 @@ impl ActionHandler for ProposalSubmit {
                         | CommunityPoolSpend(_)
                         | CommunityPoolOutput(_)
                         | Ics20Withdrawal(_)
-                        | CommunityPoolDeposit(_) => {
+                        | CommunityPoolDeposit(_)
+                        | FeeGrant(_) => {
                             // These actions are all valid for Community Pool spend proposals, because they
                             // don't require proving, so they don't represent a DoS vector.
                         }
@@ -364,6 +376,8 @@ async fn build_community_pool_transaction(
             effect_hash: Some(effect_hash),
             spend_auths: Default::default(),
             delegator_vote_auths: Default::default(),
+            fee_grant_auths: Default::default(),
+            fee_grant_reclaim_auths: Default::default(),
         },
     )
 }
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use penumbra_fee::component::StateWriteExt as _;
 use penumbra_sct::{component::source::SourceContext, CommitmentSource};
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{gas::GasCost, Transaction};
 use tokio::task::JoinSet;
 use tracing::{instrument, Instrument};
 
@@ -15,8 +16,8 @@ mod stateless;
 
 use self::stateful::{claimed_anchor_is_valid, fee_greater_than_base_fee, fmd_parameters_valid};
 use stateless::{
-    check_memo_exists_if_outputs_absent_if_not, no_duplicate_spends, no_duplicate_votes,
-    num_clues_equal_to_num_outputs, valid_binding_signature,
+    check_memo_exists_if_outputs_absent_if_not, fee_grant_uses_only_pay_fees, no_duplicate_spends,
+    no_duplicate_votes, num_clues_equal_to_num_outputs, valid_binding_signature,
 };
 
 #[async_trait]
@@ -34,6 +35,7 @@ impl ActionHandler for Transaction {
         no_duplicate_votes(self)?;
         num_clues_equal_to_num_outputs(self)?;
         check_memo_exists_if_outputs_absent_if_not(self)?;
+        fee_grant_uses_only_pay_fees(self)?;
 
         let context = self.context();
 
@@ -100,6 +102,9 @@ impl ActionHandler for Transaction {
         // Delete the note source, in case someone else tries to read it.
         state.put_current_source(None);
 
+        // Count the transaction's gas towards the block's, which the base fee adjusts to.
+        state.record_gas_used(self.gas_cost());
+
         Ok(())
     }
 }
@@ -108,16 +113,20 @@ impl ActionHandler for Transaction {
 mod tests {
     use anyhow::Result;
     use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
-    use penumbra_fee::Fee;
+    use penumbra_fee::{
+        grant::{FeeGrantId, FeeGrantUsePlan},
+        Fee,
+    };
     use penumbra_keys::test_keys;
     use penumbra_shielded_pool::{Note, OutputPlan, SpendPlan};
     use penumbra_tct as tct;
     use penumbra_transaction::{
         plan::{CluePlan, DetectionDataPlan, TransactionPlan},
-        TransactionParameters, WitnessData,
+        Action, Transaction, TransactionParameters, WitnessData,
     };
     use rand_core::OsRng;
 
+    use super::fee_grant_uses_only_pay_fees;
     use crate::ActionHandler;
 
     #[tokio::test]
@@ -260,4 +269,55 @@ mod tests {
 
         Ok(())
     }
+
+    fn tx_with_fee_grant_uses(fee: Fee, draws: &[Fee]) -> Transaction {
+        let plan_use = |amount: Fee| {
+            FeeGrantUsePlan::new(
+                &test_keys::FULL_VIEWING_KEY,
+                0,
+                FeeGrantId([0u8; 32]),
+                amount,
+            )
+            .fee_grant_use(&test_keys::FULL_VIEWING_KEY, [0u8; 64].into())
+        };
+        let mut tx = Transaction::default();
+        tx.transaction_body.transaction_parameters.fee = fee;
+        tx.transaction_body.actions = draws
+            .iter()
+            .map(|amount| Action::FeeGrantUse(plan_use(*amount)))
+            .collect();
+        tx
+    }
+
+    #[test]
+    fn fee_grant_uses_may_only_pay_the_fee() {
+        let fee = |amount: u64| Fee::from_staking_token_amount(amount.into());
+
+        // Drawing up to the fee, in one or several uses, is fine.
+        assert!(
+            fee_grant_uses_only_pay_fees(&tx_with_fee_grant_uses(fee(100), &[fee(100)])).is_ok()
+        );
+        assert!(fee_grant_uses_only_pay_fees(&tx_with_fee_grant_uses(
+            fee(100),
+            &[fee(30), fee(70)]
+        ))
+        .is_ok());
+
+        // Drawing more than the fee would let the excess be spent on outputs.
+        assert!(fee_grant_uses_only_pay_fees(&tx_with_fee_grant_uses(
+            fee(100),
+            &[fee(60), fee(60)]
+        ))
+        .is_err());
+
+        // So would drawing an asset other than the fee's.
+        let other_asset = Fee(Value {
+            amount: 50u64.into(),
+            asset_id: penumbra_asset::asset::Id(decaf377::Fq::from(1u64)),
+        });
+        assert!(
+            fee_grant_uses_only_pay_fees(&tx_with_fee_grant_uses(fee(100), &[other_asset]))
+                .is_err()
+        );
+    }
 }
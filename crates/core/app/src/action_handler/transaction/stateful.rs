@@ -83,20 +83,21 @@ pub async fn fee_greater_than_base_fee<S: StateRead>(
     state: S,
     transaction: &Transaction,
 ) -> Result<()> {
-    let current_gas_prices = state
-        .get_gas_prices()
+    let fee = transaction.transaction_body().transaction_parameters.fee;
+    let Some(current_gas_prices) = state
+        .get_gas_prices_for(fee.asset_id())
         .await
-        .expect("gas prices must be present in state");
+        .expect("gas prices must be present in state")
+    else {
+        anyhow::bail!(
+            "consensus rule violated: transaction fees can't be paid in {}",
+            fee.asset_id()
+        );
+    };
 
     let transaction_base_price = current_gas_prices.fee(&transaction.gas_cost());
 
-    if transaction
-        .transaction_body()
-        .transaction_parameters
-        .fee
-        .amount()
-        >= transaction_base_price
-    {
+    if fee.amount() >= transaction_base_price {
         Ok(())
     } else {
         Err(anyhow::anyhow!(
@@ -87,3 +87,28 @@ pub fn check_memo_exists_if_outputs_absent_if_not(tx: &Transaction) -> anyhow::R
         ))
     }
 }
+
+pub(super) fn fee_grant_uses_only_pay_fees(tx: &Transaction) -> Result<()> {
+    // Fee grants escrow value for paying fees, so the value drawn from them must not
+    // exceed the transaction's fee, or it could be diverted to outputs.
+    let fee = tx.transaction_parameters().fee;
+    let mut drawn = penumbra_num::Amount::zero();
+    for grant_use in tx.fee_grant_uses() {
+        if grant_use.body.amount.asset_id() != fee.asset_id() {
+            anyhow::bail!("fee grant use must be in the same asset as the transaction fee");
+        }
+        drawn = drawn
+            .checked_add(&grant_use.body.amount.amount())
+            .context("fee grant use amounts overflow")?;
+    }
+
+    if drawn > fee.amount() {
+        anyhow::bail!(
+            "fee grant uses draw {} but the transaction fee is only {}",
+            drawn,
+            fee.amount()
+        );
+    }
+
+    Ok(())
+}
@@ -9,7 +9,7 @@ use jmt::RootHash;
 use penumbra_community_pool::component::{CommunityPool, StateWriteExt as _};
 use penumbra_community_pool::StateReadExt as _;
 use penumbra_compact_block::component::CompactBlockManager;
-use penumbra_dex::component::Dex;
+use penumbra_dex::component::{Dex, StateReadExt as _, StateWriteExt as _};
 use penumbra_distributions::component::{Distributions, StateReadExt as _, StateWriteExt as _};
 use penumbra_fee::component::{Fee, StateReadExt as _, StateWriteExt as _};
 use penumbra_funding::component::Funding;
@@ -38,7 +38,9 @@ use tendermint::validator::Update;
 use tracing::Instrument;
 
 use crate::action_handler::ActionHandler;
+use crate::epoch_summary::EpochSummaryWrite as _;
 use crate::params::AppParameters;
+use crate::profile::{BlockProfile, Stopwatch};
 use crate::{CommunityPoolStateReadExt, PenumbraHost};
 
 pub mod state_key;
@@ -53,6 +55,10 @@ type InterBlockState = Arc<StateDelta<Snapshot>>;
 /// commits the changes to the persistent storage and resets its subcomponents.
 pub struct App {
     state: InterBlockState,
+    /// The timings of executing the current block, reported at commit.
+    profile: BlockProfile,
+    /// The sequence number of the next event emitted in the current block.
+    event_sequence: u64,
 }
 
 impl App {
@@ -71,7 +77,11 @@ impl App {
             anyhow::bail!("chain is halted, refusing to restart");
         }
 
-        Ok(Self { state })
+        Ok(Self {
+            state,
+            profile: BlockProfile::default(),
+            event_sequence: 0,
+        })
     }
 
     // StateDelta::apply only works when the StateDelta wraps an underlying
@@ -87,14 +97,27 @@ impl App {
         std::mem::drop(state2);
         // Now there is only one reference to the inter-block state: self.state
 
-        let events = cache.take_events();
+        let mut events = cache.take_events();
         cache.apply_to(
             Arc::get_mut(&mut self.state).expect("no other references to inter-block state"),
         );
 
+        self.sequence_events(&mut events);
         events
     }
 
+    /// Numbers `events` in the order they were emitted in the block, following the events emitted
+    /// before them, so that indexers can order the events of a block across the responses to
+    /// `BeginBlock`, `DeliverTx` and `EndBlock`.
+    ///
+    /// The events of a transaction that fails aren't emitted, so they don't take up numbers.
+    fn sequence_events(&mut self, events: &mut [Event]) {
+        for event in events {
+            penumbra_proto::event::set_event_sequence(event, self.event_sequence);
+            self.event_sequence += 1;
+        }
+    }
+
     pub async fn init_chain(&mut self, app_state: &AppState) {
         let mut state_tx = self
             .state
@@ -116,7 +139,7 @@ impl App {
                 )
                 .await;
                 Ibc::init_chain(&mut state_tx, Some(&genesis.ibc_content)).await;
-                Dex::init_chain(&mut state_tx, Some(&())).await;
+                Dex::init_chain(&mut state_tx, Some(&genesis.dex_content)).await;
                 CommunityPool::init_chain(&mut state_tx, Some(&genesis.community_pool_content))
                     .await;
                 Governance::init_chain(&mut state_tx, Some(&genesis.governance_content)).await;
@@ -200,6 +223,9 @@ impl App {
     }
 
     pub async fn begin_block(&mut self, begin_block: &request::BeginBlock) -> Vec<abci::Event> {
+        self.profile = BlockProfile::new(begin_block.header.height.value());
+        self.event_sequence = 0;
+        let mut stopwatch = Stopwatch::start();
         let mut state_tx = StateDelta::new(self.state.clone());
 
         // If a app parameter change is scheduled for this block, apply it here, before any other
@@ -216,6 +242,9 @@ impl App {
             if let Some(community_pool_params) = app_params.new.community_pool_params {
                 state_tx.put_community_pool_params(community_pool_params);
             }
+            if let Some(dex_params) = app_params.new.dex_params {
+                state_tx.put_dex_params(dex_params);
+            }
             if let Some(distributions_params) = app_params.new.distributions_params {
                 state_tx.put_distributions_params(distributions_params);
             }
@@ -242,21 +271,42 @@ impl App {
             }
         }
 
+        self.profile
+            .record(&["begin_block", "app_parameters"], stopwatch.lap());
+
         // Run each of the begin block handlers for each component, in sequence:
         let mut arc_state_tx = Arc::new(state_tx);
         Sct::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "sct"], stopwatch.lap());
         ShieldedPool::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "shielded_pool"], stopwatch.lap());
         Distributions::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "distributions"], stopwatch.lap());
         Ibc::begin_block::<PenumbraHost, StateDelta<Arc<StateDelta<cnidarium::Snapshot>>>>(
             &mut arc_state_tx,
             begin_block,
         )
         .await;
+        self.profile
+            .record(&["begin_block", "ibc"], stopwatch.lap());
         CommunityPool::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "community_pool"], stopwatch.lap());
         Governance::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "governance"], stopwatch.lap());
         Staking::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "staking"], stopwatch.lap());
         Fee::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "fee"], stopwatch.lap());
         Funding::begin_block(&mut arc_state_tx, begin_block).await;
+        self.profile
+            .record(&["begin_block", "funding"], stopwatch.lap());
 
         let state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components did not retain copies of shared state");
@@ -288,16 +338,26 @@ impl App {
                 Ok(community_pool_tx_events) => events.extend(community_pool_tx_events),
             }
         }
+        self.profile.record(
+            &["begin_block", "community_pool_transactions"],
+            stopwatch.lap(),
+        );
 
         events
     }
 
     /// Wrapper function for [`Self::deliver_tx`]  that decodes from bytes.
     pub async fn deliver_tx_bytes(&mut self, tx_bytes: &[u8]) -> Result<Vec<abci::Event>> {
-        let tx = Arc::new(Transaction::decode(tx_bytes).context("decoding transaction")?);
-        self.deliver_tx(tx)
-            .await
-            .context("failed to deliver transaction")
+        let mut stopwatch = Stopwatch::start();
+        let result = async {
+            let tx = Arc::new(Transaction::decode(tx_bytes).context("decoding transaction")?);
+            self.deliver_tx(tx)
+                .await
+                .context("failed to deliver transaction")
+        }
+        .await;
+        self.profile.record(&["deliver_tx"], stopwatch.lap());
+        result
     }
 
     pub async fn deliver_tx(&mut self, tx: Arc<Transaction>) -> Result<Vec<abci::Event>> {
@@ -365,27 +425,57 @@ impl App {
             .await
             .context("executing transaction")?;
 
+        state_tx
+            .record_transaction_in_epoch_summary(&tx)
+            .await
+            .context("recording transaction in epoch summary")?;
+
         // At this point, we've completed execution successfully with no errors,
         // so we can apply the transaction to the State. Otherwise, we'd have
         // bubbled up an error and dropped the StateTransaction.
-        Ok(state_tx.apply().1)
+        let mut events = state_tx.apply().1;
+        self.sequence_events(&mut events);
+        Ok(events)
     }
 
     pub async fn end_block(&mut self, end_block: &request::EndBlock) -> Vec<abci::Event> {
+        let mut stopwatch = Stopwatch::start();
         let state_tx = StateDelta::new(self.state.clone());
 
         let mut arc_state_tx = Arc::new(state_tx);
         ShieldedPool::end_block(&mut arc_state_tx, end_block).await;
+        self.profile
+            .record(&["end_block", "shielded_pool"], stopwatch.lap());
         Distributions::end_block(&mut arc_state_tx, end_block).await;
+        self.profile
+            .record(&["end_block", "distributions"], stopwatch.lap());
         Ibc::end_block(&mut arc_state_tx, end_block).await;
+        self.profile.record(&["end_block", "ibc"], stopwatch.lap());
         Dex::end_block(&mut arc_state_tx, end_block).await;
+        self.profile.record(&["end_block", "dex"], stopwatch.lap());
         CommunityPool::end_block(&mut arc_state_tx, end_block).await;
+        self.profile
+            .record(&["end_block", "community_pool"], stopwatch.lap());
         Governance::end_block(&mut arc_state_tx, end_block).await;
+        self.profile
+            .record(&["end_block", "governance"], stopwatch.lap());
         Staking::end_block(&mut arc_state_tx, end_block).await;
+        self.profile
+            .record(&["end_block", "staking"], stopwatch.lap());
         Fee::end_block(&mut arc_state_tx, end_block).await;
+        self.profile.record(&["end_block", "fee"], stopwatch.lap());
         Funding::end_block(&mut arc_state_tx, end_block).await;
+        self.profile
+            .record(&["end_block", "funding"], stopwatch.lap());
         let mut state_tx = Arc::try_unwrap(arc_state_tx)
             .expect("components did not retain copies of shared state");
+        if let Some(timings) = state_tx.execution_timings() {
+            self.profile.record_dex(timings);
+        }
+        state_tx
+            .record_batch_swaps_in_epoch_summary()
+            .await
+            .expect("able to record batch swaps in epoch summary");
 
         // Since governance proposals can affect the entirety of application state, and the governance component
         // does not have access to the types defined in this crate so we need to handle validating them here.
@@ -506,6 +596,12 @@ impl App {
             let mut state_tx = Arc::try_unwrap(arc_state_tx)
                 .expect("components did not retain copies of shared state");
 
+            // Now that the components have ended the epoch, the staking issuance is known.
+            state_tx
+                .finish_epoch_summary(current_epoch, current_height)
+                .await
+                .expect("able to finish epoch summary");
+
             state_tx
                 .finish_epoch(state_tx.app_params_updated())
                 .await
@@ -520,6 +616,8 @@ impl App {
                     start_height: current_height + 1,
                 },
             );
+            self.profile
+                .record(&["end_block", "end_epoch"], stopwatch.lap());
 
             self.apply(state_tx)
         } else {
@@ -534,6 +632,8 @@ impl App {
                 .finish_block(state_tx.app_params_updated())
                 .await
                 .expect("must be able to finish compact block");
+            self.profile
+                .record(&["end_block", "finish_block"], stopwatch.lap());
 
             self.apply(state_tx)
         }
@@ -545,6 +645,7 @@ impl App {
     /// This method also resets `self` as if it were constructed
     /// as an empty state over top of the newly written storage.
     pub async fn commit(&mut self, storage: Storage) -> RootHash {
+        let mut stopwatch = Stopwatch::start();
         // We need to extract the State we've built up to commit it.  Fill in a dummy state.
         let dummy_state = StateDelta::new(storage.latest_snapshot());
         let mut state = Arc::try_unwrap(std::mem::replace(&mut self.state, Arc::new(dummy_state)))
@@ -589,6 +690,8 @@ impl App {
         }
 
         tracing::debug!(?jmt_root, "finished committing state");
+        self.profile.record(&["commit"], stopwatch.lap());
+        std::mem::take(&mut self.profile).finish();
 
         // Get the latest version of the state, now that we've committed it.
         self.state = Arc::new(StateDelta::new(storage.latest_snapshot()));
@@ -616,6 +719,7 @@ pub trait StateReadExt: StateRead {
     /// Returns true if the app parameters have been changed in this block.
     fn app_params_updated(&self) -> bool {
         self.community_pool_params_updated()
+            || self.dex_params_updated()
             || self.distributions_params_updated()
             || self.ibc_params_updated()
             || self.fee_params_updated()
@@ -667,6 +771,7 @@ pub trait StateReadExt: StateRead {
         let chain_id = self.get_chain_id().await?;
         let community_pool_params: penumbra_community_pool::params::CommunityPoolParameters =
             self.get_community_pool_params().await?;
+        let dex_params = self.get_dex_params().await?;
         let distributions_params = self.get_distributions_params().await?;
         let ibc_params = self.get_ibc_params().await?;
         let fee_params = self.get_fee_params().await?;
@@ -679,6 +784,7 @@ pub trait StateReadExt: StateRead {
         Ok(AppParameters {
             chain_id,
             community_pool_params,
+            dex_params,
             distributions_params,
             fee_params,
             funding_params,
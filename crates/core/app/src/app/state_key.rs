@@ -26,3 +26,13 @@ pub mod counters {
         "application/counters/halt_count"
     }
 }
+
+pub mod epoch_summary {
+    pub fn pending() -> &'static str {
+        "application/epoch_summary/pending"
+    }
+
+    pub fn by_index(epoch_index: u64) -> String {
+        format!("application/epoch_summary/by_index/{epoch_index:020}")
+    }
+}
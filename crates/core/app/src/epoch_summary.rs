@@ -0,0 +1,423 @@
+//! Summarizing the activity on chain during each epoch.
+//!
+//! While an epoch is in progress, the app accumulates a pending [`EpochSummary`] in the
+//! nonverifiable store as it executes transactions and batch swaps. When the epoch ends, the
+//! summary is completed with the staking issuance and IBC flows, stored under the epoch's index,
+//! and emitted as an [`EventEpochSummary`](pb::EventEpochSummary).
+//!
+//! Summaries are derived entirely from consensus state, but are not themselves part of it.
+
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::StreamExt;
+use penumbra_asset::{asset, Value};
+use penumbra_dex::{component::StateReadExt as _, TradingPair};
+use penumbra_distributions::component::StateReadExt as _;
+use penumbra_num::Amount;
+use penumbra_proto::{core::app::v1 as pb, DomainType, StateWriteProto as _};
+use penumbra_sct::epoch::Epoch;
+use penumbra_shielded_pool::component::SupplyRead as _;
+use penumbra_transaction::{Action, Transaction};
+use serde::{Deserialize, Serialize};
+
+use crate::app::state_key;
+
+/// The prefix of the shielded pool's per-asset state, which holds the ICS20 totals.
+const SHIELDED_POOL_ASSETS_PREFIX: &str = "shielded_pool/assets/";
+
+/// A rollup of the activity on chain during an epoch.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::EpochSummary", into = "pb::EpochSummary")]
+pub struct EpochSummary {
+    pub epoch_index: u64,
+    /// The height of the first block in the epoch.
+    pub start_height: u64,
+    /// The height of the last block in the epoch.
+    pub end_height: u64,
+    /// The staking tokens issued at the end of the epoch.
+    pub staking_issuance: Amount,
+    /// The fees paid by the epoch's transactions, by asset.
+    pub fees: Vec<Value>,
+    /// The amounts swapped in the epoch's batch swaps, by trading pair.
+    pub dex_volume: Vec<PairVolume>,
+    pub positions_opened: u64,
+    pub positions_closed: u64,
+    /// The amounts transferred over ICS20, by asset.
+    pub ibc_flows: Vec<IbcFlow>,
+}
+
+/// The amounts swapped on a trading pair during an epoch.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::PairVolume", into = "pb::PairVolume")]
+pub struct PairVolume {
+    pub trading_pair: TradingPair,
+    /// The amount of asset 1 swapped into asset 2.
+    pub swapped_1: Amount,
+    /// The amount of asset 2 swapped into asset 1.
+    pub swapped_2: Amount,
+}
+
+/// The amounts of an asset transferred over ICS20, during an epoch and in total.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::IbcFlow", into = "pb::IbcFlow")]
+pub struct IbcFlow {
+    pub asset_id: asset::Id,
+    /// The amount received during the epoch.
+    pub inflow: Amount,
+    /// The amount sent during the epoch, net of refunds.
+    ///
+    /// This is zero if the refunds during the epoch exceeded the amount sent.
+    pub outflow: Amount,
+    /// The amount received since genesis.
+    pub total_inflow: Amount,
+    /// The amount sent since genesis, net of refunds.
+    pub total_outflow: Amount,
+}
+
+impl EpochSummary {
+    /// Adds the fee paid by, and the positions opened and closed by, `transaction`.
+    pub fn add_transaction(&mut self, transaction: &Transaction) {
+        let fee = transaction.transaction_body().transaction_parameters.fee;
+        self.add_fee(fee.0);
+        for action in transaction.actions() {
+            match action {
+                Action::PositionOpen(_) => self.positions_opened += 1,
+                Action::PositionClose(_) => self.positions_closed += 1,
+                _ => {}
+            }
+        }
+    }
+
+    fn add_fee(&mut self, fee: Value) {
+        match self
+            .fees
+            .iter_mut()
+            .find(|value| value.asset_id == fee.asset_id)
+        {
+            Some(value) => value.amount = value.amount.saturating_add(&fee.amount),
+            None => self.fees.push(fee),
+        }
+    }
+
+    /// Adds the filled part of a batch swap on `trading_pair`.
+    pub fn add_batch_swap(
+        &mut self,
+        trading_pair: TradingPair,
+        swapped_1: Amount,
+        swapped_2: Amount,
+    ) {
+        match self
+            .dex_volume
+            .iter_mut()
+            .find(|volume| volume.trading_pair == trading_pair)
+        {
+            Some(volume) => {
+                volume.swapped_1 = volume.swapped_1.saturating_add(&swapped_1);
+                volume.swapped_2 = volume.swapped_2.saturating_add(&swapped_2);
+            }
+            None => self.dex_volume.push(PairVolume {
+                trading_pair,
+                swapped_1,
+                swapped_2,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+pub trait EpochSummaryRead: StateRead {
+    /// Gets the summary of the epoch with the given index, if it has ended.
+    async fn epoch_summary(&self, epoch_index: u64) -> Result<Option<EpochSummary>> {
+        self.nonverifiable_get_raw(state_key::epoch_summary::by_index(epoch_index).as_bytes())
+            .await?
+            .map(|bytes| EpochSummary::decode(&bytes[..]))
+            .transpose()
+    }
+
+    /// Gets the summary of the epoch in progress, so far.
+    async fn pending_epoch_summary(&self) -> Result<EpochSummary> {
+        Ok(self
+            .nonverifiable_get_raw(state_key::epoch_summary::pending().as_bytes())
+            .await?
+            .map(|bytes| EpochSummary::decode(&bytes[..]))
+            .transpose()?
+            .unwrap_or_default())
+    }
+
+    /// Gets the ICS20 flows of every asset ever transferred over ICS20, as of the end of the
+    /// epoch with the given index.
+    async fn ics20_flows(&self, epoch_index: u64) -> Result<Vec<IbcFlow>> {
+        let previous: BTreeMap<asset::Id, IbcFlow> = match epoch_index.checked_sub(1) {
+            Some(previous_index) => self
+                .epoch_summary(previous_index)
+                .await?
+                .map(|summary| summary.ibc_flows)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|flow| (flow.asset_id, flow))
+                .collect(),
+            None => BTreeMap::new(),
+        };
+
+        let mut keys = self.prefix_keys(SHIELDED_POOL_ASSETS_PREFIX);
+        let mut asset_ids = Vec::new();
+        while let Some(key) = keys.next().await {
+            let key = key?;
+            let Some((asset_id, field)) = key
+                .strip_prefix(SHIELDED_POOL_ASSETS_PREFIX)
+                .and_then(|rest| rest.split_once('/'))
+            else {
+                continue;
+            };
+            if field != "ics20_inflow" && field != "ics20_outflow" {
+                continue;
+            }
+            let asset_id: asset::Id = asset_id
+                .parse()
+                .with_context(|| format!("invalid asset id in state key {key}"))?;
+            if !asset_ids.contains(&asset_id) {
+                asset_ids.push(asset_id);
+            }
+        }
+
+        let mut flows = Vec::with_capacity(asset_ids.len());
+        for asset_id in asset_ids {
+            let total_inflow = self.ics20_inflow(&asset_id).await?;
+            let total_outflow = self.ics20_outflow(&asset_id).await?;
+            let (previous_inflow, previous_outflow) = previous
+                .get(&asset_id)
+                .map(|flow| (flow.total_inflow, flow.total_outflow))
+                .unwrap_or_default();
+            flows.push(IbcFlow {
+                asset_id,
+                inflow: total_inflow.saturating_sub(&previous_inflow),
+                outflow: total_outflow.saturating_sub(&previous_outflow),
+                total_inflow,
+                total_outflow,
+            });
+        }
+        Ok(flows)
+    }
+}
+
+impl<T: StateRead + ?Sized> EpochSummaryRead for T {}
+
+#[async_trait]
+pub trait EpochSummaryWrite: StateWrite {
+    fn put_pending_epoch_summary(&mut self, summary: EpochSummary) {
+        self.nonverifiable_put_raw(
+            state_key::epoch_summary::pending().into(),
+            summary.encode_to_vec(),
+        );
+    }
+
+    /// Adds `transaction` to the summary of the epoch in progress.
+    async fn record_transaction_in_epoch_summary(
+        &mut self,
+        transaction: &Transaction,
+    ) -> Result<()> {
+        let mut summary = self.pending_epoch_summary().await?;
+        summary.add_transaction(transaction);
+        self.put_pending_epoch_summary(summary);
+        Ok(())
+    }
+
+    /// Adds the batch swaps executed in the current block to the summary of the epoch in progress.
+    async fn record_batch_swaps_in_epoch_summary(&mut self) -> Result<()> {
+        let outputs = self.pending_batch_swap_outputs();
+        if outputs.is_empty() {
+            return Ok(());
+        }
+        let mut summary = self.pending_epoch_summary().await?;
+        for (trading_pair, output) in outputs {
+            summary.add_batch_swap(
+                trading_pair,
+                output.delta_1.saturating_sub(&output.unfilled_1),
+                output.delta_2.saturating_sub(&output.unfilled_2),
+            );
+        }
+        self.put_pending_epoch_summary(summary);
+        Ok(())
+    }
+
+    /// Completes the summary of `epoch`, which ends at `end_height`, stores it, and records an
+    /// event for it.
+    ///
+    /// This must be called after the components' `end_epoch`, so that the staking issuance for
+    /// the epoch has been computed.
+    async fn finish_epoch_summary(
+        &mut self,
+        epoch: Epoch,
+        end_height: u64,
+    ) -> Result<EpochSummary> {
+        let mut summary = self.pending_epoch_summary().await?;
+        summary.epoch_index = epoch.index;
+        summary.start_height = epoch.start_height;
+        summary.end_height = end_height;
+        summary.staking_issuance = self
+            .get_staking_token_issuance_for_epoch()
+            .unwrap_or_default();
+        summary.ibc_flows = self.ics20_flows(epoch.index).await?;
+
+        self.nonverifiable_put_raw(
+            state_key::epoch_summary::by_index(epoch.index).into(),
+            summary.encode_to_vec(),
+        );
+        self.put_pending_epoch_summary(EpochSummary::default());
+        self.record_proto(pb::EventEpochSummary {
+            summary: Some(summary.clone().into()),
+        });
+        Ok(summary)
+    }
+}
+
+impl<T: StateWrite + ?Sized> EpochSummaryWrite for T {}
+
+impl DomainType for EpochSummary {
+    type Proto = pb::EpochSummary;
+}
+
+impl From<EpochSummary> for pb::EpochSummary {
+    fn from(summary: EpochSummary) -> Self {
+        Self {
+            epoch_index: summary.epoch_index,
+            start_height: summary.start_height,
+            end_height: summary.end_height,
+            staking_issuance: Some(summary.staking_issuance.into()),
+            fees: summary.fees.into_iter().map(Into::into).collect(),
+            dex_volume: summary.dex_volume.into_iter().map(Into::into).collect(),
+            positions_opened: summary.positions_opened,
+            positions_closed: summary.positions_closed,
+            ibc_flows: summary.ibc_flows.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::EpochSummary> for EpochSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::EpochSummary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            epoch_index: proto.epoch_index,
+            start_height: proto.start_height,
+            end_height: proto.end_height,
+            staking_issuance: proto
+                .staking_issuance
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            fees: proto
+                .fees
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            dex_volume: proto
+                .dex_volume
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            positions_opened: proto.positions_opened,
+            positions_closed: proto.positions_closed,
+            ibc_flows: proto
+                .ibc_flows
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl DomainType for PairVolume {
+    type Proto = pb::PairVolume;
+}
+
+impl From<PairVolume> for pb::PairVolume {
+    fn from(volume: PairVolume) -> Self {
+        Self {
+            trading_pair: Some(volume.trading_pair.into()),
+            swapped_1: Some(volume.swapped_1.into()),
+            swapped_2: Some(volume.swapped_2.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PairVolume> for PairVolume {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::PairVolume) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: proto
+                .trading_pair
+                .context("missing trading pair")?
+                .try_into()?,
+            swapped_1: proto.swapped_1.context("missing swapped_1")?.try_into()?,
+            swapped_2: proto.swapped_2.context("missing swapped_2")?.try_into()?,
+        })
+    }
+}
+
+impl DomainType for IbcFlow {
+    type Proto = pb::IbcFlow;
+}
+
+impl From<IbcFlow> for pb::IbcFlow {
+    fn from(flow: IbcFlow) -> Self {
+        Self {
+            asset_id: Some(flow.asset_id.into()),
+            inflow: Some(flow.inflow.into()),
+            outflow: Some(flow.outflow.into()),
+            total_inflow: Some(flow.total_inflow.into()),
+            total_outflow: Some(flow.total_outflow.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::IbcFlow> for IbcFlow {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::IbcFlow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            asset_id: proto.asset_id.context("missing asset id")?.try_into()?,
+            inflow: proto.inflow.context("missing inflow")?.try_into()?,
+            outflow: proto.outflow.context("missing outflow")?.try_into()?,
+            total_inflow: proto
+                .total_inflow
+                .context("missing total inflow")?
+                .try_into()?,
+            total_outflow: proto
+                .total_outflow
+                .context("missing total outflow")?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_swaps_accumulate_by_pair() {
+        let pair = TradingPair::new(
+            asset::Id(decaf377::Fq::from(1u64)),
+            asset::Id(decaf377::Fq::from(2u64)),
+        );
+        let mut summary = EpochSummary::default();
+        summary.add_batch_swap(pair, 10u64.into(), 0u64.into());
+        summary.add_batch_swap(pair, 5u64.into(), 7u64.into());
+        assert_eq!(
+            summary.dex_volume,
+            vec![PairVolume {
+                trading_pair: pair,
+                swapped_1: 15u64.into(),
+                swapped_2: 7u64.into(),
+            }]
+        );
+        let roundtrip =
+            EpochSummary::decode(summary.encode_to_vec().as_slice()).expect("summary roundtrips");
+        assert_eq!(roundtrip, summary);
+    }
+}
@@ -2,9 +2,13 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
 pub mod app;
+pub mod epoch_summary;
 pub mod metrics;
 pub mod params;
+pub mod profile;
+pub mod rejection;
 pub mod rpc;
+pub mod screening;
 pub mod server;
 
 mod action_handler;
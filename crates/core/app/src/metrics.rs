@@ -29,6 +29,12 @@ pub fn register_metrics() {
         Unit::Count,
         "The total number of checktx requests made to the mempool"
     );
+    describe_histogram!(
+        PD_BLOCK_PHASE_DURATION,
+        Unit::Seconds,
+        "The time spent by each component in each phase of executing a block, when block profiling is enabled"
+    );
 }
 
 pub const MEMPOOL_CHECKTX_TOTAL: &str = "penumbra_pd_mempool_checktx_total";
+pub const PD_BLOCK_PHASE_DURATION: &str = "penumbra_pd_block_phase_duration_seconds";
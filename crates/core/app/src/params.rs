@@ -1,4 +1,5 @@
 use penumbra_community_pool::params::CommunityPoolParameters;
+use penumbra_dex::DexParameters;
 use penumbra_distributions::DistributionsParameters;
 use penumbra_fee::FeeParameters;
 use penumbra_funding::FundingParameters;
@@ -19,6 +20,7 @@ pub mod change;
 pub struct AppParameters {
     pub chain_id: String,
     pub community_pool_params: CommunityPoolParameters,
+    pub dex_params: DexParameters,
     pub distributions_params: DistributionsParameters,
     pub fee_params: FeeParameters,
     pub funding_params: FundingParameters,
@@ -43,6 +45,10 @@ impl TryFrom<pb::AppParameters> for AppParameters {
                 .community_pool_params
                 .ok_or_else(|| anyhow::anyhow!("proto response missing community pool params"))?
                 .try_into()?,
+            dex_params: msg
+                .dex_params
+                .ok_or_else(|| anyhow::anyhow!("proto response missing dex params"))?
+                .try_into()?,
             distributions_params: msg
                 .distributions_params
                 .ok_or_else(|| anyhow::anyhow!("proto response missing distribution params"))?
@@ -84,6 +90,7 @@ impl From<AppParameters> for pb::AppParameters {
         pb::AppParameters {
             chain_id: params.chain_id,
             community_pool_params: Some(params.community_pool_params.into()),
+            dex_params: Some(params.dex_params.into()),
             distributions_params: Some(params.distributions_params.into()),
             fee_params: Some(params.fee_params.into()),
             funding_params: Some(params.funding_params.into()),
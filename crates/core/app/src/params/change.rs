@@ -1,9 +1,11 @@
-use std::fmt::Display;
+use std::{collections::BTreeSet, fmt::Display};
 
 use anyhow::Result;
+use penumbra_asset::STAKING_TOKEN_ASSET_ID;
 use penumbra_community_pool::params::CommunityPoolParameters;
+use penumbra_dex::{swap::MAX_MULTI_HOP_PENALTY_BPS, DexParameters};
 use penumbra_distributions::params::DistributionsParameters;
-use penumbra_fee::FeeParameters;
+use penumbra_fee::{DynamicBaseFee, FeeParameters};
 use penumbra_funding::params::FundingParameters;
 use penumbra_governance::{
     params::GovernanceParameters, proposal::ChangedAppParameters, tally::Ratio,
@@ -21,7 +23,12 @@ use super::AppParameters;
 #[deny(unused)] // We want to be really careful here to not examine fields!
 impl AppParameters {
     pub fn check_valid_update(&self, new: &AppParameters) -> Result<()> {
-        new.check_valid()?;
+        fail_if_any(self.update_errors(new))
+    }
+
+    /// The reasons an update from these parameters to `new` is invalid, or empty if it is valid.
+    pub fn update_errors(&self, new: &AppParameters) -> Vec<String> {
+        let mut errors = new.errors();
         // TODO: move the checks below into their respective components.
         // Tracked by #3593
 
@@ -31,13 +38,24 @@ impl AppParameters {
                 CommunityPoolParameters {
                     community_pool_spend_proposals_enabled: _,
                 },
+            dex_params:
+                DexParameters {
+                    is_paused: _,
+                    min_position_reserves: _,
+                    min_position_fee_bps: _,
+                    max_position_fee_bps: _,
+                    default_multi_hop_penalty_bps: _,
+                },
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
                 },
-            fee_params: FeeParameters {
-                fixed_gas_prices: _,
-            },
+            fee_params:
+                FeeParameters {
+                    fixed_gas_prices: _,
+                    dynamic_base_fee: _,
+                    fixed_alt_gas_prices: _,
+                },
             funding_params: FundingParameters {},
             governance_params:
                 GovernanceParameters {
@@ -73,8 +91,8 @@ impl AppParameters {
         } = self;
 
         // Ensure that certain parameters are not changed by the update:
-        check_invariant([(chain_id, &new.chain_id, "chain ID")])?;
-        check_invariant([
+        errors.extend(invariant_failures([(chain_id, &new.chain_id, "chain ID")]));
+        errors.extend(invariant_failures([
             (
                 epoch_duration,
                 &new.sct_params.epoch_duration,
@@ -90,8 +108,8 @@ impl AppParameters {
                 &new.stake_params.signed_blocks_window_len,
                 "signed blocks window length",
             ),
-        ])?;
-        check_invariant([
+        ]));
+        errors.extend(invariant_failures([
             (
                 proposal_valid_quorum,
                 &new.governance_params.proposal_valid_quorum,
@@ -107,25 +125,41 @@ impl AppParameters {
                 &new.governance_params.proposal_slash_threshold,
                 "proposal slash threshold",
             ),
-        ])?;
+        ]));
 
-        Ok(())
+        errors
     }
 
     pub fn check_valid(&self) -> Result<()> {
+        fail_if_any(self.errors())
+    }
+
+    /// The reasons these parameters are invalid, or empty if they are valid.
+    pub fn errors(&self) -> Vec<String> {
         let AppParameters {
             chain_id,
             community_pool_params:
                 CommunityPoolParameters {
                     community_pool_spend_proposals_enabled: _,
                 },
+            dex_params:
+                DexParameters {
+                    is_paused: _,
+                    min_position_reserves: _,
+                    min_position_fee_bps,
+                    max_position_fee_bps,
+                    default_multi_hop_penalty_bps,
+                },
             distributions_params:
                 DistributionsParameters {
                     staking_issuance_per_block: _,
                 },
-            fee_params: FeeParameters {
-                fixed_gas_prices: _,
-            },
+            fee_params:
+                FeeParameters {
+                    fixed_gas_prices: _,
+                    dynamic_base_fee,
+                    fixed_alt_gas_prices,
+                },
             funding_params: FundingParameters {},
             governance_params:
                 GovernanceParameters {
@@ -160,7 +194,7 @@ impl AppParameters {
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
 
-        check_all([
+        let mut errors = failures([
             (!chain_id.is_empty(), "chain ID must be a non-empty string"),
             (
                 *epoch_duration >= 1,
@@ -231,13 +265,127 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
-        ])
+            (
+                *min_position_fee_bps <= 5_000,
+                "the minimum position fee must be at most 5,000 basis points",
+            ),
+            (
+                *max_position_fee_bps <= 5_000,
+                "the maximum position fee must be at most 5,000 basis points",
+            ),
+            (
+                *max_position_fee_bps == 0 || *min_position_fee_bps <= *max_position_fee_bps,
+                "the minimum position fee must not exceed the maximum position fee",
+            ),
+            (
+                *default_multi_hop_penalty_bps <= MAX_MULTI_HOP_PENALTY_BPS,
+                "the default multi-hop penalty must be at most 10,000 basis points",
+            ),
+        ]);
+
+        if let Some(DynamicBaseFee {
+            min_gas_prices: min,
+            max_gas_prices: max,
+            target_block_gas: target,
+            max_change_bps,
+        }) = dynamic_base_fee
+        {
+            errors.extend(failures([
+                (
+                    min.block_space_price <= max.block_space_price
+                        && min.compact_block_space_price <= max.compact_block_space_price
+                        && min.verification_price <= max.verification_price
+                        && min.execution_price <= max.execution_price,
+                    "the minimum gas prices of the dynamic base fee must not exceed its maximum gas prices",
+                ),
+                (
+                    target.block_space >= 1
+                        && target.compact_block_space >= 1
+                        && target.verification >= 1
+                        && target.execution >= 1,
+                    "the target block gas of the dynamic base fee must be at least 1 for every resource",
+                ),
+                (
+                    *max_change_bps <= 10_000,
+                    "the dynamic base fee must change by at most 10,000 basis points per block",
+                ),
+            ]));
+        }
+
+        let alt_fee_assets = fixed_alt_gas_prices
+            .iter()
+            .map(|prices| prices.asset_id)
+            .collect::<BTreeSet<_>>();
+        errors.extend(failures([
+            (
+                !alt_fee_assets.contains(&*STAKING_TOKEN_ASSET_ID),
+                "alternative gas prices must not be set for the staking token",
+            ),
+            (
+                alt_fee_assets.len() == fixed_alt_gas_prices.len(),
+                "alternative gas prices must be set at most once for each token",
+            ),
+        ]));
+
+        errors
+    }
+
+    /// Settings in an update from these parameters to `new` that are valid, but are probably
+    /// mistakes.
+    pub fn update_warnings(&self, new: &AppParameters) -> Vec<String> {
+        let old_prices = &self.fee_params.fixed_gas_prices;
+        let new_prices = &new.fee_params.fixed_gas_prices;
+        let mut warnings = failures(
+            [
+                (
+                    old_prices.block_space_price,
+                    new_prices.block_space_price,
+                    "block space",
+                ),
+                (
+                    old_prices.compact_block_space_price,
+                    new_prices.compact_block_space_price,
+                    "compact block space",
+                ),
+                (
+                    old_prices.verification_price,
+                    new_prices.verification_price,
+                    "verification",
+                ),
+                (
+                    old_prices.execution_price,
+                    new_prices.execution_price,
+                    "execution",
+                ),
+            ]
+            .into_iter()
+            .flat_map(|(old, new, name)| {
+                [
+                    (
+                        old == 0 || new != 0,
+                        format!("the {name} gas price drops to zero, so {name} becomes free"),
+                    ),
+                    (
+                        old == 0 || new <= old.saturating_mul(10),
+                        format!(
+                            "the {name} gas price rises more than tenfold, from {old} to {new}"
+                        ),
+                    ),
+                ]
+            }),
+        );
+        warnings.extend(failures([(
+            new.stake_params.missed_blocks_maximum <= new.stake_params.signed_blocks_window_len,
+            "the missed blocks maximum exceeds the signed blocks window length, so validators are never jailed for downtime",
+        )]));
+        warnings
     }
 
     /// Converts an `AppParameters` instance to a complete `ChangedAppParameters`.
     pub fn as_changed_params(&self) -> ChangedAppParameters {
         ChangedAppParameters {
             community_pool_params: Some(self.community_pool_params.clone()),
+            dex_params: Some(self.dex_params.clone()),
             distributions_params: Some(self.distributions_params.clone()),
             fee_params: Some(self.fee_params.clone()),
             funding_params: Some(self.funding_params.clone()),
@@ -260,6 +408,7 @@ impl AppParameters {
     ) -> Result<AppParameters> {
         if old.is_none()
             && (new.community_pool_params.is_none()
+                || new.dex_params.is_none()
                 || new.distributions_params.is_none()
                 || new.fee_params.is_none()
                 || new.funding_params.is_none()
@@ -285,6 +434,11 @@ impl AppParameters {
                     .community_pool_params
                     .clone()
             }),
+            dex_params: new.dex_params.clone().unwrap_or_else(|| {
+                old.expect("old should be set if new has any None values")
+                    .dex_params
+                    .clone()
+            }),
             distributions_params: new.distributions_params.clone().unwrap_or_else(|| {
                 old.expect("old should be set if new has any None values")
                     .distributions_params
@@ -329,10 +483,18 @@ impl AppParameters {
     }
 }
 
-/// Ensure all of the booleans are true, and if any are false, generate an error describing which
-/// failed, based on the provided descriptions.
-fn check_all<'a>(checks: impl IntoIterator<Item = (bool, impl Display + 'a)>) -> Result<()> {
-    let failed_because = checks
+/// Generate an error listing the provided reasons the parameters are invalid, if there are any.
+fn fail_if_any(failed_because: Vec<String>) -> Result<()> {
+    if !failed_because.is_empty() {
+        anyhow::bail!("invalid chain parameters: {}", failed_because.join(", "));
+    }
+
+    Ok(())
+}
+
+/// Collect the descriptions of all of the checks whose booleans are false.
+fn failures<'a>(checks: impl IntoIterator<Item = (bool, impl Display + 'a)>) -> Vec<String> {
+    checks
         .into_iter()
         .filter_map(|(ok, description)| {
             if !ok {
@@ -341,21 +503,15 @@ fn check_all<'a>(checks: impl IntoIterator<Item = (bool, impl Display + 'a)>) ->
                 None
             }
         })
-        .collect::<Vec<_>>();
-
-    if !failed_because.is_empty() {
-        anyhow::bail!("invalid chain parameters: {}", failed_because.join(", "));
-    }
-
-    Ok(())
+        .collect()
 }
 
-/// Ensure that all of the provided pairs of values are equal, and if any are not, generate an error
-/// stating that the varying names can't be changed.
-fn check_invariant<'a, T: Eq + 'a>(
+/// Collect a failure stating that the name can't be changed for each of the provided pairs of
+/// values that aren't equal.
+fn invariant_failures<'a, T: Eq + 'a>(
     sides: impl IntoIterator<Item = (&'a T, &'a T, impl Display + 'a)>,
-) -> Result<()> {
-    check_all(
+) -> Vec<String> {
+    failures(
         sides
             .into_iter()
             .map(|(old, new, name)| ((*old == *new), format!("{name} can't be changed"))),
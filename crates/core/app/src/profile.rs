@@ -0,0 +1,191 @@
+//! Opt-in profiling of how long each component takes to execute each block.
+//!
+//! Operators can enable this to find out which component is slowing block times. The timings
+//! are recorded as metrics, and optionally written out per block in the collapsed stack format
+//! read by flame graph tools such as `inferno-flamegraph`.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use once_cell::sync::OnceCell;
+use penumbra_dex::component::ExecutionTimings;
+
+use crate::metrics;
+
+static PROFILING: OnceCell<Profiling> = OnceCell::new();
+
+struct Profiling {
+    output_dir: Option<PathBuf>,
+}
+
+/// Enables block profiling for the rest of the process.
+///
+/// The duration of every component's part in each block phase is recorded in the
+/// [`metrics::PD_BLOCK_PHASE_DURATION`] histogram. If an `output_dir` is given, each block's
+/// profile is also written there, to `<height>.folded`.
+pub fn enable(output_dir: Option<PathBuf>) -> anyhow::Result<()> {
+    if let Some(output_dir) = &output_dir {
+        std::fs::create_dir_all(output_dir).with_context(|| {
+            format!(
+                "cannot create block profile directory {}",
+                output_dir.display()
+            )
+        })?;
+    }
+    PROFILING
+        .set(Profiling { output_dir })
+        .map_err(|_| anyhow::anyhow!("block profiling was already enabled"))
+}
+
+/// Times consecutive phases of executing a block.
+pub(crate) struct Stopwatch {
+    last: Instant,
+}
+
+impl Stopwatch {
+    pub fn start() -> Self {
+        Self {
+            last: Instant::now(),
+        }
+    }
+
+    /// Returns the time since the stopwatch was started or last lapped.
+    pub fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now - self.last;
+        self.last = now;
+        elapsed
+    }
+}
+
+/// The timings of executing a single block.
+///
+/// Each timing is recorded under a stack of names, from the block phase down to the part of the
+/// component that took the time, e.g. `end_block;dex;arbitrage`. Timing a block is cheap, so the
+/// timings are always recorded, but only reported if profiling is enabled.
+#[derive(Debug, Default)]
+pub(crate) struct BlockProfile {
+    height: u64,
+    timings: BTreeMap<String, Duration>,
+}
+
+impl BlockProfile {
+    pub fn new(height: u64) -> Self {
+        Self {
+            height,
+            timings: BTreeMap::new(),
+        }
+    }
+
+    /// Records that `duration` was spent in the given stack, adding to any time spent there
+    /// already, as with every transaction delivered in a block.
+    pub fn record(&mut self, stack: &[&str], duration: Duration) {
+        *self.timings.entry(stack.join(";")).or_default() += duration;
+    }
+
+    /// Records the breakdown of the DEX's execution at the end of the block.
+    pub fn record_dex(&mut self, timings: ExecutionTimings) {
+        let batches: Duration = timings.batches.iter().map(|(_, duration)| *duration).sum();
+        self.record(&["end_block", "dex", "batch"], batches);
+        for (trading_pair, duration) in timings.batches {
+            let pair = format!("{}/{}", trading_pair.asset_1(), trading_pair.asset_2());
+            self.record(&["end_block", "dex", "batch", &pair], duration);
+        }
+        self.record(&["end_block", "dex", "arbitrage"], timings.arbitrage);
+        self.record(
+            &["end_block", "dex", "position_closures"],
+            timings.position_closures,
+        );
+    }
+
+    /// Reports the profile, if profiling is enabled.
+    pub fn finish(self) {
+        let Some(profiling) = PROFILING.get() else {
+            return;
+        };
+
+        for (stack, duration) in &self.timings {
+            // Label the histogram by phase and component, leaving out the finer breakdowns,
+            // since they'd make for too many distinct series.
+            let mut names = stack.split(';');
+            let phase = names.next().unwrap_or_default().to_string();
+            let component = match (names.next(), names.next()) {
+                (None, _) => "all".to_string(),
+                (Some(component), None) => component.to_string(),
+                (Some(_), Some(_)) => continue,
+            };
+            metrics::histogram!(
+                metrics::PD_BLOCK_PHASE_DURATION,
+                "phase" => phase,
+                "component" => component
+            )
+            .record(*duration);
+        }
+
+        if let Some(output_dir) = &profiling.output_dir {
+            let path = output_dir.join(format!("{}.folded", self.height));
+            if let Err(error) = std::fs::write(&path, self.folded()) {
+                tracing::warn!(?error, ?path, "failed to write block profile");
+            }
+        }
+    }
+
+    /// Formats the profile in the collapsed stack format, with a line per stack giving the
+    /// microseconds spent in it, excluding the time accounted for by the stacks directly nested
+    /// in it.
+    fn folded(&self) -> String {
+        let mut folded = String::new();
+        for (stack, duration) in &self.timings {
+            let nested: Duration = self
+                .timings
+                .iter()
+                .filter(|(other, _)| {
+                    other
+                        .strip_prefix(stack.as_str())
+                        .and_then(|rest| rest.strip_prefix(';'))
+                        .map_or(false, |rest| !rest.contains(';'))
+                })
+                .map(|(_, duration)| *duration)
+                .sum();
+            let own = duration.saturating_sub(nested);
+            writeln!(folded, "block;{stack} {}", own.as_micros()).expect("can write to string");
+        }
+        folded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folded_excludes_nested_time() {
+        let mut profile = BlockProfile::new(1);
+        profile.record(&["end_block", "dex"], Duration::from_micros(100));
+        profile.record(
+            &["end_block", "dex", "arbitrage"],
+            Duration::from_micros(30),
+        );
+        profile.record(&["end_block", "dex", "batch"], Duration::from_micros(50));
+        profile.record(
+            &["end_block", "dex", "batch", "a/b"],
+            Duration::from_micros(50),
+        );
+        profile.record(&["deliver_tx"], Duration::from_micros(10));
+        profile.record(&["deliver_tx"], Duration::from_micros(15));
+
+        assert_eq!(
+            profile.folded(),
+            "block;deliver_tx 25\n\
+             block;end_block;dex 20\n\
+             block;end_block;dex;arbitrage 30\n\
+             block;end_block;dex;batch 0\n\
+             block;end_block;dex;batch;a/b 50\n"
+        );
+    }
+}
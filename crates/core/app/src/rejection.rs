@@ -0,0 +1,244 @@
+//! Recording why the mempool rejected transactions, so that clients can find out.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{Context, Result};
+use cnidarium::StateRead;
+use penumbra_asset::Value;
+use penumbra_fee::{component::StateReadExt as _, Fee};
+use penumbra_proto::{core::app::v1 as pb, DomainType};
+use penumbra_sct::{
+    component::{
+        clock::EpochRead,
+        tree::{SctRead, VerificationExt},
+    },
+    Nullifier,
+};
+use penumbra_tct as tct;
+use penumbra_transaction::{gas::GasCost, Transaction};
+use penumbra_txhash::TransactionId;
+use serde::{Deserialize, Serialize};
+
+/// How many rejections the [`RejectionLog`] keeps before forgetting the oldest.
+const REJECTION_LOG_CAPACITY: usize = 1024;
+
+/// How many recent anchors to report for a transaction with an invalid anchor.
+const RECENT_ANCHORS: u64 = 8;
+
+/// A record of the mempool rejecting a transaction.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(
+    try_from = "pb::TransactionRejection",
+    into = "pb::TransactionRejection"
+)]
+pub struct TransactionRejection {
+    /// The height of the latest block when the transaction was rejected.
+    pub height: u64,
+    /// The error the transaction was rejected with.
+    pub error: String,
+    /// The reasons for the rejection that could be determined from the transaction and the state.
+    pub reasons: Vec<RejectionReason>,
+}
+
+/// A structured reason a transaction was rejected.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::RejectionReason", into = "pb::RejectionReason")]
+pub enum RejectionReason {
+    /// The transaction spends a note that was already spent at `spend_height`.
+    SpentNullifier {
+        nullifier: Nullifier,
+        spend_height: u64,
+    },
+    /// The transaction claims an anchor that isn't a known root of the state commitment tree.
+    InvalidAnchor {
+        anchor: tct::Root,
+        /// The anchors of the most recent blocks, newest first.
+        recent_anchors: Vec<tct::Root>,
+    },
+    /// The transaction pays less than the minimum fee at current gas prices.
+    InsufficientFee { fee: Fee, minimum_fee: Fee },
+}
+
+impl TransactionRejection {
+    /// Works out why `transaction` was rejected with `error`, by checking it against `state`.
+    ///
+    /// This repeats the stateful checks whose failures clients can act on, so a rejection for
+    /// some other reason has no structured reasons, only the error.
+    pub async fn diagnose<S: StateRead>(
+        state: S,
+        transaction: &Transaction,
+        error: &anyhow::Error,
+    ) -> Result<Self> {
+        let height = state.get_block_height().await?;
+        let mut reasons = Vec::new();
+
+        for nullifier in transaction.spent_nullifiers() {
+            if let Some(info) = state.spend_info(nullifier).await? {
+                reasons.push(RejectionReason::SpentNullifier {
+                    nullifier,
+                    spend_height: info.spend_height,
+                });
+            }
+        }
+
+        let anchor = transaction.anchor;
+        if state.check_claimed_anchor(anchor).await.is_err() {
+            let mut recent_anchors = Vec::new();
+            for height in (height.saturating_sub(RECENT_ANCHORS)..=height).rev() {
+                if let Some(anchor) = state.get_anchor_by_height(height).await? {
+                    recent_anchors.push(anchor);
+                }
+            }
+            reasons.push(RejectionReason::InvalidAnchor {
+                anchor,
+                recent_anchors,
+            });
+        }
+
+        // The minimum fee is in the token the fee was paid in, at that token's gas prices.
+        let fee = transaction.transaction_body().transaction_parameters.fee;
+        if let Some(gas_prices) = state.get_gas_prices_for(fee.asset_id()).await? {
+            let minimum_fee = Fee(Value {
+                amount: gas_prices.fee(&transaction.gas_cost()),
+                asset_id: fee.asset_id(),
+            });
+            if fee.amount() < minimum_fee.amount() {
+                reasons.push(RejectionReason::InsufficientFee { fee, minimum_fee });
+            }
+        }
+
+        Ok(Self {
+            height,
+            // Use the alternate format specifier to include the chain of error causes.
+            error: format!("{error:#}"),
+            reasons,
+        })
+    }
+}
+
+/// A bounded, in-memory log of the transactions the mempool rejected most recently.
+///
+/// The log is shared between the mempool, which records rejections, and the app's query
+/// service, which serves them. It isn't persisted, so it's empty after a restart.
+#[derive(Clone, Default)]
+pub struct RejectionLog {
+    rejections: Arc<Mutex<VecDeque<(TransactionId, TransactionRejection)>>>,
+}
+
+impl RejectionLog {
+    pub fn record(&self, id: TransactionId, rejection: TransactionRejection) {
+        let mut rejections = self.rejections.lock().expect("lock is not poisoned");
+        // A transaction may be rejected again after a recheck, so keep only its latest rejection.
+        rejections.retain(|(rejected_id, _)| rejected_id != &id);
+        if rejections.len() >= REJECTION_LOG_CAPACITY {
+            rejections.pop_front();
+        }
+        rejections.push_back((id, rejection));
+    }
+
+    pub fn get(&self, id: &TransactionId) -> Option<TransactionRejection> {
+        self.rejections
+            .lock()
+            .expect("lock is not poisoned")
+            .iter()
+            .find(|(rejected_id, _)| rejected_id == id)
+            .map(|(_, rejection)| rejection.clone())
+    }
+}
+
+impl DomainType for TransactionRejection {
+    type Proto = pb::TransactionRejection;
+}
+
+impl From<TransactionRejection> for pb::TransactionRejection {
+    fn from(rejection: TransactionRejection) -> Self {
+        Self {
+            height: rejection.height,
+            error: rejection.error,
+            reasons: rejection.reasons.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::TransactionRejection> for TransactionRejection {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::TransactionRejection) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: proto.height,
+            error: proto.error,
+            reasons: proto
+                .reasons
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl DomainType for RejectionReason {
+    type Proto = pb::RejectionReason;
+}
+
+impl From<RejectionReason> for pb::RejectionReason {
+    fn from(reason: RejectionReason) -> Self {
+        use pb::rejection_reason::Reason;
+        let reason = match reason {
+            RejectionReason::SpentNullifier {
+                nullifier,
+                spend_height,
+            } => Reason::SpentNullifier(pb::SpentNullifier {
+                nullifier: Some(nullifier.into()),
+                spend_height,
+            }),
+            RejectionReason::InvalidAnchor {
+                anchor,
+                recent_anchors,
+            } => Reason::InvalidAnchor(pb::InvalidAnchor {
+                anchor: Some(anchor.into()),
+                recent_anchors: recent_anchors.into_iter().map(Into::into).collect(),
+            }),
+            RejectionReason::InsufficientFee { fee, minimum_fee } => {
+                Reason::InsufficientFee(pb::InsufficientFee {
+                    fee: Some(fee.into()),
+                    minimum_fee: Some(minimum_fee.into()),
+                })
+            }
+        };
+        Self {
+            reason: Some(reason),
+        }
+    }
+}
+
+impl TryFrom<pb::RejectionReason> for RejectionReason {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::RejectionReason) -> Result<Self, Self::Error> {
+        use pb::rejection_reason::Reason;
+        Ok(match proto.reason.context("missing rejection reason")? {
+            Reason::SpentNullifier(spent) => RejectionReason::SpentNullifier {
+                nullifier: spent.nullifier.context("missing nullifier")?.try_into()?,
+                spend_height: spent.spend_height,
+            },
+            Reason::InvalidAnchor(invalid) => RejectionReason::InvalidAnchor {
+                anchor: invalid.anchor.context("missing anchor")?.try_into()?,
+                recent_anchors: invalid
+                    .recent_anchors
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+            },
+            Reason::InsufficientFee(insufficient) => RejectionReason::InsufficientFee {
+                fee: insufficient.fee.context("missing fee")?.try_into()?,
+                minimum_fee: insufficient
+                    .minimum_fee
+                    .context("missing minimum fee")?
+                    .try_into()?,
+            },
+        })
+    }
+}
@@ -1,21 +1,32 @@
 use cnidarium::Storage;
+use penumbra_governance::proposal::ChangedAppParameters;
 use penumbra_proto::core::app::v1::{
     query_service_server::QueryService, AppParametersRequest, AppParametersResponse,
+    EpochSummaryRequest, EpochSummaryResponse, SimulateParameterChangeRequest,
+    SimulateParameterChangeResponse, TransactionRejectionRequest, TransactionRejectionResponse,
     TransactionsByHeightRequest, TransactionsByHeightResponse,
 };
+use penumbra_txhash::TransactionId;
 use tonic::Status;
 use tracing::instrument;
 
-use crate::app::StateReadExt as _;
+use crate::{
+    app::StateReadExt as _, epoch_summary::EpochSummaryRead as _, params::AppParameters,
+    rejection::RejectionLog,
+};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
     storage: Storage,
+    rejections: RejectionLog,
 }
 
 impl Server {
-    pub fn new(storage: Storage) -> Self {
-        Self { storage }
+    pub fn new(storage: Storage, rejections: RejectionLog) -> Self {
+        Self {
+            storage,
+            rejections,
+        }
     }
 }
 
@@ -38,6 +49,83 @@ impl QueryService for Server {
         Ok(tonic::Response::new(tx_response))
     }
 
+    #[instrument(skip(self, request))]
+    async fn transaction_rejection(
+        &self,
+        request: tonic::Request<TransactionRejectionRequest>,
+    ) -> Result<tonic::Response<TransactionRejectionResponse>, Status> {
+        let id: TransactionId = request
+            .into_inner()
+            .tx_id
+            .ok_or_else(|| tonic::Status::invalid_argument("missing transaction id"))?
+            .try_into()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid transaction id: {e}")))?;
+
+        Ok(tonic::Response::new(TransactionRejectionResponse {
+            rejection: self.rejections.get(&id).map(Into::into),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn epoch_summary(
+        &self,
+        request: tonic::Request<EpochSummaryRequest>,
+    ) -> Result<tonic::Response<EpochSummaryResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let epoch_index = request.into_inner().epoch_index;
+
+        let summary = state
+            .epoch_summary(epoch_index)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error getting epoch summary: {e}")))?;
+
+        Ok(tonic::Response::new(EpochSummaryResponse {
+            summary: summary.map(Into::into),
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn simulate_parameter_change(
+        &self,
+        request: tonic::Request<SimulateParameterChangeRequest>,
+    ) -> Result<tonic::Response<SimulateParameterChangeResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let current = state.get_app_params().await.map_err(|e| {
+            tonic::Status::unavailable(format!("error getting app parameters: {e}"))
+        })?;
+
+        let new: ChangedAppParameters = request
+            .new
+            .ok_or_else(|| tonic::Status::invalid_argument("missing new parameters"))?
+            .try_into()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid new parameters: {e}")))?;
+        let app_parameters = AppParameters::from_changed_params(&new, Some(&current))
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid new parameters: {e}")))?;
+
+        let mut errors = current.update_errors(&app_parameters);
+        if let Some(old) = request.old {
+            let old: ChangedAppParameters = old.try_into().map_err(|e| {
+                tonic::Status::invalid_argument(format!("invalid old parameters: {e}"))
+            })?;
+            // The proposal is only enacted if its old parameters match the current ones exactly.
+            if old != current.as_changed_params() {
+                errors.push(
+                    "the old parameters don't match the current app parameters, so the change wouldn't be enacted"
+                        .to_string(),
+                );
+            }
+        }
+        let warnings = current.update_warnings(&app_parameters);
+
+        Ok(tonic::Response::new(SimulateParameterChangeResponse {
+            app_parameters: Some(app_parameters.into()),
+            errors,
+            warnings,
+        }))
+    }
+
     #[instrument(skip(self, _request))]
     async fn app_parameters(
         &self,
@@ -0,0 +1,212 @@
+//! Node-local screening of the transparent data in transactions, before they enter the mempool.
+//!
+//! Operators may be required to refuse relaying transactions that send funds to certain
+//! destinations. Penumbra transactions are shielded, but a few of their parts are necessarily
+//! transparent, like the destination address of an IBC withdrawal. A [`ScreeningHook`] is shown
+//! that transparent data during `CheckTx`, and can refuse to admit the transaction into the
+//! local mempool.
+//!
+//! Screening is disabled by default. It can never affect consensus: hooks are only consulted by
+//! the mempool, and only see the transparent data extracted from the transaction, never the chain
+//! state. A transaction refused by one node's hook can still be included in a block proposed by
+//! another node, and is then executed like any other transaction.
+
+use std::{collections::BTreeSet, path::Path, sync::Arc};
+
+use anyhow::{Context, Result};
+use penumbra_transaction::{Action, Transaction};
+
+/// The transparent data of a transaction that's shown to a [`ScreeningHook`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TransparentData {
+    /// The IBC withdrawals made by the transaction.
+    pub ibc_withdrawals: Vec<IbcWithdrawalDestination>,
+}
+
+/// Where an IBC withdrawal sends its funds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IbcWithdrawalDestination {
+    /// The address on the counterparty chain the funds are sent to.
+    pub address: String,
+    /// The channel the funds are sent over.
+    pub source_channel: String,
+    /// The denomination of the funds.
+    pub denom: String,
+}
+
+impl TransparentData {
+    /// Extracts the transparent data of `transaction`.
+    pub fn from_transaction(transaction: &Transaction) -> Self {
+        let ibc_withdrawals = transaction
+            .actions()
+            .filter_map(|action| match action {
+                Action::Ics20Withdrawal(withdrawal) => Some(IbcWithdrawalDestination {
+                    address: withdrawal.destination_chain_address.clone(),
+                    source_channel: withdrawal.source_channel.to_string(),
+                    denom: withdrawal.denom.to_string(),
+                }),
+                _ => None,
+            })
+            .collect();
+        Self { ibc_withdrawals }
+    }
+
+    /// Whether the transaction has no transparent data to screen.
+    pub fn is_empty(&self) -> bool {
+        self.ibc_withdrawals.is_empty()
+    }
+}
+
+/// The outcome of screening a transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScreeningDecision {
+    /// Admit the transaction into the local mempool.
+    Accept,
+    /// Refuse to admit the transaction into the local mempool, for the given reason.
+    Reject(String),
+}
+
+/// A hook screening the transparent data of transactions before they enter the local mempool.
+///
+/// Hooks are called synchronously from the mempool worker, so they should return promptly.
+pub trait ScreeningHook: Send + Sync + 'static {
+    fn screen(&self, data: &TransparentData) -> ScreeningDecision;
+}
+
+/// The screening performed by the mempool, if any.
+///
+/// The default performs no screening.
+#[derive(Clone, Default)]
+pub struct Screening {
+    hook: Option<Arc<dyn ScreeningHook>>,
+}
+
+impl std::fmt::Debug for Screening {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Screening")
+            .field("enabled", &self.hook.is_some())
+            .finish()
+    }
+}
+
+impl Screening {
+    /// Screens transactions with `hook`.
+    pub fn new(hook: impl ScreeningHook) -> Self {
+        Self {
+            hook: Some(Arc::new(hook)),
+        }
+    }
+
+    /// Whether a hook is installed.
+    pub fn is_enabled(&self) -> bool {
+        self.hook.is_some()
+    }
+
+    /// Screens `transaction`, returning an error if the hook rejects it.
+    ///
+    /// Transactions without any transparent data are never shown to the hook.
+    pub fn check(&self, transaction: &Transaction) -> Result<()> {
+        let Some(hook) = &self.hook else {
+            return Ok(());
+        };
+        let data = TransparentData::from_transaction(transaction);
+        if data.is_empty() {
+            return Ok(());
+        }
+        match hook.screen(&data) {
+            ScreeningDecision::Accept => Ok(()),
+            ScreeningDecision::Reject(reason) => {
+                anyhow::bail!("transaction refused by local screening: {reason}")
+            }
+        }
+    }
+}
+
+/// A [`ScreeningHook`] rejecting IBC withdrawals to any address on a deny list.
+#[derive(Clone, Debug, Default)]
+pub struct DenyList {
+    addresses: BTreeSet<String>,
+}
+
+impl DenyList {
+    pub fn new(addresses: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            addresses: addresses.into_iter().collect(),
+        }
+    }
+
+    /// Loads the deny list from a file with one address per line.
+    ///
+    /// Blank lines, and lines starting with `#`, are ignored.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("cannot read deny list from {}", path.display()))?;
+        Ok(Self::new(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_string),
+        ))
+    }
+
+    pub fn len(&self) -> usize {
+        self.addresses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+}
+
+impl ScreeningHook for DenyList {
+    fn screen(&self, data: &TransparentData) -> ScreeningDecision {
+        match data
+            .ibc_withdrawals
+            .iter()
+            .find(|withdrawal| self.addresses.contains(&withdrawal.address))
+        {
+            Some(withdrawal) => ScreeningDecision::Reject(format!(
+                "IBC withdrawal destination {} is on the deny list",
+                withdrawal.address
+            )),
+            None => ScreeningDecision::Accept,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn withdrawal_to(address: &str) -> TransparentData {
+        TransparentData {
+            ibc_withdrawals: vec![IbcWithdrawalDestination {
+                address: address.to_string(),
+                source_channel: "channel-0".to_string(),
+                denom: "upenumbra".to_string(),
+            }],
+        }
+    }
+
+    #[test]
+    fn deny_list_rejects_listed_destinations() {
+        let deny_list = DenyList::new(["osmo1denied".to_string()]);
+        assert_eq!(
+            deny_list.screen(&withdrawal_to("osmo1allowed")),
+            ScreeningDecision::Accept
+        );
+        assert!(matches!(
+            deny_list.screen(&withdrawal_to("osmo1denied")),
+            ScreeningDecision::Reject(_)
+        ));
+    }
+
+    #[test]
+    fn disabled_screening_accepts_everything() {
+        let screening = Screening::default();
+        assert!(!screening.is_enabled());
+        assert!(screening.check(&Transaction::default()).is_ok());
+    }
+}
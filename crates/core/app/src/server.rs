@@ -5,6 +5,7 @@ use {
         consensus::Consensus, events::EventIndexLayer, info::Info, mempool::Mempool,
         snapshot::Snapshot,
     },
+    crate::{rejection::RejectionLog, screening::Screening},
     cnidarium::Storage,
     penumbra_tower_trace::trace::request_span,
     tendermint::v0_37::abci::{
@@ -21,8 +22,13 @@ pub mod snapshot;
 mod events;
 
 /// Returns a newly instantiated ABCI [`Server`], backed by the provided [`Storage`].
+///
+/// Transactions the mempool rejects are recorded in `rejections`. Before admitting transactions,
+/// the mempool screens them with `screening`, which has no effect on consensus.
 pub fn new(
     storage: Storage,
+    rejections: RejectionLog,
+    screening: Screening,
 ) -> Server<
     // These bounds ensure that the server can be bound to a TCP port, or a Unix socket.
     impl tower_service::Service<
@@ -58,7 +64,14 @@ pub fn new(
         }))
         .service(tower_actor::Actor::new(10, |queue: _| {
             let storage = storage.clone();
-            async move { Mempool::new(storage.clone(), queue).await?.run().await }
+            let rejections = rejections.clone();
+            let screening = screening.clone();
+            async move {
+                Mempool::new(storage.clone(), queue, rejections, screening)
+                    .await?
+                    .run()
+                    .await
+            }
         }));
     let info = Info::new(storage.clone());
     let snapshot = Snapshot {};
@@ -81,7 +94,7 @@ mod bounds_test {
     async fn servers_can_listen() {
         let storage: cnidarium::Storage = todo!();
         let addr: std::net::SocketAddr = todo!();
-        let server = super::new(storage).listen_tcp(addr);
+        let server = super::new(storage, Default::default(), Default::default()).listen_tcp(addr);
         drop(server);
     }
 }
@@ -1,6 +1,8 @@
 use anyhow::Result;
 
 use cnidarium::{Snapshot, Storage};
+use penumbra_proto::DomainType;
+use penumbra_transaction::Transaction;
 
 use tendermint::v0_37::abci::{
     request::CheckTx as CheckTxReq, request::CheckTxKind, response::CheckTx as CheckTxRsp,
@@ -10,7 +12,12 @@ use tokio::sync::{mpsc, watch};
 use tower_actor::Message;
 use tracing::Instrument;
 
-use crate::{app::App, metrics};
+use crate::{
+    app::App,
+    metrics,
+    rejection::{RejectionLog, TransactionRejection},
+    screening::Screening,
+};
 
 /// When using ABCI, we can't control block proposal directly, so we could
 /// potentially end up creating blocks with mutually incompatible transactions.
@@ -28,12 +35,16 @@ pub struct Mempool {
     queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
     app: App,
     rx_snapshot: watch::Receiver<Snapshot>,
+    rejections: RejectionLog,
+    screening: Screening,
 }
 
 impl Mempool {
     pub async fn new(
         storage: Storage,
         queue: mpsc::Receiver<Message<Request, Response, tower::BoxError>>,
+        rejections: RejectionLog,
+        screening: Screening,
     ) -> Result<Self> {
         let app = App::new(storage.latest_snapshot()).await?;
         let snapshot_rx = storage.subscribe();
@@ -42,6 +53,8 @@ impl Mempool {
             queue,
             app,
             rx_snapshot: snapshot_rx,
+            rejections,
+            screening,
         })
     }
 
@@ -56,7 +69,7 @@ impl Mempool {
             CheckTxKind::Recheck => "recheck",
         };
 
-        match self.app.deliver_tx_bytes(tx_bytes.as_ref()).await {
+        match self.screen_and_deliver(tx_bytes.as_ref()).await {
             Ok(events) => {
                 let elapsed = start.elapsed();
                 tracing::info!(?elapsed, "tx accepted");
@@ -70,6 +83,7 @@ impl Mempool {
                 let elapsed = start.elapsed();
                 tracing::info!(?e, ?elapsed, "tx rejected");
                 metrics::counter!(metrics::MEMPOOL_CHECKTX_TOTAL, "kind" => kind_str, "code" => "1").increment(1);
+                self.record_rejection(tx_bytes.as_ref(), &e).await;
                 Ok(Response::CheckTx(CheckTxRsp {
                     code: 1.into(),
                     // Use the alternate format specifier to include the chain of error causes.
@@ -80,6 +94,36 @@ impl Mempool {
         }
     }
 
+    /// Screens the transaction, if screening is enabled, then executes it against the ephemeral
+    /// mempool state.
+    ///
+    /// Screening happens first, so that refused transactions don't conflict with later ones.
+    async fn screen_and_deliver(
+        &mut self,
+        tx_bytes: &[u8],
+    ) -> Result<Vec<tendermint::abci::Event>> {
+        if self.screening.is_enabled() {
+            // Transactions that can't be decoded are rejected when they're executed.
+            if let Ok(transaction) = Transaction::decode(tx_bytes) {
+                self.screening.check(&transaction)?;
+            }
+        }
+        self.app.deliver_tx_bytes(tx_bytes).await
+    }
+
+    /// Records why a transaction was rejected, so that its sender can find out later.
+    async fn record_rejection(&self, tx_bytes: &[u8], error: &anyhow::Error) {
+        // Transactions that can't be decoded have no hash to look them up by.
+        let Ok(transaction) = Transaction::decode(tx_bytes) else {
+            return;
+        };
+        let snapshot = self.rx_snapshot.borrow().clone();
+        match TransactionRejection::diagnose(snapshot, &transaction, error).await {
+            Ok(rejection) => self.rejections.record(transaction.id(), rejection),
+            Err(e) => tracing::warn!(?e, "could not diagnose rejected transaction"),
+        }
+    }
+
     pub async fn run(mut self) -> Result<(), tower::BoxError> {
         loop {
             tokio::select! {
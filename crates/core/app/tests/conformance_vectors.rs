@@ -0,0 +1,406 @@
+//! Exports conformance test vectors for the core transaction flows.
+//!
+//! Each test drives a flow through the consensus service using mock consensus, and records the
+//! genesis state, the transactions in each block, the events they emitted, and the app hash after
+//! each block. Alternative implementations of the state machine can replay these vectors to check
+//! that they agree with this one.
+//!
+//! The tests are ignored by default, since they exist to produce the vectors rather than to check
+//! anything beyond the flows succeeding. To write the vectors out, run:
+//!
+//! ```sh
+//! PENUMBRA_TEST_VECTORS_DIR=vectors cargo test -p penumbra-app --test conformance_vectors -- --ignored
+//! ```
+//!
+//! Note that the mock consensus engine stamps blocks with the current time, and that proofs and
+//! signatures are randomized, so the vectors differ from run to run. Each vector is consistent on
+//! its own: replaying its transactions on top of its genesis state, at the recorded block times,
+//! must produce the recorded events and app hashes.
+
+mod common;
+
+use {
+    self::common::{BuilderExt, PenumbraTestNode},
+    anyhow::{anyhow, Context},
+    ark_ff::UniformRand,
+    cnidarium::TempStorage,
+    decaf377::Fq,
+    penumbra_app::server::consensus::Consensus,
+    penumbra_asset::{asset, Value},
+    penumbra_dex::{
+        component::StateReadExt as _,
+        swap::{SwapPlaintext, SwapPlan},
+        swap_claim::SwapClaimPlan,
+        TradingPair,
+    },
+    penumbra_fee::Fee,
+    penumbra_genesis::AppState,
+    penumbra_keys::{
+        keys::{SpendKey, SpendKeyBytes},
+        test_keys,
+    },
+    penumbra_mock_client::MockClient,
+    penumbra_mock_consensus::{block::Executed, TestNode},
+    penumbra_num::Amount,
+    penumbra_proto::DomainType,
+    penumbra_sct::component::StateReadExt as _,
+    penumbra_shielded_pool::{Note, OutputPlan, SpendPlan},
+    penumbra_stake::{
+        component::validator_handler::ValidatorDataRead as _, validator::Validator,
+        DelegationToken, FundingStreams, GovernanceKey, IdentityKey,
+    },
+    penumbra_transaction::{
+        memo::MemoPlaintext,
+        plan::{ActionPlan, MemoPlan},
+        Transaction, TransactionParameters, TransactionPlan,
+    },
+    rand_chacha::ChaChaRng,
+    rand_core::SeedableRng,
+    serde::Serialize,
+};
+
+/// The environment variable naming the directory to write the vectors into.
+const VECTORS_DIR_VAR: &str = "PENUMBRA_TEST_VECTORS_DIR";
+
+/// A conformance test vector: a chain started from `genesis`, and the blocks executed on it.
+#[derive(Serialize)]
+struct TestVector {
+    name: String,
+    genesis: AppState,
+    /// The app hash after applying the genesis state, hex-encoded.
+    genesis_app_hash: String,
+    blocks: Vec<BlockVector>,
+}
+
+#[derive(Serialize)]
+struct BlockVector {
+    height: u64,
+    /// The block time, in RFC 3339 format.
+    time: String,
+    /// The transactions in the block, hex-encoded in their protobuf encoding.
+    transactions: Vec<String>,
+    /// The events emitted while executing the transactions, then while ending the block.
+    events: Vec<EventVector>,
+    /// The app hash after committing the block, hex-encoded.
+    app_hash: String,
+}
+
+#[derive(Serialize)]
+struct EventVector {
+    kind: String,
+    attributes: Vec<(String, String)>,
+}
+
+/// Records the blocks of a flow, as they're executed.
+struct Recorder {
+    vector: TestVector,
+}
+
+impl Recorder {
+    fn new(name: &str, genesis: AppState, test_node: &PenumbraTestNode) -> Self {
+        Self {
+            vector: TestVector {
+                name: name.to_string(),
+                genesis,
+                genesis_app_hash: hex::encode(test_node.last_app_hash()),
+                blocks: Vec::new(),
+            },
+        }
+    }
+
+    /// Executes a block holding `transactions`, checking that each of them was accepted.
+    async fn execute(
+        &mut self,
+        test_node: &mut PenumbraTestNode,
+        transactions: Vec<Transaction>,
+    ) -> anyhow::Result<u64> {
+        let data = transactions.iter().map(DomainType::encode_to_vec).collect();
+        let Executed {
+            header,
+            deliver_tx,
+            end_block,
+            commit,
+            ..
+        } = test_node
+            .block()
+            .with_data(data)
+            .execute_and_collect()
+            .await?;
+
+        let mut events = Vec::new();
+        for response in &deliver_tx {
+            anyhow::ensure!(
+                response.code.is_ok(),
+                "transaction was rejected: {}",
+                response.log
+            );
+            events.extend(&response.events);
+        }
+        events.extend(&end_block.events);
+
+        let height = header.height.value();
+        self.vector.blocks.push(BlockVector {
+            height,
+            time: header.time.to_rfc3339(),
+            transactions: transactions
+                .iter()
+                .map(|tx| hex::encode(tx.encode_to_vec()))
+                .collect(),
+            events: events
+                .into_iter()
+                .map(|event| EventVector {
+                    kind: event.kind.clone(),
+                    attributes: event
+                        .attributes
+                        .iter()
+                        .map(|attr| (attr.key.clone(), attr.value.clone()))
+                        .collect(),
+                })
+                .collect(),
+            app_hash: hex::encode(&commit.data),
+        });
+        Ok(height)
+    }
+
+    /// Writes the vector into the directory named by [`VECTORS_DIR_VAR`], if it's set.
+    fn finish(self) -> anyhow::Result<()> {
+        let Some(dir) = std::env::var_os(VECTORS_DIR_VAR) else {
+            tracing::info!(name = %self.vector.name, "{VECTORS_DIR_VAR} is unset, not writing vector");
+            return Ok(());
+        };
+        let dir = std::path::PathBuf::from(dir);
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join(format!("{}.json", self.vector.name));
+        std::fs::write(&path, serde_json::to_vec_pretty(&self.vector)?)
+            .with_context(|| format!("cannot write vector to {}", path.display()))?;
+        tracing::info!(path = %path.display(), "wrote test vector");
+        Ok(())
+    }
+}
+
+/// Starts a test node from `genesis`, returning it along with a recorder for its blocks.
+async fn start(
+    name: &str,
+    storage: &TempStorage,
+    genesis: AppState,
+) -> anyhow::Result<(PenumbraTestNode, Recorder)> {
+    let test_node = TestNode::builder()
+        .single_validator()
+        .with_penumbra_auto_app_state(genesis.clone())?
+        .init_chain(Consensus::new(storage.as_ref().clone()))
+        .await?;
+    let recorder = Recorder::new(name, genesis, &test_node);
+    Ok((test_node, recorder))
+}
+
+/// Returns a plan for a transaction holding `actions`, with a memo if it has any outputs.
+fn plan_transaction(
+    rng: &mut ChaChaRng,
+    actions: Vec<ActionPlan>,
+) -> anyhow::Result<TransactionPlan> {
+    let has_outputs = actions
+        .iter()
+        .any(|action| matches!(action, ActionPlan::Output(_)));
+    let mut plan = TransactionPlan {
+        actions,
+        memo: has_outputs
+            .then(|| MemoPlan::new(rng, MemoPlaintext::blank_memo(*test_keys::ADDRESS_0)))
+            .transpose()?,
+        detection_data: None,
+        transaction_parameters: TransactionParameters {
+            chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+            ..Default::default()
+        },
+    };
+    plan.populate_detection_data(&mut *rng, 0);
+    Ok(plan)
+}
+
+/// Returns a note of `asset_id` known to the client.
+fn note_of(client: &MockClient, asset_id: asset::Id) -> anyhow::Result<Note> {
+    client
+        .notes
+        .values()
+        .find(|note| note.asset_id() == asset_id)
+        .cloned()
+        .ok_or_else(|| anyhow!("mock client has no note of {asset_id}"))
+}
+
+fn spend(rng: &mut ChaChaRng, client: &MockClient, note: Note) -> anyhow::Result<ActionPlan> {
+    let position = client
+        .position(note.commit())
+        .ok_or_else(|| anyhow!("input note commitment was unknown to mock client"))?;
+    Ok(SpendPlan::new(rng, note, position).into())
+}
+
+#[tokio::test]
+#[ignore]
+async fn spend_and_output() -> anyhow::Result<()> {
+    let guard = common::set_tracing_subscriber();
+    let mut rng = ChaChaRng::seed_from_u64(1);
+    let storage = TempStorage::new().await?;
+    let (mut test_node, mut recorder) =
+        start("spend_and_output", &storage, AppState::default()).await?;
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?;
+
+    // Send one of the test wallet's notes to its other account.
+    let note = note_of(&client, *penumbra_asset::STAKING_TOKEN_ASSET_ID)?;
+    let actions = vec![
+        spend(&mut rng, &client, note.clone())?,
+        OutputPlan::new(&mut rng, note.value(), *test_keys::ADDRESS_1).into(),
+    ];
+    let plan = plan_transaction(&mut rng, actions)?;
+    let tx = client.witness_auth_build(&plan).await?;
+    recorder.execute(&mut test_node, vec![tx]).await?;
+
+    recorder.finish()?;
+    drop(storage);
+    drop(guard);
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn swap_and_swap_claim() -> anyhow::Result<()> {
+    let guard = common::set_tracing_subscriber();
+    let mut rng = ChaChaRng::seed_from_u64(2);
+    let storage = TempStorage::new().await?;
+    let (mut test_node, mut recorder) =
+        start("swap_and_swap_claim", &storage, AppState::default()).await?;
+    let mut client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?;
+
+    // Swap one of the test wallet's gm notes into gn.
+    let cache = asset::Cache::with_known_assets();
+    let gm = cache.get_unit("gm").context("gm is a known asset")?.id();
+    let gn = cache.get_unit("gn").context("gn is a known asset")?.id();
+    let trading_pair = TradingPair::new(gm, gn);
+    let note = note_of(&client, gm)?;
+    let (delta_1, delta_2) = if trading_pair.asset_1() == gm {
+        (note.amount(), Amount::zero())
+    } else {
+        (Amount::zero(), note.amount())
+    };
+    let swap_plaintext = SwapPlaintext::new(
+        &mut rng,
+        trading_pair,
+        delta_1,
+        delta_2,
+        Fee::default(),
+        *test_keys::ADDRESS_0,
+    );
+    let actions = vec![
+        spend(&mut rng, &client, note)?,
+        SwapPlan::new(&mut rng, swap_plaintext.clone()).into(),
+    ];
+    let plan = plan_transaction(&mut rng, actions)?;
+    let tx = client.witness_auth_build(&plan).await?;
+    let swap_height = recorder.execute(&mut test_node, vec![tx]).await?;
+
+    // Claim the outputs of the swap, once its batch has executed.
+    let snapshot = storage.latest_snapshot();
+    client.sync_to_latest(snapshot.clone()).await?;
+    let swap_commitment = swap_plaintext.swap_commitment();
+    let claim = SwapClaimPlan {
+        swap_plaintext,
+        position: client
+            .position(swap_commitment)
+            .ok_or_else(|| anyhow!("swap commitment was unknown to mock client"))?,
+        output_data: snapshot
+            .output_data(swap_height, trading_pair)
+            .await?
+            .ok_or_else(|| anyhow!("missing batch swap output data"))?,
+        epoch_duration: snapshot.get_epoch_duration_parameter().await?,
+        proof_blinding_r: Fq::rand(&mut rng),
+        proof_blinding_s: Fq::rand(&mut rng),
+    };
+    let plan = plan_transaction(&mut rng, vec![claim.into()])?;
+    let tx = client.witness_auth_build(&plan).await?;
+    recorder.execute(&mut test_node, vec![tx]).await?;
+
+    recorder.finish()?;
+    drop(storage);
+    drop(guard);
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore]
+async fn delegate_and_undelegate() -> anyhow::Result<()> {
+    let guard = common::set_tracing_subscriber();
+    let mut rng = ChaChaRng::seed_from_u64(3);
+    let storage = TempStorage::new().await?;
+
+    // Start the chain with a validator to delegate to.
+    let validator_key = SpendKey::from(SpendKeyBytes([3; 32]));
+    let identity_key = IdentityKey(*validator_key.full_viewing_key().spend_verification_key());
+    let consensus_key = ed25519_consensus::SigningKey::new(&mut rng);
+    let validator = Validator {
+        identity_key: identity_key.clone(),
+        governance_key: GovernanceKey(identity_key.0),
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(
+            consensus_key.verification_key().as_bytes(),
+        )
+        .context("consensus key is a valid ed25519 key")?,
+        name: "conformance".to_string(),
+        website: String::new(),
+        description: String::new(),
+        enabled: true,
+        funding_streams: FundingStreams::new(),
+        sequence_number: 0,
+    };
+    let mut genesis = penumbra_genesis::Content::default();
+    genesis.stake_content.validators.push(validator.into());
+    let genesis = AppState::Content(genesis);
+
+    let (mut test_node, mut recorder) = start("delegate_and_undelegate", &storage, genesis).await?;
+    let mut client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?;
+
+    // Delegate one of the test wallet's staking token notes.
+    let rate_data = storage
+        .latest_snapshot()
+        .get_validator_rate(&identity_key)
+        .await?
+        .ok_or_else(|| anyhow!("missing rate data for the genesis validator"))?;
+    let note = note_of(&client, *penumbra_asset::STAKING_TOKEN_ASSET_ID)?;
+    let delegate = rate_data.build_delegate(note.amount());
+    let delegation = Value {
+        amount: delegate.delegation_amount,
+        asset_id: DelegationToken::new(identity_key.clone()).id(),
+    };
+    let actions = vec![
+        spend(&mut rng, &client, note.clone())?,
+        delegate.into(),
+        OutputPlan::new(&mut rng, delegation, *test_keys::ADDRESS_0).into(),
+    ];
+    let plan = plan_transaction(&mut rng, actions)?;
+    let tx = client.witness_auth_build(&plan).await?;
+    recorder.execute(&mut test_node, vec![tx]).await?;
+
+    // Undelegate the delegation tokens, in the same epoch.
+    client.sync_to_latest(storage.latest_snapshot()).await?;
+    let delegation_note = note_of(&client, delegation.asset_id)?;
+    let undelegate = rate_data.build_undelegate(delegation_note.amount());
+    let unbonding = Value {
+        amount: undelegate.unbonded_amount,
+        asset_id: undelegate.unbonding_token().id(),
+    };
+    let actions = vec![
+        spend(&mut rng, &client, delegation_note)?,
+        undelegate.into(),
+        OutputPlan::new(&mut rng, unbonding, *test_keys::ADDRESS_0).into(),
+    ];
+    let plan = plan_transaction(&mut rng, actions)?;
+    let tx = client.witness_auth_build(&plan).await?;
+    recorder.execute(&mut test_node, vec![tx]).await?;
+
+    recorder.finish()?;
+    drop(storage);
+    drop(guard);
+    Ok(())
+}
@@ -0,0 +1,89 @@
+//! Checks that the events of a block are numbered in the order they were emitted, across the
+//! responses to `BeginBlock`, `DeliverTx` and `EndBlock`.
+
+mod common;
+
+use {
+    anyhow::anyhow,
+    cnidarium::TempStorage,
+    penumbra_keys::test_keys,
+    penumbra_mock_client::MockClient,
+    penumbra_mock_consensus::{block::Executed, TestNode},
+    penumbra_proto::{event::event_sequence, DomainType},
+    penumbra_shielded_pool::{OutputPlan, SpendPlan},
+    penumbra_transaction::{
+        memo::MemoPlaintext, plan::MemoPlan, TransactionParameters, TransactionPlan,
+    },
+    rand_core::OsRng,
+};
+
+#[tokio::test]
+async fn events_are_numbered_in_order_across_the_block() -> anyhow::Result<()> {
+    // Install a test logger, acquire some temporary storage, and start the test node.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new().await?;
+    let mut test_node = common::start_test_node(&storage).await?;
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?;
+
+    // Send two of the test wallet's notes to a different account, in separate transactions.
+    let mut txs = Vec::new();
+    for note in client.notes.values().take(2).cloned() {
+        let position = client
+            .position(note.commit())
+            .ok_or_else(|| anyhow!("input note commitment was unknown to mock client"))?;
+        let mut plan = TransactionPlan {
+            actions: vec![
+                SpendPlan::new(&mut OsRng, note.clone(), position).into(),
+                OutputPlan::new(&mut OsRng, note.value(), *test_keys::ADDRESS_1).into(),
+            ],
+            memo: Some(MemoPlan::new(
+                &mut OsRng,
+                MemoPlaintext::blank_memo(*test_keys::ADDRESS_0),
+            )?),
+            detection_data: None,
+            transaction_parameters: TransactionParameters {
+                chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+                ..Default::default()
+            },
+        };
+        plan.populate_detection_data(OsRng, 0);
+        txs.push(client.witness_auth_build(&plan).await?.encode_to_vec());
+    }
+    assert_eq!(txs.len(), 2, "mock client should have two notes");
+
+    let Executed {
+        begin_block,
+        deliver_tx,
+        end_block,
+        ..
+    } = test_node
+        .block()
+        .with_data(txs)
+        .execute_and_collect()
+        .await?;
+
+    // Each transaction emits at least a spend and an output event.
+    assert!(deliver_tx.iter().all(|response| response.events.len() >= 2));
+
+    let events = begin_block
+        .events
+        .iter()
+        .chain(deliver_tx.iter().flat_map(|response| &response.events))
+        .chain(&end_block.events);
+    for (expected, event) in events.enumerate() {
+        assert_eq!(
+            event_sequence(event),
+            Some(expected as u64),
+            "event {} is out of sequence",
+            event.kind
+        );
+    }
+
+    // Free our temporary storage.
+    drop(storage);
+    drop(guard);
+
+    Ok(())
+}
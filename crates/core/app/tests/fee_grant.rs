@@ -0,0 +1,263 @@
+mod common;
+
+use self::common::TempStorageExt;
+use cnidarium::{ArcStateDeltaExt, StateDelta, TempStorage};
+use cnidarium_component::ActionHandler as _;
+use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_fee::{
+    component::{StateReadExt as _, StateWriteExt as _},
+    grant::{
+        grantee_key, sponsor_key, FeeGrant, FeeGrantId, FeeGrantReclaim, FeeGrantReclaimPlan,
+        FeeGrantUse, FeeGrantUsePlan,
+    },
+    Fee,
+};
+use penumbra_keys::test_keys;
+use penumbra_sct::component::clock::EpochManager;
+use penumbra_txhash::{EffectHash, TransactionContext};
+use rand_core::SeedableRng;
+use std::sync::Arc;
+
+fn fee(amount: u64) -> Fee {
+    Fee(Value {
+        amount: amount.into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    })
+}
+
+/// A grant of `allowance` to the grantee key of the test wallet's account 0, sponsored by
+/// the same account.
+fn grant(allowance: u64, expiry_height: u64) -> FeeGrant {
+    FeeGrant {
+        grantee: grantee_key(&test_keys::FULL_VIEWING_KEY, 0),
+        allowance: fee(allowance),
+        expiry_height,
+        nonce: [0u8; 32],
+        sponsor: sponsor_key(&test_keys::FULL_VIEWING_KEY, 0),
+    }
+}
+
+/// A use of `grant_id` by the test wallet's `account`, signed over a dummy effect hash.
+fn grant_use(account: u32, grant_id: FeeGrantId, amount: u64) -> (FeeGrantUse, TransactionContext) {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+    let plan = FeeGrantUsePlan::new(&test_keys::FULL_VIEWING_KEY, account, grant_id, fee(amount));
+    let dummy_effect_hash = [0u8; 64];
+    let auth_sig = test_keys::SPEND_KEY
+        .spend_auth_key()
+        .randomize(&plan.randomizer)
+        .sign(&mut rng, dummy_effect_hash.as_ref());
+    let context = TransactionContext {
+        anchor: penumbra_tct::Tree::new().root(),
+        effect_hash: EffectHash(dummy_effect_hash),
+    };
+    (
+        plan.fee_grant_use(&test_keys::FULL_VIEWING_KEY, auth_sig),
+        context,
+    )
+}
+
+/// A reclaim of `amount` from `grant_id` by the test wallet's `account`, signed over a dummy
+/// effect hash.
+fn grant_reclaim(
+    account: u32,
+    grant_id: FeeGrantId,
+    amount: u64,
+) -> (FeeGrantReclaim, TransactionContext) {
+    let mut rng = rand_chacha::ChaChaRng::seed_from_u64(1312);
+    let plan =
+        FeeGrantReclaimPlan::new(&test_keys::FULL_VIEWING_KEY, account, grant_id, fee(amount));
+    let dummy_effect_hash = [0u8; 64];
+    let auth_sig = test_keys::SPEND_KEY
+        .spend_auth_key()
+        .randomize(&plan.randomizer)
+        .sign(&mut rng, dummy_effect_hash.as_ref());
+    let context = TransactionContext {
+        anchor: penumbra_tct::Tree::new().root(),
+        effect_hash: EffectHash(dummy_effect_hash),
+    };
+    (
+        plan.fee_grant_reclaim(&test_keys::FULL_VIEWING_KEY, auth_sig),
+        context,
+    )
+}
+
+/// Sets up a chain at `height` holding `grant`.
+async fn state_with_grant(
+    grant: &FeeGrant,
+    height: u64,
+) -> anyhow::Result<(TempStorage, Arc<StateDelta<cnidarium::Snapshot>>)> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(height);
+    state_tx.apply();
+
+    grant.check_stateless(()).await?;
+    grant.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    grant.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    Ok((storage, state))
+}
+
+#[tokio::test]
+async fn fee_grant_allowance_is_drawn_down() -> anyhow::Result<()> {
+    let grant = grant(100, 10);
+    let (_storage, mut state) = state_with_grant(&grant, 1).await?;
+
+    for _ in 0..2 {
+        let (grant_use, context) = grant_use(0, grant.id(), 30);
+        grant_use.check_stateless(context).await?;
+        grant_use.check_stateful(state.clone()).await?;
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        grant_use.execute(&mut state_tx).await?;
+        state_tx.apply();
+    }
+
+    let remaining = state
+        .fee_grant(&grant.id())
+        .await?
+        .expect("grant is still recorded");
+    assert_eq!(remaining.allowance, fee(40));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fee_grant_cannot_be_overdrawn() -> anyhow::Result<()> {
+    let grant = grant(100, 10);
+    let (_storage, mut state) = state_with_grant(&grant, 1).await?;
+
+    let (grant_use, _) = grant_use(0, grant.id(), 60);
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    grant_use.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // Only 40 is left, so drawing another 60 fails, both when checked and when executed.
+    let (grant_use, _) = self::grant_use(0, grant.id(), 60);
+    assert!(grant_use.check_stateful(state.clone()).await.is_err());
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    assert!(grant_use.execute(&mut state_tx).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_fee_grant_cannot_be_used() -> anyhow::Result<()> {
+    let grant = grant(100, 5);
+    let (_storage, mut state) = state_with_grant(&grant, 5).await?;
+
+    // The grant can still be used at its expiry height...
+    let (grant_use, _) = grant_use(0, grant.id(), 10);
+    grant_use.check_stateful(state.clone()).await?;
+
+    // ... but not after it.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(6);
+    state_tx.apply();
+    assert!(grant_use.check_stateful(state.clone()).await.is_err());
+
+    // Nor can a grant be made that has already expired.
+    assert!(self::grant(100, 5)
+        .check_stateful(state.clone())
+        .await
+        .is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fee_grant_cannot_be_used_by_another_grantee() -> anyhow::Result<()> {
+    let grant = grant(100, 10);
+    let (_storage, state) = state_with_grant(&grant, 1).await?;
+
+    // The grant was made to account 0, so account 1's key can't draw on it, even though it
+    // signs correctly with its own key.
+    let (grant_use, context) = grant_use(1, grant.id(), 10);
+    grant_use.check_stateless(context).await?;
+    assert!(grant_use.check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn fee_grant_can_be_reclaimed_by_its_sponsor() -> anyhow::Result<()> {
+    let grant = grant(100, 10);
+    let (_storage, mut state) = state_with_grant(&grant, 1).await?;
+
+    let (grant_use, _) = grant_use(0, grant.id(), 30);
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    grant_use.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    // Another account's key can't reclaim the grant, even though it signs correctly.
+    let (reclaim, context) = grant_reclaim(1, grant.id(), 70);
+    reclaim.check_stateless(context).await?;
+    assert!(reclaim.check_stateful(state.clone()).await.is_err());
+
+    // The sponsor must reclaim exactly what remains.
+    let (reclaim, _) = grant_reclaim(0, grant.id(), 100);
+    assert!(reclaim.check_stateful(state.clone()).await.is_err());
+
+    // Reclaiming before the grant expires revokes it.
+    let (reclaim, context) = grant_reclaim(0, grant.id(), 70);
+    reclaim.check_stateless(context).await?;
+    reclaim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    reclaim.execute(&mut state_tx).await?;
+    state_tx.apply();
+
+    assert!(state.fee_grant(&grant.id()).await?.is_none());
+    assert!(state
+        .fee_grant_ids_by_grantee(&grant.grantee)
+        .await?
+        .is_empty());
+
+    // So it can neither be used nor reclaimed again.
+    let (grant_use, _) = self::grant_use(0, grant.id(), 10);
+    assert!(grant_use.check_stateful(state.clone()).await.is_err());
+    assert!(reclaim.check_stateful(state.clone()).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn expired_fee_grants_are_pruned() -> anyhow::Result<()> {
+    let unused = grant(100, 5);
+    let (_storage, mut state) = state_with_grant(&unused, 5).await?;
+    let mut used_up = grant(30, 5);
+    used_up.nonce = [1u8; 32];
+    let later = grant(100, 6);
+
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    for grant in [&used_up, &later] {
+        grant.execute(&mut state_tx).await?;
+    }
+    let (grant_use, _) = grant_use(0, used_up.id(), 30);
+    grant_use.execute(&mut state_tx).await?;
+    state_tx.prune_expired_fee_grants(5).await?;
+    state_tx.apply();
+
+    // Only the grant that is still usable is left for the grantee to draw on...
+    assert_eq!(
+        state.fee_grant_ids_by_grantee(&unused.grantee).await?,
+        vec![later.id()]
+    );
+    // ... the used up grant is gone...
+    assert!(state.fee_grant(&used_up.id()).await?.is_none());
+
+    // ... and the unused allowance can still be reclaimed after expiry.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(6);
+    state_tx.apply();
+    let (reclaim, _) = grant_reclaim(0, unused.id(), 100);
+    reclaim.check_stateful(state.clone()).await?;
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    reclaim.execute(&mut state_tx).await?;
+    state_tx.apply();
+    assert!(state.fee_grant(&unused.id()).await?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use self::common::TempStorageExt;
+use cnidarium::{ArcStateDeltaExt, StateDelta, TempStorage};
+use penumbra_app::rejection::{RejectionReason, TransactionRejection};
+use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_fee::{
+    component::{StateReadExt as _, StateWriteExt as _},
+    grant::{grantee_key, sponsor_key, FeeGrant},
+    AltGasPrices, Fee, GasPrices,
+};
+use penumbra_keys::test_keys;
+use penumbra_sct::component::clock::EpochManager;
+use penumbra_transaction::{gas::GasCost, Action, Transaction};
+use std::sync::Arc;
+
+fn prices(price: u64) -> GasPrices {
+    GasPrices {
+        block_space_price: price,
+        compact_block_space_price: price,
+        verification_price: price,
+        execution_price: price,
+    }
+}
+
+/// A transaction with a single action that has a gas cost, paying `fee`.
+fn transaction_paying(fee: Fee) -> Transaction {
+    let mut transaction = Transaction::default();
+    transaction.transaction_body.actions = vec![Action::FeeGrant(FeeGrant {
+        grantee: grantee_key(&test_keys::FULL_VIEWING_KEY, 0),
+        allowance: Fee::from_staking_token_amount(100u64.into()),
+        expiry_height: 10,
+        nonce: [0u8; 32],
+        sponsor: sponsor_key(&test_keys::FULL_VIEWING_KEY, 0),
+    })];
+    transaction.transaction_body.transaction_parameters.fee = fee;
+    transaction
+}
+
+#[tokio::test]
+async fn insufficient_fee_is_diagnosed_in_the_fee_token() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    // Fees can be paid in gm, at its own gas prices, which are higher than the staking token's.
+    let gm = asset::Cache::with_known_assets()
+        .get_unit("gm")
+        .expect("gm is a known asset")
+        .id();
+    let gm_prices = prices(5_000);
+    let mut fee_params = state.get_fee_params().await?;
+    fee_params.fixed_gas_prices = prices(1_000);
+    fee_params.fixed_alt_gas_prices = vec![AltGasPrices {
+        asset_id: gm,
+        gas_prices: gm_prices,
+    }];
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(1);
+    state_tx.put_gas_prices(fee_params.fixed_gas_prices);
+    state_tx.put_fee_params(fee_params);
+    state_tx.apply();
+
+    let transaction = transaction_paying(Fee(Value {
+        amount: 1u64.into(),
+        asset_id: gm,
+    }));
+    let error = anyhow::anyhow!("fee too small");
+    let rejection = TransactionRejection::diagnose(state.clone(), &transaction, &error).await?;
+
+    let minimum_fee = Fee(Value {
+        amount: gm_prices.fee(&transaction.gas_cost()),
+        asset_id: gm,
+    });
+    assert_ne!(minimum_fee.asset_id(), *STAKING_TOKEN_ASSET_ID);
+    assert!(rejection
+        .reasons
+        .contains(&RejectionReason::InsufficientFee {
+            fee: transaction.transaction_body.transaction_parameters.fee,
+            minimum_fee,
+        }));
+
+    // A fee paid in a token fees can't be paid in has no minimum to report.
+    let gn = asset::Cache::with_known_assets()
+        .get_unit("gn")
+        .expect("gn is a known asset")
+        .id();
+    let transaction = transaction_paying(Fee(Value {
+        amount: 1u64.into(),
+        asset_id: gn,
+    }));
+    let rejection = TransactionRejection::diagnose(state.clone(), &transaction, &error).await?;
+    assert!(!rejection
+        .reasons
+        .iter()
+        .any(|reason| matches!(reason, RejectionReason::InsufficientFee { .. })));
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use self::common::TempStorageExt;
+use cnidarium::{StateDelta, TempStorage};
+use futures::TryStreamExt;
+use penumbra_keys::keys::{SpendKey, SpendKeyBytes};
+use penumbra_stake::{
+    component::validator_handler::{ValidatorDataRead, ValidatorDataWrite},
+    rate::RateData,
+    IdentityKey,
+};
+
+#[tokio::test]
+async fn validator_rate_history_is_read_by_epoch_range() -> anyhow::Result<()> {
+    let storage = TempStorage::new().await?.apply_default_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let validator_key = SpendKey::from(SpendKeyBytes([3; 32]));
+    let identity_key = IdentityKey(*validator_key.full_viewing_key().spend_verification_key());
+    for epoch_index in [1, 2, 3, 10] {
+        state.set_validator_rate_data(
+            &identity_key,
+            RateData {
+                identity_key: identity_key.clone(),
+                epoch_index,
+                validator_reward_rate: 0u64.into(),
+                validator_exchange_rate: 1_0000_0000u64.into(),
+            },
+        );
+    }
+    storage.commit(state).await?;
+
+    let epochs = |start, end| {
+        let snapshot = storage.latest_snapshot();
+        let identity_key = identity_key.clone();
+        async move {
+            let history: Vec<RateData> = snapshot
+                .validator_rate_history(&identity_key, start, end)?
+                .try_collect()
+                .await?;
+            anyhow::Ok(
+                history
+                    .into_iter()
+                    .map(|rate_data| rate_data.epoch_index)
+                    .collect::<Vec<_>>(),
+            )
+        }
+    };
+    assert_eq!(epochs(2, Some(10)).await?, [2, 3]);
+    assert_eq!(epochs(2, None).await?, [2, 3, 10]);
+    assert_eq!(epochs(0, Some(1)).await?, Vec::<u64>::new());
+
+    Ok(())
+}
@@ -38,6 +38,8 @@ pub(super) struct Inner {
     // For now, don't bother with a domain type here,
     // since we don't render images from Rust code.
     images: Vec<pb::AssetImage>,
+    icon_uri: Option<String>,
+    docs_uri: Option<String>,
 
     /// Sorted by priority order.
     pub(super) units: Vec<BareDenomUnit>,
@@ -63,6 +65,8 @@ impl From<&Inner> for pb::Metadata {
             penumbra_asset_id: Some(inner.id.into()),
             denom_units: inner.units.clone().into_iter().map(|x| x.into()).collect(),
             images: inner.images.clone(),
+            icon_uri: inner.icon_uri.clone().unwrap_or_default(),
+            docs_uri: inner.docs_uri.clone().unwrap_or_default(),
         }
     }
 }
@@ -128,10 +132,47 @@ impl TryFrom<pb::Metadata> for Inner {
             name: value.name,
             symbol: value.symbol,
             images: value.images,
+            icon_uri: parse_uri(value.icon_uri).context("invalid icon URI")?,
+            docs_uri: parse_uri(value.docs_uri).context("invalid docs URI")?,
         })
     }
 }
 
+/// The maximum length of the URIs in asset metadata, in bytes.
+pub const MAX_URI_LENGTH: usize = 2048;
+
+/// The schemes allowed for the URIs in asset metadata.
+///
+/// These are the schemes wallets can be expected to fetch from safely; in particular, `http`
+/// would let anyone on the network path substitute the icon of an asset.
+pub const URI_SCHEMES: &[&str] = &["https", "ipfs"];
+
+/// Validates an optional URI of asset metadata, which is absent if empty.
+fn parse_uri(uri: String) -> anyhow::Result<Option<String>> {
+    if uri.is_empty() {
+        return Ok(None);
+    }
+    ensure!(
+        uri.len() <= MAX_URI_LENGTH,
+        "URI is {} bytes long, exceeding the maximum of {MAX_URI_LENGTH}",
+        uri.len()
+    );
+    ensure!(
+        !uri.chars().any(|c| c.is_whitespace() || c.is_control()),
+        "URI {uri:?} contains whitespace or control characters"
+    );
+    let (scheme, rest) = uri
+        .split_once("://")
+        .with_context(|| format!("URI {uri:?} has no scheme"))?;
+    ensure!(
+        URI_SCHEMES.contains(&scheme.to_ascii_lowercase().as_str()),
+        "URI {uri:?} has scheme {scheme:?}, but only {} are allowed",
+        URI_SCHEMES.join(", ")
+    );
+    ensure!(!rest.is_empty(), "URI {uri:?} is empty after its scheme");
+    Ok(Some(uri))
+}
+
 impl From<Metadata> for pb::Metadata {
     fn from(dn: Metadata) -> Self {
         dn.inner.as_ref().into()
@@ -249,6 +290,8 @@ impl Inner {
             name: String::new(),
             symbol: String::new(),
             images: Vec::new(),
+            icon_uri: None,
+            docs_uri: None,
         }
     }
 }
@@ -259,6 +302,35 @@ impl Metadata {
         self.inner.id
     }
 
+    /// Returns the URI of an icon for the asset, if it has one.
+    pub fn icon_uri(&self) -> Option<&str> {
+        self.inner.icon_uri.as_deref()
+    }
+
+    /// Returns the URI of documentation about the asset, if it has one.
+    pub fn docs_uri(&self) -> Option<&str> {
+        self.inner.docs_uri.as_deref()
+    }
+
+    /// Returns a copy of this metadata with the given icon and docs URIs, replacing any it had.
+    ///
+    /// # Errors
+    ///
+    /// Errors if either URI is longer than [`MAX_URI_LENGTH`], or doesn't use one of the
+    /// [`URI_SCHEMES`].
+    pub fn with_uris(
+        &self,
+        icon_uri: Option<String>,
+        docs_uri: Option<String>,
+    ) -> anyhow::Result<Metadata> {
+        pb::Metadata {
+            icon_uri: icon_uri.unwrap_or_default(),
+            docs_uri: docs_uri.unwrap_or_default(),
+            ..self.clone().into()
+        }
+        .try_into()
+    }
+
     pub fn base_denom(&self) -> Denom {
         Denom {
             denom: self.inner.base_denom.clone(),
@@ -431,43 +503,45 @@ impl Unit {
         }
     }
 
+    /// Parses an amount written in this unit, like `1.5`, into an amount of the base unit.
+    ///
+    /// The decimal separator is always `.`, whatever the locale, and amounts can't contain
+    /// thousands separators or use scientific notation.
     pub fn parse_value(&self, value: &str) -> anyhow::Result<Amount> {
-        let split: Vec<&str> = value.split('.').collect();
-        if split.len() > 2 {
-            anyhow::bail!("expected only one decimal point")
-        } else {
-            let left = split[0];
-
-            // The decimal point and right hand side is optional. If it's not present, we use "0"
-            // such that the rest of the logic is the same.
-            let right = if split.len() > 1 { split[1] } else { "0" };
-
-            let v1 = left.parse::<u128>().map_err(|e| anyhow::anyhow!(e))?;
-            let mut v2 = right.parse::<u128>().map_err(|e| anyhow::anyhow!(e))?;
-            let v1_power_of_ten = 10u128.pow(self.exponent().into());
-
-            if right.len() == (self.exponent() + 1) as usize && v2 == 0 {
-                // This stanza means that the value is the base unit. Simply return v1.
-                return Ok(v1.into());
-            } else if right.len() > self.exponent().into() {
-                anyhow::bail!("cannot represent this value");
-            }
-
-            let v2_power_of_ten = 10u128.pow((self.exponent() - right.len() as u8).into());
-            v2 = v2
-                .checked_mul(v2_power_of_ten)
-                .context("multiplication overflowed when applying right hand side exponent")?;
+        if value.contains(',') {
+            anyhow::bail!(
+                "amount {value} contains a comma; use '.' as the decimal separator, and don't separate thousands"
+            );
+        }
+        if let Some(c) = value.chars().find(|c| !c.is_ascii_digit() && *c != '.') {
+            anyhow::bail!("amount {value} contains {c:?}, but may only contain digits and a '.'");
+        }
+        let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+        if fraction.contains('.') {
+            anyhow::bail!("amount {value} has more than one decimal point");
+        }
+        if whole.is_empty() && fraction.is_empty() {
+            anyhow::bail!("amount {value} has no digits");
+        }
 
-            let v = v1
-                .checked_mul(v1_power_of_ten)
-                .and_then(|x| x.checked_add(v2));
+        // Trailing zeros after the decimal point don't change the amount.
+        let fraction = fraction.trim_end_matches('0');
+        let exponent = usize::from(self.exponent());
+        if fraction.len() > exponent {
+            anyhow::bail!(
+                "amount {value} has too many decimal places, as {self} has at most {exponent}"
+            );
+        }
 
-            if let Some(value) = v {
-                Ok(value.into())
-            } else {
-                anyhow::bail!("overflow!")
-            }
+        // Shifting the decimal point right by the exponent gives the amount of the base unit.
+        let digits = format!("{whole}{fraction:0<exponent$}");
+        if digits.is_empty() {
+            return Ok(Amount::zero());
         }
+        let amount = digits
+            .parse::<u128>()
+            .with_context(|| format!("amount {value} of {self} is too large"))?;
+        Ok(amount.into())
     }
 
     pub fn exponent(&self) -> u8 {
@@ -520,6 +594,50 @@ impl Display for Unit {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn metadata_uris() {
+        let metadata = Metadata::default_for(&Denom {
+            denom: "upenumbra".to_string(),
+        })
+        .expect("upenumbra is a known denom");
+        assert_eq!(metadata.icon_uri(), None);
+
+        let metadata = metadata
+            .with_uris(
+                Some("https://example.com/penumbra.svg".to_string()),
+                Some(
+                    "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+                        .to_string(),
+                ),
+            )
+            .unwrap();
+        assert_eq!(
+            metadata.icon_uri(),
+            Some("https://example.com/penumbra.svg")
+        );
+        let proto = pb::Metadata::from(metadata.clone());
+        let round_trip = Metadata::try_from(proto).unwrap();
+        assert_eq!(round_trip.icon_uri(), metadata.icon_uri());
+        assert_eq!(round_trip.docs_uri(), metadata.docs_uri());
+
+        for (uri, error) in [
+            ("http://example.com/icon.png", "scheme"),
+            ("javascript://alert(1)", "scheme"),
+            ("data:image/png;base64,AAAA", "no scheme"),
+            ("https://", "empty after its scheme"),
+            ("https://example.com/my icon.png", "whitespace"),
+        ] {
+            let err = metadata
+                .with_uris(Some(uri.to_string()), None)
+                .expect_err(uri);
+            assert!(format!("{err:#}").contains(error), "{uri}: {err:#}");
+        }
+        let long = format!("https://example.com/{}", "a".repeat(MAX_URI_LENGTH));
+        assert!(metadata.with_uris(None, Some(long)).is_err());
+    }
+
     #[test]
     fn can_parse_metadata_from_chain_registry() {
         const SOME_COSMOS_JSON: &str = r#"
@@ -13,12 +13,11 @@ use std::{
 use anyhow::Context;
 use penumbra_num::{Amount, AmountVar};
 use penumbra_proto::{penumbra::core::asset::v1 as pb, DomainType};
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use crate::EquivalentValue;
 use crate::{
-    asset::{AssetIdVar, Cache, Id, Metadata, REGISTRY},
+    asset::{AssetIdVar, Cache, Id, Metadata, Unit, REGISTRY},
     EstimatedPrice,
 };
 
@@ -57,6 +56,28 @@ impl ValueView {
         self.value().asset_id
     }
 
+    /// Get the `Amount` of the underlying `Value`, without having to match on visibility.
+    pub fn amount(&self) -> Amount {
+        match self {
+            ValueView::KnownAssetId { amount, .. } => *amount,
+            ValueView::UnknownAssetId { amount, .. } => *amount,
+        }
+    }
+
+    /// Formats this value in the default unit of its asset if the asset is known, or in base
+    /// units of its asset ID otherwise.
+    pub fn display(&self) -> String {
+        match self {
+            ValueView::KnownAssetId {
+                amount, metadata, ..
+            } => {
+                let unit = metadata.default_unit();
+                format!("{}{}", unit.format_value(*amount), unit)
+            }
+            ValueView::UnknownAssetId { amount, asset_id } => format!("{amount}{asset_id}"),
+        }
+    }
+
     /// Use the provided [`EstimatedPrice`]s and asset metadata [`Cache`] to add
     /// equivalent values to this [`ValueView`].
     pub fn with_prices(mut self, prices: &[EstimatedPrice], known_metadata: &Cache) -> Self {
@@ -355,56 +376,99 @@ impl ValueVar {
     }
 }
 
-impl FromStr for Value {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let asset_id_re =
-            Regex::new(r"^([0-9.]+)(passet[0-9].*)$").context("unable to parse asset ID regex")?;
-        let denom_re =
-            Regex::new(r"^([0-9.]+)([^0-9.].*)$").context("unable to parse denom regex")?;
-
-        if let Some(captures) = asset_id_re.captures(s) {
-            let numeric_str = captures
-                .get(1)
-                .context("string value should have numeric part")?
-                .as_str();
-            let asset_id_str = captures
-                .get(2)
-                .context("string value should have asset ID part")?
-                .as_str();
-
+impl Value {
+    /// Parses a value written as an amount followed by a unit, like `1.5penumbra`, or as an
+    /// amount of the base unit of an asset ID, like `1000passet1...`.
+    ///
+    /// Amounts use `.` as the decimal separator whatever the locale, and may be separated from
+    /// the unit by whitespace. Amounts with thousands separators, in scientific notation, with
+    /// more decimal places than the unit has, or that are negative are rejected, with an error
+    /// explaining how to write them instead. Units that aren't known are parsed as base
+    /// denominations, so that assets from other chains can be named by their denom.
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let (amount, unit) = split_value(s)?;
+        if unit.starts_with("passet1") {
             let asset_id =
-                Id::from_str(asset_id_str).context("unable to parse string value's asset ID")?;
-            let amount = numeric_str
-                .parse::<u64>()
-                .context("unable to parse string value's numeric amount")?;
-
-            Ok(Value {
+                Id::from_str(unit).with_context(|| format!("invalid asset ID {unit}"))?;
+            if !amount.chars().all(|c| c.is_ascii_digit()) {
+                anyhow::bail!(
+                    "could not parse {s} as a value; amounts of asset IDs are whole numbers of base units"
+                );
+            }
+            let amount = amount
+                .parse::<u128>()
+                .with_context(|| format!("amount {amount} is too large"))?;
+            return Ok(Value {
                 amount: amount.into(),
                 asset_id,
-            })
-        } else if let Some(captures) = denom_re.captures(s) {
-            let numeric_str = captures
-                .get(1)
-                .context("string value should have numeric part")?
-                .as_str();
-            let denom_str = captures
-                .get(2)
-                .context("string value should have denom part")?
-                .as_str();
-
-            let display_denom = REGISTRY.parse_unit(denom_str);
-            let amount = display_denom.parse_value(numeric_str)?;
-            let asset_id = display_denom.base().id();
-
-            Ok(Value { amount, asset_id })
-        } else {
-            Err(anyhow::anyhow!(
-                "could not parse {} as a value; provide both a numeric value and denomination, e.g. 1penumbra",
-                s
-            ))
+            });
+        }
+        Ok(Self::parse_with_unit(s)?.0)
+    }
+
+    /// Parses a value written as an amount followed by a unit, like [`Value::parse`], also
+    /// returning the unit the amount was written in.
+    ///
+    /// Unlike [`Value::parse`], this doesn't accept asset IDs in place of units.
+    pub fn parse_with_unit(s: &str) -> anyhow::Result<(Self, Unit)> {
+        let (amount, unit) = split_value(s)?;
+        let unit = REGISTRY.parse_unit(unit);
+        let amount = unit
+            .parse_value(amount)
+            .with_context(|| format!("could not parse {s} as a value"))?;
+        Ok((
+            Value {
+                amount,
+                asset_id: unit.id(),
+            },
+            unit,
+        ))
+    }
+}
+
+/// Splits a value like `1.5penumbra` into its amount and its unit.
+fn split_value(s: &str) -> anyhow::Result<(&str, &str)> {
+    let s = s.trim();
+    let (amount, unit) = s.split_at(
+        // Commas and underscores are included, so that the amount parser can explain they're
+        // not allowed, rather than them being parsed as part of the unit.
+        s.find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | ',' | '_')))
+            .unwrap_or(s.len()),
+    );
+    let unit = unit.trim_start();
+
+    if amount.is_empty() {
+        if unit.starts_with('-') {
+            anyhow::bail!("could not parse {s} as a value, as values can't be negative");
         }
+        anyhow::bail!(
+            "could not parse {s} as a value; provide an amount followed by a unit, e.g. 1.5penumbra"
+        );
+    }
+    if amount.contains('_') {
+        anyhow::bail!("could not parse {s} as a value; write the amount without separating digits");
+    }
+    let mut unit_chars = unit.chars();
+    if matches!(unit_chars.next(), Some('e' | 'E'))
+        && matches!(unit_chars.next(), Some('0'..='9' | '+' | '-'))
+    {
+        anyhow::bail!(
+            "could not parse {s} as a value, as scientific notation isn't supported; write the amount out in full, or use a smaller unit, e.g. 1000000upenumbra rather than 1e6upenumbra"
+        );
+    }
+    if unit.is_empty() {
+        anyhow::bail!(
+            "could not parse {s} as a value; provide a unit after the amount, e.g. {amount}penumbra"
+        );
+    }
+    Ok((amount, unit))
+}
+
+impl FromStr for Value {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Value::parse(s)
     }
 }
 
@@ -533,6 +597,27 @@ mod tests {
     fn value_parsing_errors() {
         assert!(Value::from_str("1").is_err());
         assert!(Value::from_str("nala").is_err());
+        assert!(Value::from_str("-1penumbra").is_err());
+        assert!(Value::from_str("1,5penumbra").is_err());
+        assert!(Value::from_str("1_000penumbra").is_err());
+        assert!(Value::from_str("1e6upenumbra").is_err());
+        assert!(Value::from_str("1.5E-3penumbra").is_err());
+        assert!(Value::from_str("1.2.3penumbra").is_err());
+        assert!(Value::from_str("1.0000001penumbra").is_err());
+        assert!(Value::from_str("1.5upenumbra").is_err());
+    }
+
+    #[test]
+    fn value_parsing_lenient() {
+        let v: Value = "1.5penumbra".parse().unwrap();
+        assert_eq!(v.amount, 1500000u64.into());
+        assert_eq!(v, " 1.5 penumbra ".parse().unwrap());
+        assert_eq!(v, "1.500000000penumbra".parse().unwrap());
+        assert_eq!(v, "1500mpenumbra".parse().unwrap());
+
+        let (v, unit) = Value::parse_with_unit(".5penumbra").unwrap();
+        assert_eq!(v.amount, 500000u64.into());
+        assert_eq!(unit.to_string(), "penumbra");
     }
 
     #[test]
@@ -551,4 +636,20 @@ mod tests {
         assert_eq!(v2.format(&cache), "1mpenumbra");
         assert_eq!(v3.format(&cache), "4penumbra");
     }
+
+    #[test]
+    fn value_view_display() {
+        let cache = crate::asset::Cache::with_known_assets();
+        let known: Value = "1500000upenumbra".parse().unwrap();
+        let view = known.view_with_cache(&cache);
+        assert_eq!(view.amount(), known.amount);
+        assert_eq!(view.display(), "1.5penumbra");
+
+        let unknown = known.view_with_cache(&Cache::default());
+        assert_eq!(unknown.amount(), known.amount);
+        assert_eq!(
+            unknown.display(),
+            format!("{}{}", known.amount, known.asset_id)
+        );
+    }
 }
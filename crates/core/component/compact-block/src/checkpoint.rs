@@ -0,0 +1,238 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use ibc_types::core::commitment::{MerklePath, MerkleProof, MerkleRoot};
+use penumbra_fee::GasPrices;
+use penumbra_proto::{
+    penumbra::core::component::compact_block::v1 as pb, penumbra::crypto::tct::v1 as pb_tct,
+    DomainType,
+};
+use penumbra_sct::state_key;
+use penumbra_shielded_pool::fmd;
+use penumbra_tct as tct;
+
+/// A checkpoint of the state commitment tree at the end of an epoch, from which a
+/// client can begin syncing compact blocks instead of starting from genesis.
+///
+/// A checkpoint is only trustworthy once it has been [verified](SyncCheckpoint::verify)
+/// against a [`TrustedCheckpoint`] the user obtained from a source they trust.
+#[derive(Clone, Debug)]
+pub struct SyncCheckpoint {
+    /// The index of the epoch ending at this checkpoint.
+    pub epoch_index: u64,
+    /// The height of the last block of the epoch.
+    pub height: u64,
+    /// The SCT anchor after the last block of the epoch.
+    pub anchor: tct::Root,
+    /// The frontier of the state commitment tree at the checkpoint.
+    pub sct: tct::Tree,
+    /// A proof that `anchor` was recorded as the anchor for `height` in the chain state.
+    pub anchor_proof: MerkleProof,
+    /// The height of the chain state that `anchor_proof` is against.
+    pub proof_height: u64,
+    /// The FMD parameters at the time the checkpoint was served.
+    pub fmd_parameters: fmd::Parameters,
+    /// The gas prices at the time the checkpoint was served.
+    pub gas_prices: GasPrices,
+}
+
+impl SyncCheckpoint {
+    /// Checks that the checkpoint is the `trusted` one, that it is internally consistent,
+    /// and that its anchor was recorded in the chain state with the given `app_hash`.
+    ///
+    /// The `app_hash` should be taken from the header of the block at `proof_height + 1`.
+    /// It comes from the node serving the checkpoint, so it only shows that the node is
+    /// consistent with itself: it's the `trusted` anchor that ties the checkpoint to the chain.
+    pub fn verify(&self, app_hash: &[u8], trusted: &TrustedCheckpoint) -> Result<()> {
+        ensure!(
+            self.height == trusted.height && self.anchor == trusted.anchor,
+            "checkpoint at height {} with anchor {} is not the trusted checkpoint {}",
+            self.height,
+            self.anchor,
+            trusted
+        );
+        ensure!(
+            self.sct.root() == self.anchor,
+            "checkpoint state commitment tree does not match its anchor"
+        );
+
+        // The tree should be positioned at the very start of the epoch following the checkpoint.
+        let position = self
+            .sct
+            .position()
+            .ok_or_else(|| anyhow!("checkpoint state commitment tree is full"))?;
+        ensure!(
+            u64::from(position.epoch()) == self.epoch_index + 1
+                && position.block() == 0
+                && position.commitment() == 0,
+            "checkpoint state commitment tree is not at the end of epoch {}",
+            self.epoch_index
+        );
+
+        self.anchor_proof
+            .verify_membership(
+                &penumbra_ibc::ROOT_STORE_PROOF_SPECS,
+                MerkleRoot {
+                    hash: app_hash.to_vec(),
+                },
+                MerklePath {
+                    key_path: vec![state_key::tree::anchor_by_height(self.height)],
+                },
+                self.anchor.encode_to_vec(),
+                0,
+            )
+            .context("could not verify checkpoint anchor against app hash")?;
+
+        Ok(())
+    }
+}
+
+/// A checkpoint a user trusts, identified by its height and anchor, written as `HEIGHT:ANCHOR`.
+///
+/// Checkpoints are served and proven by the same node, so a dishonest node could serve a
+/// made-up one. The anchor must instead come from a source the user trusts, such as
+/// `pcli query chain checkpoint` run against a node they operate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TrustedCheckpoint {
+    /// The height of the last block of the checkpoint's epoch.
+    pub height: u64,
+    /// The SCT anchor after that block.
+    pub anchor: tct::Root,
+}
+
+impl From<&SyncCheckpoint> for TrustedCheckpoint {
+    fn from(checkpoint: &SyncCheckpoint) -> Self {
+        TrustedCheckpoint {
+            height: checkpoint.height,
+            anchor: checkpoint.anchor,
+        }
+    }
+}
+
+impl fmt::Display for TrustedCheckpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.height, self.anchor)
+    }
+}
+
+impl FromStr for TrustedCheckpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (height, anchor) = s
+            .split_once(':')
+            .ok_or_else(|| anyhow!("expected a checkpoint of the form HEIGHT:ANCHOR"))?;
+        let anchor = tct::Root::try_from(pb_tct::MerkleRoot {
+            inner: hex::decode(anchor).context("checkpoint anchor is not hex")?,
+        })
+        .context("invalid checkpoint anchor")?;
+        Ok(TrustedCheckpoint {
+            height: height.parse().context("invalid checkpoint height")?,
+            anchor,
+        })
+    }
+}
+
+impl DomainType for SyncCheckpoint {
+    type Proto = pb::SyncCheckpointResponse;
+}
+
+impl From<SyncCheckpoint> for pb::SyncCheckpointResponse {
+    fn from(checkpoint: SyncCheckpoint) -> Self {
+        pb::SyncCheckpointResponse {
+            epoch_index: checkpoint.epoch_index,
+            height: checkpoint.height,
+            anchor: Some(checkpoint.anchor.into()),
+            state_commitment_tree: bincode::serialize(&checkpoint.sct)
+                .expect("able to serialize state commitment tree to bincode"),
+            anchor_proof: Some(checkpoint.anchor_proof.into()),
+            proof_height: checkpoint.proof_height,
+            fmd_parameters: Some(checkpoint.fmd_parameters.into()),
+            gas_prices: Some(checkpoint.gas_prices.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::SyncCheckpointResponse> for SyncCheckpoint {
+    type Error = anyhow::Error;
+
+    fn try_from(value: pb::SyncCheckpointResponse) -> Result<Self, Self::Error> {
+        Ok(SyncCheckpoint {
+            epoch_index: value.epoch_index,
+            height: value.height,
+            anchor: value
+                .anchor
+                .ok_or_else(|| anyhow!("missing anchor in SyncCheckpointResponse"))?
+                .try_into()?,
+            sct: bincode::deserialize(&value.state_commitment_tree)
+                .context("could not deserialize checkpoint state commitment tree")?,
+            anchor_proof: value
+                .anchor_proof
+                .ok_or_else(|| anyhow!("missing anchor proof in SyncCheckpointResponse"))?
+                .try_into()?,
+            proof_height: value.proof_height,
+            fmd_parameters: value
+                .fmd_parameters
+                .ok_or_else(|| anyhow!("missing FMD parameters in SyncCheckpointResponse"))?
+                .try_into()?,
+            gas_prices: value
+                .gas_prices
+                .ok_or_else(|| anyhow!("missing gas prices in SyncCheckpointResponse"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkpoint() -> SyncCheckpoint {
+        let mut sct = tct::Tree::new();
+        sct.end_epoch().expect("can end epoch");
+        SyncCheckpoint {
+            epoch_index: 0,
+            height: 10,
+            anchor: sct.root(),
+            sct,
+            anchor_proof: MerkleProof { proofs: vec![] },
+            proof_height: 11,
+            fmd_parameters: fmd::Parameters::default(),
+            gas_prices: GasPrices::default(),
+        }
+    }
+
+    #[test]
+    fn trusted_checkpoint_roundtrips_through_string() {
+        let trusted = TrustedCheckpoint::from(&checkpoint());
+        let parsed: TrustedCheckpoint = trusted.to_string().parse().expect("can parse checkpoint");
+        assert_eq!(parsed, trusted);
+
+        assert!("10".parse::<TrustedCheckpoint>().is_err());
+        assert!("ten:00".parse::<TrustedCheckpoint>().is_err());
+    }
+
+    #[test]
+    fn verify_rejects_untrusted_checkpoint() {
+        let checkpoint = checkpoint();
+        let trusted = TrustedCheckpoint::from(&checkpoint);
+
+        // A node could prove any checkpoint against its own app hash, so one that isn't
+        // the trusted checkpoint is rejected before its proof is looked at.
+        for untrusted in [
+            TrustedCheckpoint {
+                height: 20,
+                ..trusted
+            },
+            TrustedCheckpoint {
+                anchor: tct::Tree::new().root(),
+                ..trusted
+            },
+        ] {
+            let error = checkpoint
+                .verify(&[], &untrusted)
+                .expect_err("untrusted checkpoint is rejected");
+            assert!(error.to_string().contains("is not the trusted checkpoint"));
+        }
+    }
+}
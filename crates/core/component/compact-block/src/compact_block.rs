@@ -38,6 +38,11 @@ pub struct CompactBlock {
     pub app_parameters_updated: bool,
     /// Updated gas prices, if they have changed.
     pub gas_prices: Option<GasPrices>,
+    /// The time of the block, if it was recorded.
+    ///
+    /// Unlike the other fields, this doesn't need to be checked by `requires_scanning`: clients
+    /// only need it for blocks that have transactions, which are scanned anyway.
+    pub block_time: Option<tendermint::Time>,
     // **IMPORTANT NOTE FOR FUTURE HUMANS**: if you want to add new fields to the `CompactBlock`,
     // you must update `CompactBlock::requires_scanning` to check for the emptiness of those fields,
     // because the client will skip processing any compact block that is marked as not requiring
@@ -57,6 +62,7 @@ impl Default for CompactBlock {
             swap_outputs: BTreeMap::new(),
             app_parameters_updated: false,
             gas_prices: None,
+            block_time: None,
         }
     }
 }
@@ -95,6 +101,7 @@ impl From<CompactBlock> for pb::CompactBlock {
             swap_outputs: cb.swap_outputs.into_values().map(Into::into).collect(),
             app_parameters_updated: cb.app_parameters_updated,
             gas_prices: cb.gas_prices.map(Into::into),
+            block_time: cb.block_time.map(time_to_proto),
         }
     }
 }
@@ -132,14 +139,31 @@ impl TryFrom<pb::CompactBlock> for CompactBlock {
             proposal_started: value.proposal_started,
             app_parameters_updated: value.app_parameters_updated,
             gas_prices: value.gas_prices.map(TryInto::try_into).transpose()?,
+            block_time: value.block_time.map(time_from_proto).transpose()?,
         })
     }
 }
 
+fn time_to_proto(time: tendermint::Time) -> pbjson_types::Timestamp {
+    let nanos = time.unix_timestamp_nanos();
+    pbjson_types::Timestamp {
+        seconds: nanos.div_euclid(1_000_000_000) as i64,
+        nanos: nanos.rem_euclid(1_000_000_000) as i32,
+    }
+}
+
+fn time_from_proto(timestamp: pbjson_types::Timestamp) -> Result<tendermint::Time> {
+    Ok(tendermint::Time::from_unix_timestamp(
+        timestamp.seconds,
+        timestamp.nanos.try_into()?,
+    )?)
+}
+
 impl From<CompactBlock> for CompactBlockRangeResponse {
     fn from(cb: CompactBlock) -> Self {
         Self {
             compact_block: Some(cb.into()),
+            ..Default::default()
         }
     }
 }
@@ -116,6 +116,9 @@ trait Inner: StateWrite {
         // Add all the pending nullifiers to the compact block
         let nullifiers = self.pending_nullifiers().into_iter().collect();
 
+        // The genesis block is finalized before any block header is seen, so it has no time.
+        let block_time = self.get_block_timestamp().await.ok();
+
         let compact_block = CompactBlock {
             height,
             state_payloads,
@@ -127,6 +130,7 @@ trait Inner: StateWrite {
             fmd_parameters,
             app_parameters_updated,
             gas_prices,
+            block_time,
         };
 
         self.nonverifiable_put_raw(
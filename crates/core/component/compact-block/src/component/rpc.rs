@@ -2,17 +2,25 @@ use std::pin::Pin;
 
 use anyhow::bail;
 use cnidarium::Storage;
-use futures::{StreamExt, TryFutureExt, TryStreamExt};
-use penumbra_proto::core::component::compact_block::v1::{
-    query_service_server::QueryService, CompactBlockRangeRequest, CompactBlockRangeResponse,
-    CompactBlockRequest, CompactBlockResponse,
+use futures::{StreamExt, TryFutureExt};
+use penumbra_fee::component::StateReadExt as _;
+use penumbra_proto::{
+    core::component::compact_block::v1::{
+        query_service_server::QueryService, CompactBlockEncoding, CompactBlockRangeRequest,
+        CompactBlockRangeResponse, CompactBlockRequest, CompactBlockResponse,
+        SyncCheckpointRequest, SyncCheckpointResponse,
+    },
+    DomainType,
 };
-use penumbra_sct::component::clock::EpochRead;
+use penumbra_sct::component::{clock::EpochRead, tree::SctRead};
+use penumbra_shielded_pool::component::StateReadExt as _;
+use penumbra_tct as tct;
 use tokio::sync::mpsc;
 use tonic::Status;
 use tracing::{instrument, Instrument};
 
 use super::{metrics, StateReadExt};
+use crate::{encoding::Encoder, SyncCheckpoint};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -49,12 +57,83 @@ impl QueryService for Server {
         }))
     }
 
+    #[instrument(skip(self, request), fields(max_height = request.get_ref().max_height))]
+    async fn sync_checkpoint(
+        &self,
+        request: tonic::Request<SyncCheckpointRequest>,
+    ) -> Result<tonic::Response<SyncCheckpointResponse>, Status> {
+        let snapshot = self.storage.latest_snapshot();
+
+        let current_height = snapshot
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting block height: {e}")))?;
+        let max_height = match request.get_ref().max_height {
+            0 => current_height,
+            max_height => std::cmp::min(max_height, current_height),
+        };
+
+        // The epoch containing `max_height` may not have ended yet, so the latest checkpoint
+        // at or before it is the one at the end of the previous epoch.
+        let epoch = snapshot
+            .get_epoch_by_height(max_height)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("could not get epoch: {e:#}")))?;
+        let epoch_index = epoch
+            .index
+            .checked_sub(1)
+            .ok_or_else(|| tonic::Status::not_found("no epoch has ended yet"))?;
+        let height = epoch.start_height - 1;
+
+        let sct = snapshot
+            .get_sct_checkpoint(epoch_index)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error fetching checkpoint: {e:#}")))?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("no checkpoint recorded for epoch {epoch_index}"))
+            })?;
+
+        let (anchor, anchor_proof) = snapshot
+            .get_with_proof(penumbra_sct::state_key::tree::anchor_by_height(height).into_bytes())
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error proving anchor: {e:#}")))?;
+        let anchor = anchor
+            .ok_or_else(|| tonic::Status::internal(format!("missing anchor for height {height}")))
+            .and_then(|bytes| {
+                tct::Root::decode(bytes.as_slice())
+                    .map_err(|e| tonic::Status::internal(format!("invalid anchor: {e:#}")))
+            })?;
+
+        let fmd_parameters = snapshot.get_current_fmd_parameters().await.map_err(|e| {
+            tonic::Status::internal(format!("error fetching FMD parameters: {e:#}"))
+        })?;
+        let gas_prices = snapshot
+            .get_gas_prices()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error fetching gas prices: {e:#}")))?;
+
+        Ok(tonic::Response::new(
+            SyncCheckpoint {
+                epoch_index,
+                height,
+                anchor,
+                sct,
+                anchor_proof,
+                proof_height: snapshot.version(),
+                fmd_parameters,
+                gas_prices,
+            }
+            .into(),
+        ))
+    }
+
     #[instrument(
         skip(self, request),
         fields(
             start_height = request.get_ref().start_height,
             end_height = request.get_ref().end_height,
             keep_alive = request.get_ref().keep_alive,
+            encoding = request.get_ref().encoding,
         ),
     )]
     async fn compact_block_range(
@@ -76,9 +155,15 @@ impl QueryService for Server {
             start_height,
             end_height,
             keep_alive,
+            encoding,
             ..
         } = request.into_inner();
 
+        // Clients asking for an encoding we don't know about get plain compact blocks,
+        // which they can always decode.
+        let encoding =
+            CompactBlockEncoding::try_from(encoding).unwrap_or(CompactBlockEncoding::Unspecified);
+
         let current_height = snapshot
             .get_block_height()
             .await
@@ -106,6 +191,7 @@ impl QueryService for Server {
         tokio::spawn(
             async move {
                 let _guard = CompactBlockConnectionCounter::new();
+                let mut encoder = Encoder::new(encoding);
 
                 // Phase 1: Catch up from the start height.
                 tracing::debug!(
@@ -142,7 +228,8 @@ impl QueryService for Server {
                     // Future iterations of this work should start by moving block serialization
                     // outside of the `send_op` future, and investigate if long blocking sends can
                     // happen for benign reasons (i.e not caused by the client).
-                    tx_blocks.send(Ok(compact_block.into())).await?;
+                    let response = encoder.encode(compact_block)?;
+                    tx_blocks.send(Ok(response)).await?;
                     metrics::counter!(metrics::COMPACT_BLOCK_RANGE_SERVED_TOTAL).increment(1);
                 }
 
@@ -171,8 +258,11 @@ impl QueryService for Server {
                         .await
                         .expect("no error fetching block")
                         .expect("compact block for in-range height must be present");
+                    let response = encoder
+                        .encode(block)
+                        .map_err(|e| tonic::Status::internal(e.to_string()))?;
                     tx_blocks
-                        .send(Ok(block.into()))
+                        .send(Ok(response))
                         .await
                         .map_err(|_| tonic::Status::cancelled("client closed connection"))?;
                     metrics::counter!(metrics::COMPACT_BLOCK_RANGE_SERVED_TOTAL).increment(1);
@@ -200,8 +290,11 @@ impl QueryService for Server {
                         .await
                         .map_err(|e| tonic::Status::internal(e.to_string()))?
                         .expect("compact block for in-range height must be present");
+                    let response = encoder
+                        .encode(block)
+                        .map_err(|e| tonic::Status::internal(e.to_string()))?;
                     tx_blocks
-                        .send(Ok(block.into()))
+                        .send(Ok(response))
                         .await
                         .map_err(|_| tonic::Status::cancelled("channel closed"))?;
                     metrics::counter!(metrics::COMPACT_BLOCK_RANGE_SERVED_TOTAL).increment(1);
@@ -224,11 +317,7 @@ impl QueryService for Server {
         //
         // for now, assume that we can do c10k or whatever and don't worry about it.
         Ok(tonic::Response::new(
-            tokio_stream::wrappers::ReceiverStream::new(rx_blocks)
-                .map_ok(|block| CompactBlockRangeResponse {
-                    compact_block: Some(block),
-                })
-                .boxed(),
+            tokio_stream::wrappers::ReceiverStream::new(rx_blocks).boxed(),
         ))
     }
 }
@@ -5,11 +5,16 @@
 #[cfg(feature = "component")]
 pub mod component;
 
+#[cfg_attr(docsrs, doc(cfg(feature = "zstd")))]
+#[cfg(feature = "zstd")]
+pub mod encoding;
 pub mod event;
 pub mod state_key;
 
+mod checkpoint;
 mod compact_block;
 mod state_payload;
 
+pub use checkpoint::{SyncCheckpoint, TrustedCheckpoint};
 pub use compact_block::CompactBlock;
 pub use state_payload::{StatePayload, StatePayloadDebugKind};
@@ -40,6 +40,33 @@ pub struct BatchSwapOutputData {
     pub epoch_starting_height: u64,
 }
 
+/// A swap's pro rata share of the outputs of a batch swap, split into the amounts it receives
+/// from the batch's fills and the parts of its inputs returned to it unfilled.
+///
+/// This is computed by [`BatchSwapOutputData::pro_rata_share`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProRataShare {
+    /// The amount of asset 1 received for the swap's input of asset 2.
+    pub lambda_1: Amount,
+    /// The amount of asset 2 received for the swap's input of asset 1.
+    pub lambda_2: Amount,
+    /// The amount of the swap's input of asset 1 returned unfilled.
+    pub unfilled_1: Amount,
+    /// The amount of the swap's input of asset 2 returned unfilled.
+    pub unfilled_2: Amount,
+}
+
+impl ProRataShare {
+    /// The amounts of assets 1 and 2 output to the swap, i.e., the amounts of the notes
+    /// minted when it's claimed.
+    pub fn outputs(&self) -> (Amount, Amount) {
+        (
+            self.lambda_1 + self.unfilled_1,
+            self.lambda_2 + self.unfilled_2,
+        )
+    }
+}
+
 impl BatchSwapOutputData {
     /// Given a user's inputs `(delta_1_i, delta_2_i)`, compute their pro rata share
     /// of the batch output `(lambda_1_i, lambda_2_i)`.
@@ -48,38 +75,69 @@ impl BatchSwapOutputData {
         //   lambda_2_i = (delta_1_i / delta_1) * lambda_2   + (delta_2_i / delta_2) * unfilled_2
         //   lambda_1_i = (delta_1_i / delta_1) * unfilled_1 + (delta_2_i / delta_2) * lambda_1
 
-        let delta_1_i = U128x128::from(delta_1_i);
-        let delta_2_i = U128x128::from(delta_2_i);
-        let delta_1 = U128x128::from(self.delta_1);
-        let delta_2 = U128x128::from(self.delta_2);
+        let (pro_rata_input_1, pro_rata_input_2) = self.pro_rata_inputs((delta_1_i, delta_2_i));
         let lambda_1 = U128x128::from(self.lambda_1);
         let lambda_2 = U128x128::from(self.lambda_2);
         let unfilled_1 = U128x128::from(self.unfilled_1);
         let unfilled_2 = U128x128::from(self.unfilled_2);
 
-        // Compute the user i's share of the batch inputs of assets 1 and 2.
-        // The .unwrap_or_default ensures that when the batch input delta_1 is zero, all pro-rata shares of it are also zero.
-        let pro_rata_input_1 = (delta_1_i / delta_1).unwrap_or_default();
-        let pro_rata_input_2 = (delta_2_i / delta_2).unwrap_or_default();
-
         let lambda_2_i = (pro_rata_input_1 * lambda_2).unwrap_or_default()
             + (pro_rata_input_2 * unfilled_2).unwrap_or_default();
         let lambda_1_i = (pro_rata_input_1 * unfilled_1).unwrap_or_default()
             + (pro_rata_input_2 * lambda_1).unwrap_or_default();
 
         (
-            lambda_1_i
-                .unwrap_or_default()
-                .round_down()
-                .try_into()
-                .expect("rounded amount is integral"),
-            lambda_2_i
-                .unwrap_or_default()
-                .round_down()
-                .try_into()
-                .expect("rounded amount is integral"),
+            round_down_to_amount(lambda_1_i.unwrap_or_default()),
+            round_down_to_amount(lambda_2_i.unwrap_or_default()),
+        )
+    }
+
+    /// Given a user's inputs `(delta_1_i, delta_2_i)`, compute their pro rata share of the batch
+    /// output, split into the amounts received from fills and the amounts returned unfilled.
+    ///
+    /// The swap claim circuit only rounds the total output of each asset, so the unfilled amounts
+    /// absorb the rounding of the fills: the [`outputs`](ProRataShare::outputs) of the share are
+    /// always exactly those given by [`Self::pro_rata_outputs`], and those the circuit enforces.
+    pub fn pro_rata_share(&self, (delta_1_i, delta_2_i): (Amount, Amount)) -> ProRataShare {
+        let (output_1, output_2) = self.pro_rata_outputs((delta_1_i, delta_2_i));
+        let (pro_rata_input_1, pro_rata_input_2) = self.pro_rata_inputs((delta_1_i, delta_2_i));
+
+        let lambda_1 = round_down_to_amount(
+            (pro_rata_input_2 * U128x128::from(self.lambda_1)).unwrap_or_default(),
+        )
+        .min(output_1);
+        let lambda_2 = round_down_to_amount(
+            (pro_rata_input_1 * U128x128::from(self.lambda_2)).unwrap_or_default(),
         )
+        .min(output_2);
+
+        ProRataShare {
+            lambda_1,
+            lambda_2,
+            unfilled_1: output_1 - lambda_1,
+            unfilled_2: output_2 - lambda_2,
+        }
     }
+
+    /// Computes the user's share of the batch inputs of assets 1 and 2.
+    fn pro_rata_inputs(&self, (delta_1_i, delta_2_i): (Amount, Amount)) -> (U128x128, U128x128) {
+        let delta_1_i = U128x128::from(delta_1_i);
+        let delta_2_i = U128x128::from(delta_2_i);
+        let delta_1 = U128x128::from(self.delta_1);
+        let delta_2 = U128x128::from(self.delta_2);
+
+        // The .unwrap_or_default ensures that when the batch input delta_1 is zero, all pro-rata shares of it are also zero.
+        let pro_rata_input_1 = (delta_1_i / delta_1).unwrap_or_default();
+        let pro_rata_input_2 = (delta_2_i / delta_2).unwrap_or_default();
+        (pro_rata_input_1, pro_rata_input_2)
+    }
+}
+
+fn round_down_to_amount(value: U128x128) -> Amount {
+    value
+        .round_down()
+        .try_into()
+        .expect("rounded amount is integral")
 }
 
 impl ToConstraintField<Fq> for BatchSwapOutputData {
@@ -369,6 +427,124 @@ mod tests {
         assert_eq!(lambda_2_i, Amount::from(28766268u64));
     }
 
+    /// Returns the floor of `numerator / denominator`, or zero if the denominator is zero.
+    fn floor_div(numerator: u128, denominator: u128) -> u128 {
+        numerator.checked_div(denominator).unwrap_or_default()
+    }
+
+    #[test]
+    fn pro_rata_share_rounding() {
+        let trading_pair = TradingPair::new(
+            asset::Cache::with_known_assets()
+                .get_unit("gm")
+                .expect("gm is a known asset")
+                .id(),
+            asset::Cache::with_known_assets()
+                .get_unit("gn")
+                .expect("gn is a known asset")
+                .id(),
+        );
+        for delta_1 in 0..=6u128 {
+            for delta_2 in 0..=6u128 {
+                for lambda in 0..=7u128 {
+                    for unfilled in 0..=delta_1.max(delta_2) {
+                        // The batch fills 1 => 2 for `lambda` and 2 => 1 for `lambda + 1`, leaving
+                        // as much of each input unfilled as it can.
+                        let bsod = BatchSwapOutputData {
+                            delta_1: delta_1.into(),
+                            delta_2: delta_2.into(),
+                            lambda_1: (lambda + 1).into(),
+                            lambda_2: lambda.into(),
+                            unfilled_1: unfilled.min(delta_1).into(),
+                            unfilled_2: unfilled.min(delta_2).into(),
+                            height: 1,
+                            trading_pair,
+                            epoch_starting_height: 1,
+                        };
+                        for delta_1_i in 0..=delta_1 {
+                            for delta_2_i in 0..=delta_2 {
+                                check_pro_rata_share(&bsod, delta_1_i, delta_2_i);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks the share of a swap of `(delta_1_i, delta_2_i)` against exact arithmetic.
+    fn check_pro_rata_share(bsod: &BatchSwapOutputData, delta_1_i: u128, delta_2_i: u128) {
+        let inputs = (delta_1_i.into(), delta_2_i.into());
+        let share = bsod.pro_rata_share(inputs);
+        assert_eq!(
+            share.outputs(),
+            bsod.pro_rata_outputs(inputs),
+            "share of {inputs:?} of {bsod:?} doesn't match the circuit's outputs"
+        );
+
+        let (delta_1, delta_2) = (bsod.delta_1.value(), bsod.delta_2.value());
+        let exact_lambda_1 = floor_div(delta_2_i * bsod.lambda_1.value(), delta_2);
+        let exact_lambda_2 = floor_div(delta_1_i * bsod.lambda_2.value(), delta_1);
+        let exact_output_1 =
+            floor_div(delta_1_i * bsod.unfilled_1.value(), delta_1) + exact_lambda_1;
+        let exact_output_2 =
+            floor_div(delta_2_i * bsod.unfilled_2.value(), delta_2) + exact_lambda_2;
+
+        // Fixed-point division truncates, so each amount may be rounded down past the exact one,
+        // but never by more than one unit, and never up.
+        for (computed, exact) in [
+            (share.lambda_1.value(), exact_lambda_1),
+            (share.lambda_2.value(), exact_lambda_2),
+        ] {
+            assert!(
+                computed <= exact && exact - computed <= 1,
+                "share {share:?} of {inputs:?} of {bsod:?} rounds past {exact}"
+            );
+        }
+        // The outputs are only rounded once, after the fills and the unfilled amounts are summed,
+        // so they may also exceed the sum of the separately rounded exact amounts by one.
+        let (output_1, output_2) = share.outputs();
+        for (computed, exact) in [
+            (output_1.value(), exact_output_1),
+            (output_2.value(), exact_output_2),
+        ] {
+            assert!(
+                computed <= exact + 1 && computed + 1 >= exact,
+                "outputs of share {share:?} of {inputs:?} of {bsod:?} round past {exact}"
+            );
+        }
+
+        // A swap of the whole batch receives all of it, exactly.
+        if delta_1_i == delta_1 && delta_2_i == delta_2 {
+            assert_eq!(
+                share,
+                ProRataShare {
+                    lambda_1: if delta_2 == 0 {
+                        0u64.into()
+                    } else {
+                        bsod.lambda_1
+                    },
+                    lambda_2: if delta_1 == 0 {
+                        0u64.into()
+                    } else {
+                        bsod.lambda_2
+                    },
+                    unfilled_1: if delta_1 == 0 {
+                        0u64.into()
+                    } else {
+                        bsod.unfilled_1
+                    },
+                    unfilled_2: if delta_2 == 0 {
+                        0u64.into()
+                    } else {
+                        bsod.unfilled_2
+                    },
+                },
+                "swap of the whole batch {bsod:?} doesn't receive all of it"
+            );
+        }
+    }
+
     struct ProRataOutputCircuit {
         delta_1_i: Amount,
         delta_2_i: Amount,
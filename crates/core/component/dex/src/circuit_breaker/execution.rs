@@ -1,49 +1,132 @@
-const MAX_PATH_SEARCHES: u32 = 64;
-const MAX_EXECUTIONS: u32 = 64;
+/// The budget, in gas units, of a single batch swap execution.
+///
+/// With the default costs, this allows about as much work as the previous limits of 64 path
+/// searches and 64 two-hop fills.
+const EXECUTION_BUDGET: u64 = 12_800;
+
+/// The estimated cost, in gas units, of each kind of work done while executing a batch swap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionCosts {
+    /// The fixed cost of starting a path search.
+    pub path_search: u64,
+    /// The cost of each round of relaxation during a path search, which extends every
+    /// candidate path by one hop.
+    pub path_relaxation: u64,
+    /// The cost of each hop of a filled route.
+    pub hop: u64,
+    /// The cost of reading a position while filling a route.
+    pub position_read: u64,
+    /// The cost of writing back a position after filling against it.
+    pub position_write: u64,
+}
+
+impl Default for ExecutionCosts {
+    fn default() -> Self {
+        Self {
+            path_search: 50,
+            path_relaxation: 25,
+            hop: 10,
+            position_read: 5,
+            position_write: 10,
+        }
+    }
+}
 
 /// Holds the state of the execution circuit breaker.
 /// Responsible for managing the conditions of halting execution of
 /// a single batch swap. All execution circuit breaker triggers are
 /// non-fatal and will allow the swap to be partially fulfilled up
-/// to the search and execution limits managed by the circuit breaker.
+/// to the budget managed by the circuit breaker.
 ///
-/// The circuit breaker ensures the swap will not use unbounded time complexity.
+/// Path searches and fills are charged against the budget according to the [`ExecutionCosts`],
+/// so that heterogeneous work is limited by its total estimated cost rather than by separate
+/// counts. The circuit breaker ensures the swap will not use unbounded time complexity.
 #[derive(Debug, Clone)]
 pub struct ExecutionCircuitBreaker {
-    /// The maximum number of times to perform path searches before stopping.
-    pub max_path_searches: u32,
-    /// The number of times path searches have been performed.
-    pub current_path_searches: u32,
-    /// The maximum number of times to execute against liquidity positions before stopping.
-    pub max_executions: u32,
-    /// The number of times liquidity positions have been executed against.
-    pub current_executions: u32,
+    /// The cost of each kind of work.
+    pub costs: ExecutionCosts,
+    /// The total gas that may be spent before stopping.
+    pub budget: u64,
+    /// The gas spent after which path searches settle for the first path they find, rather
+    /// than searching up to the maximum number of hops.
+    pub settle_after: u64,
+    /// The gas spent so far.
+    pub spent: u64,
 }
 
 impl ExecutionCircuitBreaker {
-    #[allow(dead_code)]
-    pub fn new(max_path_searches: u32, max_executions: u32) -> Self {
+    /// Creates a circuit breaker with the given costs and budget, which settles for shallower
+    /// paths once half of the budget is spent.
+    pub fn new(costs: ExecutionCosts, budget: u64) -> Self {
         Self {
-            max_path_searches,
-            current_path_searches: 0,
-            max_executions,
-            current_executions: 0,
+            costs,
+            budget,
+            settle_after: budget / 2,
+            spent: 0,
         }
     }
 
     pub fn exceeded_limits(&self) -> bool {
-        self.current_path_searches > self.max_path_searches
-            || self.current_executions > self.max_executions
+        self.spent >= self.budget
+    }
+
+    /// Whether path searches should settle for the best path found so far.
+    pub fn should_settle(&self) -> bool {
+        self.spent >= self.settle_after
+    }
+
+    /// Charges for starting a path search.
+    pub fn charge_path_search(&mut self) {
+        self.charge(self.costs.path_search);
+    }
+
+    /// Charges for a round of relaxation during a path search.
+    pub fn charge_path_relaxation(&mut self) {
+        self.charge(self.costs.path_relaxation);
+    }
+
+    /// Charges for filling a route of `hops` hops, which executed against `positions`
+    /// positions in total.
+    pub fn charge_fill(&mut self, hops: usize, positions: usize) {
+        let ExecutionCosts {
+            hop,
+            position_read,
+            position_write,
+            ..
+        } = self.costs;
+        self.charge(hop.saturating_mul(hops as u64));
+        self.charge((position_read + position_write).saturating_mul(positions as u64));
+    }
+
+    fn charge(&mut self, gas: u64) {
+        self.spent = self.spent.saturating_add(gas);
     }
 }
 
 impl Default for ExecutionCircuitBreaker {
     fn default() -> Self {
-        Self {
-            max_path_searches: MAX_PATH_SEARCHES,
-            current_path_searches: 0,
-            max_executions: MAX_EXECUTIONS,
-            current_executions: 0,
-        }
+        Self::new(ExecutionCosts::default(), EXECUTION_BUDGET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charges_work_against_the_budget() {
+        let mut breaker = ExecutionCircuitBreaker::new(ExecutionCosts::default(), 200);
+
+        breaker.charge_path_search();
+        breaker.charge_path_relaxation();
+        breaker.charge_path_relaxation();
+        assert_eq!(breaker.spent, 100);
+        assert!(breaker.should_settle());
+        assert!(!breaker.exceeded_limits());
+
+        // Two hops, executing against one position on each of three passes.
+        breaker.charge_fill(2, 6);
+        assert_eq!(breaker.spent, 100 + 20 + 90);
+        assert!(breaker.exceeded_limits());
     }
 }
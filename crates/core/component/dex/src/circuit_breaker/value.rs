@@ -12,6 +12,12 @@ impl ValueCircuitBreaker {
         self.balance += balance;
     }
 
+    /// Tallies the changes from `before` to `after`, which is how the batch swaps of independent
+    /// trading pairs, executed against separate forks of the state, are combined.
+    pub fn tally_changes(&mut self, before: &Self, after: &Self) {
+        self.balance += after.balance.clone() - before.balance.clone();
+    }
+
     pub fn check(&self) -> anyhow::Result<()> {
         // No assets should ever be "required" by the circuit breaker's
         // internal balance tracking, only "provided".
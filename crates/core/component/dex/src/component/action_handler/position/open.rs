@@ -7,7 +7,7 @@ use cnidarium_component::ActionHandler;
 use penumbra_proto::StateWriteProto as _;
 
 use crate::{
-    component::{PositionManager, PositionRead},
+    component::{PositionManager, PositionRead, StateReadExt as _},
     event,
     lp::{action::PositionOpen, position},
 };
@@ -32,7 +32,15 @@ impl ActionHandler for PositionOpen {
         Ok(())
     }
 
-    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // Opening positions is paused while the dex is paused, but closing and withdrawing
+        // existing positions is not.
+        state.check_dex_not_paused().await?;
+        // Reject dust positions, and fees outside the bounds set by governance.
+        state
+            .get_dex_params()
+            .await?
+            .check_position_open(&self.position)?;
         Ok(())
     }
 
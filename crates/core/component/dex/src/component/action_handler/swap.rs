@@ -35,8 +35,9 @@ impl ActionHandler for Swap {
         Ok(())
     }
 
-    async fn check_stateful<S: StateRead + 'static>(&self, _state: Arc<S>) -> Result<()> {
-        Ok(())
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        // Swapping is paused while the dex is paused, but claiming existing swaps is not.
+        state.check_dex_not_paused().await
     }
 
     async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
@@ -52,6 +53,17 @@ impl ActionHandler for Swap {
         swap_flow.0 += swap.body.delta_1_i;
         swap_flow.1 += swap.body.delta_2_i;
 
+        // Tally the swap's routing preference, falling back to the chain's default.
+        let multi_hop_penalty_bps = match swap.body.routing_preference {
+            Some(preference) => preference.multi_hop_penalty_bps,
+            None => state.get_dex_params().await?.default_multi_hop_penalty_bps,
+        };
+        swap_flow.add_multi_hop_penalty(
+            swap.body.delta_1_i,
+            swap.body.delta_2_i,
+            multi_hop_penalty_bps,
+        );
+
         // Set the batch swap flow for the trading pair.
         state.put_swap_flow(&swap.body.trading_pair, swap_flow);
 
@@ -43,6 +43,7 @@ pub trait Arbitrage: StateWrite + Sized {
             max_hops: 5,
             price_limit: Some(1u64.into()),
             fixed_candidates: Arc::new(fixed_candidates),
+            multi_hop_penalty_bps: 0,
         };
 
         // Create a flash-loan 2^64 of the arb token to ourselves.
@@ -1,4 +1,8 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use anyhow::Result;
 use async_trait::async_trait;
@@ -12,23 +16,38 @@ use tendermint::v0_37::abci;
 use tracing::instrument;
 
 use crate::{
-    component::flow::SwapFlow, event, state_key, BatchSwapOutputData, DirectedTradingPair,
-    SwapExecution, TradingPair,
+    component::flow::SwapFlow, event, genesis, state_key, BatchSwapOutputData, DexParameters,
+    DirectedTradingPair, SwapExecution, TradingPair,
 };
 
-use super::{
-    router::{HandleBatchSwaps, RoutingParams},
-    Arbitrage, PositionManager,
-};
+use super::{pair_stats::PairStatsWrite, router::execute_batch_swaps, Arbitrage, PositionManager};
 
 pub struct Dex {}
 
+/// How long each phase of executing the DEX took at the end of the block.
+#[derive(Clone, Debug, Default)]
+pub struct ExecutionTimings {
+    /// The time spent executing each trading pair's batch swaps.
+    pub batches: Vec<(TradingPair, Duration)>,
+    /// The time spent searching for and executing arbitrage.
+    pub arbitrage: Duration,
+    /// The time spent closing the positions queued for closure.
+    pub position_closures: Duration,
+}
+
 #[async_trait]
 impl Component for Dex {
-    type AppState = ();
+    type AppState = genesis::Content;
 
-    #[instrument(name = "dex", skip(_state, _app_state))]
-    async fn init_chain<S: StateWrite>(_state: S, _app_state: Option<&()>) {}
+    #[instrument(name = "dex", skip(state, app_state))]
+    async fn init_chain<S: StateWrite>(mut state: S, app_state: Option<&Self::AppState>) {
+        match app_state {
+            None => { /* Checkpoint -- no-op */ }
+            Some(genesis) => {
+                state.put_dex_params(genesis.dex_params.clone());
+            }
+        }
+    }
 
     #[instrument(name = "dex", skip(_state, _begin_block))]
     async fn begin_block<S: StateWrite + 'static>(
@@ -43,32 +62,25 @@ impl Component for Dex {
         end_block: &abci::request::EndBlock,
     ) {
         let current_epoch = state.get_current_epoch().await.expect("epoch is set");
+        let mut timings = ExecutionTimings::default();
 
         // For each batch swap during the block, calculate clearing prices and set in the JMT.
-        for (trading_pair, swap_flows) in state.swap_flows() {
-            let batch_start = std::time::Instant::now();
-            state
-                .handle_batch_swaps(
-                    trading_pair,
-                    swap_flows,
-                    end_block
-                        .height
-                        .try_into()
-                        .expect("height is part of the end block data"),
-                    current_epoch.start_height,
-                    // Always include both ends of the target pair as fixed candidates.
-                    RoutingParams::default_with_extra_candidates([
-                        trading_pair.asset_1(),
-                        trading_pair.asset_2(),
-                    ]),
-                )
-                .await
-                .expect("handling batch swaps is infaillible");
-            metrics::histogram!(crate::component::metrics::DEX_BATCH_DURATION)
-                .record(batch_start.elapsed());
-        }
+        // Trading pairs which can't affect each other's execution are handled concurrently.
+        let swap_flows = state.swap_flows();
+        timings.batches = execute_batch_swaps(
+            state,
+            swap_flows,
+            end_block
+                .height
+                .try_into()
+                .expect("height is part of the end block data"),
+            current_epoch.start_height,
+        )
+        .await
+        .expect("handling batch swaps is infaillible");
 
         // Then, perform arbitrage:
+        let arb_start = Instant::now();
         let arb_burn = match state
             .arbitrage(
                 *STAKING_TOKEN_ASSET_ID,
@@ -113,6 +125,7 @@ impl Component for Dex {
                 }
             }
         };
+        timings.arbitrage = arb_start.elapsed();
 
         if arb_burn.amount != 0u64.into() {
             // TODO: hack to avoid needing an asset cache for nice debug output
@@ -123,12 +136,38 @@ impl Component for Dex {
             tracing::info!(%burn, "executed arbitrage opportunity");
         }
 
-        // Next, close all positions queued for closure at the end of the block.
-        // It's important to do this after execution, to allow block-scoped JIT liquidity.
+        // Queue the closure of positions whose reserves fell below their auto-close thresholds
+        // during execution, so that they're closed along with the other queued positions.
         Arc::get_mut(state)
             .expect("state should be uniquely referenced after batch swaps complete")
-            .close_queued_positions()
-            .await;
+            .queue_auto_closes()
+            .await
+            .expect("queuing auto-closes is infallible");
+
+        // Next, close all positions queued for closure at the end of the block.
+        // It's important to do this after execution, to allow block-scoped JIT liquidity.
+        let state = Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete");
+        let closures_start = Instant::now();
+        state.close_queued_positions().await;
+        timings.position_closures = closures_start.elapsed();
+
+        state
+            .prune_position_updates()
+            .await
+            .expect("pruning position updates is infallible");
+
+        // Record the fees paid to positions by the arbitrage fills.
+        state
+            .record_pair_stats(None)
+            .await
+            .expect("recording pair statistics is infallible");
+
+        #[cfg(feature = "rounding-audit")]
+        super::router::rounding_audit::log_block_summary(state);
+
+        // Leave the timings for block profiling to pick up.
+        state.object_put(state_key::execution_timings(), timings);
     }
 
     #[instrument(name = "dex", skip(_state))]
@@ -140,6 +179,33 @@ impl Component for Dex {
 /// Extension trait providing read access to dex data.
 #[async_trait]
 pub trait StateReadExt: StateRead {
+    /// Gets the DEX parameters from the state.
+    ///
+    /// Chains upgraded from a version without DEX parameters have none stored until governance
+    /// sets them, so they use the defaults, which pause nothing and bound nothing.
+    async fn get_dex_params(&self) -> Result<DexParameters> {
+        Ok(self.get(state_key::dex_params()).await?.unwrap_or_default())
+    }
+
+    /// How long each phase of executing the DEX took, once the end of the block was reached.
+    fn execution_timings(&self) -> Option<ExecutionTimings> {
+        self.object_get(state_key::execution_timings())
+    }
+
+    /// Indicates if the DEX parameters have been updated in this block.
+    fn dex_params_updated(&self) -> bool {
+        self.object_get::<()>(state_key::dex_params_updated())
+            .is_some()
+    }
+
+    /// Errors if opening positions and swapping have been paused by governance.
+    async fn check_dex_not_paused(&self) -> Result<()> {
+        if self.get_dex_params().await?.is_paused {
+            anyhow::bail!("the dex is paused: new positions and swaps are not accepted");
+        }
+        Ok(())
+    }
+
     async fn output_data(
         &self,
         height: u64,
@@ -183,6 +249,13 @@ impl<T: StateRead + ?Sized> StateReadExt for T {}
 /// Extension trait providing write access to dex data.
 #[async_trait]
 pub trait StateWriteExt: StateWrite + StateReadExt {
+    /// Sets the DEX parameters in the state.
+    fn put_dex_params(&mut self, params: DexParameters) {
+        self.object_put(state_key::dex_params_updated(), ());
+        self.put(state_key::dex_params().into(), params.clone());
+        self.record_proto(event::dex_params_update(params));
+    }
+
     fn set_output_data(
         &mut self,
         output_data: BatchSwapOutputData,
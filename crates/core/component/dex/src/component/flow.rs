@@ -2,22 +2,74 @@ use std::ops::{Deref, DerefMut};
 
 use penumbra_num::Amount;
 
-// Tuple represents:
-// ((amount of asset 1 being exchanged for asset 2),
-//  (amount of asset 2 being exchanged for asset 1))
+/// The swaps into a trading pair's batch, in each direction.
+///
+/// Dereferences to the inputs, the amount of asset 1 being exchanged for asset 2 and the amount
+/// of asset 2 being exchanged for asset 1.
 #[derive(Default, Clone)]
-pub struct SwapFlow((Amount, Amount));
+pub struct SwapFlow {
+    /// The amount of asset 1 being exchanged for asset 2, and of asset 2 for asset 1.
+    inputs: (Amount, Amount),
+    /// The multi-hop penalties of the swaps in each direction, in basis points, weighted by their
+    /// inputs.
+    weighted_penalties: (u128, u128),
+}
+
+impl SwapFlow {
+    /// Records the multi-hop penalty of a swap with the given inputs.
+    pub fn add_multi_hop_penalty(&mut self, delta_1: Amount, delta_2: Amount, bps: u32) {
+        let bps = u128::from(bps);
+        self.weighted_penalties.0 = self
+            .weighted_penalties
+            .0
+            .saturating_add(delta_1.value().saturating_mul(bps));
+        self.weighted_penalties.1 = self
+            .weighted_penalties
+            .1
+            .saturating_add(delta_2.value().saturating_mul(bps));
+    }
+
+    /// The multi-hop penalty to route each direction with, in basis points, averaging the
+    /// penalties of the swaps in that direction weighted by their inputs.
+    pub fn multi_hop_penalty_bps(&self) -> (u32, u32) {
+        let average = |weighted: u128, input: Amount| {
+            weighted
+                .checked_div(input.value())
+                .and_then(|bps| u32::try_from(bps).ok())
+                .unwrap_or_default()
+        };
+        (
+            average(self.weighted_penalties.0, self.inputs.0),
+            average(self.weighted_penalties.1, self.inputs.1),
+        )
+    }
+}
 
 impl Deref for SwapFlow {
     type Target = (Amount, Amount);
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.inputs
     }
 }
 
 impl DerefMut for SwapFlow {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_hop_penalties_are_averaged_by_input() {
+        let mut flow = SwapFlow::default();
+        for (delta_1, bps) in [(3u64, 100), (1u64, 500)] {
+            flow.0 += delta_1.into();
+            flow.add_multi_hop_penalty(delta_1.into(), 0u64.into(), bps);
+        }
+        assert_eq!(flow.multi_hop_penalty_bps(), (200, 0));
     }
 }
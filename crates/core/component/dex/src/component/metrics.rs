@@ -25,6 +25,11 @@ pub fn register_metrics() {
         Unit::Seconds,
         "The time spent executing batches within the DEX"
     );
+    describe_histogram!(
+        DEX_BATCH_GROUPS,
+        Unit::Count,
+        "The number of independent groups of trading pairs the batches were executed in"
+    );
     describe_histogram!(
         DEX_PATH_SEARCH_DURATION,
         Unit::Seconds,
@@ -52,4 +57,5 @@ pub const DEX_PATH_SEARCH_DURATION: &str = "penumbra_dex_path_search_duration_se
 pub const DEX_ROUTE_FILL_DURATION: &str = "penumbra_dex_route_fill_duration_seconds";
 pub const DEX_ARB_DURATION: &str = "penumbra_dex_arb_duration_seconds";
 pub const DEX_BATCH_DURATION: &str = "penumbra_dex_batch_duration_seconds";
+pub const DEX_BATCH_GROUPS: &str = "penumbra_dex_batch_groups";
 pub const DEX_SWAP_DURATION: &str = "penumbra_dex_swap_duration_seconds";
@@ -10,12 +10,14 @@ mod action_handler;
 mod arb;
 mod dex;
 mod flow;
+pub(crate) mod pair_stats;
 pub(crate) mod position_manager;
 mod swap_manager;
 
 pub use self::metrics::register_metrics;
 pub use arb::Arbitrage;
-pub use dex::{Dex, StateReadExt, StateWriteExt};
+pub use dex::{Dex, ExecutionTimings, StateReadExt, StateWriteExt};
+pub use pair_stats::PairStatsRead;
 pub use position_manager::{PositionManager, PositionRead};
 pub use swap_manager::SwapManager;
 
@@ -0,0 +1,253 @@
+//! Rolling statistics of the trading on each pair, so that clients can show volumes and fees
+//! without summing up every batch swap themselves.
+//!
+//! The statistics aren't part of consensus, so they're kept in nonverifiable storage, as a bucket
+//! for each hour of the last 24 hours and a running total for the current epoch. A pair's volume
+//! is recorded when its batch swaps are executed. Fees are recorded by each hop of a fill, since
+//! a route can cross pairs other than the one being swapped, and are added to the statistics of
+//! the pairs that earned them once the batch, or the block's arbitrage, is done.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use penumbra_num::Amount;
+use penumbra_sct::component::clock::EpochRead;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    state_key, BatchSwapOutputData, DirectedTradingPair, PairStats, PairStatsWindow, TradingPair,
+};
+
+/// The length of the rolling window, in hours.
+const WINDOW_HOURS: u64 = 24;
+
+/// The fees paid to the liquidity positions of each pair, in each of the pair's assets.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct LpFees(BTreeMap<TradingPair, (Amount, Amount)>);
+
+impl LpFees {
+    /// Records the fee paid by a hop along `pair` that traded `input`, to a position charging
+    /// `fee_bps` basis points.
+    pub(crate) fn add_hop(&mut self, pair: DirectedTradingPair, input: Amount, fee_bps: u32) {
+        // Reserves are at most 112 bits wide and fees at most 50%, so this can't overflow.
+        let fee = Amount::from(input.value() * u128::from(fee_bps) / 10_000);
+        let trading_pair = TradingPair::from(pair);
+        let fees = self.0.entry(trading_pair).or_default();
+        if pair.start == trading_pair.asset_1() {
+            fees.0 += fee;
+        } else {
+            fees.1 += fee;
+        }
+    }
+
+    fn extend(&mut self, other: LpFees) {
+        for (trading_pair, (fee_1, fee_2)) in other.0 {
+            let fees = self.0.entry(trading_pair).or_default();
+            fees.0 += fee_1;
+            fees.1 += fee_2;
+        }
+    }
+}
+
+/// The statistics of a pair, as stored.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+struct PairStatsRecord {
+    /// The trading in each hour of the rolling window, by hours since the Unix epoch.
+    hours: BTreeMap<u64, PairStatsWindow>,
+    /// The starting height of the epoch `epoch` is the trading in.
+    epoch_starting_height: u64,
+    epoch: PairStatsWindow,
+}
+
+impl PairStatsRecord {
+    /// Drops the hours that have left the rolling window as of `hour`, and the epoch's trading if
+    /// the epoch starting at `epoch_starting_height` is a new one.
+    fn expire(&mut self, hour: u64, epoch_starting_height: u64) {
+        self.hours.retain(|h, _| *h + WINDOW_HOURS > hour);
+        if self.epoch_starting_height != epoch_starting_height {
+            self.epoch_starting_height = epoch_starting_height;
+            self.epoch = PairStatsWindow::default();
+        }
+    }
+
+    fn add(&mut self, hour: u64, epoch_starting_height: u64, trading: PairStatsWindow) {
+        self.expire(hour, epoch_starting_height);
+        *self.hours.entry(hour).or_default() += trading;
+        self.epoch += trading;
+    }
+
+    fn stats(
+        mut self,
+        trading_pair: TradingPair,
+        hour: u64,
+        epoch_starting_height: u64,
+    ) -> PairStats {
+        self.expire(hour, epoch_starting_height);
+        let mut last_24h = PairStatsWindow::default();
+        for trading in self.hours.into_values() {
+            last_24h += trading;
+        }
+        PairStats {
+            trading_pair,
+            last_24h,
+            epoch: self.epoch,
+        }
+    }
+}
+
+async fn get_record<S: StateRead + ?Sized>(
+    state: &S,
+    trading_pair: &TradingPair,
+) -> Result<PairStatsRecord> {
+    Ok(
+        match state
+            .nonverifiable_get_raw(state_key::pair_stats(trading_pair).as_bytes())
+            .await?
+        {
+            Some(bytes) => serde_json::from_slice(&bytes)?,
+            None => PairStatsRecord::default(),
+        },
+    )
+}
+
+/// Returns the hour of the current block, counted from the Unix epoch, and the starting height
+/// of the current epoch.
+async fn current_period<S: StateRead + ?Sized>(state: &S) -> Result<(u64, u64)> {
+    let timestamp = state.get_block_timestamp().await?;
+    let hour = u64::try_from(timestamp.unix_timestamp() / (60 * 60))?;
+    let epoch = state.get_current_epoch().await?;
+    Ok((hour, epoch.start_height))
+}
+
+/// Extension trait providing read access to the statistics of each pair.
+#[async_trait]
+pub trait PairStatsRead: StateRead {
+    /// The trading on `trading_pair` in the 24 hours up to the current block, and in the current
+    /// epoch.
+    async fn pair_stats(&self, trading_pair: TradingPair) -> Result<PairStats> {
+        let record = get_record(self, &trading_pair).await?;
+        let (hour, epoch_starting_height) = current_period(self).await?;
+        Ok(record.stats(trading_pair, hour, epoch_starting_height))
+    }
+}
+
+impl<T: StateRead + ?Sized> PairStatsRead for T {}
+
+/// Extension trait providing write access to the statistics of each pair.
+#[async_trait]
+pub(crate) trait PairStatsWrite: StateWrite {
+    /// Sets aside `fees` to be added to the statistics by [`Self::record_pair_stats`].
+    fn put_pending_lp_fees(&mut self, fees: LpFees) {
+        let mut pending: LpFees = self
+            .object_get(state_key::pending_lp_fees())
+            .unwrap_or_default();
+        pending.extend(fees);
+        self.object_put(state_key::pending_lp_fees(), pending);
+    }
+
+    /// Adds the volume of `batch`, if there is one, and the pending fees to the statistics of
+    /// the pairs they're for.
+    async fn record_pair_stats(&mut self, batch: Option<&BatchSwapOutputData>) -> Result<()> {
+        let fees: LpFees = self
+            .object_get(state_key::pending_lp_fees())
+            .unwrap_or_default();
+        self.object_delete(state_key::pending_lp_fees());
+
+        let mut trading: BTreeMap<TradingPair, PairStatsWindow> = fees
+            .0
+            .into_iter()
+            .map(|(trading_pair, (lp_fees_1, lp_fees_2))| {
+                let window = PairStatsWindow {
+                    lp_fees_1,
+                    lp_fees_2,
+                    ..Default::default()
+                };
+                (trading_pair, window)
+            })
+            .collect();
+        if let Some(batch) = batch {
+            let window = trading.entry(batch.trading_pair).or_default();
+            window.volume_1 += (batch.delta_1 - batch.unfilled_1) + batch.lambda_1;
+            window.volume_2 += (batch.delta_2 - batch.unfilled_2) + batch.lambda_2;
+            window.batches += 1;
+        }
+        if trading.is_empty() {
+            return Ok(());
+        }
+
+        let (hour, epoch_starting_height) = current_period(self).await?;
+        for (trading_pair, window) in trading {
+            let mut record = get_record(self, &trading_pair).await?;
+            record.add(hour, epoch_starting_height, window);
+            self.nonverifiable_put_raw(
+                state_key::pair_stats(&trading_pair).as_bytes().to_vec(),
+                serde_json::to_vec(&record)?,
+            );
+        }
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> PairStatsWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+
+    use super::*;
+
+    fn pair() -> TradingPair {
+        let cache = asset::Cache::with_known_assets();
+        TradingPair::new(
+            cache.get_unit("gm").expect("gm is known").id(),
+            cache.get_unit("gn").expect("gn is known").id(),
+        )
+    }
+
+    fn batch() -> PairStatsWindow {
+        PairStatsWindow {
+            volume_1: 10u64.into(),
+            batches: 1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn old_hours_and_epochs_expire() {
+        let mut record = PairStatsRecord::default();
+        record.add(100, 0, batch());
+        record.add(110, 0, batch());
+        record.add(110, 50, batch());
+
+        let stats = record.clone().stats(pair(), 110, 50);
+        assert_eq!(stats.last_24h.batches, 3);
+        assert_eq!(stats.last_24h.volume_1, 30u64.into());
+        assert_eq!(stats.epoch.batches, 1);
+
+        let stats = record.clone().stats(pair(), 124, 50);
+        assert_eq!(stats.last_24h.batches, 2);
+
+        let stats = record.stats(pair(), 134, 90);
+        assert_eq!(stats.last_24h.batches, 0);
+        assert_eq!(stats.epoch.batches, 0);
+    }
+
+    #[test]
+    fn hop_fees_are_recorded_in_the_input_asset() {
+        let pair = pair();
+        let mut fees = LpFees::default();
+        fees.add_hop(
+            DirectedTradingPair::new(pair.asset_1(), pair.asset_2()),
+            10_000u64.into(),
+            30,
+        );
+        fees.add_hop(
+            DirectedTradingPair::new(pair.asset_2(), pair.asset_1()),
+            1_000u64.into(),
+            30,
+        );
+        assert_eq!(fees.0[&pair], (30u64.into(), 3u64.into()));
+    }
+}
@@ -7,20 +7,27 @@ use async_trait::async_trait;
 use cnidarium::{EscapedByteSlice, StateRead, StateWrite};
 use futures::Stream;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use penumbra_asset::{asset, Balance, Value};
 use penumbra_num::Amount;
 use penumbra_proto::DomainType;
 use penumbra_proto::{StateReadProto, StateWriteProto};
+use penumbra_sct::component::clock::EpochRead;
 
 use crate::circuit_breaker::ValueCircuitBreaker;
 use crate::lp::position::State;
 use crate::{
+    event,
     lp::position::{self, Position},
     state_key, DirectedTradingPair,
 };
 
 const DYNAMIC_ASSET_LIMIT: usize = 10;
 
+/// How many blocks' worth of position updates are kept, for incremental exports of the book.
+/// Mirrors that fall further behind than this must export the book in full again.
+pub(crate) const POSITION_UPDATE_RETENTION_BLOCKS: u64 = 1_000;
+
 #[async_trait]
 pub trait PositionRead: StateRead {
     /// Return a stream of all [`position::Metadata`] available.
@@ -57,6 +64,26 @@ pub trait PositionRead: StateRead {
             .boxed()
     }
 
+    /// Returns a stream of the [`position::Id`]s of positions updated after `height`, in the order
+    /// they were updated. Positions updated in several blocks appear once for each block.
+    fn positions_updated_since(
+        &self,
+        height: u64,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<position::Id>> + Send + 'static>>> {
+        let prefix = state_key::internal::position_updates::prefix();
+        let start = state_key::internal::position_updates::start(height.saturating_add(1));
+        Ok(self
+            .nonverifiable_range_raw(Some(prefix), start..)?
+            .map(|entry| match entry {
+                Ok((k, _)) => {
+                    let raw_id = <&[u8; 32]>::try_from(&k[15..47])?.to_owned();
+                    Ok(position::Id(raw_id))
+                }
+                Err(e) => Err(e),
+            })
+            .boxed())
+    }
+
     async fn position_by_id(&self, id: &position::Id) -> Result<Option<position::Position>> {
         self.get(&state_key::position_by_id(id)).await
     }
@@ -114,6 +141,55 @@ pub trait PositionManager: StateWrite + PositionRead {
         self.object_put(state_key::pending_position_closures(), to_close);
     }
 
+    /// Queues the closure of every open position updated in this block whose reserves fell below
+    /// its auto-close thresholds.
+    async fn queue_auto_closes(&mut self) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let pending = self.pending_position_closures();
+        let mut updated: Vec<position::Id> = self
+            .positions_updated_since(height.saturating_sub(1))?
+            .try_collect()
+            .await?;
+        updated.sort();
+        updated.dedup();
+
+        for id in updated {
+            if pending.contains(&id) {
+                continue;
+            }
+            let Some(position) = self.position_by_id(&id).await? else {
+                continue;
+            };
+            if position.state == position::State::Opened && position.is_below_close_threshold() {
+                tracing::debug!(?id, "reserves below auto-close thresholds, queuing closure");
+                self.queue_close_position(id);
+                self.record_proto(event::position_auto_close(&id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Forgets the positions updated more than [`POSITION_UPDATE_RETENTION_BLOCKS`] blocks ago,
+    /// so that the index of updates doesn't grow without bound.
+    async fn prune_position_updates(&mut self) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let Some(cutoff) = height.checked_sub(POSITION_UPDATE_RETENTION_BLOCKS) else {
+            return Ok(());
+        };
+        let stale: Vec<Vec<u8>> = self
+            .nonverifiable_range_raw(
+                Some(state_key::internal::position_updates::prefix()),
+                ..state_key::internal::position_updates::start(cutoff),
+            )?
+            .map_ok(|(k, _)| k)
+            .try_collect()
+            .await?;
+        for key in stale {
+            self.nonverifiable_delete(key);
+        }
+        Ok(())
+    }
+
     /// Close all positions that have been queued for closure.
     async fn close_queued_positions(&mut self) -> () {
         let to_close = self.pending_position_closures();
@@ -162,6 +238,14 @@ pub trait PositionManager: StateWrite + PositionRead {
         self.update_position_aggregate_value(&position, &prev)
             .await?;
 
+        // Record that the position changed in this block, for incremental exports of the book.
+        // The block height is only missing in tests that manipulate positions directly.
+        let height = self.get_block_height().await.unwrap_or_default();
+        self.nonverifiable_put_raw(
+            state_key::internal::position_updates::key(height, &id),
+            vec![],
+        );
+
         self.put(state_key::position_by_id(&id), position);
         Ok(())
     }
@@ -256,12 +340,23 @@ pub(crate) trait Inner: StateWrite {
     fn index_position_by_price(&mut self, position: &position::Position) {
         let (pair, phi) = (position.phi.pair, &position.phi);
         let id = position.id();
-        if position.reserves.r2 != 0u64.into() {
+        let pair12 = DirectedTradingPair {
+            start: pair.asset_1(),
+            end: pair.asset_2(),
+        };
+        let pair21 = DirectedTradingPair {
+            start: pair.asset_2(),
+            end: pair.asset_1(),
+        };
+        let usable_r1 = position
+            .usable_reserves_for(pair.asset_1())
+            .expect("position has reserves of its own assets");
+        let usable_r2 = position
+            .usable_reserves_for(pair.asset_2())
+            .expect("position has reserves of its own assets");
+
+        if usable_r2 != 0u64.into() {
             // Index this position for trades FROM asset 1 TO asset 2, since the position has asset 2 to give out.
-            let pair12 = DirectedTradingPair {
-                start: pair.asset_1(),
-                end: pair.asset_2(),
-            };
             let phi12 = phi.component.clone();
             self.nonverifiable_put_raw(
                 state_key::internal::price_index::key(&pair12, &phi12, &id),
@@ -270,12 +365,8 @@ pub(crate) trait Inner: StateWrite {
             tracing::debug!("indexing position for 1=>2 trades");
         }
 
-        if position.reserves.r1 != 0u64.into() {
+        if usable_r1 != 0u64.into() {
             // Index this position for trades FROM asset 2 TO asset 1, since the position has asset 1 to give out.
-            let pair21 = DirectedTradingPair {
-                start: pair.asset_2(),
-                end: pair.asset_1(),
-            };
             let phi21 = phi.component.flip();
             self.nonverifiable_put_raw(
                 state_key::internal::price_index::key(&pair21, &phi21, &id),
@@ -303,6 +394,7 @@ pub(crate) trait Inner: StateWrite {
     }
 
     /// Updates the nonverifiable liquidity indices given a [`Position`] in the direction specified by the [`DirectedTradingPair`].
+    /// Reserves too small to be traded for don't count towards the available liquidity.
     /// An [`Option<Position>`] may be specified to allow for the case where a position is being updated.
     async fn update_liquidity_index(
         &mut self,
@@ -333,7 +425,7 @@ pub(crate) trait Inner: StateWrite {
                 // Use the new reserves to compute `new_position_contribution`,
                 // the amount of asset A contributed by the position (i.e. the reserves of asset A).
                 let new_position_contribution = position
-                    .reserves_for(pair.start)
+                    .usable_reserves_for(pair.start)
                     .expect("specified position should match provided trading pair");
 
                 // Compute `new_A_from_B`.
@@ -364,13 +456,13 @@ pub(crate) trait Inner: StateWrite {
 
                 // Use the previous reserves to compute `prev_position_contribution` (denominated in asset_1).
                 let prev_position_contribution = prev
-                    .reserves_for(pair.start)
+                    .usable_reserves_for(pair.start)
                     .expect("specified position should match provided trading pair");
 
                 // Use the new reserves to compute `new_position_contribution`,
                 // the amount of asset A contributed by the position (i.e. the reserves of asset A).
                 let new_position_contribution = position
-                    .reserves_for(pair.start)
+                    .usable_reserves_for(pair.start)
                     .expect("specified position should match provided trading pair");
 
                 // Compute `new_A_from_B`.
@@ -402,7 +494,7 @@ pub(crate) trait Inner: StateWrite {
 
                 // Use the previous reserves to compute `prev_position_contribution` (denominated in asset_1).
                 let prev_position_contribution = prev
-                    .reserves_for(pair.start)
+                    .usable_reserves_for(pair.start)
                     .expect("specified position should match provided trading pair");
 
                 // Compute `new_A_from_B`.
@@ -16,7 +16,11 @@ use penumbra_proto::StateWriteProto as _;
 use tracing::instrument;
 
 use crate::{
-    component::{metrics, PositionManager, PositionRead},
+    component::{
+        metrics,
+        pair_stats::{LpFees, PairStatsWrite},
+        PositionManager, PositionRead,
+    },
     event,
     lp::{
         position::{self, Position},
@@ -300,6 +304,8 @@ struct Frontier<S> {
     pub positions_by_price: PositionsByPrice,
     /// A trace of the execution along the route.
     pub trace: Vec<Vec<Value>>,
+    /// The fees paid to the positions along the route.
+    pub lp_fees: LpFees,
 }
 
 struct FrontierTx {
@@ -398,6 +404,7 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             state,
             positions_by_price,
             trace,
+            lp_fees: LpFees::default(),
         })
     }
 
@@ -409,6 +416,8 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             self.state
                 .record_proto(event::position_execution(position.clone()));
         }
+        self.state
+            .put_pending_lp_fees(std::mem::take(&mut self.lp_fees));
         Ok(())
     }
 
@@ -426,6 +435,20 @@ impl<S: StateRead + StateWrite> Frontier<S> {
                 new_reserves.expect("all new reserves must be set when applying changes");
             let amount =
                 changes.trace[i + 1].expect("all trace amounts must be set when applying changes");
+            self.lp_fees.add_hop(
+                self.pairs[i],
+                changes.trace[i].expect("all trace amounts must be set when applying changes"),
+                self.positions[i].phi.component.fee,
+            );
+            #[cfg(feature = "rounding-audit")]
+            super::rounding_audit::audit_hop(
+                &mut self.state,
+                &self.positions[i],
+                self.pairs[i],
+                changes.trace[i].expect("all trace amounts must be set when applying changes"),
+                amount,
+                &new_reserves,
+            );
             self.positions[i].reserves = new_reserves;
             // Pull the asset ID from the pairs.
             trace.push(Value {
@@ -434,6 +457,17 @@ impl<S: StateRead + StateWrite> Frontier<S> {
             });
         }
 
+        // Create an ABCI event for the filled segment, so that indexers can follow the
+        // execution without replaying the routing. It's discarded along with the rest of the
+        // state changes if the fill is rolled back.
+        let position_ids = self
+            .positions
+            .iter()
+            .map(|position| position.id())
+            .collect::<Vec<_>>();
+        self.state
+            .record_proto(event::route_fill(&trace, &position_ids));
+
         // Add the new trace
         self.trace.push(trace);
 
@@ -454,12 +488,13 @@ impl<S: StateRead + StateWrite> Frontier<S> {
     async fn replace_empty_positions(&mut self) -> Result<bool, FillError> {
         for i in 0..self.pairs.len() {
             let desired_reserves = self.positions[i]
-                .reserves_for(self.pairs[i].end)
+                .usable_reserves_for(self.pairs[i].end)
                 .ok_or_else(|| {
                     FillError::AssetIdMismatch(self.pairs[i].end, self.positions[i].phi.pair)
                 })?;
 
-            // Replace any position that has been fully consumed.
+            // Replace any position that has been fully consumed, or has too little left to be
+            // traded for.
             if desired_reserves == 0u64.into() {
                 // If we can't find a replacement, report that failure upwards.
                 if !self.replace_position(i).await {
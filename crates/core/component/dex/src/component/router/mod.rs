@@ -1,16 +1,20 @@
 mod fill_route;
+mod parallel;
 mod params;
 mod path;
 mod path_cache;
 mod path_search;
+#[cfg(feature = "rounding-audit")]
+pub(crate) mod rounding_audit;
 mod route_and_fill;
 
 use path::Path;
-use path_cache::{PathCache, PathEntry, SharedPathCache};
+use path_cache::{PathCache, SharedPathCache};
 
 pub use fill_route::FillRoute;
+pub(crate) use parallel::execute_batch_swaps;
 pub use params::RoutingParams;
-pub use path_search::PathSearch;
+pub use path_search::{FoundPath, PathSearch, PathSearchRound};
 pub use route_and_fill::{HandleBatchSwaps, RouteAndFill};
 
 #[cfg(test)]
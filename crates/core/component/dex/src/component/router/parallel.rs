@@ -0,0 +1,300 @@
+//! Parallel execution of the batch swaps of independent trading pairs.
+//!
+//! Routing a batch swap can only reach positions connected to the trading pair through other
+//! positions, so the batches of pairs in different connected components of the liquidity graph
+//! read and write disjoint sets of positions. Those batches are executed concurrently, each
+//! against its own fork of the state, and the forks are then applied in a fixed order, giving the
+//! same state as executing the batches one after the other.
+//!
+//! Besides the positions and their indices, executing a batch writes two pieces of shared state:
+//! the value circuit breaker's aggregate balances, and the batch swap outputs pending for the
+//! compact block. Each fork only changes these for its own assets and pairs, so they're merged
+//! explicitly once all of the forks are applied, as is the rounding audit when it's enabled. The
+//! statistics of each pair are kept under the pair's own key, so they need no merging.
+//!
+//! The events of each pair's batch are set aside as it's executed, and recorded in order of
+//! trading pair once the forks are applied, in the order sequential execution records them.
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use cnidarium::{StateDelta, StateRead, StateWrite};
+use futures::StreamExt;
+use penumbra_asset::asset;
+use tendermint::abci;
+use tracing::Instrument;
+
+use crate::{
+    circuit_breaker::ValueCircuitBreaker,
+    component::{flow::SwapFlow, metrics, PositionManager, StateReadExt},
+    state_key, TradingPair,
+};
+
+use super::{HandleBatchSwaps, RoutingParams};
+
+/// Executes the batch swaps of each trading pair, returning how long each pair's batch took.
+///
+/// The pairs are partitioned with [`independent_groups`], and the groups are executed
+/// concurrently. Within a group, the pairs are executed in order.
+pub(crate) async fn execute_batch_swaps<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    swap_flows: BTreeMap<TradingPair, SwapFlow>,
+    block_height: u64,
+    epoch_starting_height: u64,
+) -> Result<Vec<(TradingPair, Duration)>> {
+    let groups = independent_groups(state.as_ref(), swap_flows.keys().copied()).await?;
+    metrics::histogram!(metrics::DEX_BATCH_GROUPS).record(groups.len() as f64);
+    tracing::debug!(groups = groups.len(), "partitioned batch swaps");
+
+    // Forking is only worth it if there's something to run alongside.
+    if groups.len() <= 1 {
+        let pairs = swap_flows.keys().copied().collect();
+        return execute_group(
+            state,
+            &swap_flows,
+            pairs,
+            block_height,
+            epoch_starting_height,
+        )
+        .await;
+    }
+
+    #[cfg(feature = "rounding-audit")]
+    let base_audit = super::rounding_audit::get(state.as_ref());
+    #[cfg(feature = "rounding-audit")]
+    let mut fork_audits = Vec::new();
+
+    let swap_flows = Arc::new(swap_flows);
+    let tasks: Vec<_> = groups
+        .into_iter()
+        .map(|group| {
+            let fork = Arc::new(StateDelta::new(state.clone()));
+            let swap_flows = swap_flows.clone();
+            tokio::spawn(
+                execute_group_in_fork(fork, swap_flows, group, block_height, epoch_starting_height)
+                    .in_current_span(),
+            )
+        })
+        .collect();
+
+    // Every group must finish before any is applied, since applying a fork needs the only
+    // reference to the state.
+    let mut forks = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        forks.push(task.await??);
+    }
+
+    let initial_value = value_circuit_breaker(state.as_ref()).await?;
+    let mut value = initial_value.clone();
+    let mut outputs = state.pending_batch_swap_outputs();
+    let mut timings = Vec::new();
+    let mut events = Vec::new();
+    let mut caches = Vec::with_capacity(forks.len());
+    for (fork, fork_timings, fork_events) in forks {
+        value.tally_changes(&initial_value, &value_circuit_breaker(fork.as_ref()).await?);
+        outputs.extend(fork.pending_batch_swap_outputs());
+        timings.extend(fork_timings);
+        events.extend(fork_events);
+        #[cfg(feature = "rounding-audit")]
+        fork_audits.push(super::rounding_audit::get(fork.as_ref()));
+
+        let (fork_state, cache) = Arc::try_unwrap(fork)
+            .map_err(|_| ())
+            .expect("no more outstanding refs to the fork after executing its group")
+            .flatten();
+        std::mem::drop(fork_state);
+        caches.push(cache);
+    }
+
+    let state = Arc::get_mut(state)
+        .expect("state should be uniquely referenced after all groups are executed");
+    for cache in caches {
+        cache.apply_to(&mut *state);
+    }
+    // Each fork wrote its own copy of the shared state, so replace whichever was applied last
+    // with the merged versions.
+    state.nonverifiable_put_raw(
+        state_key::aggregate_value().as_bytes().to_vec(),
+        serde_json::to_vec(&value)
+            .expect("able to serialize value circuit breaker for nonverifiable storage"),
+    );
+    state.object_put(state_key::pending_outputs(), outputs);
+    #[cfg(feature = "rounding-audit")]
+    super::rounding_audit::merge_forks(state, base_audit, fork_audits);
+
+    events.sort_by_key(|(trading_pair, _)| *trading_pair);
+    for event in events.into_iter().flat_map(|(_, events)| events) {
+        state.record(event);
+    }
+
+    timings.sort_by_key(|(trading_pair, _)| *trading_pair);
+    Ok(timings)
+}
+
+/// Executes the batch swaps of the given pairs one after the other in `fork`, like
+/// [`execute_group`], but leaves the events of each pair's batch out of the fork, returning them
+/// instead.
+async fn execute_group_in_fork<S: StateWrite + 'static>(
+    mut fork: Arc<StateDelta<Arc<S>>>,
+    swap_flows: Arc<BTreeMap<TradingPair, SwapFlow>>,
+    pairs: Vec<TradingPair>,
+    block_height: u64,
+    epoch_starting_height: u64,
+) -> Result<(
+    Arc<StateDelta<Arc<S>>>,
+    Vec<(TradingPair, Duration)>,
+    Vec<(TradingPair, Vec<abci::Event>)>,
+)> {
+    let mut timings = Vec::with_capacity(pairs.len());
+    let mut events = Vec::with_capacity(pairs.len());
+    for trading_pair in pairs {
+        let mut pair_state = Arc::new(StateDelta::new(fork.clone()));
+        timings.extend(
+            execute_group(
+                &mut pair_state,
+                &swap_flows,
+                vec![trading_pair],
+                block_height,
+                epoch_starting_height,
+            )
+            .await?,
+        );
+
+        let (fork_ref, mut cache) = Arc::try_unwrap(pair_state)
+            .map_err(|_| ())
+            .expect("no more outstanding refs to the pair's state after executing its batch")
+            .flatten();
+        std::mem::drop(fork_ref);
+        events.push((trading_pair, cache.take_events()));
+        cache.apply_to(
+            Arc::get_mut(&mut fork)
+                .expect("fork should be uniquely referenced after executing a pair's batch"),
+        );
+    }
+    Ok((fork, timings, events))
+}
+
+/// Executes the batch swaps of the given pairs one after the other.
+pub(crate) async fn execute_group<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    swap_flows: &BTreeMap<TradingPair, SwapFlow>,
+    pairs: Vec<TradingPair>,
+    block_height: u64,
+    epoch_starting_height: u64,
+) -> Result<Vec<(TradingPair, Duration)>> {
+    let mut timings = Vec::with_capacity(pairs.len());
+    for trading_pair in pairs {
+        let batch_start = Instant::now();
+        state
+            .handle_batch_swaps(
+                trading_pair,
+                swap_flows[&trading_pair].clone(),
+                block_height,
+                epoch_starting_height,
+                // Always include both ends of the target pair as fixed candidates.
+                RoutingParams::default_with_extra_candidates([
+                    trading_pair.asset_1(),
+                    trading_pair.asset_2(),
+                ]),
+            )
+            .await?;
+        let batch_duration = batch_start.elapsed();
+        metrics::histogram!(metrics::DEX_BATCH_DURATION).record(batch_duration);
+        timings.push((trading_pair, batch_duration));
+    }
+    Ok(timings)
+}
+
+/// Partitions `pairs` into groups whose batch swaps can be executed independently.
+///
+/// Two pairs are grouped together if their assets are connected in the routable assets index,
+/// which has an entry for each pair of assets that has had positions between them. As entries
+/// aren't removed once the liquidity is gone, this may group pairs that are in fact independent,
+/// but never separates pairs that aren't. Filling a route can only close positions, never open
+/// them, so executing the batches can't connect the groups either.
+///
+/// The groups are ordered by their first pair, and the pairs in each keep the order of `pairs`.
+pub(crate) async fn independent_groups<S: PositionManager + ?Sized>(
+    state: &S,
+    pairs: impl IntoIterator<Item = TradingPair>,
+) -> Result<Vec<Vec<TradingPair>>> {
+    let pairs: Vec<TradingPair> = pairs.into_iter().collect();
+    let mut components = BTreeMap::new();
+    let mut parents = Vec::new();
+
+    for pair in &pairs {
+        let component_1 =
+            discover_component(state, pair.asset_1(), &mut components, &mut parents).await?;
+        let component_2 =
+            discover_component(state, pair.asset_2(), &mut components, &mut parents).await?;
+        // No route can join assets in different components, but the batch still reads the
+        // positions in both, so their components can't be executed separately.
+        let (root_1, root_2) = (root(&parents, component_1), root(&parents, component_2));
+        parents[root_1.max(root_2)] = root_1.min(root_2);
+    }
+
+    let mut groups: Vec<(usize, Vec<TradingPair>)> = Vec::new();
+    for pair in pairs {
+        let component = root(&parents, components[&pair.asset_1()]);
+        match groups.iter_mut().find(|(root, _)| *root == component) {
+            Some((_, group)) => group.push(pair),
+            None => groups.push((component, vec![pair])),
+        }
+    }
+    Ok(groups.into_iter().map(|(_, group)| group).collect())
+}
+
+/// Returns the connected component `asset` belongs to, first finding all of the assets in it if
+/// it hasn't been seen yet.
+async fn discover_component<S: PositionManager + ?Sized>(
+    state: &S,
+    asset: asset::Id,
+    components: &mut BTreeMap<asset::Id, usize>,
+    parents: &mut Vec<usize>,
+) -> Result<usize> {
+    if let Some(component) = components.get(&asset) {
+        return Ok(*component);
+    }
+
+    let component = parents.len();
+    parents.push(component);
+    components.insert(asset, component);
+
+    let mut unexplored = vec![asset];
+    while let Some(from) = unexplored.pop() {
+        let mut routable = state.ordered_routable_assets(&from);
+        while let Some(to) = routable.next().await.transpose()? {
+            if let Entry::Vacant(entry) = components.entry(to) {
+                entry.insert(component);
+                unexplored.push(to);
+            }
+        }
+    }
+
+    Ok(component)
+}
+
+/// Returns the component that `component` was merged into, if any.
+fn root(parents: &[usize], mut component: usize) -> usize {
+    while parents[component] != component {
+        component = parents[component];
+    }
+    component
+}
+
+/// Reads the value circuit breaker's aggregate balances.
+async fn value_circuit_breaker<S: StateRead + ?Sized>(state: &S) -> Result<ValueCircuitBreaker> {
+    Ok(
+        match state
+            .nonverifiable_get_raw(state_key::aggregate_value().as_bytes())
+            .await?
+        {
+            Some(bytes) => serde_json::from_slice(&bytes)?,
+            None => ValueCircuitBreaker::default(),
+        },
+    )
+}
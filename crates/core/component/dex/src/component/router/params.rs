@@ -8,6 +8,9 @@ pub struct RoutingParams {
     pub price_limit: Option<U128x128>,
     pub fixed_candidates: Arc<Vec<asset::Id>>,
     pub max_hops: usize,
+    /// The penalty, in basis points, applied to the estimated price of a path for each hop
+    /// beyond the first.
+    pub multi_hop_penalty_bps: u32,
 }
 
 impl Default for RoutingParams {
@@ -45,6 +48,7 @@ impl Default for RoutingParams {
                     .id(),
             ]),
             max_hops: 4,
+            multi_hop_penalty_bps: 0,
         }
     }
 }
@@ -61,6 +65,12 @@ impl RoutingParams {
         params
     }
 
+    /// The factor the estimated price of a path is multiplied by for each hop beyond the first.
+    pub fn hop_penalty(&self) -> U128x128 {
+        U128x128::ratio(10_000 + u64::from(self.multi_hop_penalty_bps), 10_000u64)
+            .expect("denominator is nonzero")
+    }
+
     /// Clamps the spill price to the price limit and returns whether or not it was clamped.
     pub fn clamp_to_limit(&self, spill_price: Option<U128x128>) -> (Option<U128x128>, bool) {
         match (spill_price, self.price_limit) {
@@ -21,8 +21,11 @@ pub(super) struct Path<S: StateRead + 'static> {
     pub start: asset::Id,
     /// The nodes along the path, implicitly defining the end
     pub nodes: Vec<asset::Id>,
-    /// An estimate of the end-to-end effective price along the path
+    /// An estimate of the end-to-end effective price along the path, including the
+    /// `hop_penalty` of every hop beyond the first.
     pub price: U128x128,
+    /// The factor the estimated price is multiplied by for each hop beyond the first.
+    pub hop_penalty: U128x128,
     /// A forked view of the state after traveling along this path.
     pub state: StateDelta<S>,
     /// A span recording information about the path, for debugging.
@@ -41,17 +44,34 @@ impl<S: StateRead + 'static> Path<S> {
             start,
             nodes: Vec::new(),
             price: 1u64.into(),
+            hop_penalty: 1u64.into(),
             state,
             span,
         }
     }
 
+    /// Removes the hop penalty this path's price includes from `price`.
+    ///
+    /// Applied to the path's own price, this gives its estimated effective price. Applied to
+    /// the price of another path to the same end, it gives the price this path would have to
+    /// reach for the other one to be preferred.
+    pub fn unpenalize(&self, price: U128x128) -> U128x128 {
+        if self.nodes.len() <= 1 || self.hop_penalty == 1u64.into() {
+            return price;
+        }
+        let penalty = (1..self.nodes.len()).try_fold(U128x128::from(1u64), |penalty, _| {
+            penalty * self.hop_penalty
+        });
+        penalty.and_then(|penalty| price / penalty).unwrap_or(price)
+    }
+
     // We can't clone, because StateDelta only has an explicit fork() on purpose
     pub fn fork(&mut self) -> Self {
         Self {
             start: self.start,
             nodes: self.nodes.clone(),
             price: self.price,
+            hop_penalty: self.hop_penalty,
             state: self.state.fork(),
             span: self.span.clone(),
         }
@@ -83,7 +103,15 @@ impl<S: StateRead + 'static> Path<S> {
             .expect("position should be contain the end asset")
             .effective_price();
 
-        match self.price * hop_price {
+        // Every hop after the first is penalized, so that longer paths are only preferred
+        // when they're better by more than the penalty.
+        let path_price = if self.nodes.is_empty() {
+            self.price * hop_price
+        } else {
+            self.price * hop_price * self.hop_penalty
+        };
+
+        match path_price {
             Ok(path_price) => {
                 // Update and return the path.
                 tracing::debug!(%path_price, %hop_price, id = ?best_price_position.id(), "extended path");
@@ -3,6 +3,7 @@ use std::{collections::BTreeMap, sync::Arc};
 use cnidarium::{StateDelta, StateRead};
 use parking_lot::Mutex;
 use penumbra_asset::asset;
+use penumbra_num::fixpoint::U128x128;
 
 use super::Path;
 
@@ -69,11 +70,22 @@ pub(super) type SharedPathCache<S> = Arc<Mutex<PathCache<S>>>;
 impl<S: StateRead + 'static> PathCache<S> {
     /// Initializes a new PathCache with the identity path for the start asset.
     pub fn begin(start: asset::Id, state: StateDelta<S>) -> SharedPathCache<S> {
+        Self::begin_with_hop_penalty(start, state, 1u64.into())
+    }
+
+    /// Like [`PathCache::begin`], but penalizes every hop of a path beyond the first.
+    pub fn begin_with_hop_penalty(
+        start: asset::Id,
+        state: StateDelta<S>,
+        hop_penalty: U128x128,
+    ) -> SharedPathCache<S> {
+        let mut path = Path::begin(start, state);
+        path.hop_penalty = hop_penalty;
         let mut cache = BTreeMap::new();
         cache.insert(
             start,
             PathEntry {
-                path: Path::begin(start, state),
+                path,
                 active: true,
                 spill: None,
             },
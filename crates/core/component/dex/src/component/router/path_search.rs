@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use async_stream::try_stream;
 use async_trait::async_trait;
 use cnidarium::{StateDelta, StateRead};
-use futures::StreamExt;
+use futures::{stream::BoxStream, StreamExt};
 use penumbra_asset::asset;
 use penumbra_num::fixpoint::U128x128;
 use tokio::task::JoinSet;
@@ -11,7 +12,30 @@ use tracing::{instrument, Instrument};
 
 use crate::component::PositionManager;
 
-use super::{Path, PathCache, PathEntry, RoutingParams, SharedPathCache};
+use super::{Path, PathCache, RoutingParams, SharedPathCache};
+
+/// The best path known after a round of a progressive path search.
+#[derive(Debug, Clone)]
+pub struct PathSearchRound {
+    /// The maximum number of hops of the paths considered so far.
+    pub hops: usize,
+    /// The best path found so far, if any.
+    pub best: Option<FoundPath>,
+}
+
+/// A path found by a path search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoundPath {
+    /// The assets along the path, excluding the source asset.
+    pub nodes: Vec<asset::Id>,
+    /// The estimated price of the path.
+    pub price: U128x128,
+    /// The estimated price of the next-best path, if one exists.
+    ///
+    /// With a multi-hop penalty, this is the price the path would have to reach for the
+    /// next-best one to be preferred, which can differ from the next-best path's own price.
+    pub spill_price: Option<U128x128>,
+}
 
 #[async_trait]
 pub trait PathSearch: StateRead + Clone + 'static {
@@ -25,39 +49,31 @@ pub trait PathSearch: StateRead + Clone + 'static {
         dst: asset::Id,
         params: RoutingParams,
     ) -> Result<(Option<Vec<asset::Id>>, Option<U128x128>)> {
-        let RoutingParams {
-            max_hops,
-            fixed_candidates,
-            price_limit,
-        } = params;
-
         // Initialize some metrics for calculating time spent on path searching
         // vs route filling. We use vecs so we can count across iterations of the loop.
         let path_start = std::time::Instant::now();
-        tracing::debug!(?src, ?dst, ?max_hops, "searching for path");
-
-        // Work in a new stack of state changes, which we can completely discard
-        // at the end of routing
-        let state = StateDelta::new(self.clone());
+        tracing::debug!(?src, ?dst, max_hops = params.max_hops, "searching for path");
 
-        let cache = PathCache::begin(src, state);
-        for i in 0..max_hops {
-            relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
-            tracing::debug!(i, "finished relaxing all active paths");
+        let mut rounds = self.path_search_progressive(src, dst, params.clone());
+        let mut best = None;
+        while let Some(round) = rounds.next().await {
+            best = round?.best;
         }
 
-        let entry = cache.lock().0.remove(&dst);
-        let Some(PathEntry { path, spill, .. }) = entry else {
+        let Some(FoundPath {
+            nodes,
+            price,
+            spill_price,
+        }) = best
+        else {
             return Ok((None, None));
         };
 
-        let nodes = path.nodes;
-        let spill_price = spill.map(|p| p.price);
-        tracing::debug!(price = %path.price, spill_price = %spill_price.unwrap_or_else(|| 0u64.into()), ?src, ?nodes, "found path");
+        tracing::debug!(%price, spill_price = %spill_price.unwrap_or_else(|| 0u64.into()), ?src, ?nodes, "found path");
         metrics::histogram!(crate::component::metrics::DEX_PATH_SEARCH_DURATION)
             .record(path_start.elapsed());
 
-        match price_limit {
+        match params.price_limit {
             // Note: previously, this branch was a load-bearing termination condition, primarily
             // exercised by the arbitrage logic. However, during the course of testnet 53,  we
             // encountered two bugs that caused this predicate to not be exercised:
@@ -65,22 +81,73 @@ pub trait PathSearch: StateRead + Clone + 'static {
             // 2. We relied on an estimate of the end-to-end path price which was lossy (`path.price`).
             // The latter is an inherent information limitation, so we now have a redundant check in
             // `route_and_fill` which uses the exact price of the route.
-            Some(price_limit) if path.price >= price_limit => {
-                tracing::debug!(price = %path.price, price_limit = %price_limit, "path too expensive");
+            Some(price_limit) if price >= price_limit => {
+                tracing::debug!(%price, %price_limit, "path too expensive");
                 Ok((None, None))
             }
             _ => Ok((Some(nodes), spill_price)),
         }
     }
+
+    /// Search for routes from `src` to `dst` one hop deeper at a time, yielding the best
+    /// path known after each round of relaxation.
+    ///
+    /// The best path only gets cheaper from one round to the next, counting the multi-hop
+    /// penalty of [`RoutingParams`], so callers can stop
+    /// polling as soon as a path is good enough, and the last round yields the same path as
+    /// [`PathSearch::path_search`]. The rounds stop early once no path can be improved
+    /// further. Unlike `path_search`, the price limit is not applied.
+    ///
+    /// The stream holds a reference to the state until it is dropped.
+    fn path_search_progressive(
+        &self,
+        src: asset::Id,
+        dst: asset::Id,
+        params: RoutingParams,
+    ) -> BoxStream<'static, Result<PathSearchRound>> {
+        let hop_penalty = params.hop_penalty();
+        let RoutingParams {
+            max_hops,
+            fixed_candidates,
+            ..
+        } = params;
+
+        // Work in a new stack of state changes, which we can completely discard
+        // at the end of routing
+        let state = StateDelta::new(self.clone());
+
+        Box::pin(try_stream! {
+            let cache = PathCache::begin_with_hop_penalty(src, state, hop_penalty);
+            for i in 0..max_hops {
+                let relaxed = relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
+                tracing::debug!(i, relaxed, "finished relaxing all active paths");
+                if relaxed == 0 {
+                    break;
+                }
+
+                let best = cache.lock().0.get(&dst).map(|entry| FoundPath {
+                    nodes: entry.path.nodes.clone(),
+                    price: entry.path.unpenalize(entry.path.price),
+                    spill_price: entry
+                        .spill
+                        .as_ref()
+                        .map(|spill| entry.path.unpenalize(spill.price)),
+                });
+                yield PathSearchRound { hops: i + 1, best };
+            }
+        })
+    }
 }
 
 impl<S> PathSearch for S where S: StateRead + Clone + 'static {}
 
+/// Extends every active path by one hop, returning the number of paths extended.
 async fn relax_active_paths<S: StateRead + 'static>(
     cache: SharedPathCache<S>,
     fixed_candidates: Arc<Vec<asset::Id>>,
-) -> Result<()> {
+) -> Result<usize> {
     let active_paths = cache.lock().extract_active();
+    let relaxed = active_paths.len();
     let mut js = JoinSet::new();
     tracing::debug!(
         active_paths_len = active_paths.len(),
@@ -93,7 +160,7 @@ async fn relax_active_paths<S: StateRead + 'static>(
     while let Some(task) = js.join_next().await {
         task??;
     }
-    Ok(())
+    Ok(relaxed)
 }
 
 async fn relax_path<S: StateRead + 'static>(
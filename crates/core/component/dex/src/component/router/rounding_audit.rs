@@ -0,0 +1,194 @@
+//! An audit of the rounding of fills, enabled by the `rounding-audit` feature.
+//!
+//! Each hop of a fill rounds the amount it trades to an integer, burning the rounding error in
+//! favor of the position. The audit checks that every hop conserves value, i.e., that the input
+//! and output of the hop are exactly the changes in the position's reserves, and compares the
+//! output of the hop to the output the position's price gives at full precision. The difference
+//! is the rounding loss of the trader, which is logged for each position at `debug` level, and
+//! summed into a summary logged at `info` level at the end of each block.
+//!
+//! The audit reads and writes the state's object store while filling, so it is too slow to run in
+//! production; it exists to catch economically significant rounding drift in tests and on
+//! devnets.
+
+use std::collections::BTreeMap;
+
+use cnidarium::{StateRead, StateWrite};
+use penumbra_asset::asset;
+use penumbra_num::{fixpoint::U128x128, Amount};
+
+use crate::{
+    lp::{
+        position::{self, Position},
+        Reserves,
+    },
+    state_key, DirectedTradingPair,
+};
+
+/// The number of positions with the largest losses listed in the summary of a block.
+const TOP_POSITIONS: usize = 5;
+
+/// The rounding losses of the fills executed in the current block.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RoundingAudit {
+    /// The number of hops audited.
+    hops: u64,
+    /// The total rounding loss of each output asset.
+    losses: BTreeMap<asset::Id, U128x128>,
+    /// The total rounding loss of the fills against each position, and the asset it's in.
+    by_position: BTreeMap<position::Id, (asset::Id, U128x128)>,
+}
+
+impl RoundingAudit {
+    /// Records a rounding `loss` of `asset_id` in a hop against the position `id`.
+    fn record(&mut self, id: position::Id, asset_id: asset::Id, loss: U128x128) {
+        self.hops += 1;
+        let total = self.losses.entry(asset_id).or_default();
+        *total = (*total + loss).expect("total rounding loss fits in a U128x128");
+        let (_, total) = self
+            .by_position
+            .entry(id)
+            .or_insert((asset_id, U128x128::default()));
+        *total = (*total + loss).expect("total rounding loss fits in a U128x128");
+    }
+
+    /// Adds the losses recorded in `fork` since it was forked from a state audited as `base`.
+    fn merge(&mut self, fork: RoundingAudit, base: &RoundingAudit) {
+        self.hops += fork.hops - base.hops;
+        for (asset_id, loss) in fork.losses {
+            let since = base
+                .losses
+                .get(&asset_id)
+                .map_or(loss, |base| loss.saturating_sub(base));
+            let total = self.losses.entry(asset_id).or_default();
+            *total = (*total + since).expect("total rounding loss fits in a U128x128");
+        }
+        for (id, (asset_id, loss)) in fork.by_position {
+            let since = base
+                .by_position
+                .get(&id)
+                .map_or(loss, |(_, base)| loss.saturating_sub(base));
+            let (_, total) = self
+                .by_position
+                .entry(id)
+                .or_insert((asset_id, U128x128::default()));
+            *total = (*total + since).expect("total rounding loss fits in a U128x128");
+        }
+    }
+}
+
+/// Returns the rounding losses recorded in `state` in the current block.
+pub(crate) fn get<S: StateRead>(state: &S) -> RoundingAudit {
+    state
+        .object_get(state_key::rounding_audit())
+        .unwrap_or_default()
+}
+
+/// Records the rounding losses of concurrently executed `forks` of a state audited as `base` into
+/// `state`, once the forks have been applied to it.
+///
+/// Each fork wrote its own copy of the audit, so only the one applied last would survive.
+pub(crate) fn merge_forks<S: StateWrite>(
+    state: &mut S,
+    base: RoundingAudit,
+    forks: Vec<RoundingAudit>,
+) {
+    let mut audit = base.clone();
+    for fork in forks {
+        audit.merge(fork, &base);
+    }
+    state.object_put(state_key::rounding_audit(), audit);
+}
+
+/// Audits a hop of a fill, against `position` along `pair`, that traded `input` for `output` and
+/// left the position with the `new_reserves`.
+///
+/// # Panics
+///
+/// Panics if the hop doesn't conserve value, or if rounding favors the trader by a whole unit.
+pub(super) fn audit_hop<S: StateWrite>(
+    state: &mut S,
+    position: &Position,
+    pair: DirectedTradingPair,
+    input: Amount,
+    output: Amount,
+    new_reserves: &Reserves,
+) {
+    let id = position.id();
+    let mut filled = position.clone();
+    filled.reserves = new_reserves.clone();
+    let reserves = |position: &Position, asset| {
+        position
+            .reserves_for(asset)
+            .expect("hop asset belongs to the position's pair")
+    };
+
+    assert_eq!(
+        reserves(&filled, pair.start),
+        reserves(position, pair.start) + input,
+        "hop against position {id} does not conserve its input of {input:?}"
+    );
+    assert_eq!(
+        reserves(&filled, pair.end) + output,
+        reserves(position, pair.end),
+        "hop against position {id} does not conserve its output of {output:?}"
+    );
+
+    let exact_output = position
+        .phi
+        .orient_start(pair.start)
+        .expect("hop input belongs to the position's pair")
+        .convert_to_lambda_2(input.into())
+        .expect("exact output of a hop fits in a U128x128");
+    let output = U128x128::from(output);
+    let gain = output.saturating_sub(&exact_output);
+    assert!(
+        gain < U128x128::from(1u64),
+        "rounding of hop against position {id} favored the trader by {gain}: traded {input:?} for {output}, rather than {exact_output}"
+    );
+    let loss = exact_output.saturating_sub(&output);
+
+    tracing::debug!(
+        position_id = %id,
+        ?input,
+        %output,
+        %exact_output,
+        %loss,
+        "audited rounding of hop"
+    );
+
+    let mut audit = get(state);
+    audit.record(id, pair.end, loss);
+    state.object_put(state_key::rounding_audit(), audit);
+}
+
+/// Logs a summary of the rounding losses of the fills executed in the block, and resets them.
+pub(crate) fn log_block_summary<S: StateWrite>(state: &mut S) {
+    let Some(audit) = state.object_get::<RoundingAudit>(state_key::rounding_audit()) else {
+        return;
+    };
+    state.object_delete(state_key::rounding_audit());
+
+    let losses = audit
+        .losses
+        .iter()
+        .map(|(asset_id, loss)| format!("{loss}{asset_id}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut by_position = audit.by_position.into_iter().collect::<Vec<_>>();
+    by_position.sort_by(|(_, (_, a)), (_, (_, b))| b.cmp(a));
+    let top_positions = by_position
+        .iter()
+        .take(TOP_POSITIONS)
+        .map(|(id, (asset_id, loss))| format!("{id}: {loss}{asset_id}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    tracing::info!(
+        hops = audit.hops,
+        positions = by_position.len(),
+        %losses,
+        %top_positions,
+        "rounding losses of the block's fills"
+    );
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::StateWrite;
+use futures::StreamExt;
 use penumbra_asset::{asset, Value};
 use penumbra_num::Amount;
 use tracing::instrument;
@@ -11,7 +12,8 @@ use crate::{
     circuit_breaker::ValueCircuitBreaker,
     component::{
         flow::SwapFlow,
-        router::{FillRoute, PathSearch, RoutingParams},
+        pair_stats::PairStatsWrite,
+        router::{FillRoute, FoundPath, PathSearch, RoutingParams},
         PositionManager, StateWriteExt,
     },
     lp::position::MAX_RESERVE_AMOUNT,
@@ -45,6 +47,7 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
         Self: 'static,
     {
         let (delta_1, delta_2) = (batch_data.0, batch_data.1);
+        let (multi_hop_penalty_1, multi_hop_penalty_2) = batch_data.multi_hop_penalty_bps();
 
         tracing::debug!(?delta_1, ?delta_2, ?trading_pair, "decrypted batch swaps");
 
@@ -69,7 +72,10 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
                     trading_pair.asset_1(),
                     trading_pair.asset_2(),
                     delta_1,
-                    params.clone(),
+                    RoutingParams {
+                        multi_hop_penalty_bps: multi_hop_penalty_1,
+                        ..params.clone()
+                    },
                     execution_circuit_breaker.clone(),
                 )
                 .await?,
@@ -85,7 +91,10 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
                     trading_pair.asset_2(),
                     trading_pair.asset_1(),
                     delta_2,
-                    params.clone(),
+                    RoutingParams {
+                        multi_hop_penalty_bps: multi_hop_penalty_2,
+                        ..params.clone()
+                    },
                     execution_circuit_breaker,
                 )
                 .await?,
@@ -140,9 +149,13 @@ pub trait HandleBatchSwaps: StateWrite + Sized {
             ?swap_execution_1_for_2,
             ?swap_execution_2_for_1
         );
-        Arc::get_mut(self)
-            .expect("expected state to have no other refs")
-            .set_output_data(output_data, swap_execution_1_for_2, swap_execution_2_for_1);
+        let state = Arc::get_mut(self).expect("expected state to have no other refs");
+        // The statistics are only kept for clients, so failing to record them mustn't stop
+        // the batch from executing.
+        if let Err(e) = state.record_pair_stats(Some(&output_data)).await {
+            tracing::warn!(?e, "failed to record pair statistics");
+        }
+        state.set_output_data(output_data, swap_execution_1_for_2, swap_execution_2_for_1);
 
         Ok(())
     }
@@ -153,7 +166,12 @@ impl<T: PositionManager> HandleBatchSwaps for T {}
 /// Lower-level trait that ties together the routing and filling logic.
 #[async_trait]
 pub trait RouteAndFill: StateWrite + Sized {
-    #[instrument(skip(self, asset_1, asset_2, input, params, execution_circuit_breaker))]
+    // This runs for every direction of every trading pair with swaps in a block, so its span and
+    // events are kept at debug level, with plain `Display` fields, to cost little when disabled.
+    #[instrument(
+        level = "debug",
+        skip(self, asset_1, asset_2, input, params, execution_circuit_breaker)
+    )]
     async fn route_and_fill(
         self: &mut Arc<Self>,
         asset_1: asset::Id,
@@ -165,7 +183,7 @@ pub trait RouteAndFill: StateWrite + Sized {
     where
         Self: 'static,
     {
-        tracing::debug!(?input, ?asset_1, ?asset_2, "starting route_and_fill");
+        tracing::debug!(%input, %asset_1, %asset_2, "starting route_and_fill");
 
         // Unfilled output of asset 1
         let mut total_unfilled_1 = input;
@@ -181,40 +199,75 @@ pub trait RouteAndFill: StateWrite + Sized {
         // 1. We have no more delta_1 remaining
         // 2. A path can no longer be found
         // 3. We have reached the `RoutingParams` specified price limit
-        // 4. The execution circuit breaker has been triggered based on the number of path searches and executions
+        // 4. The execution circuit breaker has been triggered based on the estimated cost of the path searches and fills
 
         loop {
             // Check if we have exceeded the execution circuit breaker limits.
             if execution_circuit_breaker.exceeded_limits() {
-                tracing::debug!("execution circuit breaker triggered, exiting route_and_fill");
+                tracing::debug!(exit_reason = "circuit_breaker", "exiting route_and_fill");
                 break;
             }
 
-            // Find the best route between the two assets in the trading pair.
-            let (path, spill_price) = self
-                .path_search(asset_1, asset_2, params.clone())
-                .await
-                .context("error finding best path")?;
+            // Find the best route between the two assets in the trading pair, one hop deeper
+            // at a time. Once half of the budget is spent, we settle for the first path
+            // found, so that later fills in a busy batch don't each pay for a full-depth search.
+            execution_circuit_breaker.charge_path_search();
+            let mut best = None;
+            {
+                let mut rounds = self.path_search_progressive(asset_1, asset_2, params.clone());
+                while let Some(round) = rounds.next().await {
+                    let round = round.context("error finding best path")?;
+                    execution_circuit_breaker.charge_path_relaxation();
+                    best = round.best;
+                    if best.is_some() && execution_circuit_breaker.should_settle() {
+                        tracing::debug!(hops = round.hops, "settling for path within budget");
+                        break;
+                    }
+                }
+                // The search holds a reference to the state, which must be released before
+                // filling.
+            }
 
-            let Some(path) = path else {
-                tracing::debug!("no path found, exiting route_and_fill");
+            let Some(FoundPath {
+                nodes: path,
+                price,
+                spill_price,
+            }) = best
+            else {
+                tracing::debug!(exit_reason = "no_path", "exiting route_and_fill");
                 break;
             };
 
+            // As in `path_search`, routes whose estimated price reaches the limit are not filled.
+            // Since the estimate is lossy, the exact price is also checked after filling.
+            if let Some(price_limit) = params.price_limit {
+                if price >= price_limit {
+                    tracing::debug!(
+                        exit_reason = "price_limit",
+                        %price,
+                        %price_limit,
+                        "exiting route_and_fill"
+                    );
+                    break;
+                }
+            }
+
             if path.is_empty() {
-                tracing::debug!("empty path found, exiting route_and_fill");
+                tracing::debug!(exit_reason = "empty_path", "exiting route_and_fill");
                 break;
             }
 
-            // Increment the execution circuit breaker path search counter.
-            execution_circuit_breaker.current_path_searches += 1;
-
             let delta_1 = Value {
                 amount: total_unfilled_1.min(max_delta_1),
                 asset_id: asset_1,
             };
 
-            tracing::debug!(?path, delta_1 = ?delta_1.amount, "found path, filling up to spill price");
+            tracing::debug!(
+                hops = path.len(),
+                delta_1 = %delta_1.amount,
+                "found path, filling up to spill price"
+            );
+            tracing::trace!(?path, "found path");
 
             let execution = Arc::get_mut(self)
                 .expect("expected state to have no other refs")
@@ -226,7 +279,7 @@ pub trait RouteAndFill: StateWrite + Sized {
                 Err(FillError::ExecutionOverflow(position_id)) => {
                     // We have encountered an overflow during the execution of the route.
                     // To route around this, we will close the position and try to route and fill again.
-                    tracing::debug!(culprit = ?position_id, "overflow detected during routing execution");
+                    tracing::debug!(culprit = %position_id, "overflow detected during routing execution");
                     Arc::get_mut(self)
                         .expect("expected state to have no other refs")
                         .close_position_by_id(&position_id)
@@ -251,7 +304,12 @@ pub trait RouteAndFill: StateWrite + Sized {
                         .expect("unable to subtract unfilled input from total input"),
                     asset_id: asset_1,
                 };
-                tracing::debug!(input = ?delta_1.amount, output = ?lambda_2.amount, unfilled = ?unfilled_1.amount, "filled along best path");
+                tracing::debug!(
+                    input = %delta_1.amount,
+                    output = %lambda_2.amount,
+                    unfilled = %unfilled_1.amount,
+                    "filled along best path"
+                );
 
                 assert_eq!(lambda_2.asset_id, asset_2);
                 assert_eq!(unfilled_1.asset_id, asset_1);
@@ -265,17 +323,18 @@ pub trait RouteAndFill: StateWrite + Sized {
                 )
             };
 
-            // Increment the execution circuit breaker execution counter.
-            execution_circuit_breaker.current_executions += 1;
+            // Charge the execution circuit breaker for the work done filling the route: each
+            // trace passes through one position per hop.
+            execution_circuit_breaker.charge_fill(path.len(), execution.traces.len() * path.len());
 
             if total_unfilled_1.value() == 0 {
-                tracing::debug!("filled all input, exiting route_and_fill");
+                tracing::debug!(exit_reason = "filled", "exiting route_and_fill");
                 break;
             }
 
             // Ensure that we've actually executed, or else bail out.
             let Some(accurate_max_price) = execution.max_price() else {
-                tracing::debug!("no traces in execution, exiting route_and_fill");
+                tracing::debug!(exit_reason = "no_traces", "exiting route_and_fill");
                 break;
             };
 
@@ -283,9 +342,10 @@ pub trait RouteAndFill: StateWrite + Sized {
             if let Some(price_limit) = params.price_limit {
                 if accurate_max_price >= price_limit {
                     tracing::debug!(
-                        ?accurate_max_price,
-                        ?price_limit,
-                        "execution price above price limit, exiting route_and_fill"
+                        exit_reason = "price_limit",
+                        %accurate_max_price,
+                        %price_limit,
+                        "exiting route_and_fill"
                     );
                     break;
                 }
@@ -6,11 +6,12 @@ use futures::StreamExt;
 use penumbra_asset::{asset, Value};
 use penumbra_num::{fixpoint::U128x128, Amount};
 use rand_core::OsRng;
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use crate::lp::SellOrder;
 use crate::{
     component::{
+        flow::SwapFlow,
         router::{FillRoute, HandleBatchSwaps, Path},
         tests::TempStorageExt,
         PositionManager, PositionRead, StateReadExt, StateWriteExt,
@@ -19,10 +20,13 @@ use crate::{
         position::{self, Position},
         Reserves,
     },
-    DirectedTradingPair, DirectedUnitPair,
+    DirectedTradingPair, DirectedUnitPair, TradingPair,
 };
 
-use super::{PathSearch, RoutingParams};
+use super::{
+    parallel::{execute_batch_swaps, execute_group, independent_groups},
+    PathSearch, RoutingParams,
+};
 
 #[tokio::test(flavor = "multi_thread")]
 async fn path_search_basic() {
@@ -66,6 +70,124 @@ async fn path_search_basic() {
         .unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn path_search_progressive_converges() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut state = StateDelta::new(());
+    create_test_positions_basic(&mut state, true).await;
+    let state = Arc::new(state);
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let params = RoutingParams {
+        max_hops: 4,
+        ..Default::default()
+    };
+
+    let rounds = state
+        .path_search_progressive(gm.id(), penumbra.id(), params.clone())
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    assert!(!rounds.is_empty() && rounds.len() <= 4);
+
+    // Each round considers one more hop, and the best path only ever gets cheaper.
+    let mut best_price = None;
+    for (i, round) in rounds.iter().enumerate() {
+        assert_eq!(round.hops, i + 1);
+        if let Some(best) = &round.best {
+            assert!(best_price.map_or(true, |price| best.price <= price));
+            best_price = Some(best.price);
+        } else {
+            assert!(best_price.is_none(), "a path can't be lost");
+        }
+    }
+
+    // The last round agrees with the full search.
+    let (path, spill_price) = state.path_search(gm.id(), penumbra.id(), params).await?;
+    let last = rounds.last().and_then(|round| round.best.clone());
+    assert_eq!(last.as_ref().map(|best| best.nodes.clone()), path);
+    assert_eq!(last.and_then(|best| best.spill_price), spill_price);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn multi_hop_penalty_prefers_direct_paths() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let mut state = StateDelta::new(());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    // Trading gn for penumbra directly gets 10 penumbra per gn, while going through gm gets
+    // 11, a 10% better price.
+    let direct = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    state
+        .put_position(limit_buy(direct, 1u64.into(), 10u64.into()))
+        .await?;
+    let gn_for_gm = DirectedUnitPair::new(gn.clone(), gm.clone());
+    state
+        .put_position(limit_buy(gn_for_gm, 1u64.into(), 11u64.into()))
+        .await?;
+    let gm_for_penumbra = DirectedUnitPair::new(gm.clone(), penumbra.clone());
+    state
+        .put_position(limit_buy(gm_for_penumbra, 11u64.into(), 1u64.into()))
+        .await?;
+    let state = Arc::new(state);
+
+    let search = |multi_hop_penalty_bps| {
+        state.path_search(
+            gn.id(),
+            penumbra.id(),
+            RoutingParams {
+                max_hops: 2,
+                multi_hop_penalty_bps,
+                ..Default::default()
+            },
+        )
+    };
+
+    // Without a penalty, or with one below the price difference, the longer path is taken.
+    let (path, _spill) = search(0).await?;
+    assert_eq!(path, Some(vec![gm.id(), penumbra.id()]));
+    let (path, _spill) = search(500).await?;
+    assert_eq!(path, Some(vec![gm.id(), penumbra.id()]));
+
+    // A penalty above the price difference routes through the direct pair, until its price
+    // exceeds that of the longer path, penalized.
+    let (path, spill_price) = search(2_000).await?;
+    assert_eq!(path, Some(vec![penumbra.id()]));
+    let rounds = state
+        .path_search_progressive(
+            gn.id(),
+            penumbra.id(),
+            RoutingParams {
+                max_hops: 2,
+                multi_hop_penalty_bps: 2_000,
+                ..Default::default()
+            },
+        )
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let best = rounds
+        .last()
+        .and_then(|round| round.best.clone())
+        .expect("a path is found");
+    assert_eq!(best.spill_price, spill_price);
+    assert!(spill_price.is_some_and(|spill_price| spill_price > best.price));
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn path_extension_basic() {
     let _ = tracing_subscriber::fmt::try_init();
@@ -841,6 +963,18 @@ async fn fill_route_unconstrained() -> anyhow::Result<()> {
         "the output amount is correct"
     );
 
+    // Each filled segment of the route is reported in an event.
+    let (_, events) = state_tx.apply();
+    let route_fills = events
+        .iter()
+        .filter(|event| event.kind == "penumbra.core.component.dex.v1.EventRouteFill")
+        .count();
+    assert_eq!(
+        route_fills,
+        execution.traces.len(),
+        "there is one event per trace"
+    );
+
     Ok(())
 }
 
@@ -2184,3 +2318,189 @@ async fn path_compare_node_ids() -> anyhow::Result<()> {
     assert!(path2 < path1);
     Ok(())
 }
+
+#[tokio::test]
+async fn independent_pairs_execute_in_parallel() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+    let test_usd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+    let test_btc = asset::Cache::with_known_assets()
+        .get_unit("test_btc")
+        .unwrap();
+
+    // gm and gn are both connected to penumbra, but not to test_usd or test_btc.
+    let gn_penumbra = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+    let gm_penumbra = DirectedUnitPair::new(gm.clone(), penumbra.clone());
+    let btc_usd = DirectedUnitPair::new(test_btc.clone(), test_usd.clone());
+    for pair in [&gn_penumbra, &gm_penumbra, &btc_usd] {
+        state_tx
+            .put_position(limit_buy(pair.clone(), 1u64.into(), 1u64.into()))
+            .await
+            .unwrap();
+    }
+    state_tx.apply();
+
+    let gn_penumbra: TradingPair = gn_penumbra.into_directed_trading_pair().into();
+    let gm_penumbra: TradingPair = gm_penumbra.into_directed_trading_pair().into();
+    let btc_usd: TradingPair = btc_usd.into_directed_trading_pair().into();
+
+    let groups = independent_groups(state.as_ref(), [gn_penumbra, btc_usd, gm_penumbra]).await?;
+    assert_eq!(groups, vec![vec![gn_penumbra, gm_penumbra], vec![btc_usd]]);
+
+    // Swap one unit of gn for penumbra, and one of test_btc for test_usd.
+    let mut swap_flows = BTreeMap::new();
+    for (trading_pair, input) in [(gn_penumbra, gn.id()), (btc_usd, test_btc.id())] {
+        let mut swap_flow = SwapFlow::default();
+        if trading_pair.asset_1() == input {
+            swap_flow.0 += 1u32.into();
+        } else {
+            swap_flow.1 += 1u32.into();
+        }
+        swap_flows.insert(trading_pair, swap_flow);
+    }
+
+    let timings = execute_batch_swaps(&mut state, swap_flows, 0, 0).await?;
+    assert_eq!(timings.len(), 2);
+
+    // Both forks' outputs should have been applied, and merged for the compact block.
+    for trading_pair in [gn_penumbra, btc_usd] {
+        let output_data = state.output_data(0, trading_pair).await?.unwrap();
+        assert_eq!(output_data.unfilled_1, 0u64.into());
+        assert_eq!(output_data.unfilled_2, 0u64.into());
+        assert_eq!(output_data.lambda_1 + output_data.lambda_2, 1u64.into());
+    }
+    assert_eq!(state.pending_batch_swap_outputs().len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn parallel_execution_matches_sequential_execution() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let unit = |denom| asset::Cache::with_known_assets().get_unit(denom).unwrap();
+    let (gm, gn, penumbra) = (unit("gm"), unit("gn"), unit("penumbra"));
+    let (test_usd, test_btc, test_atom) = (unit("test_usd"), unit("test_btc"), unit("test_atom"));
+
+    // Two groups of two pairs each, so that each fork executes several batches.
+    let markets = [
+        DirectedUnitPair::new(gn.clone(), penumbra.clone()),
+        DirectedUnitPair::new(gm.clone(), penumbra.clone()),
+        DirectedUnitPair::new(test_btc.clone(), test_usd.clone()),
+        DirectedUnitPair::new(test_atom.clone(), test_usd.clone()),
+    ];
+    let mut swap_flows = BTreeMap::new();
+    for market in &markets {
+        state
+            .put_position(limit_buy(market.clone(), 2u64.into(), 1u64.into()))
+            .await?;
+
+        let trading_pair: TradingPair = market.clone().into_directed_trading_pair().into();
+        let mut swap_flow = SwapFlow::default();
+        if trading_pair.asset_1() == market.start.id() {
+            swap_flow.0 += market.start.unit_amount();
+        } else {
+            swap_flow.1 += market.start.unit_amount();
+        }
+        swap_flows.insert(trading_pair, swap_flow);
+    }
+    let state = Arc::new(state);
+
+    let groups = independent_groups(state.as_ref(), swap_flows.keys().copied()).await?;
+    assert_eq!(groups.len(), 2);
+
+    let mut parallel = Arc::new(StateDelta::new(state.clone()));
+    execute_batch_swaps(&mut parallel, swap_flows.clone(), 0, 0).await?;
+
+    let mut sequential = Arc::new(StateDelta::new(state.clone()));
+    let pairs = swap_flows.keys().copied().collect();
+    execute_group(&mut sequential, &swap_flows, pairs, 0, 0).await?;
+
+    assert_eq!(
+        parallel.pending_batch_swap_outputs(),
+        sequential.pending_batch_swap_outputs()
+    );
+    assert_eq!(parallel.pending_batch_swap_outputs().len(), 4);
+
+    let changes = |state: Arc<StateDelta<Arc<StateDelta<cnidarium::Snapshot>>>>| {
+        let (_, mut cache) = Arc::try_unwrap(state)
+            .map_err(|_| ())
+            .expect("no more outstanding refs to the state")
+            .flatten();
+        let events = cache.take_events();
+        (
+            cache.unwritten_changes().clone(),
+            cache.nonverifiable_changes().clone(),
+            events,
+        )
+    };
+    let (parallel, sequential) = (changes(parallel), changes(sequential));
+    assert_eq!(parallel.0, sequential.0);
+    assert_eq!(parallel.1, sequential.1);
+    assert!(!sequential.2.is_empty());
+    assert_eq!(parallel.2, sequential.2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn unfillable_positions_are_not_routed() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let gm_for_gn = DirectedTradingPair::new(gm.id(), gn.id());
+    let gn_for_gm = DirectedTradingPair::new(gn.id(), gm.id());
+
+    // A unit of gn is worth a millionth of a unit of gm, so a thousand units of gn are worth
+    // less than the unit of gm it would take to trade for them.
+    let mut position = Position::new(
+        OsRng,
+        gm_for_gn,
+        0u32,
+        1_000_000u64.into(),
+        1u64.into(),
+        Reserves {
+            r1: 0u64.into(),
+            r2: 1_000u64.into(),
+        },
+    );
+    let id = position.id();
+    state.put_position(position.clone()).await?;
+
+    let routable = |state: &StateDelta<_>| {
+        futures::stream::select(
+            state.positions_by_price(&gm_for_gn),
+            state.positions_by_price(&gn_for_gm),
+        )
+    };
+
+    assert!(routable(&state).next().await.is_none());
+
+    // Once the position has enough reserves to be worth trading for, it's routed again.
+    let gn_reserves = if position.phi.pair.asset_1() == gn.id() {
+        &mut position.reserves.r1
+    } else {
+        &mut position.reserves.r2
+    };
+    *gn_reserves = 10_000_000u64.into();
+    state.put_position(position).await?;
+
+    assert_eq!(routable(&state).next().await.transpose()?, Some(id));
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::{pin::Pin, sync::Arc};
+use std::{collections::BTreeSet, pin::Pin, sync::Arc};
 
 use async_stream::try_stream;
 use futures::{StreamExt, TryStreamExt};
@@ -7,18 +7,20 @@ use tracing::instrument;
 
 use cnidarium::{StateDelta, Storage};
 use penumbra_asset::{asset, Value};
+use penumbra_num::fixpoint::U128x128;
 use penumbra_proto::{
     core::component::dex::v1::{
         query_service_server::QueryService, simulate_trade_request::routing,
         simulate_trade_request::routing::Setting, simulate_trade_request::Routing,
         simulation_service_server::SimulationService, ArbExecutionRequest, ArbExecutionResponse,
         ArbExecutionsRequest, ArbExecutionsResponse, BatchSwapOutputDataRequest,
-        BatchSwapOutputDataResponse, LiquidityPositionByIdRequest, LiquidityPositionByIdResponse,
+        BatchSwapOutputDataResponse, DexStatusRequest, DexStatusResponse, ExportPositionsRequest,
+        ExportPositionsResponse, LiquidityPositionByIdRequest, LiquidityPositionByIdResponse,
         LiquidityPositionsByIdRequest, LiquidityPositionsByIdResponse,
         LiquidityPositionsByPriceRequest, LiquidityPositionsByPriceResponse,
-        LiquidityPositionsRequest, LiquidityPositionsResponse, SimulateTradeRequest,
-        SimulateTradeResponse, SpreadRequest, SpreadResponse, SwapExecutionRequest,
-        SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        LiquidityPositionsRequest, LiquidityPositionsResponse, PairStatsRequest, PairStatsResponse,
+        SimulateTradeRequest, SimulateTradeResponse, SpreadRequest, SpreadResponse,
+        SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
     },
     DomainType, StateReadProto,
 };
@@ -30,8 +32,9 @@ use crate::{
 };
 
 use super::{
+    position_manager::POSITION_UPDATE_RETENTION_BLOCKS,
     router::{RouteAndFill, RoutingParams},
-    PositionRead, StateReadExt,
+    PairStatsRead, PositionRead, StateReadExt,
 };
 
 // TODO: Hide this and only expose a Router?
@@ -66,6 +69,8 @@ impl QueryService for Server {
         Pin<Box<dyn futures::Stream<Item = Result<ArbExecutionsResponse, tonic::Status>> + Send>>;
     type SwapExecutionsStream =
         Pin<Box<dyn futures::Stream<Item = Result<SwapExecutionsResponse, tonic::Status>> + Send>>;
+    type ExportPositionsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ExportPositionsResponse, tonic::Status>> + Send>>;
 
     #[instrument(skip(self, request))]
     async fn arb_execution(
@@ -328,6 +333,46 @@ impl QueryService for Server {
         }))
     }
 
+    #[instrument(skip(self, _request))]
+    async fn dex_status(
+        &self,
+        _request: tonic::Request<DexStatusRequest>,
+    ) -> Result<tonic::Response<DexStatusResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let params = state
+            .get_dex_params()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error getting dex params: {e}")))?;
+
+        Ok(tonic::Response::new(DexStatusResponse {
+            is_paused: params.is_paused,
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn pair_stats(
+        &self,
+        request: tonic::Request<PairStatsRequest>,
+    ) -> Result<tonic::Response<PairStatsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: TradingPair = request
+            .trading_pair
+            .ok_or_else(|| tonic::Status::invalid_argument("missing trading pair"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing trading pair: {:#}", e))
+            })?;
+
+        let stats = state.pair_stats(pair).await.map_err(|e| {
+            tonic::Status::internal(format!("error getting pair statistics: {:#}", e))
+        })?;
+
+        Ok(tonic::Response::new(stats.into()))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions_by_price(
         &self,
@@ -484,6 +529,130 @@ impl QueryService for Server {
             .boxed(),
         ))
     }
+
+    #[instrument(skip(self, request))]
+    async fn export_positions(
+        &self,
+        request: tonic::Request<ExportPositionsRequest>,
+    ) -> Result<tonic::Response<Self::ExportPositionsStream>, Status> {
+        let request = request.into_inner();
+        let state = match request.snapshot_height {
+            0 => self.storage.latest_snapshot(),
+            height => self.storage.snapshot(height).ok_or_else(|| {
+                Status::not_found(format!(
+                    "no snapshot of height {height} is retained; export from a recent height, or the latest one"
+                ))
+            })?,
+        };
+        let snapshot_height = state.version();
+
+        let start_after: Option<position::Id> = request
+            .start_after
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: anyhow::Error| {
+                Status::invalid_argument(format!("error converting start_after: {e:#}"))
+            })?;
+        let limit = if request.limit != 0 {
+            request.limit as usize
+        } else {
+            usize::MAX
+        };
+
+        // Incremental exports list the positions updated since the given height, ordered by ID,
+        // while full exports list every position in the order of their state keys.
+        let ids = if request.updated_since_height != 0 {
+            // Older updates have been pruned, so the positions updated since then can't be told.
+            if snapshot_height.saturating_sub(request.updated_since_height)
+                > POSITION_UPDATE_RETENTION_BLOCKS
+            {
+                return Err(Status::failed_precondition(format!(
+                    "position updates are only kept for {POSITION_UPDATE_RETENTION_BLOCKS} blocks; export the positions in full instead"
+                )));
+            }
+            let ids: BTreeSet<position::Id> = state
+                .positions_updated_since(request.updated_since_height)
+                .map_err(|e| Status::internal(format!("error reading position updates: {e:#}")))?
+                .try_collect()
+                .await
+                .map_err(|e: anyhow::Error| {
+                    Status::unavailable(format!("error reading position updates: {e:#}"))
+                })?;
+            Some(ids)
+        } else {
+            None
+        };
+        let include_closed = request.include_closed || ids.is_some();
+
+        let s = try_stream! {
+            let positions = match ids {
+                Some(ids) => {
+                    let state = state.clone();
+                    futures::stream::iter(ids)
+                        .then(move |id| {
+                            let state = state.clone();
+                            async move {
+                                state.position_by_id(&id).await?.ok_or_else(|| {
+                                    anyhow::anyhow!("updated position not found in state: {}", id)
+                                })
+                            }
+                        })
+                        .boxed()
+                }
+                None => state.all_positions(),
+            };
+            let mut positions = positions
+                .map_err(|e: anyhow::Error| {
+                    Status::unavailable(format!("error getting position from storage: {e:#}"))
+                })
+                .try_filter(|position| {
+                    futures::future::ready(
+                        include_closed || position.state == position::State::Opened,
+                    )
+                });
+
+            let mut skipping = start_after.is_some();
+            let mut exported = 0;
+            while let Some(position) = positions.try_next().await? {
+                let id = position.id();
+                if skipping {
+                    skipping = Some(id) != start_after;
+                    continue;
+                }
+                if exported == limit {
+                    break;
+                }
+                exported += 1;
+
+                let pair = position.phi.pair;
+                let approx_effective_price_1_to_2 = position
+                    .phi
+                    .orient_start(pair.asset_1())
+                    .expect("position has one end = asset 1")
+                    .effective_price()
+                    .into();
+                let approx_effective_price_2_to_1 = position
+                    .phi
+                    .orient_start(pair.asset_2())
+                    .expect("position has one end = asset 2")
+                    .effective_price()
+                    .into();
+                yield ExportPositionsResponse {
+                    id: Some(id.into()),
+                    position: Some(position.into()),
+                    approx_effective_price_1_to_2,
+                    approx_effective_price_2_to_1,
+                    snapshot_height,
+                };
+            }
+            if skipping {
+                Err(Status::invalid_argument(
+                    "start_after is not a position of this export",
+                ))?;
+            }
+        };
+        Ok(tonic::Response::new(s.boxed()))
+    }
 }
 
 #[tonic::async_trait]
@@ -521,15 +690,27 @@ impl SimulationService for Server {
                 tonic::Status::invalid_argument(format!("error parsing output id: {:#}", e))
             })?;
 
+        let state = self.storage.latest_snapshot();
+
+        // Simulate the multi-hop penalty swaps get by default, to route the way a swap would be.
+        let multi_hop_penalty_bps = state
+            .get_dex_params()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error getting dex params: {e}")))?
+            .default_multi_hop_penalty_bps;
         let routing_params = match routing_strategy {
-            Setting::Default(_) => RoutingParams::default(),
+            Setting::Default(_) => RoutingParams {
+                multi_hop_penalty_bps,
+                ..RoutingParams::default()
+            },
             Setting::SingleHop(_) => RoutingParams {
                 max_hops: 1,
                 ..RoutingParams::default()
             },
         };
 
-        let state = self.storage.latest_snapshot();
+        // The trade is filled in a delta over the snapshot that's dropped afterwards, so nothing
+        // is written.
         let mut state_tx = Arc::new(StateDelta::new(state));
         let execution_circuit_breaker = ExecutionCircuitBreaker::default();
         let swap_execution = state_tx
@@ -556,9 +737,16 @@ impl SimulationService for Server {
             asset_id: input.asset_id,
         };
 
+        let price_impact_bps = swap_execution
+            .price_impact()
+            .and_then(|impact| (impact * U128x128::from(10_000u32)).ok())
+            .and_then(|bps| u64::try_from(bps.round_down()).ok())
+            .unwrap_or_default() as u32;
+
         Ok(tonic::Response::new(SimulateTradeResponse {
             unfilled: Some(unfilled.into()),
             output: Some(swap_execution.into()),
+            price_impact_bps,
         }))
     }
 }
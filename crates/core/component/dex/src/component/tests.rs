@@ -18,7 +18,7 @@ use crate::{
         Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    BatchSwapOutputData, DexParameters, DirectedTradingPair, DirectedUnitPair,
 };
 
 // TODO: what's the right way to mock genesis? if component A needs component B,
@@ -472,6 +472,81 @@ async fn empty_order_fails() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Test that a chain upgraded from a version without DEX parameters, which only `init_chain`
+/// writes, uses the defaults rather than rejecting every position and swap.
+async fn missing_dex_params_fall_back_to_defaults() -> anyhow::Result<()> {
+    use cnidarium_component::ActionHandler;
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    assert_eq!(state.get_dex_params().await?, DexParameters::default());
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let position = Position::new(
+        OsRng,
+        DirectedTradingPair::new(gm.id(), gn.id()),
+        0u32,
+        1_200_000u64.into(),
+        1_000_000u64.into(),
+        Reserves {
+            r1: 1u64.into(),
+            r2: 0u64.into(),
+        },
+    );
+    PositionOpen { position }.check_stateful(state).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that positions are found by the height they were updated at, and that the index
+/// of updates only keeps recent blocks.
+async fn position_updates_are_indexed_and_pruned() -> anyhow::Result<()> {
+    use crate::component::position_manager::POSITION_UPDATE_RETENTION_BLOCKS;
+    use futures::TryStreamExt;
+    use penumbra_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let mut ids = Vec::new();
+    for height in 1..=3 {
+        let position = Position::new(
+            OsRng,
+            DirectedTradingPair::new(gm.id(), gn.id()),
+            0u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 1u64.into(),
+                r2: 0u64.into(),
+            },
+        );
+        ids.push(position.id());
+        let mut state_tx = state.try_begin_transaction().unwrap();
+        state_tx.put_block_height(height);
+        state_tx.put_position(position).await?;
+        state_tx.apply();
+    }
+
+    let updated: Vec<_> = state.positions_updated_since(1)?.try_collect().await?;
+    assert_eq!(updated, ids[1..]);
+
+    // Once the first update falls out of the retained window, only it is pruned.
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    state_tx.put_block_height(POSITION_UPDATE_RETENTION_BLOCKS + 2);
+    state_tx.prune_position_updates().await?;
+    state_tx.apply();
+
+    let updated: Vec<_> = state.positions_updated_since(0)?.try_collect().await?;
+    assert_eq!(updated, ids[1..]);
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Test that positions are created and returned as expected.
 async fn position_create_and_retrieve() -> anyhow::Result<()> {
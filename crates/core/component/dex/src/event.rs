@@ -5,9 +5,10 @@ use crate::{
     },
     swap::Swap,
     swap_claim::SwapClaim,
-    BatchSwapOutputData, SwapExecution,
+    BatchSwapOutputData, DexParameters, SwapExecution,
 };
 
+use penumbra_asset::Value;
 use penumbra_proto::penumbra::core::component::dex::v1 as pb;
 
 pub fn swap(swap: &Swap) -> pb::EventSwap {
@@ -46,6 +47,12 @@ pub fn position_close(action: &PositionClose) -> pb::EventPositionClose {
     }
 }
 
+pub fn position_auto_close(position_id: &position::Id) -> pb::EventPositionClose {
+    pb::EventPositionClose {
+        position_id: Some((*position_id).into()),
+    }
+}
+
 pub fn position_withdraw(
     position_withdraw: &PositionWithdraw,
     final_position_state: &Position,
@@ -73,6 +80,13 @@ pub fn position_execution(post_execution_state: Position) -> pb::EventPositionEx
     }
 }
 
+pub fn route_fill(trace: &[Value], position_ids: &[position::Id]) -> pb::EventRouteFill {
+    pb::EventRouteFill {
+        trace: trace.iter().cloned().map(Into::into).collect(),
+        position_ids: position_ids.iter().cloned().map(Into::into).collect(),
+    }
+}
+
 pub fn batch_swap(
     bsod: BatchSwapOutputData,
     swap_execution_1_for_2: Option<SwapExecution>,
@@ -91,3 +105,9 @@ pub fn arb_execution(height: u64, swap_execution: SwapExecution) -> pb::EventArb
         swap_execution: Some(swap_execution.into()),
     }
 }
+
+pub fn dex_params_update(params: DexParameters) -> pb::EventDexParametersUpdate {
+    pb::EventDexParametersUpdate {
+        new_parameters: Some(params.into()),
+    }
+}
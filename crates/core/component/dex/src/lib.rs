@@ -4,17 +4,24 @@
 #[cfg(feature = "component")]
 pub mod component;
 pub mod event;
+pub mod genesis;
+pub mod params;
 pub mod state_key;
 
 mod batch_swap_output_data;
 mod circuit_breaker;
+mod pair_stats;
 mod swap_execution;
 mod trading_pair;
 
-pub use batch_swap_output_data::BatchSwapOutputData;
+pub use batch_swap_output_data::{BatchSwapOutputData, ProRataShare};
 pub(crate) use circuit_breaker::ExecutionCircuitBreaker;
+pub use pair_stats::{PairStats, PairStatsWindow};
+pub use params::{DexParameters, PositionOpenError};
 pub use swap_execution::SwapExecution;
-pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
+pub use trading_pair::{
+    DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar, TradingPairView,
+};
 
 pub mod lp;
 pub mod swap;
@@ -1,14 +1,17 @@
 mod nft;
 mod order;
+mod price;
 mod reserves;
 mod trading_function;
 
 pub mod action;
 pub mod plan;
 pub mod position;
+pub mod replication;
 
 pub use nft::LpNft;
 pub use order::{BuyOrder, SellOrder};
+pub use price::DisplayPrice;
 pub use reserves::Reserves;
 pub use trading_function::BareTradingFunction;
 pub use trading_function::TradingFunction;
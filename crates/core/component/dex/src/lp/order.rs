@@ -1,11 +1,7 @@
 use anyhow::{anyhow, Result};
-use penumbra_asset::{
-    asset::{self, Unit},
-    Value,
-};
+use penumbra_asset::{asset, Value};
 use penumbra_num::{fixpoint::U128x128, Amount};
 use rand_core::CryptoRngCore;
-use regex::Regex;
 
 use crate::DirectedTradingPair;
 
@@ -48,24 +44,13 @@ fn parse_parts(input: &str) -> Result<(&str, &str, u32)> {
     Ok((val1, val2, fee))
 }
 
-fn extract_unit(input: &str) -> Result<Unit> {
-    let unit_re = Regex::new(r"[0-9.]+([^0-9.].*+)$")?;
-    if let Some(captures) = unit_re.captures(input) {
-        let unit = captures.get(1).expect("matched regex").as_str();
-        Ok(asset::REGISTRY.parse_unit(unit))
-    } else {
-        Err(anyhow!("could not extract unit from {}", input))
-    }
-}
-
 impl BuyOrder {
     /// Eventually we'll need to plumb in an asset::Cache so this isn't FromStr
     pub fn parse_str(input: &str) -> Result<Self> {
         let (desired_str, price_str, fee) = parse_parts(input)?;
 
-        let desired_unit = extract_unit(desired_str)?;
-        let desired = desired_str.parse::<Value>()?;
-        let price = price_str.parse::<Value>()?;
+        let (desired, desired_unit) = Value::parse_with_unit(desired_str)?;
+        let price = Value::parse(price_str)?;
 
         // In, e.g., 100mpenumbra@1.2gm, we're expressing the desire to:
         // - buy 100_000 upenumbra (absolute value)
@@ -148,9 +133,8 @@ impl SellOrder {
     pub fn parse_str(input: &str) -> Result<Self> {
         let (offered_str, price_str, fee) = parse_parts(input)?;
 
-        let offered_unit = extract_unit(offered_str)?;
-        let offered = offered_str.parse::<Value>()?;
-        let price = price_str.parse::<Value>()?;
+        let (offered, offered_unit) = Value::parse_with_unit(offered_str)?;
+        let price = Value::parse(price_str)?;
 
         // In, e.g., 100mpenumbra@1.2gm, we're expressing the desire to:
         // - sell 100_000 upenumbra (absolute value)
@@ -35,6 +35,9 @@ pub struct Position {
     /// Set to `true` if a position is a limit-order, meaning that it will be closed after being
     /// filled against. Note that this is not currently supported in the dex state machine.
     pub close_on_fill: bool,
+    /// If set, the position is closed at the end of any block in which its reserves of either
+    /// asset are below the corresponding threshold. A zero threshold is ignored.
+    pub close_below: Option<Reserves>,
 }
 
 impl std::fmt::Debug for Position {
@@ -96,6 +99,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            close_below: None,
         }
     }
 
@@ -141,11 +145,37 @@ impl Position {
             Err(anyhow!("cyclical pairs aren't allowed"))
         } else if self.phi.component.fee > MAX_FEE_BPS {
             Err(anyhow!("fee cannot be greater than 50% (5000bps)"))
+        } else if let Some(close_below) = &self.close_below {
+            if close_below.r1.value() > MAX_RESERVE_AMOUNT
+                || close_below.r2.value() > MAX_RESERVE_AMOUNT
+            {
+                Err(anyhow!("auto-close thresholds are out-of-bounds"))
+            } else if close_below.r1 == Amount::zero() && close_below.r2 == Amount::zero() {
+                Err(anyhow!(
+                    "auto-close thresholds must set a threshold for either asset"
+                ))
+            } else if self.is_below_close_threshold() {
+                Err(anyhow!(
+                    "initial reserves are below the auto-close thresholds"
+                ))
+            } else {
+                Ok(())
+            }
         } else {
             Ok(())
         }
     }
 
+    /// Returns whether the position's reserves of either asset are below the threshold set for
+    /// that asset by [`Position::close_below`], meaning it should be closed.
+    pub fn is_below_close_threshold(&self) -> bool {
+        let Some(close_below) = &self.close_below else {
+            return false;
+        };
+        (close_below.r1 != Amount::zero() && self.reserves.r1 < close_below.r1)
+            || (close_below.r2 != Amount::zero() && self.reserves.r2 < close_below.r2)
+    }
+
     /// Returns the amount of the given asset that is currently in the position's reserves.
     pub fn reserves_for(&self, asset: asset::Id) -> Option<Amount> {
         if asset == self.phi.pair.asset_1() {
@@ -156,6 +186,26 @@ impl Position {
             None
         }
     }
+
+    /// Returns the amount of the given asset in the position's reserves that can usefully be
+    /// traded out of it.
+    ///
+    /// This is zero if the reserves are worth less than one unit of the other asset at the
+    /// position's price: after rounding, trading for them costs a whole unit, far more than the
+    /// position's price, so they're left out of routing.
+    pub fn usable_reserves_for(&self, asset: asset::Id) -> Option<Amount> {
+        let reserves = self.reserves_for(asset)?;
+        let worth = self
+            .phi
+            .orient_end(asset)
+            .expect("asset is part of the position's pair")
+            .convert_to_delta_1(reserves.into());
+        match worth {
+            Ok(worth) if worth < 1u64.into() => Some(Amount::zero()),
+            // Reserves worth too much to represent are certainly worth trading for.
+            _ => Some(reserves),
+        }
+    }
 }
 
 /// A hash of a [`Position`].
@@ -340,6 +390,7 @@ impl From<Position> for pb::Position {
             phi: Some(p.phi.into()),
             nonce: p.nonce.to_vec(),
             close_on_fill: p.close_on_fill,
+            close_below: p.close_below.map(Into::into),
         }
     }
 }
@@ -366,6 +417,7 @@ impl TryFrom<pb::Position> for Position {
                 .try_into()
                 .context("expected 32-byte nonce")?,
             close_on_fill: p.close_on_fill,
+            close_below: p.close_below.map(TryInto::try_into).transpose()?,
         })
     }
 }
@@ -534,4 +586,35 @@ mod test {
         assert_position_similar(b_position_3, a_position_2);
         assert_position_similar(b_position_4, a_position_1);
     }
+
+    #[test]
+    fn close_below_thresholds() {
+        let pair = DirectedTradingPair::new(asset::Id(Fq::zero()), asset::Id(Fq::from(1u64)));
+        let mut position = Position::new(
+            OsRng,
+            pair,
+            0u32,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: 150u64.into(),
+                r2: 0u64.into(),
+            },
+        );
+        assert!(!position.is_below_close_threshold());
+
+        // Only the threshold for the provisioned asset applies, since the other is zero.
+        position.close_below = Some(Reserves {
+            r1: 100u64.into(),
+            r2: 0u64.into(),
+        });
+        position.check_stateless().expect("thresholds are valid");
+        assert!(!position.is_below_close_threshold());
+        position.reserves.r1 = 99u64.into();
+        assert!(position.is_below_close_threshold());
+        assert!(position.check_stateless().is_err());
+
+        position.close_below = Some(Reserves::zero());
+        assert!(position.check_stateless().is_err());
+    }
 }
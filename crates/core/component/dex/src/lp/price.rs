@@ -0,0 +1,291 @@
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, Context, Result};
+use penumbra_asset::asset::{Unit, REGISTRY};
+use penumbra_num::Amount;
+use rand_core::CryptoRngCore;
+
+use crate::{DirectedTradingPair, TradingPair};
+
+use super::{
+    position::{Position, MAX_RESERVE_AMOUNT},
+    Reserves,
+};
+
+/// The most decimal places a price is written with.
+const MAX_DECIMALS: u32 = 24;
+
+/// A price quoted in display units, e.g. `1.25 penumbra/gm`, the price of one `penumbra` in `gm`.
+///
+/// The price is kept as the exact decimal it was written as, so that it converts exactly into
+/// the coefficients of a trading function, rather than being rounded to base units of the quote
+/// asset first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayPrice {
+    /// The price, scaled up by `10^decimals`.
+    scaled: u128,
+    decimals: u32,
+    /// The unit being priced.
+    pub base: Unit,
+    /// The unit the price is in.
+    pub quote: Unit,
+}
+
+impl DisplayPrice {
+    /// Parses a price, e.g. `1.25`, quoted in a pair of units, e.g. `penumbra/gm`.
+    pub fn parse(price: &str, units: &str) -> Result<Self> {
+        let (base, quote) = units.split_once('/').with_context(|| {
+            format!("units {units} must be written as base/quote, e.g. penumbra/gm")
+        })?;
+        let (base, quote) = (REGISTRY.parse_unit(base), REGISTRY.parse_unit(quote));
+        if base.id() == quote.id() {
+            anyhow::bail!("can't price {base} in {quote}, an asset can't be priced in itself");
+        }
+
+        let (whole, fraction) = price.split_once('.').unwrap_or((price, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            anyhow::bail!("price {price} must be a positive decimal number, e.g. 1.25");
+        }
+        let decimals = fraction.len() as u32;
+        if decimals > MAX_DECIMALS {
+            anyhow::bail!("price {price} has more than {MAX_DECIMALS} decimal places");
+        }
+        let scaled = format!("{whole}{fraction}")
+            .parse::<u128>()
+            .with_context(|| format!("price {price} has too many digits"))?;
+        if scaled == 0 {
+            anyhow::bail!("price {price} must be positive");
+        }
+
+        Ok(Self {
+            scaled,
+            decimals,
+            base,
+            quote,
+        })
+    }
+
+    /// The coefficients `(p, q)` of a trading function from the base asset to the quote asset
+    /// at this price, in lowest terms: a base unit of the base asset trades for `p / q` base units
+    /// of the quote asset.
+    ///
+    /// Errors if the coefficients are too large for a position, which happens when the price
+    /// has more precision than the assets' base units can express.
+    pub fn coefficients(&self) -> Result<(Amount, Amount)> {
+        let too_precise = || {
+            anyhow!(
+                "{self} is too precise to express in base units of {} and {}",
+                self.base,
+                self.quote
+            )
+        };
+        let p = self
+            .scaled
+            .checked_mul(self.quote.unit_amount().value())
+            .ok_or_else(too_precise)?;
+        let q = 10u128
+            .checked_pow(self.decimals)
+            .and_then(|scale| scale.checked_mul(self.base.unit_amount().value()))
+            .ok_or_else(too_precise)?;
+        let (p, q) = lowest_terms(p, q);
+        if p > MAX_RESERVE_AMOUNT || q > MAX_RESERVE_AMOUNT {
+            return Err(too_precise());
+        }
+        Ok((p.into(), q.into()))
+    }
+
+    /// Builds a position trading between the base and quote assets at this price, holding
+    /// `reserves.r1` of the base asset and `reserves.r2` of the quote asset.
+    pub fn into_position<R: CryptoRngCore>(
+        &self,
+        rng: R,
+        fee: u32,
+        reserves: Reserves,
+    ) -> Result<Position> {
+        let (p, q) = self.coefficients()?;
+        let position = Position::new(
+            rng,
+            DirectedTradingPair::new(self.base.id(), self.quote.id()),
+            fee,
+            p,
+            q,
+            reserves,
+        );
+        position.check_stateless()?;
+        Ok(position)
+    }
+
+    /// Formats the price of `base` in `quote` given by the coefficients `(p, q)` of a trading
+    /// function from `base` to `quote`, as in [`Self::coefficients`].
+    ///
+    /// The price is written exactly if it has at most [`MAX_DECIMALS`] decimal places, so that
+    /// the price a position was opened at reads back as it was written, and is otherwise rounded
+    /// down and marked with a `~`.
+    pub fn format_coefficients(base: &Unit, quote: &Unit, p: Amount, q: Amount) -> String {
+        let approximate = || {
+            let price = (p.value() as f64 * base.unit_amount().value() as f64)
+                / (q.value() as f64 * quote.unit_amount().value() as f64);
+            format!("~{price} {base}/{quote}")
+        };
+
+        // Cancel out common factors before multiplying, to keep within 128 bits.
+        let (p, quote_unit) = lowest_terms(p.value(), quote.unit_amount().value());
+        let (base_unit, q) = lowest_terms(base.unit_amount().value(), q.value());
+        let (Some(numerator), Some(denominator)) =
+            (p.checked_mul(base_unit), q.checked_mul(quote_unit))
+        else {
+            return approximate();
+        };
+        if denominator == 0 {
+            return approximate();
+        }
+
+        let mut price = (numerator / denominator).to_string();
+        let mut remainder = numerator % denominator;
+        if remainder != 0 {
+            price.push('.');
+        }
+        for _ in 0..MAX_DECIMALS {
+            if remainder == 0 {
+                return format!("{price} {base}/{quote}");
+            }
+            let Some(shifted) = remainder.checked_mul(10) else {
+                return approximate();
+            };
+            price.push(char::from(b'0' + (shifted / denominator) as u8));
+            remainder = shifted % denominator;
+        }
+        if remainder == 0 {
+            format!("{price} {base}/{quote}")
+        } else {
+            format!("~{price} {base}/{quote}")
+        }
+    }
+
+    /// Formats the price a position trades at, quoted as the price of `base` in `quote`.
+    ///
+    /// Returns `None` if `base` and `quote` aren't the position's assets.
+    pub fn format_position(position: &Position, base: &Unit, quote: &Unit) -> Option<String> {
+        if position.phi.pair != TradingPair::from(DirectedTradingPair::new(base.id(), quote.id())) {
+            return None;
+        }
+        let phi = position.phi.orient_start(base.id())?;
+        Some(Self::format_coefficients(base, quote, phi.p, phi.q))
+    }
+}
+
+fn lowest_terms(a: u128, b: u128) -> (u128, u128) {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+    if x == 0 {
+        (a, b)
+    } else {
+        (a / x, b / x)
+    }
+}
+
+impl fmt::Display for DisplayPrice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = format!(
+            "{:0>width$}",
+            self.scaled,
+            width = self.decimals as usize + 1
+        );
+        let (whole, fraction) = digits.split_at(digits.len() - self.decimals as usize);
+        if fraction.is_empty() {
+            write!(f, "{whole} {}/{}", self.base, self.quote)
+        } else {
+            write!(f, "{whole}.{fraction} {}/{}", self.base, self.quote)
+        }
+    }
+}
+
+impl FromStr for DisplayPrice {
+    type Err = anyhow::Error;
+
+    /// Parses a price written as, e.g., `1.25 penumbra/gm`.
+    fn from_str(s: &str) -> Result<Self> {
+        let (price, units) = s
+            .trim()
+            .split_once(char::is_whitespace)
+            .with_context(|| format!("price {s} must be written as, e.g., 1.25 penumbra/gm"))?;
+        Self::parse(price, units.trim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_price_round_trips_exactly() {
+        for price in [
+            "1.25 penumbra/gm",
+            "0.000001 gm/gn",
+            "3 gn/penumbra",
+            "1234.5678 mpenumbra/gm",
+        ] {
+            let parsed: DisplayPrice = price.parse().unwrap();
+            assert_eq!(parsed.to_string(), price);
+
+            let (p, q) = parsed.coefficients().unwrap();
+            assert_eq!(
+                DisplayPrice::format_coefficients(&parsed.base, &parsed.quote, p, q),
+                price
+            );
+
+            let position = parsed
+                .into_position(
+                    rand::thread_rng(),
+                    30,
+                    Reserves {
+                        r1: 1_000_000u64.into(),
+                        r2: 0u64.into(),
+                    },
+                )
+                .unwrap();
+            assert_eq!(
+                DisplayPrice::format_position(&position, &parsed.base, &parsed.quote).unwrap(),
+                price
+            );
+        }
+    }
+
+    #[test]
+    fn coefficients_are_in_base_units() {
+        // upenumbra and ugm both have 6 decimals, so 1.25 penumbra/gm is 5 ugm for 4 upenumbra.
+        let price: DisplayPrice = "1.25 penumbra/gm".parse().unwrap();
+        assert_eq!(price.coefficients().unwrap(), (5u64.into(), 4u64.into()));
+
+        // mpenumbra has 3 decimals, so 2 mpenumbra/gm is 2_000 ugm for 1 upenumbra.
+        let price: DisplayPrice = "2 mpenumbra/gm".parse().unwrap();
+        assert_eq!(
+            price.coefficients().unwrap(),
+            (2_000u64.into(), 1u64.into())
+        );
+    }
+
+    #[test]
+    fn inexact_prices_are_marked() {
+        let gm = REGISTRY.parse_unit("gm");
+        let gn = REGISTRY.parse_unit("gn");
+        let price = DisplayPrice::format_coefficients(&gm, &gn, 1u64.into(), 3u64.into());
+        assert!(price.starts_with("~0.333333"), "{price}");
+    }
+
+    #[test]
+    fn bad_prices_are_rejected() {
+        assert!("-1 penumbra/gm".parse::<DisplayPrice>().is_err());
+        assert!("0 penumbra/gm".parse::<DisplayPrice>().is_err());
+        assert!("1.25 penumbra".parse::<DisplayPrice>().is_err());
+        assert!("1.25 gm/gm".parse::<DisplayPrice>().is_err());
+        assert!("1.25penumbra/gm".parse::<DisplayPrice>().is_err());
+    }
+}
@@ -0,0 +1,196 @@
+//! Measuring how closely a set of liquidity positions replicates a target trading curve.
+//!
+//! A replicating strategy approximates a curve, such as that of a constant-product market
+//! maker, with a ladder of concentrated positions. Each position behaves like a limit order at
+//! its price: below that price it holds only the start asset, and above it only the end asset.
+//! The portfolio's value as a function of the market price is therefore piecewise linear, and
+//! can be compared with the target's portfolio value function at any price.
+
+use anyhow::anyhow;
+
+use super::position::Position;
+use crate::DirectedUnitPair;
+
+/// The value of a replicating portfolio and of its target at a single market price.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplicationSample {
+    /// The market price, in display units of the end asset per display unit of the start asset.
+    pub price: f64,
+    /// The value of the target portfolio at this price, in display units of the end asset.
+    pub target: f64,
+    /// The value of the positions at this price, in display units of the end asset.
+    pub replicated: f64,
+}
+
+impl ReplicationSample {
+    /// The amount by which the positions' value exceeds the target's.
+    pub fn error(&self) -> f64 {
+        self.replicated - self.target
+    }
+
+    /// The error relative to the target's value.
+    ///
+    /// This is zero if both values are zero, and infinite if only the target is.
+    pub fn relative_error(&self) -> f64 {
+        if self.target == 0.0 {
+            if self.replicated == 0.0 {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        } else {
+            self.error() / self.target
+        }
+    }
+}
+
+/// A report on how closely a set of positions tracks a target portfolio value function.
+///
+/// Fees are not taken into account: they only ever add to the value of the positions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplicationReport {
+    /// The samples the report was computed from, in increasing order of price.
+    pub samples: Vec<ReplicationSample>,
+}
+
+impl ReplicationReport {
+    /// Evaluates `positions` on `pair` against the portfolio value function `target` at each of
+    /// the given market prices.
+    ///
+    /// Errors if a position is not on `pair`.
+    pub fn new(
+        pair: &DirectedUnitPair,
+        positions: &[Position],
+        target: impl Fn(f64) -> f64,
+        prices: impl IntoIterator<Item = f64>,
+    ) -> anyhow::Result<Self> {
+        let ticks = positions
+            .iter()
+            .map(|position| Tick::new(pair, position))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut samples = prices
+            .into_iter()
+            .map(|price| ReplicationSample {
+                price,
+                target: target(price),
+                replicated: ticks.iter().map(|tick| tick.value_at(price)).sum(),
+            })
+            .collect::<Vec<_>>();
+        samples.sort_by(|a, b| a.price.total_cmp(&b.price));
+
+        Ok(Self { samples })
+    }
+
+    /// The largest absolute error over all samples.
+    pub fn max_absolute_error(&self) -> f64 {
+        self.samples
+            .iter()
+            .map(|sample| sample.error().abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// The largest relative error over all samples, i.e. the margin of error of the
+    /// replication.
+    pub fn max_relative_error(&self) -> f64 {
+        self.samples
+            .iter()
+            .map(|sample| sample.relative_error().abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// The root mean square of the relative errors, i.e. the expected tracking error when the
+    /// price is equally likely to be at any of the samples.
+    pub fn tracking_error(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let sum_of_squares: f64 = self
+            .samples
+            .iter()
+            .map(|sample| sample.relative_error().powi(2))
+            .sum();
+        (sum_of_squares / self.samples.len() as f64).sqrt()
+    }
+}
+
+/// A position reduced to its price and its size, in display units.
+struct Tick {
+    /// The price at which the position trades, in end asset per start asset.
+    price: f64,
+    /// The position's reserves, valued in the start asset at its own price.
+    size: f64,
+}
+
+impl Tick {
+    fn new(pair: &DirectedUnitPair, position: &Position) -> anyhow::Result<Self> {
+        let not_on_pair = || anyhow!("position {} is not on the pair {}", position.id(), pair);
+        let phi = position
+            .phi
+            .orient_start(pair.start.id())
+            .ok_or_else(not_on_pair)?;
+        let r_start = position
+            .reserves_for(pair.start.id())
+            .ok_or_else(not_on_pair)?;
+        let r_end = position
+            .reserves_for(pair.end.id())
+            .ok_or_else(not_on_pair)?;
+
+        let unit_start = pair.start.unit_amount().value() as f64;
+        let unit_end = pair.end.unit_amount().value() as f64;
+        let price = (phi.p.value() as f64 / phi.q.value() as f64) * (unit_start / unit_end);
+        let size = r_start.value() as f64 / unit_start + r_end.value() as f64 / unit_end / price;
+
+        Ok(Self { price, size })
+    }
+
+    /// The value of the position once arbitrage has moved it to the market price: it holds
+    /// only the end asset if the market price is above its own, and only the start asset
+    /// otherwise.
+    fn value_at(&self, market_price: f64) -> f64 {
+        self.size * market_price.min(self.price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+    use penumbra_num::Amount;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::lp::Reserves;
+
+    #[test]
+    fn single_tick_tracks_a_linear_target() -> anyhow::Result<()> {
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair = DirectedUnitPair::new(gm.clone(), gn.clone());
+
+        // Sells 2gm for 3gn each.
+        let position = Position::new(
+            OsRng,
+            pair.into_directed_trading_pair(),
+            0,
+            Amount::from(3u64) * gn.unit_amount(),
+            gm.unit_amount(),
+            Reserves {
+                r1: Amount::from(2u64) * gm.unit_amount(),
+                r2: Amount::zero(),
+            },
+        );
+
+        // The target is the position's own payoff, except above its price.
+        let report =
+            ReplicationReport::new(&pair, &[position], |price| 2.0 * price, [4.0, 1.0, 2.0])?;
+        let prices = report.samples.iter().map(|s| s.price).collect::<Vec<_>>();
+        assert_eq!(prices, [1.0, 2.0, 4.0]);
+        assert!(report.samples[0].error().abs() < 1e-9);
+        assert!(report.samples[1].error().abs() < 1e-9);
+        // At a price of 4gn, the position has sold out for 6gn, short of the target's 8gn.
+        assert!((report.samples[2].error() + 2.0).abs() < 1e-9);
+        assert!((report.max_relative_error() - 0.25).abs() < 1e-9);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::anyhow;
+use penumbra_num::Amount;
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::TradingPair;
+
+/// Statistics of the trading on a pair, over the last 24 hours and the current epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PairStats", into = "pb::PairStats")]
+pub struct PairStats {
+    pub trading_pair: TradingPair,
+    /// Trading in the 24 hours up to the latest block.
+    pub last_24h: PairStatsWindow,
+    /// Trading in the current epoch.
+    pub epoch: PairStatsWindow,
+}
+
+/// Statistics of the trading on a pair over some period.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PairStatsWindow", into = "pb::PairStatsWindow")]
+pub struct PairStatsWindow {
+    /// The amount of asset 1 traded by the pair's batch swaps: the input of the 1 => 2 direction
+    /// that was filled, plus the output of the 2 => 1 direction.
+    pub volume_1: Amount,
+    /// The amount of asset 2 traded by the pair's batch swaps: the input of the 2 => 1 direction
+    /// that was filled, plus the output of the 1 => 2 direction.
+    pub volume_2: Amount,
+    /// The fees paid in asset 1 to the pair's liquidity positions, on any route.
+    pub lp_fees_1: Amount,
+    /// The fees paid in asset 2 to the pair's liquidity positions, on any route.
+    pub lp_fees_2: Amount,
+    /// The number of the pair's batch swaps executed.
+    pub batches: u64,
+}
+
+impl std::ops::AddAssign for PairStatsWindow {
+    fn add_assign(&mut self, other: Self) {
+        self.volume_1 += other.volume_1;
+        self.volume_2 += other.volume_2;
+        self.lp_fees_1 += other.lp_fees_1;
+        self.lp_fees_2 += other.lp_fees_2;
+        self.batches += other.batches;
+    }
+}
+
+impl DomainType for PairStats {
+    type Proto = pb::PairStats;
+}
+
+impl From<PairStats> for pb::PairStats {
+    fn from(s: PairStats) -> Self {
+        pb::PairStats {
+            trading_pair: Some(s.trading_pair.into()),
+            last_24h: Some(s.last_24h.into()),
+            epoch: Some(s.epoch.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PairStats> for PairStats {
+    type Error = anyhow::Error;
+    fn try_from(s: pb::PairStats) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: s
+                .trading_pair
+                .ok_or_else(|| anyhow!("Missing trading_pair"))?
+                .try_into()?,
+            last_24h: s
+                .last_24h
+                .ok_or_else(|| anyhow!("Missing last_24h"))?
+                .try_into()?,
+            epoch: s
+                .epoch
+                .ok_or_else(|| anyhow!("Missing epoch"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<PairStats> for pb::PairStatsResponse {
+    fn from(s: PairStats) -> Self {
+        pb::PairStatsResponse {
+            stats: Some(s.into()),
+        }
+    }
+}
+
+impl DomainType for PairStatsWindow {
+    type Proto = pb::PairStatsWindow;
+}
+
+impl From<PairStatsWindow> for pb::PairStatsWindow {
+    fn from(w: PairStatsWindow) -> Self {
+        pb::PairStatsWindow {
+            volume_1: Some(w.volume_1.into()),
+            volume_2: Some(w.volume_2.into()),
+            lp_fees_1: Some(w.lp_fees_1.into()),
+            lp_fees_2: Some(w.lp_fees_2.into()),
+            batches: w.batches,
+        }
+    }
+}
+
+impl TryFrom<pb::PairStatsWindow> for PairStatsWindow {
+    type Error = anyhow::Error;
+    fn try_from(w: pb::PairStatsWindow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            volume_1: w
+                .volume_1
+                .ok_or_else(|| anyhow!("Missing volume_1"))?
+                .try_into()?,
+            volume_2: w
+                .volume_2
+                .ok_or_else(|| anyhow!("Missing volume_2"))?
+                .try_into()?,
+            lp_fees_1: w
+                .lp_fees_1
+                .ok_or_else(|| anyhow!("Missing lp_fees_1"))?
+                .try_into()?,
+            lp_fees_2: w
+                .lp_fees_2
+                .ok_or_else(|| anyhow!("Missing lp_fees_2"))?
+                .try_into()?,
+            batches: w.batches,
+        })
+    }
+}
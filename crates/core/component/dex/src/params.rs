@@ -0,0 +1,171 @@
+use penumbra_asset::asset;
+use penumbra_num::Amount;
+use penumbra_proto::core::component::dex::v1 as pb;
+use penumbra_proto::DomainType;
+use serde::{Deserialize, Serialize};
+
+use crate::lp::position::Position;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(try_from = "pb::DexParameters", into = "pb::DexParameters")]
+pub struct DexParameters {
+    /// Whether opening new positions and swapping are paused.
+    pub is_paused: bool,
+    /// The minimum amount of each asset a newly opened position may provision, if it provisions
+    /// any of it. Zero sets no minimum.
+    pub min_position_reserves: Amount,
+    /// The minimum fee, in basis points, a newly opened position may charge.
+    pub min_position_fee_bps: u32,
+    /// The maximum fee, in basis points, a newly opened position may charge. Zero sets no maximum
+    /// beyond the limit on all positions.
+    pub max_position_fee_bps: u32,
+    /// The multi-hop penalty, in basis points, of swaps that don't set a routing preference.
+    pub default_multi_hop_penalty_bps: u32,
+}
+
+/// The reasons a position can be rejected by [`DexParameters::check_position_open`].
+#[derive(Clone, Debug, thiserror::Error, PartialEq, Eq)]
+pub enum PositionOpenError {
+    #[error(
+        "position reserves of {amount} of asset {asset_id} are below the minimum of {minimum}"
+    )]
+    ReservesTooSmall {
+        asset_id: asset::Id,
+        amount: Amount,
+        minimum: Amount,
+    },
+    #[error("position fee of {fee}bps is below the minimum of {minimum}bps")]
+    FeeTooLow { fee: u32, minimum: u32 },
+    #[error("position fee of {fee}bps is above the maximum of {maximum}bps")]
+    FeeTooHigh { fee: u32, maximum: u32 },
+}
+
+impl DexParameters {
+    /// Checks that a newly opened position meets the minimum reserves and fee bounds.
+    ///
+    /// Dust positions would bloat the routing graph, slowing down path search for everyone,
+    /// without offering any meaningful liquidity.
+    pub fn check_position_open(&self, position: &Position) -> Result<(), PositionOpenError> {
+        let pair = position.phi.pair;
+        for (asset_id, amount) in [
+            (pair.asset_1(), position.reserves.r1),
+            (pair.asset_2(), position.reserves.r2),
+        ] {
+            if amount != Amount::zero() && amount < self.min_position_reserves {
+                return Err(PositionOpenError::ReservesTooSmall {
+                    asset_id,
+                    amount,
+                    minimum: self.min_position_reserves,
+                });
+            }
+        }
+
+        let fee = position.phi.component.fee;
+        if fee < self.min_position_fee_bps {
+            return Err(PositionOpenError::FeeTooLow {
+                fee,
+                minimum: self.min_position_fee_bps,
+            });
+        }
+        if self.max_position_fee_bps != 0 && fee > self.max_position_fee_bps {
+            return Err(PositionOpenError::FeeTooHigh {
+                fee,
+                maximum: self.max_position_fee_bps,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl DomainType for DexParameters {
+    type Proto = pb::DexParameters;
+}
+
+impl TryFrom<pb::DexParameters> for DexParameters {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::DexParameters) -> anyhow::Result<Self> {
+        Ok(DexParameters {
+            is_paused: msg.is_paused,
+            min_position_reserves: msg
+                .min_position_reserves
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            min_position_fee_bps: msg.min_position_fee_bps,
+            max_position_fee_bps: msg.max_position_fee_bps,
+            default_multi_hop_penalty_bps: msg.default_multi_hop_penalty_bps,
+        })
+    }
+}
+
+impl From<DexParameters> for pb::DexParameters {
+    fn from(params: DexParameters) -> Self {
+        pb::DexParameters {
+            is_paused: params.is_paused,
+            min_position_reserves: Some(params.min_position_reserves.into()),
+            min_position_fee_bps: params.min_position_fee_bps,
+            max_position_fee_bps: params.max_position_fee_bps,
+            default_multi_hop_penalty_bps: params.default_multi_hop_penalty_bps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::{lp::Reserves, DirectedTradingPair};
+
+    fn position(fee: u32, r1: u64, r2: u64) -> Position {
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        Position::new(
+            OsRng,
+            DirectedTradingPair::new(gm.id(), gn.id()),
+            fee,
+            1u64.into(),
+            1u64.into(),
+            Reserves {
+                r1: r1.into(),
+                r2: r2.into(),
+            },
+        )
+    }
+
+    #[test]
+    fn check_position_open() {
+        let params = DexParameters {
+            min_position_reserves: 1_000u64.into(),
+            min_position_fee_bps: 1,
+            max_position_fee_bps: 100,
+            ..Default::default()
+        };
+
+        // Only the assets actually provisioned need to meet the minimum.
+        assert_eq!(params.check_position_open(&position(30, 1_000, 0)), Ok(()));
+        assert!(matches!(
+            params.check_position_open(&position(30, 1_000, 999)),
+            Err(PositionOpenError::ReservesTooSmall { .. })
+        ));
+        assert_eq!(
+            params.check_position_open(&position(0, 1_000, 0)),
+            Err(PositionOpenError::FeeTooLow { fee: 0, minimum: 1 })
+        );
+        assert_eq!(
+            params.check_position_open(&position(101, 1_000, 0)),
+            Err(PositionOpenError::FeeTooHigh {
+                fee: 101,
+                maximum: 100
+            })
+        );
+
+        // The default parameters set no bounds.
+        assert_eq!(
+            DexParameters::default().check_position_open(&position(5_000, 1, 1)),
+            Ok(())
+        );
+    }
+}
@@ -2,6 +2,14 @@ use std::string::String;
 
 use crate::{lp::position, DirectedTradingPair, TradingPair};
 
+pub fn dex_params() -> &'static str {
+    "dex/params"
+}
+
+pub fn dex_params_updated() -> &'static str {
+    "dex/params_updated"
+}
+
 pub fn positions(trading_pair: &TradingPair, position_id: &str) -> String {
     format!("dex/positions/{trading_pair}/opened/{position_id}")
 }
@@ -47,6 +55,14 @@ pub fn swap_flows() -> &'static str {
     "dex/swap_flows"
 }
 
+pub fn execution_timings() -> &'static str {
+    "dex/execution_timings"
+}
+
+pub fn rounding_audit() -> &'static str {
+    "dex/rounding_audit"
+}
+
 pub fn pending_position_closures() -> &'static str {
     "dex/pending_position_closures"
 }
@@ -63,6 +79,21 @@ pub fn aggregate_value() -> &'static str {
     "dex/aggregate_value"
 }
 
+/// The fees paid to the liquidity positions of each pair that are yet to be added to the pair's
+/// statistics.
+pub fn pending_lp_fees() -> &'static str {
+    "dex/pending_lp_fees"
+}
+
+/// The rolling statistics of the trading on a pair, kept in nonverifiable storage.
+pub fn pair_stats(trading_pair: &TradingPair) -> String {
+    format!(
+        "dex/pair_stats/{}/{}",
+        &trading_pair.asset_1(),
+        &trading_pair.asset_2()
+    )
+}
+
 /// Encompasses non-consensus state keys.
 pub(crate) mod internal {
     use super::*;
@@ -130,4 +161,27 @@ pub(crate) mod internal {
             key.to_vec()
         }
     }
+
+    /// The heights positions were updated at, so that the positions that changed since a height
+    /// can be found without reading every position.
+    pub mod position_updates {
+        use super::*;
+
+        pub fn prefix() -> &'static [u8] {
+            b"dex/pu/"
+        }
+
+        /// The start of the range of updates made at `height` or later, relative to the prefix.
+        pub fn start(height: u64) -> Vec<u8> {
+            height.to_be_bytes().to_vec()
+        }
+
+        pub fn key(height: u64, id: &position::Id) -> Vec<u8> {
+            let mut key = [0u8; 47];
+            key[0..7].copy_from_slice(prefix());
+            key[7..15].copy_from_slice(&height.to_be_bytes());
+            key[15..47].copy_from_slice(&id.0);
+            key.to_vec()
+        }
+    }
 }
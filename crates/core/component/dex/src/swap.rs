@@ -7,6 +7,7 @@ mod ciphertext;
 mod payload;
 mod plaintext;
 mod plan;
+mod routing_preference;
 mod view;
 
 pub mod proof;
@@ -16,6 +17,7 @@ pub use ciphertext::SwapCiphertext;
 pub use payload::SwapPayload;
 pub use plaintext::{SwapPlaintext, SwapPlaintextVar};
 pub use plan::SwapPlan;
+pub use routing_preference::{RoutingPreference, MAX_MULTI_HOP_PENALTY_BPS};
 pub use view::SwapView;
 
 // Swap ciphertext byte length.
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::TradingPair;
 
-use super::{proof::SwapProof, SwapPayload};
+use super::{proof::SwapProof, RoutingPreference, SwapPayload};
 
 #[derive(Clone, Debug)]
 pub struct Swap {
@@ -87,6 +87,8 @@ pub struct Body {
     pub delta_2_i: Amount,
     pub fee_commitment: balance::Commitment,
     pub payload: SwapPayload,
+    /// How the swap prefers its batch to be routed, if not the chain's default.
+    pub routing_preference: Option<RoutingPreference>,
 }
 
 impl EffectingData for Body {
@@ -107,6 +109,7 @@ impl From<Body> for pb::SwapBody {
             delta_2_i: Some(s.delta_2_i.into()),
             fee_commitment: Some(s.fee_commitment.into()),
             payload: Some(s.payload.into()),
+            routing_preference: s.routing_preference.map(Into::into),
         }
     }
 }
@@ -137,6 +140,11 @@ impl TryFrom<pb::SwapBody> for Body {
                 .payload
                 .ok_or_else(|| anyhow::anyhow!("missing payload"))?
                 .try_into()?,
+            routing_preference: s
+                .routing_preference
+                .map(TryInto::try_into)
+                .transpose()
+                .context("routing preference malformed")?,
         })
     }
 }
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 use crate::swap::proof::{SwapProofPrivate, SwapProofPublic};
 
 // TODO: rename action::Body to SwapBody
-use super::{action as swap, proof::SwapProof, Swap, SwapPlaintext};
+use super::{action as swap, proof::SwapProof, RoutingPreference, Swap, SwapPlaintext};
 
 /// A planned [`Swap`](Swap).
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -21,6 +21,7 @@ pub struct SwapPlan {
     pub fee_blinding: Fr,
     pub proof_blinding_r: Fq,
     pub proof_blinding_s: Fq,
+    pub routing_preference: Option<RoutingPreference>,
 }
 
 impl SwapPlan {
@@ -33,6 +34,7 @@ impl SwapPlan {
             swap_plaintext,
             proof_blinding_r: Fq::rand(rng),
             proof_blinding_s: Fq::rand(rng),
+            routing_preference: None,
         }
     }
 
@@ -52,6 +54,7 @@ impl SwapPlan {
             delta_2_i: self.swap_plaintext.delta_2_i,
             fee_commitment: self.fee_commitment(),
             payload: self.swap_plaintext.encrypt(fvk.outgoing()),
+            routing_preference: self.routing_preference,
         }
     }
 
@@ -125,6 +128,7 @@ impl From<SwapPlan> for pb::SwapPlan {
             fee_blinding: msg.fee_blinding.to_bytes().to_vec(),
             proof_blinding_r: msg.proof_blinding_r.to_bytes().to_vec(),
             proof_blinding_s: msg.proof_blinding_s.to_bytes().to_vec(),
+            routing_preference: msg.routing_preference.map(Into::into),
         }
     }
 }
@@ -153,6 +157,11 @@ impl TryFrom<pb::SwapPlan> for SwapPlan {
                 .context("swap plaintext malformed")?,
             proof_blinding_r: Fq::from_bytes(proof_blinding_r_bytes)?,
             proof_blinding_s: Fq::from_bytes(proof_blinding_s_bytes)?,
+            routing_preference: msg
+                .routing_preference
+                .map(TryInto::try_into)
+                .transpose()
+                .context("routing preference malformed")?,
         })
     }
 }
@@ -0,0 +1,50 @@
+use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// The largest multi-hop penalty a swap can ask for, 100%.
+pub const MAX_MULTI_HOP_PENALTY_BPS: u32 = 10_000;
+
+/// A swap's preference for how the input of its batch is routed.
+///
+/// Each direction of a batch is routed with the preferences of its swaps averaged,
+/// weighted by their inputs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "pb::RoutingPreference", into = "pb::RoutingPreference")]
+pub struct RoutingPreference {
+    /// The penalty, in basis points, applied to the estimated price of a route for each
+    /// hop beyond the first.
+    pub multi_hop_penalty_bps: u32,
+}
+
+impl RoutingPreference {
+    /// Prefer routes with fewer hops unless a longer one is better by more than `bps`.
+    pub fn with_multi_hop_penalty_bps(multi_hop_penalty_bps: u32) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            multi_hop_penalty_bps <= MAX_MULTI_HOP_PENALTY_BPS,
+            "multi-hop penalty of {multi_hop_penalty_bps}bps exceeds the maximum of {MAX_MULTI_HOP_PENALTY_BPS}bps",
+        );
+        Ok(Self {
+            multi_hop_penalty_bps,
+        })
+    }
+}
+
+impl DomainType for RoutingPreference {
+    type Proto = pb::RoutingPreference;
+}
+
+impl From<RoutingPreference> for pb::RoutingPreference {
+    fn from(preference: RoutingPreference) -> Self {
+        Self {
+            multi_hop_penalty_bps: preference.multi_hop_penalty_bps,
+        }
+    }
+}
+
+impl TryFrom<pb::RoutingPreference> for RoutingPreference {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::RoutingPreference) -> Result<Self, Self::Error> {
+        Self::with_multi_hop_penalty_bps(msg.multi_hop_penalty_bps)
+    }
+}
@@ -1,6 +1,8 @@
 use penumbra_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
 use serde::{Deserialize, Serialize};
 
+use crate::TradingPairView;
+
 use super::{Swap, SwapPlaintext};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -10,6 +12,8 @@ pub enum SwapView {
     Visible {
         swap: Swap,
         swap_plaintext: SwapPlaintext,
+        /// The swap's trading pair, with the metadata of its assets where known.
+        trading_pair: TradingPairView,
     },
     Opaque {
         swap: Swap,
@@ -28,16 +32,25 @@ impl TryFrom<pb::SwapView> for SwapView {
             .swap_view
             .ok_or_else(|| anyhow::anyhow!("missing swap field"))?
         {
-            pb::swap_view::SwapView::Visible(x) => Ok(SwapView::Visible {
-                swap: x
-                    .swap
-                    .ok_or_else(|| anyhow::anyhow!("missing swap field"))?
-                    .try_into()?,
-                swap_plaintext: x
+            pb::swap_view::SwapView::Visible(x) => {
+                let swap_plaintext: SwapPlaintext = x
                     .swap_plaintext
                     .ok_or_else(|| anyhow::anyhow!("missing swap plaintext field"))?
-                    .try_into()?,
-            }),
+                    .try_into()?;
+                let trading_pair = TradingPairView::new(
+                    swap_plaintext.trading_pair,
+                    x.asset_1_metadata.map(TryInto::try_into).transpose()?,
+                    x.asset_2_metadata.map(TryInto::try_into).transpose()?,
+                )?;
+                Ok(SwapView::Visible {
+                    swap: x
+                        .swap
+                        .ok_or_else(|| anyhow::anyhow!("missing swap field"))?
+                        .try_into()?,
+                    swap_plaintext,
+                    trading_pair,
+                })
+            }
             pb::swap_view::SwapView::Opaque(x) => Ok(SwapView::Opaque {
                 swap: x
                     .swap
@@ -55,15 +68,16 @@ impl From<SwapView> for pb::SwapView {
             SwapView::Visible {
                 swap,
                 swap_plaintext,
+                trading_pair,
             } => Self {
                 swap_view: Some(sv::SwapView::Visible(sv::Visible {
                     swap: Some(swap.into()),
                     swap_plaintext: Some(swap_plaintext.into()),
                     // Swap claim crossreferencing is not yet supported in the Rust stack.
                     claim_tx: None,
+                    asset_1_metadata: trading_pair.asset_1_metadata.map(Into::into),
+                    asset_2_metadata: trading_pair.asset_2_metadata.map(Into::into),
                     // These fields are also not yet supported in the Rust stack.
-                    asset_1_metadata: None,
-                    asset_2_metadata: None,
                     batch_swap_output_data: None,
                     output_1: None,
                     output_2: None,
@@ -81,10 +95,7 @@ impl From<SwapView> for pb::SwapView {
 impl From<SwapView> for Swap {
     fn from(v: SwapView) -> Self {
         match v {
-            SwapView::Visible {
-                swap,
-                swap_plaintext: _,
-            } => swap,
+            SwapView::Visible { swap, .. } => swap,
             SwapView::Opaque { swap } => swap,
         }
     }
@@ -1,7 +1,10 @@
+use penumbra_asset::ValueView;
 use penumbra_proto::{penumbra::core::component::dex::v1 as pbd, DomainType};
 use penumbra_shielded_pool::NoteView;
 use serde::{Deserialize, Serialize};
 
+use crate::TradingPairView;
+
 use super::SwapClaim;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -18,6 +21,34 @@ pub enum SwapClaimView {
     },
 }
 
+impl SwapClaimView {
+    /// The trading pair of the claimed swap, with the metadata of its assets where the views of
+    /// the outputs know it.
+    pub fn trading_pair(&self) -> TradingPairView {
+        match self {
+            SwapClaimView::Visible {
+                swap_claim,
+                output_1,
+                output_2,
+            } => {
+                let metadata = |output: &NoteView| match &output.value {
+                    ValueView::KnownAssetId { metadata, .. } => Some(metadata.clone()),
+                    ValueView::UnknownAssetId { .. } => None,
+                };
+                // The outputs of a claim are notes of the pair's assets, in order, but if the
+                // views disagree, the pair is still viewed without them.
+                TradingPairView::new(
+                    swap_claim.body.output_data.trading_pair,
+                    metadata(output_1),
+                    metadata(output_2),
+                )
+                .unwrap_or_else(|_| swap_claim.body.output_data.trading_pair.into())
+            }
+            SwapClaimView::Opaque { swap_claim } => swap_claim.body.output_data.trading_pair.into(),
+        }
+    }
+}
+
 impl DomainType for SwapClaimView {
     type Proto = pbd::SwapClaimView;
 }
@@ -14,6 +14,18 @@ pub struct SwapExecution {
 }
 
 impl SwapExecution {
+    /// Returns the price of the earliest execution trace.
+    ///
+    /// Traces are filled best price first, so this is the closest thing to the spot price
+    /// the trade saw.
+    pub fn min_price(&self) -> Option<U128x128> {
+        let trace = self.traces.first()?;
+        let input = trace.first()?;
+        let output = trace.last()?;
+
+        U128x128::ratio(input.amount, output.amount).ok()
+    }
+
     /// Returns the price of the latest execution trace.
     pub fn max_price(&self) -> Option<U128x128> {
         let Some((input, output)) = self.traces.last().and_then(|trace| {
@@ -27,6 +39,18 @@ impl SwapExecution {
         let price = U128x128::ratio(input.amount, output.amount).ok()?;
         Some(price)
     }
+
+    /// Returns the price impact of the trade, as a fraction: how much worse the average price of
+    /// the whole trade is than the price of its first, best-priced trace.
+    ///
+    /// Prices are quoted as input per unit of output, so this is `1 - min_price / average_price`.
+    /// Returns `None` if nothing was filled.
+    pub fn price_impact(&self) -> Option<U128x128> {
+        let spot_price = self.min_price()?;
+        let average_price = U128x128::ratio(self.input.amount, self.output.amount).ok()?;
+        let ratio = spot_price.checked_div(&average_price).ok()?;
+        Some(U128x128::from(1u64).saturating_sub(&ratio))
+    }
 }
 
 impl DomainType for SwapExecution {
@@ -74,3 +98,48 @@ impl From<SwapExecution> for pb::SwapExecution {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::asset;
+
+    use super::*;
+
+    fn value(amount: u64, asset_id: asset::Id) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id,
+        }
+    }
+
+    #[test]
+    fn price_impact_compares_average_and_best_prices() {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .expect("gm is known")
+            .id();
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .expect("gn is known")
+            .id();
+
+        // 100 at a price of 1, then 100 at a price of 4, for an average price of 1.6.
+        let execution = SwapExecution {
+            traces: vec![
+                vec![value(100, gm), value(100, gn)],
+                vec![value(100, gm), value(25, gn)],
+            ],
+            input: value(200, gm),
+            output: value(125, gn),
+        };
+        let impact = f64::from(execution.price_impact().expect("trade was filled"));
+        assert!((impact - 0.375).abs() < 1e-9);
+
+        let unfilled = SwapExecution {
+            traces: vec![],
+            input: value(0, gm),
+            output: value(0, gn),
+        };
+        assert_eq!(unfilled.price_impact(), None);
+    }
+}
@@ -10,7 +10,10 @@ use std::{
     str::FromStr,
 };
 
-use penumbra_asset::asset::{self, AssetIdVar, Unit, REGISTRY};
+use penumbra_asset::{
+    asset::{self, AssetIdVar, Metadata, Unit, REGISTRY},
+    Value, ValueView,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 #[serde(try_from = "pb::DirectedTradingPair", into = "pb::DirectedTradingPair")]
@@ -262,6 +265,109 @@ impl fmt::Display for TradingPair {
     }
 }
 
+/// A [`TradingPair`] with the metadata of its assets, where known, so it can be displayed
+/// with denominations rather than asset IDs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradingPairView {
+    pub trading_pair: TradingPair,
+    pub asset_1_metadata: Option<Metadata>,
+    pub asset_2_metadata: Option<Metadata>,
+}
+
+impl TradingPair {
+    /// Views the trading pair, with the metadata of its assets found in `cache`.
+    pub fn view_with_cache(&self, cache: &asset::Cache) -> TradingPairView {
+        TradingPairView {
+            trading_pair: *self,
+            asset_1_metadata: cache.get(&self.asset_1).cloned(),
+            asset_2_metadata: cache.get(&self.asset_2).cloned(),
+        }
+    }
+}
+
+impl TradingPairView {
+    /// Views `trading_pair` with the given metadata of its assets, checking that the metadata
+    /// describes them.
+    pub fn new(
+        trading_pair: TradingPair,
+        asset_1_metadata: Option<Metadata>,
+        asset_2_metadata: Option<Metadata>,
+    ) -> anyhow::Result<Self> {
+        for (metadata, asset_id) in [
+            (&asset_1_metadata, trading_pair.asset_1),
+            (&asset_2_metadata, trading_pair.asset_2),
+        ] {
+            if let Some(metadata) = metadata {
+                anyhow::ensure!(
+                    metadata.id() == asset_id,
+                    "metadata for {} given for asset {}",
+                    metadata,
+                    asset_id
+                );
+            }
+        }
+        Ok(Self {
+            trading_pair,
+            asset_1_metadata,
+            asset_2_metadata,
+        })
+    }
+
+    /// The metadata of `asset_id`, if it's one of the pair's assets and its metadata is known.
+    pub fn metadata(&self, asset_id: &asset::Id) -> Option<&Metadata> {
+        if *asset_id == self.trading_pair.asset_1 {
+            self.asset_1_metadata.as_ref()
+        } else if *asset_id == self.trading_pair.asset_2 {
+            self.asset_2_metadata.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Views a value of one of the pair's assets, with its metadata if known.
+    pub fn view_value(&self, value: Value) -> ValueView {
+        match self.metadata(&value.asset_id) {
+            Some(metadata) => ValueView::KnownAssetId {
+                amount: value.amount,
+                metadata: metadata.clone(),
+                equivalent_values: Vec::new(),
+                extended_metadata: None,
+            },
+            None => ValueView::UnknownAssetId {
+                amount: value.amount,
+                asset_id: value.asset_id,
+            },
+        }
+    }
+}
+
+impl From<TradingPair> for TradingPairView {
+    fn from(trading_pair: TradingPair) -> Self {
+        Self {
+            trading_pair,
+            asset_1_metadata: None,
+            asset_2_metadata: None,
+        }
+    }
+}
+
+/// Produces an output string of the form ASSET1:ASSET2, naming each asset by the default unit
+/// of its denomination if known, or by its asset ID otherwise.
+impl fmt::Display for TradingPairView {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = |metadata: &Option<Metadata>, id: &asset::Id| match metadata {
+            Some(metadata) => metadata.default_unit().to_string(),
+            None => id.to_string(),
+        };
+        write!(
+            f,
+            "{}:{}",
+            name(&self.asset_1_metadata, &self.trading_pair.asset_1),
+            name(&self.asset_2_metadata, &self.trading_pair.asset_2),
+        )
+    }
+}
+
 /// A directed tuple of `Unit`s, similar to a `DirectedTradingPair` but embedding
 /// useful denom data.
 #[derive(Clone, Debug)]
@@ -0,0 +1,184 @@
+use penumbra_proto::{core::component::fee::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{Gas, GasPrices};
+
+/// Parameters of a base fee that adjusts to how full recent blocks were.
+///
+/// After each block, the price of each resource moves towards balancing its use against its
+/// target, in the manner of EIP-1559: it rises if the block used more than the target, and falls
+/// if it used less, by up to `max_change_bps` of the price when the block used none or twice the
+/// target. The prices never leave the range between `min_gas_prices` and `max_gas_prices`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "pb::DynamicBaseFee", into = "pb::DynamicBaseFee")]
+pub struct DynamicBaseFee {
+    pub min_gas_prices: GasPrices,
+    pub max_gas_prices: GasPrices,
+    pub target_block_gas: Gas,
+    pub max_change_bps: u32,
+}
+
+impl DynamicBaseFee {
+    /// The gas prices for the next block, given the prices for the last one and the gas it used.
+    pub fn next_gas_prices(&self, current: &GasPrices, used: &Gas) -> GasPrices {
+        let next = |price, used, target, min, max| {
+            adjust(price, used, target, self.max_change_bps).clamp(min, max)
+        };
+        GasPrices {
+            block_space_price: next(
+                current.block_space_price,
+                used.block_space,
+                self.target_block_gas.block_space,
+                self.min_gas_prices.block_space_price,
+                self.max_gas_prices.block_space_price,
+            ),
+            compact_block_space_price: next(
+                current.compact_block_space_price,
+                used.compact_block_space,
+                self.target_block_gas.compact_block_space,
+                self.min_gas_prices.compact_block_space_price,
+                self.max_gas_prices.compact_block_space_price,
+            ),
+            verification_price: next(
+                current.verification_price,
+                used.verification,
+                self.target_block_gas.verification,
+                self.min_gas_prices.verification_price,
+                self.max_gas_prices.verification_price,
+            ),
+            execution_price: next(
+                current.execution_price,
+                used.execution,
+                self.target_block_gas.execution,
+                self.min_gas_prices.execution_price,
+                self.max_gas_prices.execution_price,
+            ),
+        }
+    }
+
+    /// The highest the gas prices can have risen to `blocks` blocks after they were `current`.
+    ///
+    /// Paying these prices keeps a transaction valid while it waits up to that many blocks to be
+    /// included, however full those blocks are.
+    pub fn max_gas_prices_after(&self, current: &GasPrices, blocks: u32) -> GasPrices {
+        let full = Gas {
+            block_space: self.target_block_gas.block_space.saturating_mul(2),
+            compact_block_space: self.target_block_gas.compact_block_space.saturating_mul(2),
+            verification: self.target_block_gas.verification.saturating_mul(2),
+            execution: self.target_block_gas.execution.saturating_mul(2),
+        };
+        (0..blocks).fold(*current, |prices, _| self.next_gas_prices(&prices, &full))
+    }
+}
+
+/// Moves `price` towards balancing `used` gas against `target`, by up to `max_change_bps` of it.
+fn adjust(price: u64, used: u64, target: u64, max_change_bps: u32) -> u64 {
+    if target == 0 {
+        return price;
+    }
+    let (price, used, target) = (price as u128, used as u128, target as u128);
+    let difference = used.abs_diff(target).min(target);
+    let change = price * difference * max_change_bps as u128 / (target * 10_000);
+    let next = if used > target {
+        // Always rise a little when over target, so that a price of zero doesn't stay at zero.
+        price + change.max(1)
+    } else {
+        price - change
+    };
+    next.min(u64::MAX as u128) as u64
+}
+
+impl DomainType for DynamicBaseFee {
+    type Proto = pb::DynamicBaseFee;
+}
+
+impl TryFrom<pb::DynamicBaseFee> for DynamicBaseFee {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::DynamicBaseFee) -> anyhow::Result<Self> {
+        Ok(DynamicBaseFee {
+            min_gas_prices: proto.min_gas_prices.unwrap_or_default().try_into()?,
+            max_gas_prices: proto.max_gas_prices.unwrap_or_default().try_into()?,
+            target_block_gas: proto.target_block_gas.unwrap_or_default().try_into()?,
+            max_change_bps: proto.max_change_bps,
+        })
+    }
+}
+
+impl From<DynamicBaseFee> for pb::DynamicBaseFee {
+    fn from(params: DynamicBaseFee) -> Self {
+        pb::DynamicBaseFee {
+            min_gas_prices: Some(params.min_gas_prices.into()),
+            max_gas_prices: Some(params.max_gas_prices.into()),
+            target_block_gas: Some(params.target_block_gas.into()),
+            max_change_bps: params.max_change_bps,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices(price: u64) -> GasPrices {
+        GasPrices {
+            block_space_price: price,
+            compact_block_space_price: price,
+            verification_price: price,
+            execution_price: price,
+        }
+    }
+
+    fn gas(amount: u64) -> Gas {
+        Gas {
+            block_space: amount,
+            compact_block_space: amount,
+            verification: amount,
+            execution: amount,
+        }
+    }
+
+    fn params() -> DynamicBaseFee {
+        DynamicBaseFee {
+            min_gas_prices: prices(10),
+            max_gas_prices: prices(1_000),
+            target_block_gas: gas(100),
+            max_change_bps: 1_250,
+        }
+    }
+
+    #[test]
+    fn prices_follow_block_fullness() {
+        let params = params();
+        // On target, the prices don't move.
+        assert_eq!(params.next_gas_prices(&prices(400), &gas(100)), prices(400));
+        // Twice the target raises them by the most allowed, and an empty block lowers them by it.
+        assert_eq!(params.next_gas_prices(&prices(400), &gas(200)), prices(450));
+        assert_eq!(
+            params.next_gas_prices(&prices(400), &gas(1_000)),
+            prices(450)
+        );
+        assert_eq!(params.next_gas_prices(&prices(400), &gas(0)), prices(350));
+        // In between, they move in proportion.
+        assert_eq!(params.next_gas_prices(&prices(400), &gas(150)), prices(425));
+        // A little over target always raises them.
+        assert_eq!(params.next_gas_prices(&prices(10), &gas(101)), prices(11));
+    }
+
+    #[test]
+    fn prices_stay_within_bounds() {
+        let params = params();
+        assert_eq!(params.next_gas_prices(&prices(10), &gas(0)), prices(10));
+        assert_eq!(
+            params.next_gas_prices(&prices(990), &gas(200)),
+            prices(1_000)
+        );
+        // Prices set before the base fee was dynamic are brought within bounds.
+        assert_eq!(params.next_gas_prices(&prices(0), &gas(100)), prices(10));
+        assert_eq!(
+            params.max_gas_prices_after(&prices(400), 100),
+            prices(1_000)
+        );
+        assert_eq!(params.max_gas_prices_after(&prices(400), 2), prices(506));
+    }
+}
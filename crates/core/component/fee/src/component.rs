@@ -1,3 +1,4 @@
+mod action_handler;
 pub mod rpc;
 mod view;
 
@@ -7,6 +8,7 @@ use crate::genesis;
 use async_trait::async_trait;
 use cnidarium::StateWrite;
 use cnidarium_component::Component;
+use penumbra_sct::component::clock::EpochRead;
 use tendermint::abci;
 use tracing::instrument;
 pub use view::{StateReadExt, StateWriteExt};
@@ -37,12 +39,44 @@ impl Component for Fee {
     ) {
     }
 
-    #[instrument(name = "fee", skip(_state, _end_block))]
+    #[instrument(name = "fee", skip(state, _end_block))]
     async fn end_block<S: StateWrite + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _end_block: &abci::request::EndBlock,
     ) {
-        // TODO: update gas prices here eventually
+        let state = Arc::get_mut(state).expect("state should be unique");
+        let used = state.pending_block_gas_used();
+        state.put_block_gas_used(used);
+
+        let fee_params = state
+            .get_fee_params()
+            .await
+            .expect("fee params must be present in state");
+        let current = state
+            .get_gas_prices()
+            .await
+            .expect("gas prices must be present in state");
+        let next = match &fee_params.dynamic_base_fee {
+            // Move the prices towards balancing the gas blocks use against their target.
+            Some(dynamic_base_fee) => dynamic_base_fee.next_gas_prices(&current, &used),
+            // Otherwise, the prices are fixed, though governance may have just changed them.
+            None => fee_params.fixed_gas_prices,
+        };
+        if next != current {
+            tracing::debug!(?current, ?next, ?used, "updating gas prices");
+            state.put_gas_prices(next);
+        }
+
+        // Grants can be used up to and including their expiry height, so the ones expiring at
+        // this height are no longer usable from the next block on.
+        let height = state
+            .get_block_height()
+            .await
+            .expect("block height must be present in state");
+        state
+            .prune_expired_fee_grants(height)
+            .await
+            .expect("pruning expired fee grants is infallible");
     }
 
     #[instrument(name = "fee", skip(_state))]
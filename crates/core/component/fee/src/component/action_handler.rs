@@ -0,0 +1,3 @@
+mod fee_grant;
+mod fee_grant_reclaim;
+mod fee_grant_use;
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use penumbra_num::Amount;
+use penumbra_sct::component::clock::EpochRead;
+
+use crate::{
+    component::{StateReadExt, StateWriteExt},
+    grant::FeeGrant,
+};
+
+#[async_trait]
+impl ActionHandler for FeeGrant {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        ensure!(
+            self.allowance.amount() > Amount::zero(),
+            "fee grant allowance must be nonzero"
+        );
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        let current_height = state.get_block_height().await?;
+        ensure!(
+            self.expiry_height >= current_height,
+            "fee grant expiry height {} is in the past (current height is {})",
+            self.expiry_height,
+            current_height
+        );
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Checked here rather than in `check_stateful`, since an identical grant
+        // could be created earlier in the same block.
+        let id = self.id();
+        ensure!(
+            state.fee_grant(&id).await?.is_none(),
+            "fee grant {id} already exists"
+        );
+
+        state.put_fee_grant(&id, self.clone());
+
+        Ok(())
+    }
+}
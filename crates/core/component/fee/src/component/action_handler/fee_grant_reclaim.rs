@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, ensure, Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use penumbra_txhash::TransactionContext;
+
+use crate::{
+    component::{StateReadExt, StateWriteExt},
+    grant::{FeeGrant, FeeGrantReclaim},
+};
+
+#[async_trait]
+impl ActionHandler for FeeGrantReclaim {
+    type CheckStatelessContext = TransactionContext;
+    async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
+        self.body
+            .sponsor
+            .verify(context.effect_hash.as_ref(), &self.auth_sig)
+            .context("fee grant reclaim auth signature failed to verify")?;
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        self.reclaimed_grant(state.as_ref()).await?;
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Re-checked here, since other transactions in the same block may have
+        // drawn on or reclaimed the grant since `check_stateful` ran.
+        let grant = self.reclaimed_grant(&state).await?;
+        state.delete_fee_grant(&self.body.grant_id, &grant);
+
+        Ok(())
+    }
+}
+
+impl FeeGrantReclaim {
+    /// Checks that the sponsor of the grant is reclaiming all that remains of it,
+    /// returning the grant.
+    async fn reclaimed_grant<S: StateRead>(&self, state: &S) -> Result<FeeGrant> {
+        let id = &self.body.grant_id;
+        let grant = state
+            .fee_grant(id)
+            .await?
+            .ok_or_else(|| anyhow!("fee grant {id} does not exist"))?;
+
+        ensure!(
+            grant.sponsor == self.body.sponsor,
+            "fee grant {id} was not made by the provided sponsor key"
+        );
+        ensure!(
+            grant.allowance.asset_id() == self.body.amount.asset_id(),
+            "fee grant {id} is not in the reclaimed asset"
+        );
+        ensure!(
+            grant.allowance.amount() == self.body.amount.amount(),
+            "fee grant {id} has {} remaining, not the {} reclaimed",
+            grant.allowance.amount(),
+            self.body.amount.amount()
+        );
+
+        Ok(grant)
+    }
+}
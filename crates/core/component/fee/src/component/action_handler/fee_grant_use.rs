@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, ensure, Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use cnidarium_component::ActionHandler;
+use penumbra_asset::Value;
+use penumbra_sct::component::clock::EpochRead;
+use penumbra_txhash::TransactionContext;
+
+use crate::{
+    component::{StateReadExt, StateWriteExt},
+    grant::{FeeGrant, FeeGrantUse},
+    Fee,
+};
+
+#[async_trait]
+impl ActionHandler for FeeGrantUse {
+    type CheckStatelessContext = TransactionContext;
+    async fn check_stateless(&self, context: TransactionContext) -> Result<()> {
+        self.body
+            .grantee
+            .verify(context.effect_hash.as_ref(), &self.auth_sig)
+            .context("fee grant use auth signature failed to verify")?;
+
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: Arc<S>) -> Result<()> {
+        self.draw(state.as_ref()).await?;
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        // Re-checked here, since other transactions in the same block may have
+        // drawn on the grant since `check_stateful` ran.
+        let grant = self.draw(&state).await?;
+        state.put_fee_grant(&self.body.grant_id, grant);
+
+        Ok(())
+    }
+}
+
+impl FeeGrantUse {
+    /// Checks that the grant can cover this use, returning the grant with the
+    /// amount drawn deducted from its allowance.
+    async fn draw<S: StateRead>(&self, state: &S) -> Result<FeeGrant> {
+        let id = &self.body.grant_id;
+        let mut grant = state
+            .fee_grant(id)
+            .await?
+            .ok_or_else(|| anyhow!("fee grant {id} does not exist"))?;
+
+        let current_height = state.get_block_height().await?;
+        ensure!(
+            grant.expiry_height >= current_height,
+            "fee grant {id} expired at height {}",
+            grant.expiry_height
+        );
+        ensure!(
+            grant.grantee == self.body.grantee,
+            "fee grant {id} was not made to the provided grantee key"
+        );
+        ensure!(
+            grant.allowance.asset_id() == self.body.amount.asset_id(),
+            "fee grant {id} cannot pay fees in the requested asset"
+        );
+
+        let remaining = grant
+            .allowance
+            .amount()
+            .checked_sub(&self.body.amount.amount())
+            .ok_or_else(|| anyhow!("fee grant {id} has insufficient allowance remaining"))?;
+        grant.allowance = Fee(Value {
+            amount: remaining,
+            asset_id: grant.allowance.asset_id(),
+        });
+
+        Ok(grant)
+    }
+}
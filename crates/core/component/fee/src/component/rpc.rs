@@ -1,6 +1,15 @@
+use std::pin::Pin;
+
+use async_stream::try_stream;
 use async_trait::async_trait;
 use cnidarium::Storage;
-use penumbra_proto::core::component::fee::v1::{self as pb, query_service_server::QueryService};
+use decaf377_rdsa::{SpendAuth, VerificationKey};
+use futures::{StreamExt, TryStreamExt};
+use penumbra_proto::{
+    core::component::fee::v1::{self as pb, query_service_server::QueryService},
+    DomainType,
+};
+use penumbra_sct::component::clock::EpochRead;
 
 use super::StateReadExt;
 
@@ -17,6 +26,13 @@ impl Server {
 
 #[async_trait]
 impl QueryService for Server {
+    type FeeGrantsByGranteeStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::FeeGrantsByGranteeResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+
     async fn current_gas_prices(
         &self,
         _request: tonic::Request<pb::CurrentGasPricesRequest>,
@@ -32,4 +48,76 @@ impl QueryService for Server {
             gas_prices: Some(gas_prices.into()),
         }))
     }
+
+    async fn base_fee(
+        &self,
+        _request: tonic::Request<pb::BaseFeeRequest>,
+    ) -> Result<tonic::Response<pb::BaseFeeResponse>, tonic::Status> {
+        let state = self.storage.latest_snapshot();
+
+        let gas_prices = state
+            .get_gas_prices()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let block_gas_used = state
+            .get_block_gas_used()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let fee_params = state
+            .get_fee_params()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(pb::BaseFeeResponse {
+            gas_prices: Some(gas_prices.into()),
+            block_gas_used: Some(block_gas_used.into()),
+            dynamic_base_fee: fee_params.dynamic_base_fee.map(Into::into),
+        }))
+    }
+
+    async fn fee_grants_by_grantee(
+        &self,
+        request: tonic::Request<pb::FeeGrantsByGranteeRequest>,
+    ) -> Result<tonic::Response<Self::FeeGrantsByGranteeStream>, tonic::Status> {
+        let state = self.storage.latest_snapshot();
+
+        let grantee: VerificationKey<SpendAuth> = request
+            .into_inner()
+            .grantee
+            .ok_or_else(|| tonic::Status::invalid_argument("missing grantee"))?
+            .try_into()
+            .map_err(|_| tonic::Status::invalid_argument("invalid grantee"))?;
+
+        let current_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+        let ids = state
+            .fee_grant_ids_by_grantee(&grantee)
+            .await
+            .map_err(|e| tonic::Status::internal(e.to_string()))?;
+
+        let s = try_stream! {
+            for id in ids {
+                let grant = state
+                    .fee_grant(&id)
+                    .await?
+                    .expect("indexed fee grant must be present");
+                if grant.expiry_height < current_height {
+                    continue;
+                }
+                yield pb::FeeGrantsByGranteeResponse {
+                    grant_id: Some(id.into()),
+                    grant: Some(grant.to_proto()),
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("error getting fee grants: {e}"))
+            })
+            .boxed(),
+        ))
+    }
 }
@@ -1,9 +1,17 @@
 use anyhow::{anyhow, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
+use decaf377_rdsa::{SpendAuth, VerificationKey};
+use futures::{StreamExt, TryStreamExt};
+use penumbra_asset::{asset, STAKING_TOKEN_ASSET_ID};
+use penumbra_num::Amount;
 use penumbra_proto::{StateReadProto, StateWriteProto};
 
-use crate::{params::FeeParameters, state_key, GasPrices};
+use crate::{
+    grant::{FeeGrant, FeeGrantId},
+    params::FeeParameters,
+    state_key, Gas, GasPrices,
+};
 
 /// This trait provides read access to fee-related parts of the Penumbra
 /// state store.
@@ -33,11 +41,66 @@ pub trait StateReadExt: StateRead {
             .ok_or_else(|| anyhow!("Missing GasPrices"))
     }
 
+    /// Gets the gas prices for paying fees in the given token, or `None` if fees can't be
+    /// paid in it.
+    async fn get_gas_prices_for(&self, asset_id: asset::Id) -> Result<Option<GasPrices>> {
+        if asset_id == *STAKING_TOKEN_ASSET_ID {
+            return self.get_gas_prices().await.map(Some);
+        }
+        Ok(self
+            .get_fee_params()
+            .await?
+            .fixed_alt_gas_prices
+            .into_iter()
+            .find(|prices| prices.asset_id == asset_id)
+            .map(|prices| prices.gas_prices))
+    }
+
     /// Returns true if the gas prices have been changed in this block.
     fn gas_prices_changed(&self) -> bool {
         self.object_get::<()>(state_key::gas_prices_changed())
             .is_some()
     }
+
+    /// Gets the gas used by the transactions executed so far in this block.
+    fn pending_block_gas_used(&self) -> Gas {
+        self.object_get(state_key::pending_block_gas_used())
+            .unwrap_or_default()
+    }
+
+    /// Gets the gas used by the latest block, as recorded at its end.
+    async fn get_block_gas_used(&self) -> Result<Gas> {
+        Ok(self
+            .get(state_key::block_gas_used())
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets a fee grant, whose allowance is what remains after the fees already paid from it.
+    async fn fee_grant(&self, id: &FeeGrantId) -> Result<Option<FeeGrant>> {
+        self.get(&state_key::grants::by_id(id)).await
+    }
+
+    /// Gets the ids of the unexpired fee grants made to the given grantee key.
+    ///
+    /// Grants that expired at the end of an earlier block have been pruned from this index.
+    async fn fee_grant_ids_by_grantee(
+        &self,
+        grantee: &VerificationKey<SpendAuth>,
+    ) -> Result<Vec<FeeGrantId>> {
+        self.prefix::<FeeGrantId>(&state_key::grants::by_grantee_prefix(&grantee.to_bytes()))
+            .map(|entry| entry.map(|(_, id)| id))
+            .try_collect()
+            .await
+    }
+
+    /// Gets the ids of the fee grants whose last usable height is `expiry_height`.
+    async fn fee_grant_ids_expiring_at(&self, expiry_height: u64) -> Result<Vec<FeeGrantId>> {
+        self.prefix::<FeeGrantId>(&state_key::grants::by_expiry_prefix(expiry_height))
+            .map(|entry| entry.map(|(_, id)| id))
+            .try_collect()
+            .await
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
@@ -67,6 +130,57 @@ pub trait StateWriteExt: StateWrite {
         // Mark that they've changed
         self.object_put(state_key::gas_prices_changed(), ());
     }
+
+    /// Adds the gas used by a transaction to the gas used so far in this block.
+    fn record_gas_used(&mut self, gas: Gas) {
+        let used = self.pending_block_gas_used() + gas;
+        self.object_put(state_key::pending_block_gas_used(), used);
+    }
+
+    /// Writes the gas used by the block that's ending to the JMT.
+    fn put_block_gas_used(&mut self, gas: Gas) {
+        self.put(state_key::block_gas_used().into(), gas);
+    }
+
+    /// Records a fee grant, indexing it by its grantee and its expiry height.
+    fn put_fee_grant(&mut self, id: &FeeGrantId, grant: FeeGrant) {
+        self.put(
+            state_key::grants::by_grantee(&grant.grantee.to_bytes(), id),
+            *id,
+        );
+        self.put(state_key::grants::by_expiry(grant.expiry_height, id), *id);
+        self.put(state_key::grants::by_id(id), grant);
+    }
+
+    /// Deletes a fee grant, along with its index entries.
+    fn delete_fee_grant(&mut self, id: &FeeGrantId, grant: &FeeGrant) {
+        self.delete(state_key::grants::by_grantee(&grant.grantee.to_bytes(), id));
+        self.delete(state_key::grants::by_expiry(grant.expiry_height, id));
+        self.delete(state_key::grants::by_id(id));
+    }
+
+    /// Prunes the fee grants whose last usable height is `height`.
+    ///
+    /// Expired grants are dropped from their grantees' index. Those whose allowance was used
+    /// up are deleted outright, while the rest are kept until their sponsor reclaims them.
+    async fn prune_expired_fee_grants(&mut self, height: u64) -> Result<()> {
+        for id in self.fee_grant_ids_expiring_at(height).await? {
+            let grant = self
+                .fee_grant(&id)
+                .await?
+                .ok_or_else(|| anyhow!("indexed fee grant {id} must be present"))?;
+            if grant.allowance.amount() == Amount::zero() {
+                self.delete_fee_grant(&id, &grant);
+            } else {
+                self.delete(state_key::grants::by_grantee(
+                    &grant.grantee.to_bytes(),
+                    &id,
+                ));
+                self.delete(state_key::grants::by_expiry(grant.expiry_height, &id));
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> StateWriteExt for T {}
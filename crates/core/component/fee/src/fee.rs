@@ -1,8 +1,11 @@
+use std::fmt::{self, Display};
+
 use anyhow::Context;
 use penumbra_proto::{penumbra::core::component::fee::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
 
 use decaf377::Fr;
-use penumbra_asset::{asset, balance, Balance, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_asset::{asset, balance, Balance, Value, ValueView, STAKING_TOKEN_ASSET_ID};
 use penumbra_num::Amount;
 
 // Each fee tier multiplier has an implicit 100 denominator.
@@ -118,6 +121,69 @@ impl Fee {
     pub fn value(&self) -> Value {
         self.0
     }
+
+    /// Views this fee, with the metadata of its asset if it's in `cache`.
+    pub fn view_with_cache(&self, cache: &asset::Cache) -> FeeView {
+        FeeView(self.0.view_with_cache(cache))
+    }
+}
+
+/// A view of a [`Fee`], with the metadata of the asset it's paid in, if the viewer knows it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeView", into = "pb::FeeView")]
+pub struct FeeView(pub ValueView);
+
+impl FeeView {
+    /// The fee being viewed.
+    pub fn fee(&self) -> Fee {
+        Fee(self.0.value())
+    }
+
+    /// Whether the metadata of the fee's asset is known.
+    pub fn is_known(&self) -> bool {
+        matches!(self.0, ValueView::KnownAssetId { .. })
+    }
+}
+
+impl Display for FeeView {
+    /// Formats the fee in the best unit of its asset, or in base units of its asset ID if the
+    /// asset is unknown, like [`Value::format`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            ValueView::KnownAssetId {
+                amount, metadata, ..
+            } => {
+                let unit = metadata.best_unit_for(*amount);
+                write!(f, "{}{}", unit.format_value(*amount), unit)
+            }
+            ValueView::UnknownAssetId { amount, asset_id } => write!(f, "{amount}{asset_id}"),
+        }
+    }
+}
+
+impl DomainType for FeeView {
+    type Proto = pb::FeeView;
+}
+
+impl From<FeeView> for pb::FeeView {
+    fn from(view: FeeView) -> Self {
+        pb::FeeView {
+            value: Some(view.0.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeView> for FeeView {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeView) -> anyhow::Result<Self> {
+        Ok(FeeView(
+            proto
+                .value
+                .context("missing protobuf contents for FeeView value")?
+                .try_into()?,
+        ))
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
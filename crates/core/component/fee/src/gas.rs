@@ -1,14 +1,17 @@
 use std::{iter::Sum, ops::Add};
 
+use anyhow::Context;
 use serde::{Deserialize, Serialize};
 
+use penumbra_asset::asset;
 use penumbra_num::Amount;
 use penumbra_proto::{core::component::fee::v1 as pb, DomainType};
 
 /// Represents the different resources that a transaction can consume,
 /// for purposes of calculating multidimensional fees based on real
 /// transaction resource consumption.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[serde(try_from = "pb::Gas", into = "pb::Gas")]
 pub struct Gas {
     pub block_space: u64,
     pub compact_block_space: u64,
@@ -46,6 +49,34 @@ impl Sum for Gas {
     }
 }
 
+impl DomainType for Gas {
+    type Proto = pb::Gas;
+}
+
+impl From<Gas> for pb::Gas {
+    fn from(gas: Gas) -> Self {
+        pb::Gas {
+            block_space: gas.block_space,
+            compact_block_space: gas.compact_block_space,
+            verification: gas.verification,
+            execution: gas.execution,
+        }
+    }
+}
+
+impl TryFrom<pb::Gas> for Gas {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::Gas) -> Result<Self, Self::Error> {
+        Ok(Gas {
+            block_space: proto.block_space,
+            compact_block_space: proto.compact_block_space,
+            verification: proto.verification,
+            execution: proto.execution,
+        })
+    }
+}
+
 /// Expresses the price of each unit of gas in terms of the staking token.
 ///
 /// These prices have an implicit denominator of 1,000 relative to the base unit
@@ -74,6 +105,18 @@ impl GasPrices {
                 + (self.execution_price * gas.execution) / 1_000,
         )
     }
+
+    /// The higher of these and `other` prices, for each resource.
+    pub fn at_least(&self, other: &GasPrices) -> GasPrices {
+        GasPrices {
+            block_space_price: self.block_space_price.max(other.block_space_price),
+            compact_block_space_price: self
+                .compact_block_space_price
+                .max(other.compact_block_space_price),
+            verification_price: self.verification_price.max(other.verification_price),
+            execution_price: self.execution_price.max(other.execution_price),
+        }
+    }
 }
 
 impl DomainType for GasPrices {
@@ -103,3 +146,44 @@ impl TryFrom<pb::GasPrices> for GasPrices {
         })
     }
 }
+
+/// Gas prices for paying fees in a token other than the staking token.
+///
+/// Like [`GasPrices`], these have an implicit denominator of 1,000, but relative to the base
+/// unit of `asset_id` rather than of the staking token.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "pb::AltGasPrices", into = "pb::AltGasPrices")]
+pub struct AltGasPrices {
+    pub asset_id: asset::Id,
+    pub gas_prices: GasPrices,
+}
+
+impl DomainType for AltGasPrices {
+    type Proto = pb::AltGasPrices;
+}
+
+impl From<AltGasPrices> for pb::AltGasPrices {
+    fn from(prices: AltGasPrices) -> Self {
+        pb::AltGasPrices {
+            asset_id: Some(prices.asset_id.into()),
+            gas_prices: Some(prices.gas_prices.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::AltGasPrices> for AltGasPrices {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::AltGasPrices) -> Result<Self, Self::Error> {
+        Ok(AltGasPrices {
+            asset_id: proto
+                .asset_id
+                .context("missing asset id for alternative gas prices")?
+                .try_into()?,
+            gas_prices: proto
+                .gas_prices
+                .context("missing alternative gas prices")?
+                .try_into()?,
+        })
+    }
+}
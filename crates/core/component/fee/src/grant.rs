@@ -0,0 +1,556 @@
+use anyhow::{anyhow, Context};
+use ark_ff::PrimeField;
+use decaf377::Fr;
+use decaf377_rdsa::{Signature, SpendAuth, VerificationKey};
+use penumbra_asset::Balance;
+use penumbra_keys::FullViewingKey;
+use penumbra_proto::{penumbra::core::component::fee::v1 as pb, DomainType};
+use penumbra_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+use crate::Fee;
+
+/// The identifier of a [`FeeGrant`], derived from its contents.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeGrantId", into = "pb::FeeGrantId")]
+pub struct FeeGrantId(pub [u8; 32]);
+
+impl std::fmt::Display for FeeGrantId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&hex::encode(self.0))
+    }
+}
+
+impl std::str::FromStr for FeeGrantId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s)?;
+        Ok(Self(
+            bytes
+                .try_into()
+                .map_err(|_| anyhow!("fee grant id must be 32 bytes"))?,
+        ))
+    }
+}
+
+impl DomainType for FeeGrantId {
+    type Proto = pb::FeeGrantId;
+}
+
+impl From<FeeGrantId> for pb::FeeGrantId {
+    fn from(id: FeeGrantId) -> Self {
+        pb::FeeGrantId {
+            inner: id.0.to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantId> for FeeGrantId {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantId) -> anyhow::Result<Self> {
+        Ok(FeeGrantId(
+            proto
+                .inner
+                .try_into()
+                .map_err(|_| anyhow!("fee grant id must be 32 bytes"))?,
+        ))
+    }
+}
+
+/// Derives the randomizer of the grantee key for the given account.
+///
+/// Fee grants are made to a randomized spend verification key, so that grants to
+/// different accounts of the same wallet can't be linked to each other. Deriving the
+/// randomizer from the nullifier key lets the wallet find its grants without
+/// remembering anything beyond its viewing key.
+pub fn grantee_randomizer(fvk: &FullViewingKey, account: u32) -> Fr {
+    let hash = blake2b_simd::Params::new()
+        .personal(b"Penumbra_FeeGrnt")
+        .key(&fvk.nullifier_key().0.to_bytes())
+        .hash(&account.to_le_bytes());
+    Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+/// Derives the key the given account receives fee grants at.
+pub fn grantee_key(fvk: &FullViewingKey, account: u32) -> VerificationKey<SpendAuth> {
+    fvk.spend_verification_key()
+        .randomize(&grantee_randomizer(fvk, account))
+}
+
+/// Derives the randomizer of the sponsor key for the given account.
+///
+/// This is derived like [`grantee_randomizer`], but with a different personalization,
+/// so that an account's grants can't be linked to the grants it sponsors.
+pub fn sponsor_randomizer(fvk: &FullViewingKey, account: u32) -> Fr {
+    let hash = blake2b_simd::Params::new()
+        .personal(b"Penumbra_FeeSpsr")
+        .key(&fvk.nullifier_key().0.to_bytes())
+        .hash(&account.to_le_bytes());
+    Fr::from_le_bytes_mod_order(hash.as_bytes())
+}
+
+/// Derives the key the given account sponsors fee grants with, which can reclaim them.
+pub fn sponsor_key(fvk: &FullViewingKey, account: u32) -> VerificationKey<SpendAuth> {
+    fvk.spend_verification_key()
+        .randomize(&sponsor_randomizer(fvk, account))
+}
+
+/// An action escrowing an allowance that transactions authorized by the grantee
+/// can use to pay their fees.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeGrant", into = "pb::FeeGrant")]
+pub struct FeeGrant {
+    /// The key authorized to draw on the allowance.
+    pub grantee: VerificationKey<SpendAuth>,
+    /// The allowance, which also fixes the token fees can be paid in.
+    pub allowance: Fee,
+    /// The last height at which the allowance can be used.
+    pub expiry_height: u64,
+    /// A nonce distinguishing otherwise identical grants.
+    pub nonce: [u8; 32],
+    /// The key authorized to reclaim what remains of the allowance.
+    pub sponsor: VerificationKey<SpendAuth>,
+}
+
+impl FeeGrant {
+    pub fn id(&self) -> FeeGrantId {
+        let hash = blake2b_simd::Params::new()
+            .personal(b"PAH:fee_grant_id")
+            .hash_length(32)
+            .hash(&self.encode_to_vec());
+        FeeGrantId(hash.as_bytes().try_into().expect("hash length is 32 bytes"))
+    }
+
+    pub fn balance(&self) -> Balance {
+        // Creating a grant escrows the allowance.
+        -Balance::from(self.allowance.0)
+    }
+}
+
+impl EffectingData for FeeGrant {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for FeeGrant {
+    type Proto = pb::FeeGrant;
+}
+
+impl From<FeeGrant> for pb::FeeGrant {
+    fn from(grant: FeeGrant) -> Self {
+        pb::FeeGrant {
+            grantee: Some(grant.grantee.into()),
+            allowance: Some(grant.allowance.into()),
+            expiry_height: grant.expiry_height,
+            nonce: grant.nonce.to_vec(),
+            sponsor: Some(grant.sponsor.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrant> for FeeGrant {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrant) -> anyhow::Result<Self> {
+        Ok(FeeGrant {
+            grantee: proto
+                .grantee
+                .ok_or_else(|| anyhow!("missing grantee"))?
+                .try_into()
+                .context("malformed grantee")?,
+            allowance: proto
+                .allowance
+                .ok_or_else(|| anyhow!("missing allowance"))?
+                .try_into()
+                .context("malformed allowance")?,
+            expiry_height: proto.expiry_height,
+            nonce: proto
+                .nonce
+                .try_into()
+                .map_err(|_| anyhow!("fee grant nonce must be 32 bytes"))?,
+            sponsor: proto
+                .sponsor
+                .ok_or_else(|| anyhow!("missing sponsor"))?
+                .try_into()
+                .context("malformed sponsor")?,
+        })
+    }
+}
+
+/// The effecting data of a [`FeeGrantUse`].
+#[derive(Clone, Debug)]
+pub struct FeeGrantUseBody {
+    /// The grant to draw on.
+    pub grant_id: FeeGrantId,
+    /// The key the grant was made to.
+    pub grantee: VerificationKey<SpendAuth>,
+    /// The amount drawn from the allowance.
+    pub amount: Fee,
+}
+
+impl EffectingData for FeeGrantUseBody {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for FeeGrantUseBody {
+    type Proto = pb::FeeGrantUseBody;
+}
+
+impl From<FeeGrantUseBody> for pb::FeeGrantUseBody {
+    fn from(body: FeeGrantUseBody) -> Self {
+        pb::FeeGrantUseBody {
+            grant_id: Some(body.grant_id.into()),
+            grantee: Some(body.grantee.into()),
+            amount: Some(body.amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantUseBody> for FeeGrantUseBody {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantUseBody) -> anyhow::Result<Self> {
+        Ok(FeeGrantUseBody {
+            grant_id: proto
+                .grant_id
+                .ok_or_else(|| anyhow!("missing grant id"))?
+                .try_into()?,
+            grantee: proto
+                .grantee
+                .ok_or_else(|| anyhow!("missing grantee"))?
+                .try_into()
+                .context("malformed grantee")?,
+            amount: proto
+                .amount
+                .ok_or_else(|| anyhow!("missing amount"))?
+                .try_into()
+                .context("malformed amount")?,
+        })
+    }
+}
+
+/// An action drawing on a [`FeeGrant`] to pay the fee of the transaction containing it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeGrantUse", into = "pb::FeeGrantUse")]
+pub struct FeeGrantUse {
+    pub body: FeeGrantUseBody,
+    /// A signature over the transaction's effect hash by the grantee key.
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl FeeGrantUse {
+    pub fn balance(&self) -> Balance {
+        Balance::from(self.body.amount.0)
+    }
+}
+
+impl EffectingData for FeeGrantUse {
+    fn effect_hash(&self) -> EffectHash {
+        // The effecting data is in the body of the action, so we can
+        // just use hash the proto-encoding of the body.
+        self.body.effect_hash()
+    }
+}
+
+impl DomainType for FeeGrantUse {
+    type Proto = pb::FeeGrantUse;
+}
+
+impl From<FeeGrantUse> for pb::FeeGrantUse {
+    fn from(action: FeeGrantUse) -> Self {
+        pb::FeeGrantUse {
+            body: Some(action.body.into()),
+            auth_sig: Some(action.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantUse> for FeeGrantUse {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantUse) -> anyhow::Result<Self> {
+        Ok(FeeGrantUse {
+            body: proto
+                .body
+                .ok_or_else(|| anyhow!("missing fee grant use body"))?
+                .try_into()?,
+            auth_sig: proto
+                .auth_sig
+                .ok_or_else(|| anyhow!("missing auth sig"))?
+                .try_into()
+                .context("malformed auth sig")?,
+        })
+    }
+}
+
+/// A planned [`FeeGrantUse`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeGrantUsePlan", into = "pb::FeeGrantUsePlan")]
+pub struct FeeGrantUsePlan {
+    pub grant_id: FeeGrantId,
+    pub amount: Fee,
+    /// The randomizer applied to the spend authorization key to obtain the grantee key.
+    pub randomizer: Fr,
+}
+
+impl FeeGrantUsePlan {
+    /// Plans a use of a grant made to the grantee key of the given account.
+    pub fn new(fvk: &FullViewingKey, account: u32, grant_id: FeeGrantId, amount: Fee) -> Self {
+        Self {
+            grant_id,
+            amount,
+            randomizer: grantee_randomizer(fvk, account),
+        }
+    }
+
+    /// Construct the [`FeeGrantUse`] described by this plan.
+    pub fn fee_grant_use(
+        &self,
+        fvk: &FullViewingKey,
+        auth_sig: Signature<SpendAuth>,
+    ) -> FeeGrantUse {
+        FeeGrantUse {
+            body: self.fee_grant_use_body(fvk),
+            auth_sig,
+        }
+    }
+
+    /// Construct the [`FeeGrantUseBody`] described by this plan.
+    pub fn fee_grant_use_body(&self, fvk: &FullViewingKey) -> FeeGrantUseBody {
+        FeeGrantUseBody {
+            grant_id: self.grant_id,
+            grantee: fvk.spend_verification_key().randomize(&self.randomizer),
+            amount: self.amount,
+        }
+    }
+
+    pub fn balance(&self) -> Balance {
+        Balance::from(self.amount.0)
+    }
+}
+
+impl DomainType for FeeGrantUsePlan {
+    type Proto = pb::FeeGrantUsePlan;
+}
+
+impl From<FeeGrantUsePlan> for pb::FeeGrantUsePlan {
+    fn from(plan: FeeGrantUsePlan) -> Self {
+        pb::FeeGrantUsePlan {
+            grant_id: Some(plan.grant_id.into()),
+            amount: Some(plan.amount.into()),
+            randomizer: plan.randomizer.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantUsePlan> for FeeGrantUsePlan {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantUsePlan) -> anyhow::Result<Self> {
+        Ok(FeeGrantUsePlan {
+            grant_id: proto
+                .grant_id
+                .ok_or_else(|| anyhow!("missing grant id"))?
+                .try_into()?,
+            amount: proto
+                .amount
+                .ok_or_else(|| anyhow!("missing amount"))?
+                .try_into()
+                .context("malformed amount")?,
+            randomizer: Fr::from_bytes(proto.randomizer.as_slice().try_into()?)?,
+        })
+    }
+}
+
+/// The effecting data of a [`FeeGrantReclaim`].
+#[derive(Clone, Debug)]
+pub struct FeeGrantReclaimBody {
+    /// The grant to reclaim.
+    pub grant_id: FeeGrantId,
+    /// The key of the sponsor who made the grant.
+    pub sponsor: VerificationKey<SpendAuth>,
+    /// The amount reclaimed, which must be all that remains of the allowance.
+    pub amount: Fee,
+}
+
+impl EffectingData for FeeGrantReclaimBody {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+impl DomainType for FeeGrantReclaimBody {
+    type Proto = pb::FeeGrantReclaimBody;
+}
+
+impl From<FeeGrantReclaimBody> for pb::FeeGrantReclaimBody {
+    fn from(body: FeeGrantReclaimBody) -> Self {
+        pb::FeeGrantReclaimBody {
+            grant_id: Some(body.grant_id.into()),
+            sponsor: Some(body.sponsor.into()),
+            amount: Some(body.amount.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantReclaimBody> for FeeGrantReclaimBody {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantReclaimBody) -> anyhow::Result<Self> {
+        Ok(FeeGrantReclaimBody {
+            grant_id: proto
+                .grant_id
+                .ok_or_else(|| anyhow!("missing grant id"))?
+                .try_into()?,
+            sponsor: proto
+                .sponsor
+                .ok_or_else(|| anyhow!("missing sponsor"))?
+                .try_into()
+                .context("malformed sponsor")?,
+            amount: proto
+                .amount
+                .ok_or_else(|| anyhow!("missing amount"))?
+                .try_into()
+                .context("malformed amount")?,
+        })
+    }
+}
+
+/// An action revoking a [`FeeGrant`] and returning what remains of its allowance
+/// to the transaction containing it.
+///
+/// A grant can be reclaimed at any time, whether or not it has expired.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeGrantReclaim", into = "pb::FeeGrantReclaim")]
+pub struct FeeGrantReclaim {
+    pub body: FeeGrantReclaimBody,
+    /// A signature over the transaction's effect hash by the sponsor key.
+    pub auth_sig: Signature<SpendAuth>,
+}
+
+impl FeeGrantReclaim {
+    pub fn balance(&self) -> Balance {
+        Balance::from(self.body.amount.0)
+    }
+}
+
+impl EffectingData for FeeGrantReclaim {
+    fn effect_hash(&self) -> EffectHash {
+        self.body.effect_hash()
+    }
+}
+
+impl DomainType for FeeGrantReclaim {
+    type Proto = pb::FeeGrantReclaim;
+}
+
+impl From<FeeGrantReclaim> for pb::FeeGrantReclaim {
+    fn from(action: FeeGrantReclaim) -> Self {
+        pb::FeeGrantReclaim {
+            body: Some(action.body.into()),
+            auth_sig: Some(action.auth_sig.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantReclaim> for FeeGrantReclaim {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantReclaim) -> anyhow::Result<Self> {
+        Ok(FeeGrantReclaim {
+            body: proto
+                .body
+                .ok_or_else(|| anyhow!("missing fee grant reclaim body"))?
+                .try_into()?,
+            auth_sig: proto
+                .auth_sig
+                .ok_or_else(|| anyhow!("missing auth sig"))?
+                .try_into()
+                .context("malformed auth sig")?,
+        })
+    }
+}
+
+/// A planned [`FeeGrantReclaim`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::FeeGrantReclaimPlan", into = "pb::FeeGrantReclaimPlan")]
+pub struct FeeGrantReclaimPlan {
+    pub grant_id: FeeGrantId,
+    pub amount: Fee,
+    /// The randomizer applied to the spend authorization key to obtain the sponsor key.
+    pub randomizer: Fr,
+}
+
+impl FeeGrantReclaimPlan {
+    /// Plans reclaiming a grant sponsored by the given account.
+    pub fn new(fvk: &FullViewingKey, account: u32, grant_id: FeeGrantId, amount: Fee) -> Self {
+        Self {
+            grant_id,
+            amount,
+            randomizer: sponsor_randomizer(fvk, account),
+        }
+    }
+
+    /// Construct the [`FeeGrantReclaim`] described by this plan.
+    pub fn fee_grant_reclaim(
+        &self,
+        fvk: &FullViewingKey,
+        auth_sig: Signature<SpendAuth>,
+    ) -> FeeGrantReclaim {
+        FeeGrantReclaim {
+            body: self.fee_grant_reclaim_body(fvk),
+            auth_sig,
+        }
+    }
+
+    /// Construct the [`FeeGrantReclaimBody`] described by this plan.
+    pub fn fee_grant_reclaim_body(&self, fvk: &FullViewingKey) -> FeeGrantReclaimBody {
+        FeeGrantReclaimBody {
+            grant_id: self.grant_id,
+            sponsor: fvk.spend_verification_key().randomize(&self.randomizer),
+            amount: self.amount,
+        }
+    }
+
+    pub fn balance(&self) -> Balance {
+        Balance::from(self.amount.0)
+    }
+}
+
+impl DomainType for FeeGrantReclaimPlan {
+    type Proto = pb::FeeGrantReclaimPlan;
+}
+
+impl From<FeeGrantReclaimPlan> for pb::FeeGrantReclaimPlan {
+    fn from(plan: FeeGrantReclaimPlan) -> Self {
+        pb::FeeGrantReclaimPlan {
+            grant_id: Some(plan.grant_id.into()),
+            amount: Some(plan.amount.into()),
+            randomizer: plan.randomizer.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::FeeGrantReclaimPlan> for FeeGrantReclaimPlan {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::FeeGrantReclaimPlan) -> anyhow::Result<Self> {
+        Ok(FeeGrantReclaimPlan {
+            grant_id: proto
+                .grant_id
+                .ok_or_else(|| anyhow!("missing grant id"))?
+                .try_into()?,
+            amount: proto
+                .amount
+                .ok_or_else(|| anyhow!("missing amount"))?
+                .try_into()
+                .context("malformed amount")?,
+            randomizer: Fr::from_bytes(proto.randomizer.as_slice().try_into()?)?,
+        })
+    }
+}
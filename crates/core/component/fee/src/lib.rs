@@ -8,11 +8,14 @@ pub mod component;
 pub mod event;
 pub mod state_key;
 
+mod base_fee;
 mod fee;
 mod gas;
 pub mod genesis;
+pub mod grant;
 pub mod params;
 
-pub use fee::{Fee, FeeTier};
-pub use gas::{Gas, GasPrices};
+pub use base_fee::DynamicBaseFee;
+pub use fee::{Fee, FeeTier, FeeView};
+pub use gas::{AltGasPrices, Gas, GasPrices};
 pub use params::FeeParameters;
@@ -3,12 +3,17 @@ use penumbra_proto::penumbra::core::component::fee::v1 as pb;
 use penumbra_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
-use crate::GasPrices;
+use crate::{AltGasPrices, DynamicBaseFee, GasPrices};
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(try_from = "pb::FeeParameters", into = "pb::FeeParameters")]
 pub struct FeeParameters {
+    /// The gas prices, unless the base fee is dynamic.
     pub fixed_gas_prices: GasPrices,
+    /// If set, the gas prices adjust to how full recent blocks were, rather than being fixed.
+    pub dynamic_base_fee: Option<DynamicBaseFee>,
+    /// The gas prices for each token other than the staking token that fees can be paid in.
+    pub fixed_alt_gas_prices: Vec<AltGasPrices>,
 }
 
 impl DomainType for FeeParameters {
@@ -21,6 +26,12 @@ impl TryFrom<pb::FeeParameters> for FeeParameters {
     fn try_from(msg: pb::FeeParameters) -> anyhow::Result<Self> {
         Ok(FeeParameters {
             fixed_gas_prices: msg.fixed_gas_prices.unwrap_or_default().try_into()?,
+            dynamic_base_fee: msg.dynamic_base_fee.map(TryInto::try_into).transpose()?,
+            fixed_alt_gas_prices: msg
+                .fixed_alt_gas_prices
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
         })
     }
 }
@@ -29,6 +40,12 @@ impl From<FeeParameters> for pb::FeeParameters {
     fn from(params: FeeParameters) -> Self {
         pb::FeeParameters {
             fixed_gas_prices: Some(params.fixed_gas_prices.into()),
+            dynamic_base_fee: params.dynamic_base_fee.map(Into::into),
+            fixed_alt_gas_prices: params
+                .fixed_alt_gas_prices
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -10,6 +10,38 @@ pub fn gas_prices_changed() -> &'static str {
     "fee/gas_prices_changed"
 }
 
+pub fn block_gas_used() -> &'static str {
+    "fee/block_gas_used"
+}
+
+pub fn pending_block_gas_used() -> &'static str {
+    "fee/pending_block_gas_used"
+}
+
 pub fn fee_params_updated() -> &'static str {
     "fee/fee_params_updated"
 }
+
+pub mod grants {
+    use crate::grant::FeeGrantId;
+
+    pub fn by_id(id: &FeeGrantId) -> String {
+        format!("fee/grants/by_id/{id}")
+    }
+
+    pub fn by_grantee_prefix(grantee: &[u8; 32]) -> String {
+        format!("fee/grants/by_grantee/{}/", hex::encode(grantee))
+    }
+
+    pub fn by_grantee(grantee: &[u8; 32], id: &FeeGrantId) -> String {
+        format!("{}{id}", by_grantee_prefix(grantee))
+    }
+
+    pub fn by_expiry_prefix(expiry_height: u64) -> String {
+        format!("fee/grants/by_expiry/{expiry_height:020}/")
+    }
+
+    pub fn by_expiry(expiry_height: u64, id: &FeeGrantId) -> String {
+        format!("{}{id}", by_expiry_prefix(expiry_height))
+    }
+}
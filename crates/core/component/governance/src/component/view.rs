@@ -9,6 +9,7 @@ use cnidarium::{StateRead, StateWrite};
 use futures::StreamExt;
 use ibc_types::core::client::ClientId;
 use penumbra_asset::{asset, Value, STAKING_TOKEN_DENOM};
+use penumbra_dex::component::{StateReadExt as _, StateWriteExt as _};
 use penumbra_ibc::component::ClientStateReadExt as _;
 use penumbra_ibc::component::ClientStateWriteExt as _;
 use penumbra_num::Amount;
@@ -890,7 +891,18 @@ pub trait StateWriteExt: StateWrite + penumbra_ibc::component::ConnectionStateWr
                 // Nothing to do for signaling proposals
                 tracing::info!("signaling proposal passed, nothing to do");
             }
-            ProposalPayload::Emergency { halt_chain } => {
+            ProposalPayload::Emergency {
+                halt_chain,
+                pause_dex,
+            } => {
+                // If the proposal calls to pause the dex, do so immediately, without going through
+                // the app parameter update process, so that it takes effect for the next transaction.
+                if *pause_dex {
+                    tracing::info!("emergency proposal passed calling for pausing the dex");
+                    let mut dex_params = self.get_dex_params().await?;
+                    dex_params.is_paused = true;
+                    self.put_dex_params(dex_params);
+                }
                 // If the proposal calls to halt the chain...
                 if *halt_chain {
                     // Print an informational message and signal to the consensus worker to halt the
@@ -389,7 +389,7 @@ impl DelegatorVoteProof {
         .collect::<Vec<_>>()
         .tap(|public_inputs| tracing::trace!(?public_inputs));
 
-        let start = std::time::Instant::now();
+        let start = web_time::Instant::now();
         Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
             vk,
             public_inputs.as_slice(),
@@ -6,6 +6,7 @@ use std::str::FromStr;
 
 use crate::params::GovernanceParameters;
 use penumbra_community_pool::params::CommunityPoolParameters;
+use penumbra_dex::DexParameters;
 use penumbra_distributions::params::DistributionsParameters;
 use penumbra_fee::params::FeeParameters;
 use penumbra_ibc::params::IBCParameters;
@@ -53,9 +54,13 @@ impl From<Proposal> for pb::Proposal {
                     },
                 }))
             }
-            ProposalPayload::Emergency { halt_chain } => {
-                Some(Payload::Emergency(pb::proposal::Emergency { halt_chain }))
-            }
+            ProposalPayload::Emergency {
+                halt_chain,
+                pause_dex,
+            } => Some(Payload::Emergency(pb::proposal::Emergency {
+                halt_chain,
+                pause_dex,
+            })),
             ProposalPayload::ParameterChange { old, new } => {
                 Some(Payload::ParameterChange(pb::proposal::ParameterChange {
                     old_parameters: Some((*old).into()),
@@ -111,6 +116,7 @@ impl TryFrom<pb::Proposal> for Proposal {
                 },
                 Payload::Emergency(emergency) => ProposalPayload::Emergency {
                     halt_chain: emergency.halt_chain,
+                    pause_dex: emergency.pause_dex,
                 },
                 Payload::ParameterChange(parameter_change) => ProposalPayload::ParameterChange {
                     old: Box::new(
@@ -268,6 +274,9 @@ pub enum ProposalPayload {
         /// If `halt_chain == true`, then the chain will immediately halt when the proposal is
         /// passed.
         halt_chain: bool,
+        /// If `pause_dex == true`, then opening new positions and swapping will be paused when
+        /// the proposal is passed. They can be resumed by a parameter change proposal.
+        pause_dex: bool,
     },
     /// A parameter change proposal describes a replacement of the app parameters, which should
     /// take effect when the proposal is passed.
@@ -318,6 +327,8 @@ pub enum ProposalPayloadToml {
     },
     Emergency {
         halt_chain: bool,
+        #[serde(default)]
+        pause_dex: bool,
     },
     ParameterChange {
         old: Box<ChangedAppParameters>,
@@ -343,9 +354,13 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
     fn try_from(toml: ProposalPayloadToml) -> Result<Self, Self::Error> {
         Ok(match toml {
             ProposalPayloadToml::Signaling { commit } => ProposalPayload::Signaling { commit },
-            ProposalPayloadToml::Emergency { halt_chain } => {
-                ProposalPayload::Emergency { halt_chain }
-            }
+            ProposalPayloadToml::Emergency {
+                halt_chain,
+                pause_dex,
+            } => ProposalPayload::Emergency {
+                halt_chain,
+                pause_dex,
+            },
             ProposalPayloadToml::ParameterChange { old, new } => {
                 ProposalPayload::ParameterChange { old, new }
             }
@@ -376,9 +391,13 @@ impl From<ProposalPayload> for ProposalPayloadToml {
     fn from(payload: ProposalPayload) -> Self {
         match payload {
             ProposalPayload::Signaling { commit } => ProposalPayloadToml::Signaling { commit },
-            ProposalPayload::Emergency { halt_chain } => {
-                ProposalPayloadToml::Emergency { halt_chain }
-            }
+            ProposalPayload::Emergency {
+                halt_chain,
+                pause_dex,
+            } => ProposalPayloadToml::Emergency {
+                halt_chain,
+                pause_dex,
+            },
             ProposalPayload::ParameterChange { old, new } => {
                 ProposalPayloadToml::ParameterChange { old, new }
             }
@@ -436,6 +455,7 @@ impl ProposalPayload {
 )]
 pub struct ChangedAppParameters {
     pub community_pool_params: Option<CommunityPoolParameters>,
+    pub dex_params: Option<DexParameters>,
     pub distributions_params: Option<DistributionsParameters>,
     pub ibc_params: Option<IBCParameters>,
     pub fee_params: Option<FeeParameters>,
@@ -459,6 +479,7 @@ impl TryFrom<pb::ChangedAppParameters> for ChangedAppParameters {
                 .community_pool_params
                 .map(TryInto::try_into)
                 .transpose()?,
+            dex_params: msg.dex_params.map(TryInto::try_into).transpose()?,
             distributions_params: msg
                 .distributions_params
                 .map(TryInto::try_into)
@@ -481,6 +502,7 @@ impl From<ChangedAppParameters> for pb::ChangedAppParameters {
     fn from(params: ChangedAppParameters) -> Self {
         pb::ChangedAppParameters {
             community_pool_params: params.community_pool_params.map(Into::into),
+            dex_params: params.dex_params.map(Into::into),
             distributions_params: params.distributions_params.map(Into::into),
             fee_params: params.fee_params.map(Into::into),
             funding_params: params.funding_params.map(Into::into),
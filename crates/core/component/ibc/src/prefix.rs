@@ -51,6 +51,11 @@ mod vendored {
 pub static IBC_PROOF_SPECS: Lazy<Vec<ics23::ProofSpec>> =
     Lazy::new(|| vec![vendored::ics23_spec(), vendored::ics23_spec()]);
 
+/// The ICS23 proof spec for data stored directly in the root store of the penumbra state,
+/// rather than inside a substore.
+pub static ROOT_STORE_PROOF_SPECS: Lazy<Vec<ics23::ProofSpec>> =
+    Lazy::new(|| vec![vendored::ics23_spec()]);
+
 /// TODO: upstream into ibc-types
 pub trait MerklePrefixExt {
     fn apply_string(&self, path: String) -> String;
@@ -1,10 +1,18 @@
+use std::pin::Pin;
+
+use async_stream::try_stream;
 use cnidarium::Storage;
+use futures::{StreamExt, TryStreamExt};
 use penumbra_proto::core::component::sct::v1::query_service_server::QueryService;
-use penumbra_proto::core::component::sct::v1::{EpochByHeightRequest, EpochByHeightResponse};
+use penumbra_proto::core::component::sct::v1::{
+    AnchorsByHeightRequest, AnchorsByHeightResponse, EpochByHeightRequest, EpochByHeightResponse,
+    SctPosition, SctStatsRequest, SctStatsResponse,
+};
 use tonic::Status;
 use tracing::instrument;
 
 use super::clock::EpochRead;
+use super::tree::SctRead;
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -35,4 +43,70 @@ impl QueryService for Server {
             epoch: Some(epoch.into()),
         }))
     }
+
+    #[instrument(skip(self, _request))]
+    async fn sct_stats(
+        &self,
+        _request: tonic::Request<SctStatsRequest>,
+    ) -> Result<tonic::Response<SctStatsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let tree = state.get_sct().await;
+        let height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("could not get block height: {e}")))?;
+
+        Ok(tonic::Response::new(SctStatsResponse {
+            next_position: tree.position().map(|position| SctPosition {
+                position: position.into(),
+                epoch: position.epoch().into(),
+                block: position.block().into(),
+                commitment: position.commitment().into(),
+            }),
+            root: Some(tree.root().into()),
+            height,
+        }))
+    }
+
+    type AnchorsByHeightStream =
+        Pin<Box<dyn futures::Stream<Item = Result<AnchorsByHeightResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn anchors_by_height(
+        &self,
+        request: tonic::Request<AnchorsByHeightRequest>,
+    ) -> Result<tonic::Response<Self::AnchorsByHeightStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let latest_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("could not get block height: {e}")))?;
+        let end_height = match request.end_height {
+            0 => latest_height + 1,
+            end_height => end_height.min(latest_height + 1),
+        };
+        let start_height = request.start_height;
+
+        let s = try_stream! {
+            for height in start_height..end_height {
+                // Heights with no recorded anchor are skipped.
+                if let Some(anchor) = state.get_anchor_by_height(height).await? {
+                    yield AnchorsByHeightResponse {
+                        height,
+                        anchor: Some(anchor.into()),
+                    };
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            s.map_err(|e: anyhow::Error| {
+                tonic::Status::unavailable(format!("could not get anchor: {e}"))
+            })
+            .boxed(),
+        ))
+    }
 }
@@ -39,6 +39,17 @@ pub trait SctRead: StateRead {
         self.get(&state_key::tree::anchor_by_height(height)).await
     }
 
+    /// Return the state commitment tree as of the end of the given epoch, if it was recorded.
+    ///
+    /// Only the frontier of the tree is retained, which is enough for a client to
+    /// begin syncing from the end of that epoch.
+    async fn get_sct_checkpoint(&self, epoch_index: u64) -> Result<Option<tct::Tree>> {
+        self.nonverifiable_get_raw(state_key::tree::checkpoint_by_epoch(epoch_index).as_bytes())
+            .await?
+            .map(|bytes| bincode::deserialize(&bytes).map_err(Into::into))
+            .transpose()
+    }
+
     /// Return metadata on the specified nullifier, if it has been spent.
     async fn spend_info(&self, nullifier: Nullifier) -> Result<Option<NullificationInfo>> {
         self.get(&state_key::nullifier_set::spent_nullifier_lookup(
@@ -89,6 +100,15 @@ pub trait SctManager: StateWrite {
                 .expect("epoch must be set")
                 .index;
             self.record_proto(event::epoch_root(index, epoch_root));
+
+            // Keep a copy of the tree as of the end of the epoch, so that clients
+            // can start syncing from here rather than from genesis.
+            let bytes = bincode::serialize(&sct)
+                .expect("able to serialize state commitment tree to bincode");
+            self.nonverifiable_put_raw(
+                state_key::tree::checkpoint_by_epoch(index).into_bytes(),
+                bytes,
+            );
         }
 
         self.write_sct_cache(sct);
@@ -36,4 +36,12 @@ impl Epoch {
     pub fn is_scheduled_epoch_end(&self, current_height: u64, epoch_duration: u64) -> bool {
         current_height - self.start_height >= epoch_duration - 1
     }
+
+    /// Returns the height of the scheduled last block of the epoch.
+    ///
+    /// An epoch ends early when a validator leaves the active set, so this is the latest
+    /// the epoch will end.
+    pub fn scheduled_end_height(&self, epoch_duration: u64) -> u64 {
+        self.start_height + epoch_duration.saturating_sub(1)
+    }
 }
@@ -57,6 +57,10 @@ pub mod tree {
         "sct/tree/state_commitment_tree"
     }
 
+    pub fn checkpoint_by_epoch(epoch_index: u64) -> String {
+        format!("sct/tree/checkpoint_by_epoch/{}", epoch_index)
+    }
+
     pub fn note_source(note_commitment: &penumbra_tct::StateCommitment) -> String {
         format!("sct/tree/note_source/{}", note_commitment)
     }
@@ -2,6 +2,7 @@ use cnidarium::Storage;
 use penumbra_asset::asset;
 use penumbra_proto::core::component::shielded_pool::v1::{
     query_service_server::QueryService, AssetMetadataByIdRequest, AssetMetadataByIdResponse,
+    Ics20FlowsRequest, Ics20FlowsResponse,
 };
 
 use tonic::Status;
@@ -56,4 +57,33 @@ impl QueryService for Server {
 
         Ok(tonic::Response::new(rsp))
     }
+
+    #[instrument(skip(self, request))]
+    async fn ics20_flows(
+        &self,
+        request: tonic::Request<Ics20FlowsRequest>,
+    ) -> Result<tonic::Response<Ics20FlowsResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let request = request.into_inner();
+        let id: asset::Id = request
+            .asset_id
+            .ok_or_else(|| Status::invalid_argument("missing asset_id"))?
+            .try_into()
+            .map_err(|e| Status::invalid_argument(format!("could not parse asset_id: {e}")))?;
+
+        let inflow = state
+            .ics20_inflow(&id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+        let outflow = state
+            .ics20_outflow(&id)
+            .await
+            .map_err(|e| Status::internal(e.to_string()))?;
+
+        Ok(tonic::Response::new(Ics20FlowsResponse {
+            inflow: Some(inflow.into()),
+            outflow: Some(outflow.into()),
+        }))
+    }
 }
@@ -18,6 +18,22 @@ pub trait SupplyRead: StateRead {
     async fn denom_by_asset(&self, asset_id: &asset::Id) -> Result<Option<Metadata>> {
         self.get(&state_key::denom_by_asset(asset_id)).await
     }
+
+    /// Gets the total amount of an asset received over ICS20.
+    async fn ics20_inflow(&self, asset_id: &asset::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::ics20_inflow(asset_id))
+            .await?
+            .unwrap_or_default())
+    }
+
+    /// Gets the total amount of an asset sent over ICS20, net of refunds for timed-out transfers.
+    async fn ics20_outflow(&self, asset_id: &asset::Id) -> Result<Amount> {
+        Ok(self
+            .get(&state_key::ics20_outflow(asset_id))
+            .await?
+            .unwrap_or_default())
+    }
 }
 
 impl<T: StateRead + ?Sized> SupplyRead for T {}
@@ -92,6 +108,38 @@ pub trait SupplyWrite: StateWrite {
         self.put(key, new_supply);
         Ok(())
     }
+
+    /// Records an amount of an asset received over ICS20.
+    async fn record_ics20_inflow(&mut self, asset_id: &asset::Id, amount: Amount) -> Result<()> {
+        let inflow = self.ics20_inflow(asset_id).await?;
+        // Saturate rather than fail: the totals are informational, and shouldn't
+        // cause a transfer to be rejected.
+        self.put(
+            state_key::ics20_inflow(asset_id),
+            inflow.saturating_add(&amount),
+        );
+        Ok(())
+    }
+
+    /// Records an amount of an asset sent over ICS20.
+    async fn record_ics20_outflow(&mut self, asset_id: &asset::Id, amount: Amount) -> Result<()> {
+        let outflow = self.ics20_outflow(asset_id).await?;
+        self.put(
+            state_key::ics20_outflow(asset_id),
+            outflow.saturating_add(&amount),
+        );
+        Ok(())
+    }
+
+    /// Removes the amount of a refunded ICS20 transfer from the outflow of its asset.
+    async fn revert_ics20_outflow(&mut self, asset_id: &asset::Id, amount: Amount) -> Result<()> {
+        let outflow = self.ics20_outflow(asset_id).await?;
+        self.put(
+            state_key::ics20_outflow(asset_id),
+            outflow.saturating_sub(&amount),
+        );
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> SupplyWrite for T {}
@@ -15,7 +15,7 @@ use ibc_types::{
             MsgChannelOpenConfirm, MsgChannelOpenInit, MsgChannelOpenTry, MsgRecvPacket,
             MsgTimeout,
         },
-        ChannelId, PortId, Version,
+        ChannelId, Packet, PortId, Version,
     },
     transfer::acknowledgement::TokenTransferAcknowledgement,
 };
@@ -144,6 +144,9 @@ pub trait Ics20TransferWriteExt: StateWrite {
                 .expect("couldn't update token supply in ics20 withdrawal!");
         }
 
+        self.record_ics20_outflow(&withdrawal.denom.id(), withdrawal.amount)
+            .await?;
+
         self.send_packet_execute(checked_packet).await;
 
         Ok(())
@@ -251,10 +254,7 @@ impl AppHandlerCheck for Ics20Transfer {
 }
 
 // the main entry point for ICS20 transfer packet handling
-async fn recv_transfer_packet_inner<S: StateWrite>(
-    mut state: S,
-    msg: &MsgRecvPacket,
-) -> Result<()> {
+async fn recv_transfer_packet_inner<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
     // parse if we are source or dest, and mint or burn accordingly
     //
     // see this part of the spec for this logic:
@@ -262,7 +262,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
     // https://github.com/cosmos/ibc/tree/main/spec/app/ics-020-fungible-token-transfer (onRecvPacket)
     //
     // NOTE: spec says proto but this is actually JSON according to the ibc-go implementation
-    let packet_data: FungibleTokenPacketData = serde_json::from_slice(msg.packet.data.as_slice())
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(packet.data.as_slice())
         .with_context(|| "failed to decode FTPD packet")?;
     let denom: asset::Metadata = packet_data
         .denom
@@ -278,13 +278,13 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
     // NOTE: here we assume we are chain A.
 
     // 2. check if we are the source chain for the denom.
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom, false) {
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, false) {
         // mint tokens to receiver in the amount of packet_data.amount in the denom of denom (with
         // the source removed, since we're the source)
         let prefix = format!(
             "{source_port}/{source_chan}/",
-            source_port = msg.packet.port_on_a,
-            source_chan = msg.packet.chan_on_a
+            source_port = packet.port_on_a,
+            source_chan = packet.chan_on_a
         );
 
         let unprefixed_denom: asset::Metadata = packet_data
@@ -306,7 +306,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         // check if we have enough balance to unescrow tokens to receiver
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_b,
+                &packet.chan_on_b,
                 &unprefixed_denom.id(),
             ))
             .await?
@@ -322,9 +322,9 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
                 value,
                 &receiver_address,
                 CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
+                    packet_seq: packet.sequence.0,
                     // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
+                    channel_id: packet.chan_on_a.0.clone(),
                     sender: packet_data.sender.clone(),
                 },
             )
@@ -334,7 +334,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         // update the value balance
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_b,
+                &packet.chan_on_b,
                 &unprefixed_denom.id(),
             ))
             .await?
@@ -345,9 +345,13 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
             .checked_sub(&receiver_amount)
             .context("underflow subtracing value balance in ics20 transfer")?;
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_b, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_b, &denom.id()),
             new_value_balance,
         );
+
+        state
+            .record_ics20_inflow(&unprefixed_denom.id(), receiver_amount)
+            .await?;
     } else {
         // create new denom:
         //
@@ -357,7 +361,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         // then mint that denom to packet_data.receiver in packet_data.amount
         let prefixed_denomination = format!(
             "{}/{}/{}",
-            msg.packet.port_on_b, msg.packet.chan_on_b, packet_data.denom
+            packet.port_on_b, packet.chan_on_b, packet_data.denom
         );
 
         let denom: asset::Metadata = prefixed_denomination
@@ -379,9 +383,9 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
                 value,
                 &receiver_address,
                 CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
+                    packet_seq: packet.sequence.0,
                     // We are chain A
-                    channel_id: msg.packet.chan_on_a.0.clone(),
+                    channel_id: packet.chan_on_a.0.clone(),
                     sender: packet_data.sender.clone(),
                 },
             )
@@ -391,7 +395,7 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
         // update the value balance
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_b,
+                &packet.chan_on_b,
                 &denom.id(),
             ))
             .await?
@@ -399,17 +403,21 @@ async fn recv_transfer_packet_inner<S: StateWrite>(
 
         let new_value_balance = value_balance.saturating_add(&value.amount);
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_b, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_b, &denom.id()),
             new_value_balance,
         );
+
+        state
+            .record_ics20_inflow(&denom.id(), receiver_amount)
+            .await?;
     }
 
     Ok(())
 }
 
 // see: https://github.com/cosmos/ibc/blob/8326e26e7e1188b95c32481ff00348a705b23700/spec/app/ics-020-fungible-token-transfer/README.md?plain=1#L297
-async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
-    let packet_data: FungibleTokenPacketData = serde_json::from_slice(msg.packet.data.as_slice())?;
+async fn timeout_packet_inner<S: StateWrite>(mut state: S, packet: &Packet) -> Result<()> {
+    let packet_data: FungibleTokenPacketData = serde_json::from_slice(packet.data.as_slice())?;
     let denom: asset::Metadata = packet_data // CRITICAL: verify that this denom is validated in upstream timeout handling
         .denom
         .as_str()
@@ -432,11 +440,11 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
         asset_id: denom.id(),
     };
 
-    if is_source(&msg.packet.port_on_a, &msg.packet.chan_on_a, &denom, true) {
+    if is_source(&packet.port_on_a, &packet.chan_on_a, &denom, true) {
         // sender was source chain, unescrow tokens back to sender
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
+                &packet.chan_on_a,
                 &denom.id(),
             ))
             .await?
@@ -451,8 +459,8 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
                 value,
                 &receiver,
                 CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    channel_id: msg.packet.chan_on_a.0.clone(),
+                    packet_seq: packet.sequence.0,
+                    channel_id: packet.chan_on_a.0.clone(),
                     sender: packet_data.sender.clone(),
                 },
             )
@@ -462,7 +470,7 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
         // update the value balance
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
+                &packet.chan_on_a,
                 &denom.id(),
             ))
             .await?
@@ -473,13 +481,13 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
             .checked_sub(&amount)
             .context("underflow in ics20 timeout packet value balance subtraction")?;
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_a, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_a, &denom.id()),
             new_value_balance,
         );
     } else {
         let value_balance: Amount = state
             .get(&state_key::ics20_value_balance(
-                &msg.packet.chan_on_a,
+                &packet.chan_on_a,
                 &denom.id(),
             ))
             .await?
@@ -491,8 +499,8 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
                 &receiver,
                 // NOTE: should this be Ics20TransferTimeout?
                 CommitmentSource::Ics20Transfer {
-                    packet_seq: msg.packet.sequence.0,
-                    channel_id: msg.packet.chan_on_a.0.clone(),
+                    packet_seq: packet.sequence.0,
+                    channel_id: packet.chan_on_a.0.clone(),
                     sender: packet_data.sender.clone(),
                 },
             )
@@ -501,11 +509,14 @@ async fn timeout_packet_inner<S: StateWrite>(mut state: S, msg: &MsgTimeout) ->
 
         let new_value_balance = value_balance.saturating_add(&value.amount);
         state.put(
-            state_key::ics20_value_balance(&msg.packet.chan_on_a, &denom.id()),
+            state_key::ics20_value_balance(&packet.chan_on_a, &denom.id()),
             new_value_balance,
         );
     }
 
+    // The transfer never reached the counterparty, so it no longer counts as sent.
+    state.revert_ics20_outflow(&denom.id(), amount).await?;
+
     Ok(())
 }
 
@@ -520,7 +531,7 @@ impl AppHandlerExecute for Ics20Transfer {
     async fn chan_close_init_execute<S: StateWrite>(_state: S, _msg: &MsgChannelCloseInit) {}
     async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
         // recv packet should never fail a transaction, but it should record a failure acknowledgement.
-        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, msg).await {
+        let ack: Vec<u8> = match recv_transfer_packet_inner(&mut state, &msg.packet).await {
             Ok(_) => {
                 // record packet acknowledgement without error
                 TokenTransferAcknowledgement::success().into()
@@ -542,7 +553,7 @@ impl AppHandlerExecute for Ics20Transfer {
 
     async fn timeout_packet_execute<S: StateWrite>(mut state: S, msg: &MsgTimeout) -> Result<()> {
         // timeouts may fail due to counterparty chains sending transfers of u128-1
-        timeout_packet_inner(&mut state, msg)
+        timeout_packet_inner(&mut state, &msg.packet)
             .await
             .context("able to timeout packet")?;
 
@@ -553,3 +564,103 @@ impl AppHandlerExecute for Ics20Transfer {
 }
 
 impl AppHandler for Ics20Transfer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use cnidarium::{StateDelta, TempStorage};
+    use ibc_types::core::{
+        channel::{channel::State, ChannelEnd, Counterparty},
+        client::Height,
+        connection::ConnectionId,
+    };
+    use ibc_types::timestamp::Timestamp;
+    use penumbra_ibc::component::ChannelStateWriteExt as _;
+    use penumbra_keys::test_keys;
+
+    use crate::component::SupplyRead as _;
+
+    fn channel() -> ChannelId {
+        ChannelId::new(0)
+    }
+
+    /// A transfer packet sent from `chan_on_a` to `chan_on_b`.
+    fn packet(chan_on_a: ChannelId, chan_on_b: ChannelId, data: Vec<u8>) -> Packet {
+        Packet {
+            sequence: 1u64.into(),
+            port_on_a: PortId::transfer(),
+            chan_on_a,
+            port_on_b: PortId::transfer(),
+            chan_on_b,
+            data,
+            timeout_height_on_b: Height::new(0, 100).expect("valid height").into(),
+            timeout_timestamp_on_b: Timestamp::from_nanoseconds(0).expect("valid timestamp"),
+        }
+    }
+
+    fn withdrawal(denom: &str, amount: u64) -> Ics20Withdrawal {
+        Ics20Withdrawal {
+            amount: amount.into(),
+            denom: denom.try_into().expect("valid denom"),
+            destination_chain_address: "cosmos1receiver".to_string(),
+            return_address: *test_keys::ADDRESS_0,
+            timeout_height: Height::new(0, 100).expect("valid height"),
+            timeout_time: 1,
+            source_channel: channel(),
+        }
+    }
+
+    #[tokio::test]
+    async fn ics20_flows_count_deposits_withdrawals_and_refunds() -> Result<()> {
+        let storage = TempStorage::new().await?;
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_channel(
+            &channel(),
+            &PortId::transfer(),
+            ChannelEnd {
+                state: State::Open,
+                ordering: ChannelOrder::Unordered,
+                remote: Counterparty::new(PortId::transfer(), Some(ChannelId::new(7))),
+                connection_hops: vec![ConnectionId::new(0)],
+                version: Version::new("ics20-1".to_string()),
+            },
+        );
+        state.put_send_sequence(&channel(), &PortId::transfer(), 1);
+
+        // A deposit of a counterparty token is minted as a voucher, and counted as an inflow.
+        let deposit = FungibleTokenPacketData {
+            denom: "uatom".to_string(),
+            amount: "100".to_string(),
+            sender: "cosmos1sender".to_string(),
+            receiver: test_keys::ADDRESS_0.to_string(),
+            memo: String::new(),
+        };
+        let deposit = packet(ChannelId::new(7), channel(), serde_json::to_vec(&deposit)?);
+        recv_transfer_packet_inner(&mut state, &deposit).await?;
+        let voucher: asset::Metadata = "transfer/channel-0/uatom".try_into()?;
+        assert_eq!(state.ics20_inflow(&voucher.id()).await?, 100u64.into());
+        assert_eq!(state.ics20_outflow(&voucher.id()).await?, Amount::zero());
+
+        // Withdrawing part of the voucher burns it, and counts as an outflow.
+        state
+            .withdrawal_execute(&withdrawal("transfer/channel-0/uatom", 40))
+            .await?;
+        assert_eq!(state.ics20_outflow(&voucher.id()).await?, 40u64.into());
+        assert_eq!(state.ics20_inflow(&voucher.id()).await?, 100u64.into());
+
+        // Withdrawing a native token escrows it, and counts as an outflow...
+        let native = withdrawal("upenumbra", 25);
+        state.withdrawal_execute(&native).await?;
+        let upenumbra = native.denom.id();
+        assert_eq!(state.ics20_outflow(&upenumbra).await?, 25u64.into());
+
+        // ... until the transfer times out and is refunded.
+        let refund = packet(channel(), ChannelId::new(7), native.packet_data());
+        timeout_packet_inner(&mut state, &refund).await?;
+        assert_eq!(state.ics20_outflow(&upenumbra).await?, Amount::zero());
+        assert_eq!(state.ics20_inflow(&upenumbra).await?, Amount::zero());
+
+        Ok(())
+    }
+}
@@ -232,7 +232,7 @@ impl OutputProof {
         );
 
         tracing::trace!(?public_inputs);
-        let start = std::time::Instant::now();
+        let start = web_time::Instant::now();
         let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
             vk,
             public_inputs.as_slice(),
@@ -5,6 +5,14 @@ pub fn token_supply(asset_id: &asset::Id) -> String {
     format!("shielded_pool/assets/{asset_id}/token_supply")
 }
 
+pub fn ics20_inflow(asset_id: &asset::Id) -> String {
+    format!("shielded_pool/assets/{asset_id}/ics20_inflow")
+}
+
+pub fn ics20_outflow(asset_id: &asset::Id) -> String {
+    format!("shielded_pool/assets/{asset_id}/ics20_outflow")
+}
+
 pub fn known_assets() -> &'static str {
     "shielded_pool/known_assets"
 }
@@ -7,8 +7,9 @@ use penumbra_proto::{
     core::component::stake::v1::{
         query_service_server::QueryService, CurrentValidatorRateRequest,
         CurrentValidatorRateResponse, ValidatorInfoRequest, ValidatorInfoResponse,
-        ValidatorPenaltyRequest, ValidatorPenaltyResponse, ValidatorStatusRequest,
-        ValidatorStatusResponse,
+        ValidatorPenaltyRequest, ValidatorPenaltyResponse, ValidatorRateHistoryRequest,
+        ValidatorRateHistoryResponse, ValidatorStatusRequest, ValidatorStatusResponse,
+        ValidatorUptimeRequest, ValidatorUptimeResponse,
     },
     DomainType,
 };
@@ -73,6 +74,43 @@ impl QueryService for Server {
         ))
     }
 
+    type ValidatorRateHistoryStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValidatorRateHistoryResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn validator_rate_history(
+        &self,
+        request: tonic::Request<ValidatorRateHistoryRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorRateHistoryStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+        let identity_key = request
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+        let end_epoch_index = match request.end_epoch_index {
+            0 => None,
+            end => Some(end),
+        };
+
+        let history = state
+            .validator_rate_history(&identity_key, request.start_epoch_index, end_epoch_index)
+            .map_err(|e| Status::unavailable(format!("error getting rate history: {e}")))?;
+
+        Ok(tonic::Response::new(
+            history
+                .map_ok(|rate_data| ValidatorRateHistoryResponse {
+                    data: Some(rate_data.into()),
+                })
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting rate history: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     #[instrument(skip(self, request))]
     async fn validator_status(
         &self,
@@ -146,4 +184,28 @@ impl QueryService for Server {
             None => Err(Status::not_found("current validator rate not found")),
         }
     }
+
+    #[instrument(skip(self, request))]
+    async fn validator_uptime(
+        &self,
+        request: tonic::Request<ValidatorUptimeRequest>,
+    ) -> Result<tonic::Response<ValidatorUptimeResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let identity_key = request
+            .into_inner()
+            .identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        let uptime = state
+            .get_validator_uptime(&identity_key)
+            .await
+            .map_err(|e| Status::unavailable(format!("error getting validator uptime: {e}")))?
+            .ok_or_else(|| Status::not_found("validator uptime not found"))?;
+
+        Ok(tonic::Response::new(ValidatorUptimeResponse {
+            uptime: Some(uptime.into()),
+        }))
+    }
 }
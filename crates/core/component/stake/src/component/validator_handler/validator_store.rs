@@ -2,16 +2,16 @@ use crate::{
     component::{StateReadExt as _, MAX_VOTING_POWER},
     rate::RateData,
     state_key,
-    validator::{self, BondingState::*, State, Validator},
+    validator::{self, State, Validator},
     DelegationToken, IdentityKey, Uptime,
 };
-use anyhow::Result;
+use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use futures::{Future, FutureExt, TryStreamExt};
+use futures::{Future, FutureExt, Stream, StreamExt, TryStreamExt};
 use penumbra_num::Amount;
-use penumbra_proto::{state::future::DomainFuture, StateReadProto, StateWriteProto};
-use std::pin::Pin;
+use penumbra_proto::{state::future::DomainFuture, DomainType, StateReadProto, StateWriteProto};
+use std::{ops::Bound, pin::Pin};
 use tendermint::PublicKey;
 use tracing::instrument;
 
@@ -79,6 +79,37 @@ pub trait ValidatorDataRead: StateRead {
             .boxed()
     }
 
+    /// Returns the validator's rate data in each epoch from `start` up to, but not including,
+    /// `end`, in order of epoch index. Epochs the validator didn't exist in are skipped.
+    fn validator_rate_history(
+        &self,
+        identity_key: &IdentityKey,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<RateData>> + Send + 'static>>> {
+        let prefix = state_key::validators::rate::history::prefix(identity_key);
+        // The range is relative to the prefix.
+        let start_key = state_key::validators::rate::history::epoch(start);
+        let end_key = match end {
+            Some(end) => {
+                Bound::Excluded(state_key::validators::rate::history::epoch(end).into_bytes())
+            }
+            None => Bound::Unbounded,
+        };
+
+        Ok(self
+            .nonverifiable_range_raw(
+                Some(prefix.as_bytes()),
+                (Bound::Included(start_key.into_bytes()), end_key),
+            )?
+            .map(|result| {
+                result.and_then(|(_, bytes)| {
+                    RateData::decode(bytes.as_slice()).context("failed to decode rate data")
+                })
+            })
+            .boxed())
+    }
+
     async fn get_prev_validator_rate(&self, identity_key: &IdentityKey) -> Option<RateData> {
         self.get(&state_key::validators::rate::previous_by_id(identity_key))
             .await
@@ -157,17 +188,7 @@ pub trait ValidatorDataRead: StateRead {
 
         let min_epoch_delay = self.get_stake_params().await?.unbonding_epochs;
 
-        let upper_bound_epoch = starting_epoch.saturating_add(min_epoch_delay);
-
-        let unbonding_epoch = match val_bonding_state {
-            Bonded => upper_bound_epoch,
-            // When the minimum delay parameter changes, an unbonding validator may
-            // have a delay that is larger than the new minimum delay. In this case,
-            Unbonding { unbonds_at_epoch } => unbonds_at_epoch.min(upper_bound_epoch),
-            Unbonded => starting_epoch,
-        };
-
-        Ok(unbonding_epoch)
+        Ok(val_bonding_state.unbonding_epoch(starting_epoch, min_epoch_delay))
     }
 
     // TODO(erwan): we pull the entire validator definition instead of tracking
@@ -248,6 +269,15 @@ pub trait ValidatorDataWrite: StateWrite {
     #[instrument(skip(self))]
     fn set_validator_rate_data(&mut self, identity_key: &IdentityKey, rate_data: RateData) {
         tracing::debug!("setting validator rate data");
+        // The history is an index for clients, so it lives outside of consensus state.
+        self.nonverifiable_put_raw(
+            state_key::validators::rate::history::for_id_in_epoch(
+                identity_key,
+                rate_data.epoch_index,
+            )
+            .into_bytes(),
+            rate_data.encode_to_vec(),
+        );
         self.put(
             state_key::validators::rate::current_by_id(identity_key),
             rate_data,
@@ -56,6 +56,23 @@ pub mod validators {
         pub fn previous_by_id(id: &crate::IdentityKey) -> String {
             format!("staking/validators/data/rate/previous/{id}")
         }
+
+        /// The rate data of a validator in every epoch, indexed in nonverifiable storage.
+        pub mod history {
+            pub fn prefix(id: &crate::IdentityKey) -> String {
+                format!("staking/validators/data/rate/history/{id}/")
+            }
+
+            pub fn for_id_in_epoch(id: &crate::IdentityKey, epoch_index: u64) -> String {
+                format!("{}{}", prefix(id), epoch(epoch_index))
+            }
+
+            /// The key of an epoch's rate data relative to the prefix, for range queries.
+            pub fn epoch(epoch_index: u64) -> String {
+                // Padded like the penalty keys, so that lex order agrees with epoch order.
+                format!("{epoch_index:010}")
+            }
+        }
     }
 
     pub mod power {
@@ -77,6 +77,16 @@ impl Uptime {
     pub fn num_missed_blocks(&self) -> usize {
         self.signatures.iter_zeros().len()
     }
+
+    /// The height of the latest block recorded.
+    pub fn as_of_block_height(&self) -> u64 {
+        self.as_of_block_height
+    }
+
+    /// The number of blocks in the window.
+    pub fn window_len(&self) -> usize {
+        self.signatures.len()
+    }
 }
 
 impl DomainType for Uptime {
@@ -22,6 +22,23 @@ pub enum State {
     Unbonding { unbonds_at_epoch: u64 },
 }
 
+impl State {
+    /// Returns the epoch from which an undelegation started in `starting_epoch` can be claimed,
+    /// given the minimum delay of `unbonding_epochs`.
+    pub fn unbonding_epoch(&self, starting_epoch: u64, unbonding_epochs: u64) -> u64 {
+        let upper_bound_epoch = starting_epoch.saturating_add(unbonding_epochs);
+
+        match self {
+            State::Bonded => upper_bound_epoch,
+            // When the minimum delay parameter changes, an unbonding validator may
+            // have a delay that is larger than the new minimum delay. In this case,
+            // the undelegation completes after the new minimum delay.
+            State::Unbonding { unbonds_at_epoch } => (*unbonds_at_epoch).min(upper_bound_epoch),
+            State::Unbonded => starting_epoch,
+        }
+    }
+}
+
 impl std::fmt::Display for State {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -1,4 +1,5 @@
 use penumbra_community_pool::genesis::Content as CommunityPoolContent;
+use penumbra_dex::genesis::Content as DexContent;
 use penumbra_distributions::genesis::Content as DistributionsContent;
 use penumbra_fee::genesis::Content as FeeContent;
 use penumbra_funding::genesis::Content as FundingContent;
@@ -28,6 +29,8 @@ pub struct Content {
     pub chain_id: String,
     /// Community Pool module genesis state.
     pub community_pool_content: CommunityPoolContent,
+    /// DEX module genesis state.
+    pub dex_content: DexContent,
     /// Distributions module genesis state.
     pub distributions_content: DistributionsContent,
     /// Fee module genesis state.
@@ -76,6 +79,7 @@ impl From<Content> for pb::GenesisContent {
         pb::GenesisContent {
             chain_id: genesis.chain_id,
             community_pool_content: Some(genesis.community_pool_content.into()),
+            dex_content: Some(genesis.dex_content.into()),
             distributions_content: Some(genesis.distributions_content.into()),
             fee_content: Some(genesis.fee_content.into()),
             funding_content: Some(genesis.funding_content.into()),
@@ -116,6 +120,10 @@ impl TryFrom<pb::GenesisContent> for Content {
                 .community_pool_content
                 .ok_or_else(|| anyhow::anyhow!("proto response missing Community Pool content"))?
                 .try_into()?,
+            dex_content: msg
+                .dex_content
+                .ok_or_else(|| anyhow::anyhow!("proto response missing dex content"))?
+                .try_into()?,
             distributions_content: msg
                 .distributions_content
                 .ok_or_else(|| anyhow::anyhow!("proto response missing distributions content"))?
@@ -31,6 +31,21 @@ impl AddressView {
             AddressView::Decoded { address, .. } => *address,
         }
     }
+
+    /// Formats this address briefly, as the account it belongs to if it's decoded, or in full
+    /// if it's opaque, so that it can be copy-pasted.
+    pub fn display_short(&self) -> String {
+        match self {
+            AddressView::Decoded { index, .. } => {
+                if !index.is_ephemeral() {
+                    format!("[account {:?}]", index.account)
+                } else {
+                    format!("[account {:?} (one-time address)]", index.account)
+                }
+            }
+            AddressView::Opaque { address } => format!("{}", address),
+        }
+    }
 }
 
 impl DomainType for AddressView {
@@ -156,6 +156,33 @@ impl AddressIndex {
         AddressIndex::from(account)
     }
 
+    /// The `n`th deposit address index of an account, for pre-generating addresses in sequence.
+    ///
+    /// The sequence number is stored in the randomizer, so it can be recovered with
+    /// [`AddressIndex::sequence_number`] from the index of any address it was used for. The
+    /// sequence number `0` is the account's default address.
+    pub fn sequential(account: u32, n: u64) -> Self {
+        let mut randomizer = [0u8; 12];
+        randomizer[0..8].copy_from_slice(&n.to_le_bytes());
+        Self {
+            account,
+            randomizer,
+        }
+    }
+
+    /// The sequence number of an index made by [`AddressIndex::sequential`], or `None` if the
+    /// index has a randomizer that can't have been made that way, such as a random ephemeral one.
+    pub fn sequence_number(&self) -> Option<u64> {
+        if self.randomizer[8..12] != [0; 4] {
+            return None;
+        }
+        Some(u64::from_le_bytes(
+            self.randomizer[0..8]
+                .try_into()
+                .expect("randomizer has 8 byte prefix"),
+        ))
+    }
+
     pub fn new_ephemeral<R: RngCore + CryptoRng>(account: u32, mut rng: R) -> Self {
         let mut bytes = [0u8; 12];
 
@@ -286,5 +313,12 @@ mod tests {
             let index2 = key.index_for_diversifier(&diversifier);
             assert_eq!(index2, index);
         }
+
+        #[test]
+        fn sequence_number_roundtrip(account in any::<u32>(), n in any::<u64>()) {
+            let index = AddressIndex::sequential(account, n);
+            assert_eq!(index.account, account);
+            assert_eq!(index.sequence_number(), Some(n));
+        }
     }
 }
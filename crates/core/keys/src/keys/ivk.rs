@@ -44,6 +44,23 @@ impl IncomingViewingKey {
         )
     }
 
+    /// Derive the addresses with sequence numbers in `range` for the given account, along with
+    /// their indices and detection keys.
+    ///
+    /// The addresses depend only on the key, account and sequence numbers, so they can be
+    /// generated ahead of time on an offline machine, and matched to incoming notes later.
+    pub fn sequential_addresses(
+        &self,
+        account: u32,
+        range: std::ops::Range<u64>,
+    ) -> impl Iterator<Item = (AddressIndex, Address, fmd::DetectionKey)> + '_ {
+        range.map(move |n| {
+            let index = AddressIndex::sequential(account, n);
+            let (address, dtk) = self.payment_address(index);
+            (index, address, dtk)
+        })
+    }
+
     /// Derive an ephemeral address for the provided account.
     pub fn ephemeral_address<R: RngCore + CryptoRng>(
         &self,
@@ -193,6 +210,26 @@ mod test {
         }
     }
 
+    #[test]
+    fn sequential_addresses_are_deterministic_and_recoverable() {
+        let rng = rand::rngs::OsRng;
+        let spend_key =
+            SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(rng), &Bip44Path::new(0));
+        let fvk = spend_key.full_viewing_key();
+        let ivk = fvk.incoming();
+
+        let first: Vec<_> = ivk.sequential_addresses(3, 0..4).collect();
+        let second: Vec<_> = ivk.sequential_addresses(3, 0..4).collect();
+        assert_eq!(first.len(), 4);
+        for ((index, address, dtk), (_, address2, dtk2)) in first.iter().zip(second.iter()) {
+            assert_eq!(address, address2);
+            assert_eq!(dtk.to_bytes(), dtk2.to_bytes());
+            assert_eq!(fvk.address_index(address), Some(*index));
+        }
+        assert_eq!(first[0].1, ivk.payment_address(AddressIndex::from(3u32)).0);
+        assert_eq!(first[2].0.sequence_number(), Some(2));
+    }
+
     #[test]
     fn views_address_fails_on_other_address() {
         let rng = rand::rngs::OsRng;
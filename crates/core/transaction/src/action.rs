@@ -38,6 +38,10 @@ pub enum Action {
     CommunityPoolSpend(penumbra_community_pool::CommunityPoolSpend),
     CommunityPoolOutput(penumbra_community_pool::CommunityPoolOutput),
     CommunityPoolDeposit(penumbra_community_pool::CommunityPoolDeposit),
+
+    FeeGrant(penumbra_fee::grant::FeeGrant),
+    FeeGrantUse(penumbra_fee::grant::FeeGrantUse),
+    FeeGrantReclaim(penumbra_fee::grant::FeeGrantReclaim),
 }
 
 impl EffectingData for Action {
@@ -64,6 +68,9 @@ impl EffectingData for Action {
             Action::CommunityPoolSpend(d) => d.effect_hash(),
             Action::CommunityPoolOutput(d) => d.effect_hash(),
             Action::CommunityPoolDeposit(d) => d.effect_hash(),
+            Action::FeeGrant(g) => g.effect_hash(),
+            Action::FeeGrantUse(u) => u.effect_hash(),
+            Action::FeeGrantReclaim(r) => r.effect_hash(),
         }
     }
 }
@@ -108,6 +115,9 @@ impl Action {
             Action::CommunityPoolDeposit(_) => tracing::info_span!("CommunityPoolDeposit", ?idx),
             Action::CommunityPoolSpend(_) => tracing::info_span!("CommunityPoolSpend", ?idx),
             Action::CommunityPoolOutput(_) => tracing::info_span!("CommunityPoolOutput", ?idx),
+            Action::FeeGrant(_) => tracing::info_span!("FeeGrant", ?idx),
+            Action::FeeGrantUse(_) => tracing::info_span!("FeeGrantUse", ?idx),
+            Action::FeeGrantReclaim(_) => tracing::info_span!("FeeGrantReclaim", ?idx),
         }
     }
 }
@@ -134,6 +144,9 @@ impl IsAction for Action {
             Action::CommunityPoolDeposit(deposit) => deposit.balance_commitment(),
             Action::CommunityPoolSpend(spend) => spend.balance_commitment(),
             Action::CommunityPoolOutput(output) => output.balance_commitment(),
+            Action::FeeGrant(grant) => grant.balance_commitment(),
+            Action::FeeGrantUse(grant_use) => grant_use.balance_commitment(),
+            Action::FeeGrantReclaim(reclaim) => reclaim.balance_commitment(),
             // These actions just post Protobuf data to the chain, and leave the
             // value balance unchanged.
             Action::IbcRelay(x) => x.balance_commitment(),
@@ -162,6 +175,9 @@ impl IsAction for Action {
             Action::CommunityPoolSpend(x) => x.view_from_perspective(txp),
             Action::CommunityPoolOutput(x) => x.view_from_perspective(txp),
             Action::CommunityPoolDeposit(x) => x.view_from_perspective(txp),
+            Action::FeeGrant(x) => x.view_from_perspective(txp),
+            Action::FeeGrantUse(x) => x.view_from_perspective(txp),
+            Action::FeeGrantReclaim(x) => x.view_from_perspective(txp),
             // TODO: figure out where to implement the actual decryption methods for these? where are their action definitions?
             Action::ValidatorDefinition(x) => ActionView::ValidatorDefinition(x.to_owned()),
             Action::IbcRelay(x) => ActionView::IbcRelay(x.to_owned()),
@@ -239,6 +255,15 @@ impl From<Action> for pb::Action {
             Action::CommunityPoolDeposit(inner) => pb::Action {
                 action: Some(pb::action::Action::CommunityPoolDeposit(inner.into())),
             },
+            Action::FeeGrant(inner) => pb::Action {
+                action: Some(pb::action::Action::FeeGrant(inner.into())),
+            },
+            Action::FeeGrantUse(inner) => pb::Action {
+                action: Some(pb::action::Action::FeeGrantUse(inner.into())),
+            },
+            Action::FeeGrantReclaim(inner) => pb::Action {
+                action: Some(pb::action::Action::FeeGrantReclaim(inner.into())),
+            },
         }
     }
 }
@@ -304,6 +329,11 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::CommunityPoolDeposit(inner) => {
                 Ok(Action::CommunityPoolDeposit(inner.try_into()?))
             }
+            pb::action::Action::FeeGrant(inner) => Ok(Action::FeeGrant(inner.try_into()?)),
+            pb::action::Action::FeeGrantUse(inner) => Ok(Action::FeeGrantUse(inner.try_into()?)),
+            pb::action::Action::FeeGrantReclaim(inner) => {
+                Ok(Action::FeeGrantReclaim(inner.try_into()?))
+            }
         }
     }
 }
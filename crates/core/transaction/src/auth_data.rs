@@ -15,6 +15,12 @@ pub struct AuthorizationData {
     /// The required delegator vote authorization signatures, returned in the same order as the
     /// DelegatorVote actions in the original request.
     pub delegator_vote_auths: Vec<Signature<SpendAuth>>,
+    /// The required fee grant use authorization signatures, returned in the same order as the
+    /// FeeGrantUse actions in the original request.
+    pub fee_grant_auths: Vec<Signature<SpendAuth>>,
+    /// The required fee grant reclaim authorization signatures, returned in the same order as
+    /// the FeeGrantReclaim actions in the original request.
+    pub fee_grant_reclaim_auths: Vec<Signature<SpendAuth>>,
 }
 
 impl DomainType for AuthorizationData {
@@ -31,6 +37,12 @@ impl From<AuthorizationData> for pb::AuthorizationData {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            fee_grant_auths: msg.fee_grant_auths.into_iter().map(Into::into).collect(),
+            fee_grant_reclaim_auths: msg
+                .fee_grant_reclaim_auths
+                .into_iter()
+                .map(Into::into)
+                .collect(),
         }
     }
 }
@@ -50,6 +62,16 @@ impl TryFrom<pb::AuthorizationData> for AuthorizationData {
                 .into_iter()
                 .map(TryInto::try_into)
                 .collect::<Result<_, _>>()?,
+            fee_grant_auths: value
+                .fee_grant_auths
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+            fee_grant_reclaim_auths: value
+                .fee_grant_reclaim_auths
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
         })
     }
 }
@@ -1,6 +1,9 @@
 use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_dex::{PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim};
-use penumbra_fee::Gas;
+use penumbra_fee::{
+    grant::{FeeGrant, FeeGrantReclaim, FeeGrantUse},
+    Gas,
+};
 use penumbra_ibc::IbcRelay;
 use penumbra_shielded_pool::{Ics20Withdrawal, Output, Spend};
 use penumbra_stake::{
@@ -196,6 +199,27 @@ fn position_withdraw_gas_cost() -> Gas {
     }
 }
 
+/// The gas cost of making, drawing on, or reclaiming a fee grant, which only touch the grant's state.
+fn fee_grant_gas_cost() -> Gas {
+    Gas {
+        // Each [`Action`] has a `0` `block_space` cost, since the [`Transaction`] itself
+        // will use the encoded size of the complete transaction to calculate the block space.
+        block_space: 0,
+        // The compact block space cost is based on the byte size of the data the [`Action`] adds
+        // to the compact block.
+        // For a FeeGrant, FeeGrantUse or FeeGrantReclaim the compact block is not modified.
+        compact_block_space: 0u64,
+        // Does not include a zk-SNARK proof, so there's no verification cost.
+        verification: 0,
+        // Execution cost is currently hardcoded at 10 for all Action variants.
+        execution: 10,
+    }
+}
+
+pub fn fee_grant_use_gas_cost() -> Gas {
+    fee_grant_gas_cost()
+}
+
 impl GasCost for Transaction {
     fn gas_cost(&self) -> Gas {
         self.actions().map(GasCost::gas_cost).sum()
@@ -243,6 +267,9 @@ impl GasCost for ActionPlan {
             ActionPlan::CommunityPoolOutput(d) => d.gas_cost(),
             ActionPlan::CommunityPoolDeposit(dd) => dd.gas_cost(),
             ActionPlan::Ics20Withdrawal(w) => w.gas_cost(),
+            ActionPlan::FeeGrant(g) => g.gas_cost(),
+            ActionPlan::FeeGrantUse(_) => fee_grant_use_gas_cost(),
+            ActionPlan::FeeGrantReclaim(_) => fee_grant_gas_cost(),
         }
     }
 }
@@ -269,6 +296,9 @@ impl GasCost for Action {
             Action::CommunityPoolDeposit(deposit) => deposit.gas_cost(),
             Action::CommunityPoolSpend(spend) => spend.gas_cost(),
             Action::CommunityPoolOutput(output) => output.gas_cost(),
+            Action::FeeGrant(grant) => grant.gas_cost(),
+            Action::FeeGrantUse(grant_use) => grant_use.gas_cost(),
+            Action::FeeGrantReclaim(reclaim) => reclaim.gas_cost(),
             Action::IbcRelay(x) => x.gas_cost(),
             Action::ValidatorDefinition(x) => x.gas_cost(),
         }
@@ -456,6 +486,24 @@ impl GasCost for Ics20Withdrawal {
     }
 }
 
+impl GasCost for FeeGrant {
+    fn gas_cost(&self) -> Gas {
+        fee_grant_gas_cost()
+    }
+}
+
+impl GasCost for FeeGrantUse {
+    fn gas_cost(&self) -> Gas {
+        fee_grant_use_gas_cost()
+    }
+}
+
+impl GasCost for FeeGrantReclaim {
+    fn gas_cost(&self) -> Gas {
+        fee_grant_gas_cost()
+    }
+}
+
 impl GasCost for CommunityPoolDeposit {
     fn gas_cost(&self) -> Gas {
         Gas {
@@ -10,6 +10,7 @@ use penumbra_dex::{
     swap::{Swap, SwapCiphertext, SwapView},
     swap_claim::{SwapClaim, SwapClaimView},
 };
+use penumbra_fee::grant::{FeeGrant, FeeGrantReclaim, FeeGrantUse};
 use penumbra_governance::{
     DelegatorVote, DelegatorVoteView, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
     ValidatorVote, VotingReceiptToken,
@@ -225,6 +226,36 @@ impl IsAction for CommunityPoolDeposit {
     }
 }
 
+impl IsAction for FeeGrant {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::FeeGrant(self.clone())
+    }
+}
+
+impl IsAction for FeeGrantUse {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::FeeGrantUse(self.clone())
+    }
+}
+
+impl IsAction for FeeGrantReclaim {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::FeeGrantReclaim(self.clone())
+    }
+}
+
 impl IsAction for CommunityPoolOutput {
     fn balance_commitment(&self) -> balance::Commitment {
         // Outputs from the Community Pool require value
@@ -331,6 +362,7 @@ impl IsAction for Swap {
         ActionView::Swap(match plaintext {
             Some(swap_plaintext) => SwapView::Visible {
                 swap: self.to_owned(),
+                trading_pair: swap_plaintext.trading_pair.view_with_cache(&txp.denoms),
                 swap_plaintext,
             },
             None => SwapView::Opaque {
@@ -22,6 +22,7 @@ mod detection_data;
 mod error;
 mod is_action;
 mod parameters;
+mod receipt;
 mod transaction;
 mod witness_data;
 
@@ -38,8 +39,12 @@ pub use error::Error;
 pub use is_action::IsAction;
 pub use parameters::TransactionParameters;
 pub use plan::{ActionPlan, TransactionPlan};
+pub use receipt::PaymentReceipt;
 pub use transaction::{Transaction, TransactionBody};
-pub use view::{ActionView, MemoPlaintextView, MemoView, TransactionPerspective, TransactionView};
+pub use view::{
+    ActionView, MemoPlaintextView, MemoView, TransactionPerspective, TransactionPerspectiveBuilder,
+    TransactionView,
+};
 pub use witness_data::WitnessData;
 
 pub use penumbra_txhash as txhash;
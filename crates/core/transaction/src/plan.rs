@@ -6,9 +6,11 @@ use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, Communi
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     lp::plan::PositionWithdrawPlan,
+    lp::position,
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
 };
+use penumbra_fee::grant::{FeeGrantReclaimPlan, FeeGrantUsePlan};
 use penumbra_governance::{
     DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
 };
@@ -256,6 +258,26 @@ impl TransactionPlan {
         })
     }
 
+    pub fn fee_grant_use_plans(&self) -> impl Iterator<Item = &FeeGrantUsePlan> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::FeeGrantUse(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn fee_grant_reclaim_plans(&self) -> impl Iterator<Item = &FeeGrantReclaimPlan> {
+        self.actions.iter().filter_map(|action| {
+            if let ActionPlan::FeeGrantReclaim(v) = action {
+                Some(v)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn community_pool_deposits(&self) -> impl Iterator<Item = &CommunityPoolDeposit> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::CommunityPoolDeposit(v) = action {
@@ -286,6 +308,14 @@ impl TransactionPlan {
         })
     }
 
+    /// The IDs of the positions opened by this transaction.
+    ///
+    /// A position's ID is derived from its trading function and nonce, so it's known as soon as
+    /// the transaction is planned, before it's built or broadcast.
+    pub fn opened_position_ids(&self) -> impl Iterator<Item = position::Id> + '_ {
+        self.position_openings().map(|open| open.position.id())
+    }
+
     pub fn position_closings(&self) -> impl Iterator<Item = &PositionClose> {
         self.actions.iter().filter_map(|action| {
             if let ActionPlan::PositionClose(v) = action {
@@ -399,8 +429,12 @@ impl TryFrom<pb::TransactionPlan> for TransactionPlan {
 
 #[cfg(test)]
 mod tests {
+    use decaf377::Fq;
     use penumbra_asset::{asset, Value, STAKING_TOKEN_ASSET_ID};
-    use penumbra_dex::{swap::SwapPlaintext, swap::SwapPlan, TradingPair};
+    use penumbra_dex::{
+        swap::SwapPlaintext, swap::SwapPlan, swap_claim::SwapClaimPlan, BatchSwapOutputData,
+        TradingPair,
+    };
     use penumbra_fee::Fee;
     use penumbra_keys::{
         keys::{Bip44Path, SeedPhrase, SpendKey},
@@ -547,4 +581,91 @@ mod tests {
         //     .expect("can build");
         // assert_eq!(plan_effect_hash, transaction.effect_hash());
     }
+
+    #[test]
+    fn witness_data_covers_every_swap_claim() {
+        let sk = SpendKey::from_seed_phrase_bip44(SeedPhrase::generate(OsRng), &Bip44Path::new(0));
+        let fvk = sk.full_viewing_key();
+        let (addr, _dtk) = fvk.incoming().payment_address(0u32.into());
+
+        let trading_pair = TradingPair::new(
+            asset::Cache::with_known_assets()
+                .get_unit("nala")
+                .unwrap()
+                .id(),
+            *STAKING_TOKEN_ASSET_ID,
+        );
+        let output_data = BatchSwapOutputData {
+            delta_1: 0u64.into(),
+            delta_2: 0u64.into(),
+            lambda_1: 0u64.into(),
+            lambda_2: 0u64.into(),
+            unfilled_1: 0u64.into(),
+            unfilled_2: 0u64.into(),
+            height: 1,
+            trading_pair,
+            epoch_starting_height: 0,
+        };
+
+        let mut sct = tct::Tree::new();
+        let note = Note::generate(
+            &mut OsRng,
+            &addr,
+            Value {
+                amount: 10000u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        let spend_position = sct.insert(tct::Witness::Keep, note.commit()).unwrap();
+
+        let mut actions = vec![SpendPlan::new(&mut OsRng, note, spend_position).into()];
+        let mut swap_commitments = Vec::new();
+        for amount in [100u64, 200, 300] {
+            let swap_plaintext = SwapPlaintext::new(
+                &mut OsRng,
+                trading_pair,
+                amount.into(),
+                0u64.into(),
+                Fee::from_staking_token_amount(10u64.into()),
+                addr.clone(),
+            );
+            let commitment = swap_plaintext.swap_commitment();
+            let position = sct.insert(tct::Witness::Keep, commitment).unwrap();
+            swap_commitments.push(commitment);
+            actions.push(
+                SwapClaimPlan {
+                    swap_plaintext,
+                    position,
+                    output_data,
+                    epoch_duration: 100,
+                    proof_blinding_r: Fq::from(1u64),
+                    proof_blinding_s: Fq::from(2u64),
+                }
+                .into(),
+            );
+        }
+
+        let plan = TransactionPlan {
+            actions,
+            ..Default::default()
+        };
+        let witness_data = plan.witness_data(&sct).unwrap();
+
+        assert_eq!(witness_data.anchor, sct.root());
+        assert_eq!(witness_data.state_commitment_proofs.len(), 4);
+        for commitment in swap_commitments {
+            let proof = &witness_data.state_commitment_proofs[&commitment];
+            assert_eq!(proof.commitment(), commitment);
+            assert!(proof.verify(sct.root()).is_ok());
+        }
+
+        // Claims of swaps that aren't in the tree can't be witnessed, even if the spends can.
+        let mut other_sct = tct::Tree::new();
+        for spend in plan.spend_plans() {
+            other_sct
+                .insert(tct::Witness::Keep, spend.note.commit())
+                .unwrap();
+        }
+        assert!(plan.witness_data(&other_sct).is_err());
+    }
 }
@@ -15,6 +15,7 @@ use penumbra_dex::{
     swap::SwapPlan,
     swap_claim::SwapClaimPlan,
 };
+use penumbra_fee::grant::{FeeGrant, FeeGrantReclaimPlan, FeeGrantUsePlan};
 use penumbra_governance::{
     delegator_vote::DelegatorVotePlan, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw,
     ValidatorVote,
@@ -75,6 +76,13 @@ pub enum ActionPlan {
     CommunityPoolDeposit(CommunityPoolDeposit),
 
     Ics20Withdrawal(Ics20Withdrawal),
+
+    /// Grant an allowance for another key's transaction fees.
+    FeeGrant(FeeGrant),
+    /// Pay the transaction's fee from a fee grant.
+    FeeGrantUse(FeeGrantUsePlan),
+    /// Reclaim what remains of a fee grant's allowance, revoking it.
+    FeeGrantReclaim(FeeGrantReclaimPlan),
 }
 
 impl ActionPlan {
@@ -154,6 +162,11 @@ impl ActionPlan {
             CommunityPoolDeposit(plan) => Action::CommunityPoolDeposit(plan.clone()),
             // Fixme: action name
             Ics20Withdrawal(plan) => Action::Ics20Withdrawal(plan.clone()),
+            FeeGrant(plan) => Action::FeeGrant(plan.clone()),
+            FeeGrantUse(plan) => Action::FeeGrantUse(plan.fee_grant_use(fvk, [0; 64].into())),
+            FeeGrantReclaim(plan) => {
+                Action::FeeGrantReclaim(plan.fee_grant_reclaim(fvk, [0; 64].into()))
+            }
         })
     }
 
@@ -179,6 +192,9 @@ impl ActionPlan {
             PositionClose(position_close) => position_close.balance(),
             PositionWithdraw(position_withdraw) => position_withdraw.balance(),
             Ics20Withdrawal(withdrawal) => withdrawal.balance(),
+            FeeGrant(fee_grant) => fee_grant.balance(),
+            FeeGrantUse(fee_grant_use) => fee_grant_use.balance(),
+            FeeGrantReclaim(fee_grant_reclaim) => fee_grant_reclaim.balance(),
             // None of these contribute to transaction balance:
             IbcAction(_) | ValidatorDefinition(_) | ValidatorVote(_) => Balance::default(),
         }
@@ -209,6 +225,9 @@ impl ActionPlan {
             CommunityPoolOutput(_) => Fr::zero(),
             CommunityPoolDeposit(_) => Fr::zero(),
             Ics20Withdrawal(_) => Fr::zero(),
+            FeeGrant(_) => Fr::zero(),
+            FeeGrantUse(_) => Fr::zero(),
+            FeeGrantReclaim(_) => Fr::zero(),
         }
     }
 
@@ -238,6 +257,9 @@ impl ActionPlan {
             CommunityPoolOutput(plan) => plan.effect_hash(),
             CommunityPoolDeposit(plan) => plan.effect_hash(),
             Ics20Withdrawal(plan) => plan.effect_hash(),
+            FeeGrant(plan) => plan.effect_hash(),
+            FeeGrantUse(plan) => plan.fee_grant_use_body(fvk).effect_hash(),
+            FeeGrantReclaim(plan) => plan.fee_grant_reclaim_body(fvk).effect_hash(),
         }
     }
 }
@@ -334,6 +356,24 @@ impl From<Ics20Withdrawal> for ActionPlan {
     }
 }
 
+impl From<FeeGrant> for ActionPlan {
+    fn from(inner: FeeGrant) -> ActionPlan {
+        ActionPlan::FeeGrant(inner)
+    }
+}
+
+impl From<FeeGrantUsePlan> for ActionPlan {
+    fn from(inner: FeeGrantUsePlan) -> ActionPlan {
+        ActionPlan::FeeGrantUse(inner)
+    }
+}
+
+impl From<FeeGrantReclaimPlan> for ActionPlan {
+    fn from(inner: FeeGrantReclaimPlan) -> ActionPlan {
+        ActionPlan::FeeGrantReclaim(inner)
+    }
+}
+
 impl DomainType for ActionPlan {
     type Proto = pb_t::ActionPlan;
 }
@@ -412,6 +452,15 @@ impl From<ActionPlan> for pb_t::ActionPlan {
             ActionPlan::Ics20Withdrawal(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::Ics20Withdrawal(inner.into())),
             },
+            ActionPlan::FeeGrant(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::FeeGrant(inner.into())),
+            },
+            ActionPlan::FeeGrantUse(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::FeeGrantUse(inner.into())),
+            },
+            ActionPlan::FeeGrantReclaim(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::FeeGrantReclaim(inner.into())),
+            },
         }
     }
 }
@@ -488,6 +537,15 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::Ics20Withdrawal(inner) => {
                 Ok(ActionPlan::Ics20Withdrawal(inner.try_into()?))
             }
+            pb_t::action_plan::Action::FeeGrant(inner) => {
+                Ok(ActionPlan::FeeGrant(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::FeeGrantUse(inner) => {
+                Ok(ActionPlan::FeeGrantUse(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::FeeGrantReclaim(inner) => {
+                Ok(ActionPlan::FeeGrantReclaim(inner.try_into()?))
+            }
         }
     }
 }
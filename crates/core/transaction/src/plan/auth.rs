@@ -17,6 +17,8 @@ impl TransactionPlan {
         let effect_hash = self.effect_hash(sk.full_viewing_key())?;
         let mut spend_auths = Vec::new();
         let mut delegator_vote_auths = Vec::new();
+        let mut fee_grant_auths = Vec::new();
+        let mut fee_grant_reclaim_auths = Vec::new();
 
         for spend_plan in self.spend_plans() {
             let rsk = sk.spend_auth_key().randomize(&spend_plan.randomizer);
@@ -30,10 +32,26 @@ impl TransactionPlan {
             let auth_sig = rsk.sign(&mut rng, effect_hash.as_ref());
             delegator_vote_auths.push(auth_sig);
         }
+        for fee_grant_use_plan in self.fee_grant_use_plans() {
+            let rsk = sk
+                .spend_auth_key()
+                .randomize(&fee_grant_use_plan.randomizer);
+            let auth_sig = rsk.sign(&mut rng, effect_hash.as_ref());
+            fee_grant_auths.push(auth_sig);
+        }
+        for fee_grant_reclaim_plan in self.fee_grant_reclaim_plans() {
+            let rsk = sk
+                .spend_auth_key()
+                .randomize(&fee_grant_reclaim_plan.randomizer);
+            let auth_sig = rsk.sign(&mut rng, effect_hash.as_ref());
+            fee_grant_reclaim_auths.push(auth_sig);
+        }
         Ok(AuthorizationData {
             effect_hash: Some(effect_hash),
             spend_auths,
             delegator_vote_auths,
+            fee_grant_auths,
+            fee_grant_reclaim_auths,
         })
     }
 }
@@ -100,6 +100,38 @@ impl TransactionPlan {
             delegator_vote.auth_sig = auth_sig;
         }
 
+        for (fee_grant_use, auth_sig) in transaction
+            .transaction_body
+            .actions
+            .iter_mut()
+            .filter_map(|action| {
+                if let Action::FeeGrantUse(u) = action {
+                    Some(u)
+                } else {
+                    None
+                }
+            })
+            .zip(auth_data.fee_grant_auths.clone().into_iter())
+        {
+            fee_grant_use.auth_sig = auth_sig;
+        }
+
+        for (fee_grant_reclaim, auth_sig) in transaction
+            .transaction_body
+            .actions
+            .iter_mut()
+            .filter_map(|action| {
+                if let Action::FeeGrantReclaim(r) = action {
+                    Some(r)
+                } else {
+                    None
+                }
+            })
+            .zip(auth_data.fee_grant_reclaim_auths.clone().into_iter())
+        {
+            fee_grant_reclaim.auth_sig = auth_sig;
+        }
+
         // Compute the binding signature and assemble the transaction.
         let binding_signing_key = rdsa::SigningKey::from(synthetic_blinding_factor);
         let auth_hash = transaction.transaction_body.auth_hash();
@@ -195,15 +227,21 @@ impl TransactionPlan {
     pub fn witness_data(&self, sct: &penumbra_tct::Tree) -> Result<WitnessData, anyhow::Error> {
         let anchor = sct.root();
 
-        let witness_note = |spend: &penumbra_shielded_pool::SpendPlan| {
-            let commitment = spend.note.commit();
+        let witness = |commitment: penumbra_tct::StateCommitment| {
             sct.witness(commitment)
                 .ok_or_else(|| anyhow::anyhow!("commitment should exist in tree"))
                 .map(|proof| (commitment, proof))
         };
+        // Each swap claim proves the inclusion of its swap, just as each spend proves the
+        // inclusion of its note, so a transaction claiming several swaps needs a proof for each.
         let state_commitment_proofs = self
             .spend_plans()
-            .map(witness_note)
+            .map(|spend| spend.note.commit())
+            .chain(
+                self.swap_claim_plans()
+                    .map(|swap_claim| swap_claim.swap_plaintext.swap_commitment()),
+            )
+            .map(witness)
             .collect::<Result<_, _>>()?;
 
         Ok(WitnessData {
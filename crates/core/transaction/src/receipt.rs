@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Context, Error};
+use penumbra_keys::PayloadKey;
+use penumbra_proto::{core::transaction::v1 as pbt, DomainType};
+use penumbra_shielded_pool::{Note, Output};
+use penumbra_tct::StateCommitment;
+use penumbra_txhash::TransactionId;
+use serde::{Deserialize, Serialize};
+
+use crate::{Transaction, TransactionPerspective};
+
+/// A receipt for a payment made by a single output of a transaction.
+///
+/// The receipt discloses the [`PayloadKey`] of the output, so that anyone holding it can decrypt
+/// the output's note and check who it paid and how much, without any viewing key. This allows a
+/// sender to prove a payment to a third party, e.g., to resolve a dispute, without disclosing the
+/// rest of the transaction.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pbt::PaymentReceipt", into = "pbt::PaymentReceipt")]
+pub struct PaymentReceipt {
+    /// The transaction containing the output.
+    pub transaction_id: TransactionId,
+    /// The commitment to the output's note.
+    pub note_commitment: StateCommitment,
+    /// The payload key of the output.
+    pub payload_key: PayloadKey,
+}
+
+impl PaymentReceipt {
+    /// Makes a receipt for the output of `transaction` with the given `note_commitment`, using the
+    /// payload key in the `perspective` of its sender or recipient.
+    pub fn new(
+        transaction: &Transaction,
+        perspective: &TransactionPerspective,
+        note_commitment: StateCommitment,
+    ) -> anyhow::Result<Self> {
+        let payload_key = perspective
+            .payload_keys
+            .get(&note_commitment)
+            .copied()
+            .ok_or_else(|| anyhow!("no payload key for output {note_commitment}"))?;
+        let receipt = Self {
+            transaction_id: transaction.id(),
+            note_commitment,
+            payload_key,
+        };
+        // Don't hand out a receipt that won't verify.
+        receipt.verify(transaction)?;
+        Ok(receipt)
+    }
+
+    /// Verifies the receipt against the `transaction` it was made for, returning the note the
+    /// receipted output paid.
+    pub fn verify(&self, transaction: &Transaction) -> anyhow::Result<Note> {
+        anyhow::ensure!(
+            transaction.id() == self.transaction_id,
+            "receipt is for transaction {}, not {}",
+            self.transaction_id,
+            transaction.id()
+        );
+        let output = self.output(transaction)?;
+        let payload = &output.body.note_payload;
+        let note = Note::decrypt_with_payload_key(
+            &payload.encrypted_note,
+            &self.payload_key,
+            &payload.ephemeral_key,
+        )
+        .context("receipt's payload key does not decrypt the output")?;
+        // Decryption authenticates the ciphertext, but the note must also be the one committed
+        // to, or the output could pay something other than the note the receipt shows.
+        anyhow::ensure!(
+            note.commit() == self.note_commitment,
+            "decrypted note does not match the output's note commitment"
+        );
+        Ok(note)
+    }
+
+    /// Finds the receipted output in `transaction`.
+    fn output<'a>(&self, transaction: &'a Transaction) -> anyhow::Result<&'a Output> {
+        transaction
+            .outputs()
+            .find(|output| output.body.note_payload.note_commitment == self.note_commitment)
+            .ok_or_else(|| {
+                anyhow!(
+                    "transaction {} has no output {}",
+                    self.transaction_id,
+                    self.note_commitment
+                )
+            })
+    }
+}
+
+impl DomainType for PaymentReceipt {
+    type Proto = pbt::PaymentReceipt;
+}
+
+impl TryFrom<pbt::PaymentReceipt> for PaymentReceipt {
+    type Error = Error;
+
+    fn try_from(proto: pbt::PaymentReceipt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            transaction_id: proto
+                .transaction_id
+                .ok_or_else(|| anyhow!("missing transaction ID"))?
+                .try_into()?,
+            note_commitment: proto
+                .note_commitment
+                .ok_or_else(|| anyhow!("missing note commitment"))?
+                .try_into()?,
+            payload_key: proto
+                .payload_key
+                .ok_or_else(|| anyhow!("missing payload key"))?
+                .try_into()?,
+        })
+    }
+}
+
+impl From<PaymentReceipt> for pbt::PaymentReceipt {
+    fn from(receipt: PaymentReceipt) -> Self {
+        Self {
+            transaction_id: Some(receipt.transaction_id.into()),
+            note_commitment: Some(receipt.note_commitment.into()),
+            payload_key: Some(receipt.payload_key.into()),
+        }
+    }
+}
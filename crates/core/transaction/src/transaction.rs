@@ -12,6 +12,7 @@ use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     swap::Swap,
 };
+use penumbra_fee::{grant::FeeGrantUse, FeeView};
 use penumbra_governance::{DelegatorVote, ProposalSubmit, ProposalWithdraw, ValidatorVote};
 use penumbra_ibc::IbcRelay;
 use penumbra_keys::{FullViewingKey, PayloadKey};
@@ -157,8 +158,9 @@ impl Transaction {
             return Err(anyhow::anyhow!("no memo"));
         }
 
-        // Any output will let us decrypt the memo.
-        if let Some(output) = self.outputs().next() {
+        // The memo key is wrapped to the sender and recipient of every output, including any
+        // outputs to designated viewers of the memo, so try each in turn until one unwraps it.
+        for output in self.outputs() {
             // First decrypt the wrapped memo key on the output.
             let ovk_wrapped_key = output.body.ovk_wrapped_key.clone();
             let shared_secret = Note::decrypt_key(
@@ -174,10 +176,17 @@ impl Transaction {
                 Ok(shared_secret) => {
                     let payload_key =
                         PayloadKey::derive(&shared_secret, &output.body.note_payload.ephemeral_key);
-                    wrapped_memo_key.decrypt_outgoing(&payload_key)?
+                    match wrapped_memo_key.decrypt_outgoing(&payload_key) {
+                        Ok(memo_key) => memo_key,
+                        Err(_) => continue,
+                    }
                 }
-                Err(_) => wrapped_memo_key
-                    .decrypt(output.body.note_payload.ephemeral_key, fvk.incoming())?,
+                Err(_) => match wrapped_memo_key
+                    .decrypt(output.body.note_payload.ephemeral_key, fvk.incoming())
+                {
+                    Ok(memo_key) => memo_key,
+                    Err(_) => continue,
+                },
             };
 
             // Now we can use the memo key to decrypt the memo.
@@ -257,7 +266,10 @@ impl Transaction {
                 | Action::Ics20Withdrawal(_)
                 | Action::CommunityPoolSpend(_)
                 | Action::CommunityPoolOutput(_)
-                | Action::CommunityPoolDeposit(_) => {}
+                | Action::CommunityPoolDeposit(_)
+                | Action::FeeGrant(_)
+                | Action::FeeGrantUse(_)
+                | Action::FeeGrantReclaim(_) => {}
             }
         }
 
@@ -321,12 +333,16 @@ impl Transaction {
                     fmd_clues: detection_data.fmd_clues.clone(),
                 });
 
+        let transaction_parameters = self.transaction_parameters();
+        let fee_view = FeeView(txp.view_value(transaction_parameters.fee.value()));
+
         TransactionView {
             body_view: TransactionBodyView {
                 action_views,
-                transaction_parameters: self.transaction_parameters(),
+                transaction_parameters,
                 detection_data,
                 memo_view,
+                fee_view,
             },
             binding_sig: self.binding_sig,
             anchor: self.anchor,
@@ -481,6 +497,16 @@ impl Transaction {
             .filter_map(|x| x)
     }
 
+    pub fn fee_grant_uses(&self) -> impl Iterator<Item = &FeeGrantUse> {
+        self.actions().filter_map(|action| {
+            if let Action::FeeGrantUse(u) = action {
+                Some(u)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn community_pool_deposits(&self) -> impl Iterator<Item = &CommunityPoolDeposit> {
         self.actions().filter_map(|action| {
             if let Action::CommunityPoolDeposit(d) = action {
@@ -1,15 +1,21 @@
 use anyhow::Context;
 use decaf377_rdsa::{Binding, Signature};
+use penumbra_asset::asset::Cache;
+use penumbra_fee::FeeView;
 use penumbra_keys::AddressView;
 use penumbra_proto::{core::transaction::v1 as pbt, DomainType};
 
 use serde::{Deserialize, Serialize};
 
 pub mod action_view;
+pub mod describe;
+mod perspective_builder;
 mod transaction_perspective;
 
 pub use action_view::ActionView;
+pub use describe::{ActionDescription, DescribeAction, DescribeContext};
 use penumbra_tct as tct;
+pub use perspective_builder::TransactionPerspectiveBuilder;
 pub use transaction_perspective::TransactionPerspective;
 
 use crate::{
@@ -35,6 +41,8 @@ pub struct TransactionBodyView {
     pub transaction_parameters: TransactionParameters,
     pub detection_data: Option<DetectionData>,
     pub memo_view: Option<MemoView>,
+    /// The fee, with the metadata of its asset if the perspective the view was built from had it.
+    pub fee_view: FeeView,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -170,11 +178,20 @@ impl TryFrom<pbt::TransactionBodyView> for TransactionBodyView {
             None => None,
         };
 
-        let transaction_parameters = body_view
+        let transaction_parameters: TransactionParameters = body_view
             .transaction_parameters
             .ok_or_else(|| anyhow::anyhow!("transaction view missing transaction parameters view"))?
             .try_into()?;
 
+        // Views from before the fee view was added don't have one, so view the fee without any
+        // asset metadata.
+        let fee_view = match body_view.fee_view {
+            Some(fee_view) => fee_view.try_into()?,
+            None => transaction_parameters
+                .fee
+                .view_with_cache(&Cache::default()),
+        };
+
         // Iterate through the detection_data vec, and convert each FMD clue.
         let fmd_clues = body_view
             .detection_data
@@ -193,6 +210,7 @@ impl TryFrom<pbt::TransactionBodyView> for TransactionBodyView {
             transaction_parameters,
             detection_data,
             memo_view,
+            fee_view,
         })
     }
 }
@@ -214,6 +232,7 @@ impl From<TransactionBodyView> for pbt::TransactionBodyView {
             transaction_parameters: Some(v.transaction_parameters.into()),
             detection_data: v.detection_data.map(Into::into),
             memo_view: v.memo_view.map(|m| m.into()),
+            fee_view: Some(v.fee_view.into()),
         }
     }
 }
@@ -4,6 +4,7 @@ use penumbra_dex::{
     swap::SwapView,
     swap_claim::SwapClaimView,
 };
+use penumbra_fee::grant::{FeeGrant, FeeGrantReclaim, FeeGrantUse};
 use penumbra_governance::{ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote};
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::{core::transaction::v1 as pbt, DomainType};
@@ -44,6 +45,9 @@ pub enum ActionView {
     CommunityPoolDeposit(CommunityPoolDeposit),
     CommunityPoolSpend(CommunityPoolSpend),
     CommunityPoolOutput(CommunityPoolOutput),
+    FeeGrant(FeeGrant),
+    FeeGrantUse(FeeGrantUse),
+    FeeGrantReclaim(FeeGrantReclaim),
 }
 
 impl DomainType for ActionView {
@@ -86,6 +90,9 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::CommunityPoolDeposit(x) => ActionView::CommunityPoolDeposit(x.try_into()?),
                 AV::CommunityPoolSpend(x) => ActionView::CommunityPoolSpend(x.try_into()?),
                 AV::CommunityPoolOutput(x) => ActionView::CommunityPoolOutput(x.try_into()?),
+                AV::FeeGrant(x) => ActionView::FeeGrant(x.try_into()?),
+                AV::FeeGrantUse(x) => ActionView::FeeGrantUse(x.try_into()?),
+                AV::FeeGrantReclaim(x) => ActionView::FeeGrantReclaim(x.try_into()?),
             },
         )
     }
@@ -117,6 +124,9 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::CommunityPoolDeposit(x) => AV::CommunityPoolDeposit(x.into()),
                 ActionView::CommunityPoolSpend(x) => AV::CommunityPoolSpend(x.into()),
                 ActionView::CommunityPoolOutput(x) => AV::CommunityPoolOutput(x.into()),
+                ActionView::FeeGrant(x) => AV::FeeGrant(x.into()),
+                ActionView::FeeGrantUse(x) => AV::FeeGrantUse(x.into()),
+                ActionView::FeeGrantReclaim(x) => AV::FeeGrantReclaim(x.into()),
             }),
         }
     }
@@ -146,6 +156,9 @@ impl From<ActionView> for Action {
             ActionView::CommunityPoolDeposit(x) => Action::CommunityPoolDeposit(x),
             ActionView::CommunityPoolSpend(x) => Action::CommunityPoolSpend(x),
             ActionView::CommunityPoolOutput(x) => Action::CommunityPoolOutput(x),
+            ActionView::FeeGrant(x) => Action::FeeGrant(x),
+            ActionView::FeeGrantUse(x) => Action::FeeGrantUse(x),
+            ActionView::FeeGrantReclaim(x) => Action::FeeGrantReclaim(x),
         }
     }
 }
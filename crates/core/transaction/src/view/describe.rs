@@ -0,0 +1,529 @@
+//! Human-readable descriptions of the actions in a transaction view.
+//!
+//! Each kind of action describes itself through [`DescribeAction`], as a structured
+//! [`ActionDescription`] rather than as text for one particular output, so that a terminal, a
+//! JSON or an HTML renderer all show the same information. The trait is defined here rather than
+//! in the components, since describing an action can need types from components other than the
+//! action's own, but it's implemented for each action's view type, so a new action only needs a
+//! new implementation.
+
+use penumbra_asset::{
+    asset::{Cache, Id},
+    Value, ValueView,
+};
+use penumbra_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
+use penumbra_dex::{
+    lp::action::{PositionClose, PositionOpen, PositionWithdraw},
+    swap::SwapView,
+    swap_claim::SwapClaimView,
+    TradingPairView,
+};
+use penumbra_fee::{
+    grant::{FeeGrant, FeeGrantReclaim, FeeGrantUse},
+    Fee,
+};
+use penumbra_governance::{
+    DelegatorVoteView, ProposalDepositClaim, ProposalSubmit, ProposalWithdraw, ValidatorVote,
+};
+use penumbra_ibc::IbcRelay;
+use penumbra_keys::AddressView;
+use penumbra_shielded_pool::{Ics20Withdrawal, OutputView, SpendView};
+use penumbra_stake::{validator::Definition, Delegate, Undelegate, UndelegateClaim};
+use serde::Serialize;
+
+use super::ActionView;
+
+/// What's needed to describe an action beyond the action view itself.
+#[derive(Clone, Copy)]
+pub struct DescribeContext<'a> {
+    /// Metadata for formatting plaintext values, such as those moved by Community Pool actions.
+    pub assets: &'a Cache,
+    /// The Community Pool spend proposal that enacted the transaction, if it was enacted by one.
+    pub community_pool_proposal: Option<u64>,
+}
+
+impl<'a> DescribeContext<'a> {
+    pub fn new(assets: &'a Cache) -> Self {
+        Self {
+            assets,
+            community_pool_proposal: None,
+        }
+    }
+
+    /// Formats a value in the best unit of its asset, or in base units of its asset ID if the
+    /// asset is unknown.
+    pub fn value(&self, value: Value) -> String {
+        value.format(self.assets)
+    }
+
+    /// Formats a fee, like [`DescribeContext::value`].
+    pub fn fee(&self, fee: &Fee) -> String {
+        self.value(fee.0)
+    }
+}
+
+/// A description of an action, for display.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ActionDescription {
+    /// A stable identifier of the kind of action, e.g. `spend`, for programs consuming the
+    /// description.
+    pub kind: &'static str,
+    /// The name of the action, e.g. `Spend` or `Submit Governance Proposal #3`.
+    pub title: String,
+    /// The details of the action, which may be empty.
+    pub summary: String,
+}
+
+impl ActionDescription {
+    pub fn new(kind: &'static str, title: impl Into<String>, summary: impl Into<String>) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            summary: summary.into(),
+        }
+    }
+}
+
+/// An action that can describe itself for display.
+pub trait DescribeAction {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription;
+}
+
+impl DescribeAction for ActionView {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        match self {
+            ActionView::Spend(x) => x.describe(context),
+            ActionView::Output(x) => x.describe(context),
+            ActionView::Swap(x) => x.describe(context),
+            ActionView::SwapClaim(x) => x.describe(context),
+            ActionView::DelegatorVote(x) => x.describe(context),
+            ActionView::ValidatorDefinition(x) => x.describe(context),
+            ActionView::IbcRelay(x) => x.describe(context),
+            ActionView::ProposalSubmit(x) => x.describe(context),
+            ActionView::ProposalWithdraw(x) => x.describe(context),
+            ActionView::ValidatorVote(x) => x.describe(context),
+            ActionView::ProposalDepositClaim(x) => x.describe(context),
+            ActionView::PositionOpen(x) => x.describe(context),
+            ActionView::PositionClose(x) => x.describe(context),
+            ActionView::PositionWithdraw(x) => x.describe(context),
+            ActionView::Delegate(x) => x.describe(context),
+            ActionView::Undelegate(x) => x.describe(context),
+            ActionView::UndelegateClaim(x) => x.describe(context),
+            ActionView::Ics20Withdrawal(x) => x.describe(context),
+            ActionView::CommunityPoolDeposit(x) => x.describe(context),
+            ActionView::CommunityPoolSpend(x) => x.describe(context),
+            ActionView::CommunityPoolOutput(x) => x.describe(context),
+            ActionView::FeeGrant(x) => x.describe(context),
+            ActionView::FeeGrantUse(x) => x.describe(context),
+            ActionView::FeeGrantReclaim(x) => x.describe(context),
+        }
+    }
+}
+
+impl DescribeAction for SpendView {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let summary = match self {
+            SpendView::Visible { spend: _, note } => format!(
+                "{} -> {}",
+                note.address.display_short(),
+                note.value.display()
+            ),
+            // The nullifier is taken to be a unique value, for aesthetic reasons.
+            SpendView::Opaque { spend } => opaque_bytes(&spend.body.nullifier.to_bytes()),
+        };
+        ActionDescription::new("spend", "Spend", summary)
+    }
+}
+
+impl DescribeAction for OutputView {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let summary = match self {
+            OutputView::Visible { note, .. } => format!(
+                "{} -> {}",
+                note.value.display(),
+                note.address.display_short()
+            ),
+            // The encrypted note is taken to be a unique value, for aesthetic reasons.
+            OutputView::Opaque { output } => {
+                opaque_bytes(&output.body.note_payload.encrypted_note.0)
+            }
+        };
+        ActionDescription::new("output", "Output", summary)
+    }
+}
+
+impl DescribeAction for SwapView {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = match self {
+            SwapView::Visible {
+                swap: _,
+                swap_plaintext,
+                trading_pair,
+            } => {
+                // Typical swaps are one asset for another, but we can't know that for sure.
+                let pair = &swap_plaintext.trading_pair;
+                let (from_asset, from_value, to_asset) = match (
+                    swap_plaintext.delta_1_i.value(),
+                    swap_plaintext.delta_2_i.value(),
+                ) {
+                    (0, v) if v > 0 => (pair.asset_2(), swap_plaintext.delta_2_i, pair.asset_1()),
+                    (v, 0) if v > 0 => (pair.asset_1(), swap_plaintext.delta_1_i, pair.asset_2()),
+                    // The pathological case (both assets have output values).
+                    _ => (pair.asset_1(), swap_plaintext.delta_1_i, pair.asset_1()),
+                };
+                let from = trading_pair.view_value(Value {
+                    amount: from_value,
+                    asset_id: from_asset,
+                });
+                format!(
+                    "{} for {} and paid claim fee {}",
+                    trading_pair_value(&from),
+                    trading_pair_asset(trading_pair, &to_asset),
+                    context.fee(&swap_plaintext.claim_fee),
+                )
+            }
+            SwapView::Opaque { swap } => format!(
+                "Opaque swap for trading pair: {} <=> {}",
+                asset_id(&swap.body.trading_pair.asset_1()),
+                asset_id(&swap.body.trading_pair.asset_2()),
+            ),
+        };
+        ActionDescription::new("swap", "Swap", summary)
+    }
+}
+
+impl DescribeAction for SwapClaimView {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = match self {
+            SwapClaimView::Visible {
+                swap_claim,
+                output_1,
+                output_2,
+            } => {
+                let claimed_value = match (
+                    output_1.value.amount().value(),
+                    output_2.value.amount().value(),
+                ) {
+                    (0, v) if v > 0 => output_2.value.display(),
+                    (v, 0) if v > 0 => output_1.value.display(),
+                    // The pathological case (both assets have output values).
+                    _ => format!(
+                        "{} and {}",
+                        output_1.value.display(),
+                        output_2.value.display(),
+                    ),
+                };
+                format!(
+                    "Claimed {} with fee {}",
+                    claimed_value,
+                    context.fee(&swap_claim.body.fee),
+                )
+            }
+            // The nullifier is taken to be a unique value, for aesthetic reasons.
+            SwapClaimView::Opaque { swap_claim } => {
+                opaque_bytes(&swap_claim.body.nullifier.to_bytes())
+            }
+        };
+        ActionDescription::new("swap_claim", "Swap Claim", summary)
+    }
+}
+
+impl DescribeAction for Ics20Withdrawal {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let unit = self.denom.best_unit_for(self.amount);
+        let summary = format!(
+            "{}{} via {} to {}",
+            unit.format_value(self.amount),
+            unit,
+            self.source_channel,
+            self.destination_chain_address,
+        );
+        ActionDescription::new("ics20_withdrawal", "Ics20 Withdrawal", summary)
+    }
+}
+
+impl DescribeAction for PositionOpen {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let position = &self.position;
+        let summary = format!(
+            "Reserves: ({} {}, {} {}) Fee: {} ID: {}",
+            position.reserves.r1,
+            asset_id(&position.phi.pair.asset_1()),
+            position.reserves.r2,
+            asset_id(&position.phi.pair.asset_2()),
+            position.phi.component.fee,
+            position.id(),
+        );
+        ActionDescription::new("position_open", "Open Liquidity Position", summary)
+    }
+}
+
+impl DescribeAction for PositionClose {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("position_close", "Close Liquidity Position", "")
+    }
+}
+
+impl DescribeAction for PositionWithdraw {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("position_withdraw", "Withdraw Liquidity Position", "")
+    }
+}
+
+impl DescribeAction for ProposalDepositClaim {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let title = format!("Claim Deposit for Governance Proposal #{}", self.proposal);
+        ActionDescription::new("proposal_deposit_claim", title, "")
+    }
+}
+
+impl DescribeAction for ProposalSubmit {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let title = format!("Submit Governance Proposal #{}", self.proposal.id);
+        ActionDescription::new("proposal_submit", title, "")
+    }
+}
+
+impl DescribeAction for ProposalWithdraw {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        let title = format!("Withdraw Governance Proposal #{}", self.proposal);
+        ActionDescription::new("proposal_withdraw", title, "")
+    }
+}
+
+impl DescribeAction for IbcRelay {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("ibc_relay", "IBC Relay", "")
+    }
+}
+
+impl DescribeAction for DelegatorVoteView {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("delegator_vote", "Delegator Vote", "")
+    }
+}
+
+impl DescribeAction for Definition {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("validator_definition", "Upload Validator Definition", "")
+    }
+}
+
+impl DescribeAction for ValidatorVote {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("validator_vote", "Validator Vote", "")
+    }
+}
+
+impl DescribeAction for CommunityPoolDeposit {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new(
+            "community_pool_deposit",
+            "Community Pool Deposit",
+            context.value(self.value),
+        )
+    }
+}
+
+impl DescribeAction for CommunityPoolSpend {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = match context.community_pool_proposal {
+            Some(proposal) => format!(
+                "{} for Governance Proposal #{}",
+                context.value(self.value),
+                proposal
+            ),
+            None => context.value(self.value),
+        };
+        ActionDescription::new("community_pool_spend", "Community Pool Spend", summary)
+    }
+}
+
+impl DescribeAction for CommunityPoolOutput {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = format!(
+            "{} -> {}",
+            context.value(self.value),
+            AddressView::Opaque {
+                address: self.address
+            }
+            .display_short(),
+        );
+        ActionDescription::new("community_pool_output", "Community Pool Output", summary)
+    }
+}
+
+impl DescribeAction for FeeGrant {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = format!(
+            "{} until height {} ID: {}",
+            context.fee(&self.allowance),
+            self.expiry_height,
+            self.id(),
+        );
+        ActionDescription::new("fee_grant", "Fee Grant", summary)
+    }
+}
+
+impl DescribeAction for FeeGrantUse {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = format!(
+            "{} from grant {}",
+            context.fee(&self.body.amount),
+            self.body.grant_id,
+        );
+        ActionDescription::new("fee_grant_use", "Fee Grant Use", summary)
+    }
+}
+
+impl DescribeAction for FeeGrantReclaim {
+    fn describe(&self, context: &DescribeContext) -> ActionDescription {
+        let summary = format!(
+            "{} from grant {}",
+            context.fee(&self.body.amount),
+            self.body.grant_id,
+        );
+        ActionDescription::new("fee_grant_reclaim", "Fee Grant Reclaim", summary)
+    }
+}
+
+impl DescribeAction for Delegate {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("delegate", "Delegation", "")
+    }
+}
+
+impl DescribeAction for Undelegate {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("undelegate", "Undelegation", "")
+    }
+}
+
+impl DescribeAction for UndelegateClaim {
+    fn describe(&self, _context: &DescribeContext) -> ActionDescription {
+        ActionDescription::new("undelegate_claim", "Undelegation Claim", "")
+    }
+}
+
+/// Formats a value of one of the assets of a trading pair, like `100gm`, or like
+/// `100 passet1abc...xyz` if the asset is unknown.
+fn trading_pair_value(value: &ValueView) -> String {
+    match value {
+        ValueView::KnownAssetId { .. } => value.display(),
+        ValueView::UnknownAssetId {
+            amount,
+            asset_id: id,
+        } => {
+            format!("{} {}", amount, asset_id(id))
+        }
+    }
+}
+
+/// Formats one of the assets of a trading pair, like `gm`, or abbreviated like
+/// `passet1abc...xyz` if the asset is unknown.
+fn trading_pair_asset(trading_pair: &TradingPairView, id: &Id) -> String {
+    match trading_pair.metadata(id) {
+        Some(metadata) => metadata.default_unit().to_string(),
+        None => asset_id(id),
+    }
+}
+
+/// Formats an asset ID abbreviated, like `passet1abc...xyz`.
+pub fn asset_id(asset_id: &Id) -> String {
+    let input = &asset_id.to_string();
+    let truncated = &input[0..10]; //passet1
+    let ellipsis = "...";
+    let end = &input[(input.len() - 3)..];
+    format!("{}{}{}", truncated, ellipsis, end)
+}
+
+/// Creates a pretty placeholder for encrypted information, like a memo we can't read.
+///
+/// The placeholder is derived from the bytes, so that different ciphertexts look different.
+pub fn opaque_bytes(bytes: &[u8]) -> String {
+    if bytes.len() < 8 {
+        return String::new();
+    }
+
+    // to be more general, perhaps this should be configurable
+    // an opaque address needs less space than an opaque memo, etc
+    let max_bytes = 32;
+    let rem = &bytes[..bytes.len().min(max_bytes)];
+
+    hex::encode_upper(rem)
+        .chars()
+        .map(|c| match c {
+            '0' => "\u{2595}",
+            '1' => "\u{2581}",
+            '2' => "\u{2582}",
+            '3' => "\u{2583}",
+            '4' => "\u{2584}",
+            '5' => "\u{2585}",
+            '6' => "\u{2586}",
+            '7' => "\u{2587}",
+            '8' => "\u{2588}",
+            '9' => "\u{2589}",
+            'A' => "\u{259A}",
+            'B' => "\u{259B}",
+            'C' => "\u{259C}",
+            'D' => "\u{259D}",
+            'E' => "\u{259E}",
+            'F' => "\u{259F}",
+            _ => "",
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opaque_bytes_are_rendered_as_blocks() {
+        assert_eq!(opaque_bytes(&[0u8; 7]), "");
+        assert_eq!(opaque_bytes(&[0x01; 8]).chars().count(), 16);
+        assert_eq!(opaque_bytes(&[0xAB; 64]).chars().count(), 64);
+    }
+
+    #[test]
+    fn swapped_assets_are_named_by_their_denoms() {
+        let assets = Cache::with_known_assets();
+        let gm = assets.get_unit("gm").expect("gm is known");
+        let gn = assets.get_unit("gn").expect("gn is known");
+        let pair = penumbra_dex::TradingPair::new(gm.id(), gn.id());
+
+        let known = pair.view_with_cache(&assets);
+        assert_eq!(
+            trading_pair_value(&known.view_value(gm.value(100u64.into()))),
+            "100gm"
+        );
+        assert_eq!(trading_pair_asset(&known, &gn.id()), "gn");
+
+        // Without metadata, the asset IDs are abbreviated.
+        let unknown = TradingPairView::from(pair);
+        assert_eq!(
+            trading_pair_value(&unknown.view_value(gm.value(1u64.into()))),
+            format!("{} {}", gm.value(1u64.into()).amount, asset_id(&gm.id()))
+        );
+        assert_eq!(trading_pair_asset(&unknown, &gn.id()), asset_id(&gn.id()));
+    }
+
+    #[test]
+    fn community_pool_spends_name_their_proposal() {
+        let assets = Cache::with_known_assets();
+        let spend = CommunityPoolSpend {
+            value: Value {
+                amount: 1_000_000u64.into(),
+                asset_id: *penumbra_asset::STAKING_TOKEN_ASSET_ID,
+            },
+        };
+        let mut context = DescribeContext::new(&assets);
+        let plain = spend.describe(&context);
+        assert_eq!(plain.kind, "community_pool_spend");
+        assert_eq!(plain.summary, "1penumbra");
+
+        context.community_pool_proposal = Some(7);
+        let described = ActionView::CommunityPoolSpend(spend).describe(&context);
+        assert_eq!(described.summary, "1penumbra for Governance Proposal #7");
+    }
+}
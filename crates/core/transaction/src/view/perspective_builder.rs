@@ -0,0 +1,237 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use penumbra_asset::asset;
+use penumbra_dex::{
+    swap::{SwapCiphertext, SwapView},
+    swap_claim::SwapClaimView,
+};
+use penumbra_keys::{
+    keys::{IncomingViewingKey, OutgoingViewingKey},
+    Address, AddressView, FullViewingKey, PayloadKey,
+};
+use penumbra_sct::Nullifier;
+use penumbra_shielded_pool::{Note, Output, OutputView, SpendView};
+use penumbra_tct::StateCommitment;
+
+use crate::{view::action_view::ActionView, MemoView, Transaction, TransactionPerspective};
+
+/// Builds a [`TransactionPerspective`] on a transaction from whatever key material is at hand.
+///
+/// Unlike the view service, which views transactions with the full viewing key of a single
+/// wallet, the builder accepts any combination of keys, so that e.g. an auditor holding only the
+/// incoming viewing keys of several wallets, plus a few payload keys disclosed to them, can
+/// decrypt as much of the transaction as that material allows. Each piece of key material is
+/// only used for the parts of the transaction it actually decrypts, so it's fine to provide keys
+/// that turn out to be unrelated to the transaction.
+///
+/// Note that fuzzy message detection keys aren't accepted: a detection key can only flag clues
+/// as possibly matching, which doesn't decrypt any part of the transaction. The incoming viewing
+/// key the detection key was derived from should be provided instead.
+#[derive(Clone, Debug)]
+pub struct TransactionPerspectiveBuilder<'tx> {
+    transaction: &'tx Transaction,
+    full_viewing_keys: Vec<FullViewingKey>,
+    incoming_viewing_keys: Vec<IncomingViewingKey>,
+    outgoing_viewing_keys: Vec<OutgoingViewingKey>,
+    payload_keys: BTreeMap<StateCommitment, PayloadKey>,
+    spend_nullifiers: BTreeMap<Nullifier, Note>,
+    advice_notes: BTreeMap<StateCommitment, Note>,
+    denoms: asset::Cache,
+}
+
+impl<'tx> TransactionPerspectiveBuilder<'tx> {
+    /// Starts building a perspective on `transaction`, with no key material.
+    pub fn new(transaction: &'tx Transaction) -> Self {
+        Self {
+            transaction,
+            full_viewing_keys: Vec::new(),
+            incoming_viewing_keys: Vec::new(),
+            outgoing_viewing_keys: Vec::new(),
+            payload_keys: BTreeMap::new(),
+            spend_nullifiers: BTreeMap::new(),
+            advice_notes: BTreeMap::new(),
+            denoms: asset::Cache::default(),
+        }
+    }
+
+    /// Views the transaction with a full viewing key.
+    ///
+    /// Besides its incoming and outgoing viewing keys, the full viewing key decodes the addresses
+    /// it controls into [`AddressView`]s.
+    pub fn with_full_viewing_key(mut self, fvk: FullViewingKey) -> Self {
+        self.full_viewing_keys.push(fvk);
+        self
+    }
+
+    /// Views the transaction with an incoming viewing key, decrypting outputs sent to it.
+    pub fn with_incoming_viewing_key(mut self, ivk: IncomingViewingKey) -> Self {
+        self.incoming_viewing_keys.push(ivk);
+        self
+    }
+
+    /// Views the transaction with an outgoing viewing key, decrypting outputs and swaps sent
+    /// from it.
+    pub fn with_outgoing_viewing_key(mut self, ovk: OutgoingViewingKey) -> Self {
+        self.outgoing_viewing_keys.push(ovk);
+        self
+    }
+
+    /// Adds the payload key for the output or swap with the given commitment.
+    pub fn with_payload_key(
+        mut self,
+        commitment: StateCommitment,
+        payload_key: PayloadKey,
+    ) -> Self {
+        self.payload_keys.insert(commitment, payload_key);
+        self
+    }
+
+    /// Adds the note spent by revealing `nullifier`, decrypting spends of that note.
+    pub fn with_spent_note(mut self, nullifier: Nullifier, note: Note) -> Self {
+        self.spend_nullifiers.insert(nullifier, note);
+        self
+    }
+
+    /// Adds the opening of a note commitment that's referred to, but not included, in the
+    /// transaction, like the outputs of a swap claim.
+    pub fn with_advice_note(mut self, note: Note) -> Self {
+        self.advice_notes.insert(note.commit(), note);
+        self
+    }
+
+    /// Adds asset metadata, used to display the values in the transaction.
+    pub fn with_denoms(mut self, denoms: impl IntoIterator<Item = asset::Metadata>) -> Self {
+        self.denoms.extend(denoms);
+        self
+    }
+
+    /// Builds the perspective, decrypting everything the provided key material allows.
+    pub fn build(self) -> TransactionPerspective {
+        let mut txp = TransactionPerspective {
+            payload_keys: self.payload_keys.clone(),
+            spend_nullifiers: self.spend_nullifiers.clone(),
+            advice_notes: self.advice_notes.clone(),
+            denoms: self.denoms.clone(),
+            transaction_id: self.transaction.id(),
+            ..Default::default()
+        };
+
+        for output in self.transaction.outputs() {
+            let commitment = output.body.note_payload.note_commitment;
+            if txp.payload_keys.contains_key(&commitment) {
+                continue;
+            }
+            if let Some(payload_key) =
+                self.output_payload_key_candidates(output)
+                    .find(|payload_key| {
+                        Note::decrypt_with_payload_key(
+                            &output.body.note_payload.encrypted_note,
+                            payload_key,
+                            &output.body.note_payload.ephemeral_key,
+                        )
+                        .is_ok()
+                    })
+            {
+                txp.payload_keys.insert(commitment, payload_key);
+            }
+        }
+
+        for swap in self.transaction.swaps() {
+            let commitment = swap.body.payload.commitment;
+            if txp.payload_keys.contains_key(&commitment) {
+                continue;
+            }
+            if let Some(payload_key) = self
+                .outgoing_viewing_keys()
+                .map(|ovk| PayloadKey::derive_swap(ovk, commitment))
+                .find(|payload_key| {
+                    SwapCiphertext::decrypt_with_payload_key(
+                        &swap.body.payload.encrypted_swap,
+                        payload_key,
+                        commitment,
+                    )
+                    .is_ok()
+                })
+            {
+                txp.payload_keys.insert(commitment, payload_key);
+            }
+        }
+
+        // With the notes decrypted, decode the addresses they're sent to, if any full viewing key
+        // controls them.
+        let view = self.transaction.view_from_perspective(&txp);
+        let mut addresses = BTreeSet::new();
+        for action_view in view.action_views() {
+            match action_view {
+                ActionView::Spend(SpendView::Visible { note, .. })
+                | ActionView::Output(OutputView::Visible { note, .. }) => {
+                    addresses.insert(note.address());
+                }
+                ActionView::Swap(SwapView::Visible { swap_plaintext, .. }) => {
+                    addresses.insert(swap_plaintext.claim_address);
+                }
+                ActionView::SwapClaim(SwapClaimView::Visible { output_1, .. }) => {
+                    addresses.insert(output_1.address());
+                }
+                _ => {}
+            }
+        }
+        if let Some(MemoView::Visible { plaintext, .. }) = &view.body_view.memo_view {
+            addresses.insert(plaintext.return_address.address());
+        }
+        txp.address_views = addresses
+            .into_iter()
+            .filter_map(|address| self.view_address(address))
+            .collect();
+
+        txp
+    }
+
+    /// Returns the payload keys that might decrypt `output`, from each of the viewing keys.
+    fn output_payload_key_candidates<'a>(
+        &'a self,
+        output: &'a Output,
+    ) -> impl Iterator<Item = PayloadKey> + 'a {
+        let body = &output.body;
+        let epk = &body.note_payload.ephemeral_key;
+        let outgoing = self.outgoing_viewing_keys().filter_map(move |ovk| {
+            Note::decrypt_key(
+                body.ovk_wrapped_key.clone(),
+                body.note_payload.note_commitment,
+                body.balance_commitment,
+                ovk,
+                epk,
+            )
+            .ok()
+            .map(|shared_secret| PayloadKey::derive(&shared_secret, epk))
+        });
+        let incoming = self.incoming_viewing_keys().filter_map(move |ivk| {
+            ivk.key_agreement_with(epk)
+                .ok()
+                .map(|shared_secret| PayloadKey::derive(&shared_secret, epk))
+        });
+        outgoing.chain(incoming)
+    }
+
+    fn incoming_viewing_keys(&self) -> impl Iterator<Item = &IncomingViewingKey> {
+        self.full_viewing_keys
+            .iter()
+            .map(FullViewingKey::incoming)
+            .chain(&self.incoming_viewing_keys)
+    }
+
+    fn outgoing_viewing_keys(&self) -> impl Iterator<Item = &OutgoingViewingKey> {
+        self.full_viewing_keys
+            .iter()
+            .map(FullViewingKey::outgoing)
+            .chain(&self.outgoing_viewing_keys)
+    }
+
+    /// Decodes `address` with the first full viewing key that controls it.
+    fn view_address(&self, address: Address) -> Option<AddressView> {
+        self.full_viewing_keys
+            .iter()
+            .find(|fvk| fvk.incoming().views_address(&address))
+            .map(|fvk| fvk.view_address(address))
+    }
+}
@@ -34,6 +34,10 @@ pub use traits::{
 /// To address this, the `download-proving-keys` feature will download them from
 /// the network at build time. All proving keys are checked against hardcoded hashes
 /// to ensure they have not been tampered with.
+///
+/// Neither feature is needed on targets without a filesystem, like
+/// `wasm32-unknown-unknown`, where the keys can be fetched at runtime instead, and
+/// loaded with `try_load`.
 #[derive(Debug, Default)]
 pub struct LazyProvingKey {
     pk_id: &'static str,
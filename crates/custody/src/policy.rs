@@ -8,6 +8,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{AuthorizeRequest, PreAuthorization};
 
+mod trading_bot;
+
+pub use trading_bot::TradingBotPolicy;
+
 /// A trait for checking whether a transaction plan is allowed by a policy.
 pub trait Policy {
     /// Checks whether the proposed transaction plan is allowed by this policy.
@@ -36,6 +40,12 @@ pub enum AuthPolicy {
     OnlyIbcRelay,
     /// Require specific pre-authorizations for submitted [`TransactionPlan`](penumbra_transaction::TransactionPlan)s.
     PreAuthorization(PreAuthorizationPolicy),
+    /// Intended for trading bots, only allows swaps and positions in some
+    /// trading pairs, and limits how much can be spent on them in any hour.
+    ///
+    /// This policy should be combined with an `AllowList` to prevent sending
+    /// funds outside of the bot's account, and listed last.
+    TradingBot(TradingBotPolicy),
 }
 
 /// A set of pre-authorization policies.
@@ -153,7 +163,8 @@ impl Policy for AuthPolicy {
                     match action {
                         ActionPlan::Spend { .. }
                         | ActionPlan::Output { .. }
-                        | ActionPlan::IbcAction { .. } => {}
+                        | ActionPlan::IbcAction { .. }
+                        | ActionPlan::FeeGrantUse { .. } => {}
                         _ => {
                             anyhow::bail!("action {:?} not allowed by OnlyRelay policy", action);
                         }
@@ -162,6 +173,7 @@ impl Policy for AuthPolicy {
                 Ok(())
             }
             AuthPolicy::PreAuthorization(policy) => policy.check(request),
+            AuthPolicy::TradingBot(policy) => policy.check(request),
         }
     }
 }
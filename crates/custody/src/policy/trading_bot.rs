@@ -0,0 +1,323 @@
+//! A spend authorization policy for automated traders.
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use penumbra_asset::{asset, Value};
+use penumbra_dex::TradingPair;
+use penumbra_num::Amount;
+use penumbra_transaction::{plan::ActionPlan, TransactionPlan};
+use serde::{Deserialize, Serialize};
+
+use super::Policy;
+use crate::AuthorizeRequest;
+
+/// The window over which spending is limited.
+const WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// Intended for trading bots, only allows trading in some pairs, and limits how much the wallet
+/// can spend on trading in any hour.
+///
+/// Plans may only spend and create notes, swap and claim swaps, and open, close and withdraw
+/// positions, so governance, staking, validator and IBC actions are always rejected. Swaps and
+/// opened positions must trade one of the `allowed_pairs`.
+///
+/// The value a plan commits to swaps and positions, and pays in fees, counts against the
+/// `hourly_spend_limits` for an hour from when the plan is approved. Outputs don't count, so
+/// this policy should be combined with a `DestinationAllowList` to prevent sending funds
+/// outside of the bot's account. Spending is counted as soon as this policy approves a plan,
+/// even if another policy then rejects it, so this policy should be listed last.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TradingBotPolicy {
+    /// The most of each asset the wallet may spend in any hour, like `1000penumbra`.
+    ///
+    /// Assets without a limit can't be spent at all.
+    #[serde(with = "values_as_strings")]
+    pub hourly_spend_limits: Vec<Value>,
+    /// The pairs swaps and positions may trade, like `penumbra:gm`.
+    #[serde(with = "trading_pairs_as_strings")]
+    pub allowed_pairs: Vec<TradingPair>,
+    /// The spending approved in the last hour.
+    #[serde(skip)]
+    spent: SpendHistory,
+}
+
+impl TradingBotPolicy {
+    pub fn new(hourly_spend_limits: Vec<Value>, allowed_pairs: Vec<TradingPair>) -> Self {
+        Self {
+            hourly_spend_limits,
+            allowed_pairs,
+            spent: Default::default(),
+        }
+    }
+
+    /// Checks the plan as if it were requested at `now`.
+    fn check_at(&self, plan: &TransactionPlan, now: Instant) -> anyhow::Result<()> {
+        for action in &plan.actions {
+            match action {
+                ActionPlan::Spend(_)
+                | ActionPlan::Output(_)
+                | ActionPlan::SwapClaim(_)
+                | ActionPlan::PositionClose(_)
+                | ActionPlan::PositionWithdraw(_)
+                | ActionPlan::FeeGrantUse(_) => {}
+                ActionPlan::Swap(swap) => {
+                    self.check_pair(swap.swap_plaintext.trading_pair)?;
+                }
+                ActionPlan::PositionOpen(open) => {
+                    self.check_pair(open.position.phi.pair)?;
+                }
+                _ => {
+                    anyhow::bail!("action {:?} not allowed by TradingBot policy", action);
+                }
+            }
+        }
+
+        let spending = spending(plan)?;
+        let mut spent = self
+            .spent
+            .0
+            .lock()
+            .expect("spend history lock is not poisoned");
+        while let Some((approved_at, _)) = spent.front() {
+            if now.saturating_duration_since(*approved_at) < WINDOW {
+                break;
+            }
+            spent.pop_front();
+        }
+        for (asset_id, amount) in &spending {
+            let limit = self
+                .hourly_spend_limits
+                .iter()
+                .find(|limit| limit.asset_id == *asset_id)
+                .map(|limit| limit.amount)
+                .ok_or_else(|| {
+                    anyhow::anyhow!("plan spends asset {asset_id}, which has no limit")
+                })?;
+            let spent_in_window = spent
+                .iter()
+                .filter_map(|(_, spending)| spending.get(asset_id))
+                .try_fold(*amount, |total, amount| total.checked_add(amount))
+                .ok_or_else(|| anyhow::anyhow!("spending of asset {asset_id} overflows"))?;
+            if spent_in_window > limit {
+                anyhow::bail!(
+                    "plan would spend {spent_in_window} of asset {asset_id} in the last hour, over the limit of {limit}"
+                );
+            }
+        }
+        spent.push_back((now, spending));
+
+        Ok(())
+    }
+
+    fn check_pair(&self, pair: TradingPair) -> anyhow::Result<()> {
+        if !self.allowed_pairs.contains(&pair) {
+            anyhow::bail!("trading pair {} not allowed by TradingBot policy", pair);
+        }
+        Ok(())
+    }
+}
+
+impl Policy for TradingBotPolicy {
+    fn check(&self, request: &AuthorizeRequest) -> anyhow::Result<()> {
+        self.check_at(&request.plan, Instant::now())
+    }
+}
+
+/// Sums the value a plan commits to swaps and positions, and pays in fees.
+fn spending(plan: &TransactionPlan) -> anyhow::Result<BTreeMap<asset::Id, Amount>> {
+    let mut values = vec![plan.transaction_parameters.fee.0];
+    for swap in plan.swap_plans() {
+        let plaintext = &swap.swap_plaintext;
+        values.extend([
+            plaintext.delta_1_value(),
+            plaintext.delta_2_value(),
+            plaintext.claim_fee.0,
+        ]);
+    }
+    for open in plan.position_openings() {
+        let pair = open.position.phi.pair;
+        values.extend([
+            Value {
+                amount: open.position.reserves.r1,
+                asset_id: pair.asset_1(),
+            },
+            Value {
+                amount: open.position.reserves.r2,
+                asset_id: pair.asset_2(),
+            },
+        ]);
+    }
+
+    let mut spending = BTreeMap::<asset::Id, Amount>::new();
+    for value in values
+        .into_iter()
+        .filter(|value| value.amount != Amount::zero())
+    {
+        let total = spending.entry(value.asset_id).or_default();
+        *total = total
+            .checked_add(&value.amount)
+            .ok_or_else(|| anyhow::anyhow!("plan's spending of {} overflows", value.asset_id))?;
+    }
+    Ok(spending)
+}
+
+/// The spending approved by a [`TradingBotPolicy`], oldest first.
+///
+/// This is shared between clones of the policy, and isn't part of its configuration, so it's
+/// ignored when comparing policies.
+#[derive(Clone, Debug, Default)]
+struct SpendHistory(Arc<Mutex<VecDeque<(Instant, BTreeMap<asset::Id, Amount>)>>>);
+
+impl PartialEq for SpendHistory {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for SpendHistory {}
+
+/// A serde helper to write values as strings, like `1000penumbra`.
+///
+/// Values are written back in base units of their asset ID, which parse to the same value.
+mod values_as_strings {
+    use penumbra_asset::Value;
+
+    pub fn serialize<S: serde::Serializer>(
+        values: &[Value],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::Serialize;
+        values
+            .iter()
+            .map(|value| format!("{}{}", value.amount, value.asset_id))
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Value>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|value| Value::parse(value).map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
+
+/// A serde helper to write trading pairs as strings, like `penumbra:gm`.
+///
+/// Pairs are written back as asset IDs, like `passet1...:passet1...`, which parse to the same
+/// pair.
+mod trading_pairs_as_strings {
+    use std::str::FromStr;
+
+    use penumbra_asset::asset::{self, REGISTRY};
+    use penumbra_dex::TradingPair;
+
+    pub fn serialize<S: serde::Serializer>(
+        pairs: &[TradingPair],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::Serialize;
+        pairs
+            .iter()
+            .map(|pair| pair.to_string())
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<TradingPair>, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::Deserialize;
+        let parse_asset = |s: &str| -> anyhow::Result<asset::Id> {
+            if s.starts_with("passet1") {
+                asset::Id::from_str(s)
+            } else {
+                Ok(REGISTRY.parse_unit(s).id())
+            }
+        };
+        Vec::<String>::deserialize(deserializer)?
+            .iter()
+            .map(|pair| {
+                let (asset_1, asset_2) = pair
+                    .split_once(':')
+                    .ok_or_else(|| anyhow::anyhow!("invalid trading pair {pair}"))?;
+                Ok(TradingPair::new(
+                    parse_asset(asset_1)?,
+                    parse_asset(asset_2)?,
+                ))
+            })
+            .collect::<anyhow::Result<_>>()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_fee::Fee;
+    use penumbra_governance::ProposalWithdraw;
+    use penumbra_transaction::TransactionParameters;
+
+    use super::*;
+
+    fn plan_paying_fee(fee: &str) -> TransactionPlan {
+        TransactionPlan {
+            transaction_parameters: TransactionParameters {
+                fee: Fee(Value::parse(fee).unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn limits_hourly_spending() {
+        let policy = TradingBotPolicy::new(vec![Value::parse("10upenumbra").unwrap()], vec![]);
+        let start = Instant::now();
+
+        policy
+            .check_at(&plan_paying_fee("6upenumbra"), start)
+            .unwrap();
+        policy
+            .check_at(
+                &plan_paying_fee("5upenumbra"),
+                start + Duration::from_secs(60),
+            )
+            .expect_err("spending 11upenumbra in an hour is over the limit");
+        policy
+            .check_at(
+                &plan_paying_fee("4upenumbra"),
+                start + Duration::from_secs(60),
+            )
+            .unwrap();
+        // After an hour, the first plan's spending no longer counts.
+        policy
+            .check_at(&plan_paying_fee("6upenumbra"), start + WINDOW)
+            .unwrap();
+        policy
+            .check_at(&plan_paying_fee("1ugm"), start + WINDOW)
+            .expect_err("assets without a limit can't be spent");
+    }
+
+    #[test]
+    fn rejects_governance_actions() {
+        let policy = TradingBotPolicy::new(vec![Value::parse("10upenumbra").unwrap()], vec![]);
+        let mut plan = plan_paying_fee("1upenumbra");
+        plan.actions
+            .push(ActionPlan::ProposalWithdraw(ProposalWithdraw {
+                proposal: 1,
+                reason: "test".to_string(),
+            }));
+        policy
+            .check_at(&plan, Instant::now())
+            .expect_err("governance actions aren't allowed");
+    }
+}
@@ -40,7 +40,7 @@ fn is_default<T: Default + Eq>(value: &T) -> bool {
 mod tests {
     use penumbra_keys::keys::{Bip44Path, SeedPhrase};
 
-    use crate::policy::PreAuthorizationPolicy;
+    use crate::policy::{PreAuthorizationPolicy, TradingBotPolicy};
 
     use super::*;
 
@@ -66,6 +66,10 @@ mod tests {
                 required_signatures: 1,
                 allowed_signers: vec![pvk],
             }),
+            AuthPolicy::TradingBot(TradingBotPolicy::new(
+                vec!["1000penumbra".parse().unwrap()],
+                vec!["penumbra:gm".parse().unwrap()],
+            )),
         ];
 
         let example = Config {
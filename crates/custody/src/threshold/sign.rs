@@ -289,7 +289,10 @@ impl DomainType for FollowerRound2 {
 ///
 /// A plan can require more than one signature, hence the need for this method.
 fn required_signatures(plan: &TransactionPlan) -> usize {
-    plan.spend_plans().count() + plan.delegator_vote_plans().count()
+    plan.spend_plans().count()
+        + plan.delegator_vote_plans().count()
+        + plan.fee_grant_use_plans().count()
+        + plan.fee_grant_reclaim_plans().count()
 }
 
 pub struct CoordinatorState1 {
@@ -392,6 +395,8 @@ pub fn coordinator_round3(
         .spend_plans()
         .map(|x| x.randomizer)
         .chain(state.plan.delegator_vote_plans().map(|x| x.randomizer))
+        .chain(state.plan.fee_grant_use_plans().map(|x| x.randomizer))
+        .chain(state.plan.fee_grant_reclaim_plans().map(|x| x.randomizer))
         .zip(share_maps.iter())
         .zip(state.signing_packages.iter())
         .map(|((randomizer, share_map), signing_package)| {
@@ -403,11 +408,17 @@ pub fn coordinator_round3(
             )
         })
         .collect::<Result<Vec<_>, _>>()?;
-    let delegator_vote_auths = spend_auths.split_off(state.plan.spend_plans().count());
+    let mut delegator_vote_auths = spend_auths.split_off(state.plan.spend_plans().count());
+    let mut fee_grant_auths =
+        delegator_vote_auths.split_off(state.plan.delegator_vote_plans().count());
+    let fee_grant_reclaim_auths =
+        fee_grant_auths.split_off(state.plan.fee_grant_use_plans().count());
     Ok(AuthorizationData {
         effect_hash: Some(state.effect_hash),
         spend_auths,
         delegator_vote_auths,
+        fee_grant_auths,
+        fee_grant_reclaim_auths,
     })
 }
 
@@ -443,6 +454,8 @@ pub fn follower_round2(
         .spend_plans()
         .map(|x| x.randomizer)
         .chain(state.plan.delegator_vote_plans().map(|x| x.randomizer))
+        .chain(state.plan.fee_grant_use_plans().map(|x| x.randomizer))
+        .chain(state.plan.fee_grant_reclaim_plans().map(|x| x.randomizer))
         .zip(signing_packages)
         .zip(state.nonces.into_iter())
         .map(|((randomizer, signing_package), signer_nonces)| {
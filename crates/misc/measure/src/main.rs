@@ -5,11 +5,11 @@ use clap::Parser;
 use tracing::Instrument;
 use tracing_subscriber::EnvFilter;
 
-use penumbra_compact_block::CompactBlock;
+use penumbra_compact_block::{encoding::Decoder, CompactBlock};
 use penumbra_proto::{
     penumbra::core::component::compact_block::v1::{
         query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
-        CompactBlockRangeRequest,
+        CompactBlockEncoding, CompactBlockRangeRequest,
     },
     penumbra::util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, GetStatusRequest,
@@ -58,6 +58,9 @@ pub enum Command {
         /// If set, skip downloading the genesis compact block.
         #[clap(long)]
         skip_genesis: bool,
+        /// If set, request delta-encoded, zstd-compressed compact blocks.
+        #[clap(long)]
+        compressed: bool,
     },
     /// Load-test `pd` by holding open many connections subscribing to compact block updates.
     OpenConnections {
@@ -108,6 +111,7 @@ impl Opt {
                                         start_height,
                                         end_height,
                                         keep_alive: true,
+                                        ..Default::default()
                                     },
                                 ))
                                 .await
@@ -152,6 +156,7 @@ impl Opt {
                                 start_height,
                                 end_height,
                                 keep_alive: true,
+                                ..Default::default()
                             }))
                             .await
                             .unwrap()
@@ -177,7 +182,10 @@ impl Opt {
                     res?;
                 }
             }
-            Command::StreamBlocks { skip_genesis } => {
+            Command::StreamBlocks {
+                skip_genesis,
+                compressed,
+            } => {
                 let channel = Channel::from_shared(self.node.to_string())?
                     .connect()
                     .await?;
@@ -192,6 +200,11 @@ impl Opt {
                         start_height,
                         end_height,
                         keep_alive: false,
+                        encoding: if compressed {
+                            CompactBlockEncoding::ZstdDelta as i32
+                        } else {
+                            CompactBlockEncoding::Unspecified as i32
+                        },
                     }))
                     .await?
                     .into_inner();
@@ -213,10 +226,11 @@ impl Opt {
 
                 use penumbra_compact_block::StatePayload;
 
+                let mut decoder = Decoder::new();
                 while let Some(block_rsp) = stream.message().await? {
                     cb_count += 1;
                     bytes += block_rsp.encoded_len();
-                    let block: CompactBlock = block_rsp.try_into()?;
+                    let block: CompactBlock = decoder.decode(block_rsp)?;
                     nf_count += block.nullifiers.len();
                     sp_rolled_up_count += block
                         .state_payloads
@@ -0,0 +1,140 @@
+//! An example of planning Penumbra transactions on `wasm32-unknown-unknown`.
+//!
+//! This exports the [`Planner`] to JavaScript through `wasm-bindgen`. Since there is no view
+//! service to query in the browser, the caller fetches the notes the planner asks for, by
+//! sending the requests returned by [`notes_requests`] to a view service, and passes them to
+//! [`plan`]. Requests, notes and plans are passed as JSON.
+
+#![deny(clippy::unwrap_used)]
+
+use anyhow::Context;
+use penumbra_asset::Value;
+use penumbra_fee::GasPrices;
+use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
+use penumbra_proof_params::{
+    CONVERT_PROOF_PROVING_KEY, DELEGATOR_VOTE_PROOF_PROVING_KEY,
+    NULLIFIER_DERIVATION_PROOF_PROVING_KEY, OUTPUT_PROOF_PROVING_KEY, SPEND_PROOF_PROVING_KEY,
+    SWAPCLAIM_PROOF_PROVING_KEY, SWAP_PROOF_PROVING_KEY,
+};
+use penumbra_shielded_pool::fmd;
+use penumbra_view::{Planner, SpendableNoteRecord};
+use rand_core::OsRng;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+/// A transaction sending values to some addresses.
+#[derive(Debug, Deserialize)]
+pub struct SendRequest {
+    /// The full viewing key of the sending wallet.
+    pub full_viewing_key: String,
+    /// The account to send from, and return change to.
+    #[serde(default)]
+    pub source: u32,
+    pub chain_id: String,
+    pub fmd_parameters: fmd::Parameters,
+    pub gas_prices: GasPrices,
+    pub outputs: Vec<SendOutput>,
+}
+
+/// One output of a [`SendRequest`].
+#[derive(Debug, Deserialize)]
+pub struct SendOutput {
+    pub address: String,
+    /// The value to send, like `1.5penumbra`.
+    pub value: String,
+}
+
+impl SendRequest {
+    fn parse(json: &str) -> anyhow::Result<Self> {
+        serde_json::from_str(json).context("invalid send request")
+    }
+
+    fn source(&self) -> AddressIndex {
+        AddressIndex::new(self.source)
+    }
+
+    /// Builds a planner for the request, before any notes are spent.
+    fn planner(&self) -> anyhow::Result<Planner<OsRng>> {
+        let mut planner = Planner::new(OsRng);
+        planner.set_gas_prices(self.gas_prices.clone());
+        for output in &self.outputs {
+            let address: Address = output
+                .address
+                .parse()
+                .with_context(|| format!("invalid address {}", output.address))?;
+            let value: Value = output
+                .value
+                .parse()
+                .with_context(|| format!("invalid value {}", output.value))?;
+            planner.output(value, address);
+        }
+        planner.add_gas_fees();
+        Ok(planner)
+    }
+}
+
+/// Returns the requests for the notes needed to fund the transaction described by the JSON
+/// `request`, as a JSON array of `NotesRequest`s.
+#[wasm_bindgen]
+pub fn notes_requests(request: &str) -> Result<String, JsError> {
+    let request = SendRequest::parse(request).map_err(js_error)?;
+    let (spendable_requests, _voting_requests) = request
+        .planner()
+        .map_err(js_error)?
+        .notes_requests(request.source());
+    Ok(serde_json::to_string(&spendable_requests)?)
+}
+
+/// Plans the transaction described by the JSON `request`, spending the JSON array of
+/// `SpendableNoteRecord`s in `notes`, which must be the notes returned for the requests given by
+/// [`notes_requests`]. Returns the `TransactionPlan`, as JSON.
+#[wasm_bindgen]
+pub fn plan(request: &str, notes: &str) -> Result<String, JsError> {
+    plan_inner(request, notes).map_err(js_error)
+}
+
+fn plan_inner(request: &str, notes: &str) -> anyhow::Result<String> {
+    let request = SendRequest::parse(request)?;
+    let notes: Vec<SpendableNoteRecord> =
+        serde_json::from_str(notes).context("invalid note records")?;
+    let fvk: FullViewingKey = request
+        .full_viewing_key
+        .parse()
+        .context("invalid full viewing key")?;
+    let (self_address, _) = fvk.payment_address(request.source());
+
+    let plan = request.planner()?.plan_with_spendable_and_votable_notes(
+        request.chain_id.clone(),
+        &request.fmd_parameters,
+        notes,
+        Vec::new(),
+        self_address,
+    )?;
+    Ok(serde_json::to_string(&plan)?)
+}
+
+/// Loads the proving key named `name` (e.g. `spend`) from `bytes`, checking it against the
+/// expected key, so that the proofs of planned actions can be built.
+///
+/// There is no filesystem to read the keys from in the browser, so they have to be fetched by the
+/// caller and passed in before building a transaction.
+#[wasm_bindgen]
+pub fn load_proving_key(name: &str, bytes: &[u8]) -> Result<(), JsError> {
+    let key = match name {
+        "spend" => &SPEND_PROOF_PROVING_KEY,
+        "output" => &OUTPUT_PROOF_PROVING_KEY,
+        "swap" => &SWAP_PROOF_PROVING_KEY,
+        "swapclaim" => &SWAPCLAIM_PROOF_PROVING_KEY,
+        "convert" => &CONVERT_PROOF_PROVING_KEY,
+        "delegator_vote" => &DELEGATOR_VOTE_PROOF_PROVING_KEY,
+        "nullifier_derivation" => &NULLIFIER_DERIVATION_PROOF_PROVING_KEY,
+        _ => return Err(JsError::new(&format!("unknown proving key {name}"))),
+    };
+    key.try_load(bytes).map_err(js_error)?;
+    Ok(())
+}
+
+/// Converts an error into one thrown in JavaScript, keeping the chain of its causes.
+fn js_error(error: anyhow::Error) -> JsError {
+    JsError::new(&format!("{error:#}"))
+}
@@ -0,0 +1,45 @@
+//! Run with `wasm-pack test --node crates/misc/wasm-planner`.
+#![cfg(target_arch = "wasm32")]
+
+use penumbra_keys::test_keys::{ADDRESS_0_STR, FULL_VIEWING_KEY_STR};
+use penumbra_wasm_planner::{load_proving_key, notes_requests, plan};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn request(outputs: &str) -> String {
+    format!(
+        r#"{{
+            "full_viewing_key": "{FULL_VIEWING_KEY_STR}",
+            "chain_id": "penumbra-testnet",
+            "fmd_parameters": {{"precisionBits": 0, "asOfBlockHeight": "1"}},
+            "gas_prices": {{}},
+            "outputs": {outputs}
+        }}"#
+    )
+}
+
+#[wasm_bindgen_test]
+fn rejects_empty_transaction() {
+    let request = request("[]");
+    assert_eq!(
+        notes_requests(&request).expect("can compute notes requests"),
+        "[]"
+    );
+    assert!(plan(&request, "[]").is_err());
+}
+
+#[wasm_bindgen_test]
+fn requests_notes_for_outputs() {
+    let request = request(&format!(
+        r#"[{{"address": "{ADDRESS_0_STR}", "value": "1penumbra"}}]"#
+    ));
+    assert_ne!(
+        notes_requests(&request).expect("can compute notes requests"),
+        "[]"
+    );
+}
+
+#[wasm_bindgen_test]
+fn rejects_wrong_proving_key() {
+    assert!(load_proving_key("spend", &[0; 32]).is_err());
+    assert!(load_proving_key("unknown", &[]).is_err());
+}
@@ -59,6 +59,35 @@ pub trait ProtoEvent: Message + Name + Serialize + DeserializeOwned + Sized {
 
 impl<E: Message + Name + Serialize + DeserializeOwned + Sized> ProtoEvent for E {}
 
+/// The key of the attribute numbering the events of a block in the order they were emitted.
+///
+/// The chain numbers every event it emits in a block from zero, across the responses to
+/// `BeginBlock`, each `DeliverTx`, and `EndBlock`, so that indexers can reconstruct the exact
+/// order of the events of a block, e.g. whether a position was closed before or after it was
+/// filled. The attribute is ignored when parsing events with [`ProtoEvent::from_event`].
+pub const EVENT_SEQUENCE_KEY: &str = "penumbra.event_sequence";
+
+/// Sets the sequence number of `event` in its block, replacing any previous one.
+pub fn set_event_sequence(event: &mut abci::Event, sequence: u64) {
+    event
+        .attributes
+        .retain(|attr| attr.key != EVENT_SEQUENCE_KEY);
+    event.attributes.push(EventAttribute {
+        key: EVENT_SEQUENCE_KEY.to_string(),
+        value: sequence.to_string(),
+        index: true,
+    });
+}
+
+/// Returns the sequence number of `event` in its block, if it has one.
+pub fn event_sequence(event: &abci::Event) -> Option<u64> {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == EVENT_SEQUENCE_KEY)
+        .and_then(|attr| attr.value.parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
@@ -120,4 +149,25 @@ mod tests {
         let proto_output2 = EventOutput::from_event(&abci_output).unwrap();
         assert_eq!(proto_output, proto_output2);
     }
+
+    #[test]
+    fn event_sequence_round_trip() {
+        use super::*;
+        use crate::core::component::sct::v1::Nullifier;
+        use crate::core::component::shielded_pool::v1::EventSpend;
+
+        let proto_spend = EventSpend {
+            nullifier: Some(Nullifier { inner: vec![7; 32] }),
+        };
+        let mut abci_spend = proto_spend.into_event();
+        assert_eq!(event_sequence(&abci_spend), None);
+
+        set_event_sequence(&mut abci_spend, 3);
+        set_event_sequence(&mut abci_spend, 42);
+        assert_eq!(event_sequence(&abci_spend), Some(42));
+        assert_eq!(abci_spend.attributes.len(), 2);
+
+        // The sequence number doesn't get in the way of parsing the event.
+        assert_eq!(EventSpend::from_event(&abci_spend).unwrap(), proto_spend);
+    }
 }
@@ -33,6 +33,265 @@ impl ::prost::Name for TransactionsByHeightResponse {
         ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
     }
 }
+/// Requests the reason a transaction was rejected.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionRejectionRequest {
+    /// The hash of the rejected transaction.
+    #[prost(message, optional, tag = "1")]
+    pub tx_id: ::core::option::Option<super::super::txhash::v1::TransactionId>,
+}
+impl ::prost::Name for TransactionRejectionRequest {
+    const NAME: &'static str = "TransactionRejectionRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionRejectionResponse {
+    /// The rejection, or empty if the node hasn't rejected the transaction recently.
+    #[prost(message, optional, tag = "1")]
+    pub rejection: ::core::option::Option<TransactionRejection>,
+}
+impl ::prost::Name for TransactionRejectionResponse {
+    const NAME: &'static str = "TransactionRejectionResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// A record of the node's mempool rejecting a transaction.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionRejection {
+    /// The height of the latest block when the transaction was rejected.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// The error the transaction was rejected with.
+    #[prost(string, tag = "2")]
+    pub error: ::prost::alloc::string::String,
+    /// The reasons for the rejection that could be determined from the transaction and the state.
+    #[prost(message, repeated, tag = "3")]
+    pub reasons: ::prost::alloc::vec::Vec<RejectionReason>,
+}
+impl ::prost::Name for TransactionRejection {
+    const NAME: &'static str = "TransactionRejection";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// A structured reason a transaction was rejected.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RejectionReason {
+    #[prost(oneof = "rejection_reason::Reason", tags = "1, 2, 3")]
+    pub reason: ::core::option::Option<rejection_reason::Reason>,
+}
+/// Nested message and enum types in `RejectionReason`.
+pub mod rejection_reason {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Reason {
+        #[prost(message, tag = "1")]
+        SpentNullifier(super::SpentNullifier),
+        #[prost(message, tag = "2")]
+        InvalidAnchor(super::InvalidAnchor),
+        #[prost(message, tag = "3")]
+        InsufficientFee(super::InsufficientFee),
+    }
+}
+impl ::prost::Name for RejectionReason {
+    const NAME: &'static str = "RejectionReason";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The transaction spends a note that was already spent.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SpentNullifier {
+    #[prost(message, optional, tag = "1")]
+    pub nullifier: ::core::option::Option<super::super::component::sct::v1::Nullifier>,
+    /// The height at which the note was originally spent.
+    #[prost(uint64, tag = "2")]
+    pub spend_height: u64,
+}
+impl ::prost::Name for SpentNullifier {
+    const NAME: &'static str = "SpentNullifier";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The transaction claims an anchor that isn't a known root of the state commitment tree.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InvalidAnchor {
+    #[prost(message, optional, tag = "1")]
+    pub anchor: ::core::option::Option<super::super::super::crypto::tct::v1::MerkleRoot>,
+    /// The anchors of the most recent blocks, newest first.
+    #[prost(message, repeated, tag = "2")]
+    pub recent_anchors: ::prost::alloc::vec::Vec<
+        super::super::super::crypto::tct::v1::MerkleRoot,
+    >,
+}
+impl ::prost::Name for InvalidAnchor {
+    const NAME: &'static str = "InvalidAnchor";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The transaction pays less than the minimum fee at current gas prices.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InsufficientFee {
+    #[prost(message, optional, tag = "1")]
+    pub fee: ::core::option::Option<super::super::component::fee::v1::Fee>,
+    #[prost(message, optional, tag = "2")]
+    pub minimum_fee: ::core::option::Option<super::super::component::fee::v1::Fee>,
+}
+impl ::prost::Name for InsufficientFee {
+    const NAME: &'static str = "InsufficientFee";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// Requests the summary of an epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochSummaryRequest {
+    /// The index of the epoch.
+    #[prost(uint64, tag = "1")]
+    pub epoch_index: u64,
+}
+impl ::prost::Name for EpochSummaryRequest {
+    const NAME: &'static str = "EpochSummaryRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochSummaryResponse {
+    /// The summary, or empty if the epoch hasn't ended yet.
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<EpochSummary>,
+}
+impl ::prost::Name for EpochSummaryResponse {
+    const NAME: &'static str = "EpochSummaryResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// A rollup of the activity on chain during an epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EpochSummary {
+    /// The index of the epoch.
+    #[prost(uint64, tag = "1")]
+    pub epoch_index: u64,
+    /// The height of the first block in the epoch.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The height of the last block in the epoch.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+    /// The staking tokens issued at the end of the epoch.
+    #[prost(message, optional, tag = "4")]
+    pub staking_issuance: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The fees paid by the epoch's transactions, by asset.
+    #[prost(message, repeated, tag = "5")]
+    pub fees: ::prost::alloc::vec::Vec<super::super::asset::v1::Value>,
+    /// The amounts swapped in the epoch's batch swaps, by trading pair.
+    #[prost(message, repeated, tag = "6")]
+    pub dex_volume: ::prost::alloc::vec::Vec<PairVolume>,
+    /// The number of liquidity positions opened by the epoch's transactions.
+    #[prost(uint64, tag = "7")]
+    pub positions_opened: u64,
+    /// The number of liquidity positions closed by the epoch's transactions.
+    #[prost(uint64, tag = "8")]
+    pub positions_closed: u64,
+    /// The amounts transferred into and out of the chain over ICS20, by asset.
+    #[prost(message, repeated, tag = "9")]
+    pub ibc_flows: ::prost::alloc::vec::Vec<IbcFlow>,
+}
+impl ::prost::Name for EpochSummary {
+    const NAME: &'static str = "EpochSummary";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The amounts swapped on a trading pair during an epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PairVolume {
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<
+        super::super::component::dex::v1::TradingPair,
+    >,
+    /// The amount of asset 1 swapped into asset 2.
+    #[prost(message, optional, tag = "2")]
+    pub swapped_1: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The amount of asset 2 swapped into asset 1.
+    #[prost(message, optional, tag = "3")]
+    pub swapped_2: ::core::option::Option<super::super::num::v1::Amount>,
+}
+impl ::prost::Name for PairVolume {
+    const NAME: &'static str = "PairVolume";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// The amounts of an asset transferred over ICS20, during an epoch and in total.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IbcFlow {
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::asset::v1::AssetId>,
+    /// The amount received during the epoch.
+    #[prost(message, optional, tag = "2")]
+    pub inflow: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The amount sent during the epoch, net of refunds.
+    #[prost(message, optional, tag = "3")]
+    pub outflow: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The amount received since genesis, as of the end of the epoch.
+    #[prost(message, optional, tag = "4")]
+    pub total_inflow: ::core::option::Option<super::super::num::v1::Amount>,
+    /// The amount sent since genesis, net of refunds, as of the end of the epoch.
+    #[prost(message, optional, tag = "5")]
+    pub total_outflow: ::core::option::Option<super::super::num::v1::Amount>,
+}
+impl ::prost::Name for IbcFlow {
+    const NAME: &'static str = "IbcFlow";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+/// Emitted at the end of each epoch, summarizing the epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventEpochSummary {
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<EpochSummary>,
+}
+impl ::prost::Name for EventEpochSummary {
+    const NAME: &'static str = "EventEpochSummary";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AppParameters {
@@ -84,6 +343,11 @@ pub struct AppParameters {
     pub shielded_pool_params: ::core::option::Option<
         super::super::component::shielded_pool::v1::ShieldedPoolParameters,
     >,
+    /// DEX module parameters.
+    #[prost(message, optional, tag = "11")]
+    pub dex_params: ::core::option::Option<
+        super::super::component::dex::v1::DexParameters,
+    >,
 }
 impl ::prost::Name for AppParameters {
     const NAME: &'static str = "AppParameters";
@@ -116,6 +380,52 @@ impl ::prost::Name for AppParametersResponse {
         ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
     }
 }
+/// Requests a simulation of a parameter change proposal against the current app parameters.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateParameterChangeRequest {
+    /// The parameters the proposal expects to change from, if any.
+    ///
+    /// A proposal is only enacted if these match the app parameters when it passes, so if they're
+    /// set, they're checked against the current ones.
+    #[prost(message, optional, tag = "1")]
+    pub old: ::core::option::Option<
+        super::super::component::governance::v1::ChangedAppParameters,
+    >,
+    /// The parameters the proposal changes, where unset components are left as they are.
+    #[prost(message, optional, tag = "2")]
+    pub new: ::core::option::Option<
+        super::super::component::governance::v1::ChangedAppParameters,
+    >,
+}
+impl ::prost::Name for SimulateParameterChangeRequest {
+    const NAME: &'static str = "SimulateParameterChangeRequest";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulateParameterChangeResponse {
+    /// The app parameters the change would result in.
+    #[prost(message, optional, tag = "1")]
+    pub app_parameters: ::core::option::Option<AppParameters>,
+    /// The reasons the change would be rejected, or empty if it would be accepted.
+    #[prost(string, repeated, tag = "2")]
+    pub errors: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Settings that would be accepted, but are probably mistakes, such as a combination of
+    /// parameters that disables a mechanism.
+    #[prost(string, repeated, tag = "3")]
+    pub warnings: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+impl ::prost::Name for SimulateParameterChangeResponse {
+    const NAME: &'static str = "SimulateParameterChangeResponse";
+    const PACKAGE: &'static str = "penumbra.core.app.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.app.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GenesisAppState {
@@ -191,6 +501,11 @@ pub struct GenesisContent {
     pub funding_content: ::core::option::Option<
         super::super::component::funding::v1::GenesisContent,
     >,
+    /// DEX module genesis state.
+    #[prost(message, optional, tag = "11")]
+    pub dex_content: ::core::option::Option<
+        super::super::component::dex::v1::GenesisContent,
+    >,
 }
 impl ::prost::Name for GenesisContent {
     const NAME: &'static str = "GenesisContent";
@@ -345,6 +660,97 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Returns why the node's mempool rejected a transaction, if it did so recently.
+        pub async fn transaction_rejection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionRejectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionRejectionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1.QueryService/TransactionRejection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1.QueryService",
+                        "TransactionRejection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns the summary of the activity on chain during an epoch that has ended.
+        pub async fn epoch_summary(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EpochSummaryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EpochSummaryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1.QueryService/EpochSummary",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.core.app.v1.QueryService", "EpochSummary"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Applies a proposed parameter change to the current app parameters, and validates the result,
+        /// without submitting anything, so that parameter change proposals can be checked beforehand.
+        pub async fn simulate_parameter_change(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SimulateParameterChangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateParameterChangeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.app.v1.QueryService/SimulateParameterChange",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.app.v1.QueryService",
+                        "SimulateParameterChange",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -371,6 +777,31 @@ pub mod query_service_server {
             tonic::Response<super::TransactionsByHeightResponse>,
             tonic::Status,
         >;
+        /// Returns why the node's mempool rejected a transaction, if it did so recently.
+        async fn transaction_rejection(
+            &self,
+            request: tonic::Request<super::TransactionRejectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionRejectionResponse>,
+            tonic::Status,
+        >;
+        /// Returns the summary of the activity on chain during an epoch that has ended.
+        async fn epoch_summary(
+            &self,
+            request: tonic::Request<super::EpochSummaryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EpochSummaryResponse>,
+            tonic::Status,
+        >;
+        /// Applies a proposed parameter change to the current app parameters, and validates the result,
+        /// without submitting anything, so that parameter change proposals can be checked beforehand.
+        async fn simulate_parameter_change(
+            &self,
+            request: tonic::Request<super::SimulateParameterChangeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SimulateParameterChangeResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the overall Penumbra application.
     #[derive(Debug)]
@@ -545,6 +976,151 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.app.v1.QueryService/TransactionRejection" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionRejectionSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::TransactionRejectionRequest>
+                    for TransactionRejectionSvc<T> {
+                        type Response = super::TransactionRejectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionRejectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::transaction_rejection(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TransactionRejectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.app.v1.QueryService/EpochSummary" => {
+                    #[allow(non_camel_case_types)]
+                    struct EpochSummarySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::EpochSummaryRequest>
+                    for EpochSummarySvc<T> {
+                        type Response = super::EpochSummaryResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EpochSummaryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::epoch_summary(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EpochSummarySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.app.v1.QueryService/SimulateParameterChange" => {
+                    #[allow(non_camel_case_types)]
+                    struct SimulateParameterChangeSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SimulateParameterChangeRequest>
+                    for SimulateParameterChangeSvc<T> {
+                        type Response = super::SimulateParameterChangeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SimulateParameterChangeRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::simulate_parameter_change(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SimulateParameterChangeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
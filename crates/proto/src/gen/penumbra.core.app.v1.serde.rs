@@ -36,6 +36,9 @@ impl serde::Serialize for AppParameters {
         if self.shielded_pool_params.is_some() {
             len += 1;
         }
+        if self.dex_params.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.AppParameters", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chainId", &self.chain_id)?;
@@ -67,6 +70,9 @@ impl serde::Serialize for AppParameters {
         if let Some(v) = self.shielded_pool_params.as_ref() {
             struct_ser.serialize_field("shieldedPoolParams", v)?;
         }
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -97,6 +103,8 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
             "fundingParams",
             "shielded_pool_params",
             "shieldedPoolParams",
+            "dex_params",
+            "dexParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -111,6 +119,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
             DistributionsParams,
             FundingParams,
             ShieldedPoolParams,
+            DexParams,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -143,6 +152,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                             "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
                             "fundingParams" | "funding_params" => Ok(GeneratedField::FundingParams),
                             "shieldedPoolParams" | "shielded_pool_params" => Ok(GeneratedField::ShieldedPoolParams),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -172,6 +182,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                 let mut distributions_params__ = None;
                 let mut funding_params__ = None;
                 let mut shielded_pool_params__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ChainId => {
@@ -234,6 +245,12 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                             }
                             shielded_pool_params__ = map_.next_value()?;
                         }
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -250,6 +267,7 @@ impl<'de> serde::Deserialize<'de> for AppParameters {
                     distributions_params: distributions_params__,
                     funding_params: funding_params__,
                     shielded_pool_params: shielded_pool_params__,
+                    dex_params: dex_params__,
                 })
             }
         }
@@ -424,7 +442,7 @@ impl<'de> serde::Deserialize<'de> for AppParametersResponse {
         deserializer.deserialize_struct("penumbra.core.app.v1.AppParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisAppState {
+impl serde::Serialize for EpochSummary {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -432,41 +450,106 @@ impl serde::Serialize for GenesisAppState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.genesis_app_state.is_some() {
+        if self.epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.GenesisAppState", len)?;
-        if let Some(v) = self.genesis_app_state.as_ref() {
-            match v {
-                genesis_app_state::GenesisAppState::GenesisContent(v) => {
-                    struct_ser.serialize_field("genesisContent", v)?;
-                }
-                genesis_app_state::GenesisAppState::GenesisCheckpoint(v) => {
-                    #[allow(clippy::needless_borrow)]
-                    struct_ser.serialize_field("genesisCheckpoint", pbjson::private::base64::encode(&v).as_str())?;
-                }
-            }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.staking_issuance.is_some() {
+            len += 1;
+        }
+        if !self.fees.is_empty() {
+            len += 1;
+        }
+        if !self.dex_volume.is_empty() {
+            len += 1;
+        }
+        if self.positions_opened != 0 {
+            len += 1;
+        }
+        if self.positions_closed != 0 {
+            len += 1;
+        }
+        if !self.ibc_flows.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.EpochSummary", len)?;
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if let Some(v) = self.staking_issuance.as_ref() {
+            struct_ser.serialize_field("stakingIssuance", v)?;
+        }
+        if !self.fees.is_empty() {
+            struct_ser.serialize_field("fees", &self.fees)?;
+        }
+        if !self.dex_volume.is_empty() {
+            struct_ser.serialize_field("dexVolume", &self.dex_volume)?;
+        }
+        if self.positions_opened != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("positionsOpened", ToString::to_string(&self.positions_opened).as_str())?;
+        }
+        if self.positions_closed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("positionsClosed", ToString::to_string(&self.positions_closed).as_str())?;
+        }
+        if !self.ibc_flows.is_empty() {
+            struct_ser.serialize_field("ibcFlows", &self.ibc_flows)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisAppState {
+impl<'de> serde::Deserialize<'de> for EpochSummary {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "genesis_content",
-            "genesisContent",
-            "genesis_checkpoint",
-            "genesisCheckpoint",
+            "epoch_index",
+            "epochIndex",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "staking_issuance",
+            "stakingIssuance",
+            "fees",
+            "dex_volume",
+            "dexVolume",
+            "positions_opened",
+            "positionsOpened",
+            "positions_closed",
+            "positionsClosed",
+            "ibc_flows",
+            "ibcFlows",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            GenesisContent,
-            GenesisCheckpoint,
+            EpochIndex,
+            StartHeight,
+            EndHeight,
+            StakingIssuance,
+            Fees,
+            DexVolume,
+            PositionsOpened,
+            PositionsClosed,
+            IbcFlows,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -489,8 +572,15 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "genesisContent" | "genesis_content" => Ok(GeneratedField::GenesisContent),
-                            "genesisCheckpoint" | "genesis_checkpoint" => Ok(GeneratedField::GenesisCheckpoint),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "stakingIssuance" | "staking_issuance" => Ok(GeneratedField::StakingIssuance),
+                            "fees" => Ok(GeneratedField::Fees),
+                            "dexVolume" | "dex_volume" => Ok(GeneratedField::DexVolume),
+                            "positionsOpened" | "positions_opened" => Ok(GeneratedField::PositionsOpened),
+                            "positionsClosed" | "positions_closed" => Ok(GeneratedField::PositionsClosed),
+                            "ibcFlows" | "ibc_flows" => Ok(GeneratedField::IbcFlows),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -500,46 +590,113 @@ impl<'de> serde::Deserialize<'de> for GenesisAppState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisAppState;
+            type Value = EpochSummary;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.app.v1.GenesisAppState")
+                formatter.write_str("struct penumbra.core.app.v1.EpochSummary")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisAppState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochSummary, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut genesis_app_state__ = None;
+                let mut epoch_index__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut staking_issuance__ = None;
+                let mut fees__ = None;
+                let mut dex_volume__ = None;
+                let mut positions_opened__ = None;
+                let mut positions_closed__ = None;
+                let mut ibc_flows__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::GenesisContent => {
-                            if genesis_app_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("genesisContent"));
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
                             }
-                            genesis_app_state__ = map_.next_value::<::std::option::Option<_>>()?.map(genesis_app_state::GenesisAppState::GenesisContent)
-;
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::GenesisCheckpoint => {
-                            if genesis_app_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("genesisCheckpoint"));
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
                             }
-                            genesis_app_state__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| genesis_app_state::GenesisAppState::GenesisCheckpoint(x.0));
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StakingIssuance => {
+                            if staking_issuance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakingIssuance"));
+                            }
+                            staking_issuance__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fees => {
+                            if fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees"));
+                            }
+                            fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DexVolume => {
+                            if dex_volume__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexVolume"));
+                            }
+                            dex_volume__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionsOpened => {
+                            if positions_opened__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionsOpened"));
+                            }
+                            positions_opened__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PositionsClosed => {
+                            if positions_closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionsClosed"));
+                            }
+                            positions_closed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IbcFlows => {
+                            if ibc_flows__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcFlows"));
+                            }
+                            ibc_flows__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisAppState {
-                    genesis_app_state: genesis_app_state__,
+                Ok(EpochSummary {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    staking_issuance: staking_issuance__,
+                    fees: fees__.unwrap_or_default(),
+                    dex_volume: dex_volume__.unwrap_or_default(),
+                    positions_opened: positions_opened__.unwrap_or_default(),
+                    positions_closed: positions_closed__.unwrap_or_default(),
+                    ibc_flows: ibc_flows__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.app.v1.GenesisAppState", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.app.v1.EpochSummary", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for EpochSummaryRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -547,111 +704,31 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.chain_id.is_empty() {
-            len += 1;
-        }
-        if self.stake_content.is_some() {
-            len += 1;
-        }
-        if self.shielded_pool_content.is_some() {
-            len += 1;
-        }
-        if self.governance_content.is_some() {
-            len += 1;
-        }
-        if self.ibc_content.is_some() {
-            len += 1;
-        }
-        if self.sct_content.is_some() {
-            len += 1;
-        }
-        if self.community_pool_content.is_some() {
-            len += 1;
-        }
-        if self.fee_content.is_some() {
-            len += 1;
-        }
-        if self.distributions_content.is_some() {
-            len += 1;
-        }
-        if self.funding_content.is_some() {
+        if self.epoch_index != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.GenesisContent", len)?;
-        if !self.chain_id.is_empty() {
-            struct_ser.serialize_field("chainId", &self.chain_id)?;
-        }
-        if let Some(v) = self.stake_content.as_ref() {
-            struct_ser.serialize_field("stakeContent", v)?;
-        }
-        if let Some(v) = self.shielded_pool_content.as_ref() {
-            struct_ser.serialize_field("shieldedPoolContent", v)?;
-        }
-        if let Some(v) = self.governance_content.as_ref() {
-            struct_ser.serialize_field("governanceContent", v)?;
-        }
-        if let Some(v) = self.ibc_content.as_ref() {
-            struct_ser.serialize_field("ibcContent", v)?;
-        }
-        if let Some(v) = self.sct_content.as_ref() {
-            struct_ser.serialize_field("sctContent", v)?;
-        }
-        if let Some(v) = self.community_pool_content.as_ref() {
-            struct_ser.serialize_field("communityPoolContent", v)?;
-        }
-        if let Some(v) = self.fee_content.as_ref() {
-            struct_ser.serialize_field("feeContent", v)?;
-        }
-        if let Some(v) = self.distributions_content.as_ref() {
-            struct_ser.serialize_field("distributionsContent", v)?;
-        }
-        if let Some(v) = self.funding_content.as_ref() {
-            struct_ser.serialize_field("fundingContent", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.EpochSummaryRequest", len)?;
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for EpochSummaryRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "chain_id",
-            "chainId",
-            "stake_content",
-            "stakeContent",
-            "shielded_pool_content",
-            "shieldedPoolContent",
-            "governance_content",
-            "governanceContent",
-            "ibc_content",
-            "ibcContent",
-            "sct_content",
-            "sctContent",
-            "community_pool_content",
-            "communityPoolContent",
-            "fee_content",
-            "feeContent",
-            "distributions_content",
-            "distributionsContent",
-            "funding_content",
-            "fundingContent",
+            "epoch_index",
+            "epochIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ChainId,
-            StakeContent,
-            ShieldedPoolContent,
-            GovernanceContent,
-            IbcContent,
-            SctContent,
-            CommunityPoolContent,
-            FeeContent,
-            DistributionsContent,
-            FundingContent,
+            EpochIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -674,16 +751,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
-                            "stakeContent" | "stake_content" => Ok(GeneratedField::StakeContent),
-                            "shieldedPoolContent" | "shielded_pool_content" => Ok(GeneratedField::ShieldedPoolContent),
-                            "governanceContent" | "governance_content" => Ok(GeneratedField::GovernanceContent),
-                            "ibcContent" | "ibc_content" => Ok(GeneratedField::IbcContent),
-                            "sctContent" | "sct_content" => Ok(GeneratedField::SctContent),
-                            "communityPoolContent" | "community_pool_content" => Ok(GeneratedField::CommunityPoolContent),
-                            "feeContent" | "fee_content" => Ok(GeneratedField::FeeContent),
-                            "distributionsContent" | "distributions_content" => Ok(GeneratedField::DistributionsContent),
-                            "fundingContent" | "funding_content" => Ok(GeneratedField::FundingContent),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -693,108 +761,1953 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = EpochSummaryRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.app.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.app.v1.EpochSummaryRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochSummaryRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut chain_id__ = None;
-                let mut stake_content__ = None;
-                let mut shielded_pool_content__ = None;
-                let mut governance_content__ = None;
-                let mut ibc_content__ = None;
-                let mut sct_content__ = None;
-                let mut community_pool_content__ = None;
-                let mut fee_content__ = None;
-                let mut distributions_content__ = None;
-                let mut funding_content__ = None;
+                let mut epoch_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ChainId => {
-                            if chain_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("chainId"));
-                            }
-                            chain_id__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::StakeContent => {
-                            if stake_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stakeContent"));
-                            }
-                            stake_content__ = map_.next_value()?;
-                        }
-                        GeneratedField::ShieldedPoolContent => {
-                            if shielded_pool_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("shieldedPoolContent"));
-                            }
-                            shielded_pool_content__ = map_.next_value()?;
-                        }
-                        GeneratedField::GovernanceContent => {
-                            if governance_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("governanceContent"));
-                            }
-                            governance_content__ = map_.next_value()?;
-                        }
-                        GeneratedField::IbcContent => {
-                            if ibc_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcContent"));
-                            }
-                            ibc_content__ = map_.next_value()?;
-                        }
-                        GeneratedField::SctContent => {
-                            if sct_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sctContent"));
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
                             }
-                            sct_content__ = map_.next_value()?;
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::CommunityPoolContent => {
-                            if community_pool_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("communityPoolContent"));
-                            }
-                            community_pool_content__ = map_.next_value()?;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::FeeContent => {
-                            if fee_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("feeContent"));
+                    }
+                }
+                Ok(EpochSummaryRequest {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.EpochSummaryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EpochSummaryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.summary.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.EpochSummaryResponse", len)?;
+        if let Some(v) = self.summary.as_ref() {
+            struct_ser.serialize_field("summary", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EpochSummaryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "summary",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Summary,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "summary" => Ok(GeneratedField::Summary),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EpochSummaryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.EpochSummaryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EpochSummaryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut summary__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Summary => {
+                            if summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summary"));
+                            }
+                            summary__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EpochSummaryResponse {
+                    summary: summary__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.EpochSummaryResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventEpochSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.summary.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.EventEpochSummary", len)?;
+        if let Some(v) = self.summary.as_ref() {
+            struct_ser.serialize_field("summary", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventEpochSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "summary",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Summary,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "summary" => Ok(GeneratedField::Summary),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventEpochSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.EventEpochSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventEpochSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut summary__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Summary => {
+                            if summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summary"));
+                            }
+                            summary__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventEpochSummary {
+                    summary: summary__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.EventEpochSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisAppState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.genesis_app_state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.GenesisAppState", len)?;
+        if let Some(v) = self.genesis_app_state.as_ref() {
+            match v {
+                genesis_app_state::GenesisAppState::GenesisContent(v) => {
+                    struct_ser.serialize_field("genesisContent", v)?;
+                }
+                genesis_app_state::GenesisAppState::GenesisCheckpoint(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("genesisCheckpoint", pbjson::private::base64::encode(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisAppState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "genesis_content",
+            "genesisContent",
+            "genesis_checkpoint",
+            "genesisCheckpoint",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GenesisContent,
+            GenesisCheckpoint,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "genesisContent" | "genesis_content" => Ok(GeneratedField::GenesisContent),
+                            "genesisCheckpoint" | "genesis_checkpoint" => Ok(GeneratedField::GenesisCheckpoint),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisAppState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.GenesisAppState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisAppState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut genesis_app_state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GenesisContent => {
+                            if genesis_app_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("genesisContent"));
+                            }
+                            genesis_app_state__ = map_.next_value::<::std::option::Option<_>>()?.map(genesis_app_state::GenesisAppState::GenesisContent)
+;
+                        }
+                        GeneratedField::GenesisCheckpoint => {
+                            if genesis_app_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("genesisCheckpoint"));
+                            }
+                            genesis_app_state__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| genesis_app_state::GenesisAppState::GenesisCheckpoint(x.0));
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisAppState {
+                    genesis_app_state: genesis_app_state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.GenesisAppState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        if self.stake_content.is_some() {
+            len += 1;
+        }
+        if self.shielded_pool_content.is_some() {
+            len += 1;
+        }
+        if self.governance_content.is_some() {
+            len += 1;
+        }
+        if self.ibc_content.is_some() {
+            len += 1;
+        }
+        if self.sct_content.is_some() {
+            len += 1;
+        }
+        if self.community_pool_content.is_some() {
+            len += 1;
+        }
+        if self.fee_content.is_some() {
+            len += 1;
+        }
+        if self.distributions_content.is_some() {
+            len += 1;
+        }
+        if self.funding_content.is_some() {
+            len += 1;
+        }
+        if self.dex_content.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.GenesisContent", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
+        }
+        if let Some(v) = self.stake_content.as_ref() {
+            struct_ser.serialize_field("stakeContent", v)?;
+        }
+        if let Some(v) = self.shielded_pool_content.as_ref() {
+            struct_ser.serialize_field("shieldedPoolContent", v)?;
+        }
+        if let Some(v) = self.governance_content.as_ref() {
+            struct_ser.serialize_field("governanceContent", v)?;
+        }
+        if let Some(v) = self.ibc_content.as_ref() {
+            struct_ser.serialize_field("ibcContent", v)?;
+        }
+        if let Some(v) = self.sct_content.as_ref() {
+            struct_ser.serialize_field("sctContent", v)?;
+        }
+        if let Some(v) = self.community_pool_content.as_ref() {
+            struct_ser.serialize_field("communityPoolContent", v)?;
+        }
+        if let Some(v) = self.fee_content.as_ref() {
+            struct_ser.serialize_field("feeContent", v)?;
+        }
+        if let Some(v) = self.distributions_content.as_ref() {
+            struct_ser.serialize_field("distributionsContent", v)?;
+        }
+        if let Some(v) = self.funding_content.as_ref() {
+            struct_ser.serialize_field("fundingContent", v)?;
+        }
+        if let Some(v) = self.dex_content.as_ref() {
+            struct_ser.serialize_field("dexContent", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "chain_id",
+            "chainId",
+            "stake_content",
+            "stakeContent",
+            "shielded_pool_content",
+            "shieldedPoolContent",
+            "governance_content",
+            "governanceContent",
+            "ibc_content",
+            "ibcContent",
+            "sct_content",
+            "sctContent",
+            "community_pool_content",
+            "communityPoolContent",
+            "fee_content",
+            "feeContent",
+            "distributions_content",
+            "distributionsContent",
+            "funding_content",
+            "fundingContent",
+            "dex_content",
+            "dexContent",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ChainId,
+            StakeContent,
+            ShieldedPoolContent,
+            GovernanceContent,
+            IbcContent,
+            SctContent,
+            CommunityPoolContent,
+            FeeContent,
+            DistributionsContent,
+            FundingContent,
+            DexContent,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "stakeContent" | "stake_content" => Ok(GeneratedField::StakeContent),
+                            "shieldedPoolContent" | "shielded_pool_content" => Ok(GeneratedField::ShieldedPoolContent),
+                            "governanceContent" | "governance_content" => Ok(GeneratedField::GovernanceContent),
+                            "ibcContent" | "ibc_content" => Ok(GeneratedField::IbcContent),
+                            "sctContent" | "sct_content" => Ok(GeneratedField::SctContent),
+                            "communityPoolContent" | "community_pool_content" => Ok(GeneratedField::CommunityPoolContent),
+                            "feeContent" | "fee_content" => Ok(GeneratedField::FeeContent),
+                            "distributionsContent" | "distributions_content" => Ok(GeneratedField::DistributionsContent),
+                            "fundingContent" | "funding_content" => Ok(GeneratedField::FundingContent),
+                            "dexContent" | "dex_content" => Ok(GeneratedField::DexContent),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut chain_id__ = None;
+                let mut stake_content__ = None;
+                let mut shielded_pool_content__ = None;
+                let mut governance_content__ = None;
+                let mut ibc_content__ = None;
+                let mut sct_content__ = None;
+                let mut community_pool_content__ = None;
+                let mut fee_content__ = None;
+                let mut distributions_content__ = None;
+                let mut funding_content__ = None;
+                let mut dex_content__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::StakeContent => {
+                            if stake_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stakeContent"));
+                            }
+                            stake_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::ShieldedPoolContent => {
+                            if shielded_pool_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("shieldedPoolContent"));
+                            }
+                            shielded_pool_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::GovernanceContent => {
+                            if governance_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("governanceContent"));
+                            }
+                            governance_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::IbcContent => {
+                            if ibc_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcContent"));
+                            }
+                            ibc_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::SctContent => {
+                            if sct_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sctContent"));
+                            }
+                            sct_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommunityPoolContent => {
+                            if community_pool_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("communityPoolContent"));
+                            }
+                            community_pool_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeContent => {
+                            if fee_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeContent"));
+                            }
+                            fee_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::DistributionsContent => {
+                            if distributions_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distributionsContent"));
+                            }
+                            distributions_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::FundingContent => {
+                            if funding_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingContent"));
+                            }
+                            funding_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::DexContent => {
+                            if dex_content__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexContent"));
+                            }
+                            dex_content__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    stake_content: stake_content__,
+                    shielded_pool_content: shielded_pool_content__,
+                    governance_content: governance_content__,
+                    ibc_content: ibc_content__,
+                    sct_content: sct_content__,
+                    community_pool_content: community_pool_content__,
+                    fee_content: fee_content__,
+                    distributions_content: distributions_content__,
+                    funding_content: funding_content__,
+                    dex_content: dex_content__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcFlow {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.inflow.is_some() {
+            len += 1;
+        }
+        if self.outflow.is_some() {
+            len += 1;
+        }
+        if self.total_inflow.is_some() {
+            len += 1;
+        }
+        if self.total_outflow.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.IbcFlow", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.inflow.as_ref() {
+            struct_ser.serialize_field("inflow", v)?;
+        }
+        if let Some(v) = self.outflow.as_ref() {
+            struct_ser.serialize_field("outflow", v)?;
+        }
+        if let Some(v) = self.total_inflow.as_ref() {
+            struct_ser.serialize_field("totalInflow", v)?;
+        }
+        if let Some(v) = self.total_outflow.as_ref() {
+            struct_ser.serialize_field("totalOutflow", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcFlow {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "inflow",
+            "outflow",
+            "total_inflow",
+            "totalInflow",
+            "total_outflow",
+            "totalOutflow",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Inflow,
+            Outflow,
+            TotalInflow,
+            TotalOutflow,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "inflow" => Ok(GeneratedField::Inflow),
+                            "outflow" => Ok(GeneratedField::Outflow),
+                            "totalInflow" | "total_inflow" => Ok(GeneratedField::TotalInflow),
+                            "totalOutflow" | "total_outflow" => Ok(GeneratedField::TotalOutflow),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcFlow;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.IbcFlow")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcFlow, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut inflow__ = None;
+                let mut outflow__ = None;
+                let mut total_inflow__ = None;
+                let mut total_outflow__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Inflow => {
+                            if inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inflow"));
+                            }
+                            inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::Outflow => {
+                            if outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outflow"));
+                            }
+                            outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::TotalInflow => {
+                            if total_inflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalInflow"));
+                            }
+                            total_inflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::TotalOutflow => {
+                            if total_outflow__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalOutflow"));
+                            }
+                            total_outflow__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(IbcFlow {
+                    asset_id: asset_id__,
+                    inflow: inflow__,
+                    outflow: outflow__,
+                    total_inflow: total_inflow__,
+                    total_outflow: total_outflow__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.IbcFlow", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InsufficientFee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if self.minimum_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.InsufficientFee", len)?;
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.minimum_fee.as_ref() {
+            struct_ser.serialize_field("minimumFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InsufficientFee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "fee",
+            "minimum_fee",
+            "minimumFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Fee,
+            MinimumFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fee" => Ok(GeneratedField::Fee),
+                            "minimumFee" | "minimum_fee" => Ok(GeneratedField::MinimumFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InsufficientFee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.InsufficientFee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InsufficientFee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut fee__ = None;
+                let mut minimum_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinimumFee => {
+                            if minimum_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minimumFee"));
+                            }
+                            minimum_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(InsufficientFee {
+                    fee: fee__,
+                    minimum_fee: minimum_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.InsufficientFee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InvalidAnchor {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.anchor.is_some() {
+            len += 1;
+        }
+        if !self.recent_anchors.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.InvalidAnchor", len)?;
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        if !self.recent_anchors.is_empty() {
+            struct_ser.serialize_field("recentAnchors", &self.recent_anchors)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InvalidAnchor {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "anchor",
+            "recent_anchors",
+            "recentAnchors",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Anchor,
+            RecentAnchors,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "recentAnchors" | "recent_anchors" => Ok(GeneratedField::RecentAnchors),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InvalidAnchor;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.InvalidAnchor")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InvalidAnchor, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut anchor__ = None;
+                let mut recent_anchors__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
+                            }
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::RecentAnchors => {
+                            if recent_anchors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("recentAnchors"));
+                            }
+                            recent_anchors__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(InvalidAnchor {
+                    anchor: anchor__,
+                    recent_anchors: recent_anchors__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.InvalidAnchor", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PairVolume {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.swapped_1.is_some() {
+            len += 1;
+        }
+        if self.swapped_2.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.PairVolume", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.swapped_1.as_ref() {
+            struct_ser.serialize_field("swapped1", v)?;
+        }
+        if let Some(v) = self.swapped_2.as_ref() {
+            struct_ser.serialize_field("swapped2", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PairVolume {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "swapped_1",
+            "swapped1",
+            "swapped_2",
+            "swapped2",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            Swapped1,
+            Swapped2,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "swapped1" | "swapped_1" => Ok(GeneratedField::Swapped1),
+                            "swapped2" | "swapped_2" => Ok(GeneratedField::Swapped2),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PairVolume;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.PairVolume")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PairVolume, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut swapped_1__ = None;
+                let mut swapped_2__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Swapped1 => {
+                            if swapped_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapped1"));
+                            }
+                            swapped_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::Swapped2 => {
+                            if swapped_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapped2"));
+                            }
+                            swapped_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PairVolume {
+                    trading_pair: trading_pair__,
+                    swapped_1: swapped_1__,
+                    swapped_2: swapped_2__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.PairVolume", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for RejectionReason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.reason.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.RejectionReason", len)?;
+        if let Some(v) = self.reason.as_ref() {
+            match v {
+                rejection_reason::Reason::SpentNullifier(v) => {
+                    struct_ser.serialize_field("spentNullifier", v)?;
+                }
+                rejection_reason::Reason::InvalidAnchor(v) => {
+                    struct_ser.serialize_field("invalidAnchor", v)?;
+                }
+                rejection_reason::Reason::InsufficientFee(v) => {
+                    struct_ser.serialize_field("insufficientFee", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RejectionReason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spent_nullifier",
+            "spentNullifier",
+            "invalid_anchor",
+            "invalidAnchor",
+            "insufficient_fee",
+            "insufficientFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SpentNullifier,
+            InvalidAnchor,
+            InsufficientFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spentNullifier" | "spent_nullifier" => Ok(GeneratedField::SpentNullifier),
+                            "invalidAnchor" | "invalid_anchor" => Ok(GeneratedField::InvalidAnchor),
+                            "insufficientFee" | "insufficient_fee" => Ok(GeneratedField::InsufficientFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RejectionReason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.RejectionReason")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RejectionReason, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SpentNullifier => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spentNullifier"));
+                            }
+                            reason__ = map_.next_value::<::std::option::Option<_>>()?.map(rejection_reason::Reason::SpentNullifier)
+;
+                        }
+                        GeneratedField::InvalidAnchor => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("invalidAnchor"));
+                            }
+                            reason__ = map_.next_value::<::std::option::Option<_>>()?.map(rejection_reason::Reason::InvalidAnchor)
+;
+                        }
+                        GeneratedField::InsufficientFee => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("insufficientFee"));
+                            }
+                            reason__ = map_.next_value::<::std::option::Option<_>>()?.map(rejection_reason::Reason::InsufficientFee)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RejectionReason {
+                    reason: reason__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.RejectionReason", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulateParameterChangeRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.old.is_some() {
+            len += 1;
+        }
+        if self.new.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.SimulateParameterChangeRequest", len)?;
+        if let Some(v) = self.old.as_ref() {
+            struct_ser.serialize_field("old", v)?;
+        }
+        if let Some(v) = self.new.as_ref() {
+            struct_ser.serialize_field("new", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateParameterChangeRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "old",
+            "new",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Old,
+            New,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "old" => Ok(GeneratedField::Old),
+                            "new" => Ok(GeneratedField::New),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateParameterChangeRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.SimulateParameterChangeRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateParameterChangeRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut old__ = None;
+                let mut new__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Old => {
+                            if old__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("old"));
+                            }
+                            old__ = map_.next_value()?;
+                        }
+                        GeneratedField::New => {
+                            if new__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("new"));
+                            }
+                            new__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SimulateParameterChangeRequest {
+                    old: old__,
+                    new: new__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.SimulateParameterChangeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulateParameterChangeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.app_parameters.is_some() {
+            len += 1;
+        }
+        if !self.errors.is_empty() {
+            len += 1;
+        }
+        if !self.warnings.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.SimulateParameterChangeResponse", len)?;
+        if let Some(v) = self.app_parameters.as_ref() {
+            struct_ser.serialize_field("appParameters", v)?;
+        }
+        if !self.errors.is_empty() {
+            struct_ser.serialize_field("errors", &self.errors)?;
+        }
+        if !self.warnings.is_empty() {
+            struct_ser.serialize_field("warnings", &self.warnings)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulateParameterChangeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "app_parameters",
+            "appParameters",
+            "errors",
+            "warnings",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AppParameters,
+            Errors,
+            Warnings,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "appParameters" | "app_parameters" => Ok(GeneratedField::AppParameters),
+                            "errors" => Ok(GeneratedField::Errors),
+                            "warnings" => Ok(GeneratedField::Warnings),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulateParameterChangeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.SimulateParameterChangeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulateParameterChangeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut app_parameters__ = None;
+                let mut errors__ = None;
+                let mut warnings__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AppParameters => {
+                            if app_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("appParameters"));
+                            }
+                            app_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::Errors => {
+                            if errors__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("errors"));
+                            }
+                            errors__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Warnings => {
+                            if warnings__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("warnings"));
+                            }
+                            warnings__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SimulateParameterChangeResponse {
+                    app_parameters: app_parameters__,
+                    errors: errors__.unwrap_or_default(),
+                    warnings: warnings__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.SimulateParameterChangeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpentNullifier {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.spend_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.SpentNullifier", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.spend_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("spendHeight", ToString::to_string(&self.spend_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpentNullifier {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nullifier",
+            "spend_height",
+            "spendHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nullifier,
+            SpendHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "spendHeight" | "spend_height" => Ok(GeneratedField::SpendHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpentNullifier;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.SpentNullifier")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpentNullifier, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nullifier__ = None;
+                let mut spend_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::SpendHeight => {
+                            if spend_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendHeight"));
+                            }
+                            spend_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SpentNullifier {
+                    nullifier: nullifier__,
+                    spend_height: spend_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.SpentNullifier", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionRejection {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.error.is_empty() {
+            len += 1;
+        }
+        if !self.reasons.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.TransactionRejection", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.error.is_empty() {
+            struct_ser.serialize_field("error", &self.error)?;
+        }
+        if !self.reasons.is_empty() {
+            struct_ser.serialize_field("reasons", &self.reasons)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionRejection {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "error",
+            "reasons",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Error,
+            Reasons,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "error" => Ok(GeneratedField::Error),
+                            "reasons" => Ok(GeneratedField::Reasons),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionRejection;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.TransactionRejection")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionRejection, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut error__ = None;
+                let mut reasons__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            fee_content__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::DistributionsContent => {
-                            if distributions_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("distributionsContent"));
+                        GeneratedField::Error => {
+                            if error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("error"));
                             }
-                            distributions_content__ = map_.next_value()?;
+                            error__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::FundingContent => {
-                            if funding_content__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fundingContent"));
+                        GeneratedField::Reasons => {
+                            if reasons__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reasons"));
                             }
-                            funding_content__ = map_.next_value()?;
+                            reasons__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    chain_id: chain_id__.unwrap_or_default(),
-                    stake_content: stake_content__,
-                    shielded_pool_content: shielded_pool_content__,
-                    governance_content: governance_content__,
-                    ibc_content: ibc_content__,
-                    sct_content: sct_content__,
-                    community_pool_content: community_pool_content__,
-                    fee_content: fee_content__,
-                    distributions_content: distributions_content__,
-                    funding_content: funding_content__,
+                Ok(TransactionRejection {
+                    height: height__.unwrap_or_default(),
+                    error: error__.unwrap_or_default(),
+                    reasons: reasons__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.app.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.app.v1.TransactionRejection", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionRejectionRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.TransactionRejectionRequest", len)?;
+        if let Some(v) = self.tx_id.as_ref() {
+            struct_ser.serialize_field("txId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionRejectionRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_id",
+            "txId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txId" | "tx_id" => Ok(GeneratedField::TxId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionRejectionRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.TransactionRejectionRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionRejectionRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxId => {
+                            if tx_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txId"));
+                            }
+                            tx_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionRejectionRequest {
+                    tx_id: tx_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.TransactionRejectionRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionRejectionResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rejection.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.app.v1.TransactionRejectionResponse", len)?;
+        if let Some(v) = self.rejection.as_ref() {
+            struct_ser.serialize_field("rejection", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionRejectionResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rejection",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Rejection,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rejection" => Ok(GeneratedField::Rejection),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionRejectionResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.app.v1.TransactionRejectionResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionRejectionResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rejection__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Rejection => {
+                            if rejection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rejection"));
+                            }
+                            rejection__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionRejectionResponse {
+                    rejection: rejection__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.app.v1.TransactionRejectionResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for TransactionsByHeightRequest {
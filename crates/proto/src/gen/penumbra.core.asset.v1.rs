@@ -84,6 +84,15 @@ pub struct Metadata {
     pub penumbra_asset_id: ::core::option::Option<AssetId>,
     #[prost(message, repeated, tag = "1985")]
     pub images: ::prost::alloc::vec::Vec<AssetImage>,
+    /// A URI of an icon for the asset, for wallets to render without consulting an
+    /// external registry. If set, must use the `https` or `ipfs` scheme, and be at
+    /// most 2048 bytes long.
+    #[prost(string, tag = "1986")]
+    pub icon_uri: ::prost::alloc::string::String,
+    /// A URI of documentation about the asset, under the same constraints as the
+    /// `icon_uri`.
+    #[prost(string, tag = "1987")]
+    pub docs_uri: ::prost::alloc::string::String,
 }
 impl ::prost::Name for Metadata {
     const NAME: &'static str = "Metadata";
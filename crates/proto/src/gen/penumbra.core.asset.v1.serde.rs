@@ -1036,6 +1036,12 @@ impl serde::Serialize for Metadata {
         if !self.images.is_empty() {
             len += 1;
         }
+        if !self.icon_uri.is_empty() {
+            len += 1;
+        }
+        if !self.docs_uri.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.asset.v1.Metadata", len)?;
         if !self.description.is_empty() {
             struct_ser.serialize_field("description", &self.description)?;
@@ -1061,6 +1067,12 @@ impl serde::Serialize for Metadata {
         if !self.images.is_empty() {
             struct_ser.serialize_field("images", &self.images)?;
         }
+        if !self.icon_uri.is_empty() {
+            struct_ser.serialize_field("iconUri", &self.icon_uri)?;
+        }
+        if !self.docs_uri.is_empty() {
+            struct_ser.serialize_field("docsUri", &self.docs_uri)?;
+        }
         struct_ser.end()
     }
 }
@@ -1081,6 +1093,10 @@ impl<'de> serde::Deserialize<'de> for Metadata {
             "penumbra_asset_id",
             "penumbraAssetId",
             "images",
+            "icon_uri",
+            "iconUri",
+            "docs_uri",
+            "docsUri",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1093,6 +1109,8 @@ impl<'de> serde::Deserialize<'de> for Metadata {
             Symbol,
             PenumbraAssetId,
             Images,
+            IconUri,
+            DocsUri,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1123,6 +1141,8 @@ impl<'de> serde::Deserialize<'de> for Metadata {
                             "symbol" => Ok(GeneratedField::Symbol),
                             "penumbraAssetId" | "penumbra_asset_id" => Ok(GeneratedField::PenumbraAssetId),
                             "images" => Ok(GeneratedField::Images),
+                            "iconUri" | "icon_uri" => Ok(GeneratedField::IconUri),
+                            "docsUri" | "docs_uri" => Ok(GeneratedField::DocsUri),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1150,6 +1170,8 @@ impl<'de> serde::Deserialize<'de> for Metadata {
                 let mut symbol__ = None;
                 let mut penumbra_asset_id__ = None;
                 let mut images__ = None;
+                let mut icon_uri__ = None;
+                let mut docs_uri__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Description => {
@@ -1200,6 +1222,18 @@ impl<'de> serde::Deserialize<'de> for Metadata {
                             }
                             images__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::IconUri => {
+                            if icon_uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("iconUri"));
+                            }
+                            icon_uri__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DocsUri => {
+                            if docs_uri__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("docsUri"));
+                            }
+                            docs_uri__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1214,6 +1248,8 @@ impl<'de> serde::Deserialize<'de> for Metadata {
                     symbol: symbol__.unwrap_or_default(),
                     penumbra_asset_id: penumbra_asset_id__,
                     images: images__.unwrap_or_default(),
+                    icon_uri: icon_uri__.unwrap_or_default(),
+                    docs_uri: docs_uri__.unwrap_or_default(),
                 })
             }
         }
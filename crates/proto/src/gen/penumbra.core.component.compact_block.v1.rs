@@ -39,6 +39,11 @@ pub struct CompactBlock {
     /// Updated gas prices, if they have changed.
     #[prost(message, optional, tag = "10")]
     pub gas_prices: ::core::option::Option<super::super::fee::v1::GasPrices>,
+    /// The time of the block, as recorded in its header.
+    ///
+    /// Unset for the genesis block, and for blocks stored before this field was added.
+    #[prost(message, optional, tag = "11")]
+    pub block_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 impl ::prost::Name for CompactBlock {
     const NAME: &'static str = "CompactBlock";
@@ -146,6 +151,12 @@ pub struct CompactBlockRangeRequest {
     /// streaming new compact blocks as they are created.
     #[prost(bool, tag = "4")]
     pub keep_alive: bool,
+    /// The encoding the client would like to receive compact blocks in.
+    ///
+    /// Servers that do not support the requested encoding will fall back to
+    /// sending plain `CompactBlock`s, so clients must be prepared to handle either.
+    #[prost(enumeration = "CompactBlockEncoding", tag = "5")]
+    pub encoding: i32,
 }
 impl ::prost::Name for CompactBlockRangeRequest {
     const NAME: &'static str = "CompactBlockRangeRequest";
@@ -161,6 +172,14 @@ impl ::prost::Name for CompactBlockRangeRequest {
 pub struct CompactBlockRangeResponse {
     #[prost(message, optional, tag = "1")]
     pub compact_block: ::core::option::Option<CompactBlock>,
+    /// The encoding used for `encoded_compact_block`.
+    #[prost(enumeration = "CompactBlockEncoding", tag = "2")]
+    pub encoding: i32,
+    /// The compact block, encoded according to `encoding`.
+    ///
+    /// Only set if the server honored a non-default encoding request.
+    #[prost(bytes = "vec", tag = "3")]
+    pub encoded_compact_block: ::prost::alloc::vec::Vec<u8>,
 }
 impl ::prost::Name for CompactBlockRangeResponse {
     const NAME: &'static str = "CompactBlockRangeResponse";
@@ -201,6 +220,115 @@ impl ::prost::Name for CompactBlockResponse {
         )
     }
 }
+/// Requests a checkpoint to begin syncing from.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncCheckpointRequest {
+    /// The checkpoint returned is the latest one at or before this height.
+    ///
+    /// If unset, the latest checkpoint is returned.
+    #[prost(uint64, tag = "1")]
+    pub max_height: u64,
+}
+impl ::prost::Name for SyncCheckpointRequest {
+    const NAME: &'static str = "SyncCheckpointRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.compact_block.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.compact_block.v1.{}", Self::NAME
+        )
+    }
+}
+/// A checkpoint of the state commitment tree at the end of an epoch.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SyncCheckpointResponse {
+    /// The index of the epoch ending at this checkpoint.
+    #[prost(uint64, tag = "1")]
+    pub epoch_index: u64,
+    /// The height of the last block of the epoch.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The SCT anchor after the last block of the epoch.
+    #[prost(message, optional, tag = "3")]
+    pub anchor: ::core::option::Option<
+        super::super::super::super::crypto::tct::v1::MerkleRoot,
+    >,
+    /// The frontier of the state commitment tree at the checkpoint, serialized with bincode.
+    #[prost(bytes = "vec", tag = "4")]
+    pub state_commitment_tree: ::prost::alloc::vec::Vec<u8>,
+    /// A proof that `anchor` was recorded as the anchor for `height` in the chain state.
+    #[prost(message, optional, tag = "5")]
+    pub anchor_proof: ::core::option::Option<
+        ::ibc_proto::ibc::core::commitment::v1::MerkleProof,
+    >,
+    /// The height of the chain state that `anchor_proof` is against.
+    ///
+    /// The corresponding app hash is found in the header of the block at `proof_height + 1`.
+    #[prost(uint64, tag = "6")]
+    pub proof_height: u64,
+    /// The current FMD parameters.
+    #[prost(message, optional, tag = "7")]
+    pub fmd_parameters: ::core::option::Option<
+        super::super::shielded_pool::v1::FmdParameters,
+    >,
+    /// The current gas prices.
+    #[prost(message, optional, tag = "8")]
+    pub gas_prices: ::core::option::Option<super::super::fee::v1::GasPrices>,
+}
+impl ::prost::Name for SyncCheckpointResponse {
+    const NAME: &'static str = "SyncCheckpointResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.compact_block.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.compact_block.v1.{}", Self::NAME
+        )
+    }
+}
+/// An encoding used to transmit compact blocks over the `CompactBlockRange` stream.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ::prost::Enumeration
+)]
+#[repr(i32)]
+pub enum CompactBlockEncoding {
+    /// Plain `CompactBlock` messages, sent in the `compact_block` field of the response.
+    Unspecified = 0,
+    /// Delta-encoded `CompactBlock` messages, compressed with zstd, sent in the
+    /// `encoded_compact_block` field of the response.
+    ///
+    /// Delta encoding is stateful over the lifetime of the stream: fields that
+    /// can be recovered from the previously sent block (such as the block height)
+    /// are omitted.
+    ZstdDelta = 1,
+}
+impl CompactBlockEncoding {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CompactBlockEncoding::Unspecified => "COMPACT_BLOCK_ENCODING_UNSPECIFIED",
+            CompactBlockEncoding::ZstdDelta => "COMPACT_BLOCK_ENCODING_ZSTD_DELTA",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COMPACT_BLOCK_ENCODING_UNSPECIFIED" => Some(Self::Unspecified),
+            "COMPACT_BLOCK_ENCODING_ZSTD_DELTA" => Some(Self::ZstdDelta),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -352,6 +480,38 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Returns a checkpoint at an epoch boundary, from which a client can begin
+        /// syncing compact blocks instead of starting from genesis.
+        pub async fn sync_checkpoint(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SyncCheckpointRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SyncCheckpointResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.compact_block.v1.QueryService/SyncCheckpoint",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.compact_block.v1.QueryService",
+                        "SyncCheckpoint",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -389,6 +549,15 @@ pub mod query_service_server {
             tonic::Response<super::CompactBlockResponse>,
             tonic::Status,
         >;
+        /// Returns a checkpoint at an epoch boundary, from which a client can begin
+        /// syncing compact blocks instead of starting from genesis.
+        async fn sync_checkpoint(
+            &self,
+            request: tonic::Request<super::SyncCheckpointRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SyncCheckpointResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the compact block component.
     #[derive(Debug)]
@@ -565,6 +734,52 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.compact_block.v1.QueryService/SyncCheckpoint" => {
+                    #[allow(non_camel_case_types)]
+                    struct SyncCheckpointSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SyncCheckpointRequest>
+                    for SyncCheckpointSvc<T> {
+                        type Response = super::SyncCheckpointResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SyncCheckpointRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::sync_checkpoint(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SyncCheckpointSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -36,6 +36,9 @@ impl serde::Serialize for CompactBlock {
         if self.gas_prices.is_some() {
             len += 1;
         }
+        if self.block_time.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.CompactBlock", len)?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -68,6 +71,9 @@ impl serde::Serialize for CompactBlock {
         if let Some(v) = self.gas_prices.as_ref() {
             struct_ser.serialize_field("gasPrices", v)?;
         }
+        if let Some(v) = self.block_time.as_ref() {
+            struct_ser.serialize_field("blockTime", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -96,6 +102,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
             "appParametersUpdated",
             "gas_prices",
             "gasPrices",
+            "block_time",
+            "blockTime",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -110,6 +118,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
             SwapOutputs,
             AppParametersUpdated,
             GasPrices,
+            BlockTime,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -142,6 +151,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                             "swapOutputs" | "swap_outputs" => Ok(GeneratedField::SwapOutputs),
                             "appParametersUpdated" | "app_parameters_updated" => Ok(GeneratedField::AppParametersUpdated),
                             "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "blockTime" | "block_time" => Ok(GeneratedField::BlockTime),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -171,6 +181,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                 let mut swap_outputs__ = None;
                 let mut app_parameters_updated__ = None;
                 let mut gas_prices__ = None;
+                let mut block_time__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Height => {
@@ -235,6 +246,12 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                             }
                             gas_prices__ = map_.next_value()?;
                         }
+                        GeneratedField::BlockTime => {
+                            if block_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockTime"));
+                            }
+                            block_time__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -251,12 +268,84 @@ impl<'de> serde::Deserialize<'de> for CompactBlock {
                     swap_outputs: swap_outputs__.unwrap_or_default(),
                     app_parameters_updated: app_parameters_updated__.unwrap_or_default(),
                     gas_prices: gas_prices__,
+                    block_time: block_time__,
                 })
             }
         }
         deserializer.deserialize_struct("penumbra.core.component.compact_block.v1.CompactBlock", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CompactBlockEncoding {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "COMPACT_BLOCK_ENCODING_UNSPECIFIED",
+            Self::ZstdDelta => "COMPACT_BLOCK_ENCODING_ZSTD_DELTA",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for CompactBlockEncoding {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "COMPACT_BLOCK_ENCODING_UNSPECIFIED",
+            "COMPACT_BLOCK_ENCODING_ZSTD_DELTA",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CompactBlockEncoding;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "COMPACT_BLOCK_ENCODING_UNSPECIFIED" => Ok(CompactBlockEncoding::Unspecified),
+                    "COMPACT_BLOCK_ENCODING_ZSTD_DELTA" => Ok(CompactBlockEncoding::ZstdDelta),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
 impl serde::Serialize for CompactBlockRangeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -274,6 +363,9 @@ impl serde::Serialize for CompactBlockRangeRequest {
         if self.keep_alive {
             len += 1;
         }
+        if self.encoding != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.CompactBlockRangeRequest", len)?;
         if self.start_height != 0 {
             #[allow(clippy::needless_borrow)]
@@ -286,6 +378,11 @@ impl serde::Serialize for CompactBlockRangeRequest {
         if self.keep_alive {
             struct_ser.serialize_field("keepAlive", &self.keep_alive)?;
         }
+        if self.encoding != 0 {
+            let v = CompactBlockEncoding::try_from(self.encoding)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.encoding)))?;
+            struct_ser.serialize_field("encoding", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -302,6 +399,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeRequest {
             "endHeight",
             "keep_alive",
             "keepAlive",
+            "encoding",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -309,6 +407,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeRequest {
             StartHeight,
             EndHeight,
             KeepAlive,
+            Encoding,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -334,6 +433,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeRequest {
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
                             "keepAlive" | "keep_alive" => Ok(GeneratedField::KeepAlive),
+                            "encoding" => Ok(GeneratedField::Encoding),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -356,6 +456,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeRequest {
                 let mut start_height__ = None;
                 let mut end_height__ = None;
                 let mut keep_alive__ = None;
+                let mut encoding__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::StartHeight => {
@@ -380,6 +481,12 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeRequest {
                             }
                             keep_alive__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::Encoding => {
+                            if encoding__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encoding"));
+                            }
+                            encoding__ = Some(map_.next_value::<CompactBlockEncoding>()? as i32);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -389,6 +496,7 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeRequest {
                     start_height: start_height__.unwrap_or_default(),
                     end_height: end_height__.unwrap_or_default(),
                     keep_alive: keep_alive__.unwrap_or_default(),
+                    encoding: encoding__.unwrap_or_default(),
                 })
             }
         }
@@ -406,10 +514,25 @@ impl serde::Serialize for CompactBlockRangeResponse {
         if self.compact_block.is_some() {
             len += 1;
         }
+        if self.encoding != 0 {
+            len += 1;
+        }
+        if !self.encoded_compact_block.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.CompactBlockRangeResponse", len)?;
         if let Some(v) = self.compact_block.as_ref() {
             struct_ser.serialize_field("compactBlock", v)?;
         }
+        if self.encoding != 0 {
+            let v = CompactBlockEncoding::try_from(self.encoding)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.encoding)))?;
+            struct_ser.serialize_field("encoding", &v)?;
+        }
+        if !self.encoded_compact_block.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("encodedCompactBlock", pbjson::private::base64::encode(&self.encoded_compact_block).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -422,11 +545,16 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeResponse {
         const FIELDS: &[&str] = &[
             "compact_block",
             "compactBlock",
+            "encoding",
+            "encoded_compact_block",
+            "encodedCompactBlock",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             CompactBlock,
+            Encoding,
+            EncodedCompactBlock,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -450,6 +578,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeResponse {
                     {
                         match value {
                             "compactBlock" | "compact_block" => Ok(GeneratedField::CompactBlock),
+                            "encoding" => Ok(GeneratedField::Encoding),
+                            "encodedCompactBlock" | "encoded_compact_block" => Ok(GeneratedField::EncodedCompactBlock),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -470,6 +600,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeResponse {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut compact_block__ = None;
+                let mut encoding__ = None;
+                let mut encoded_compact_block__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::CompactBlock => {
@@ -478,6 +610,20 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeResponse {
                             }
                             compact_block__ = map_.next_value()?;
                         }
+                        GeneratedField::Encoding => {
+                            if encoding__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encoding"));
+                            }
+                            encoding__ = Some(map_.next_value::<CompactBlockEncoding>()? as i32);
+                        }
+                        GeneratedField::EncodedCompactBlock => {
+                            if encoded_compact_block__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("encodedCompactBlock"));
+                            }
+                            encoded_compact_block__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -485,6 +631,8 @@ impl<'de> serde::Deserialize<'de> for CompactBlockRangeResponse {
                 }
                 Ok(CompactBlockRangeResponse {
                     compact_block: compact_block__,
+                    encoding: encoding__.unwrap_or_default(),
+                    encoded_compact_block: encoded_compact_block__.unwrap_or_default(),
                 })
             }
         }
@@ -1114,3 +1262,334 @@ impl<'de> serde::Deserialize<'de> for state_payload::Swap {
         deserializer.deserialize_struct("penumbra.core.component.compact_block.v1.StatePayload.Swap", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for SyncCheckpointRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.max_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.SyncCheckpointRequest", len)?;
+        if self.max_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("maxHeight", ToString::to_string(&self.max_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SyncCheckpointRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "max_height",
+            "maxHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MaxHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "maxHeight" | "max_height" => Ok(GeneratedField::MaxHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SyncCheckpointRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.compact_block.v1.SyncCheckpointRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SyncCheckpointRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut max_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MaxHeight => {
+                            if max_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxHeight"));
+                            }
+                            max_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SyncCheckpointRequest {
+                    max_height: max_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.compact_block.v1.SyncCheckpointRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SyncCheckpointResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.anchor.is_some() {
+            len += 1;
+        }
+        if !self.state_commitment_tree.is_empty() {
+            len += 1;
+        }
+        if self.anchor_proof.is_some() {
+            len += 1;
+        }
+        if self.proof_height != 0 {
+            len += 1;
+        }
+        if self.fmd_parameters.is_some() {
+            len += 1;
+        }
+        if self.gas_prices.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.compact_block.v1.SyncCheckpointResponse", len)?;
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        if !self.state_commitment_tree.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("stateCommitmentTree", pbjson::private::base64::encode(&self.state_commitment_tree).as_str())?;
+        }
+        if let Some(v) = self.anchor_proof.as_ref() {
+            struct_ser.serialize_field("anchorProof", v)?;
+        }
+        if self.proof_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("proofHeight", ToString::to_string(&self.proof_height).as_str())?;
+        }
+        if let Some(v) = self.fmd_parameters.as_ref() {
+            struct_ser.serialize_field("fmdParameters", v)?;
+        }
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SyncCheckpointResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "epoch_index",
+            "epochIndex",
+            "height",
+            "anchor",
+            "state_commitment_tree",
+            "stateCommitmentTree",
+            "anchor_proof",
+            "anchorProof",
+            "proof_height",
+            "proofHeight",
+            "fmd_parameters",
+            "fmdParameters",
+            "gas_prices",
+            "gasPrices",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            EpochIndex,
+            Height,
+            Anchor,
+            StateCommitmentTree,
+            AnchorProof,
+            ProofHeight,
+            FmdParameters,
+            GasPrices,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "height" => Ok(GeneratedField::Height),
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "stateCommitmentTree" | "state_commitment_tree" => Ok(GeneratedField::StateCommitmentTree),
+                            "anchorProof" | "anchor_proof" => Ok(GeneratedField::AnchorProof),
+                            "proofHeight" | "proof_height" => Ok(GeneratedField::ProofHeight),
+                            "fmdParameters" | "fmd_parameters" => Ok(GeneratedField::FmdParameters),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SyncCheckpointResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.compact_block.v1.SyncCheckpointResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SyncCheckpointResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut epoch_index__ = None;
+                let mut height__ = None;
+                let mut anchor__ = None;
+                let mut state_commitment_tree__ = None;
+                let mut anchor_proof__ = None;
+                let mut proof_height__ = None;
+                let mut fmd_parameters__ = None;
+                let mut gas_prices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
+                            }
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::StateCommitmentTree => {
+                            if state_commitment_tree__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateCommitmentTree"));
+                            }
+                            state_commitment_tree__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::AnchorProof => {
+                            if anchor_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchorProof"));
+                            }
+                            anchor_proof__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProofHeight => {
+                            if proof_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proofHeight"));
+                            }
+                            proof_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FmdParameters => {
+                            if fmd_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fmdParameters"));
+                            }
+                            fmd_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                            }
+                            gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SyncCheckpointResponse {
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    height: height__.unwrap_or_default(),
+                    anchor: anchor__,
+                    state_commitment_tree: state_commitment_tree__.unwrap_or_default(),
+                    anchor_proof: anchor_proof__,
+                    proof_height: proof_height__.unwrap_or_default(),
+                    fmd_parameters: fmd_parameters__,
+                    gas_prices: gas_prices__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.compact_block.v1.SyncCheckpointResponse", FIELDS, GeneratedVisitor)
+    }
+}
@@ -123,6 +123,11 @@ pub struct SwapBody {
     /// The swap commitment and encryption of the swap data.
     #[prost(message, optional, tag = "5")]
     pub payload: ::core::option::Option<SwapPayload>,
+    /// How the swap prefers its batch to be routed.
+    ///
+    /// If absent, the chain's default preference applies.
+    #[prost(message, optional, tag = "6")]
+    pub routing_preference: ::core::option::Option<RoutingPreference>,
 }
 impl ::prost::Name for SwapBody {
     const NAME: &'static str = "SwapBody";
@@ -148,6 +153,28 @@ impl ::prost::Name for SwapPayload {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+/// A swap's preference for how the input of its batch is routed.
+///
+/// Each direction of a batch is routed with the preferences of its swaps averaged, weighted by
+/// their inputs.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RoutingPreference {
+    /// The penalty, in basis points, applied to the estimated price of a route for each hop beyond
+    /// the first.
+    ///
+    /// Routing prefers direct liquidity unless a longer route is better by more than the penalty,
+    /// trading execution price for fewer hops. At most 10000 (100%).
+    #[prost(uint32, tag = "1")]
+    pub multi_hop_penalty_bps: u32,
+}
+impl ::prost::Name for RoutingPreference {
+    const NAME: &'static str = "RoutingPreference";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SwapPlaintext {
@@ -192,6 +219,9 @@ pub struct SwapPlan {
     /// The second blinding factor to use for the ZK swap proof.
     #[prost(bytes = "vec", tag = "4")]
     pub proof_blinding_s: ::prost::alloc::vec::Vec<u8>,
+    /// How the swap prefers its batch to be routed, if not the chain's default.
+    #[prost(message, optional, tag = "5")]
+    pub routing_preference: ::core::option::Option<RoutingPreference>,
 }
 impl ::prost::Name for SwapPlan {
     const NAME: &'static str = "SwapPlan";
@@ -568,6 +598,11 @@ pub struct Position {
     /// / immediately after being filled.
     #[prost(bool, tag = "5")]
     pub close_on_fill: bool,
+    /// If set, the position is closed at the end of any block in which its reserves of
+    /// either asset are below the corresponding threshold here. A zero threshold is
+    /// ignored.
+    #[prost(message, optional, tag = "6")]
+    pub close_below: ::core::option::Option<Reserves>,
 }
 impl ::prost::Name for Position {
     const NAME: &'static str = "Position";
@@ -1143,6 +1178,64 @@ impl ::prost::Name for LiquidityPositionsByPriceResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportPositionsRequest {
+    /// The height of the snapshot to export positions from, or 0 for the latest.
+    ///
+    /// Only the snapshots of recent heights are retained, so to page through a
+    /// large export, use the height of the first page for the following ones.
+    #[prost(uint64, tag = "1")]
+    pub snapshot_height: u64,
+    /// If nonzero, only export the positions updated after this height, including
+    /// those closed or withdrawn since. Updates are only kept for a limited number
+    /// of blocks, and older heights are rejected.
+    #[prost(uint64, tag = "2")]
+    pub updated_since_height: u64,
+    /// If set, resume the export after this position, which should be the last
+    /// position of the previous page of the same export.
+    #[prost(message, optional, tag = "3")]
+    pub start_after: ::core::option::Option<PositionId>,
+    /// The maximum number of positions to return, or 0 for all of them.
+    #[prost(uint64, tag = "4")]
+    pub limit: u64,
+    /// If true, include closed and withdrawn positions. They are always included
+    /// when `updated_since_height` is set.
+    #[prost(bool, tag = "5")]
+    pub include_closed: bool,
+}
+impl ::prost::Name for ExportPositionsRequest {
+    const NAME: &'static str = "ExportPositionsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportPositionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<PositionId>,
+    /// The position, including its trading pair, fee and reserves.
+    #[prost(message, optional, tag = "2")]
+    pub position: ::core::option::Option<Position>,
+    /// An approximation of the effective price of the position when trading 1 => 2.
+    #[prost(double, tag = "3")]
+    pub approx_effective_price_1_to_2: f64,
+    /// An approximation of the effective price of the position when trading 2 => 1.
+    #[prost(double, tag = "4")]
+    pub approx_effective_price_2_to_1: f64,
+    /// The height of the snapshot the position was exported from.
+    #[prost(uint64, tag = "5")]
+    pub snapshot_height: u64,
+}
+impl ::prost::Name for ExportPositionsResponse {
+    const NAME: &'static str = "ExportPositionsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpreadRequest {
     #[prost(message, optional, tag = "2")]
     pub trading_pair: ::core::option::Option<TradingPair>,
@@ -1179,6 +1272,103 @@ impl ::prost::Name for SpreadResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DexStatusRequest {}
+impl ::prost::Name for DexStatusRequest {
+    const NAME: &'static str = "DexStatusRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DexStatusResponse {
+    /// Whether opening new positions and swapping are paused by governance.
+    #[prost(bool, tag = "1")]
+    pub is_paused: bool,
+}
+impl ::prost::Name for DexStatusResponse {
+    const NAME: &'static str = "DexStatusResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PairStatsRequest {
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+}
+impl ::prost::Name for PairStatsRequest {
+    const NAME: &'static str = "PairStatsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PairStatsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub stats: ::core::option::Option<PairStats>,
+}
+impl ::prost::Name for PairStatsResponse {
+    const NAME: &'static str = "PairStatsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Statistics of the trading on a pair.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PairStats {
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// Trading in the 24 hours up to the latest block.
+    #[prost(message, optional, tag = "2")]
+    pub last_24h: ::core::option::Option<PairStatsWindow>,
+    /// Trading in the current epoch.
+    #[prost(message, optional, tag = "3")]
+    pub epoch: ::core::option::Option<PairStatsWindow>,
+}
+impl ::prost::Name for PairStats {
+    const NAME: &'static str = "PairStats";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// Statistics of the trading on a pair over some period.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PairStatsWindow {
+    /// The amount of asset 1 traded by the pair's batch swaps, whether sold or bought.
+    #[prost(message, optional, tag = "1")]
+    pub volume_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of asset 2 traded by the pair's batch swaps, whether sold or bought.
+    #[prost(message, optional, tag = "2")]
+    pub volume_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The fees paid in asset 1 to the pair's liquidity positions, on any route.
+    #[prost(message, optional, tag = "3")]
+    pub lp_fees_1: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The fees paid in asset 2 to the pair's liquidity positions, on any route.
+    #[prost(message, optional, tag = "4")]
+    pub lp_fees_2: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The number of the pair's batch swaps executed.
+    #[prost(uint64, tag = "5")]
+    pub batches: u64,
+}
+impl ::prost::Name for PairStatsWindow {
+    const NAME: &'static str = "PairStatsWindow";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SimulateTradeRequest {
     #[prost(message, optional, tag = "1")]
     pub input: ::core::option::Option<super::super::super::asset::v1::Value>,
@@ -1257,6 +1447,10 @@ pub struct SimulateTradeResponse {
     /// Estimated input amount that will not be swapped due to liquidity
     #[prost(message, optional, tag = "2")]
     pub unfilled: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The price impact of the trade, in basis points: how much worse its average price is than
+    /// the price of its best-priced trace. Zero if nothing could be filled.
+    #[prost(uint32, tag = "3")]
+    pub price_impact_bps: u32,
 }
 impl ::prost::Name for SimulateTradeResponse {
     const NAME: &'static str = "SimulateTradeResponse";
@@ -1409,6 +1603,23 @@ impl ::prost::Name for EventPositionExecution {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventRouteFill {
+    /// The amounts traded at each hop: the input, each intermediate amount, and the output.
+    #[prost(message, repeated, tag = "1")]
+    pub trace: ::prost::alloc::vec::Vec<super::super::super::asset::v1::Value>,
+    /// The positions traded against, one per hop.
+    #[prost(message, repeated, tag = "2")]
+    pub position_ids: ::prost::alloc::vec::Vec<PositionId>,
+}
+impl ::prost::Name for EventRouteFill {
+    const NAME: &'static str = "EventRouteFill";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventBatchSwap {
     /// The BatchSwapOutputData containing the results of the batch swap.
     #[prost(message, optional, tag = "1")]
@@ -1444,6 +1655,71 @@ impl ::prost::Name for EventArbExecution {
         ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventDexParametersUpdate {
+    /// The new DEX parameters.
+    #[prost(message, optional, tag = "1")]
+    pub new_parameters: ::core::option::Option<DexParameters>,
+}
+impl ::prost::Name for EventDexParametersUpdate {
+    const NAME: &'static str = "EventDexParametersUpdate";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// The configuration parameters for the DEX component.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DexParameters {
+    /// Whether opening new positions and swapping are paused.
+    ///
+    /// Closing and withdrawing positions and claiming swaps remain possible while paused.
+    #[prost(bool, tag = "1")]
+    pub is_paused: bool,
+    /// The minimum amount of each asset a newly opened position may provision.
+    ///
+    /// Positions may still provision none of one of the assets. A missing or zero
+    /// amount sets no minimum.
+    #[prost(message, optional, tag = "2")]
+    pub min_position_reserves: ::core::option::Option<
+        super::super::super::num::v1::Amount,
+    >,
+    /// The minimum fee, in basis points, a newly opened position may charge.
+    #[prost(uint32, tag = "3")]
+    pub min_position_fee_bps: u32,
+    /// The maximum fee, in basis points, a newly opened position may charge.
+    ///
+    /// Zero sets no maximum, beyond the 50% (5000bps) all positions are limited to.
+    #[prost(uint32, tag = "4")]
+    pub max_position_fee_bps: u32,
+    /// The multi-hop penalty, in basis points, of swaps that don't set a routing preference.
+    #[prost(uint32, tag = "5")]
+    pub default_multi_hop_penalty_bps: u32,
+}
+impl ::prost::Name for DexParameters {
+    const NAME: &'static str = "DexParameters";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
+/// DEX genesis state.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenesisContent {
+    /// The initial parameters for the DEX component.
+    #[prost(message, optional, tag = "1")]
+    pub dex_params: ::core::option::Option<DexParameters>,
+}
+impl ::prost::Name for GenesisContent {
+    const NAME: &'static str = "GenesisContent";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.dex.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -1816,6 +2092,42 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Export the liquidity positions on the DEX, for mirroring the order book.
+        ///
+        /// Positions are read from a single snapshot of the state, in a stable order,
+        /// and can be paged through with `limit` and `start_after`. Passing
+        /// `updated_since_height` exports only the positions that changed since a
+        /// previous export, to update a mirror incrementally.
+        pub async fn export_positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ExportPositionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/ExportPositions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "ExportPositions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Get the current (direct) spread on a trading pair.
         ///
         /// This method doesn't do simulation, so actually executing might result in a
@@ -1847,6 +2159,66 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Get the current status of the DEX, including whether it is paused.
+        pub async fn dex_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DexStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DexStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/DexStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "DexStatus",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get the trading volume, fees paid to liquidity providers, and number of batches executed
+        /// for a trading pair, over the last 24 hours and the current epoch.
+        pub async fn pair_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PairStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PairStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/PairStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.core.component.dex.v1.QueryService", "PairStats"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -2094,6 +2466,25 @@ pub mod query_service_server {
             tonic::Response<Self::LiquidityPositionsByPriceStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ExportPositions method.
+        type ExportPositionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ExportPositionsResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// Export the liquidity positions on the DEX, for mirroring the order book.
+        ///
+        /// Positions are read from a single snapshot of the state, in a stable order,
+        /// and can be paged through with `limit` and `start_after`. Passing
+        /// `updated_since_height` exports only the positions that changed since a
+        /// previous export, to update a mirror incrementally.
+        async fn export_positions(
+            &self,
+            request: tonic::Request<super::ExportPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ExportPositionsStream>,
+            tonic::Status,
+        >;
         /// Get the current (direct) spread on a trading pair.
         ///
         /// This method doesn't do simulation, so actually executing might result in a
@@ -2102,6 +2493,23 @@ pub mod query_service_server {
             &self,
             request: tonic::Request<super::SpreadRequest>,
         ) -> std::result::Result<tonic::Response<super::SpreadResponse>, tonic::Status>;
+        /// Get the current status of the DEX, including whether it is paused.
+        async fn dex_status(
+            &self,
+            request: tonic::Request<super::DexStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DexStatusResponse>,
+            tonic::Status,
+        >;
+        /// Get the trading volume, fees paid to liquidity providers, and number of batches executed
+        /// for a trading pair, over the last 24 hours and the current epoch.
+        async fn pair_stats(
+            &self,
+            request: tonic::Request<super::PairStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PairStatsResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the DEX component.
     #[derive(Debug)]
@@ -2621,6 +3029,53 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/ExportPositions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportPositionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ExportPositionsRequest>
+                    for ExportPositionsSvc<T> {
+                        type Response = super::ExportPositionsResponse;
+                        type ResponseStream = T::ExportPositionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportPositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::export_positions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportPositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.core.component.dex.v1.QueryService/Spread" => {
                     #[allow(non_camel_case_types)]
                     struct SpreadSvc<T: QueryService>(pub Arc<T>);
@@ -2667,6 +3122,98 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/DexStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct DexStatusSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::DexStatusRequest>
+                    for DexStatusSvc<T> {
+                        type Response = super::DexStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DexStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::dex_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DexStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/PairStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct PairStatsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::PairStatsRequest>
+                    for PairStatsSvc<T> {
+                        type Response = super::PairStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PairStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::pair_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = PairStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
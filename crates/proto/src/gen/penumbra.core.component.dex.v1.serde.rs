@@ -1035,6 +1035,348 @@ impl<'de> serde::Deserialize<'de> for BatchSwapOutputDataResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.BatchSwapOutputDataResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for DexParameters {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.is_paused {
+            len += 1;
+        }
+        if self.min_position_reserves.is_some() {
+            len += 1;
+        }
+        if self.min_position_fee_bps != 0 {
+            len += 1;
+        }
+        if self.max_position_fee_bps != 0 {
+            len += 1;
+        }
+        if self.default_multi_hop_penalty_bps != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
+        if self.is_paused {
+            struct_ser.serialize_field("isPaused", &self.is_paused)?;
+        }
+        if let Some(v) = self.min_position_reserves.as_ref() {
+            struct_ser.serialize_field("minPositionReserves", v)?;
+        }
+        if self.min_position_fee_bps != 0 {
+            struct_ser.serialize_field("minPositionFeeBps", &self.min_position_fee_bps)?;
+        }
+        if self.max_position_fee_bps != 0 {
+            struct_ser.serialize_field("maxPositionFeeBps", &self.max_position_fee_bps)?;
+        }
+        if self.default_multi_hop_penalty_bps != 0 {
+            struct_ser.serialize_field("defaultMultiHopPenaltyBps", &self.default_multi_hop_penalty_bps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DexParameters {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "is_paused",
+            "isPaused",
+            "min_position_reserves",
+            "minPositionReserves",
+            "min_position_fee_bps",
+            "minPositionFeeBps",
+            "max_position_fee_bps",
+            "maxPositionFeeBps",
+            "default_multi_hop_penalty_bps",
+            "defaultMultiHopPenaltyBps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IsPaused,
+            MinPositionReserves,
+            MinPositionFeeBps,
+            MaxPositionFeeBps,
+            DefaultMultiHopPenaltyBps,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "isPaused" | "is_paused" => Ok(GeneratedField::IsPaused),
+                            "minPositionReserves" | "min_position_reserves" => Ok(GeneratedField::MinPositionReserves),
+                            "minPositionFeeBps" | "min_position_fee_bps" => Ok(GeneratedField::MinPositionFeeBps),
+                            "maxPositionFeeBps" | "max_position_fee_bps" => Ok(GeneratedField::MaxPositionFeeBps),
+                            "defaultMultiHopPenaltyBps" | "default_multi_hop_penalty_bps" => Ok(GeneratedField::DefaultMultiHopPenaltyBps),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DexParameters;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DexParameters")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexParameters, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut is_paused__ = None;
+                let mut min_position_reserves__ = None;
+                let mut min_position_fee_bps__ = None;
+                let mut max_position_fee_bps__ = None;
+                let mut default_multi_hop_penalty_bps__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IsPaused => {
+                            if is_paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isPaused"));
+                            }
+                            is_paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::MinPositionReserves => {
+                            if min_position_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minPositionReserves"));
+                            }
+                            min_position_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinPositionFeeBps => {
+                            if min_position_fee_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minPositionFeeBps"));
+                            }
+                            min_position_fee_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MaxPositionFeeBps => {
+                            if max_position_fee_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxPositionFeeBps"));
+                            }
+                            max_position_fee_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DefaultMultiHopPenaltyBps => {
+                            if default_multi_hop_penalty_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("defaultMultiHopPenaltyBps"));
+                            }
+                            default_multi_hop_penalty_bps__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DexParameters {
+                    is_paused: is_paused__.unwrap_or_default(),
+                    min_position_reserves: min_position_reserves__,
+                    min_position_fee_bps: min_position_fee_bps__.unwrap_or_default(),
+                    max_position_fee_bps: max_position_fee_bps__.unwrap_or_default(),
+                    default_multi_hop_penalty_bps: default_multi_hop_penalty_bps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexParameters", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DexStatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexStatusRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DexStatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DexStatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DexStatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexStatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(DexStatusRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexStatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DexStatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.is_paused {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexStatusResponse", len)?;
+        if self.is_paused {
+            struct_ser.serialize_field("isPaused", &self.is_paused)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DexStatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "is_paused",
+            "isPaused",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IsPaused,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "isPaused" | "is_paused" => Ok(GeneratedField::IsPaused),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DexStatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DexStatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DexStatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut is_paused__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IsPaused => {
+                            if is_paused__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("isPaused"));
+                            }
+                            is_paused__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DexStatusResponse {
+                    is_paused: is_paused__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DexStatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DirectedTradingPair {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1395,7 +1737,7 @@ impl<'de> serde::Deserialize<'de> for EventBatchSwap {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventBatchSwap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventPositionClose {
+impl serde::Serialize for EventDexParametersUpdate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1403,30 +1745,30 @@ impl serde::Serialize for EventPositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.new_parameters.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventDexParametersUpdate", len)?;
+        if let Some(v) = self.new_parameters.as_ref() {
+            struct_ser.serialize_field("newParameters", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventPositionClose {
+impl<'de> serde::Deserialize<'de> for EventDexParametersUpdate {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "new_parameters",
+            "newParameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            NewParameters,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1449,7 +1791,7 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "newParameters" | "new_parameters" => Ok(GeneratedField::NewParameters),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1459,21 +1801,117 @@ impl<'de> serde::Deserialize<'de> for EventPositionClose {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventPositionClose;
+            type Value = EventDexParametersUpdate;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventDexParametersUpdate")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDexParametersUpdate, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut new_parameters__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
+                        GeneratedField::NewParameters => {
+                            if new_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newParameters"));
+                            }
+                            new_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDexParametersUpdate {
+                    new_parameters: new_parameters__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventDexParametersUpdate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventPositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventPositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventPositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventPositionClose;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventPositionClose")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventPositionClose, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("positionId"));
                             }
                             position_id__ = map_.next_value()?;
@@ -1981,6 +2419,119 @@ impl<'de> serde::Deserialize<'de> for EventPositionWithdraw {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventPositionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for EventRouteFill {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.trace.is_empty() {
+            len += 1;
+        }
+        if !self.position_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.EventRouteFill", len)?;
+        if !self.trace.is_empty() {
+            struct_ser.serialize_field("trace", &self.trace)?;
+        }
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventRouteFill {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trace",
+            "position_ids",
+            "positionIds",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Trace,
+            PositionIds,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "trace" => Ok(GeneratedField::Trace),
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventRouteFill;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventRouteFill")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventRouteFill, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trace__ = None;
+                let mut position_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Trace => {
+                            if trace__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trace"));
+                            }
+                            trace__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                            }
+                            position_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventRouteFill {
+                    trace: trace__.unwrap_or_default(),
+                    position_ids: position_ids__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventRouteFill", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for EventSwap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2185,10 +2736,864 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Output1Commitment,
-            Output2Commitment,
-            Nullifier,
+            TradingPair,
+            Output1Commitment,
+            Output2Commitment,
+            Nullifier,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
+                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventSwapClaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut output_1_commitment__ = None;
+                let mut output_2_commitment__ = None;
+                let mut nullifier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output1Commitment => {
+                            if output_1_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
+                            }
+                            output_1_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Output2Commitment => {
+                            if output_2_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
+                            }
+                            output_2_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventSwapClaim {
+                    trading_pair: trading_pair__,
+                    output_1_commitment: output_1_commitment__,
+                    output_2_commitment: output_2_commitment__,
+                    nullifier: nullifier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportPositionsRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.snapshot_height != 0 {
+            len += 1;
+        }
+        if self.updated_since_height != 0 {
+            len += 1;
+        }
+        if self.start_after.is_some() {
+            len += 1;
+        }
+        if self.limit != 0 {
+            len += 1;
+        }
+        if self.include_closed {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ExportPositionsRequest", len)?;
+        if self.snapshot_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("snapshotHeight", ToString::to_string(&self.snapshot_height).as_str())?;
+        }
+        if self.updated_since_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("updatedSinceHeight", ToString::to_string(&self.updated_since_height).as_str())?;
+        }
+        if let Some(v) = self.start_after.as_ref() {
+            struct_ser.serialize_field("startAfter", v)?;
+        }
+        if self.limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+        }
+        if self.include_closed {
+            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportPositionsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "snapshot_height",
+            "snapshotHeight",
+            "updated_since_height",
+            "updatedSinceHeight",
+            "start_after",
+            "startAfter",
+            "limit",
+            "include_closed",
+            "includeClosed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SnapshotHeight,
+            UpdatedSinceHeight,
+            StartAfter,
+            Limit,
+            IncludeClosed,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "snapshotHeight" | "snapshot_height" => Ok(GeneratedField::SnapshotHeight),
+                            "updatedSinceHeight" | "updated_since_height" => Ok(GeneratedField::UpdatedSinceHeight),
+                            "startAfter" | "start_after" => Ok(GeneratedField::StartAfter),
+                            "limit" => Ok(GeneratedField::Limit),
+                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportPositionsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.ExportPositionsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportPositionsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut snapshot_height__ = None;
+                let mut updated_since_height__ = None;
+                let mut start_after__ = None;
+                let mut limit__ = None;
+                let mut include_closed__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SnapshotHeight => {
+                            if snapshot_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("snapshotHeight"));
+                            }
+                            snapshot_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UpdatedSinceHeight => {
+                            if updated_since_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("updatedSinceHeight"));
+                            }
+                            updated_since_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StartAfter => {
+                            if start_after__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startAfter"));
+                            }
+                            start_after__ = map_.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::IncludeClosed => {
+                            if include_closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeClosed"));
+                            }
+                            include_closed__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExportPositionsRequest {
+                    snapshot_height: snapshot_height__.unwrap_or_default(),
+                    updated_since_height: updated_since_height__.unwrap_or_default(),
+                    start_after: start_after__,
+                    limit: limit__.unwrap_or_default(),
+                    include_closed: include_closed__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ExportPositionsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ExportPositionsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.position.is_some() {
+            len += 1;
+        }
+        if self.approx_effective_price_1_to_2 != 0. {
+            len += 1;
+        }
+        if self.approx_effective_price_2_to_1 != 0. {
+            len += 1;
+        }
+        if self.snapshot_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.ExportPositionsResponse", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        if self.approx_effective_price_1_to_2 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice1To2", &self.approx_effective_price_1_to_2)?;
+        }
+        if self.approx_effective_price_2_to_1 != 0. {
+            struct_ser.serialize_field("approxEffectivePrice2To1", &self.approx_effective_price_2_to_1)?;
+        }
+        if self.snapshot_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("snapshotHeight", ToString::to_string(&self.snapshot_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ExportPositionsResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "position",
+            "approx_effective_price_1_to_2",
+            "approxEffectivePrice1To2",
+            "approx_effective_price_2_to_1",
+            "approxEffectivePrice2To1",
+            "snapshot_height",
+            "snapshotHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Position,
+            ApproxEffectivePrice1To2,
+            ApproxEffectivePrice2To1,
+            SnapshotHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "position" => Ok(GeneratedField::Position),
+                            "approxEffectivePrice1To2" | "approx_effective_price_1_to_2" => Ok(GeneratedField::ApproxEffectivePrice1To2),
+                            "approxEffectivePrice2To1" | "approx_effective_price_2_to_1" => Ok(GeneratedField::ApproxEffectivePrice2To1),
+                            "snapshotHeight" | "snapshot_height" => Ok(GeneratedField::SnapshotHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ExportPositionsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.ExportPositionsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportPositionsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut position__ = None;
+                let mut approx_effective_price_1_to_2__ = None;
+                let mut approx_effective_price_2_to_1__ = None;
+                let mut snapshot_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::ApproxEffectivePrice1To2 => {
+                            if approx_effective_price_1_to_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice1To2"));
+                            }
+                            approx_effective_price_1_to_2__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ApproxEffectivePrice2To1 => {
+                            if approx_effective_price_2_to_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("approxEffectivePrice2To1"));
+                            }
+                            approx_effective_price_2_to_1__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SnapshotHeight => {
+                            if snapshot_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("snapshotHeight"));
+                            }
+                            snapshot_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ExportPositionsResponse {
+                    id: id__,
+                    position: position__,
+                    approx_effective_price_1_to_2: approx_effective_price_1_to_2__.unwrap_or_default(),
+                    approx_effective_price_2_to_1: approx_effective_price_2_to_1__.unwrap_or_default(),
+                    snapshot_height: snapshot_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.ExportPositionsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.dex_params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.GenesisContent", len)?;
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "dex_params",
+            "dexParams",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            DexParams,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut dex_params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    dex_params: dex_params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionByIdRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionByIdRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionByIdRequest {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionByIdResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionByIdResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionByIdResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByIdRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
+        if !self.position_id.is_empty() {
+            struct_ser.serialize_field("positionId", &self.position_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsByIdRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsByIdRequest {
+                    position_id: position_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByIdResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2211,10 +3616,7 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "output1Commitment" | "output_1_commitment" => Ok(GeneratedField::Output1Commitment),
-                            "output2Commitment" | "output_2_commitment" => Ok(GeneratedField::Output2Commitment),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "data" => Ok(GeneratedField::Data),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2224,63 +3626,39 @@ impl<'de> serde::Deserialize<'de> for EventSwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventSwapClaim;
+            type Value = LiquidityPositionsByIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.EventSwapClaim")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventSwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut output_1_commitment__ = None;
-                let mut output_2_commitment__ = None;
-                let mut nullifier__ = None;
+                let mut data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
-                            }
-                            trading_pair__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output1Commitment => {
-                            if output_1_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output1Commitment"));
-                            }
-                            output_1_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Output2Commitment => {
-                            if output_2_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("output2Commitment"));
-                            }
-                            output_2_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
                             }
-                            nullifier__ = map_.next_value()?;
+                            data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventSwapClaim {
-                    trading_pair: trading_pair__,
-                    output_1_commitment: output_1_commitment__,
-                    output_2_commitment: output_2_commitment__,
-                    nullifier: nullifier__,
+                Ok(LiquidityPositionsByIdResponse {
+                    data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.EventSwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdRequest {
+impl serde::Serialize for LiquidityPositionsByPriceRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2288,30 +3666,39 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.limit != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.limit != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "trading_pair",
+            "tradingPair",
+            "limit",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            TradingPair,
+            Limit,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2334,7 +3721,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "limit" => Ok(GeneratedField::Limit),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2344,39 +3732,49 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdRequest;
+            type Value = LiquidityPositionsByPriceRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut trading_pair__ = None;
+                let mut limit__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            position_id__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Limit => {
+                            if limit__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("limit"));
+                            }
+                            limit__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdRequest {
-                    position_id: position_id__,
+                Ok(LiquidityPositionsByPriceRequest {
+                    trading_pair: trading_pair__,
+                    limit: limit__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdResponse {
+impl serde::Serialize for LiquidityPositionsByPriceResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2387,14 +3785,14 @@ impl serde::Serialize for LiquidityPositionByIdResponse {
         if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
         if let Some(v) = self.data.as_ref() {
             struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2439,13 +3837,13 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdResponse;
+            type Value = LiquidityPositionsByPriceResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -2463,15 +3861,15 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdResponse {
+                Ok(LiquidityPositionsByPriceResponse {
                     data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdRequest {
+impl serde::Serialize for LiquidityPositionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2479,30 +3877,30 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.position_id.is_empty() {
+        if self.include_closed {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
-        if !self.position_id.is_empty() {
-            struct_ser.serialize_field("positionId", &self.position_id)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
+        if self.include_closed {
+            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "include_closed",
+            "includeClosed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            IncludeClosed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2525,7 +3923,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2535,39 +3933,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdRequest;
+            type Value = LiquidityPositionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut include_closed__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::IncludeClosed => {
+                            if include_closed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeClosed"));
                             }
-                            position_id__ = Some(map_.next_value()?);
+                            include_closed__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdRequest {
-                    position_id: position_id__.unwrap_or_default(),
+                Ok(LiquidityPositionsRequest {
+                    include_closed: include_closed__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdResponse {
+impl serde::Serialize for LiquidityPositionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2578,14 +3976,14 @@ impl serde::Serialize for LiquidityPositionsByIdResponse {
         if self.data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
         if let Some(v) = self.data.as_ref() {
             struct_ser.serialize_field("data", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2630,13 +4028,13 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdResponse;
+            type Value = LiquidityPositionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -2654,15 +4052,15 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdResponse {
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdResponse {
+                Ok(LiquidityPositionsResponse {
                     data: data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceRequest {
+impl serde::Serialize for LpNft {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2670,39 +4068,38 @@ impl serde::Serialize for LiquidityPositionsByPriceRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.trading_pair.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        if self.limit != 0 {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", len)?;
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
-        if self.limit != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("limit", ToString::to_string(&self.limit).as_str())?;
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
+impl<'de> serde::Deserialize<'de> for LpNft {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "trading_pair",
-            "tradingPair",
-            "limit",
+            "position_id",
+            "positionId",
+            "state",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TradingPair,
-            Limit,
+            PositionId,
+            State,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2725,8 +4122,8 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
-                            "limit" => Ok(GeneratedField::Limit),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "state" => Ok(GeneratedField::State),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2736,49 +4133,47 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceRequest;
+            type Value = LpNft;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut trading_pair__ = None;
-                let mut limit__ = None;
+                let mut position_id__ = None;
+                let mut state__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Limit => {
-                            if limit__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("limit"));
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
                             }
-                            limit__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            state__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceRequest {
-                    trading_pair: trading_pair__,
-                    limit: limit__.unwrap_or_default(),
+                Ok(LpNft {
+                    position_id: position_id__,
+                    state: state__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByPriceResponse {
+impl serde::Serialize for PairStats {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2786,29 +4181,47 @@ impl serde::Serialize for LiquidityPositionsByPriceResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        if self.last_24h.is_some() {
+            len += 1;
+        }
+        if self.epoch.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PairStats", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if let Some(v) = self.last_24h.as_ref() {
+            struct_ser.serialize_field("last24h", v)?;
+        }
+        if let Some(v) = self.epoch.as_ref() {
+            struct_ser.serialize_field("epoch", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
+impl<'de> serde::Deserialize<'de> for PairStats {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "trading_pair",
+            "tradingPair",
+            "last_24h",
+            "last24h",
+            "epoch",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            TradingPair,
+            Last24h,
+            Epoch,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2831,7 +4244,9 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "last24h" | "last_24h" => Ok(GeneratedField::Last24h),
+                            "epoch" => Ok(GeneratedField::Epoch),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2841,39 +4256,55 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByPriceResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByPriceResponse;
+            type Value = PairStats;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PairStats")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByPriceResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PairStats, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut trading_pair__ = None;
+                let mut last_24h__ = None;
+                let mut epoch__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            data__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Last24h => {
+                            if last_24h__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("last24h"));
+                            }
+                            last_24h__ = map_.next_value()?;
+                        }
+                        GeneratedField::Epoch => {
+                            if epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epoch"));
+                            }
+                            epoch__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByPriceResponse {
-                    data: data__,
+                Ok(PairStats {
+                    trading_pair: trading_pair__,
+                    last_24h: last_24h__,
+                    epoch: epoch__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByPriceResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PairStats", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsRequest {
+impl serde::Serialize for PairStatsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2881,30 +4312,30 @@ impl serde::Serialize for LiquidityPositionsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_closed {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", len)?;
-        if self.include_closed {
-            struct_ser.serialize_field("includeClosed", &self.include_closed)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PairStatsRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
+impl<'de> serde::Deserialize<'de> for PairStatsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_closed",
-            "includeClosed",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeClosed,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2927,7 +4358,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeClosed" | "include_closed" => Ok(GeneratedField::IncludeClosed),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2937,39 +4368,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsRequest;
+            type Value = PairStatsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PairStatsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PairStatsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_closed__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeClosed => {
-                            if include_closed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeClosed"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            include_closed__ = Some(map_.next_value()?);
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsRequest {
-                    include_closed: include_closed__.unwrap_or_default(),
+                Ok(PairStatsRequest {
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PairStatsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsResponse {
+impl serde::Serialize for PairStatsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2977,29 +4408,29 @@ impl serde::Serialize for LiquidityPositionsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.stats.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PairStatsResponse", len)?;
+        if let Some(v) = self.stats.as_ref() {
+            struct_ser.serialize_field("stats", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
+impl<'de> serde::Deserialize<'de> for PairStatsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "stats",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            Stats,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3022,7 +4453,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "stats" => Ok(GeneratedField::Stats),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3032,39 +4463,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsResponse;
+            type Value = PairStatsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PairStatsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PairStatsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut stats__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Stats => {
+                            if stats__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stats"));
                             }
-                            data__ = map_.next_value()?;
+                            stats__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsResponse {
-                    data: data__,
+                Ok(PairStatsResponse {
+                    stats: stats__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PairStatsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LpNft {
+impl serde::Serialize for PairStatsWindow {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3072,38 +4503,66 @@ impl serde::Serialize for LpNft {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.volume_1.is_some() {
             len += 1;
         }
-        if self.state.is_some() {
+        if self.volume_2.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LpNft", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.lp_fees_1.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        if self.lp_fees_2.is_some() {
+            len += 1;
+        }
+        if self.batches != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PairStatsWindow", len)?;
+        if let Some(v) = self.volume_1.as_ref() {
+            struct_ser.serialize_field("volume1", v)?;
+        }
+        if let Some(v) = self.volume_2.as_ref() {
+            struct_ser.serialize_field("volume2", v)?;
+        }
+        if let Some(v) = self.lp_fees_1.as_ref() {
+            struct_ser.serialize_field("lpFees1", v)?;
+        }
+        if let Some(v) = self.lp_fees_2.as_ref() {
+            struct_ser.serialize_field("lpFees2", v)?;
+        }
+        if self.batches != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("batches", ToString::to_string(&self.batches).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LpNft {
+impl<'de> serde::Deserialize<'de> for PairStatsWindow {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "state",
+            "volume_1",
+            "volume1",
+            "volume_2",
+            "volume2",
+            "lp_fees_1",
+            "lpFees1",
+            "lp_fees_2",
+            "lpFees2",
+            "batches",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            State,
+            Volume1,
+            Volume2,
+            LpFees1,
+            LpFees2,
+            Batches,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3126,8 +4585,11 @@ impl<'de> serde::Deserialize<'de> for LpNft {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "state" => Ok(GeneratedField::State),
+                            "volume1" | "volume_1" => Ok(GeneratedField::Volume1),
+                            "volume2" | "volume_2" => Ok(GeneratedField::Volume2),
+                            "lpFees1" | "lp_fees_1" => Ok(GeneratedField::LpFees1),
+                            "lpFees2" | "lp_fees_2" => Ok(GeneratedField::LpFees2),
+                            "batches" => Ok(GeneratedField::Batches),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3137,44 +4599,70 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LpNft;
+            type Value = PairStatsWindow;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LpNft")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PairStatsWindow")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LpNft, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PairStatsWindow, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut state__ = None;
+                let mut volume_1__ = None;
+                let mut volume_2__ = None;
+                let mut lp_fees_1__ = None;
+                let mut lp_fees_2__ = None;
+                let mut batches__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Volume1 => {
+                            if volume_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volume1"));
                             }
-                            position_id__ = map_.next_value()?;
+                            volume_1__ = map_.next_value()?;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Volume2 => {
+                            if volume_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("volume2"));
                             }
-                            state__ = map_.next_value()?;
+                            volume_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::LpFees1 => {
+                            if lp_fees_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpFees1"));
+                            }
+                            lp_fees_1__ = map_.next_value()?;
+                        }
+                        GeneratedField::LpFees2 => {
+                            if lp_fees_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpFees2"));
+                            }
+                            lp_fees_2__ = map_.next_value()?;
+                        }
+                        GeneratedField::Batches => {
+                            if batches__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("batches"));
+                            }
+                            batches__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LpNft {
-                    position_id: position_id__,
-                    state: state__,
+                Ok(PairStatsWindow {
+                    volume_1: volume_1__,
+                    volume_2: volume_2__,
+                    lp_fees_1: lp_fees_1__,
+                    lp_fees_2: lp_fees_2__,
+                    batches: batches__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PairStatsWindow", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for Position {
@@ -3200,6 +4688,9 @@ impl serde::Serialize for Position {
         if self.close_on_fill {
             len += 1;
         }
+        if self.close_below.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
         if let Some(v) = self.phi.as_ref() {
             struct_ser.serialize_field("phi", v)?;
@@ -3217,6 +4708,9 @@ impl serde::Serialize for Position {
         if self.close_on_fill {
             struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
         }
+        if let Some(v) = self.close_below.as_ref() {
+            struct_ser.serialize_field("closeBelow", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -3233,6 +4727,8 @@ impl<'de> serde::Deserialize<'de> for Position {
             "reserves",
             "close_on_fill",
             "closeOnFill",
+            "close_below",
+            "closeBelow",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3242,6 +4738,7 @@ impl<'de> serde::Deserialize<'de> for Position {
             State,
             Reserves,
             CloseOnFill,
+            CloseBelow,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3269,6 +4766,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                             "state" => Ok(GeneratedField::State),
                             "reserves" => Ok(GeneratedField::Reserves),
                             "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "closeBelow" | "close_below" => Ok(GeneratedField::CloseBelow),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3293,6 +4791,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                 let mut state__ = None;
                 let mut reserves__ = None;
                 let mut close_on_fill__ = None;
+                let mut close_below__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Phi => {
@@ -3327,6 +4826,12 @@ impl<'de> serde::Deserialize<'de> for Position {
                             }
                             close_on_fill__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::CloseBelow => {
+                            if close_below__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeBelow"));
+                            }
+                            close_below__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -3338,6 +4843,7 @@ impl<'de> serde::Deserialize<'de> for Position {
                     state: state__,
                     reserves: reserves__,
                     close_on_fill: close_on_fill__.unwrap_or_default(),
+                    close_below: close_below__,
                 })
             }
         }
@@ -4470,6 +5976,104 @@ impl<'de> serde::Deserialize<'de> for Reserves {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.Reserves", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for RoutingPreference {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.multi_hop_penalty_bps != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.RoutingPreference", len)?;
+        if self.multi_hop_penalty_bps != 0 {
+            struct_ser.serialize_field("multiHopPenaltyBps", &self.multi_hop_penalty_bps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for RoutingPreference {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "multi_hop_penalty_bps",
+            "multiHopPenaltyBps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MultiHopPenaltyBps,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "multiHopPenaltyBps" | "multi_hop_penalty_bps" => Ok(GeneratedField::MultiHopPenaltyBps),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = RoutingPreference;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.RoutingPreference")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<RoutingPreference, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut multi_hop_penalty_bps__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MultiHopPenaltyBps => {
+                            if multi_hop_penalty_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("multiHopPenaltyBps"));
+                            }
+                            multi_hop_penalty_bps__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(RoutingPreference {
+                    multi_hop_penalty_bps: multi_hop_penalty_bps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.RoutingPreference", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SimulateTradeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -4871,6 +6475,9 @@ impl serde::Serialize for SimulateTradeResponse {
         if self.unfilled.is_some() {
             len += 1;
         }
+        if self.price_impact_bps != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
         if let Some(v) = self.output.as_ref() {
             struct_ser.serialize_field("output", v)?;
@@ -4878,6 +6485,9 @@ impl serde::Serialize for SimulateTradeResponse {
         if let Some(v) = self.unfilled.as_ref() {
             struct_ser.serialize_field("unfilled", v)?;
         }
+        if self.price_impact_bps != 0 {
+            struct_ser.serialize_field("priceImpactBps", &self.price_impact_bps)?;
+        }
         struct_ser.end()
     }
 }
@@ -4890,12 +6500,15 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         const FIELDS: &[&str] = &[
             "output",
             "unfilled",
+            "price_impact_bps",
+            "priceImpactBps",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Output,
             Unfilled,
+            PriceImpactBps,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4920,6 +6533,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         match value {
                             "output" => Ok(GeneratedField::Output),
                             "unfilled" => Ok(GeneratedField::Unfilled),
+                            "priceImpactBps" | "price_impact_bps" => Ok(GeneratedField::PriceImpactBps),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4941,6 +6555,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
             {
                 let mut output__ = None;
                 let mut unfilled__ = None;
+                let mut price_impact_bps__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Output => {
@@ -4955,6 +6570,14 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                             }
                             unfilled__ = map_.next_value()?;
                         }
+                        GeneratedField::PriceImpactBps => {
+                            if price_impact_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceImpactBps"));
+                            }
+                            price_impact_bps__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -4963,6 +6586,7 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                 Ok(SimulateTradeResponse {
                     output: output__,
                     unfilled: unfilled__,
+                    price_impact_bps: price_impact_bps__.unwrap_or_default(),
                 })
             }
         }
@@ -5354,6 +6978,9 @@ impl serde::Serialize for SwapBody {
         if self.payload.is_some() {
             len += 1;
         }
+        if self.routing_preference.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapBody", len)?;
         if let Some(v) = self.trading_pair.as_ref() {
             struct_ser.serialize_field("tradingPair", v)?;
@@ -5370,6 +6997,9 @@ impl serde::Serialize for SwapBody {
         if let Some(v) = self.payload.as_ref() {
             struct_ser.serialize_field("payload", v)?;
         }
+        if let Some(v) = self.routing_preference.as_ref() {
+            struct_ser.serialize_field("routingPreference", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -5389,6 +7019,8 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             "fee_commitment",
             "feeCommitment",
             "payload",
+            "routing_preference",
+            "routingPreference",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -5398,6 +7030,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
             Delta2I,
             FeeCommitment,
             Payload,
+            RoutingPreference,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5425,6 +7058,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             "delta2I" | "delta_2_i" => Ok(GeneratedField::Delta2I),
                             "feeCommitment" | "fee_commitment" => Ok(GeneratedField::FeeCommitment),
                             "payload" => Ok(GeneratedField::Payload),
+                            "routingPreference" | "routing_preference" => Ok(GeneratedField::RoutingPreference),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5449,6 +7083,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                 let mut delta_2_i__ = None;
                 let mut fee_commitment__ = None;
                 let mut payload__ = None;
+                let mut routing_preference__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::TradingPair => {
@@ -5481,6 +7116,12 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                             }
                             payload__ = map_.next_value()?;
                         }
+                        GeneratedField::RoutingPreference => {
+                            if routing_preference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routingPreference"));
+                            }
+                            routing_preference__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -5492,6 +7133,7 @@ impl<'de> serde::Deserialize<'de> for SwapBody {
                     delta_2_i: delta_2_i__,
                     fee_commitment: fee_commitment__,
                     payload: payload__,
+                    routing_preference: routing_preference__,
                 })
             }
         }
@@ -7385,6 +9027,9 @@ impl serde::Serialize for SwapPlan {
         if !self.proof_blinding_s.is_empty() {
             len += 1;
         }
+        if self.routing_preference.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SwapPlan", len)?;
         if let Some(v) = self.swap_plaintext.as_ref() {
             struct_ser.serialize_field("swapPlaintext", v)?;
@@ -7401,6 +9046,9 @@ impl serde::Serialize for SwapPlan {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("proofBlindingS", pbjson::private::base64::encode(&self.proof_blinding_s).as_str())?;
         }
+        if let Some(v) = self.routing_preference.as_ref() {
+            struct_ser.serialize_field("routingPreference", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -7419,6 +9067,8 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
             "proofBlindingR",
             "proof_blinding_s",
             "proofBlindingS",
+            "routing_preference",
+            "routingPreference",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -7427,6 +9077,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
             FeeBlinding,
             ProofBlindingR,
             ProofBlindingS,
+            RoutingPreference,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7453,6 +9104,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                             "feeBlinding" | "fee_blinding" => Ok(GeneratedField::FeeBlinding),
                             "proofBlindingR" | "proof_blinding_r" => Ok(GeneratedField::ProofBlindingR),
                             "proofBlindingS" | "proof_blinding_s" => Ok(GeneratedField::ProofBlindingS),
+                            "routingPreference" | "routing_preference" => Ok(GeneratedField::RoutingPreference),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7476,6 +9128,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                 let mut fee_blinding__ = None;
                 let mut proof_blinding_r__ = None;
                 let mut proof_blinding_s__ = None;
+                let mut routing_preference__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::SwapPlaintext => {
@@ -7508,6 +9161,12 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::RoutingPreference => {
+                            if routing_preference__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("routingPreference"));
+                            }
+                            routing_preference__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -7518,6 +9177,7 @@ impl<'de> serde::Deserialize<'de> for SwapPlan {
                     fee_blinding: fee_blinding__.unwrap_or_default(),
                     proof_blinding_r: proof_blinding_r__.unwrap_or_default(),
                     proof_blinding_s: proof_blinding_s__.unwrap_or_default(),
+                    routing_preference: routing_preference__,
                 })
             }
         }
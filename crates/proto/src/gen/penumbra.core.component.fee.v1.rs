@@ -17,6 +17,21 @@ impl ::prost::Name for Fee {
         ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
     }
 }
+/// A view of a fee, with the metadata of the token used to pay it, if the viewer knows it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeView {
+    /// The amount and token of the fee.
+    #[prost(message, optional, tag = "1")]
+    pub value: ::core::option::Option<super::super::super::asset::v1::ValueView>,
+}
+impl ::prost::Name for FeeView {
+    const NAME: &'static str = "FeeView";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GasPrices {
@@ -40,6 +55,24 @@ impl ::prost::Name for GasPrices {
         ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
     }
 }
+/// Gas prices for paying fees in a token other than the staking token.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AltGasPrices {
+    /// The token the prices are denominated in.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The price per unit of each resource in that token, with an implicit 1,000 denominator.
+    #[prost(message, optional, tag = "2")]
+    pub gas_prices: ::core::option::Option<GasPrices>,
+}
+impl ::prost::Name for AltGasPrices {
+    const NAME: &'static str = "AltGasPrices";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FeeTier {
@@ -100,15 +133,243 @@ impl ::prost::Name for FeeTier {
         ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
     }
 }
+/// The identifier of a fee grant.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantId {
+    #[prost(bytes = "vec", tag = "1")]
+    pub inner: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for FeeGrantId {
+    const NAME: &'static str = "FeeGrantId";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// Grants an allowance that transactions authorized by the grantee can use to pay their fees.
+///
+/// The allowance is escrowed when the grant is created, and whatever remains of it
+/// can be reclaimed by the sponsor with a `FeeGrantReclaim`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrant {
+    /// The key authorized to draw on the allowance.
+    #[prost(message, optional, tag = "1")]
+    pub grantee: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+    /// The allowance, which also fixes the token fees can be paid in.
+    #[prost(message, optional, tag = "2")]
+    pub allowance: ::core::option::Option<Fee>,
+    /// The last height at which the allowance can be used.
+    #[prost(uint64, tag = "3")]
+    pub expiry_height: u64,
+    /// A nonce distinguishing otherwise identical grants.
+    #[prost(bytes = "vec", tag = "4")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+    /// The key authorized to reclaim what remains of the allowance.
+    #[prost(message, optional, tag = "5")]
+    pub sponsor: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+}
+impl ::prost::Name for FeeGrant {
+    const NAME: &'static str = "FeeGrant";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// The effecting data of a `FeeGrantUse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantUseBody {
+    /// The grant to draw on.
+    #[prost(message, optional, tag = "1")]
+    pub grant_id: ::core::option::Option<FeeGrantId>,
+    /// The key the grant was made to.
+    #[prost(message, optional, tag = "2")]
+    pub grantee: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+    /// The amount drawn from the allowance, which can only be used to pay the transaction's fee.
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<Fee>,
+}
+impl ::prost::Name for FeeGrantUseBody {
+    const NAME: &'static str = "FeeGrantUseBody";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// Draws on a fee grant to pay the fee of the transaction containing it.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantUse {
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<FeeGrantUseBody>,
+    /// A signature over the transaction's effect hash by the grantee key.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for FeeGrantUse {
+    const NAME: &'static str = "FeeGrantUse";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// A planned `FeeGrantUse`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantUsePlan {
+    /// The grant to draw on.
+    #[prost(message, optional, tag = "1")]
+    pub grant_id: ::core::option::Option<FeeGrantId>,
+    /// The amount drawn from the allowance.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<Fee>,
+    /// The randomizer applied to the spend authorization key to obtain the grantee key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub randomizer: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for FeeGrantUsePlan {
+    const NAME: &'static str = "FeeGrantUsePlan";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// The effecting data of a `FeeGrantReclaim`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantReclaimBody {
+    /// The grant to reclaim.
+    #[prost(message, optional, tag = "1")]
+    pub grant_id: ::core::option::Option<FeeGrantId>,
+    /// The key of the sponsor who made the grant.
+    #[prost(message, optional, tag = "2")]
+    pub sponsor: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+    /// The amount reclaimed, which must be all that remains of the allowance.
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<Fee>,
+}
+impl ::prost::Name for FeeGrantReclaimBody {
+    const NAME: &'static str = "FeeGrantReclaimBody";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// Revokes a fee grant, returning what remains of its allowance to the transaction containing it.
+///
+/// A grant can be reclaimed at any time, whether or not it has expired.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantReclaim {
+    #[prost(message, optional, tag = "1")]
+    pub body: ::core::option::Option<FeeGrantReclaimBody>,
+    /// A signature over the transaction's effect hash by the sponsor key.
+    #[prost(message, optional, tag = "2")]
+    pub auth_sig: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+}
+impl ::prost::Name for FeeGrantReclaim {
+    const NAME: &'static str = "FeeGrantReclaim";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// A planned `FeeGrantReclaim`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantReclaimPlan {
+    /// The grant to reclaim.
+    #[prost(message, optional, tag = "1")]
+    pub grant_id: ::core::option::Option<FeeGrantId>,
+    /// The amount reclaimed.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<Fee>,
+    /// The randomizer applied to the spend authorization key to obtain the sponsor key.
+    #[prost(bytes = "vec", tag = "3")]
+    pub randomizer: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for FeeGrantReclaimPlan {
+    const NAME: &'static str = "FeeGrantReclaimPlan";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// An amount of each of the resources a transaction can consume.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Gas {
+    #[prost(uint64, tag = "1")]
+    pub block_space: u64,
+    #[prost(uint64, tag = "2")]
+    pub compact_block_space: u64,
+    #[prost(uint64, tag = "3")]
+    pub verification: u64,
+    #[prost(uint64, tag = "4")]
+    pub execution: u64,
+}
+impl ::prost::Name for Gas {
+    const NAME: &'static str = "Gas";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+/// Parameters of a base fee that adjusts to how full recent blocks were.
+///
+/// After each block, the price of each resource moves towards balancing its use against its
+/// target: it rises if the block used more than the target, and falls if it used less, by up to
+/// `max_change_bps` of the price when the block used none or twice the target.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DynamicBaseFee {
+    /// The lowest the gas prices can fall to.
+    #[prost(message, optional, tag = "1")]
+    pub min_gas_prices: ::core::option::Option<GasPrices>,
+    /// The highest the gas prices can rise to.
+    #[prost(message, optional, tag = "2")]
+    pub max_gas_prices: ::core::option::Option<GasPrices>,
+    /// The gas of each resource a block is targeted to use.
+    #[prost(message, optional, tag = "3")]
+    pub target_block_gas: ::core::option::Option<Gas>,
+    /// The most the price of a resource can change after a block, in basis points.
+    #[prost(uint32, tag = "4")]
+    pub max_change_bps: u32,
+}
+impl ::prost::Name for DynamicBaseFee {
+    const NAME: &'static str = "DynamicBaseFee";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
 /// Fee component configuration data.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FeeParameters {
-    /// Fixed gas prices used to compute transactions' base fees.
-    ///
-    /// In the future, this should be removed and replaced with parameters for dynamic gas pricing.
+    /// Fixed gas prices used to compute transactions' base fees, unless `dynamic_base_fee` is set.
     #[prost(message, optional, tag = "1")]
     pub fixed_gas_prices: ::core::option::Option<GasPrices>,
+    /// If set, the gas prices adjust to how full recent blocks were, rather than being fixed.
+    #[prost(message, optional, tag = "2")]
+    pub dynamic_base_fee: ::core::option::Option<DynamicBaseFee>,
+    /// Fixed gas prices for the tokens other than the staking token that fees can be paid in.
+    #[prost(message, repeated, tag = "3")]
+    pub fixed_alt_gas_prices: ::prost::alloc::vec::Vec<AltGasPrices>,
 }
 impl ::prost::Name for FeeParameters {
     const NAME: &'static str = "FeeParameters";
@@ -156,6 +417,69 @@ impl ::prost::Name for CurrentGasPricesResponse {
         ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BaseFeeRequest {}
+impl ::prost::Name for BaseFeeRequest {
+    const NAME: &'static str = "BaseFeeRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BaseFeeResponse {
+    /// The gas prices transactions in the next block must pay at least.
+    #[prost(message, optional, tag = "1")]
+    pub gas_prices: ::core::option::Option<GasPrices>,
+    /// The gas used by the latest block.
+    #[prost(message, optional, tag = "2")]
+    pub block_gas_used: ::core::option::Option<Gas>,
+    /// The parameters of the dynamic base fee, if the base fee is dynamic.
+    #[prost(message, optional, tag = "3")]
+    pub dynamic_base_fee: ::core::option::Option<DynamicBaseFee>,
+}
+impl ::prost::Name for BaseFeeResponse {
+    const NAME: &'static str = "BaseFeeResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantsByGranteeRequest {
+    /// The grantee key to look up grants for.
+    #[prost(message, optional, tag = "1")]
+    pub grantee: ::core::option::Option<
+        super::super::super::super::crypto::decaf377_rdsa::v1::SpendVerificationKey,
+    >,
+}
+impl ::prost::Name for FeeGrantsByGranteeRequest {
+    const NAME: &'static str = "FeeGrantsByGranteeRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeGrantsByGranteeResponse {
+    /// The identifier of the grant.
+    #[prost(message, optional, tag = "1")]
+    pub grant_id: ::core::option::Option<FeeGrantId>,
+    /// The grant, with its allowance reduced by any fees already paid from it.
+    #[prost(message, optional, tag = "2")]
+    pub grant: ::core::option::Option<FeeGrant>,
+}
+impl ::prost::Name for FeeGrantsByGranteeResponse {
+    const NAME: &'static str = "FeeGrantsByGranteeResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.fee.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.fee.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -274,6 +598,65 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Get the unexpired fee grants made to a grantee key.
+        pub async fn fee_grants_by_grantee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FeeGrantsByGranteeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::FeeGrantsByGranteeResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.fee.v1.QueryService/FeeGrantsByGrantee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.fee.v1.QueryService",
+                        "FeeGrantsByGrantee",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get the current base fee, and the gas used by the latest block it was adjusted for.
+        pub async fn base_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BaseFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BaseFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.fee.v1.QueryService/BaseFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.core.component.fee.v1.QueryService", "BaseFee"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -292,6 +675,31 @@ pub mod query_service_server {
             tonic::Response<super::CurrentGasPricesResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the FeeGrantsByGrantee method.
+        type FeeGrantsByGranteeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::FeeGrantsByGranteeResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Get the unexpired fee grants made to a grantee key.
+        async fn fee_grants_by_grantee(
+            &self,
+            request: tonic::Request<super::FeeGrantsByGranteeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::FeeGrantsByGranteeStream>,
+            tonic::Status,
+        >;
+        /// Get the current base fee, and the gas used by the latest block it was adjusted for.
+        async fn base_fee(
+            &self,
+            request: tonic::Request<super::BaseFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BaseFeeResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the fee component.
     #[derive(Debug)]
@@ -420,6 +828,101 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.fee.v1.QueryService/FeeGrantsByGrantee" => {
+                    #[allow(non_camel_case_types)]
+                    struct FeeGrantsByGranteeSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<
+                        super::FeeGrantsByGranteeRequest,
+                    > for FeeGrantsByGranteeSvc<T> {
+                        type Response = super::FeeGrantsByGranteeResponse;
+                        type ResponseStream = T::FeeGrantsByGranteeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FeeGrantsByGranteeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::fee_grants_by_grantee(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = FeeGrantsByGranteeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.fee.v1.QueryService/BaseFee" => {
+                    #[allow(non_camel_case_types)]
+                    struct BaseFeeSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::BaseFeeRequest>
+                    for BaseFeeSvc<T> {
+                        type Response = super::BaseFeeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BaseFeeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::base_fee(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BaseFeeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
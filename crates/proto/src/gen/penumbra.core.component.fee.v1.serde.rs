@@ -1,4 +1,118 @@
-impl serde::Serialize for CurrentGasPricesRequest {
+impl serde::Serialize for AltGasPrices {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.gas_prices.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.AltGasPrices", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AltGasPrices {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "gas_prices",
+            "gasPrices",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            GasPrices,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AltGasPrices;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.AltGasPrices")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AltGasPrices, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut gas_prices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                            }
+                            gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(AltGasPrices {
+                    asset_id: asset_id__,
+                    gas_prices: gas_prices__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.AltGasPrices", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BaseFeeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6,11 +120,11 @@ impl serde::Serialize for CurrentGasPricesRequest {
     {
         use serde::ser::SerializeStruct;
         let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesRequest", len)?;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.BaseFeeRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CurrentGasPricesRequest {
+impl<'de> serde::Deserialize<'de> for BaseFeeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -50,27 +164,1612 @@ impl<'de> serde::Deserialize<'de> for CurrentGasPricesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CurrentGasPricesRequest;
+            type Value = BaseFeeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.fee.v1.CurrentGasPricesRequest")
+                formatter.write_str("struct penumbra.core.component.fee.v1.BaseFeeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrentGasPricesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BaseFeeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 while map_.next_key::<GeneratedField>()?.is_some() {
                     let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(CurrentGasPricesRequest {
+                Ok(BaseFeeRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.BaseFeeRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BaseFeeResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.gas_prices.is_some() {
+            len += 1;
+        }
+        if self.block_gas_used.is_some() {
+            len += 1;
+        }
+        if self.dynamic_base_fee.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.BaseFeeResponse", len)?;
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        if let Some(v) = self.block_gas_used.as_ref() {
+            struct_ser.serialize_field("blockGasUsed", v)?;
+        }
+        if let Some(v) = self.dynamic_base_fee.as_ref() {
+            struct_ser.serialize_field("dynamicBaseFee", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BaseFeeResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "gas_prices",
+            "gasPrices",
+            "block_gas_used",
+            "blockGasUsed",
+            "dynamic_base_fee",
+            "dynamicBaseFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GasPrices,
+            BlockGasUsed,
+            DynamicBaseFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "blockGasUsed" | "block_gas_used" => Ok(GeneratedField::BlockGasUsed),
+                            "dynamicBaseFee" | "dynamic_base_fee" => Ok(GeneratedField::DynamicBaseFee),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BaseFeeResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.BaseFeeResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BaseFeeResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut gas_prices__ = None;
+                let mut block_gas_used__ = None;
+                let mut dynamic_base_fee__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                            }
+                            gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockGasUsed => {
+                            if block_gas_used__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockGasUsed"));
+                            }
+                            block_gas_used__ = map_.next_value()?;
+                        }
+                        GeneratedField::DynamicBaseFee => {
+                            if dynamic_base_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dynamicBaseFee"));
+                            }
+                            dynamic_base_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(BaseFeeResponse {
+                    gas_prices: gas_prices__,
+                    block_gas_used: block_gas_used__,
+                    dynamic_base_fee: dynamic_base_fee__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.BaseFeeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CurrentGasPricesRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CurrentGasPricesRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CurrentGasPricesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.CurrentGasPricesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrentGasPricesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(CurrentGasPricesRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for CurrentGasPricesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.gas_prices.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesResponse", len)?;
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for CurrentGasPricesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "gas_prices",
+            "gasPrices",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GasPrices,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CurrentGasPricesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.CurrentGasPricesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrentGasPricesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut gas_prices__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                            }
+                            gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(CurrentGasPricesResponse {
+                    gas_prices: gas_prices__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DynamicBaseFee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.min_gas_prices.is_some() {
+            len += 1;
+        }
+        if self.max_gas_prices.is_some() {
+            len += 1;
+        }
+        if self.target_block_gas.is_some() {
+            len += 1;
+        }
+        if self.max_change_bps != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.DynamicBaseFee", len)?;
+        if let Some(v) = self.min_gas_prices.as_ref() {
+            struct_ser.serialize_field("minGasPrices", v)?;
+        }
+        if let Some(v) = self.max_gas_prices.as_ref() {
+            struct_ser.serialize_field("maxGasPrices", v)?;
+        }
+        if let Some(v) = self.target_block_gas.as_ref() {
+            struct_ser.serialize_field("targetBlockGas", v)?;
+        }
+        if self.max_change_bps != 0 {
+            struct_ser.serialize_field("maxChangeBps", &self.max_change_bps)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DynamicBaseFee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "min_gas_prices",
+            "minGasPrices",
+            "max_gas_prices",
+            "maxGasPrices",
+            "target_block_gas",
+            "targetBlockGas",
+            "max_change_bps",
+            "maxChangeBps",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            MinGasPrices,
+            MaxGasPrices,
+            TargetBlockGas,
+            MaxChangeBps,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "minGasPrices" | "min_gas_prices" => Ok(GeneratedField::MinGasPrices),
+                            "maxGasPrices" | "max_gas_prices" => Ok(GeneratedField::MaxGasPrices),
+                            "targetBlockGas" | "target_block_gas" => Ok(GeneratedField::TargetBlockGas),
+                            "maxChangeBps" | "max_change_bps" => Ok(GeneratedField::MaxChangeBps),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DynamicBaseFee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.DynamicBaseFee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DynamicBaseFee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut min_gas_prices__ = None;
+                let mut max_gas_prices__ = None;
+                let mut target_block_gas__ = None;
+                let mut max_change_bps__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::MinGasPrices => {
+                            if min_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minGasPrices"));
+                            }
+                            min_gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxGasPrices => {
+                            if max_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxGasPrices"));
+                            }
+                            max_gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::TargetBlockGas => {
+                            if target_block_gas__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetBlockGas"));
+                            }
+                            target_block_gas__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxChangeBps => {
+                            if max_change_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxChangeBps"));
+                            }
+                            max_change_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DynamicBaseFee {
+                    min_gas_prices: min_gas_prices__,
+                    max_gas_prices: max_gas_prices__,
+                    target_block_gas: target_block_gas__,
+                    max_change_bps: max_change_bps__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.DynamicBaseFee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Fee {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.Fee", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Fee {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "asset_id",
+            "assetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            AssetId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Fee;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.Fee")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Fee, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Fee {
+                    amount: amount__,
+                    asset_id: asset_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.Fee", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrant {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grantee.is_some() {
+            len += 1;
+        }
+        if self.allowance.is_some() {
+            len += 1;
+        }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.sponsor.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrant", len)?;
+        if let Some(v) = self.grantee.as_ref() {
+            struct_ser.serialize_field("grantee", v)?;
+        }
+        if let Some(v) = self.allowance.as_ref() {
+            struct_ser.serialize_field("allowance", v)?;
+        }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.sponsor.as_ref() {
+            struct_ser.serialize_field("sponsor", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrant {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grantee",
+            "allowance",
+            "expiry_height",
+            "expiryHeight",
+            "nonce",
+            "sponsor",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Grantee,
+            Allowance,
+            ExpiryHeight,
+            Nonce,
+            Sponsor,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantee" => Ok(GeneratedField::Grantee),
+                            "allowance" => Ok(GeneratedField::Allowance),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "sponsor" => Ok(GeneratedField::Sponsor),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrant;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrant")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrant, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grantee__ = None;
+                let mut allowance__ = None;
+                let mut expiry_height__ = None;
+                let mut nonce__ = None;
+                let mut sponsor__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Grantee => {
+                            if grantee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantee"));
+                            }
+                            grantee__ = map_.next_value()?;
+                        }
+                        GeneratedField::Allowance => {
+                            if allowance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowance"));
+                            }
+                            allowance__ = map_.next_value()?;
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Sponsor => {
+                            if sponsor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sponsor"));
+                            }
+                            sponsor__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrant {
+                    grantee: grantee__,
+                    allowance: allowance__,
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                    sponsor: sponsor__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrant", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantId {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.inner.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantId", len)?;
+        if !self.inner.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantId {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "inner",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Inner,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "inner" => Ok(GeneratedField::Inner),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantId;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantId")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantId, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut inner__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
+                            }
+                            inner__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantId {
+                    inner: inner__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantId", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantReclaim {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.auth_sig.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantReclaim", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantReclaim {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            AuthSig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantReclaim;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantReclaim")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantReclaim, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantReclaim {
+                    body: body__,
+                    auth_sig: auth_sig__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantReclaim", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantReclaimBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id.is_some() {
+            len += 1;
+        }
+        if self.sponsor.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantReclaimBody", len)?;
+        if let Some(v) = self.grant_id.as_ref() {
+            struct_ser.serialize_field("grantId", v)?;
+        }
+        if let Some(v) = self.sponsor.as_ref() {
+            struct_ser.serialize_field("sponsor", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantReclaimBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+            "sponsor",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+            Sponsor,
+            Amount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            "sponsor" => Ok(GeneratedField::Sponsor),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantReclaimBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantReclaimBody")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantReclaimBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                let mut sponsor__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sponsor => {
+                            if sponsor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sponsor"));
+                            }
+                            sponsor__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantReclaimBody {
+                    grant_id: grant_id__,
+                    sponsor: sponsor__,
+                    amount: amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantReclaimBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantReclaimPlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.randomizer.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantReclaimPlan", len)?;
+        if let Some(v) = self.grant_id.as_ref() {
+            struct_ser.serialize_field("grantId", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.randomizer.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("randomizer", pbjson::private::base64::encode(&self.randomizer).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantReclaimPlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+            "amount",
+            "randomizer",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+            Amount,
+            Randomizer,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "randomizer" => Ok(GeneratedField::Randomizer),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantReclaimPlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantReclaimPlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantReclaimPlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                let mut amount__ = None;
+                let mut randomizer__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Randomizer => {
+                            if randomizer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("randomizer"));
+                            }
+                            randomizer__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantReclaimPlan {
+                    grant_id: grant_id__,
+                    amount: amount__,
+                    randomizer: randomizer__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantReclaimPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantUse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.body.is_some() {
+            len += 1;
+        }
+        if self.auth_sig.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantUse", len)?;
+        if let Some(v) = self.body.as_ref() {
+            struct_ser.serialize_field("body", v)?;
+        }
+        if let Some(v) = self.auth_sig.as_ref() {
+            struct_ser.serialize_field("authSig", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantUse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "body",
+            "auth_sig",
+            "authSig",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Body,
+            AuthSig,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "body" => Ok(GeneratedField::Body),
+                            "authSig" | "auth_sig" => Ok(GeneratedField::AuthSig),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantUse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantUse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantUse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut body__ = None;
+                let mut auth_sig__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Body => {
+                            if body__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("body"));
+                            }
+                            body__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuthSig => {
+                            if auth_sig__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("authSig"));
+                            }
+                            auth_sig__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantUse {
+                    body: body__,
+                    auth_sig: auth_sig__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantUse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantUseBody {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id.is_some() {
+            len += 1;
+        }
+        if self.grantee.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantUseBody", len)?;
+        if let Some(v) = self.grant_id.as_ref() {
+            struct_ser.serialize_field("grantId", v)?;
+        }
+        if let Some(v) = self.grantee.as_ref() {
+            struct_ser.serialize_field("grantee", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantUseBody {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+            "grantee",
+            "amount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+            Grantee,
+            Amount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            "grantee" => Ok(GeneratedField::Grantee),
+                            "amount" => Ok(GeneratedField::Amount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantUseBody;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantUseBody")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantUseBody, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                let mut grantee__ = None;
+                let mut amount__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Grantee => {
+                            if grantee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantee"));
+                            }
+                            grantee__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantUseBody {
+                    grant_id: grant_id__,
+                    grantee: grantee__,
+                    amount: amount__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantUseBody", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeGrantUsePlan {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.grant_id.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.randomizer.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantUsePlan", len)?;
+        if let Some(v) = self.grant_id.as_ref() {
+            struct_ser.serialize_field("grantId", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.randomizer.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("randomizer", pbjson::private::base64::encode(&self.randomizer).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeGrantUsePlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "grant_id",
+            "grantId",
+            "amount",
+            "randomizer",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            GrantId,
+            Amount,
+            Randomizer,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "randomizer" => Ok(GeneratedField::Randomizer),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeGrantUsePlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantUsePlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantUsePlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut grant_id__ = None;
+                let mut amount__ = None;
+                let mut randomizer__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
+                            }
+                            grant_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Randomizer => {
+                            if randomizer__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("randomizer"));
+                            }
+                            randomizer__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeGrantUsePlan {
+                    grant_id: grant_id__,
+                    amount: amount__,
+                    randomizer: randomizer__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantUsePlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for CurrentGasPricesResponse {
+impl serde::Serialize for FeeGrantsByGranteeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -78,30 +1777,29 @@ impl serde::Serialize for CurrentGasPricesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.gas_prices.is_some() {
+        if self.grantee.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesResponse", len)?;
-        if let Some(v) = self.gas_prices.as_ref() {
-            struct_ser.serialize_field("gasPrices", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantsByGranteeRequest", len)?;
+        if let Some(v) = self.grantee.as_ref() {
+            struct_ser.serialize_field("grantee", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for CurrentGasPricesResponse {
+impl<'de> serde::Deserialize<'de> for FeeGrantsByGranteeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "gas_prices",
-            "gasPrices",
+            "grantee",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            GasPrices,
+            Grantee,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -124,7 +1822,7 @@ impl<'de> serde::Deserialize<'de> for CurrentGasPricesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "grantee" => Ok(GeneratedField::Grantee),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -134,39 +1832,39 @@ impl<'de> serde::Deserialize<'de> for CurrentGasPricesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = CurrentGasPricesResponse;
+            type Value = FeeGrantsByGranteeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.fee.v1.CurrentGasPricesResponse")
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantsByGranteeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<CurrentGasPricesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantsByGranteeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut gas_prices__ = None;
+                let mut grantee__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::GasPrices => {
-                            if gas_prices__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                        GeneratedField::Grantee => {
+                            if grantee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantee"));
                             }
-                            gas_prices__ = map_.next_value()?;
+                            grantee__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(CurrentGasPricesResponse {
-                    gas_prices: gas_prices__,
+                Ok(FeeGrantsByGranteeRequest {
+                    grantee: grantee__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.fee.v1.CurrentGasPricesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantsByGranteeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Fee {
+impl serde::Serialize for FeeGrantsByGranteeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -174,38 +1872,38 @@ impl serde::Serialize for Fee {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.amount.is_some() {
+        if self.grant_id.is_some() {
             len += 1;
         }
-        if self.asset_id.is_some() {
+        if self.grant.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.Fee", len)?;
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeGrantsByGranteeResponse", len)?;
+        if let Some(v) = self.grant_id.as_ref() {
+            struct_ser.serialize_field("grantId", v)?;
         }
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
+        if let Some(v) = self.grant.as_ref() {
+            struct_ser.serialize_field("grant", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Fee {
+impl<'de> serde::Deserialize<'de> for FeeGrantsByGranteeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "amount",
-            "asset_id",
-            "assetId",
+            "grant_id",
+            "grantId",
+            "grant",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Amount,
-            AssetId,
+            GrantId,
+            Grant,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -228,8 +1926,8 @@ impl<'de> serde::Deserialize<'de> for Fee {
                         E: serde::de::Error,
                     {
                         match value {
-                            "amount" => Ok(GeneratedField::Amount),
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "grantId" | "grant_id" => Ok(GeneratedField::GrantId),
+                            "grant" => Ok(GeneratedField::Grant),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -239,44 +1937,44 @@ impl<'de> serde::Deserialize<'de> for Fee {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Fee;
+            type Value = FeeGrantsByGranteeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.fee.v1.Fee")
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeGrantsByGranteeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Fee, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeGrantsByGranteeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut amount__ = None;
-                let mut asset_id__ = None;
+                let mut grant_id__ = None;
+                let mut grant__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
+                        GeneratedField::GrantId => {
+                            if grant_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grantId"));
                             }
-                            amount__ = map_.next_value()?;
+                            grant_id__ = map_.next_value()?;
                         }
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
+                        GeneratedField::Grant => {
+                            if grant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("grant"));
                             }
-                            asset_id__ = map_.next_value()?;
+                            grant__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Fee {
-                    amount: amount__,
-                    asset_id: asset_id__,
+                Ok(FeeGrantsByGranteeResponse {
+                    grant_id: grant_id__,
+                    grant: grant__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.fee.v1.Fee", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeGrantsByGranteeResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for FeeParameters {
@@ -290,10 +1988,22 @@ impl serde::Serialize for FeeParameters {
         if self.fixed_gas_prices.is_some() {
             len += 1;
         }
+        if self.dynamic_base_fee.is_some() {
+            len += 1;
+        }
+        if !self.fixed_alt_gas_prices.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeParameters", len)?;
         if let Some(v) = self.fixed_gas_prices.as_ref() {
             struct_ser.serialize_field("fixedGasPrices", v)?;
         }
+        if let Some(v) = self.dynamic_base_fee.as_ref() {
+            struct_ser.serialize_field("dynamicBaseFee", v)?;
+        }
+        if !self.fixed_alt_gas_prices.is_empty() {
+            struct_ser.serialize_field("fixedAltGasPrices", &self.fixed_alt_gas_prices)?;
+        }
         struct_ser.end()
     }
 }
@@ -306,11 +2016,17 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
         const FIELDS: &[&str] = &[
             "fixed_gas_prices",
             "fixedGasPrices",
+            "dynamic_base_fee",
+            "dynamicBaseFee",
+            "fixed_alt_gas_prices",
+            "fixedAltGasPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             FixedGasPrices,
+            DynamicBaseFee,
+            FixedAltGasPrices,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -334,6 +2050,8 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
                     {
                         match value {
                             "fixedGasPrices" | "fixed_gas_prices" => Ok(GeneratedField::FixedGasPrices),
+                            "dynamicBaseFee" | "dynamic_base_fee" => Ok(GeneratedField::DynamicBaseFee),
+                            "fixedAltGasPrices" | "fixed_alt_gas_prices" => Ok(GeneratedField::FixedAltGasPrices),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -354,6 +2072,8 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut fixed_gas_prices__ = None;
+                let mut dynamic_base_fee__ = None;
+                let mut fixed_alt_gas_prices__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::FixedGasPrices => {
@@ -362,6 +2082,18 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
                             }
                             fixed_gas_prices__ = map_.next_value()?;
                         }
+                        GeneratedField::DynamicBaseFee => {
+                            if dynamic_base_fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dynamicBaseFee"));
+                            }
+                            dynamic_base_fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::FixedAltGasPrices => {
+                            if fixed_alt_gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fixedAltGasPrices"));
+                            }
+                            fixed_alt_gas_prices__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -369,6 +2101,8 @@ impl<'de> serde::Deserialize<'de> for FeeParameters {
                 }
                 Ok(FeeParameters {
                     fixed_gas_prices: fixed_gas_prices__,
+                    dynamic_base_fee: dynamic_base_fee__,
+                    fixed_alt_gas_prices: fixed_alt_gas_prices__.unwrap_or_default(),
                 })
             }
         }
@@ -550,6 +2284,261 @@ impl<'de> serde::Deserialize<'de> for fee_tier::Tier {
         deserializer.deserialize_any(GeneratedVisitor)
     }
 }
+impl serde::Serialize for FeeView {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.FeeView", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeView {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeView;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.FeeView")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeView, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(FeeView {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.FeeView", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Gas {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.block_space != 0 {
+            len += 1;
+        }
+        if self.compact_block_space != 0 {
+            len += 1;
+        }
+        if self.verification != 0 {
+            len += 1;
+        }
+        if self.execution != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.fee.v1.Gas", len)?;
+        if self.block_space != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("blockSpace", ToString::to_string(&self.block_space).as_str())?;
+        }
+        if self.compact_block_space != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("compactBlockSpace", ToString::to_string(&self.compact_block_space).as_str())?;
+        }
+        if self.verification != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("verification", ToString::to_string(&self.verification).as_str())?;
+        }
+        if self.execution != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("execution", ToString::to_string(&self.execution).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Gas {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "block_space",
+            "blockSpace",
+            "compact_block_space",
+            "compactBlockSpace",
+            "verification",
+            "execution",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BlockSpace,
+            CompactBlockSpace,
+            Verification,
+            Execution,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "blockSpace" | "block_space" => Ok(GeneratedField::BlockSpace),
+                            "compactBlockSpace" | "compact_block_space" => Ok(GeneratedField::CompactBlockSpace),
+                            "verification" => Ok(GeneratedField::Verification),
+                            "execution" => Ok(GeneratedField::Execution),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Gas;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.fee.v1.Gas")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Gas, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut block_space__ = None;
+                let mut compact_block_space__ = None;
+                let mut verification__ = None;
+                let mut execution__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BlockSpace => {
+                            if block_space__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockSpace"));
+                            }
+                            block_space__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CompactBlockSpace => {
+                            if compact_block_space__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("compactBlockSpace"));
+                            }
+                            compact_block_space__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Verification => {
+                            if verification__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("verification"));
+                            }
+                            verification__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Execution => {
+                            if execution__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("execution"));
+                            }
+                            execution__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Gas {
+                    block_space: block_space__.unwrap_or_default(),
+                    compact_block_space: compact_block_space__.unwrap_or_default(),
+                    verification: verification__.unwrap_or_default(),
+                    execution: execution__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.fee.v1.Gas", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for GasPrices {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -639,6 +639,10 @@ pub mod proposal {
         /// If `true`, the chain will halt immediately when the proposal is passed.
         #[prost(bool, tag = "1")]
         pub halt_chain: bool,
+        /// If `true`, opening new positions and swapping on the DEX will be paused when the proposal
+        /// is passed.
+        #[prost(bool, tag = "2")]
+        pub pause_dex: bool,
     }
     impl ::prost::Name for Emergency {
         const NAME: &'static str = "Emergency";
@@ -1043,6 +1047,9 @@ pub struct ChangedAppParameters {
     pub shielded_pool_params: ::core::option::Option<
         super::super::shielded_pool::v1::ShieldedPoolParameters,
     >,
+    /// DEX module parameters.
+    #[prost(message, optional, tag = "10")]
+    pub dex_params: ::core::option::Option<super::super::dex::v1::DexParameters>,
 }
 impl ::prost::Name for ChangedAppParameters {
     const NAME: &'static str = "ChangedAppParameters";
@@ -245,6 +245,9 @@ impl serde::Serialize for ChangedAppParameters {
         if self.shielded_pool_params.is_some() {
             len += 1;
         }
+        if self.dex_params.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ChangedAppParameters", len)?;
         if let Some(v) = self.sct_params.as_ref() {
             struct_ser.serialize_field("sctParams", v)?;
@@ -273,6 +276,9 @@ impl serde::Serialize for ChangedAppParameters {
         if let Some(v) = self.shielded_pool_params.as_ref() {
             struct_ser.serialize_field("shieldedPoolParams", v)?;
         }
+        if let Some(v) = self.dex_params.as_ref() {
+            struct_ser.serialize_field("dexParams", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -301,6 +307,8 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
             "fundingParams",
             "shielded_pool_params",
             "shieldedPoolParams",
+            "dex_params",
+            "dexParams",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -314,6 +322,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
             DistributionsParams,
             FundingParams,
             ShieldedPoolParams,
+            DexParams,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -345,6 +354,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                             "distributionsParams" | "distributions_params" => Ok(GeneratedField::DistributionsParams),
                             "fundingParams" | "funding_params" => Ok(GeneratedField::FundingParams),
                             "shieldedPoolParams" | "shielded_pool_params" => Ok(GeneratedField::ShieldedPoolParams),
+                            "dexParams" | "dex_params" => Ok(GeneratedField::DexParams),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -373,6 +383,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                 let mut distributions_params__ = None;
                 let mut funding_params__ = None;
                 let mut shielded_pool_params__ = None;
+                let mut dex_params__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::SctParams => {
@@ -429,6 +440,12 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                             }
                             shielded_pool_params__ = map_.next_value()?;
                         }
+                        GeneratedField::DexParams => {
+                            if dex_params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("dexParams"));
+                            }
+                            dex_params__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -444,6 +461,7 @@ impl<'de> serde::Deserialize<'de> for ChangedAppParameters {
                     distributions_params: distributions_params__,
                     funding_params: funding_params__,
                     shielded_pool_params: shielded_pool_params__,
+                    dex_params: dex_params__,
                 })
             }
         }
@@ -3319,10 +3337,16 @@ impl serde::Serialize for proposal::Emergency {
         if self.halt_chain {
             len += 1;
         }
+        if self.pause_dex {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.Emergency", len)?;
         if self.halt_chain {
             struct_ser.serialize_field("haltChain", &self.halt_chain)?;
         }
+        if self.pause_dex {
+            struct_ser.serialize_field("pauseDex", &self.pause_dex)?;
+        }
         struct_ser.end()
     }
 }
@@ -3335,11 +3359,14 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
         const FIELDS: &[&str] = &[
             "halt_chain",
             "haltChain",
+            "pause_dex",
+            "pauseDex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             HaltChain,
+            PauseDex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3363,6 +3390,7 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                     {
                         match value {
                             "haltChain" | "halt_chain" => Ok(GeneratedField::HaltChain),
+                            "pauseDex" | "pause_dex" => Ok(GeneratedField::PauseDex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3383,6 +3411,7 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut halt_chain__ = None;
+                let mut pause_dex__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::HaltChain => {
@@ -3391,6 +3420,12 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                             }
                             halt_chain__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::PauseDex => {
+                            if pause_dex__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pauseDex"));
+                            }
+                            pause_dex__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -3398,6 +3433,7 @@ impl<'de> serde::Deserialize<'de> for proposal::Emergency {
                 }
                 Ok(proposal::Emergency {
                     halt_chain: halt_chain__.unwrap_or_default(),
+                    pause_dex: pause_dex__.unwrap_or_default(),
                 })
             }
         }
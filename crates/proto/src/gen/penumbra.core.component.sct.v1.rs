@@ -299,6 +299,98 @@ impl ::prost::Name for EpochByHeightResponse {
         ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
     }
 }
+/// Requests statistics about the current state of the state commitment tree.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctStatsRequest {}
+impl ::prost::Name for SctStatsRequest {
+    const NAME: &'static str = "SctStatsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctStatsResponse {
+    /// The position the next commitment will be inserted at, or empty if the tree is full.
+    #[prost(message, optional, tag = "1")]
+    pub next_position: ::core::option::Option<SctPosition>,
+    /// The current root of the tree.
+    #[prost(message, optional, tag = "2")]
+    pub root: ::core::option::Option<
+        super::super::super::super::crypto::tct::v1::MerkleRoot,
+    >,
+    /// The height of the most recent block whose anchor was recorded.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+}
+impl ::prost::Name for SctStatsResponse {
+    const NAME: &'static str = "SctStatsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
+/// A position in the state commitment tree.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SctPosition {
+    /// The position, packed as a single number, as used in compact blocks.
+    #[prost(uint64, tag = "1")]
+    pub position: u64,
+    /// The index of the epoch in the tree.
+    #[prost(uint32, tag = "2")]
+    pub epoch: u32,
+    /// The index of the block within its epoch.
+    #[prost(uint32, tag = "3")]
+    pub block: u32,
+    /// The index of the commitment within its block.
+    #[prost(uint32, tag = "4")]
+    pub commitment: u32,
+}
+impl ::prost::Name for SctPosition {
+    const NAME: &'static str = "SctPosition";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
+/// Requests the anchor, the root of the state commitment tree, at the end of each block in a range.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnchorsByHeightRequest {
+    /// The first height to return the anchor for.
+    #[prost(uint64, tag = "1")]
+    pub start_height: u64,
+    /// The height after the last height to return the anchor for, or 0 to return anchors up to the latest block.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
+}
+impl ::prost::Name for AnchorsByHeightRequest {
+    const NAME: &'static str = "AnchorsByHeightRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AnchorsByHeightResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, optional, tag = "2")]
+    pub anchor: ::core::option::Option<
+        super::super::super::super::crypto::tct::v1::MerkleRoot,
+    >,
+}
+impl ::prost::Name for AnchorsByHeightResponse {
+    const NAME: &'static str = "AnchorsByHeightResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.sct.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.component.sct.v1.{}", Self::NAME)
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -306,6 +398,9 @@ pub mod query_service_client {
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     /// Query operations for the SCT component.
+    ///
+    /// Full nodes only keep the frontier of the state commitment tree, so they can't serve inclusion
+    /// proofs for commitments; those come from a client that witnessed the commitment while syncing.
     #[derive(Debug, Clone)]
     pub struct QueryServiceClient<T> {
         inner: tonic::client::Grpc<T>,
@@ -416,6 +511,66 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn sct_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SctStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SctStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.sct.v1.QueryService/SctStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.sct.v1.QueryService",
+                        "SctStats",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn anchors_by_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AnchorsByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::AnchorsByHeightResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.sct.v1.QueryService/AnchorsByHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.sct.v1.QueryService",
+                        "AnchorsByHeight",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -433,8 +588,31 @@ pub mod query_service_server {
             tonic::Response<super::EpochByHeightResponse>,
             tonic::Status,
         >;
+        async fn sct_stats(
+            &self,
+            request: tonic::Request<super::SctStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SctStatsResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the AnchorsByHeight method.
+        type AnchorsByHeightStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::AnchorsByHeightResponse, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        async fn anchors_by_height(
+            &self,
+            request: tonic::Request<super::AnchorsByHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::AnchorsByHeightStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the SCT component.
+    ///
+    /// Full nodes only keep the frontier of the state commitment tree, so they can't serve inclusion
+    /// proofs for commitments; those come from a client that witnessed the commitment while syncing.
     #[derive(Debug)]
     pub struct QueryServiceServer<T: QueryService> {
         inner: _Inner<T>,
@@ -560,6 +738,100 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.sct.v1.QueryService/SctStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct SctStatsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::SctStatsRequest>
+                    for SctStatsSvc<T> {
+                        type Response = super::SctStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SctStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::sct_stats(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SctStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.sct.v1.QueryService/AnchorsByHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct AnchorsByHeightSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::AnchorsByHeightRequest>
+                    for AnchorsByHeightSvc<T> {
+                        type Response = super::AnchorsByHeightResponse;
+                        type ResponseStream = T::AnchorsByHeightStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AnchorsByHeightRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::anchors_by_height(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = AnchorsByHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
@@ -561,6 +561,42 @@ impl ::prost::Name for AssetMetadataByIdResponse {
         )
     }
 }
+/// Requests the cumulative ICS20 flows of an asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20FlowsRequest {
+    /// The asset id to request flows for.
+    #[prost(message, optional, tag = "1")]
+    pub asset_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+}
+impl ::prost::Name for Ics20FlowsRequest {
+    const NAME: &'static str = "Ics20FlowsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Ics20FlowsResponse {
+    /// The total amount of the asset received over ICS20.
+    #[prost(message, optional, tag = "1")]
+    pub inflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total amount of the asset sent over ICS20, excluding transfers refunded on timeout.
+    #[prost(message, optional, tag = "2")]
+    pub outflow: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for Ics20FlowsResponse {
+    const NAME: &'static str = "Ics20FlowsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.shielded_pool.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!(
+            "penumbra.core.component.shielded_pool.v1.{}", Self::NAME
+        )
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -678,6 +714,37 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Gets the cumulative amounts of an asset transferred in and out over ICS20.
+        pub async fn ics20_flows(
+            &mut self,
+            request: impl tonic::IntoRequest<super::Ics20FlowsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::Ics20FlowsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.shielded_pool.v1.QueryService/Ics20Flows",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.shielded_pool.v1.QueryService",
+                        "Ics20Flows",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -695,6 +762,14 @@ pub mod query_service_server {
             tonic::Response<super::AssetMetadataByIdResponse>,
             tonic::Status,
         >;
+        /// Gets the cumulative amounts of an asset transferred in and out over ICS20.
+        async fn ics20_flows(
+            &self,
+            request: tonic::Request<super::Ics20FlowsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::Ics20FlowsResponse>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the shielded pool component.
     #[derive(Debug)]
@@ -823,6 +898,52 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.shielded_pool.v1.QueryService/Ics20Flows" => {
+                    #[allow(non_camel_case_types)]
+                    struct Ics20FlowsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::Ics20FlowsRequest>
+                    for Ics20FlowsSvc<T> {
+                        type Response = super::Ics20FlowsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::Ics20FlowsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::ics20_flows(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = Ics20FlowsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
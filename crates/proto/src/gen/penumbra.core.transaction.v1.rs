@@ -94,7 +94,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 60, 61, 62, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -164,6 +164,13 @@ pub mod action {
         CommunityPoolDeposit(
             super::super::super::component::governance::v1::CommunityPoolDeposit,
         ),
+        /// Fee grants
+        #[prost(message, tag = "60")]
+        FeeGrant(super::super::super::component::fee::v1::FeeGrant),
+        #[prost(message, tag = "61")]
+        FeeGrantUse(super::super::super::component::fee::v1::FeeGrantUse),
+        #[prost(message, tag = "62")]
+        FeeGrantReclaim(super::super::super::component::fee::v1::FeeGrantReclaim),
         #[prost(message, tag = "200")]
         Ics20Withdrawal(super::super::super::component::ibc::v1::Ics20Withdrawal),
     }
@@ -252,6 +259,33 @@ impl ::prost::Name for PayloadKeyWithCommitment {
         ::prost::alloc::format!("penumbra.core.transaction.v1.{}", Self::NAME)
     }
 }
+/// A receipt for a payment made by a single output of a transaction.
+///
+/// The receipt discloses the payload key of the output, which allows anyone holding it to
+/// decrypt the output's note, and so to check who it paid and how much, without any
+/// viewing key. It discloses nothing about the transaction's other actions.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PaymentReceipt {
+    /// The transaction containing the output.
+    #[prost(message, optional, tag = "1")]
+    pub transaction_id: ::core::option::Option<super::super::txhash::v1::TransactionId>,
+    /// The commitment to the output's note.
+    #[prost(message, optional, tag = "2")]
+    pub note_commitment: ::core::option::Option<
+        super::super::super::crypto::tct::v1::StateCommitment,
+    >,
+    /// The payload key of the output.
+    #[prost(message, optional, tag = "3")]
+    pub payload_key: ::core::option::Option<super::super::keys::v1::PayloadKey>,
+}
+impl ::prost::Name for PaymentReceipt {
+    const NAME: &'static str = "PaymentReceipt";
+    const PACKAGE: &'static str = "penumbra.core.transaction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.core.transaction.v1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct NullifierWithNote {
@@ -308,6 +342,9 @@ pub struct TransactionBodyView {
     /// outputs in the actions of this transaction.
     #[prost(message, optional, tag = "5")]
     pub memo_view: ::core::option::Option<MemoView>,
+    /// A view of the fee paid by this transaction, with the metadata of the fee token if known.
+    #[prost(message, optional, tag = "6")]
+    pub fee_view: ::core::option::Option<super::super::component::fee::v1::FeeView>,
 }
 impl ::prost::Name for TransactionBodyView {
     const NAME: &'static str = "TransactionBodyView";
@@ -322,7 +359,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 43, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 60, 61, 62, 43, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -389,6 +426,13 @@ pub mod action_view {
         CommunityPoolDeposit(
             super::super::super::component::governance::v1::CommunityPoolDeposit,
         ),
+        /// Fee grants
+        #[prost(message, tag = "60")]
+        FeeGrant(super::super::super::component::fee::v1::FeeGrant),
+        #[prost(message, tag = "61")]
+        FeeGrantUse(super::super::super::component::fee::v1::FeeGrantUse),
+        #[prost(message, tag = "62")]
+        FeeGrantReclaim(super::super::super::component::fee::v1::FeeGrantReclaim),
         /// TODO: we have no way to recover the opening of the undelegate_claim's
         /// balance commitment, and can only infer the value from looking at the rest
         /// of the transaction. is that fine?
@@ -424,6 +468,18 @@ pub struct AuthorizationData {
     pub delegator_vote_auths: ::prost::alloc::vec::Vec<
         super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
     >,
+    /// The required fee grant use authorizations, returned in the same order as the
+    /// FeeGrantUse actions in the original request.
+    #[prost(message, repeated, tag = "4")]
+    pub fee_grant_auths: ::prost::alloc::vec::Vec<
+        super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
+    /// The required fee grant reclaim authorizations, returned in the same order as the
+    /// FeeGrantReclaim actions in the original request.
+    #[prost(message, repeated, tag = "5")]
+    pub fee_grant_reclaim_auths: ::prost::alloc::vec::Vec<
+        super::super::super::crypto::decaf377_rdsa::v1::SpendAuthSignature,
+    >,
 }
 impl ::prost::Name for AuthorizationData {
     const NAME: &'static str = "AuthorizationData";
@@ -503,7 +559,7 @@ impl ::prost::Name for DetectionDataPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 60, 61, 62"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -579,6 +635,13 @@ pub mod action_plan {
         CommunityPoolDeposit(
             super::super::super::component::governance::v1::CommunityPoolDeposit,
         ),
+        /// Fee grants
+        #[prost(message, tag = "60")]
+        FeeGrant(super::super::super::component::fee::v1::FeeGrant),
+        #[prost(message, tag = "61")]
+        FeeGrantUse(super::super::super::component::fee::v1::FeeGrantUsePlan),
+        #[prost(message, tag = "62")]
+        FeeGrantReclaim(super::super::super::component::fee::v1::FeeGrantReclaimPlan),
     }
 }
 impl ::prost::Name for ActionPlan {
@@ -75,6 +75,15 @@ impl serde::Serialize for Action {
                 action::Action::CommunityPoolDeposit(v) => {
                     struct_ser.serialize_field("communityPoolDeposit", v)?;
                 }
+                action::Action::FeeGrant(v) => {
+                    struct_ser.serialize_field("feeGrant", v)?;
+                }
+                action::Action::FeeGrantUse(v) => {
+                    struct_ser.serialize_field("feeGrantUse", v)?;
+                }
+                action::Action::FeeGrantReclaim(v) => {
+                    struct_ser.serialize_field("feeGrantReclaim", v)?;
+                }
                 action::Action::Ics20Withdrawal(v) => {
                     struct_ser.serialize_field("ics20Withdrawal", v)?;
                 }
@@ -127,6 +136,12 @@ impl<'de> serde::Deserialize<'de> for Action {
             "communityPoolOutput",
             "community_pool_deposit",
             "communityPoolDeposit",
+            "fee_grant",
+            "feeGrant",
+            "fee_grant_use",
+            "feeGrantUse",
+            "fee_grant_reclaim",
+            "feeGrantReclaim",
             "ics20_withdrawal",
             "ics20Withdrawal",
         ];
@@ -154,6 +169,9 @@ impl<'de> serde::Deserialize<'de> for Action {
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
+            FeeGrant,
+            FeeGrantUse,
+            FeeGrantReclaim,
             Ics20Withdrawal,
             __SkipField__,
         }
@@ -198,6 +216,9 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
+                            "feeGrant" | "fee_grant" => Ok(GeneratedField::FeeGrant),
+                            "feeGrantUse" | "fee_grant_use" => Ok(GeneratedField::FeeGrantUse),
+                            "feeGrantReclaim" | "fee_grant_reclaim" => Ok(GeneratedField::FeeGrantReclaim),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
@@ -366,6 +387,27 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("communityPoolDeposit"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::CommunityPoolDeposit)
+;
+                        }
+                        GeneratedField::FeeGrant => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrant"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::FeeGrant)
+;
+                        }
+                        GeneratedField::FeeGrantUse => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantUse"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::FeeGrantUse)
+;
+                        }
+                        GeneratedField::FeeGrantReclaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantReclaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::FeeGrantReclaim)
 ;
                         }
                         GeneratedField::Ics20Withdrawal => {
@@ -468,6 +510,15 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::CommunityPoolDeposit(v) => {
                     struct_ser.serialize_field("communityPoolDeposit", v)?;
                 }
+                action_plan::Action::FeeGrant(v) => {
+                    struct_ser.serialize_field("feeGrant", v)?;
+                }
+                action_plan::Action::FeeGrantUse(v) => {
+                    struct_ser.serialize_field("feeGrantUse", v)?;
+                }
+                action_plan::Action::FeeGrantReclaim(v) => {
+                    struct_ser.serialize_field("feeGrantReclaim", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -519,6 +570,12 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "communityPoolOutput",
             "community_pool_deposit",
             "communityPoolDeposit",
+            "fee_grant",
+            "feeGrant",
+            "fee_grant_use",
+            "feeGrantUse",
+            "fee_grant_reclaim",
+            "feeGrantReclaim",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -545,6 +602,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
+            FeeGrant,
+            FeeGrantUse,
+            FeeGrantReclaim,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -589,6 +649,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
+                            "feeGrant" | "fee_grant" => Ok(GeneratedField::FeeGrant),
+                            "feeGrantUse" | "fee_grant_use" => Ok(GeneratedField::FeeGrantUse),
+                            "feeGrantReclaim" | "fee_grant_reclaim" => Ok(GeneratedField::FeeGrantReclaim),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -763,6 +826,27 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("communityPoolDeposit"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::CommunityPoolDeposit)
+;
+                        }
+                        GeneratedField::FeeGrant => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrant"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::FeeGrant)
+;
+                        }
+                        GeneratedField::FeeGrantUse => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantUse"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::FeeGrantUse)
+;
+                        }
+                        GeneratedField::FeeGrantReclaim => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantReclaim"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::FeeGrantReclaim)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -852,6 +936,15 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::CommunityPoolDeposit(v) => {
                     struct_ser.serialize_field("communityPoolDeposit", v)?;
                 }
+                action_view::ActionView::FeeGrant(v) => {
+                    struct_ser.serialize_field("feeGrant", v)?;
+                }
+                action_view::ActionView::FeeGrantUse(v) => {
+                    struct_ser.serialize_field("feeGrantUse", v)?;
+                }
+                action_view::ActionView::FeeGrantReclaim(v) => {
+                    struct_ser.serialize_field("feeGrantReclaim", v)?;
+                }
                 action_view::ActionView::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
@@ -905,6 +998,12 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "communityPoolOutput",
             "community_pool_deposit",
             "communityPoolDeposit",
+            "fee_grant",
+            "feeGrant",
+            "fee_grant_use",
+            "feeGrantUse",
+            "fee_grant_reclaim",
+            "feeGrantReclaim",
             "undelegate_claim",
             "undelegateClaim",
             "ics20_withdrawal",
@@ -933,6 +1032,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             CommunityPoolSpend,
             CommunityPoolOutput,
             CommunityPoolDeposit,
+            FeeGrant,
+            FeeGrantUse,
+            FeeGrantReclaim,
             UndelegateClaim,
             Ics20Withdrawal,
             __SkipField__,
@@ -977,6 +1079,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "communityPoolSpend" | "community_pool_spend" => Ok(GeneratedField::CommunityPoolSpend),
                             "communityPoolOutput" | "community_pool_output" => Ok(GeneratedField::CommunityPoolOutput),
                             "communityPoolDeposit" | "community_pool_deposit" => Ok(GeneratedField::CommunityPoolDeposit),
+                            "feeGrant" | "fee_grant" => Ok(GeneratedField::FeeGrant),
+                            "feeGrantUse" | "fee_grant_use" => Ok(GeneratedField::FeeGrantUse),
+                            "feeGrantReclaim" | "fee_grant_reclaim" => Ok(GeneratedField::FeeGrantReclaim),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
@@ -1139,6 +1244,27 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("communityPoolDeposit"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::CommunityPoolDeposit)
+;
+                        }
+                        GeneratedField::FeeGrant => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrant"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::FeeGrant)
+;
+                        }
+                        GeneratedField::FeeGrantUse => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantUse"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::FeeGrantUse)
+;
+                        }
+                        GeneratedField::FeeGrantReclaim => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantReclaim"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::FeeGrantReclaim)
 ;
                         }
                         GeneratedField::UndelegateClaim => {
@@ -1185,6 +1311,12 @@ impl serde::Serialize for AuthorizationData {
         if !self.delegator_vote_auths.is_empty() {
             len += 1;
         }
+        if !self.fee_grant_auths.is_empty() {
+            len += 1;
+        }
+        if !self.fee_grant_reclaim_auths.is_empty() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1.AuthorizationData", len)?;
         if let Some(v) = self.effect_hash.as_ref() {
             struct_ser.serialize_field("effectHash", v)?;
@@ -1195,6 +1327,12 @@ impl serde::Serialize for AuthorizationData {
         if !self.delegator_vote_auths.is_empty() {
             struct_ser.serialize_field("delegatorVoteAuths", &self.delegator_vote_auths)?;
         }
+        if !self.fee_grant_auths.is_empty() {
+            struct_ser.serialize_field("feeGrantAuths", &self.fee_grant_auths)?;
+        }
+        if !self.fee_grant_reclaim_auths.is_empty() {
+            struct_ser.serialize_field("feeGrantReclaimAuths", &self.fee_grant_reclaim_auths)?;
+        }
         struct_ser.end()
     }
 }
@@ -1211,6 +1349,10 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
             "spendAuths",
             "delegator_vote_auths",
             "delegatorVoteAuths",
+            "fee_grant_auths",
+            "feeGrantAuths",
+            "fee_grant_reclaim_auths",
+            "feeGrantReclaimAuths",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1218,6 +1360,8 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
             EffectHash,
             SpendAuths,
             DelegatorVoteAuths,
+            FeeGrantAuths,
+            FeeGrantReclaimAuths,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1243,6 +1387,8 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
                             "effectHash" | "effect_hash" => Ok(GeneratedField::EffectHash),
                             "spendAuths" | "spend_auths" => Ok(GeneratedField::SpendAuths),
                             "delegatorVoteAuths" | "delegator_vote_auths" => Ok(GeneratedField::DelegatorVoteAuths),
+                            "feeGrantAuths" | "fee_grant_auths" => Ok(GeneratedField::FeeGrantAuths),
+                            "feeGrantReclaimAuths" | "fee_grant_reclaim_auths" => Ok(GeneratedField::FeeGrantReclaimAuths),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1265,6 +1411,8 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
                 let mut effect_hash__ = None;
                 let mut spend_auths__ = None;
                 let mut delegator_vote_auths__ = None;
+                let mut fee_grant_auths__ = None;
+                let mut fee_grant_reclaim_auths__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::EffectHash => {
@@ -1285,6 +1433,18 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
                             }
                             delegator_vote_auths__ = Some(map_.next_value()?);
                         }
+                        GeneratedField::FeeGrantAuths => {
+                            if fee_grant_auths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantAuths"));
+                            }
+                            fee_grant_auths__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeGrantReclaimAuths => {
+                            if fee_grant_reclaim_auths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeGrantReclaimAuths"));
+                            }
+                            fee_grant_reclaim_auths__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1294,6 +1454,8 @@ impl<'de> serde::Deserialize<'de> for AuthorizationData {
                     effect_hash: effect_hash__,
                     spend_auths: spend_auths__.unwrap_or_default(),
                     delegator_vote_auths: delegator_vote_auths__.unwrap_or_default(),
+                    fee_grant_auths: fee_grant_auths__.unwrap_or_default(),
+                    fee_grant_reclaim_auths: fee_grant_reclaim_auths__.unwrap_or_default(),
                 })
             }
         }
@@ -2612,6 +2774,138 @@ impl<'de> serde::Deserialize<'de> for PayloadKeyWithCommitment {
         deserializer.deserialize_struct("penumbra.core.transaction.v1.PayloadKeyWithCommitment", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PaymentReceipt {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.transaction_id.is_some() {
+            len += 1;
+        }
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        if self.payload_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1.PaymentReceipt", len)?;
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
+        }
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if let Some(v) = self.payload_key.as_ref() {
+            struct_ser.serialize_field("payloadKey", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PaymentReceipt {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transaction_id",
+            "transactionId",
+            "note_commitment",
+            "noteCommitment",
+            "payload_key",
+            "payloadKey",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransactionId,
+            NoteCommitment,
+            PayloadKey,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "payloadKey" | "payload_key" => Ok(GeneratedField::PayloadKey),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PaymentReceipt;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.transaction.v1.PaymentReceipt")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PaymentReceipt, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut transaction_id__ = None;
+                let mut note_commitment__ = None;
+                let mut payload_key__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
+                            }
+                            transaction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::PayloadKey => {
+                            if payload_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payloadKey"));
+                            }
+                            payload_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PaymentReceipt {
+                    transaction_id: transaction_id__,
+                    note_commitment: note_commitment__,
+                    payload_key: payload_key__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.transaction.v1.PaymentReceipt", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Transaction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -2910,6 +3204,9 @@ impl serde::Serialize for TransactionBodyView {
         if self.memo_view.is_some() {
             len += 1;
         }
+        if self.fee_view.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.transaction.v1.TransactionBodyView", len)?;
         if !self.action_views.is_empty() {
             struct_ser.serialize_field("actionViews", &self.action_views)?;
@@ -2923,6 +3220,9 @@ impl serde::Serialize for TransactionBodyView {
         if let Some(v) = self.memo_view.as_ref() {
             struct_ser.serialize_field("memoView", v)?;
         }
+        if let Some(v) = self.fee_view.as_ref() {
+            struct_ser.serialize_field("feeView", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -2941,6 +3241,8 @@ impl<'de> serde::Deserialize<'de> for TransactionBodyView {
             "detectionData",
             "memo_view",
             "memoView",
+            "fee_view",
+            "feeView",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -2949,6 +3251,7 @@ impl<'de> serde::Deserialize<'de> for TransactionBodyView {
             TransactionParameters,
             DetectionData,
             MemoView,
+            FeeView,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2975,6 +3278,7 @@ impl<'de> serde::Deserialize<'de> for TransactionBodyView {
                             "transactionParameters" | "transaction_parameters" => Ok(GeneratedField::TransactionParameters),
                             "detectionData" | "detection_data" => Ok(GeneratedField::DetectionData),
                             "memoView" | "memo_view" => Ok(GeneratedField::MemoView),
+                            "feeView" | "fee_view" => Ok(GeneratedField::FeeView),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2998,6 +3302,7 @@ impl<'de> serde::Deserialize<'de> for TransactionBodyView {
                 let mut transaction_parameters__ = None;
                 let mut detection_data__ = None;
                 let mut memo_view__ = None;
+                let mut fee_view__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::ActionViews => {
@@ -3024,6 +3329,12 @@ impl<'de> serde::Deserialize<'de> for TransactionBodyView {
                             }
                             memo_view__ = map_.next_value()?;
                         }
+                        GeneratedField::FeeView => {
+                            if fee_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeView"));
+                            }
+                            fee_view__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -3034,6 +3345,7 @@ impl<'de> serde::Deserialize<'de> for TransactionBodyView {
                     transaction_parameters: transaction_parameters__,
                     detection_data: detection_data__,
                     memo_view: memo_view__,
+                    fee_view: fee_view__,
                 })
             }
         }
@@ -75,6 +75,72 @@ impl ::prost::Name for Tag {
         ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
     }
 }
+/// GetTxInclusionProofRequest is the request type for the GetTxInclusionProof RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTxInclusionProofRequest {
+    /// Hash of the transaction to prove the inclusion of.
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for GetTxInclusionProofRequest {
+    const NAME: &'static str = "GetTxInclusionProofRequest";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
+/// GetTxInclusionProofResponse is the response type for the GetTxInclusionProof RPC method.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTxInclusionProofResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(uint64, tag = "2")]
+    pub index: u64,
+    /// A merkle proof of the transaction's inclusion in the data hash of the block's header, which
+    /// contains the transaction itself.
+    #[prost(message, optional, tag = "3")]
+    pub proof: ::core::option::Option<
+        super::super::super::super::tendermint::types::TxProof,
+    >,
+    /// The header of the block including the transaction, and the commit signing it.
+    #[prost(message, optional, tag = "4")]
+    pub signed_header: ::core::option::Option<
+        super::super::super::super::tendermint::types::SignedHeader,
+    >,
+    /// The result code of executing the transaction, which is zero if it succeeded.
+    #[prost(uint32, tag = "5")]
+    pub code: u32,
+    /// The events emitted by executing the transaction.
+    ///
+    /// Unlike the transaction, these aren't covered by the proof, so they're only as trustworthy as
+    /// the node reporting them.
+    #[prost(message, repeated, tag = "6")]
+    pub events: ::prost::alloc::vec::Vec<Event>,
+}
+impl ::prost::Name for GetTxInclusionProofResponse {
+    const NAME: &'static str = "GetTxInclusionProofResponse";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Event {
+    #[prost(string, tag = "1")]
+    pub r#type: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub attributes: ::prost::alloc::vec::Vec<Tag>,
+}
+impl ::prost::Name for Event {
+    const NAME: &'static str = "Event";
+    const PACKAGE: &'static str = "penumbra.util.tendermint_proxy.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.util.tendermint_proxy.v1.{}", Self::NAME)
+    }
+}
 /// BroadcastTxAsyncRequest is the request type for the BroadcastTxAsync RPC method.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -508,6 +574,38 @@ pub mod tendermint_proxy_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Fetch a proof that a transaction was included in a block, along with the block's signed
+        /// header and the events the transaction emitted.
+        pub async fn get_tx_inclusion_proof(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTxInclusionProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTxInclusionProofResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/GetTxInclusionProof",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.util.tendermint_proxy.v1.TendermintProxyService",
+                        "GetTxInclusionProof",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// ABCIQuery defines a query handler that supports ABCI queries directly to the
         /// application, bypassing Tendermint completely. The ABCI query must contain
         /// a valid and supported path, including app, custom, p2p, and store.
@@ -611,6 +709,15 @@ pub mod tendermint_proxy_service_server {
             &self,
             request: tonic::Request<super::GetTxRequest>,
         ) -> std::result::Result<tonic::Response<super::GetTxResponse>, tonic::Status>;
+        /// Fetch a proof that a transaction was included in a block, along with the block's signed
+        /// header and the events the transaction emitted.
+        async fn get_tx_inclusion_proof(
+            &self,
+            request: tonic::Request<super::GetTxInclusionProofRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTxInclusionProofResponse>,
+            tonic::Status,
+        >;
         /// ABCIQuery defines a query handler that supports ABCI queries directly to the
         /// application, bypassing Tendermint completely. The ABCI query must contain
         /// a valid and supported path, including app, custom, p2p, and store.
@@ -903,6 +1010,56 @@ pub mod tendermint_proxy_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/GetTxInclusionProof" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTxInclusionProofSvc<T: TendermintProxyService>(pub Arc<T>);
+                    impl<
+                        T: TendermintProxyService,
+                    > tonic::server::UnaryService<super::GetTxInclusionProofRequest>
+                    for GetTxInclusionProofSvc<T> {
+                        type Response = super::GetTxInclusionProofResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTxInclusionProofRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TendermintProxyService>::get_tx_inclusion_proof(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTxInclusionProofSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.util.tendermint_proxy.v1.TendermintProxyService/ABCIQuery" => {
                     #[allow(non_camel_case_types)]
                     struct ABCIQuerySvc<T: TendermintProxyService>(pub Arc<T>);
@@ -85,6 +85,15 @@ pub struct BroadcastTransactionRequest {
     /// If true, wait for the view service to detect the transaction during sync.
     #[prost(bool, tag = "2")]
     pub await_detection: bool,
+    /// If true, the view service submits a claim for each of the transaction's swaps
+    /// once the swap's batch has executed, paying with the swap's pre-paid claim fee.
+    #[prost(bool, tag = "3")]
+    pub claim_swaps: bool,
+    /// If set, makes retrying the broadcast safe: the first transaction broadcast with
+    /// a given key is recorded, and any later broadcast with the same key re-sends that
+    /// transaction instead of the one in the request, so at most one of them can land.
+    #[prost(string, tag = "4")]
+    pub idempotency_key: ::prost::alloc::string::String,
 }
 impl ::prost::Name for BroadcastTransactionRequest {
     const NAME: &'static str = "BroadcastTransactionRequest";
@@ -162,6 +171,214 @@ impl ::prost::Name for BroadcastTransactionResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BroadcastQueueRequest {
+    /// If set, only return the queued transaction with this hash.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+}
+impl ::prost::Name for BroadcastQueueRequest {
+    const NAME: &'static str = "BroadcastQueueRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BroadcastQueueResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<QueuedTransaction>,
+}
+impl ::prost::Name for BroadcastQueueResponse {
+    const NAME: &'static str = "BroadcastQueueResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A transaction accepted for broadcast by a view service that keeps a broadcast queue.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueuedTransaction {
+    /// The hash of the queued transaction.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+    #[prost(enumeration = "queued_transaction::Status", tag = "2")]
+    pub status: i32,
+    /// How many times the transaction has been sent to a fullnode.
+    #[prost(uint32, tag = "3")]
+    pub attempts: u32,
+    /// The error from the last attempt to send the transaction, if it failed.
+    #[prost(string, tag = "4")]
+    pub last_error: ::prost::alloc::string::String,
+    /// The expiry height of the transaction, or 0 if it doesn't expire.
+    #[prost(uint64, tag = "5")]
+    pub expiry_height: u64,
+    /// The height the transaction was detected at, if it's confirmed.
+    #[prost(uint64, tag = "6")]
+    pub detection_height: u64,
+    /// When the transaction will next be sent, if it's pending.
+    #[prost(message, optional, tag = "7")]
+    pub next_attempt: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+/// Nested message and enum types in `QueuedTransaction`.
+pub mod queued_transaction {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Status {
+        Unspecified = 0,
+        /// The transaction hasn't been detected on chain yet, and will be re-sent.
+        Pending = 1,
+        /// The transaction was detected on chain.
+        Confirmed = 2,
+        /// The chain passed the transaction's expiry height before it was detected.
+        Expired = 3,
+        /// Another transaction spent one of the transaction's nullifiers, so it can never be included.
+        Conflicted = 4,
+    }
+    impl Status {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Status::Unspecified => "STATUS_UNSPECIFIED",
+                Status::Pending => "STATUS_PENDING",
+                Status::Confirmed => "STATUS_CONFIRMED",
+                Status::Expired => "STATUS_EXPIRED",
+                Status::Conflicted => "STATUS_CONFLICTED",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+                "STATUS_PENDING" => Some(Self::Pending),
+                "STATUS_CONFIRMED" => Some(Self::Confirmed),
+                "STATUS_EXPIRED" => Some(Self::Expired),
+                "STATUS_CONFLICTED" => Some(Self::Conflicted),
+                _ => None,
+            }
+        }
+    }
+}
+impl ::prost::Name for QueuedTransaction {
+    const NAME: &'static str = "QueuedTransaction";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportFrontierRequest {}
+impl ::prost::Name for ExportFrontierRequest {
+    const NAME: &'static str = "ExportFrontierRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExportFrontierResponse {
+    #[prost(message, optional, tag = "1")]
+    pub frontier: ::core::option::Option<WalletFrontier>,
+}
+impl ::prost::Name for ExportFrontierResponse {
+    const NAME: &'static str = "ExportFrontierResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportFrontierRequest {
+    #[prost(message, optional, tag = "1")]
+    pub frontier: ::core::option::Option<WalletFrontier>,
+}
+impl ::prost::Name for ImportFrontierRequest {
+    const NAME: &'static str = "ImportFrontierRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportFrontierResponse {
+    /// The height the view service will resume syncing after.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+}
+impl ::prost::Name for ImportFrontierResponse {
+    const NAME: &'static str = "ImportFrontierResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// A snapshot of a wallet's synchronized state, sufficient to resume syncing from its height.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WalletFrontier {
+    /// The version of the frontier format; currently 1.
+    #[prost(uint32, tag = "1")]
+    pub version: u32,
+    /// The wallet the frontier was exported from.
+    #[prost(message, optional, tag = "2")]
+    pub wallet_id: ::core::option::Option<super::super::core::keys::v1::WalletId>,
+    /// The height of the last block scanned into the frontier.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+    /// The root of the state commitment tree after that block.
+    #[prost(message, optional, tag = "4")]
+    pub anchor: ::core::option::Option<super::super::crypto::tct::v1::MerkleRoot>,
+    /// The wallet's state commitment tree, serialized with bincode.
+    #[prost(bytes = "vec", tag = "5")]
+    pub state_commitment_tree: ::prost::alloc::vec::Vec<u8>,
+    /// The unspent notes the state commitment tree witnesses.
+    #[prost(message, repeated, tag = "6")]
+    pub notes: ::prost::alloc::vec::Vec<SpendableNoteRecord>,
+    /// The unclaimed swaps the state commitment tree witnesses.
+    #[prost(message, repeated, tag = "7")]
+    pub swaps: ::prost::alloc::vec::Vec<SwapRecord>,
+    /// The FMD parameters as of the frontier height.
+    #[prost(message, optional, tag = "8")]
+    pub fmd_parameters: ::core::option::Option<
+        super::super::core::component::shielded_pool::v1::FmdParameters,
+    >,
+    /// The gas prices as of the frontier height.
+    #[prost(message, optional, tag = "9")]
+    pub gas_prices: ::core::option::Option<
+        super::super::core::component::fee::v1::GasPrices,
+    >,
+    /// The SHA-256 hash of the encoding of the frontier with this field left empty, to detect
+    /// frontiers damaged in transit.
+    #[prost(bytes = "vec", tag = "10")]
+    pub checksum: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for WalletFrontier {
+    const NAME: &'static str = "WalletFrontier";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionPlannerRequest {
     /// The expiry height for the requested TransactionPlan
     #[prost(uint64, tag = "1")]
@@ -173,6 +390,9 @@ pub struct TransactionPlannerRequest {
     /// If present, only spends funds from the given account.
     #[prost(message, optional, tag = "4")]
     pub source: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+    /// If set, pays the fee from a fee grant made to the source account, if one is available.
+    #[prost(bool, tag = "5")]
+    pub use_fee_grant: bool,
     /// Request contents
     #[prost(message, repeated, tag = "20")]
     pub outputs: ::prost::alloc::vec::Vec<transaction_planner_request::Output>,
@@ -248,6 +468,9 @@ pub mod transaction_planner_request {
             super::super::super::core::asset::v1::AssetId,
         >,
         /// The pre-paid fee to be paid for claiming the Swap outputs.
+        ///
+        /// If unset, the view service estimates it from the current gas prices, with a margin.
+        /// Whatever the claim doesn't use is returned as change.
         #[prost(message, optional, tag = "3")]
         pub fee: ::core::option::Option<
             super::super::super::core::component::fee::v1::Fee,
@@ -569,6 +792,49 @@ impl ::prost::Name for EphemeralAddressResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EphemeralAddressesRequest {
+    /// If present, only list the ephemeral addresses of the account specified by the `AddressIndex`.
+    #[prost(message, optional, tag = "1")]
+    pub account_filter: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+}
+impl ::prost::Name for EphemeralAddressesRequest {
+    const NAME: &'static str = "EphemeralAddressesRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EphemeralAddressesResponse {
+    /// The ephemeral address.
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::super::core::keys::v1::Address>,
+    /// The index the address was derived from, including its randomizer.
+    #[prost(message, optional, tag = "2")]
+    pub address_index: ::core::option::Option<
+        super::super::core::keys::v1::AddressIndex,
+    >,
+    /// The sync height of the view service when the address was handed out.
+    #[prost(uint64, tag = "3")]
+    pub height_generated: u64,
+    /// The transactions that sent notes to the address or named it as their return address.
+    #[prost(message, repeated, tag = "4")]
+    pub transactions: ::prost::alloc::vec::Vec<
+        super::super::core::txhash::v1::TransactionId,
+    >,
+}
+impl ::prost::Name for EphemeralAddressesResponse {
+    const NAME: &'static str = "EphemeralAddressesResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BalancesRequest {
     /// If present, filter balances to only include the account specified by the `AddressIndex`.
     #[prost(message, optional, tag = "1")]
@@ -616,6 +882,51 @@ impl ::prost::Name for BalancesResponse {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceMatrixRequest {}
+impl ::prost::Name for BalanceMatrixRequest {
+    const NAME: &'static str = "BalanceMatrixRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceMatrixResponse {
+    /// The balances, ordered by account and then by asset ID.
+    #[prost(message, repeated, tag = "1")]
+    pub entries: ::prost::alloc::vec::Vec<BalanceMatrixEntry>,
+}
+impl ::prost::Name for BalanceMatrixResponse {
+    const NAME: &'static str = "BalanceMatrixResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// The balance of one asset in one account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BalanceMatrixEntry {
+    /// The account holding the balance.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+    /// The total amount of the asset held by the account, with metadata, if known.
+    #[prost(message, optional, tag = "2")]
+    pub balance_view: ::core::option::Option<super::super::core::asset::v1::ValueView>,
+    /// The number of unspent notes the balance is made up of.
+    #[prost(uint64, tag = "3")]
+    pub note_count: u64,
+}
+impl ::prost::Name for BalanceMatrixEntry {
+    const NAME: &'static str = "BalanceMatrixEntry";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
 /// Requests sync status of the view service.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -640,6 +951,12 @@ pub struct StatusResponse {
     /// Whether the view service is catching up with the chain state
     #[prost(bool, tag = "3")]
     pub catching_up: bool,
+    /// The height the wallet was created at, if known.
+    ///
+    /// Blocks before the last epoch boundary preceding this height were not scanned.
+    /// If zero, the wallet was scanned from genesis.
+    #[prost(uint64, tag = "4")]
+    pub birthday_height: u64,
 }
 impl ::prost::Name for StatusResponse {
     const NAME: &'static str = "StatusResponse";
@@ -680,6 +997,37 @@ impl ::prost::Name for StatusStreamResponse {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
+/// Requests the identity of the chain and protocol version the view service is tracking.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HandshakeRequest {}
+impl ::prost::Name for HandshakeRequest {
+    const NAME: &'static str = "HandshakeRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HandshakeResponse {
+    /// The chain ID of the chain the view service is synchronizing with.
+    #[prost(string, tag = "1")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// The version of the application protocol the view service understands.
+    #[prost(uint64, tag = "2")]
+    pub app_version: u64,
+    /// The hash of the schema of the view service's storage, identifying its version.
+    #[prost(string, tag = "3")]
+    pub storage_schema_hash: ::prost::alloc::string::String,
+}
+impl ::prost::Name for HandshakeResponse {
+    const NAME: &'static str = "HandshakeResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
 /// A query for notes known by the view service.
 ///
 /// This message uses the fact that all proto fields are optional
@@ -703,6 +1051,11 @@ pub struct NotesRequest {
     /// Ignored if `asset_id` is unset or if `include_spent` is set.
     #[prost(message, optional, tag = "6")]
     pub amount_to_spend: ::core::option::Option<super::super::core::num::v1::Amount>,
+    /// If set along with `address_index`, notes from the wallet's other accounts are
+    /// returned after those of the requested account, so that `amount_to_spend` can be
+    /// reached by spending from several accounts.
+    #[prost(bool, tag = "7")]
+    pub allow_other_accounts: bool,
 }
 impl ::prost::Name for NotesRequest {
     const NAME: &'static str = "NotesRequest";
@@ -1000,6 +1353,78 @@ impl ::prost::Name for NoteByCommitmentResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisclosedNoteByCommitmentRequest {
+    #[prost(message, optional, tag = "1")]
+    pub note_commitment: ::core::option::Option<
+        super::super::crypto::tct::v1::StateCommitment,
+    >,
+    /// The payload key of the output creating the note.
+    #[prost(message, optional, tag = "2")]
+    pub payload_key: ::core::option::Option<super::super::core::keys::v1::PayloadKey>,
+}
+impl ::prost::Name for DisclosedNoteByCommitmentRequest {
+    const NAME: &'static str = "DisclosedNoteByCommitmentRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DisclosedNoteByCommitmentResponse {
+    #[prost(message, optional, tag = "1")]
+    pub note: ::core::option::Option<
+        super::super::core::component::shielded_pool::v1::Note,
+    >,
+    /// The height of the block containing the output.
+    #[prost(uint64, tag = "2")]
+    pub height_created: u64,
+    /// The transaction containing the output.
+    #[prost(message, optional, tag = "3")]
+    pub transaction_id: ::core::option::Option<
+        super::super::core::txhash::v1::TransactionId,
+    >,
+}
+impl ::prost::Name for DisclosedNoteByCommitmentResponse {
+    const NAME: &'static str = "DisclosedNoteByCommitmentResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WitnessByCommitmentRequest {
+    /// The state commitments to witness.
+    #[prost(message, repeated, tag = "1")]
+    pub commitments: ::prost::alloc::vec::Vec<
+        super::super::crypto::tct::v1::StateCommitment,
+    >,
+}
+impl ::prost::Name for WitnessByCommitmentRequest {
+    const NAME: &'static str = "WitnessByCommitmentRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WitnessByCommitmentResponse {
+    #[prost(message, optional, tag = "1")]
+    pub witness_data: ::core::option::Option<
+        super::super::core::transaction::v1::WitnessData,
+    >,
+}
+impl ::prost::Name for WitnessByCommitmentResponse {
+    const NAME: &'static str = "WitnessByCommitmentResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SwapByCommitmentRequest {
     #[prost(message, optional, tag = "2")]
     pub swap_commitment: ::core::option::Option<
@@ -1084,6 +1509,39 @@ impl ::prost::Name for NullifierStatusResponse {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionByNullifierRequest {
+    /// The nullifier of a note or swap of the wallet.
+    #[prost(message, optional, tag = "1")]
+    pub nullifier: ::core::option::Option<
+        super::super::core::component::sct::v1::Nullifier,
+    >,
+}
+impl ::prost::Name for TransactionByNullifierRequest {
+    const NAME: &'static str = "TransactionByNullifierRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransactionByNullifierResponse {
+    /// The hash of the transaction that spent the nullifier, absent if no known transaction spent it.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<super::super::core::txhash::v1::TransactionId>,
+    /// The height of the block the transaction was included in.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+}
+impl ::prost::Name for TransactionByNullifierResponse {
+    const NAME: &'static str = "TransactionByNullifierResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionInfoByHashRequest {
     /// The transaction hash to query for.
     #[prost(message, optional, tag = "2")]
@@ -1137,6 +1595,9 @@ pub struct TransactionInfo {
     pub view: ::core::option::Option<
         super::super::core::transaction::v1::TransactionView,
     >,
+    /// The time of the block the transaction was included in, if known.
+    #[prost(message, optional, tag = "6")]
+    pub block_time: ::core::option::Option<::pbjson_types::Timestamp>,
 }
 impl ::prost::Name for TransactionInfo {
     const NAME: &'static str = "TransactionInfo";
@@ -1321,13 +1782,52 @@ impl ::prost::Name for OwnedPositionIdsResponse {
         ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
     }
 }
-/// Requests information on an asset by asset id
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AssetMetadataByIdRequest {
-    /// The asset id to request information on.
-    #[prost(message, optional, tag = "2")]
-    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+pub struct WatchPositionsRequest {
+    /// The IDs of the positions to watch, which may not be on chain yet.
+    #[prost(message, repeated, tag = "1")]
+    pub position_ids: ::prost::alloc::vec::Vec<
+        super::super::core::component::dex::v1::PositionId,
+    >,
+}
+impl ::prost::Name for WatchPositionsRequest {
+    const NAME: &'static str = "WatchPositionsRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchPositionsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<
+        super::super::core::component::dex::v1::PositionId,
+    >,
+    /// The position as of `height`, if it has been opened by then.
+    #[prost(message, optional, tag = "2")]
+    pub position: ::core::option::Option<
+        super::super::core::component::dex::v1::Position,
+    >,
+    /// The sync height at which the position was in this state.
+    #[prost(uint64, tag = "3")]
+    pub height: u64,
+}
+impl ::prost::Name for WatchPositionsResponse {
+    const NAME: &'static str = "WatchPositionsResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("penumbra.view.v1.{}", Self::NAME)
+    }
+}
+/// Requests information on an asset by asset id
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetMetadataByIdRequest {
+    /// The asset id to request information on.
+    #[prost(message, optional, tag = "2")]
+    pub asset_id: ::core::option::Option<super::super::core::asset::v1::AssetId>,
 }
 impl ::prost::Name for AssetMetadataByIdRequest {
     const NAME: &'static str = "AssetMetadataByIdRequest";
@@ -1492,6 +1992,33 @@ pub mod view_service_client {
                 .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "StatusStream"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Identify the chain and protocol version the view service is tracking, and the version of its
+        /// storage, so that clients can check they match the fullnode they are talking to.
+        pub async fn handshake(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HandshakeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::HandshakeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/Handshake",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Handshake"));
+            self.inner.unary(req, path, codec).await
+        }
         /// Queries for notes that have been accepted by the chain.
         /// Returns a stream of `NotesResponse`s.
         pub async fn notes(
@@ -1798,6 +2325,36 @@ pub mod view_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Query for the ephemeral addresses handed out by `EphemeralAddress`, along with the
+        /// transactions that used them.
+        /// Returns a stream of `EphemeralAddressesResponse`s.
+        pub async fn ephemeral_addresses(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EphemeralAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::EphemeralAddressesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/EphemeralAddresses",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "EphemeralAddresses"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Query for balance of a given address.
         /// Returns a stream of `BalancesResponses`.
         pub async fn balances(
@@ -1825,6 +2382,37 @@ pub mod view_service_client {
                 .insert(GrpcMethod::new("penumbra.view.v1.ViewService", "Balances"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query for the balance of every asset in every account at once.
+        ///
+        /// Unlike `Balances`, which returns one entry per note, the balances are summed up by the view
+        /// service, along with the number of notes making up each one.
+        pub async fn balance_matrix(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BalanceMatrixRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BalanceMatrixResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/BalanceMatrix",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "BalanceMatrix"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Query for a note by its note commitment, optionally waiting until the note is detected.
         pub async fn note_by_commitment(
             &mut self,
@@ -1937,6 +2525,34 @@ pub mod view_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Query for the transaction that spent one of the wallet's nullifiers, if it has been spent.
+        pub async fn transaction_by_nullifier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TransactionByNullifierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionByNullifierResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/TransactionByNullifier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "TransactionByNullifier"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// Query for a given transaction by its hash.
         pub async fn transaction_info_by_hash(
             &mut self,
@@ -2025,6 +2641,36 @@ pub mod view_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Watch the given liquidity positions, streaming each one's on-chain state as of the latest
+        /// synced block, first when the request is made and then whenever it changes, such as when the
+        /// position is opened, filled against, or closed.
+        pub async fn watch_positions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WatchPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::WatchPositionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/WatchPositions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "WatchPositions"),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
         /// Translates a high-level intent ("send X funds to Y address") into a complete transaction plan.
         pub async fn transaction_planner(
             &mut self,
@@ -2191,6 +2837,161 @@ pub mod view_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query the transactions queued for broadcast, if the view service keeps a broadcast queue.
+        ///
+        /// Queued transactions are re-sent with backoff until they're detected on chain or expire,
+        /// including after the view service restarts.
+        pub async fn broadcast_queue(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BroadcastQueueRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BroadcastQueueResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/BroadcastQueue",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "BroadcastQueue"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Export the wallet's state commitment tree, along with the unspent notes and unclaimed swaps
+        /// it witnesses, so that the wallet can be moved to another view service without rescanning.
+        pub async fn export_frontier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportFrontierResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/ExportFrontier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "ExportFrontier"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Import a frontier exported by `ExportFrontier`, and resume syncing from its height.
+        ///
+        /// The frontier must have been exported for the same wallet, at a height beyond that the view
+        /// service has synced to. Notes and swaps detected before that height are replaced by those in
+        /// the frontier.
+        pub async fn import_frontier(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportFrontierResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/ImportFrontier",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "ImportFrontier"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Query for a note by its note commitment, decrypting it with the given payload key.
+        ///
+        /// Unlike `NoteByCommitment`, the note need not be owned by the wallet: it can be any output of
+        /// a transaction known to the view service, such as a payment the wallet sent.
+        pub async fn disclosed_note_by_commitment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DisclosedNoteByCommitmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DisclosedNoteByCommitmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/DisclosedNoteByCommitment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "DisclosedNoteByCommitment"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Returns authentication paths for the given state commitments, to a common anchor.
+        ///
+        /// Unlike `Witness`, this method takes commitments rather than a transaction plan, for clients
+        /// that build their own proofs. Only commitments the wallet's state commitment tree witnesses,
+        /// those of the wallet's own notes and swaps, can be witnessed.
+        pub async fn witness_by_commitment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WitnessByCommitmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WitnessByCommitmentResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/WitnessByCommitment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("penumbra.view.v1.ViewService", "WitnessByCommitment"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -2221,6 +3022,15 @@ pub mod view_service_server {
             tonic::Response<Self::StatusStreamStream>,
             tonic::Status,
         >;
+        /// Identify the chain and protocol version the view service is tracking, and the version of its
+        /// storage, so that clients can check they match the fullnode they are talking to.
+        async fn handshake(
+            &self,
+            request: tonic::Request<super::HandshakeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::HandshakeResponse>,
+            tonic::Status,
+        >;
         /// Server streaming response type for the Notes method.
         type NotesStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::NotesResponse, tonic::Status>,
@@ -2327,6 +3137,25 @@ pub mod view_service_server {
             tonic::Response<super::EphemeralAddressResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the EphemeralAddresses method.
+        type EphemeralAddressesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::EphemeralAddressesResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Query for the ephemeral addresses handed out by `EphemeralAddress`, along with the
+        /// transactions that used them.
+        /// Returns a stream of `EphemeralAddressesResponse`s.
+        async fn ephemeral_addresses(
+            &self,
+            request: tonic::Request<super::EphemeralAddressesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::EphemeralAddressesStream>,
+            tonic::Status,
+        >;
         /// Server streaming response type for the Balances method.
         type BalancesStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::BalancesResponse, tonic::Status>,
@@ -2339,6 +3168,17 @@ pub mod view_service_server {
             &self,
             request: tonic::Request<super::BalancesRequest>,
         ) -> std::result::Result<tonic::Response<Self::BalancesStream>, tonic::Status>;
+        /// Query for the balance of every asset in every account at once.
+        ///
+        /// Unlike `Balances`, which returns one entry per note, the balances are summed up by the view
+        /// service, along with the number of notes making up each one.
+        async fn balance_matrix(
+            &self,
+            request: tonic::Request<super::BalanceMatrixRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BalanceMatrixResponse>,
+            tonic::Status,
+        >;
         /// Query for a note by its note commitment, optionally waiting until the note is detected.
         async fn note_by_commitment(
             &self,
@@ -2377,6 +3217,14 @@ pub mod view_service_server {
             tonic::Response<super::NullifierStatusResponse>,
             tonic::Status,
         >;
+        /// Query for the transaction that spent one of the wallet's nullifiers, if it has been spent.
+        async fn transaction_by_nullifier(
+            &self,
+            request: tonic::Request<super::TransactionByNullifierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TransactionByNullifierResponse>,
+            tonic::Status,
+        >;
         /// Query for a given transaction by its hash.
         async fn transaction_info_by_hash(
             &self,
@@ -2417,6 +3265,25 @@ pub mod view_service_server {
             tonic::Response<Self::OwnedPositionIdsStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the WatchPositions method.
+        type WatchPositionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<
+                    super::WatchPositionsResponse,
+                    tonic::Status,
+                >,
+            >
+            + Send
+            + 'static;
+        /// Watch the given liquidity positions, streaming each one's on-chain state as of the latest
+        /// synced block, first when the request is made and then whenever it changes, such as when the
+        /// position is opened, filled against, or closed.
+        async fn watch_positions(
+            &self,
+            request: tonic::Request<super::WatchPositionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::WatchPositionsStream>,
+            tonic::Status,
+        >;
         /// Translates a high-level intent ("send X funds to Y address") into a complete transaction plan.
         async fn transaction_planner(
             &self,
@@ -2501,6 +3368,61 @@ pub mod view_service_server {
             tonic::Response<Self::BroadcastTransactionStream>,
             tonic::Status,
         >;
+        /// Query the transactions queued for broadcast, if the view service keeps a broadcast queue.
+        ///
+        /// Queued transactions are re-sent with backoff until they're detected on chain or expire,
+        /// including after the view service restarts.
+        async fn broadcast_queue(
+            &self,
+            request: tonic::Request<super::BroadcastQueueRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BroadcastQueueResponse>,
+            tonic::Status,
+        >;
+        /// Export the wallet's state commitment tree, along with the unspent notes and unclaimed swaps
+        /// it witnesses, so that the wallet can be moved to another view service without rescanning.
+        async fn export_frontier(
+            &self,
+            request: tonic::Request<super::ExportFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExportFrontierResponse>,
+            tonic::Status,
+        >;
+        /// Import a frontier exported by `ExportFrontier`, and resume syncing from its height.
+        ///
+        /// The frontier must have been exported for the same wallet, at a height beyond that the view
+        /// service has synced to. Notes and swaps detected before that height are replaced by those in
+        /// the frontier.
+        async fn import_frontier(
+            &self,
+            request: tonic::Request<super::ImportFrontierRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportFrontierResponse>,
+            tonic::Status,
+        >;
+        /// Query for a note by its note commitment, decrypting it with the given payload key.
+        ///
+        /// Unlike `NoteByCommitment`, the note need not be owned by the wallet: it can be any output of
+        /// a transaction known to the view service, such as a payment the wallet sent.
+        async fn disclosed_note_by_commitment(
+            &self,
+            request: tonic::Request<super::DisclosedNoteByCommitmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DisclosedNoteByCommitmentResponse>,
+            tonic::Status,
+        >;
+        /// Returns authentication paths for the given state commitments, to a common anchor.
+        ///
+        /// Unlike `Witness`, this method takes commitments rather than a transaction plan, for clients
+        /// that build their own proofs. Only commitments the wallet's state commitment tree witnesses,
+        /// those of the wallet's own notes and swaps, can be witnessed.
+        async fn witness_by_commitment(
+            &self,
+            request: tonic::Request<super::WitnessByCommitmentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::WitnessByCommitmentResponse>,
+            tonic::Status,
+        >;
     }
     /// The view RPC is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -2678,6 +3600,52 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/Handshake" => {
+                    #[allow(non_camel_case_types)]
+                    struct HandshakeSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::HandshakeRequest>
+                    for HandshakeSvc<T> {
+                        type Response = super::HandshakeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HandshakeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::handshake(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = HandshakeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1.ViewService/Notes" => {
                     #[allow(non_camel_case_types)]
                     struct NotesSvc<T: ViewService>(pub Arc<T>);
@@ -3188,6 +4156,55 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/EphemeralAddresses" => {
+                    #[allow(non_camel_case_types)]
+                    struct EphemeralAddressesSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<
+                        super::EphemeralAddressesRequest,
+                    > for EphemeralAddressesSvc<T> {
+                        type Response = super::EphemeralAddressesResponse;
+                        type ResponseStream = T::EphemeralAddressesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EphemeralAddressesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::ephemeral_addresses(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = EphemeralAddressesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1.ViewService/Balances" => {
                     #[allow(non_camel_case_types)]
                     struct BalancesSvc<T: ViewService>(pub Arc<T>);
@@ -3235,6 +4252,52 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/BalanceMatrix" => {
+                    #[allow(non_camel_case_types)]
+                    struct BalanceMatrixSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::BalanceMatrixRequest>
+                    for BalanceMatrixSvc<T> {
+                        type Response = super::BalanceMatrixResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BalanceMatrixRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::balance_matrix(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BalanceMatrixSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1.ViewService/NoteByCommitment" => {
                     #[allow(non_camel_case_types)]
                     struct NoteByCommitmentSvc<T: ViewService>(pub Arc<T>);
@@ -3422,6 +4485,52 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/TransactionByNullifier" => {
+                    #[allow(non_camel_case_types)]
+                    struct TransactionByNullifierSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::TransactionByNullifierRequest>
+                    for TransactionByNullifierSvc<T> {
+                        type Response = super::TransactionByNullifierResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TransactionByNullifierRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::transaction_by_nullifier(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = TransactionByNullifierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1.ViewService/TransactionInfoByHash" => {
                     #[allow(non_camel_case_types)]
                     struct TransactionInfoByHashSvc<T: ViewService>(pub Arc<T>);
@@ -3569,6 +4678,55 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/WatchPositions" => {
+                    #[allow(non_camel_case_types)]
+                    struct WatchPositionsSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<
+                        super::WatchPositionsRequest,
+                    > for WatchPositionsSvc<T> {
+                        type Response = super::WatchPositionsResponse;
+                        type ResponseStream = T::WatchPositionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WatchPositionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::watch_positions(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WatchPositionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/penumbra.view.v1.ViewService/TransactionPlanner" => {
                     #[allow(non_camel_case_types)]
                     struct TransactionPlannerSvc<T: ViewService>(pub Arc<T>);
@@ -3808,6 +4966,236 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/BroadcastQueue" => {
+                    #[allow(non_camel_case_types)]
+                    struct BroadcastQueueSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::BroadcastQueueRequest>
+                    for BroadcastQueueSvc<T> {
+                        type Response = super::BroadcastQueueResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BroadcastQueueRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::broadcast_queue(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = BroadcastQueueSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/ExportFrontier" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportFrontierSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::ExportFrontierRequest>
+                    for ExportFrontierSvc<T> {
+                        type Response = super::ExportFrontierResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportFrontierRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::export_frontier(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExportFrontierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/ImportFrontier" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportFrontierSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::ImportFrontierRequest>
+                    for ImportFrontierSvc<T> {
+                        type Response = super::ImportFrontierResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ImportFrontierRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::import_frontier(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ImportFrontierSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/DisclosedNoteByCommitment" => {
+                    #[allow(non_camel_case_types)]
+                    struct DisclosedNoteByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::DisclosedNoteByCommitmentRequest>
+                    for DisclosedNoteByCommitmentSvc<T> {
+                        type Response = super::DisclosedNoteByCommitmentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DisclosedNoteByCommitmentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::disclosed_note_by_commitment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = DisclosedNoteByCommitmentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.view.v1.ViewService/WitnessByCommitment" => {
+                    #[allow(non_camel_case_types)]
+                    struct WitnessByCommitmentSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::UnaryService<super::WitnessByCommitmentRequest>
+                    for WitnessByCommitmentSvc<T> {
+                        type Response = super::WitnessByCommitmentResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WitnessByCommitmentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::witness_by_commitment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = WitnessByCommitmentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         Ok(
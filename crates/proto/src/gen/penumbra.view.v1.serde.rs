@@ -1249,7 +1249,7 @@ impl<'de> serde::Deserialize<'de> for authorize_and_build_response::Complete {
         deserializer.deserialize_struct("penumbra.view.v1.AuthorizeAndBuildResponse.Complete", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BalancesRequest {
+impl serde::Serialize for BalanceMatrixEntry {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1257,39 +1257,48 @@ impl serde::Serialize for BalancesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.account_filter.is_some() {
+        if self.account.is_some() {
             len += 1;
         }
-        if self.asset_id_filter.is_some() {
+        if self.balance_view.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalancesRequest", len)?;
-        if let Some(v) = self.account_filter.as_ref() {
-            struct_ser.serialize_field("accountFilter", v)?;
+        if self.note_count != 0 {
+            len += 1;
         }
-        if let Some(v) = self.asset_id_filter.as_ref() {
-            struct_ser.serialize_field("assetIdFilter", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalanceMatrixEntry", len)?;
+        if let Some(v) = self.account.as_ref() {
+            struct_ser.serialize_field("account", v)?;
+        }
+        if let Some(v) = self.balance_view.as_ref() {
+            struct_ser.serialize_field("balanceView", v)?;
+        }
+        if self.note_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("noteCount", ToString::to_string(&self.note_count).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BalancesRequest {
+impl<'de> serde::Deserialize<'de> for BalanceMatrixEntry {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "account_filter",
-            "accountFilter",
-            "asset_id_filter",
-            "assetIdFilter",
+            "account",
+            "balance_view",
+            "balanceView",
+            "note_count",
+            "noteCount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AccountFilter,
-            AssetIdFilter,
+            Account,
+            BalanceView,
+            NoteCount,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1312,8 +1321,9 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
-                            "assetIdFilter" | "asset_id_filter" => Ok(GeneratedField::AssetIdFilter),
+                            "account" => Ok(GeneratedField::Account),
+                            "balanceView" | "balance_view" => Ok(GeneratedField::BalanceView),
+                            "noteCount" | "note_count" => Ok(GeneratedField::NoteCount),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1323,103 +1333,79 @@ impl<'de> serde::Deserialize<'de> for BalancesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BalancesRequest;
+            type Value = BalanceMatrixEntry;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.BalancesRequest")
+                formatter.write_str("struct penumbra.view.v1.BalanceMatrixEntry")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalancesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalanceMatrixEntry, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut account_filter__ = None;
-                let mut asset_id_filter__ = None;
+                let mut account__ = None;
+                let mut balance_view__ = None;
+                let mut note_count__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AccountFilter => {
-                            if account_filter__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("accountFilter"));
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
                             }
-                            account_filter__ = map_.next_value()?;
+                            account__ = map_.next_value()?;
                         }
-                        GeneratedField::AssetIdFilter => {
-                            if asset_id_filter__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetIdFilter"));
+                        GeneratedField::BalanceView => {
+                            if balance_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceView"));
                             }
-                            asset_id_filter__ = map_.next_value()?;
+                            balance_view__ = map_.next_value()?;
+                        }
+                        GeneratedField::NoteCount => {
+                            if note_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCount"));
+                            }
+                            note_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BalancesRequest {
-                    account_filter: account_filter__,
-                    asset_id_filter: asset_id_filter__,
+                Ok(BalanceMatrixEntry {
+                    account: account__,
+                    balance_view: balance_view__,
+                    note_count: note_count__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.BalancesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BalanceMatrixEntry", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BalancesResponse {
+impl serde::Serialize for BalanceMatrixRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.account.is_some() {
-            len += 1;
-        }
-        if self.balance.is_some() {
-            len += 1;
-        }
-        if self.account_address.is_some() {
-            len += 1;
-        }
-        if self.balance_view.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalancesResponse", len)?;
-        if let Some(v) = self.account.as_ref() {
-            struct_ser.serialize_field("account", v)?;
-        }
-        if let Some(v) = self.balance.as_ref() {
-            struct_ser.serialize_field("balance", v)?;
-        }
-        if let Some(v) = self.account_address.as_ref() {
-            struct_ser.serialize_field("accountAddress", v)?;
-        }
-        if let Some(v) = self.balance_view.as_ref() {
-            struct_ser.serialize_field("balanceView", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.BalanceMatrixRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BalancesResponse {
+impl<'de> serde::Deserialize<'de> for BalanceMatrixRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "account",
-            "balance",
-            "account_address",
-            "accountAddress",
-            "balance_view",
-            "balanceView",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Account,
-            Balance,
-            AccountAddress,
-            BalanceView,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1441,13 +1427,7 @@ impl<'de> serde::Deserialize<'de> for BalancesResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "account" => Ok(GeneratedField::Account),
-                            "balance" => Ok(GeneratedField::Balance),
-                            "accountAddress" | "account_address" => Ok(GeneratedField::AccountAddress),
-                            "balanceView" | "balance_view" => Ok(GeneratedField::BalanceView),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1455,63 +1435,27 @@ impl<'de> serde::Deserialize<'de> for BalancesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BalancesResponse;
+            type Value = BalanceMatrixRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.BalancesResponse")
+                formatter.write_str("struct penumbra.view.v1.BalanceMatrixRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalancesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalanceMatrixRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut account__ = None;
-                let mut balance__ = None;
-                let mut account_address__ = None;
-                let mut balance_view__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Account => {
-                            if account__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("account"));
-                            }
-                            account__ = map_.next_value()?;
-                        }
-                        GeneratedField::Balance => {
-                            if balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("balance"));
-                            }
-                            balance__ = map_.next_value()?;
-                        }
-                        GeneratedField::AccountAddress => {
-                            if account_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("accountAddress"));
-                            }
-                            account_address__ = map_.next_value()?;
-                        }
-                        GeneratedField::BalanceView => {
-                            if balance_view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("balanceView"));
-                            }
-                            balance_view__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(BalancesResponse {
-                    account: account__,
-                    balance: balance__,
-                    account_address: account_address__,
-                    balance_view: balance_view__,
+                Ok(BalanceMatrixRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.BalancesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BalanceMatrixRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BroadcastTransactionRequest {
+impl serde::Serialize for BalanceMatrixResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1519,38 +1463,29 @@ impl serde::Serialize for BroadcastTransactionRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.transaction.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
+        if !self.entries.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionRequest", len)?;
-        if let Some(v) = self.transaction.as_ref() {
-            struct_ser.serialize_field("transaction", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalanceMatrixResponse", len)?;
+        if !self.entries.is_empty() {
+            struct_ser.serialize_field("entries", &self.entries)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
+impl<'de> serde::Deserialize<'de> for BalanceMatrixResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "transaction",
-            "await_detection",
-            "awaitDetection",
+            "entries",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Transaction,
-            AwaitDetection,
+            Entries,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1573,8 +1508,7 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "transaction" => Ok(GeneratedField::Transaction),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "entries" => Ok(GeneratedField::Entries),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1584,47 +1518,39 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BroadcastTransactionRequest;
+            type Value = BalanceMatrixResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionRequest")
+                formatter.write_str("struct penumbra.view.v1.BalanceMatrixResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BroadcastTransactionRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalanceMatrixResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut transaction__ = None;
-                let mut await_detection__ = None;
+                let mut entries__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Transaction => {
-                            if transaction__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transaction"));
-                            }
-                            transaction__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Entries => {
+                            if entries__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("entries"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            entries__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BroadcastTransactionRequest {
-                    transaction: transaction__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(BalanceMatrixResponse {
+                    entries: entries__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BalanceMatrixResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for BroadcastTransactionResponse {
+impl serde::Serialize for BalancesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1632,39 +1558,39 @@ impl serde::Serialize for BroadcastTransactionResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.status.is_some() {
+        if self.account_filter.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse", len)?;
-        if let Some(v) = self.status.as_ref() {
-            match v {
-                broadcast_transaction_response::Status::BroadcastSuccess(v) => {
-                    struct_ser.serialize_field("broadcastSuccess", v)?;
-                }
-                broadcast_transaction_response::Status::Confirmed(v) => {
-                    struct_ser.serialize_field("confirmed", v)?;
-                }
-            }
+        if self.asset_id_filter.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalancesRequest", len)?;
+        if let Some(v) = self.account_filter.as_ref() {
+            struct_ser.serialize_field("accountFilter", v)?;
+        }
+        if let Some(v) = self.asset_id_filter.as_ref() {
+            struct_ser.serialize_field("assetIdFilter", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for BroadcastTransactionResponse {
+impl<'de> serde::Deserialize<'de> for BalancesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "broadcast_success",
-            "broadcastSuccess",
-            "confirmed",
+            "account_filter",
+            "accountFilter",
+            "asset_id_filter",
+            "assetIdFilter",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            BroadcastSuccess,
-            Confirmed,
+            AccountFilter,
+            AssetIdFilter,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1687,8 +1613,8 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "broadcastSuccess" | "broadcast_success" => Ok(GeneratedField::BroadcastSuccess),
-                            "confirmed" => Ok(GeneratedField::Confirmed),
+                            "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
+                            "assetIdFilter" | "asset_id_filter" => Ok(GeneratedField::AssetIdFilter),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1698,47 +1624,47 @@ impl<'de> serde::Deserialize<'de> for BroadcastTransactionResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = BroadcastTransactionResponse;
+            type Value = BalancesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionResponse")
+                formatter.write_str("struct penumbra.view.v1.BalancesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BroadcastTransactionResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalancesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut status__ = None;
+                let mut account_filter__ = None;
+                let mut asset_id_filter__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::BroadcastSuccess => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("broadcastSuccess"));
+                        GeneratedField::AccountFilter => {
+                            if account_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountFilter"));
                             }
-                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(broadcast_transaction_response::Status::BroadcastSuccess)
-;
+                            account_filter__ = map_.next_value()?;
                         }
-                        GeneratedField::Confirmed => {
-                            if status__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("confirmed"));
+                        GeneratedField::AssetIdFilter => {
+                            if asset_id_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetIdFilter"));
                             }
-                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(broadcast_transaction_response::Status::Confirmed)
-;
+                            asset_id_filter__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(BroadcastTransactionResponse {
-                    status: status__,
+                Ok(BalancesRequest {
+                    account_filter: account_filter__,
+                    asset_id_filter: asset_id_filter__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BalancesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for broadcast_transaction_response::BroadcastSuccess {
+impl serde::Serialize for BalancesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1746,29 +1672,55 @@ impl serde::Serialize for broadcast_transaction_response::BroadcastSuccess {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id.is_some() {
+        if self.account.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess", len)?;
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
+        if self.balance.is_some() {
+            len += 1;
+        }
+        if self.account_address.is_some() {
+            len += 1;
+        }
+        if self.balance_view.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BalancesResponse", len)?;
+        if let Some(v) = self.account.as_ref() {
+            struct_ser.serialize_field("account", v)?;
+        }
+        if let Some(v) = self.balance.as_ref() {
+            struct_ser.serialize_field("balance", v)?;
+        }
+        if let Some(v) = self.account_address.as_ref() {
+            struct_ser.serialize_field("accountAddress", v)?;
+        }
+        if let Some(v) = self.balance_view.as_ref() {
+            struct_ser.serialize_field("balanceView", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastSuccess {
+impl<'de> serde::Deserialize<'de> for BalancesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
+            "account",
+            "balance",
+            "account_address",
+            "accountAddress",
+            "balance_view",
+            "balanceView",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
+            Account,
+            Balance,
+            AccountAddress,
+            BalanceView,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1791,7 +1743,10 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
+                            "account" => Ok(GeneratedField::Account),
+                            "balance" => Ok(GeneratedField::Balance),
+                            "accountAddress" | "account_address" => Ok(GeneratedField::AccountAddress),
+                            "balanceView" | "balance_view" => Ok(GeneratedField::BalanceView),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1801,39 +1756,63 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastS
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = broadcast_transaction_response::BroadcastSuccess;
+            type Value = BalancesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess")
+                formatter.write_str("struct penumbra.view.v1.BalancesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<broadcast_transaction_response::BroadcastSuccess, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BalancesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
+                let mut account__ = None;
+                let mut balance__ = None;
+                let mut account_address__ = None;
+                let mut balance_view__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::Account => {
+                            if account__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("account"));
                             }
-                            id__ = map_.next_value()?;
+                            account__ = map_.next_value()?;
+                        }
+                        GeneratedField::Balance => {
+                            if balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balance"));
+                            }
+                            balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::AccountAddress => {
+                            if account_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountAddress"));
+                            }
+                            account_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::BalanceView => {
+                            if balance_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("balanceView"));
+                            }
+                            balance_view__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(broadcast_transaction_response::BroadcastSuccess {
-                    id: id__,
+                Ok(BalancesResponse {
+                    account: account__,
+                    balance: balance__,
+                    account_address: account_address__,
+                    balance_view: balance_view__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BalancesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for broadcast_transaction_response::Confirmed {
+impl serde::Serialize for BroadcastQueueRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1844,21 +1823,14 @@ impl serde::Serialize for broadcast_transaction_response::Confirmed {
         if self.id.is_some() {
             len += 1;
         }
-        if self.detection_height != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastQueueRequest", len)?;
         if let Some(v) = self.id.as_ref() {
             struct_ser.serialize_field("id", v)?;
         }
-        if self.detection_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("detectionHeight", ToString::to_string(&self.detection_height).as_str())?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed {
+impl<'de> serde::Deserialize<'de> for BroadcastQueueRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -1866,14 +1838,11 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
     {
         const FIELDS: &[&str] = &[
             "id",
-            "detection_height",
-            "detectionHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Id,
-            DetectionHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1897,7 +1866,6 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
                     {
                         match value {
                             "id" => Ok(GeneratedField::Id),
-                            "detectionHeight" | "detection_height" => Ok(GeneratedField::DetectionHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1907,18 +1875,17 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = broadcast_transaction_response::Confirmed;
+            type Value = BroadcastQueueRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionResponse.Confirmed")
+                formatter.write_str("struct penumbra.view.v1.BroadcastQueueRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<broadcast_transaction_response::Confirmed, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BroadcastQueueRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut id__ = None;
-                let mut detection_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Id => {
@@ -1927,29 +1894,20 @@ impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed
                             }
                             id__ = map_.next_value()?;
                         }
-                        GeneratedField::DetectionHeight => {
-                            if detection_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("detectionHeight"));
-                            }
-                            detection_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(broadcast_transaction_response::Confirmed {
+                Ok(BroadcastQueueRequest {
                     id: id__,
-                    detection_height: detection_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BroadcastQueueRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EphemeralAddressRequest {
+impl serde::Serialize for BroadcastQueueResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1957,30 +1915,29 @@ impl serde::Serialize for EphemeralAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address_index.is_some() {
+        if !self.transactions.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressRequest", len)?;
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastQueueResponse", len)?;
+        if !self.transactions.is_empty() {
+            struct_ser.serialize_field("transactions", &self.transactions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
+impl<'de> serde::Deserialize<'de> for BroadcastQueueResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address_index",
-            "addressIndex",
+            "transactions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AddressIndex,
+            Transactions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2003,7 +1960,7 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "transactions" => Ok(GeneratedField::Transactions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2013,39 +1970,39 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EphemeralAddressRequest;
+            type Value = BroadcastQueueResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.EphemeralAddressRequest")
+                formatter.write_str("struct penumbra.view.v1.BroadcastQueueResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BroadcastQueueResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address_index__ = None;
+                let mut transactions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                        GeneratedField::Transactions => {
+                            if transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactions"));
                             }
-                            address_index__ = map_.next_value()?;
+                            transactions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EphemeralAddressRequest {
-                    address_index: address_index__,
+                Ok(BroadcastQueueResponse {
+                    transactions: transactions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BroadcastQueueResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EphemeralAddressResponse {
+impl serde::Serialize for BroadcastTransactionRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2053,29 +2010,56 @@ impl serde::Serialize for EphemeralAddressResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.transaction.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressResponse", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if self.await_detection {
+            len += 1;
+        }
+        if self.claim_swaps {
+            len += 1;
+        }
+        if !self.idempotency_key.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionRequest", len)?;
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        if self.claim_swaps {
+            struct_ser.serialize_field("claimSwaps", &self.claim_swaps)?;
+        }
+        if !self.idempotency_key.is_empty() {
+            struct_ser.serialize_field("idempotencyKey", &self.idempotency_key)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
+impl<'de> serde::Deserialize<'de> for BroadcastTransactionRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "transaction",
+            "await_detection",
+            "awaitDetection",
+            "claim_swaps",
+            "claimSwaps",
+            "idempotency_key",
+            "idempotencyKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            Transaction,
+            AwaitDetection,
+            ClaimSwaps,
+            IdempotencyKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2098,7 +2082,10 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "transaction" => Ok(GeneratedField::Transaction),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "claimSwaps" | "claim_swaps" => Ok(GeneratedField::ClaimSwaps),
+                            "idempotencyKey" | "idempotency_key" => Ok(GeneratedField::IdempotencyKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2108,61 +2095,103 @@ impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EphemeralAddressResponse;
+            type Value = BroadcastTransactionRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.EphemeralAddressResponse")
+                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BroadcastTransactionRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut transaction__ = None;
+                let mut await_detection__ = None;
+                let mut claim_swaps__ = None;
+                let mut idempotency_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Transaction => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transaction"));
                             }
-                            address__ = map_.next_value()?;
+                            transaction__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ClaimSwaps => {
+                            if claim_swaps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimSwaps"));
+                            }
+                            claim_swaps__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IdempotencyKey => {
+                            if idempotency_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("idempotencyKey"));
+                            }
+                            idempotency_key__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EphemeralAddressResponse {
-                    address: address__,
+                Ok(BroadcastTransactionRequest {
+                    transaction: transaction__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                    claim_swaps: claim_swaps__.unwrap_or_default(),
+                    idempotency_key: idempotency_key__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FmdParametersRequest {
+impl serde::Serialize for BroadcastTransactionResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersRequest", len)?;
+        let mut len = 0;
+        if self.status.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse", len)?;
+        if let Some(v) = self.status.as_ref() {
+            match v {
+                broadcast_transaction_response::Status::BroadcastSuccess(v) => {
+                    struct_ser.serialize_field("broadcastSuccess", v)?;
+                }
+                broadcast_transaction_response::Status::Confirmed(v) => {
+                    struct_ser.serialize_field("confirmed", v)?;
+                }
+            }
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
+impl<'de> serde::Deserialize<'de> for BroadcastTransactionResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "broadcast_success",
+            "broadcastSuccess",
+            "confirmed",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            BroadcastSuccess,
+            Confirmed,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2184,35 +2213,59 @@ impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
-                    }
-                }
-                deserializer.deserialize_identifier(GeneratedVisitor)
+                        match value {
+                            "broadcastSuccess" | "broadcast_success" => Ok(GeneratedField::BroadcastSuccess),
+                            "confirmed" => Ok(GeneratedField::Confirmed),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
             }
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FmdParametersRequest;
+            type Value = BroadcastTransactionResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.FMDParametersRequest")
+                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BroadcastTransactionResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut status__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BroadcastSuccess => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("broadcastSuccess"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(broadcast_transaction_response::Status::BroadcastSuccess)
+;
+                        }
+                        GeneratedField::Confirmed => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("confirmed"));
+                            }
+                            status__ = map_.next_value::<::std::option::Option<_>>()?.map(broadcast_transaction_response::Status::Confirmed)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(FmdParametersRequest {
+                Ok(BroadcastTransactionResponse {
+                    status: status__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for FmdParametersResponse {
+impl serde::Serialize for broadcast_transaction_response::BroadcastSuccess {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2220,29 +2273,29 @@ impl serde::Serialize for FmdParametersResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.parameters.is_some() {
+        if self.id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersResponse", len)?;
-        if let Some(v) = self.parameters.as_ref() {
-            struct_ser.serialize_field("parameters", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
+impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::BroadcastSuccess {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "parameters",
+            "id",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Parameters,
+            Id,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2265,7 +2318,7 @@ impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "parameters" => Ok(GeneratedField::Parameters),
+                            "id" => Ok(GeneratedField::Id),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2275,61 +2328,79 @@ impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = FmdParametersResponse;
+            type Value = broadcast_transaction_response::BroadcastSuccess;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.FMDParametersResponse")
+                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<broadcast_transaction_response::BroadcastSuccess, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut parameters__ = None;
+                let mut id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Parameters => {
-                            if parameters__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("parameters"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            parameters__ = map_.next_value()?;
+                            id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(FmdParametersResponse {
-                    parameters: parameters__,
+                Ok(broadcast_transaction_response::BroadcastSuccess {
+                    id: id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.BroadcastSuccess", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GasPricesRequest {
+impl serde::Serialize for broadcast_transaction_response::Confirmed {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesRequest", len)?;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.detection_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if self.detection_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("detectionHeight", ToString::to_string(&self.detection_height).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GasPricesRequest {
+impl<'de> serde::Deserialize<'de> for broadcast_transaction_response::Confirmed {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "id",
+            "detection_height",
+            "detectionHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Id,
+            DetectionHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2351,7 +2422,11 @@ impl<'de> serde::Deserialize<'de> for GasPricesRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "detectionHeight" | "detection_height" => Ok(GeneratedField::DetectionHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2359,27 +2434,49 @@ impl<'de> serde::Deserialize<'de> for GasPricesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GasPricesRequest;
+            type Value = broadcast_transaction_response::Confirmed;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.GasPricesRequest")
+                formatter.write_str("struct penumbra.view.v1.BroadcastTransactionResponse.Confirmed")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<broadcast_transaction_response::Confirmed, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut id__ = None;
+                let mut detection_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::DetectionHeight => {
+                            if detection_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionHeight"));
+                            }
+                            detection_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(GasPricesRequest {
+                Ok(broadcast_transaction_response::Confirmed {
+                    id: id__,
+                    detection_height: detection_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.GasPricesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.BroadcastTransactionResponse.Confirmed", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GasPricesResponse {
+impl serde::Serialize for DisclosedNoteByCommitmentRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2387,30 +2484,39 @@ impl serde::Serialize for GasPricesResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.gas_prices.is_some() {
+        if self.note_commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesResponse", len)?;
-        if let Some(v) = self.gas_prices.as_ref() {
-            struct_ser.serialize_field("gasPrices", v)?;
+        if self.payload_key.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DisclosedNoteByCommitmentRequest", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if let Some(v) = self.payload_key.as_ref() {
+            struct_ser.serialize_field("payloadKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GasPricesResponse {
+impl<'de> serde::Deserialize<'de> for DisclosedNoteByCommitmentRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "gas_prices",
-            "gasPrices",
+            "note_commitment",
+            "noteCommitment",
+            "payload_key",
+            "payloadKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            GasPrices,
+            NoteCommitment,
+            PayloadKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2433,7 +2539,8 @@ impl<'de> serde::Deserialize<'de> for GasPricesResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "payloadKey" | "payload_key" => Ok(GeneratedField::PayloadKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2443,39 +2550,47 @@ impl<'de> serde::Deserialize<'de> for GasPricesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GasPricesResponse;
+            type Value = DisclosedNoteByCommitmentRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.GasPricesResponse")
+                formatter.write_str("struct penumbra.view.v1.DisclosedNoteByCommitmentRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DisclosedNoteByCommitmentRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut gas_prices__ = None;
+                let mut note_commitment__ = None;
+                let mut payload_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::GasPrices => {
-                            if gas_prices__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
                             }
-                            gas_prices__ = map_.next_value()?;
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::PayloadKey => {
+                            if payload_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("payloadKey"));
+                            }
+                            payload_key__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GasPricesResponse {
-                    gas_prices: gas_prices__,
+                Ok(DisclosedNoteByCommitmentRequest {
+                    note_commitment: note_commitment__,
+                    payload_key: payload_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.GasPricesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DisclosedNoteByCommitmentRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressRequest {
+impl serde::Serialize for DisclosedNoteByCommitmentResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2483,29 +2598,48 @@ impl serde::Serialize for IndexByAddressRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.address.is_some() {
+        if self.note.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressRequest", len)?;
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        if self.height_created != 0 {
+            len += 1;
+        }
+        if self.transaction_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.DisclosedNoteByCommitmentResponse", len)?;
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if self.height_created != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
+        }
+        if let Some(v) = self.transaction_id.as_ref() {
+            struct_ser.serialize_field("transactionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
+impl<'de> serde::Deserialize<'de> for DisclosedNoteByCommitmentResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "address",
+            "note",
+            "height_created",
+            "heightCreated",
+            "transaction_id",
+            "transactionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Address,
+            Note,
+            HeightCreated,
+            TransactionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2528,7 +2662,9 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "address" => Ok(GeneratedField::Address),
+                            "note" => Ok(GeneratedField::Note),
+                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
+                            "transactionId" | "transaction_id" => Ok(GeneratedField::TransactionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2538,39 +2674,57 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressRequest;
+            type Value = DisclosedNoteByCommitmentResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.IndexByAddressRequest")
+                formatter.write_str("struct penumbra.view.v1.DisclosedNoteByCommitmentResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DisclosedNoteByCommitmentResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut address__ = None;
+                let mut note__ = None;
+                let mut height_created__ = None;
+                let mut transaction_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
                             }
-                            address__ = map_.next_value()?;
+                            note__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightCreated => {
+                            if height_created__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                            }
+                            height_created__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TransactionId => {
+                            if transaction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactionId"));
+                            }
+                            transaction_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(IndexByAddressRequest {
-                    address: address__,
+                Ok(DisclosedNoteByCommitmentResponse {
+                    note: note__,
+                    height_created: height_created__.unwrap_or_default(),
+                    transaction_id: transaction_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.DisclosedNoteByCommitmentResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for IndexByAddressResponse {
+impl serde::Serialize for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2581,14 +2735,14 @@ impl serde::Serialize for IndexByAddressResponse {
         if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressRequest", len)?;
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -2634,13 +2788,13 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = IndexByAddressResponse;
+            type Value = EphemeralAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.IndexByAddressResponse")
+                formatter.write_str("struct penumbra.view.v1.EphemeralAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -2658,15 +2812,15 @@ impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
                         }
                     }
                 }
-                Ok(IndexByAddressResponse {
+                Ok(EphemeralAddressRequest {
                     address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentRequest {
+impl serde::Serialize for EphemeralAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2674,39 +2828,29 @@ impl serde::Serialize for NoteByCommitmentRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_commitment.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
+        if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentRequest", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressResponse", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "await_detection",
-            "awaitDetection",
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            AwaitDetection,
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2729,8 +2873,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "address" => Ok(GeneratedField::Address),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2740,47 +2883,39 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentRequest;
+            type Value = EphemeralAddressResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1.EphemeralAddressResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut await_detection__ = None;
+                let mut address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NoteByCommitmentRequest {
-                    note_commitment: note_commitment__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(EphemeralAddressResponse {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NoteByCommitmentResponse {
+impl serde::Serialize for EphemeralAddressesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2788,30 +2923,30 @@ impl serde::Serialize for NoteByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.spendable_note.is_some() {
+        if self.account_filter.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentResponse", len)?;
-        if let Some(v) = self.spendable_note.as_ref() {
-            struct_ser.serialize_field("spendableNote", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressesRequest", len)?;
+        if let Some(v) = self.account_filter.as_ref() {
+            struct_ser.serialize_field("accountFilter", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spendable_note",
-            "spendableNote",
+            "account_filter",
+            "accountFilter",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SpendableNote,
+            AccountFilter,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2834,7 +2969,7 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
+                            "accountFilter" | "account_filter" => Ok(GeneratedField::AccountFilter),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2844,39 +2979,39 @@ impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NoteByCommitmentResponse;
+            type Value = EphemeralAddressesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1.EphemeralAddressesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spendable_note__ = None;
+                let mut account_filter__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SpendableNote => {
-                            if spendable_note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spendableNote"));
+                        GeneratedField::AccountFilter => {
+                            if account_filter__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("accountFilter"));
                             }
-                            spendable_note__ = map_.next_value()?;
+                            account_filter__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NoteByCommitmentResponse {
-                    spendable_note: spendable_note__,
+                Ok(EphemeralAddressesRequest {
+                    account_filter: account_filter__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingRequest {
+impl serde::Serialize for EphemeralAddressesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2884,40 +3019,56 @@ impl serde::Serialize for NotesForVotingRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.votable_at_height != 0 {
+        if self.address.is_some() {
             len += 1;
         }
         if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingRequest", len)?;
-        if self.votable_at_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
+        if self.height_generated != 0 {
+            len += 1;
+        }
+        if !self.transactions.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.EphemeralAddressesResponse", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
         }
         if let Some(v) = self.address_index.as_ref() {
             struct_ser.serialize_field("addressIndex", v)?;
         }
+        if self.height_generated != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightGenerated", ToString::to_string(&self.height_generated).as_str())?;
+        }
+        if !self.transactions.is_empty() {
+            struct_ser.serialize_field("transactions", &self.transactions)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
+impl<'de> serde::Deserialize<'de> for EphemeralAddressesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "votable_at_height",
-            "votableAtHeight",
+            "address",
             "address_index",
             "addressIndex",
+            "height_generated",
+            "heightGenerated",
+            "transactions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            VotableAtHeight,
+            Address,
             AddressIndex,
+            HeightGenerated,
+            Transactions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2940,8 +3091,10 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
+                            "address" => Ok(GeneratedField::Address),
                             "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "heightGenerated" | "height_generated" => Ok(GeneratedField::HeightGenerated),
+                            "transactions" => Ok(GeneratedField::Transactions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2951,27 +3104,27 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingRequest;
+            type Value = EphemeralAddressesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesForVotingRequest")
+                formatter.write_str("struct penumbra.view.v1.EphemeralAddressesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EphemeralAddressesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut votable_at_height__ = None;
+                let mut address__ = None;
                 let mut address_index__ = None;
+                let mut height_generated__ = None;
+                let mut transactions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::VotableAtHeight => {
-                            if votable_at_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
                             }
-                            votable_at_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            address__ = map_.next_value()?;
                         }
                         GeneratedField::AddressIndex => {
                             if address_index__.is_some() {
@@ -2979,61 +3132,59 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
                             }
                             address_index__ = map_.next_value()?;
                         }
+                        GeneratedField::HeightGenerated => {
+                            if height_generated__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightGenerated"));
+                            }
+                            height_generated__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Transactions => {
+                            if transactions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transactions"));
+                            }
+                            transactions__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesForVotingRequest {
-                    votable_at_height: votable_at_height__.unwrap_or_default(),
+                Ok(EphemeralAddressesResponse {
+                    address: address__,
                     address_index: address_index__,
+                    height_generated: height_generated__.unwrap_or_default(),
+                    transactions: transactions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.EphemeralAddressesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesForVotingResponse {
+impl serde::Serialize for ExportFrontierRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.note_record.is_some() {
-            len += 1;
-        }
-        if self.identity_key.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
-        }
-        if let Some(v) = self.identity_key.as_ref() {
-            struct_ser.serialize_field("identityKey", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.ExportFrontierRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
+impl<'de> serde::Deserialize<'de> for ExportFrontierRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
-            "identity_key",
-            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
-            IdentityKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3055,11 +3206,7 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
-                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3067,47 +3214,27 @@ impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesForVotingResponse;
+            type Value = ExportFrontierRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesForVotingResponse")
+                formatter.write_str("struct penumbra.view.v1.ExportFrontierRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportFrontierRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
-                let mut identity_key__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
-                            }
-                            note_record__ = map_.next_value()?;
-                        }
-                        GeneratedField::IdentityKey => {
-                            if identity_key__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("identityKey"));
-                            }
-                            identity_key__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(NotesForVotingResponse {
-                    note_record: note_record__,
-                    identity_key: identity_key__,
+                Ok(ExportFrontierRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ExportFrontierRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesRequest {
+impl serde::Serialize for ExportFrontierResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3115,57 +3242,29 @@ impl serde::Serialize for NotesRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.include_spent {
-            len += 1;
-        }
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        if self.amount_to_spend.is_some() {
+        if self.frontier.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesRequest", len)?;
-        if self.include_spent {
-            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
-        }
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.amount_to_spend.as_ref() {
-            struct_ser.serialize_field("amountToSpend", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ExportFrontierResponse", len)?;
+        if let Some(v) = self.frontier.as_ref() {
+            struct_ser.serialize_field("frontier", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesRequest {
+impl<'de> serde::Deserialize<'de> for ExportFrontierResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "include_spent",
-            "includeSpent",
-            "asset_id",
-            "assetId",
-            "address_index",
-            "addressIndex",
-            "amount_to_spend",
-            "amountToSpend",
+            "frontier",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            IncludeSpent,
-            AssetId,
-            AddressIndex,
-            AmountToSpend,
+            Frontier,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3188,10 +3287,7 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            "frontier" => Ok(GeneratedField::Frontier),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3201,94 +3297,61 @@ impl<'de> serde::Deserialize<'de> for NotesRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesRequest;
+            type Value = ExportFrontierResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesRequest")
+                formatter.write_str("struct penumbra.view.v1.ExportFrontierResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ExportFrontierResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut include_spent__ = None;
-                let mut asset_id__ = None;
-                let mut address_index__ = None;
-                let mut amount_to_spend__ = None;
+                let mut frontier__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::IncludeSpent => {
-                            if include_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("includeSpent"));
-                            }
-                            include_spent__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
-                            }
-                            asset_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::AmountToSpend => {
-                            if amount_to_spend__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                        GeneratedField::Frontier => {
+                            if frontier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frontier"));
                             }
-                            amount_to_spend__ = map_.next_value()?;
+                            frontier__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NotesRequest {
-                    include_spent: include_spent__.unwrap_or_default(),
-                    asset_id: asset_id__,
-                    address_index: address_index__,
-                    amount_to_spend: amount_to_spend__,
+                Ok(ExportFrontierResponse {
+                    frontier: frontier__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ExportFrontierResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NotesResponse {
+impl serde::Serialize for FmdParametersRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.note_record.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesResponse", len)?;
-        if let Some(v) = self.note_record.as_ref() {
-            struct_ser.serialize_field("noteRecord", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NotesResponse {
+impl<'de> serde::Deserialize<'de> for FmdParametersRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_record",
-            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3310,10 +3373,7 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3321,39 +3381,27 @@ impl<'de> serde::Deserialize<'de> for NotesResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NotesResponse;
+            type Value = FmdParametersRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NotesResponse")
+                formatter.write_str("struct penumbra.view.v1.FMDParametersRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_record__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::NoteRecord => {
-                            if note_record__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteRecord"));
-                            }
-                            note_record__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(NotesResponse {
-                    note_record: note_record__,
+                Ok(FmdParametersRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NotesResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusRequest {
+impl serde::Serialize for FmdParametersResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3361,38 +3409,29 @@ impl serde::Serialize for NullifierStatusRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.await_detection {
+        if self.parameters.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusRequest", len)?;
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if self.await_detection {
-            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.FMDParametersResponse", len)?;
+        if let Some(v) = self.parameters.as_ref() {
+            struct_ser.serialize_field("parameters", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
+impl<'de> serde::Deserialize<'de> for FmdParametersResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "nullifier",
-            "await_detection",
-            "awaitDetection",
+            "parameters",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Nullifier,
-            AwaitDetection,
+            Parameters,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3415,8 +3454,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            "parameters" => Ok(GeneratedField::Parameters),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3426,77 +3464,61 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusRequest;
+            type Value = FmdParametersResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NullifierStatusRequest")
+                formatter.write_str("struct penumbra.view.v1.FMDParametersResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FmdParametersResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut nullifier__ = None;
-                let mut await_detection__ = None;
+                let mut parameters__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::AwaitDetection => {
-                            if await_detection__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                        GeneratedField::Parameters => {
+                            if parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("parameters"));
                             }
-                            await_detection__ = Some(map_.next_value()?);
+                            parameters__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(NullifierStatusRequest {
-                    nullifier: nullifier__,
-                    await_detection: await_detection__.unwrap_or_default(),
+                Ok(FmdParametersResponse {
+                    parameters: parameters__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.FMDParametersResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for NullifierStatusResponse {
+impl serde::Serialize for GasPricesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.spent {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusResponse", len)?;
-        if self.spent {
-            struct_ser.serialize_field("spent", &self.spent)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
+impl<'de> serde::Deserialize<'de> for GasPricesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "spent",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Spent,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3518,10 +3540,7 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "spent" => Ok(GeneratedField::Spent),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3529,39 +3548,27 @@ impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = NullifierStatusResponse;
+            type Value = GasPricesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.NullifierStatusResponse")
+                formatter.write_str("struct penumbra.view.v1.GasPricesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut spent__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Spent => {
-                            if spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("spent"));
-                            }
-                            spent__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(NullifierStatusResponse {
-                    spent: spent__.unwrap_or_default(),
+                Ok(GasPricesRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GasPricesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsRequest {
+impl serde::Serialize for GasPricesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3569,39 +3576,30 @@ impl serde::Serialize for OwnedPositionIdsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_state.is_some() {
-            len += 1;
-        }
-        if self.trading_pair.is_some() {
+        if self.gas_prices.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", len)?;
-        if let Some(v) = self.position_state.as_ref() {
-            struct_ser.serialize_field("positionState", v)?;
-        }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.GasPricesResponse", len)?;
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+impl<'de> serde::Deserialize<'de> for GasPricesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_state",
-            "positionState",
-            "trading_pair",
-            "tradingPair",
+            "gas_prices",
+            "gasPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionState,
-            TradingPair,
+            GasPrices,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3624,8 +3622,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3635,78 +3632,61 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsRequest;
+            type Value = GasPricesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsRequest")
+                formatter.write_str("struct penumbra.view.v1.GasPricesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GasPricesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_state__ = None;
-                let mut trading_pair__ = None;
+                let mut gas_prices__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionState => {
-                            if position_state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionState"));
-                            }
-                            position_state__ = map_.next_value()?;
-                        }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            gas_prices__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(OwnedPositionIdsRequest {
-                    position_state: position_state__,
-                    trading_pair: trading_pair__,
+                Ok(GasPricesResponse {
+                    gas_prices: gas_prices__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.GasPricesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for OwnedPositionIdsResponse {
+impl serde::Serialize for HandshakeRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.HandshakeRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
+impl<'de> serde::Deserialize<'de> for HandshakeRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3728,10 +3708,7 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -3739,39 +3716,27 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = OwnedPositionIdsResponse;
+            type Value = HandshakeRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsResponse")
+                formatter.write_str("struct penumbra.view.v1.HandshakeRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HandshakeRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(OwnedPositionIdsResponse {
-                    position_id: position_id__,
+                Ok(HandshakeRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.HandshakeRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SpendableNoteRecord {
+impl serde::Serialize for HandshakeResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -3779,101 +3744,49 @@ impl serde::Serialize for SpendableNoteRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.note_commitment.is_some() {
-            len += 1;
-        }
-        if self.note.is_some() {
-            len += 1;
-        }
-        if self.address_index.is_some() {
-            len += 1;
-        }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.height_created != 0 {
-            len += 1;
-        }
-        if self.height_spent != 0 {
+        if !self.chain_id.is_empty() {
             len += 1;
         }
-        if self.position != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
+        if self.app_version != 0 {
             len += 1;
         }
-        if self.return_address.is_some() {
+        if !self.storage_schema_hash.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
-        if let Some(v) = self.note_commitment.as_ref() {
-            struct_ser.serialize_field("noteCommitment", v)?;
-        }
-        if let Some(v) = self.note.as_ref() {
-            struct_ser.serialize_field("note", v)?;
-        }
-        if let Some(v) = self.address_index.as_ref() {
-            struct_ser.serialize_field("addressIndex", v)?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if self.height_created != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
-        }
-        if self.height_spent != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.HandshakeResponse", len)?;
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chainId", &self.chain_id)?;
         }
-        if self.position != 0 {
+        if self.app_version != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+            struct_ser.serialize_field("appVersion", ToString::to_string(&self.app_version).as_str())?;
         }
-        if let Some(v) = self.return_address.as_ref() {
-            struct_ser.serialize_field("returnAddress", v)?;
+        if !self.storage_schema_hash.is_empty() {
+            struct_ser.serialize_field("storageSchemaHash", &self.storage_schema_hash)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+impl<'de> serde::Deserialize<'de> for HandshakeResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "note_commitment",
-            "noteCommitment",
-            "note",
-            "address_index",
-            "addressIndex",
-            "nullifier",
-            "height_created",
-            "heightCreated",
-            "height_spent",
-            "heightSpent",
-            "position",
-            "source",
-            "return_address",
-            "returnAddress",
+            "chain_id",
+            "chainId",
+            "app_version",
+            "appVersion",
+            "storage_schema_hash",
+            "storageSchemaHash",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            NoteCommitment,
-            Note,
-            AddressIndex,
-            Nullifier,
-            HeightCreated,
-            HeightSpent,
-            Position,
-            Source,
-            ReturnAddress,
+            ChainId,
+            AppVersion,
+            StorageSchemaHash,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3896,15 +3809,9 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
-                            "note" => Ok(GeneratedField::Note),
-                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
-                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
-                            "position" => Ok(GeneratedField::Position),
-                            "source" => Ok(GeneratedField::Source),
-                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            "appVersion" | "app_version" => Ok(GeneratedField::AppVersion),
+                            "storageSchemaHash" | "storage_schema_hash" => Ok(GeneratedField::StorageSchemaHash),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3914,131 +3821,87 @@ impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SpendableNoteRecord;
+            type Value = HandshakeResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SpendableNoteRecord")
+                formatter.write_str("struct penumbra.view.v1.HandshakeResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<HandshakeResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut note_commitment__ = None;
-                let mut note__ = None;
-                let mut address_index__ = None;
-                let mut nullifier__ = None;
-                let mut height_created__ = None;
-                let mut height_spent__ = None;
-                let mut position__ = None;
-                let mut source__ = None;
-                let mut return_address__ = None;
+                let mut chain_id__ = None;
+                let mut app_version__ = None;
+                let mut storage_schema_hash__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::NoteCommitment => {
-                            if note_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
-                            }
-                            note_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Note => {
-                            if note__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("note"));
-                            }
-                            note__ = map_.next_value()?;
-                        }
-                        GeneratedField::AddressIndex => {
-                            if address_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("addressIndex"));
-                            }
-                            address_index__ = map_.next_value()?;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightCreated => {
-                            if height_created__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
                             }
-                            height_created__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::HeightSpent => {
-                            if height_spent__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightSpent"));
-                            }
-                            height_spent__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            chain_id__ = Some(map_.next_value()?);
                         }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::AppVersion => {
+                            if app_version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("appVersion"));
                             }
-                            position__ = 
+                            app_version__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
-                            }
-                            source__ = map_.next_value()?;
-                        }
-                        GeneratedField::ReturnAddress => {
-                            if return_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                        GeneratedField::StorageSchemaHash => {
+                            if storage_schema_hash__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("storageSchemaHash"));
                             }
-                            return_address__ = map_.next_value()?;
+                            storage_schema_hash__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SpendableNoteRecord {
-                    note_commitment: note_commitment__,
-                    note: note__,
-                    address_index: address_index__,
-                    nullifier: nullifier__,
-                    height_created: height_created__.unwrap_or_default(),
-                    height_spent: height_spent__.unwrap_or_default(),
-                    position: position__.unwrap_or_default(),
-                    source: source__,
-                    return_address: return_address__,
+                Ok(HandshakeResponse {
+                    chain_id: chain_id__.unwrap_or_default(),
+                    app_version: app_version__.unwrap_or_default(),
+                    storage_schema_hash: storage_schema_hash__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.HandshakeResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusRequest {
+impl serde::Serialize for ImportFrontierRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusRequest", len)?;
+        let mut len = 0;
+        if self.frontier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ImportFrontierRequest", len)?;
+        if let Some(v) = self.frontier.as_ref() {
+            struct_ser.serialize_field("frontier", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusRequest {
+impl<'de> serde::Deserialize<'de> for ImportFrontierRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "frontier",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Frontier,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4060,7 +3923,10 @@ impl<'de> serde::Deserialize<'de> for StatusRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "frontier" => Ok(GeneratedField::Frontier),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4068,27 +3934,39 @@ impl<'de> serde::Deserialize<'de> for StatusRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusRequest;
+            type Value = ImportFrontierRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusRequest")
+                formatter.write_str("struct penumbra.view.v1.ImportFrontierRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ImportFrontierRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut frontier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Frontier => {
+                            if frontier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("frontier"));
+                            }
+                            frontier__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(StatusRequest {
+                Ok(ImportFrontierRequest {
+                    frontier: frontier__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ImportFrontierRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusResponse {
+impl serde::Serialize for ImportFrontierResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4096,50 +3974,30 @@ impl serde::Serialize for StatusResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.full_sync_height != 0 {
-            len += 1;
-        }
-        if self.partial_sync_height != 0 {
-            len += 1;
-        }
-        if self.catching_up {
+        if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusResponse", len)?;
-        if self.full_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
-        }
-        if self.partial_sync_height != 0 {
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.ImportFrontierResponse", len)?;
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
-        }
-        if self.catching_up {
-            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusResponse {
+impl<'de> serde::Deserialize<'de> for ImportFrontierResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "full_sync_height",
-            "fullSyncHeight",
-            "partial_sync_height",
-            "partialSyncHeight",
-            "catching_up",
-            "catchingUp",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            FullSyncHeight,
-            PartialSyncHeight,
-            CatchingUp,
+            Height,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4162,9 +4020,7 @@ impl<'de> serde::Deserialize<'de> for StatusResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
-                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
-                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4174,81 +4030,71 @@ impl<'de> serde::Deserialize<'de> for StatusResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusResponse;
+            type Value = ImportFrontierResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusResponse")
+                formatter.write_str("struct penumbra.view.v1.ImportFrontierResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ImportFrontierResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut full_sync_height__ = None;
-                let mut partial_sync_height__ = None;
-                let mut catching_up__ = None;
+                let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::FullSyncHeight => {
-                            if full_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
-                            }
-                            full_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::PartialSyncHeight => {
-                            if partial_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            partial_sync_height__ = 
+                            height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::CatchingUp => {
-                            if catching_up__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("catchingUp"));
-                            }
-                            catching_up__ = Some(map_.next_value()?);
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(StatusResponse {
-                    full_sync_height: full_sync_height__.unwrap_or_default(),
-                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
-                    catching_up: catching_up__.unwrap_or_default(),
+                Ok(ImportFrontierResponse {
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.ImportFrontierResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusStreamRequest {
+impl serde::Serialize for IndexByAddressRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamRequest", len)?;
+        let mut len = 0;
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressRequest", len)?;
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
+impl<'de> serde::Deserialize<'de> for IndexByAddressRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "address",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Address,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4270,7 +4116,10 @@ impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -4278,27 +4127,39 @@ impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusStreamRequest;
+            type Value = IndexByAddressRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusStreamRequest")
+                formatter.write_str("struct penumbra.view.v1.IndexByAddressRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(StatusStreamRequest {
+                Ok(IndexByAddressRequest {
+                    address: address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for StatusStreamResponse {
+impl serde::Serialize for IndexByAddressResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4306,51 +4167,30 @@ impl serde::Serialize for StatusStreamResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.latest_known_block_height != 0 {
-            len += 1;
-        }
-        if self.full_sync_height != 0 {
-            len += 1;
-        }
-        if self.partial_sync_height != 0 {
+        if self.address_index.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamResponse", len)?;
-        if self.latest_known_block_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("latestKnownBlockHeight", ToString::to_string(&self.latest_known_block_height).as_str())?;
-        }
-        if self.full_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
-        }
-        if self.partial_sync_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.IndexByAddressResponse", len)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
+impl<'de> serde::Deserialize<'de> for IndexByAddressResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "latest_known_block_height",
-            "latestKnownBlockHeight",
-            "full_sync_height",
-            "fullSyncHeight",
-            "partial_sync_height",
-            "partialSyncHeight",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            LatestKnownBlockHeight,
-            FullSyncHeight,
-            PartialSyncHeight,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4373,9 +4213,7 @@ impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "latestKnownBlockHeight" | "latest_known_block_height" => Ok(GeneratedField::LatestKnownBlockHeight),
-                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
-                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4385,61 +4223,39 @@ impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = StatusStreamResponse;
+            type Value = IndexByAddressResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.StatusStreamResponse")
+                formatter.write_str("struct penumbra.view.v1.IndexByAddressResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IndexByAddressResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut latest_known_block_height__ = None;
-                let mut full_sync_height__ = None;
-                let mut partial_sync_height__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::LatestKnownBlockHeight => {
-                            if latest_known_block_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("latestKnownBlockHeight"));
-                            }
-                            latest_known_block_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::FullSyncHeight => {
-                            if full_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
-                            }
-                            full_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::PartialSyncHeight => {
-                            if partial_sync_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            partial_sync_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            address_index__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(StatusStreamResponse {
-                    latest_known_block_height: latest_known_block_height__.unwrap_or_default(),
-                    full_sync_height: full_sync_height__.unwrap_or_default(),
-                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                Ok(IndexByAddressResponse {
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.IndexByAddressResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapByCommitmentRequest {
+impl serde::Serialize for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4447,15 +4263,15 @@ impl serde::Serialize for SwapByCommitmentRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
+        if self.note_commitment.is_some() {
             len += 1;
         }
         if self.await_detection {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentRequest", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentRequest", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
         }
         if self.await_detection {
             struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
@@ -4463,22 +4279,22 @@ impl serde::Serialize for SwapByCommitmentRequest {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
+            "note_commitment",
+            "noteCommitment",
             "await_detection",
             "awaitDetection",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
+            NoteCommitment,
             AwaitDetection,
             __SkipField__,
         }
@@ -4502,7 +4318,7 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
                             "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
@@ -4513,25 +4329,25 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapByCommitmentRequest;
+            type Value = NoteByCommitmentRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentRequest")
+                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
+                let mut note_commitment__ = None;
                 let mut await_detection__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
                             }
-                            swap_commitment__ = map_.next_value()?;
+                            note_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::AwaitDetection => {
                             if await_detection__.is_some() {
@@ -4544,16 +4360,16 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
                         }
                     }
                 }
-                Ok(SwapByCommitmentRequest {
-                    swap_commitment: swap_commitment__,
+                Ok(NoteByCommitmentRequest {
+                    note_commitment: note_commitment__,
                     await_detection: await_detection__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapByCommitmentResponse {
+impl serde::Serialize for NoteByCommitmentResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4561,29 +4377,30 @@ impl serde::Serialize for SwapByCommitmentResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.spendable_note.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentResponse", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NoteByCommitmentResponse", len)?;
+        if let Some(v) = self.spendable_note.as_ref() {
+            struct_ser.serialize_field("spendableNote", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
+impl<'de> serde::Deserialize<'de> for NoteByCommitmentResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
+            "spendable_note",
+            "spendableNote",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
+            SpendableNote,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4606,7 +4423,7 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
+                            "spendableNote" | "spendable_note" => Ok(GeneratedField::SpendableNote),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4616,39 +4433,39 @@ impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapByCommitmentResponse;
+            type Value = NoteByCommitmentResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentResponse")
+                formatter.write_str("struct penumbra.view.v1.NoteByCommitmentResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NoteByCommitmentResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
+                let mut spendable_note__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::SpendableNote => {
+                            if spendable_note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spendableNote"));
                             }
-                            swap__ = map_.next_value()?;
+                            spendable_note__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapByCommitmentResponse {
-                    swap: swap__,
+                Ok(NoteByCommitmentResponse {
+                    spendable_note: spendable_note__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NoteByCommitmentResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for SwapRecord {
+impl serde::Serialize for NotesForVotingRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4656,82 +4473,40 @@ impl serde::Serialize for SwapRecord {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
+        if self.votable_at_height != 0 {
             len += 1;
         }
-        if self.swap.is_some() {
+        if self.address_index.is_some() {
             len += 1;
         }
-        if self.position != 0 {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingRequest", len)?;
+        if self.votable_at_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("votableAtHeight", ToString::to_string(&self.votable_at_height).as_str())?;
         }
-        if self.nullifier.is_some() {
-            len += 1;
-        }
-        if self.output_data.is_some() {
-            len += 1;
-        }
-        if self.height_claimed != 0 {
-            len += 1;
-        }
-        if self.source.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapRecord", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
-        }
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
-        }
-        if self.position != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
-        }
-        if let Some(v) = self.nullifier.as_ref() {
-            struct_ser.serialize_field("nullifier", v)?;
-        }
-        if let Some(v) = self.output_data.as_ref() {
-            struct_ser.serialize_field("outputData", v)?;
-        }
-        if self.height_claimed != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("heightClaimed", ToString::to_string(&self.height_claimed).as_str())?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for SwapRecord {
+impl<'de> serde::Deserialize<'de> for NotesForVotingRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
-            "swap",
-            "position",
-            "nullifier",
-            "output_data",
-            "outputData",
-            "height_claimed",
-            "heightClaimed",
-            "source",
+            "votable_at_height",
+            "votableAtHeight",
+            "address_index",
+            "addressIndex",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
-            Swap,
-            Position,
-            Nullifier,
-            OutputData,
-            HeightClaimed,
-            Source,
+            VotableAtHeight,
+            AddressIndex,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4754,13 +4529,8 @@ impl<'de> serde::Deserialize<'de> for SwapRecord {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
-                            "swap" => Ok(GeneratedField::Swap),
-                            "position" => Ok(GeneratedField::Position),
-                            "nullifier" => Ok(GeneratedField::Nullifier),
-                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
-                            "heightClaimed" | "height_claimed" => Ok(GeneratedField::HeightClaimed),
-                            "source" => Ok(GeneratedField::Source),
+                            "votableAtHeight" | "votable_at_height" => Ok(GeneratedField::VotableAtHeight),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4770,91 +4540,49 @@ impl<'de> serde::Deserialize<'de> for SwapRecord {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = SwapRecord;
+            type Value = NotesForVotingRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.SwapRecord")
+                formatter.write_str("struct penumbra.view.v1.NotesForVotingRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapRecord, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
-                let mut swap__ = None;
-                let mut position__ = None;
-                let mut nullifier__ = None;
-                let mut output_data__ = None;
-                let mut height_claimed__ = None;
-                let mut source__ = None;
+                let mut votable_at_height__ = None;
+                let mut address_index__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
-                            }
-                            swap_commitment__ = map_.next_value()?;
-                        }
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
-                            }
-                            swap__ = map_.next_value()?;
-                        }
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
-                            }
-                            position__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Nullifier => {
-                            if nullifier__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nullifier"));
-                            }
-                            nullifier__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputData => {
-                            if output_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputData"));
-                            }
-                            output_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::HeightClaimed => {
-                            if height_claimed__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("heightClaimed"));
+                        GeneratedField::VotableAtHeight => {
+                            if votable_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votableAtHeight"));
                             }
-                            height_claimed__ = 
+                            votable_at_height__ = 
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
                             }
-                            source__ = map_.next_value()?;
+                            address_index__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SwapRecord {
-                    swap_commitment: swap_commitment__,
-                    swap: swap__,
-                    position: position__.unwrap_or_default(),
-                    nullifier: nullifier__,
-                    output_data: output_data__,
-                    height_claimed: height_claimed__.unwrap_or_default(),
-                    source: source__,
+                Ok(NotesForVotingRequest {
+                    votable_at_height: votable_at_height__.unwrap_or_default(),
+                    address_index: address_index__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.SwapRecord", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfo {
+impl serde::Serialize for NotesForVotingResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4862,62 +4590,39 @@ impl serde::Serialize for TransactionInfo {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.height != 0 {
-            len += 1;
-        }
-        if self.id.is_some() {
-            len += 1;
-        }
-        if self.transaction.is_some() {
-            len += 1;
-        }
-        if self.perspective.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        if self.view.is_some() {
+        if self.identity_key.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfo", len)?;
-        if self.height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
-        }
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
-        }
-        if let Some(v) = self.transaction.as_ref() {
-            struct_ser.serialize_field("transaction", v)?;
-        }
-        if let Some(v) = self.perspective.as_ref() {
-            struct_ser.serialize_field("perspective", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesForVotingResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
-        if let Some(v) = self.view.as_ref() {
-            struct_ser.serialize_field("view", v)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfo {
+impl<'de> serde::Deserialize<'de> for NotesForVotingResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "height",
-            "id",
-            "transaction",
-            "perspective",
-            "view",
+            "note_record",
+            "noteRecord",
+            "identity_key",
+            "identityKey",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Height,
-            Id,
-            Transaction,
-            Perspective,
-            View,
+            NoteRecord,
+            IdentityKey,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4940,11 +4645,8 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
                         E: serde::de::Error,
                     {
                         match value {
-                            "height" => Ok(GeneratedField::Height),
-                            "id" => Ok(GeneratedField::Id),
-                            "transaction" => Ok(GeneratedField::Transaction),
-                            "perspective" => Ok(GeneratedField::Perspective),
-                            "view" => Ok(GeneratedField::View),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4954,73 +4656,47 @@ impl<'de> serde::Deserialize<'de> for TransactionInfo {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfo;
+            type Value = NotesForVotingResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfo")
+                formatter.write_str("struct penumbra.view.v1.NotesForVotingResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfo, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesForVotingResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut height__ = None;
-                let mut id__ = None;
-                let mut transaction__ = None;
-                let mut perspective__ = None;
-                let mut view__ = None;
+                let mut note_record__ = None;
+                let mut identity_key__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Height => {
-                            if height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("height"));
-                            }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
-                            }
-                            id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Transaction => {
-                            if transaction__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("transaction"));
-                            }
-                            transaction__ = map_.next_value()?;
-                        }
-                        GeneratedField::Perspective => {
-                            if perspective__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("perspective"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            perspective__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
                         }
-                        GeneratedField::View => {
-                            if view__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("view"));
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
                             }
-                            view__ = map_.next_value()?;
+                            identity_key__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfo {
-                    height: height__.unwrap_or_default(),
-                    id: id__,
-                    transaction: transaction__,
-                    perspective: perspective__,
-                    view: view__,
+                Ok(NotesForVotingResponse {
+                    note_record: note_record__,
+                    identity_key: identity_key__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfo", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesForVotingResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoByHashRequest {
+impl serde::Serialize for NotesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5028,29 +4704,66 @@ impl serde::Serialize for TransactionInfoByHashRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id.is_some() {
+        if self.include_spent {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", len)?;
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.amount_to_spend.is_some() {
+            len += 1;
+        }
+        if self.allow_other_accounts {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesRequest", len)?;
+        if self.include_spent {
+            struct_ser.serialize_field("includeSpent", &self.include_spent)?;
+        }
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.amount_to_spend.as_ref() {
+            struct_ser.serialize_field("amountToSpend", v)?;
+        }
+        if self.allow_other_accounts {
+            struct_ser.serialize_field("allowOtherAccounts", &self.allow_other_accounts)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
+impl<'de> serde::Deserialize<'de> for NotesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
+            "include_spent",
+            "includeSpent",
+            "asset_id",
+            "assetId",
+            "address_index",
+            "addressIndex",
+            "amount_to_spend",
+            "amountToSpend",
+            "allow_other_accounts",
+            "allowOtherAccounts",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
+            IncludeSpent,
+            AssetId,
+            AddressIndex,
+            AmountToSpend,
+            AllowOtherAccounts,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5073,7 +4786,11 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
+                            "includeSpent" | "include_spent" => Ok(GeneratedField::IncludeSpent),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "amountToSpend" | "amount_to_spend" => Ok(GeneratedField::AmountToSpend),
+                            "allowOtherAccounts" | "allow_other_accounts" => Ok(GeneratedField::AllowOtherAccounts),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5083,39 +4800,71 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoByHashRequest;
+            type Value = NotesRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashRequest")
+                formatter.write_str("struct penumbra.view.v1.NotesRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
+                let mut include_spent__ = None;
+                let mut asset_id__ = None;
+                let mut address_index__ = None;
+                let mut amount_to_spend__ = None;
+                let mut allow_other_accounts__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::IncludeSpent => {
+                            if include_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("includeSpent"));
                             }
-                            id__ = map_.next_value()?;
+                            include_spent__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::AmountToSpend => {
+                            if amount_to_spend__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amountToSpend"));
+                            }
+                            amount_to_spend__ = map_.next_value()?;
+                        }
+                        GeneratedField::AllowOtherAccounts => {
+                            if allow_other_accounts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowOtherAccounts"));
+                            }
+                            allow_other_accounts__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoByHashRequest {
-                    id: id__,
+                Ok(NotesRequest {
+                    include_spent: include_spent__.unwrap_or_default(),
+                    asset_id: asset_id__,
+                    address_index: address_index__,
+                    amount_to_spend: amount_to_spend__,
+                    allow_other_accounts: allow_other_accounts__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoByHashResponse {
+impl serde::Serialize for NotesResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5123,30 +4872,30 @@ impl serde::Serialize for TransactionInfoByHashResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.tx_info.is_some() {
+        if self.note_record.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", len)?;
-        if let Some(v) = self.tx_info.as_ref() {
-            struct_ser.serialize_field("txInfo", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NotesResponse", len)?;
+        if let Some(v) = self.note_record.as_ref() {
+            struct_ser.serialize_field("noteRecord", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
+impl<'de> serde::Deserialize<'de> for NotesResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "tx_info",
-            "txInfo",
+            "note_record",
+            "noteRecord",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TxInfo,
+            NoteRecord,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5169,7 +4918,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            "noteRecord" | "note_record" => Ok(GeneratedField::NoteRecord),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5179,39 +4928,39 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoByHashResponse;
+            type Value = NotesResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashResponse")
+                formatter.write_str("struct penumbra.view.v1.NotesResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NotesResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut tx_info__ = None;
+                let mut note_record__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TxInfo => {
-                            if tx_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                        GeneratedField::NoteRecord => {
+                            if note_record__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteRecord"));
                             }
-                            tx_info__ = map_.next_value()?;
+                            note_record__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoByHashResponse {
-                    tx_info: tx_info__,
+                Ok(NotesResponse {
+                    note_record: note_record__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NotesResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoRequest {
+impl serde::Serialize for NullifierStatusRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5219,41 +4968,38 @@ impl serde::Serialize for TransactionInfoRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.start_height != 0 {
+        if self.nullifier.is_some() {
             len += 1;
         }
-        if self.end_height != 0 {
+        if self.await_detection {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoRequest", len)?;
-        if self.start_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
         }
-        if self.end_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
+impl<'de> serde::Deserialize<'de> for NullifierStatusRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "start_height",
-            "startHeight",
-            "end_height",
-            "endHeight",
+            "nullifier",
+            "await_detection",
+            "awaitDetection",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            StartHeight,
-            EndHeight,
+            Nullifier,
+            AwaitDetection,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5276,8 +5022,8 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
-                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5287,51 +5033,47 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoRequest;
+            type Value = NullifierStatusRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoRequest")
+                formatter.write_str("struct penumbra.view.v1.NullifierStatusRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut start_height__ = None;
-                let mut end_height__ = None;
+                let mut nullifier__ = None;
+                let mut await_detection__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::StartHeight => {
-                            if start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
                             }
-                            start_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            nullifier__ = map_.next_value()?;
                         }
-                        GeneratedField::EndHeight => {
-                            if end_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
                             }
-                            end_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            await_detection__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoRequest {
-                    start_height: start_height__.unwrap_or_default(),
-                    end_height: end_height__.unwrap_or_default(),
+                Ok(NullifierStatusRequest {
+                    nullifier: nullifier__,
+                    await_detection: await_detection__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionInfoResponse {
+impl serde::Serialize for NullifierStatusResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5339,30 +5081,29 @@ impl serde::Serialize for TransactionInfoResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.tx_info.is_some() {
+        if self.spent {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoResponse", len)?;
-        if let Some(v) = self.tx_info.as_ref() {
-            struct_ser.serialize_field("txInfo", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.NullifierStatusResponse", len)?;
+        if self.spent {
+            struct_ser.serialize_field("spent", &self.spent)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
+impl<'de> serde::Deserialize<'de> for NullifierStatusResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "tx_info",
-            "txInfo",
+            "spent",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            TxInfo,
+            Spent,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5385,7 +5126,7 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            "spent" => Ok(GeneratedField::Spent),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5395,39 +5136,39 @@ impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionInfoResponse;
+            type Value = NullifierStatusResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionInfoResponse")
+                formatter.write_str("struct penumbra.view.v1.NullifierStatusResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<NullifierStatusResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut tx_info__ = None;
+                let mut spent__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::TxInfo => {
-                            if tx_info__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                        GeneratedField::Spent => {
+                            if spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spent"));
                             }
-                            tx_info__ = map_.next_value()?;
+                            spent__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionInfoResponse {
-                    tx_info: tx_info__,
+                Ok(NullifierStatusResponse {
+                    spent: spent__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.NullifierStatusResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionPlannerRequest {
+impl serde::Serialize for OwnedPositionIdsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5435,161 +5176,144 @@ impl serde::Serialize for TransactionPlannerRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.expiry_height != 0 {
-            len += 1;
-        }
-        if self.memo.is_some() {
-            len += 1;
-        }
-        if self.source.is_some() {
-            len += 1;
-        }
-        if !self.outputs.is_empty() {
-            len += 1;
-        }
-        if !self.swaps.is_empty() {
-            len += 1;
-        }
-        if !self.swap_claims.is_empty() {
-            len += 1;
-        }
-        if !self.delegations.is_empty() {
-            len += 1;
-        }
-        if !self.undelegations.is_empty() {
+        if self.position_state.is_some() {
             len += 1;
         }
-        if !self.undelegation_claims.is_empty() {
+        if self.trading_pair.is_some() {
             len += 1;
         }
-        if !self.ibc_relay_actions.is_empty() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", len)?;
+        if let Some(v) = self.position_state.as_ref() {
+            struct_ser.serialize_field("positionState", v)?;
         }
-        if !self.ics20_withdrawals.is_empty() {
-            len += 1;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
-        if !self.position_opens.is_empty() {
-            len += 1;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_state",
+            "positionState",
+            "trading_pair",
+            "tradingPair",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionState,
+            TradingPair,
+            __SkipField__,
         }
-        if !self.position_closes.is_empty() {
-            len += 1;
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionState" | "position_state" => Ok(GeneratedField::PositionState),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
         }
-        if !self.position_withdraws.is_empty() {
-            len += 1;
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_state__ = None;
+                let mut trading_pair__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionState => {
+                            if position_state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionState"));
+                            }
+                            position_state__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsRequest {
+                    position_state: position_state__,
+                    trading_pair: trading_pair__,
+                })
+            }
         }
-        if self.fee_mode.is_some() {
+        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OwnedPositionIdsResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest", len)?;
-        if self.expiry_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
-        }
-        if let Some(v) = self.memo.as_ref() {
-            struct_ser.serialize_field("memo", v)?;
-        }
-        if let Some(v) = self.source.as_ref() {
-            struct_ser.serialize_field("source", v)?;
-        }
-        if !self.outputs.is_empty() {
-            struct_ser.serialize_field("outputs", &self.outputs)?;
-        }
-        if !self.swaps.is_empty() {
-            struct_ser.serialize_field("swaps", &self.swaps)?;
-        }
-        if !self.swap_claims.is_empty() {
-            struct_ser.serialize_field("swapClaims", &self.swap_claims)?;
-        }
-        if !self.delegations.is_empty() {
-            struct_ser.serialize_field("delegations", &self.delegations)?;
-        }
-        if !self.undelegations.is_empty() {
-            struct_ser.serialize_field("undelegations", &self.undelegations)?;
-        }
-        if !self.undelegation_claims.is_empty() {
-            struct_ser.serialize_field("undelegationClaims", &self.undelegation_claims)?;
-        }
-        if !self.ibc_relay_actions.is_empty() {
-            struct_ser.serialize_field("ibcRelayActions", &self.ibc_relay_actions)?;
-        }
-        if !self.ics20_withdrawals.is_empty() {
-            struct_ser.serialize_field("ics20Withdrawals", &self.ics20_withdrawals)?;
-        }
-        if !self.position_opens.is_empty() {
-            struct_ser.serialize_field("positionOpens", &self.position_opens)?;
-        }
-        if !self.position_closes.is_empty() {
-            struct_ser.serialize_field("positionCloses", &self.position_closes)?;
-        }
-        if !self.position_withdraws.is_empty() {
-            struct_ser.serialize_field("positionWithdraws", &self.position_withdraws)?;
-        }
-        if let Some(v) = self.fee_mode.as_ref() {
-            match v {
-                transaction_planner_request::FeeMode::AutoFee(v) => {
-                    struct_ser.serialize_field("autoFee", v)?;
-                }
-                transaction_planner_request::FeeMode::ManualFee(v) => {
-                    struct_ser.serialize_field("manualFee", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
+impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "expiry_height",
-            "expiryHeight",
-            "memo",
-            "source",
-            "outputs",
-            "swaps",
-            "swap_claims",
-            "swapClaims",
-            "delegations",
-            "undelegations",
-            "undelegation_claims",
-            "undelegationClaims",
-            "ibc_relay_actions",
-            "ibcRelayActions",
-            "ics20_withdrawals",
-            "ics20Withdrawals",
-            "position_opens",
-            "positionOpens",
-            "position_closes",
-            "positionCloses",
-            "position_withdraws",
-            "positionWithdraws",
-            "auto_fee",
-            "autoFee",
-            "manual_fee",
-            "manualFee",
+            "position_id",
+            "positionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ExpiryHeight,
-            Memo,
-            Source,
-            Outputs,
-            Swaps,
-            SwapClaims,
-            Delegations,
-            Undelegations,
-            UndelegationClaims,
-            IbcRelayActions,
-            Ics20Withdrawals,
-            PositionOpens,
-            PositionCloses,
-            PositionWithdraws,
-            AutoFee,
-            ManualFee,
+            PositionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5612,10 +5336,2443 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
-                            "memo" => Ok(GeneratedField::Memo),
-                            "source" => Ok(GeneratedField::Source),
-                            "outputs" => Ok(GeneratedField::Outputs),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OwnedPositionIdsResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.OwnedPositionIdsResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OwnedPositionIdsResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OwnedPositionIdsResponse {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for QueuedTransaction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.status != 0 {
+            len += 1;
+        }
+        if self.attempts != 0 {
+            len += 1;
+        }
+        if !self.last_error.is_empty() {
+            len += 1;
+        }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        if self.detection_height != 0 {
+            len += 1;
+        }
+        if self.next_attempt.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.QueuedTransaction", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if self.status != 0 {
+            let v = queued_transaction::Status::try_from(self.status)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.status)))?;
+            struct_ser.serialize_field("status", &v)?;
+        }
+        if self.attempts != 0 {
+            struct_ser.serialize_field("attempts", &self.attempts)?;
+        }
+        if !self.last_error.is_empty() {
+            struct_ser.serialize_field("lastError", &self.last_error)?;
+        }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        if self.detection_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("detectionHeight", ToString::to_string(&self.detection_height).as_str())?;
+        }
+        if let Some(v) = self.next_attempt.as_ref() {
+            struct_ser.serialize_field("nextAttempt", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for QueuedTransaction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "status",
+            "attempts",
+            "last_error",
+            "lastError",
+            "expiry_height",
+            "expiryHeight",
+            "detection_height",
+            "detectionHeight",
+            "next_attempt",
+            "nextAttempt",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Status,
+            Attempts,
+            LastError,
+            ExpiryHeight,
+            DetectionHeight,
+            NextAttempt,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "status" => Ok(GeneratedField::Status),
+                            "attempts" => Ok(GeneratedField::Attempts),
+                            "lastError" | "last_error" => Ok(GeneratedField::LastError),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            "detectionHeight" | "detection_height" => Ok(GeneratedField::DetectionHeight),
+                            "nextAttempt" | "next_attempt" => Ok(GeneratedField::NextAttempt),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = QueuedTransaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.QueuedTransaction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<QueuedTransaction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut status__ = None;
+                let mut attempts__ = None;
+                let mut last_error__ = None;
+                let mut expiry_height__ = None;
+                let mut detection_height__ = None;
+                let mut next_attempt__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Status => {
+                            if status__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("status"));
+                            }
+                            status__ = Some(map_.next_value::<queued_transaction::Status>()? as i32);
+                        }
+                        GeneratedField::Attempts => {
+                            if attempts__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("attempts"));
+                            }
+                            attempts__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::LastError => {
+                            if last_error__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lastError"));
+                            }
+                            last_error__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DetectionHeight => {
+                            if detection_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("detectionHeight"));
+                            }
+                            detection_height__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::NextAttempt => {
+                            if next_attempt__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextAttempt"));
+                            }
+                            next_attempt__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(QueuedTransaction {
+                    id: id__,
+                    status: status__.unwrap_or_default(),
+                    attempts: attempts__.unwrap_or_default(),
+                    last_error: last_error__.unwrap_or_default(),
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                    detection_height: detection_height__.unwrap_or_default(),
+                    next_attempt: next_attempt__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.QueuedTransaction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for queued_transaction::Status {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "STATUS_UNSPECIFIED",
+            Self::Pending => "STATUS_PENDING",
+            Self::Confirmed => "STATUS_CONFIRMED",
+            Self::Expired => "STATUS_EXPIRED",
+            Self::Conflicted => "STATUS_CONFLICTED",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for queued_transaction::Status {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "STATUS_UNSPECIFIED",
+            "STATUS_PENDING",
+            "STATUS_CONFIRMED",
+            "STATUS_EXPIRED",
+            "STATUS_CONFLICTED",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = queued_transaction::Status;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "STATUS_UNSPECIFIED" => Ok(queued_transaction::Status::Unspecified),
+                    "STATUS_PENDING" => Ok(queued_transaction::Status::Pending),
+                    "STATUS_CONFIRMED" => Ok(queued_transaction::Status::Confirmed),
+                    "STATUS_EXPIRED" => Ok(queued_transaction::Status::Expired),
+                    "STATUS_CONFLICTED" => Ok(queued_transaction::Status::Conflicted),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.note_commitment.is_some() {
+            len += 1;
+        }
+        if self.note.is_some() {
+            len += 1;
+        }
+        if self.address_index.is_some() {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.height_created != 0 {
+            len += 1;
+        }
+        if self.height_spent != 0 {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SpendableNoteRecord", len)?;
+        if let Some(v) = self.note_commitment.as_ref() {
+            struct_ser.serialize_field("noteCommitment", v)?;
+        }
+        if let Some(v) = self.note.as_ref() {
+            struct_ser.serialize_field("note", v)?;
+        }
+        if let Some(v) = self.address_index.as_ref() {
+            struct_ser.serialize_field("addressIndex", v)?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if self.height_created != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightCreated", ToString::to_string(&self.height_created).as_str())?;
+        }
+        if self.height_spent != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightSpent", ToString::to_string(&self.height_spent).as_str())?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("returnAddress", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SpendableNoteRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "note_commitment",
+            "noteCommitment",
+            "note",
+            "address_index",
+            "addressIndex",
+            "nullifier",
+            "height_created",
+            "heightCreated",
+            "height_spent",
+            "heightSpent",
+            "position",
+            "source",
+            "return_address",
+            "returnAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NoteCommitment,
+            Note,
+            AddressIndex,
+            Nullifier,
+            HeightCreated,
+            HeightSpent,
+            Position,
+            Source,
+            ReturnAddress,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "noteCommitment" | "note_commitment" => Ok(GeneratedField::NoteCommitment),
+                            "note" => Ok(GeneratedField::Note),
+                            "addressIndex" | "address_index" => Ok(GeneratedField::AddressIndex),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "heightCreated" | "height_created" => Ok(GeneratedField::HeightCreated),
+                            "heightSpent" | "height_spent" => Ok(GeneratedField::HeightSpent),
+                            "position" => Ok(GeneratedField::Position),
+                            "source" => Ok(GeneratedField::Source),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SpendableNoteRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SpendableNoteRecord")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SpendableNoteRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut note_commitment__ = None;
+                let mut note__ = None;
+                let mut address_index__ = None;
+                let mut nullifier__ = None;
+                let mut height_created__ = None;
+                let mut height_spent__ = None;
+                let mut position__ = None;
+                let mut source__ = None;
+                let mut return_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NoteCommitment => {
+                            if note_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("noteCommitment"));
+                            }
+                            note_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Note => {
+                            if note__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("note"));
+                            }
+                            note__ = map_.next_value()?;
+                        }
+                        GeneratedField::AddressIndex => {
+                            if address_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressIndex"));
+                            }
+                            address_index__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightCreated => {
+                            if height_created__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightCreated"));
+                            }
+                            height_created__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::HeightSpent => {
+                            if height_spent__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightSpent"));
+                            }
+                            height_spent__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SpendableNoteRecord {
+                    note_commitment: note_commitment__,
+                    note: note__,
+                    address_index: address_index__,
+                    nullifier: nullifier__,
+                    height_created: height_created__.unwrap_or_default(),
+                    height_spent: height_spent__.unwrap_or_default(),
+                    position: position__.unwrap_or_default(),
+                    source: source__,
+                    return_address: return_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SpendableNoteRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatusRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.full_sync_height != 0 {
+            len += 1;
+        }
+        if self.partial_sync_height != 0 {
+            len += 1;
+        }
+        if self.catching_up {
+            len += 1;
+        }
+        if self.birthday_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusResponse", len)?;
+        if self.full_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        }
+        if self.partial_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        }
+        if self.catching_up {
+            struct_ser.serialize_field("catchingUp", &self.catching_up)?;
+        }
+        if self.birthday_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("birthdayHeight", ToString::to_string(&self.birthday_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "full_sync_height",
+            "fullSyncHeight",
+            "partial_sync_height",
+            "partialSyncHeight",
+            "catching_up",
+            "catchingUp",
+            "birthday_height",
+            "birthdayHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FullSyncHeight,
+            PartialSyncHeight,
+            CatchingUp,
+            BirthdayHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
+                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            "catchingUp" | "catching_up" => Ok(GeneratedField::CatchingUp),
+                            "birthdayHeight" | "birthday_height" => Ok(GeneratedField::BirthdayHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut full_sync_height__ = None;
+                let mut partial_sync_height__ = None;
+                let mut catching_up__ = None;
+                let mut birthday_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FullSyncHeight => {
+                            if full_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                            }
+                            full_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartialSyncHeight => {
+                            if partial_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                            }
+                            partial_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CatchingUp => {
+                            if catching_up__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("catchingUp"));
+                            }
+                            catching_up__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BirthdayHeight => {
+                            if birthday_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("birthdayHeight"));
+                            }
+                            birthday_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StatusResponse {
+                    full_sync_height: full_sync_height__.unwrap_or_default(),
+                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                    catching_up: catching_up__.unwrap_or_default(),
+                    birthday_height: birthday_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusStreamRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamRequest", len)?;
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusStreamRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                            Ok(GeneratedField::__SkipField__)
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusStreamRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusStreamRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(StatusStreamRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for StatusStreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.latest_known_block_height != 0 {
+            len += 1;
+        }
+        if self.full_sync_height != 0 {
+            len += 1;
+        }
+        if self.partial_sync_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.StatusStreamResponse", len)?;
+        if self.latest_known_block_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("latestKnownBlockHeight", ToString::to_string(&self.latest_known_block_height).as_str())?;
+        }
+        if self.full_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fullSyncHeight", ToString::to_string(&self.full_sync_height).as_str())?;
+        }
+        if self.partial_sync_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("partialSyncHeight", ToString::to_string(&self.partial_sync_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for StatusStreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "latest_known_block_height",
+            "latestKnownBlockHeight",
+            "full_sync_height",
+            "fullSyncHeight",
+            "partial_sync_height",
+            "partialSyncHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            LatestKnownBlockHeight,
+            FullSyncHeight,
+            PartialSyncHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "latestKnownBlockHeight" | "latest_known_block_height" => Ok(GeneratedField::LatestKnownBlockHeight),
+                            "fullSyncHeight" | "full_sync_height" => Ok(GeneratedField::FullSyncHeight),
+                            "partialSyncHeight" | "partial_sync_height" => Ok(GeneratedField::PartialSyncHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = StatusStreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.StatusStreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<StatusStreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut latest_known_block_height__ = None;
+                let mut full_sync_height__ = None;
+                let mut partial_sync_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::LatestKnownBlockHeight => {
+                            if latest_known_block_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("latestKnownBlockHeight"));
+                            }
+                            latest_known_block_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FullSyncHeight => {
+                            if full_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fullSyncHeight"));
+                            }
+                            full_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PartialSyncHeight => {
+                            if partial_sync_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("partialSyncHeight"));
+                            }
+                            partial_sync_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(StatusStreamResponse {
+                    latest_known_block_height: latest_known_block_height__.unwrap_or_default(),
+                    full_sync_height: full_sync_height__.unwrap_or_default(),
+                    partial_sync_height: partial_sync_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.StatusStreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapByCommitmentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        if self.await_detection {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentRequest", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        if self.await_detection {
+            struct_ser.serialize_field("awaitDetection", &self.await_detection)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapByCommitmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap_commitment",
+            "swapCommitment",
+            "await_detection",
+            "awaitDetection",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SwapCommitment,
+            AwaitDetection,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "awaitDetection" | "await_detection" => Ok(GeneratedField::AwaitDetection),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapByCommitmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap_commitment__ = None;
+                let mut await_detection__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::AwaitDetection => {
+                            if await_detection__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("awaitDetection"));
+                            }
+                            await_detection__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapByCommitmentRequest {
+                    swap_commitment: swap_commitment__,
+                    await_detection: await_detection__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapByCommitmentResponse", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapByCommitmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Swap,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swap" => Ok(GeneratedField::Swap),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SwapByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapByCommitmentResponse {
+                    swap: swap__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SwapByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SwapRecord {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.swap_commitment.is_some() {
+            len += 1;
+        }
+        if self.swap.is_some() {
+            len += 1;
+        }
+        if self.position != 0 {
+            len += 1;
+        }
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        if self.output_data.is_some() {
+            len += 1;
+        }
+        if self.height_claimed != 0 {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.SwapRecord", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
+        }
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
+        }
+        if self.position != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("position", ToString::to_string(&self.position).as_str())?;
+        }
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        if let Some(v) = self.output_data.as_ref() {
+            struct_ser.serialize_field("outputData", v)?;
+        }
+        if self.height_claimed != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("heightClaimed", ToString::to_string(&self.height_claimed).as_str())?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SwapRecord {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "swap_commitment",
+            "swapCommitment",
+            "swap",
+            "position",
+            "nullifier",
+            "output_data",
+            "outputData",
+            "height_claimed",
+            "heightClaimed",
+            "source",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            SwapCommitment,
+            Swap,
+            Position,
+            Nullifier,
+            OutputData,
+            HeightClaimed,
+            Source,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "swap" => Ok(GeneratedField::Swap),
+                            "position" => Ok(GeneratedField::Position),
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            "outputData" | "output_data" => Ok(GeneratedField::OutputData),
+                            "heightClaimed" | "height_claimed" => Ok(GeneratedField::HeightClaimed),
+                            "source" => Ok(GeneratedField::Source),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SwapRecord;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.SwapRecord")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SwapRecord, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut swap_commitment__ = None;
+                let mut swap__ = None;
+                let mut position__ = None;
+                let mut nullifier__ = None;
+                let mut output_data__ = None;
+                let mut height_claimed__ = None;
+                let mut source__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                            }
+                            swap_commitment__ = map_.next_value()?;
+                        }
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
+                            }
+                            swap__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputData => {
+                            if output_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputData"));
+                            }
+                            output_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::HeightClaimed => {
+                            if height_claimed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("heightClaimed"));
+                            }
+                            height_claimed__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SwapRecord {
+                    swap_commitment: swap_commitment__,
+                    swap: swap__,
+                    position: position__.unwrap_or_default(),
+                    nullifier: nullifier__,
+                    output_data: output_data__,
+                    height_claimed: height_claimed__.unwrap_or_default(),
+                    source: source__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.SwapRecord", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionByNullifierRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nullifier.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionByNullifierRequest", len)?;
+        if let Some(v) = self.nullifier.as_ref() {
+            struct_ser.serialize_field("nullifier", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionByNullifierRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nullifier",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nullifier,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nullifier" => Ok(GeneratedField::Nullifier),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionByNullifierRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionByNullifierRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionByNullifierRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nullifier__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nullifier => {
+                            if nullifier__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nullifier"));
+                            }
+                            nullifier__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionByNullifierRequest {
+                    nullifier: nullifier__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionByNullifierRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionByNullifierResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionByNullifierResponse", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionByNullifierResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+            "height",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            Height,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            "height" => Ok(GeneratedField::Height),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionByNullifierResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionByNullifierResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionByNullifierResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                let mut height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionByNullifierResponse {
+                    id: id__,
+                    height: height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionByNullifierResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfo {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.id.is_some() {
+            len += 1;
+        }
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        if self.perspective.is_some() {
+            len += 1;
+        }
+        if self.view.is_some() {
+            len += 1;
+        }
+        if self.block_time.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfo", len)?;
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
+        }
+        if let Some(v) = self.perspective.as_ref() {
+            struct_ser.serialize_field("perspective", v)?;
+        }
+        if let Some(v) = self.view.as_ref() {
+            struct_ser.serialize_field("view", v)?;
+        }
+        if let Some(v) = self.block_time.as_ref() {
+            struct_ser.serialize_field("blockTime", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfo {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "height",
+            "id",
+            "transaction",
+            "perspective",
+            "view",
+            "block_time",
+            "blockTime",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Height,
+            Id,
+            Transaction,
+            Perspective,
+            View,
+            BlockTime,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "height" => Ok(GeneratedField::Height),
+                            "id" => Ok(GeneratedField::Id),
+                            "transaction" => Ok(GeneratedField::Transaction),
+                            "perspective" => Ok(GeneratedField::Perspective),
+                            "view" => Ok(GeneratedField::View),
+                            "blockTime" | "block_time" => Ok(GeneratedField::BlockTime),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfo;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfo")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfo, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut height__ = None;
+                let mut id__ = None;
+                let mut transaction__ = None;
+                let mut perspective__ = None;
+                let mut view__ = None;
+                let mut block_time__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Transaction => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transaction"));
+                            }
+                            transaction__ = map_.next_value()?;
+                        }
+                        GeneratedField::Perspective => {
+                            if perspective__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("perspective"));
+                            }
+                            perspective__ = map_.next_value()?;
+                        }
+                        GeneratedField::View => {
+                            if view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("view"));
+                            }
+                            view__ = map_.next_value()?;
+                        }
+                        GeneratedField::BlockTime => {
+                            if block_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("blockTime"));
+                            }
+                            block_time__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfo {
+                    height: height__.unwrap_or_default(),
+                    id: id__,
+                    transaction: transaction__,
+                    perspective: perspective__,
+                    view: view__,
+                    block_time: block_time__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfo", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoByHashRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoByHashRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "id",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Id,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "id" => Ok(GeneratedField::Id),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoByHashRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoByHashRequest {
+                    id: id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoByHashResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", len)?;
+        if let Some(v) = self.tx_info.as_ref() {
+            struct_ser.serialize_field("txInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoByHashResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_info",
+            "txInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxInfo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoByHashResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoByHashResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoByHashResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxInfo => {
+                            if tx_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                            }
+                            tx_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoByHashResponse {
+                    tx_info: tx_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoByHashResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoRequest", len)?;
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            StartHeight,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoRequest {
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionInfoResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.tx_info.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionInfoResponse", len)?;
+        if let Some(v) = self.tx_info.as_ref() {
+            struct_ser.serialize_field("txInfo", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionInfoResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "tx_info",
+            "txInfo",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TxInfo,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "txInfo" | "tx_info" => Ok(GeneratedField::TxInfo),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionInfoResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionInfoResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionInfoResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut tx_info__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TxInfo => {
+                            if tx_info__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("txInfo"));
+                            }
+                            tx_info__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionInfoResponse {
+                    tx_info: tx_info__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionInfoResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionPlannerRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        if self.memo.is_some() {
+            len += 1;
+        }
+        if self.source.is_some() {
+            len += 1;
+        }
+        if self.use_fee_grant {
+            len += 1;
+        }
+        if !self.outputs.is_empty() {
+            len += 1;
+        }
+        if !self.swaps.is_empty() {
+            len += 1;
+        }
+        if !self.swap_claims.is_empty() {
+            len += 1;
+        }
+        if !self.delegations.is_empty() {
+            len += 1;
+        }
+        if !self.undelegations.is_empty() {
+            len += 1;
+        }
+        if !self.undelegation_claims.is_empty() {
+            len += 1;
+        }
+        if !self.ibc_relay_actions.is_empty() {
+            len += 1;
+        }
+        if !self.ics20_withdrawals.is_empty() {
+            len += 1;
+        }
+        if !self.position_opens.is_empty() {
+            len += 1;
+        }
+        if !self.position_closes.is_empty() {
+            len += 1;
+        }
+        if !self.position_withdraws.is_empty() {
+            len += 1;
+        }
+        if self.fee_mode.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest", len)?;
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiryHeight", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        if let Some(v) = self.memo.as_ref() {
+            struct_ser.serialize_field("memo", v)?;
+        }
+        if let Some(v) = self.source.as_ref() {
+            struct_ser.serialize_field("source", v)?;
+        }
+        if self.use_fee_grant {
+            struct_ser.serialize_field("useFeeGrant", &self.use_fee_grant)?;
+        }
+        if !self.outputs.is_empty() {
+            struct_ser.serialize_field("outputs", &self.outputs)?;
+        }
+        if !self.swaps.is_empty() {
+            struct_ser.serialize_field("swaps", &self.swaps)?;
+        }
+        if !self.swap_claims.is_empty() {
+            struct_ser.serialize_field("swapClaims", &self.swap_claims)?;
+        }
+        if !self.delegations.is_empty() {
+            struct_ser.serialize_field("delegations", &self.delegations)?;
+        }
+        if !self.undelegations.is_empty() {
+            struct_ser.serialize_field("undelegations", &self.undelegations)?;
+        }
+        if !self.undelegation_claims.is_empty() {
+            struct_ser.serialize_field("undelegationClaims", &self.undelegation_claims)?;
+        }
+        if !self.ibc_relay_actions.is_empty() {
+            struct_ser.serialize_field("ibcRelayActions", &self.ibc_relay_actions)?;
+        }
+        if !self.ics20_withdrawals.is_empty() {
+            struct_ser.serialize_field("ics20Withdrawals", &self.ics20_withdrawals)?;
+        }
+        if !self.position_opens.is_empty() {
+            struct_ser.serialize_field("positionOpens", &self.position_opens)?;
+        }
+        if !self.position_closes.is_empty() {
+            struct_ser.serialize_field("positionCloses", &self.position_closes)?;
+        }
+        if !self.position_withdraws.is_empty() {
+            struct_ser.serialize_field("positionWithdraws", &self.position_withdraws)?;
+        }
+        if let Some(v) = self.fee_mode.as_ref() {
+            match v {
+                transaction_planner_request::FeeMode::AutoFee(v) => {
+                    struct_ser.serialize_field("autoFee", v)?;
+                }
+                transaction_planner_request::FeeMode::ManualFee(v) => {
+                    struct_ser.serialize_field("manualFee", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "expiry_height",
+            "expiryHeight",
+            "memo",
+            "source",
+            "use_fee_grant",
+            "useFeeGrant",
+            "outputs",
+            "swaps",
+            "swap_claims",
+            "swapClaims",
+            "delegations",
+            "undelegations",
+            "undelegation_claims",
+            "undelegationClaims",
+            "ibc_relay_actions",
+            "ibcRelayActions",
+            "ics20_withdrawals",
+            "ics20Withdrawals",
+            "position_opens",
+            "positionOpens",
+            "position_closes",
+            "positionCloses",
+            "position_withdraws",
+            "positionWithdraws",
+            "auto_fee",
+            "autoFee",
+            "manual_fee",
+            "manualFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ExpiryHeight,
+            Memo,
+            Source,
+            UseFeeGrant,
+            Outputs,
+            Swaps,
+            SwapClaims,
+            Delegations,
+            Undelegations,
+            UndelegationClaims,
+            IbcRelayActions,
+            Ics20Withdrawals,
+            PositionOpens,
+            PositionCloses,
+            PositionWithdraws,
+            AutoFee,
+            ManualFee,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "source" => Ok(GeneratedField::Source),
+                            "useFeeGrant" | "use_fee_grant" => Ok(GeneratedField::UseFeeGrant),
+                            "outputs" => Ok(GeneratedField::Outputs),
                             "swaps" => Ok(GeneratedField::Swaps),
                             "swapClaims" | "swap_claims" => Ok(GeneratedField::SwapClaims),
                             "delegations" => Ok(GeneratedField::Delegations),
@@ -5637,161 +7794,490 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionPlannerRequest;
+            type Value = TransactionPlannerRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut expiry_height__ = None;
+                let mut memo__ = None;
+                let mut source__ = None;
+                let mut use_fee_grant__ = None;
+                let mut outputs__ = None;
+                let mut swaps__ = None;
+                let mut swap_claims__ = None;
+                let mut delegations__ = None;
+                let mut undelegations__ = None;
+                let mut undelegation_claims__ = None;
+                let mut ibc_relay_actions__ = None;
+                let mut ics20_withdrawals__ = None;
+                let mut position_opens__ = None;
+                let mut position_closes__ = None;
+                let mut position_withdraws__ = None;
+                let mut fee_mode__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = map_.next_value()?;
+                        }
+                        GeneratedField::Source => {
+                            if source__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("source"));
+                            }
+                            source__ = map_.next_value()?;
+                        }
+                        GeneratedField::UseFeeGrant => {
+                            if use_fee_grant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("useFeeGrant"));
+                            }
+                            use_fee_grant__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Outputs => {
+                            if outputs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputs"));
+                            }
+                            outputs__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Swaps => {
+                            if swaps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swaps"));
+                            }
+                            swaps__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SwapClaims => {
+                            if swap_claims__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapClaims"));
+                            }
+                            swap_claims__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Delegations => {
+                            if delegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegations"));
+                            }
+                            delegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Undelegations => {
+                            if undelegations__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegations"));
+                            }
+                            undelegations__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::UndelegationClaims => {
+                            if undelegation_claims__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("undelegationClaims"));
+                            }
+                            undelegation_claims__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::IbcRelayActions => {
+                            if ibc_relay_actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcRelayActions"));
+                            }
+                            ibc_relay_actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Ics20Withdrawals => {
+                            if ics20_withdrawals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawals"));
+                            }
+                            ics20_withdrawals__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionOpens => {
+                            if position_opens__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionOpens"));
+                            }
+                            position_opens__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionCloses => {
+                            if position_closes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionCloses"));
+                            }
+                            position_closes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::PositionWithdraws => {
+                            if position_withdraws__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionWithdraws"));
+                            }
+                            position_withdraws__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::AutoFee => {
+                            if fee_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("autoFee"));
+                            }
+                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::AutoFee)
+;
+                        }
+                        GeneratedField::ManualFee => {
+                            if fee_mode__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("manualFee"));
+                            }
+                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::ManualFee)
+;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TransactionPlannerRequest {
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                    memo: memo__,
+                    source: source__,
+                    use_fee_grant: use_fee_grant__.unwrap_or_default(),
+                    outputs: outputs__.unwrap_or_default(),
+                    swaps: swaps__.unwrap_or_default(),
+                    swap_claims: swap_claims__.unwrap_or_default(),
+                    delegations: delegations__.unwrap_or_default(),
+                    undelegations: undelegations__.unwrap_or_default(),
+                    undelegation_claims: undelegation_claims__.unwrap_or_default(),
+                    ibc_relay_actions: ibc_relay_actions__.unwrap_or_default(),
+                    ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
+                    position_opens: position_opens__.unwrap_or_default(),
+                    position_closes: position_closes__.unwrap_or_default(),
+                    position_withdraws: position_withdraws__.unwrap_or_default(),
+                    fee_mode: fee_mode__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::Delegate {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            RateData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::Delegate;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Delegate")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Delegate, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::Delegate {
+                    amount: amount__,
+                    rate_data: rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::Output {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        if self.address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.address.as_ref() {
+            struct_ser.serialize_field("address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "value",
+            "address",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Value,
+            Address,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "value" => Ok(GeneratedField::Value),
+                            "address" => Ok(GeneratedField::Address),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::Output;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Output")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Output, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                let mut address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
+                            }
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::Address => {
+                            if address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("address"));
+                            }
+                            address__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(transaction_planner_request::Output {
+                    value: value__,
+                    address: address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for transaction_planner_request::PositionClose {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = transaction_planner_request::PositionClose;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionClose")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionClose, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut expiry_height__ = None;
-                let mut memo__ = None;
-                let mut source__ = None;
-                let mut outputs__ = None;
-                let mut swaps__ = None;
-                let mut swap_claims__ = None;
-                let mut delegations__ = None;
-                let mut undelegations__ = None;
-                let mut undelegation_claims__ = None;
-                let mut ibc_relay_actions__ = None;
-                let mut ics20_withdrawals__ = None;
-                let mut position_opens__ = None;
-                let mut position_closes__ = None;
-                let mut position_withdraws__ = None;
-                let mut fee_mode__ = None;
+                let mut position_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ExpiryHeight => {
-                            if expiry_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
-                            }
-                            expiry_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Memo => {
-                            if memo__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("memo"));
-                            }
-                            memo__ = map_.next_value()?;
-                        }
-                        GeneratedField::Source => {
-                            if source__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("source"));
-                            }
-                            source__ = map_.next_value()?;
-                        }
-                        GeneratedField::Outputs => {
-                            if outputs__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputs"));
-                            }
-                            outputs__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Swaps => {
-                            if swaps__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swaps"));
-                            }
-                            swaps__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::SwapClaims => {
-                            if swap_claims__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapClaims"));
-                            }
-                            swap_claims__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Delegations => {
-                            if delegations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("delegations"));
-                            }
-                            delegations__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Undelegations => {
-                            if undelegations__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("undelegations"));
-                            }
-                            undelegations__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::UndelegationClaims => {
-                            if undelegation_claims__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("undelegationClaims"));
-                            }
-                            undelegation_claims__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::IbcRelayActions => {
-                            if ibc_relay_actions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ibcRelayActions"));
-                            }
-                            ibc_relay_actions__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::Ics20Withdrawals => {
-                            if ics20_withdrawals__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("ics20Withdrawals"));
-                            }
-                            ics20_withdrawals__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::PositionOpens => {
-                            if position_opens__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionOpens"));
-                            }
-                            position_opens__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::PositionCloses => {
-                            if position_closes__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionCloses"));
-                            }
-                            position_closes__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::PositionWithdraws => {
-                            if position_withdraws__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionWithdraws"));
-                            }
-                            position_withdraws__ = Some(map_.next_value()?);
-                        }
-                        GeneratedField::AutoFee => {
-                            if fee_mode__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("autoFee"));
-                            }
-                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::AutoFee)
-;
-                        }
-                        GeneratedField::ManualFee => {
-                            if fee_mode__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("manualFee"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            fee_mode__ = map_.next_value::<::std::option::Option<_>>()?.map(transaction_planner_request::FeeMode::ManualFee)
-;
+                            position_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionPlannerRequest {
-                    expiry_height: expiry_height__.unwrap_or_default(),
-                    memo: memo__,
-                    source: source__,
-                    outputs: outputs__.unwrap_or_default(),
-                    swaps: swaps__.unwrap_or_default(),
-                    swap_claims: swap_claims__.unwrap_or_default(),
-                    delegations: delegations__.unwrap_or_default(),
-                    undelegations: undelegations__.unwrap_or_default(),
-                    undelegation_claims: undelegation_claims__.unwrap_or_default(),
-                    ibc_relay_actions: ibc_relay_actions__.unwrap_or_default(),
-                    ics20_withdrawals: ics20_withdrawals__.unwrap_or_default(),
-                    position_opens: position_opens__.unwrap_or_default(),
-                    position_closes: position_closes__.unwrap_or_default(),
-                    position_withdraws: position_withdraws__.unwrap_or_default(),
-                    fee_mode: fee_mode__,
+                Ok(transaction_planner_request::PositionClose {
+                    position_id: position_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Delegate {
+impl serde::Serialize for transaction_planner_request::PositionOpen {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5799,38 +8285,29 @@ impl serde::Serialize for transaction_planner_request::Delegate {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.amount.is_some() {
-            len += 1;
-        }
-        if self.rate_data.is_some() {
+        if self.position.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", len)?;
-        if let Some(v) = self.amount.as_ref() {
-            struct_ser.serialize_field("amount", v)?;
-        }
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "amount",
-            "rate_data",
-            "rateData",
+            "position",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Amount,
-            RateData,
+            Position,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5853,8 +8330,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
                         E: serde::de::Error,
                     {
                         match value {
-                            "amount" => Ok(GeneratedField::Amount),
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "position" => Ok(GeneratedField::Position),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5864,47 +8340,39 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Delegate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Delegate;
+            type Value = transaction_planner_request::PositionOpen;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Delegate")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionOpen")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Delegate, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionOpen, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut amount__ = None;
-                let mut rate_data__ = None;
+                let mut position__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Amount => {
-                            if amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("amount"));
-                            }
-                            amount__ = map_.next_value()?;
-                        }
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
                             }
-                            rate_data__ = map_.next_value()?;
+                            position__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Delegate {
-                    amount: amount__,
-                    rate_data: rate_data__,
+                Ok(transaction_planner_request::PositionOpen {
+                    position: position__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Delegate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Output {
+impl serde::Serialize for transaction_planner_request::PositionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5912,37 +8380,47 @@ impl serde::Serialize for transaction_planner_request::Output {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        if self.address.is_some() {
+        if self.reserves.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        if self.trading_pair.is_some() {
+            len += 1;
         }
-        if let Some(v) = self.address.as_ref() {
-            struct_ser.serialize_field("address", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "address",
+            "position_id",
+            "positionId",
+            "reserves",
+            "trading_pair",
+            "tradingPair",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            Address,
+            PositionId,
+            Reserves,
+            TradingPair,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5965,8 +8443,9 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "address" => Ok(GeneratedField::Address),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5976,47 +8455,55 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Output {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Output;
+            type Value = transaction_planner_request::PositionWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Output")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Output, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut address__ = None;
+                let mut position_id__ = None;
+                let mut reserves__ = None;
+                let mut trading_pair__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            value__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Address => {
-                            if address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("address"));
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
                             }
-                            address__ = map_.next_value()?;
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Output {
-                    value: value__,
-                    address: address__,
+                Ok(transaction_planner_request::PositionWithdraw {
+                    position_id: position_id__,
+                    reserves: reserves__,
+                    trading_pair: trading_pair__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Output", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::PositionClose {
+impl serde::Serialize for transaction_planner_request::Swap {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6024,30 +8511,55 @@ impl serde::Serialize for transaction_planner_request::PositionClose {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
+        if self.target_asset.is_some() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if self.claim_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
+        }
+        if let Some(v) = self.target_asset.as_ref() {
+            struct_ser.serialize_field("targetAsset", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if let Some(v) = self.claim_address.as_ref() {
+            struct_ser.serialize_field("claimAddress", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
+            "value",
+            "target_asset",
+            "targetAsset",
+            "fee",
+            "claim_address",
+            "claimAddress",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
+            Value,
+            TargetAsset,
+            Fee,
+            ClaimAddress,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6070,7 +8582,10 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "value" => Ok(GeneratedField::Value),
+                            "targetAsset" | "target_asset" => Ok(GeneratedField::TargetAsset),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6080,39 +8595,63 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionClose
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::PositionClose;
+            type Value = transaction_planner_request::Swap;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionClose")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Swap")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionClose, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Swap, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
+                let mut value__ = None;
+                let mut target_asset__ = None;
+                let mut fee__ = None;
+                let mut claim_address__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            position_id__ = map_.next_value()?;
+                            value__ = map_.next_value()?;
+                        }
+                        GeneratedField::TargetAsset => {
+                            if target_asset__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("targetAsset"));
+                            }
+                            target_asset__ = map_.next_value()?;
+                        }
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
+                            }
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::ClaimAddress => {
+                            if claim_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("claimAddress"));
+                            }
+                            claim_address__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionClose {
-                    position_id: position_id__,
+                Ok(transaction_planner_request::Swap {
+                    value: value__,
+                    target_asset: target_asset__,
+                    fee: fee__,
+                    claim_address: claim_address__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionClose", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::PositionOpen {
+impl serde::Serialize for transaction_planner_request::SwapClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6120,29 +8659,30 @@ impl serde::Serialize for transaction_planner_request::PositionOpen {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position.is_some() {
+        if self.swap_commitment.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", len)?;
-        if let Some(v) = self.position.as_ref() {
-            struct_ser.serialize_field("position", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", len)?;
+        if let Some(v) = self.swap_commitment.as_ref() {
+            struct_ser.serialize_field("swapCommitment", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position",
+            "swap_commitment",
+            "swapCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Position,
+            SwapCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6165,7 +8705,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen
                         E: serde::de::Error,
                     {
                         match value {
-                            "position" => Ok(GeneratedField::Position),
+                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6175,39 +8715,39 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionOpen
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::PositionOpen;
+            type Value = transaction_planner_request::SwapClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionOpen")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.SwapClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionOpen, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::SwapClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position__ = None;
+                let mut swap_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Position => {
-                            if position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("position"));
+                        GeneratedField::SwapCommitment => {
+                            if swap_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
                             }
-                            position__ = map_.next_value()?;
+                            swap_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionOpen {
-                    position: position__,
+                Ok(transaction_planner_request::SwapClaim {
+                    swap_commitment: swap_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionOpen", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::PositionWithdraw {
+impl serde::Serialize for transaction_planner_request::Undelegate {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6215,47 +8755,38 @@ impl serde::Serialize for transaction_planner_request::PositionWithdraw {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.position_id.is_some() {
-            len += 1;
-        }
-        if self.reserves.is_some() {
+        if self.value.is_some() {
             len += 1;
         }
-        if self.trading_pair.is_some() {
+        if self.rate_data.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", len)?;
-        if let Some(v) = self.position_id.as_ref() {
-            struct_ser.serialize_field("positionId", v)?;
-        }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", len)?;
+        if let Some(v) = self.value.as_ref() {
+            struct_ser.serialize_field("value", v)?;
         }
-        if let Some(v) = self.trading_pair.as_ref() {
-            struct_ser.serialize_field("tradingPair", v)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithdraw {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "position_id",
-            "positionId",
-            "reserves",
-            "trading_pair",
-            "tradingPair",
+            "value",
+            "rate_data",
+            "rateData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            PositionId,
-            Reserves,
-            TradingPair,
+            Value,
+            RateData,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6278,9 +8809,8 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
                         E: serde::de::Error,
                     {
                         match value {
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "value" => Ok(GeneratedField::Value),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6290,55 +8820,47 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::PositionWithd
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::PositionWithdraw;
+            type Value = transaction_planner_request::Undelegate;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Undelegate")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::PositionWithdraw, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Undelegate, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut position_id__ = None;
-                let mut reserves__ = None;
-                let mut trading_pair__ = None;
+                let mut value__ = None;
+                let mut rate_data__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
-                            }
-                            position_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::Value => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("value"));
                             }
-                            reserves__ = map_.next_value()?;
+                            value__ = map_.next_value()?;
                         }
-                        GeneratedField::TradingPair => {
-                            if trading_pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
                             }
-                            trading_pair__ = map_.next_value()?;
+                            rate_data__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::PositionWithdraw {
-                    position_id: position_id__,
-                    reserves: reserves__,
-                    trading_pair: trading_pair__,
+                Ok(transaction_planner_request::Undelegate {
+                    value: value__,
+                    rate_data: rate_data__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.PositionWithdraw", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Swap {
+impl serde::Serialize for transaction_planner_request::UndelegateClaim {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6346,55 +8868,57 @@ impl serde::Serialize for transaction_planner_request::Swap {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.value.is_some() {
+        if self.validator_identity.is_some() {
             len += 1;
         }
-        if self.target_asset.is_some() {
+        if self.start_epoch_index != 0 {
             len += 1;
         }
-        if self.fee.is_some() {
+        if self.penalty.is_some() {
             len += 1;
         }
-        if self.claim_address.is_some() {
+        if self.unbonding_amount.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", len)?;
+        if let Some(v) = self.validator_identity.as_ref() {
+            struct_ser.serialize_field("validatorIdentity", v)?;
         }
-        if let Some(v) = self.target_asset.as_ref() {
-            struct_ser.serialize_field("targetAsset", v)?;
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
         }
-        if let Some(v) = self.fee.as_ref() {
-            struct_ser.serialize_field("fee", v)?;
+        if let Some(v) = self.penalty.as_ref() {
+            struct_ser.serialize_field("penalty", v)?;
         }
-        if let Some(v) = self.claim_address.as_ref() {
-            struct_ser.serialize_field("claimAddress", v)?;
+        if let Some(v) = self.unbonding_amount.as_ref() {
+            struct_ser.serialize_field("unbondingAmount", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
+impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateClaim {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "target_asset",
-            "targetAsset",
-            "fee",
-            "claim_address",
-            "claimAddress",
+            "validator_identity",
+            "validatorIdentity",
+            "start_epoch_index",
+            "startEpochIndex",
+            "penalty",
+            "unbonding_amount",
+            "unbondingAmount",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            TargetAsset,
-            Fee,
-            ClaimAddress,
+            ValidatorIdentity,
+            StartEpochIndex,
+            Penalty,
+            UnbondingAmount,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6417,10 +8941,10 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
                         E: serde::de::Error,
                     {
                         match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "targetAsset" | "target_asset" => Ok(GeneratedField::TargetAsset),
-                            "fee" => Ok(GeneratedField::Fee),
-                            "claimAddress" | "claim_address" => Ok(GeneratedField::ClaimAddress),
+                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "penalty" => Ok(GeneratedField::Penalty),
+                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6430,63 +8954,65 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Swap {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Swap;
+            type Value = transaction_planner_request::UndelegateClaim;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Swap")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Swap, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::UndelegateClaim, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut target_asset__ = None;
-                let mut fee__ = None;
-                let mut claim_address__ = None;
+                let mut validator_identity__ = None;
+                let mut start_epoch_index__ = None;
+                let mut penalty__ = None;
+                let mut unbonding_amount__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
+                        GeneratedField::ValidatorIdentity => {
+                            if validator_identity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
                             }
-                            value__ = map_.next_value()?;
+                            validator_identity__ = map_.next_value()?;
                         }
-                        GeneratedField::TargetAsset => {
-                            if target_asset__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("targetAsset"));
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
                             }
-                            target_asset__ = map_.next_value()?;
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Fee => {
-                            if fee__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("fee"));
+                        GeneratedField::Penalty => {
+                            if penalty__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("penalty"));
                             }
-                            fee__ = map_.next_value()?;
+                            penalty__ = map_.next_value()?;
                         }
-                        GeneratedField::ClaimAddress => {
-                            if claim_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("claimAddress"));
+                        GeneratedField::UnbondingAmount => {
+                            if unbonding_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
                             }
-                            claim_address__ = map_.next_value()?;
+                            unbonding_amount__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::Swap {
-                    value: value__,
-                    target_asset: target_asset__,
-                    fee: fee__,
-                    claim_address: claim_address__,
+                Ok(transaction_planner_request::UndelegateClaim {
+                    validator_identity: validator_identity__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    penalty: penalty__,
+                    unbonding_amount: unbonding_amount__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Swap", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::SwapClaim {
+impl serde::Serialize for TransactionPlannerResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6494,30 +9020,29 @@ impl serde::Serialize for transaction_planner_request::SwapClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap_commitment.is_some() {
+        if self.plan.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", len)?;
-        if let Some(v) = self.swap_commitment.as_ref() {
-            struct_ser.serialize_field("swapCommitment", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerResponse", len)?;
+        if let Some(v) = self.plan.as_ref() {
+            struct_ser.serialize_field("plan", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
+impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap_commitment",
-            "swapCommitment",
+            "plan",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            SwapCommitment,
+            Plan,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6540,7 +9065,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swapCommitment" | "swap_commitment" => Ok(GeneratedField::SwapCommitment),
+                            "plan" => Ok(GeneratedField::Plan),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6550,78 +9075,61 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::SwapClaim {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::SwapClaim;
+            type Value = TransactionPlannerResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.SwapClaim")
+                formatter.write_str("struct penumbra.view.v1.TransactionPlannerResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::SwapClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap_commitment__ = None;
+                let mut plan__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::SwapCommitment => {
-                            if swap_commitment__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swapCommitment"));
+                        GeneratedField::Plan => {
+                            if plan__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("plan"));
                             }
-                            swap_commitment__ = map_.next_value()?;
+                            plan__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::SwapClaim {
-                    swap_commitment: swap_commitment__,
+                Ok(TransactionPlannerResponse {
+                    plan: plan__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.SwapClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::Undelegate {
+impl serde::Serialize for UnclaimedSwapsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.value.is_some() {
-            len += 1;
-        }
-        if self.rate_data.is_some() {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", len)?;
-        if let Some(v) = self.value.as_ref() {
-            struct_ser.serialize_field("value", v)?;
-        }
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
+impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "value",
-            "rate_data",
-            "rateData",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Value,
-            RateData,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6643,11 +9151,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "value" => Ok(GeneratedField::Value),
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -6655,47 +9159,27 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::Undelegate {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::Undelegate;
+            type Value = UnclaimedSwapsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.Undelegate")
+                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::Undelegate, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut value__ = None;
-                let mut rate_data__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::Value => {
-                            if value__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("value"));
-                            }
-                            value__ = map_.next_value()?;
-                        }
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
-                            }
-                            rate_data__ = map_.next_value()?;
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(transaction_planner_request::Undelegate {
-                    value: value__,
-                    rate_data: rate_data__,
+                Ok(UnclaimedSwapsRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.Undelegate", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for transaction_planner_request::UndelegateClaim {
+impl serde::Serialize for UnclaimedSwapsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6703,57 +9187,29 @@ impl serde::Serialize for transaction_planner_request::UndelegateClaim {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.validator_identity.is_some() {
-            len += 1;
-        }
-        if self.start_epoch_index != 0 {
-            len += 1;
-        }
-        if self.penalty.is_some() {
-            len += 1;
-        }
-        if self.unbonding_amount.is_some() {
+        if self.swap.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", len)?;
-        if let Some(v) = self.validator_identity.as_ref() {
-            struct_ser.serialize_field("validatorIdentity", v)?;
-        }
-        if self.start_epoch_index != 0 {
-            #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
-        }
-        if let Some(v) = self.penalty.as_ref() {
-            struct_ser.serialize_field("penalty", v)?;
-        }
-        if let Some(v) = self.unbonding_amount.as_ref() {
-            struct_ser.serialize_field("unbondingAmount", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", len)?;
+        if let Some(v) = self.swap.as_ref() {
+            struct_ser.serialize_field("swap", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateClaim {
+impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "validator_identity",
-            "validatorIdentity",
-            "start_epoch_index",
-            "startEpochIndex",
-            "penalty",
-            "unbonding_amount",
-            "unbondingAmount",
+            "swap",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            ValidatorIdentity,
-            StartEpochIndex,
-            Penalty,
-            UnbondingAmount,
+            Swap,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6776,10 +9232,7 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateCla
                         E: serde::de::Error,
                     {
                         match value {
-                            "validatorIdentity" | "validator_identity" => Ok(GeneratedField::ValidatorIdentity),
-                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
-                            "penalty" => Ok(GeneratedField::Penalty),
-                            "unbondingAmount" | "unbonding_amount" => Ok(GeneratedField::UnbondingAmount),
+                            "swap" => Ok(GeneratedField::Swap),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6789,65 +9242,39 @@ impl<'de> serde::Deserialize<'de> for transaction_planner_request::UndelegateCla
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = transaction_planner_request::UndelegateClaim;
+            type Value = UnclaimedSwapsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim")
+                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<transaction_planner_request::UndelegateClaim, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut validator_identity__ = None;
-                let mut start_epoch_index__ = None;
-                let mut penalty__ = None;
-                let mut unbonding_amount__ = None;
+                let mut swap__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::ValidatorIdentity => {
-                            if validator_identity__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("validatorIdentity"));
-                            }
-                            validator_identity__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartEpochIndex => {
-                            if start_epoch_index__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
-                            }
-                            start_epoch_index__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Penalty => {
-                            if penalty__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("penalty"));
-                            }
-                            penalty__ = map_.next_value()?;
-                        }
-                        GeneratedField::UnbondingAmount => {
-                            if unbonding_amount__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unbondingAmount"));
+                        GeneratedField::Swap => {
+                            if swap__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swap"));
                             }
-                            unbonding_amount__ = map_.next_value()?;
+                            swap__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(transaction_planner_request::UndelegateClaim {
-                    validator_identity: validator_identity__,
-                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
-                    penalty: penalty__,
-                    unbonding_amount: unbonding_amount__,
+                Ok(UnclaimedSwapsResponse {
+                    swap: swap__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerRequest.UndelegateClaim", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for TransactionPlannerResponse {
+impl serde::Serialize for WalletFrontier {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6855,29 +9282,108 @@ impl serde::Serialize for TransactionPlannerResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.plan.is_some() {
+        if self.version != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.TransactionPlannerResponse", len)?;
-        if let Some(v) = self.plan.as_ref() {
-            struct_ser.serialize_field("plan", v)?;
+        if self.wallet_id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.anchor.is_some() {
+            len += 1;
+        }
+        if !self.state_commitment_tree.is_empty() {
+            len += 1;
+        }
+        if !self.notes.is_empty() {
+            len += 1;
+        }
+        if !self.swaps.is_empty() {
+            len += 1;
+        }
+        if self.fmd_parameters.is_some() {
+            len += 1;
+        }
+        if self.gas_prices.is_some() {
+            len += 1;
+        }
+        if !self.checksum.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletFrontier", len)?;
+        if self.version != 0 {
+            struct_ser.serialize_field("version", &self.version)?;
+        }
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.anchor.as_ref() {
+            struct_ser.serialize_field("anchor", v)?;
+        }
+        if !self.state_commitment_tree.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("stateCommitmentTree", pbjson::private::base64::encode(&self.state_commitment_tree).as_str())?;
+        }
+        if !self.notes.is_empty() {
+            struct_ser.serialize_field("notes", &self.notes)?;
+        }
+        if !self.swaps.is_empty() {
+            struct_ser.serialize_field("swaps", &self.swaps)?;
+        }
+        if let Some(v) = self.fmd_parameters.as_ref() {
+            struct_ser.serialize_field("fmdParameters", v)?;
+        }
+        if let Some(v) = self.gas_prices.as_ref() {
+            struct_ser.serialize_field("gasPrices", v)?;
+        }
+        if !self.checksum.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("checksum", pbjson::private::base64::encode(&self.checksum).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
+impl<'de> serde::Deserialize<'de> for WalletFrontier {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "plan",
+            "version",
+            "wallet_id",
+            "walletId",
+            "height",
+            "anchor",
+            "state_commitment_tree",
+            "stateCommitmentTree",
+            "notes",
+            "swaps",
+            "fmd_parameters",
+            "fmdParameters",
+            "gas_prices",
+            "gasPrices",
+            "checksum",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Plan,
+            Version,
+            WalletId,
+            Height,
+            Anchor,
+            StateCommitmentTree,
+            Notes,
+            Swaps,
+            FmdParameters,
+            GasPrices,
+            Checksum,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6900,7 +9406,16 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "plan" => Ok(GeneratedField::Plan),
+                            "version" => Ok(GeneratedField::Version),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            "height" => Ok(GeneratedField::Height),
+                            "anchor" => Ok(GeneratedField::Anchor),
+                            "stateCommitmentTree" | "state_commitment_tree" => Ok(GeneratedField::StateCommitmentTree),
+                            "notes" => Ok(GeneratedField::Notes),
+                            "swaps" => Ok(GeneratedField::Swaps),
+                            "fmdParameters" | "fmd_parameters" => Ok(GeneratedField::FmdParameters),
+                            "gasPrices" | "gas_prices" => Ok(GeneratedField::GasPrices),
+                            "checksum" => Ok(GeneratedField::Checksum),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6910,39 +9425,119 @@ impl<'de> serde::Deserialize<'de> for TransactionPlannerResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = TransactionPlannerResponse;
+            type Value = WalletFrontier;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.TransactionPlannerResponse")
+                formatter.write_str("struct penumbra.view.v1.WalletFrontier")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionPlannerResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletFrontier, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut plan__ = None;
+                let mut version__ = None;
+                let mut wallet_id__ = None;
+                let mut height__ = None;
+                let mut anchor__ = None;
+                let mut state_commitment_tree__ = None;
+                let mut notes__ = None;
+                let mut swaps__ = None;
+                let mut fmd_parameters__ = None;
+                let mut gas_prices__ = None;
+                let mut checksum__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Plan => {
-                            if plan__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("plan"));
+                        GeneratedField::Version => {
+                            if version__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("version"));
                             }
-                            plan__ = map_.next_value()?;
+                            version__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
+                            }
+                            wallet_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Anchor => {
+                            if anchor__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("anchor"));
+                            }
+                            anchor__ = map_.next_value()?;
+                        }
+                        GeneratedField::StateCommitmentTree => {
+                            if state_commitment_tree__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stateCommitmentTree"));
+                            }
+                            state_commitment_tree__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Notes => {
+                            if notes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("notes"));
+                            }
+                            notes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Swaps => {
+                            if swaps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("swaps"));
+                            }
+                            swaps__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FmdParameters => {
+                            if fmd_parameters__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fmdParameters"));
+                            }
+                            fmd_parameters__ = map_.next_value()?;
+                        }
+                        GeneratedField::GasPrices => {
+                            if gas_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("gasPrices"));
+                            }
+                            gas_prices__ = map_.next_value()?;
+                        }
+                        GeneratedField::Checksum => {
+                            if checksum__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("checksum"));
+                            }
+                            checksum__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(TransactionPlannerResponse {
-                    plan: plan__,
+                Ok(WalletFrontier {
+                    version: version__.unwrap_or_default(),
+                    wallet_id: wallet_id__,
+                    height: height__.unwrap_or_default(),
+                    anchor: anchor__,
+                    state_commitment_tree: state_commitment_tree__.unwrap_or_default(),
+                    notes: notes__.unwrap_or_default(),
+                    swaps: swaps__.unwrap_or_default(),
+                    fmd_parameters: fmd_parameters__,
+                    gas_prices: gas_prices__,
+                    checksum: checksum__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.TransactionPlannerResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletFrontier", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UnclaimedSwapsRequest {
+impl serde::Serialize for WalletIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -6950,11 +9545,11 @@ impl serde::Serialize for UnclaimedSwapsRequest {
     {
         use serde::ser::SerializeStruct;
         let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", len)?;
+        let struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletIdRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
+impl<'de> serde::Deserialize<'de> for WalletIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -6994,27 +9589,27 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UnclaimedSwapsRequest;
+            type Value = WalletIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsRequest")
+                formatter.write_str("struct penumbra.view.v1.WalletIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 while map_.next_key::<GeneratedField>()?.is_some() {
                     let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(UnclaimedSwapsRequest {
+                Ok(WalletIdRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for UnclaimedSwapsResponse {
+impl serde::Serialize for WalletIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7022,29 +9617,30 @@ impl serde::Serialize for UnclaimedSwapsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.swap.is_some() {
+        if self.wallet_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", len)?;
-        if let Some(v) = self.swap.as_ref() {
-            struct_ser.serialize_field("swap", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletIdResponse", len)?;
+        if let Some(v) = self.wallet_id.as_ref() {
+            struct_ser.serialize_field("walletId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
+impl<'de> serde::Deserialize<'de> for WalletIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "swap",
+            "wallet_id",
+            "walletId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Swap,
+            WalletId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7067,7 +9663,7 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "swap" => Ok(GeneratedField::Swap),
+                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7077,61 +9673,70 @@ impl<'de> serde::Deserialize<'de> for UnclaimedSwapsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = UnclaimedSwapsResponse;
+            type Value = WalletIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.UnclaimedSwapsResponse")
+                formatter.write_str("struct penumbra.view.v1.WalletIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnclaimedSwapsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut swap__ = None;
+                let mut wallet_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Swap => {
-                            if swap__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("swap"));
+                        GeneratedField::WalletId => {
+                            if wallet_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("walletId"));
                             }
-                            swap__ = map_.next_value()?;
+                            wallet_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(UnclaimedSwapsResponse {
-                    swap: swap__,
+                Ok(WalletIdResponse {
+                    wallet_id: wallet_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.UnclaimedSwapsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WalletIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for WalletIdRequest {
+impl serde::Serialize for WatchPositionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletIdRequest", len)?;
+        let mut len = 0;
+        if !self.position_ids.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WatchPositionsRequest", len)?;
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for WalletIdRequest {
+impl<'de> serde::Deserialize<'de> for WatchPositionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "position_ids",
+            "positionIds",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            PositionIds,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7153,7 +9758,10 @@ impl<'de> serde::Deserialize<'de> for WalletIdRequest {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -7161,27 +9769,39 @@ impl<'de> serde::Deserialize<'de> for WalletIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = WalletIdRequest;
+            type Value = WatchPositionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.WalletIdRequest")
+                formatter.write_str("struct penumbra.view.v1.WatchPositionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WatchPositionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut position_ids__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                            }
+                            position_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(WalletIdRequest {
+                Ok(WatchPositionsRequest {
+                    position_ids: position_ids__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.WalletIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WatchPositionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for WalletIdResponse {
+impl serde::Serialize for WatchPositionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -7189,30 +9809,47 @@ impl serde::Serialize for WalletIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.wallet_id.is_some() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WalletIdResponse", len)?;
-        if let Some(v) = self.wallet_id.as_ref() {
-            struct_ser.serialize_field("walletId", v)?;
+        if self.position.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WatchPositionsResponse", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for WalletIdResponse {
+impl<'de> serde::Deserialize<'de> for WatchPositionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "wallet_id",
-            "walletId",
+            "position_id",
+            "positionId",
+            "position",
+            "height",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            WalletId,
+            PositionId,
+            Position,
+            Height,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7235,7 +9872,9 @@ impl<'de> serde::Deserialize<'de> for WalletIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "walletId" | "wallet_id" => Ok(GeneratedField::WalletId),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "position" => Ok(GeneratedField::Position),
+                            "height" => Ok(GeneratedField::Height),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7245,36 +9884,54 @@ impl<'de> serde::Deserialize<'de> for WalletIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = WalletIdResponse;
+            type Value = WatchPositionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.view.v1.WalletIdResponse")
+                formatter.write_str("struct penumbra.view.v1.WatchPositionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WalletIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WatchPositionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut wallet_id__ = None;
+                let mut position_id__ = None;
+                let mut position__ = None;
+                let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::WalletId => {
-                            if wallet_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("walletId"));
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            wallet_id__ = map_.next_value()?;
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ =
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(WalletIdResponse {
-                    wallet_id: wallet_id__,
+                Ok(WatchPositionsResponse {
+                    position_id: position_id__,
+                    position: position__,
+                    height: height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.view.v1.WalletIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.view.v1.WatchPositionsResponse", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for WitnessAndBuildRequest {
@@ -7697,6 +10354,197 @@ impl<'de> serde::Deserialize<'de> for witness_and_build_response::Complete {
         deserializer.deserialize_struct("penumbra.view.v1.WitnessAndBuildResponse.Complete", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for WitnessByCommitmentRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.commitments.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WitnessByCommitmentRequest", len)?;
+        if !self.commitments.is_empty() {
+            struct_ser.serialize_field("commitments", &self.commitments)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WitnessByCommitmentRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "commitments",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Commitments,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "commitments" => Ok(GeneratedField::Commitments),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WitnessByCommitmentRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.WitnessByCommitmentRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WitnessByCommitmentRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut commitments__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Commitments => {
+                            if commitments__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commitments"));
+                            }
+                            commitments__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(WitnessByCommitmentRequest {
+                    commitments: commitments__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.WitnessByCommitmentRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for WitnessByCommitmentResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.witness_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.WitnessByCommitmentResponse", len)?;
+        if let Some(v) = self.witness_data.as_ref() {
+            struct_ser.serialize_field("witnessData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for WitnessByCommitmentResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "witness_data",
+            "witnessData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            WitnessData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "witnessData" | "witness_data" => Ok(GeneratedField::WitnessData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = WitnessByCommitmentResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.WitnessByCommitmentResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<WitnessByCommitmentResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut witness_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::WitnessData => {
+                            if witness_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("witnessData"));
+                            }
+                            witness_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(WitnessByCommitmentResponse {
+                    witness_data: witness_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.WitnessByCommitmentResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for WitnessRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -117,6 +117,28 @@ pub mod validator_governance_key {
     }
 }
 
+pub mod validator_consensus_key {
+    use super::*;
+
+    /// The Bech32 prefix used for validator consensus keys.
+    pub const BECH32_PREFIX: &str = "penumbravalconspub";
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_bech32(deserializer, BECH32_PREFIX, Variant::Bech32m)
+    }
+
+    pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: AsRef<[u8]>,
+    {
+        serialize_bech32(value, serializer, BECH32_PREFIX, Variant::Bech32m)
+    }
+}
+
 pub mod address {
     use super::*;
 
@@ -0,0 +1,65 @@
+//! A single entry point to the client APIs of the Penumbra Zone.
+//!
+//! Integrators building wallets, exchanges or payment processors on Penumbra would otherwise
+//! depend on a dozen crates, whose module layout follows the internals of the node. This crate
+//! re-exports the parts of them that clients use, at stable paths:
+//!
+//! - [`view`]: the [`ViewClient`](view::ViewClient) used to query a wallet's synced state, the
+//!   [`ViewServer`](view::ViewServer) to run one in-process, and the [`Planner`](view::Planner)
+//!   for building transaction plans;
+//! - [`custody`]: the [`CustodyClient`](custody::CustodyClient) used to authorize plans, and the
+//!   in-process [`SoftKms`](custody::soft_kms::SoftKms);
+//! - [`wallet`]: building authorized plans into transactions;
+//! - the domain types of keys, assets, amounts, fees, transactions and the components clients
+//!   interact with;
+//! - [`proto`]: the protobuf types and gRPC clients, for talking to nodes and remote services.
+//!
+//! Most programs only need the [`prelude`]:
+//!
+//! ```no_run
+//! use penumbra_sdk::prelude::*;
+//! ```
+//!
+//! # Stability
+//!
+//! The paths exported here are the supported API. Removing or renaming any of them, or changing
+//! the meaning of an item reachable through them, is a breaking change, and is only made in a
+//! release that bumps the major version (or the minor version, before 1.0). Items of the
+//! underlying crates that aren't re-exported here may change in any release.
+
+#![deny(clippy::unwrap_used)]
+// Requires nightly.
+#![cfg_attr(docsrs, feature(doc_auto_cfg))]
+
+pub use penumbra_asset as asset;
+pub use penumbra_custody as custody;
+pub use penumbra_dex as dex;
+pub use penumbra_fee as fee;
+pub use penumbra_governance as governance;
+pub use penumbra_keys as keys;
+pub use penumbra_num as num;
+pub use penumbra_proto as proto;
+pub use penumbra_shielded_pool as shielded_pool;
+pub use penumbra_stake as stake;
+pub use penumbra_tct as tct;
+pub use penumbra_transaction as transaction;
+pub use penumbra_txhash as txhash;
+pub use penumbra_view as view;
+pub use penumbra_wallet as wallet;
+
+/// The items most programs using the SDK need, for glob import.
+pub mod prelude {
+    pub use penumbra_asset::{asset, Balance, Value};
+    pub use penumbra_custody::{AuthorizeRequest, CustodyClient};
+    pub use penumbra_fee::{Fee, FeeTier, GasPrices};
+    pub use penumbra_keys::{
+        keys::{AddressIndex, SeedPhrase, SpendKey},
+        Address, FullViewingKey,
+    };
+    pub use penumbra_num::Amount;
+    pub use penumbra_proto::DomainType;
+    pub use penumbra_transaction::{Transaction, TransactionPlan};
+    pub use penumbra_txhash::TransactionId;
+    pub use penumbra_view::{Planner, ViewClient};
+    pub use penumbra_wallet::build_transaction;
+}
@@ -173,11 +173,15 @@ impl MockClient {
     pub fn witness_plan(&self, plan: &TransactionPlan) -> Result<WitnessData, Error> {
         Ok(WitnessData {
             anchor: self.sct.root(),
-            // TODO: this will only witness spends, not other proofs like swaps
+            // TODO: this will only witness spends and swap claims, not other proofs like votes
             state_commitment_proofs: plan
                 .spend_plans()
-                .map(|spend| {
-                    let nc = spend.note.commit();
+                .map(|spend| spend.note.commit())
+                .chain(
+                    plan.swap_claim_plans()
+                        .map(|claim| claim.swap_plaintext.swap_commitment()),
+                )
+                .map(|nc| {
                     Ok((
                         nc,
                         self.sct.witness(nc).ok_or_else(|| {
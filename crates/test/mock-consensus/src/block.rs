@@ -10,7 +10,7 @@ use {
         account,
         block::{self, header::Version, Block, Commit, Header, Round},
         chain, evidence,
-        v0_37::abci::{ConsensusRequest, ConsensusResponse},
+        v0_37::abci::{response, ConsensusRequest, ConsensusResponse},
         AppHash, Hash,
     },
     tower::{BoxError, Service},
@@ -31,6 +31,23 @@ pub struct Builder<'e, C> {
     evidence: evidence::List,
 }
 
+/// The consensus service's responses to executing a [`Block`].
+///
+/// These are returned by [`Builder::execute_and_collect()`].
+#[derive(Debug)]
+pub struct Executed {
+    /// The header of the executed block.
+    pub header: Header,
+    /// The response to the `BeginBlock` request.
+    pub begin_block: response::BeginBlock,
+    /// The responses to the `DeliverTx` requests, one per transaction, in order.
+    pub deliver_tx: Vec<response::DeliverTx>,
+    /// The response to the `EndBlock` request.
+    pub end_block: response::EndBlock,
+    /// The response to the `Commit` request, holding the app hash after the block.
+    pub commit: response::Commit,
+}
+
 impl<C> TestNode<C> {
     /// Returns a new [`Builder`].
     pub fn block<'e>(&'e mut self) -> Builder<'e, C> {
@@ -74,8 +91,14 @@ where
     /// Consumes this builder, executing the [`Block`] using the consensus service.
     ///
     /// Use [`TestNode::block()`] to build a new block.
-    #[instrument(level = "info", skip_all, fields(height, time))]
     pub async fn execute(self) -> Result<(), anyhow::Error> {
+        self.execute_and_collect().await.map(|_| ())
+    }
+
+    /// Consumes this builder, executing the [`Block`] like [`Builder::execute()`], and returns
+    /// the consensus service's responses.
+    #[instrument(level = "info", skip_all, fields(height, time))]
+    pub async fn execute_and_collect(self) -> Result<Executed, anyhow::Error> {
         let (test_node, block) = self.finish()?;
 
         let Block {
@@ -91,16 +114,23 @@ where
         });
 
         info!("sending block");
-        test_node.begin_block(header).await?;
+        let begin_block = test_node.begin_block(header.clone()).await?;
+        let mut deliver_tx = Vec::with_capacity(data.len());
         for tx in data {
             let tx = tx.into();
-            test_node.deliver_tx(tx).await?;
+            deliver_tx.push(test_node.deliver_tx(tx).await?);
         }
-        test_node.end_block().await?;
-        test_node.commit().await?;
+        let end_block = test_node.end_block().await?;
+        let commit = test_node.commit().await?;
         info!("finished sending block");
 
-        Ok(())
+        Ok(Executed {
+            header,
+            begin_block,
+            deliver_tx,
+            end_block,
+            commit,
+        })
     }
 
     /// Consumes this builder, returning its [`TestNode`] reference and a [`Block`].
@@ -11,10 +11,13 @@ use proto::util::tendermint_proxy::v1::BroadcastTxAsyncRequest;
 use proto::util::tendermint_proxy::v1::BroadcastTxAsyncResponse;
 use proto::util::tendermint_proxy::v1::BroadcastTxSyncRequest;
 use proto::util::tendermint_proxy::v1::BroadcastTxSyncResponse;
+use proto::util::tendermint_proxy::v1::Event;
 use proto::util::tendermint_proxy::v1::GetBlockByHeightRequest;
 use proto::util::tendermint_proxy::v1::GetBlockByHeightResponse;
 use proto::util::tendermint_proxy::v1::GetStatusRequest;
 use proto::util::tendermint_proxy::v1::GetStatusResponse;
+use proto::util::tendermint_proxy::v1::GetTxInclusionProofRequest;
+use proto::util::tendermint_proxy::v1::GetTxInclusionProofResponse;
 use proto::util::tendermint_proxy::v1::GetTxRequest;
 use proto::util::tendermint_proxy::v1::GetTxResponse;
 use proto::util::tendermint_proxy::v1::SyncInfo;
@@ -95,6 +98,74 @@ impl TendermintProxyService for TendermintProxy {
         }))
     }
 
+    async fn get_tx_inclusion_proof(
+        &self,
+        req: tonic::Request<GetTxInclusionProofRequest>,
+    ) -> Result<tonic::Response<GetTxInclusionProofResponse>, Status> {
+        let client = HttpClient::new(self.tendermint_url.to_string().as_ref()).map_err(|e| {
+            tonic::Status::unavailable(format!("error creating tendermint http client: {e:#?}"))
+        })?;
+
+        let hash = req.into_inner().hash;
+        let rsp = client
+            .tx(
+                hash.try_into().map_err(|e| {
+                    tonic::Status::invalid_argument(format!("invalid transaction hash: {e:#?}"))
+                })?,
+                true,
+            )
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting tx: {e}")))?;
+        let proof = rsp
+            .proof
+            .ok_or_else(|| tonic::Status::unavailable("tendermint returned no proof for tx"))?;
+
+        let commit = client
+            .commit(rsp.height)
+            .await
+            .map_err(|e| tonic::Status::unavailable(format!("error getting commit: {e}")))?;
+
+        // As with blocks, we convert through the tendermint-proto types, whose encoding matches
+        // the penumbra proto copies of them.
+        let proof = proto::tendermint::types::TxProof::decode(
+            tendermint_proto::types::TxProof::from(proof)
+                .encode_to_vec()
+                .as_slice(),
+        )
+        .map_err(|e| tonic::Status::internal(format!("error converting tx proof: {e}")))?;
+        let signed_header = proto::tendermint::types::SignedHeader::decode(
+            tendermint_proto::types::SignedHeader::from(commit.signed_header)
+                .encode_to_vec()
+                .as_slice(),
+        )
+        .map_err(|e| tonic::Status::internal(format!("error converting signed header: {e}")))?;
+
+        Ok(tonic::Response::new(GetTxInclusionProofResponse {
+            height: rsp.height.value(),
+            index: rsp.index as u64,
+            proof: Some(proof),
+            signed_header: Some(signed_header),
+            code: rsp.tx_result.code.value(),
+            events: rsp
+                .tx_result
+                .events
+                .into_iter()
+                .map(|e| Event {
+                    r#type: e.kind,
+                    attributes: e
+                        .attributes
+                        .into_iter()
+                        .map(|a| Tag {
+                            key: a.key.to_string().as_bytes().to_vec(),
+                            value: a.value.to_string().as_bytes().to_vec(),
+                            index: a.index,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }))
+    }
+
     async fn broadcast_tx_async(
         &self,
         req: tonic::Request<BroadcastTxAsyncRequest>,
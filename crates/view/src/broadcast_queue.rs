@@ -0,0 +1,157 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use penumbra_proto::{
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
+    },
+    DomainType,
+};
+use penumbra_transaction::Transaction;
+use rand::Rng;
+use rand_core::OsRng;
+
+use crate::{NodePool, QueueStatus, Storage};
+
+/// How long to wait before re-sending a transaction sent once.
+const MIN_BACKOFF: Duration = Duration::from_secs(5);
+
+/// The longest to wait before re-sending a transaction.
+const MAX_BACKOFF: Duration = Duration::from_secs(10 * 60);
+
+/// How often to check the queue for transactions due to be sent.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long to wait before re-sending a transaction that was sent `attempts` times.
+///
+/// The wait doubles with each attempt, up to [`MAX_BACKOFF`].
+pub(crate) fn backoff(attempts: u32) -> Duration {
+    MIN_BACKOFF
+        .saturating_mul(1 << attempts.saturating_sub(1).min(16))
+        .min(MAX_BACKOFF)
+}
+
+/// The current unix time, in seconds.
+pub(crate) fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// The unix time, in seconds, at which to re-send a transaction sent `attempts` times by `now`.
+pub(crate) fn next_attempt(now: i64, attempts: u32) -> i64 {
+    now.saturating_add(backoff(attempts).as_secs() as i64)
+}
+
+/// Sends a transaction to the pool's current node, without waiting for it to be included.
+pub(crate) async fn send(nodes: &NodePool, transaction: &Transaction) -> anyhow::Result<()> {
+    let mut client = TendermintProxyServiceClient::connect(nodes.current().to_string()).await?;
+    let rsp = client
+        .broadcast_tx_sync(BroadcastTxSyncRequest {
+            params: transaction.encode_to_vec(),
+            req_id: OsRng.gen(),
+        })
+        .await?
+        .into_inner();
+    tracing::debug!(?rsp);
+    if rsp.code != 0 {
+        anyhow::bail!(
+            "Error submitting transaction: code {}, log: {}",
+            rsp.code,
+            rsp.log
+        );
+    }
+    Ok(())
+}
+
+/// A task that re-sends the transactions in the broadcast queue until they're settled.
+///
+/// The queue is kept in storage, so transactions queued before a restart are picked up again.
+/// A pending transaction is sent again on a backoff schedule, since fullnodes may drop it from
+/// their mempool, until the view service detects it on chain, detects another transaction
+/// spending one of its nullifiers, or syncs past its expiry height.
+pub(crate) struct BroadcastQueue {
+    storage: Storage,
+    nodes: NodePool,
+}
+
+impl BroadcastQueue {
+    pub fn new(storage: Storage, nodes: NodePool) -> Self {
+        Self { storage, nodes }
+    }
+
+    pub async fn run(self) {
+        loop {
+            if let Err(e) = self.send_due().await {
+                tracing::warn!(?e, "error processing broadcast queue");
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn send_due(&self) -> anyhow::Result<()> {
+        let now = unix_now();
+        let sync_height = self.storage.last_sync_height().await?.unwrap_or(0);
+
+        for (transaction, attempts) in self.storage.due_broadcasts(now).await? {
+            let id = transaction.id();
+            if let Some(status) = settled(&self.storage, &transaction, sync_height).await? {
+                tracing::info!(%id, ?status, "queued transaction settled");
+                self.storage.settle_broadcast(id, status).await?;
+                continue;
+            }
+
+            let error = match send(&self.nodes, &transaction).await {
+                Ok(()) => None,
+                Err(e) => {
+                    tracing::debug!(%id, ?e, "re-sending queued transaction failed");
+                    Some(format!("{e:#}"))
+                }
+            };
+            self.storage
+                .record_broadcast_attempt(id, error, next_attempt(now, attempts + 1))
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns how a broadcast transaction was settled, as of the height the view service has synced
+/// to, or `None` if it's still pending.
+pub(crate) async fn settled(
+    storage: &Storage,
+    transaction: &Transaction,
+    sync_height: u64,
+) -> anyhow::Result<Option<QueueStatus>> {
+    let id = transaction.id();
+    if let Some((detection_height, _)) = storage.transaction_by_hash(&id.0).await? {
+        return Ok(Some(QueueStatus::Confirmed { detection_height }));
+    }
+    for nullifier in transaction.spent_nullifiers() {
+        if let Some((_, spender)) = storage.transaction_by_nullifier(nullifier).await? {
+            if spender != id {
+                return Ok(Some(QueueStatus::Conflicted));
+            }
+        }
+    }
+    let expiry_height = transaction.transaction_parameters().expiry_height;
+    if expiry_height != 0 && sync_height >= expiry_height {
+        return Ok(Some(QueueStatus::Expired));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_up_to_the_maximum() {
+        assert_eq!(backoff(1), MIN_BACKOFF);
+        assert_eq!(backoff(2), MIN_BACKOFF * 2);
+        assert_eq!(backoff(4), MIN_BACKOFF * 8);
+        assert_eq!(backoff(20), MAX_BACKOFF);
+        assert_eq!(backoff(u32::MAX), MAX_BACKOFF);
+    }
+}
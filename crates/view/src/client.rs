@@ -15,20 +15,23 @@ use penumbra_dex::{
     TradingPair,
 };
 use penumbra_fee::GasPrices;
-use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_keys::{keys::AddressIndex, Address, PayloadKey};
 use penumbra_num::Amount;
 use penumbra_proto::view::v1::{
     self as pb, view_service_client::ViewServiceClient, BalancesResponse,
     BroadcastTransactionResponse, WitnessRequest,
 };
 use penumbra_sct::Nullifier;
-use penumbra_shielded_pool::{fmd, note};
+use penumbra_shielded_pool::{fmd, note, Note};
 use penumbra_stake::IdentityKey;
 use penumbra_transaction::{
     txhash::TransactionId, AuthorizationData, Transaction, TransactionPlan, WitnessData,
 };
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{
+    transaction_info::time_from_proto, EphemeralAddressRecord, PositionUpdate, QueuedTransaction,
+    SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo, WalletFrontier,
+};
 
 pub(crate) type BroadcastStatusStream = Pin<
     Box<dyn Future<Output = Result<Streaming<BroadcastTransactionResponse>, anyhow::Error>> + Send>,
@@ -67,6 +70,11 @@ pub trait ViewClient {
         >,
     >;
 
+    /// Get the chain ID and app version the view service is tracking, and its storage version.
+    fn handshake(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<pb::HandshakeResponse>> + Send + 'static>>;
+
     /// Get a copy of the app parameters.
     fn app_params(
         &mut self,
@@ -101,12 +109,28 @@ pub trait ViewClient {
         asset_id: Option<asset::Id>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<(Id, Amount)>>> + Send + 'static>>;
 
+    /// Queries for the balance of every asset in every account at once, along with the number of
+    /// notes making up each balance.
+    fn balance_matrix(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<(AddressIndex, ValueView, u64)>>> + Send + 'static>>;
+
     /// Queries for a specific note by commitment, returning immediately if it is not found.
     fn note_by_commitment(
         &mut self,
         note_commitment: note::StateCommitment,
     ) -> Pin<Box<dyn Future<Output = Result<SpendableNoteRecord>> + Send + 'static>>;
 
+    /// Queries for a note by commitment, decrypting it with the payload key of the output that
+    /// created it, and returning it along with the height and ID of that output's transaction.
+    ///
+    /// Unlike [`ViewClient::note_by_commitment`], the note need not be owned by the wallet.
+    fn disclosed_note_by_commitment(
+        &mut self,
+        note_commitment: note::StateCommitment,
+        payload_key: PayloadKey,
+    ) -> Pin<Box<dyn Future<Output = Result<(Note, u64, TransactionId)>> + Send + 'static>>;
+
     /// Queries for a specific swap by commitment, returning immediately if it is not found.
     fn swap_by_commitment(
         &mut self,
@@ -126,6 +150,13 @@ pub trait ViewClient {
         nullifier: Nullifier,
     ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'static>>;
 
+    /// Queries for the height and hash of the transaction that spent a nullifier, returning
+    /// `None` if the nullifier is unspent or was spent by a transaction the wallet hasn't seen.
+    fn transaction_by_nullifier(
+        &mut self,
+        nullifier: Nullifier,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<(u64, TransactionId)>>> + Send + 'static>>;
+
     /// Queries for a specific note by commitment, waiting until the note is detected if it is not found.
     ///
     /// This is useful for waiting for a note to be detected by the view service.
@@ -145,6 +176,15 @@ pub trait ViewClient {
         plan: &TransactionPlan,
     ) -> Pin<Box<dyn Future<Output = Result<WitnessData>> + Send + 'static>>;
 
+    /// Returns authentication paths for the given state commitments, to a common anchor.
+    ///
+    /// Only commitments witnessed by the wallet's state commitment tree, those of its own notes
+    /// and swaps, can be witnessed.
+    fn witness_by_commitment(
+        &mut self,
+        commitments: Vec<penumbra_tct::StateCommitment>,
+    ) -> Pin<Box<dyn Future<Output = Result<WitnessData>> + Send + 'static>>;
+
     /// Returns a transaction built from the provided TransactionPlan and AuthorizationData
     fn witness_and_build(
         &mut self,
@@ -162,6 +202,22 @@ pub trait ViewClient {
         trading_pair: Option<TradingPair>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<position::Id>>> + Send + 'static>>;
 
+    /// Watches liquidity positions by ID, streaming the on-chain state of each position when
+    /// the stream starts and whenever it changes, including before the position is opened.
+    fn watch_positions(
+        &mut self,
+        position_ids: Vec<position::Id>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Pin<Box<dyn Stream<Item = Result<PositionUpdate>> + Send + 'static>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    >;
+
     /// Generates a full perspective for a selected transaction using a full viewing key
     fn transaction_info_by_hash(
         &mut self,
@@ -181,6 +237,39 @@ pub trait ViewClient {
         await_detection: bool,
     ) -> BroadcastStatusStream;
 
+    /// Broadcasts a transaction containing swaps, bundled with the claims of those swaps.
+    ///
+    /// The view service submits each claim by itself once the swap's batch has executed,
+    /// paying with the claim fee pre-paid in the swap.
+    fn broadcast_swap_bundle(
+        &mut self,
+        transaction: Transaction,
+        await_detection: bool,
+    ) -> BroadcastStatusStream;
+
+    /// Broadcasts a transaction under an idempotency key, so that the broadcast can be retried
+    /// safely.
+    ///
+    /// The view service records the first transaction broadcast with a given key, and re-sends
+    /// that transaction whenever the key is reused, rather than the one passed in, so that
+    /// retrying with a freshly built transaction can't send funds twice.
+    fn broadcast_idempotent(
+        &mut self,
+        transaction: Transaction,
+        await_detection: bool,
+        claim_swaps: bool,
+        idempotency_key: String,
+    ) -> BroadcastStatusStream;
+
+    /// Queries for the transactions in the view service's broadcast queue, or only the one with
+    /// the given id.
+    ///
+    /// This is empty unless the view service keeps a broadcast queue, as `pclientd` does.
+    fn broadcast_queue(
+        &mut self,
+        id: Option<TransactionId>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<QueuedTransaction>>> + Send + 'static>>;
+
     /// Return unspent notes, grouped by address index and then by asset id.
     #[instrument(skip(self))]
     fn unspent_notes_by_address_and_asset(
@@ -302,10 +391,37 @@ pub trait ViewClient {
         address_index: AddressIndex,
     ) -> Pin<Box<dyn Future<Output = Result<Address>> + Send + 'static>>;
 
+    /// Generates a fresh ephemeral address for the given account, which the view service
+    /// remembers so that its uses can be audited.
+    fn ephemeral_address(
+        &mut self,
+        address_index: AddressIndex,
+    ) -> Pin<Box<dyn Future<Output = Result<Address>> + Send + 'static>>;
+
+    /// Queries for the ephemeral addresses generated by the view service, optionally only
+    /// those of the given account, along with the transactions that used them.
+    fn ephemeral_addresses(
+        &mut self,
+        account: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<EphemeralAddressRecord>>> + Send + 'static>>;
+
     /// Queries for unclaimed Swaps.
     fn unclaimed_swaps(
         &mut self,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<SwapRecord>>> + Send + 'static>>;
+
+    /// Exports the wallet's state commitment tree, with the notes and swaps it witnesses, so
+    /// that the wallet can be moved to another view service without rescanning.
+    fn export_frontier(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<WalletFrontier>> + Send + 'static>>;
+
+    /// Imports a frontier exported by [`ViewClient::export_frontier`], returning the height the
+    /// view service will resume syncing after.
+    fn import_frontier(
+        &mut self,
+        frontier: WalletFrontier,
+    ) -> Pin<Box<dyn Future<Output = Result<u64>> + Send + 'static>>;
 }
 
 // We need to tell `async_trait` not to add a `Send` bound to the boxed
@@ -359,6 +475,22 @@ where
         .boxed()
     }
 
+    fn handshake(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<pb::HandshakeResponse>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            // We have to manually invoke the method on the type, because it has the
+            // same name as the one we're implementing.
+            let rsp = ViewServiceClient::handshake(
+                &mut self2,
+                tonic::Request::new(pb::HandshakeRequest {}),
+            );
+            Ok(rsp.await?.into_inner())
+        }
+        .boxed()
+    }
+
     fn app_params(
         &mut self,
     ) -> Pin<Box<dyn Future<Output = Result<AppParameters>> + Send + 'static>> {
@@ -491,6 +623,37 @@ where
         .boxed()
     }
 
+    fn disclosed_note_by_commitment(
+        &mut self,
+        note_commitment: note::StateCommitment,
+        payload_key: PayloadKey,
+    ) -> Pin<Box<dyn Future<Output = Result<(Note, u64, TransactionId)>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let response = ViewServiceClient::disclosed_note_by_commitment(
+                &mut self2,
+                tonic::Request::new(pb::DisclosedNoteByCommitmentRequest {
+                    note_commitment: Some(note_commitment.into()),
+                    payload_key: Some(payload_key.into()),
+                }),
+            )
+            .await?
+            .into_inner();
+
+            let note = response
+                .note
+                .ok_or_else(|| anyhow::anyhow!("empty DisclosedNoteByCommitmentResponse message"))?
+                .try_into()?;
+            let transaction_id = response
+                .transaction_id
+                .ok_or_else(|| anyhow::anyhow!("missing transaction ID"))?
+                .try_into()?;
+
+            Ok((note, response.height_created, transaction_id))
+        }
+        .boxed()
+    }
+
     fn balances(
         &mut self,
         address_index: AddressIndex,
@@ -525,6 +688,38 @@ where
         .boxed()
     }
 
+    fn balance_matrix(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<(AddressIndex, ValueView, u64)>>> + Send + 'static>>
+    {
+        let mut self2 = self.clone();
+        async move {
+            let response = ViewServiceClient::balance_matrix(
+                &mut self2,
+                tonic::Request::new(pb::BalanceMatrixRequest {}),
+            )
+            .await?
+            .into_inner();
+
+            response
+                .entries
+                .into_iter()
+                .map(|entry| {
+                    let account = entry
+                        .account
+                        .ok_or_else(|| anyhow::anyhow!("missing account in balance matrix"))?
+                        .try_into()?;
+                    let balance = entry
+                        .balance_view
+                        .ok_or_else(|| anyhow::anyhow!("missing balance in balance matrix"))?
+                        .try_into()?;
+                    Ok((account, balance, entry.note_count))
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn swap_by_commitment(
         &mut self,
         swap_commitment: penumbra_tct::StateCommitment,
@@ -592,6 +787,28 @@ where
         .boxed()
     }
 
+    fn transaction_by_nullifier(
+        &mut self,
+        nullifier: Nullifier,
+    ) -> Pin<Box<dyn Future<Output = Result<Option<(u64, TransactionId)>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::transaction_by_nullifier(
+                &mut self2,
+                tonic::Request::new(pb::TransactionByNullifierRequest {
+                    nullifier: Some(nullifier.into()),
+                }),
+            )
+            .await?
+            .into_inner();
+
+            rsp.id
+                .map(|id| anyhow::Ok((rsp.height, id.try_into()?)))
+                .transpose()
+        }
+        .boxed()
+    }
+
     /// Waits for a specific nullifier to be detected, returning immediately if it is already
     /// present, but waiting otherwise.
     fn await_nullifier(
@@ -637,6 +854,29 @@ where
         .boxed()
     }
 
+    fn witness_by_commitment(
+        &mut self,
+        commitments: Vec<penumbra_tct::StateCommitment>,
+    ) -> Pin<Box<dyn Future<Output = Result<WitnessData>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let witness_data = ViewServiceClient::witness_by_commitment(
+                &mut self2,
+                tonic::Request::new(pb::WitnessByCommitmentRequest {
+                    commitments: commitments.into_iter().map(Into::into).collect(),
+                }),
+            )
+            .await?
+            .into_inner()
+            .witness_data
+            .ok_or_else(|| anyhow::anyhow!("empty WitnessByCommitmentResponse message"))?
+            .try_into()?;
+
+            Ok(witness_data)
+        }
+        .boxed()
+    }
+
     fn assets(&mut self) -> Pin<Box<dyn Future<Output = Result<asset::Cache>> + Send + 'static>> {
         let mut self2 = self.clone();
         async move {
@@ -696,6 +936,37 @@ where
         .boxed()
     }
 
+    fn watch_positions(
+        &mut self,
+        position_ids: Vec<position::Id>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = Result<
+                        Pin<Box<dyn Stream<Item = Result<PositionUpdate>> + Send + 'static>>,
+                    >,
+                > + Send
+                + 'static,
+        >,
+    > {
+        let mut self2 = self.clone();
+        async move {
+            let stream = ViewServiceClient::watch_positions(
+                &mut self2,
+                tonic::Request::new(pb::WatchPositionsRequest {
+                    position_ids: position_ids.into_iter().map(Into::into).collect(),
+                }),
+            );
+            let stream = stream.await?.into_inner();
+
+            Ok(stream
+                .map_err(|e| anyhow::anyhow!("view service error: {}", e))
+                .and_then(|msg| async move { PositionUpdate::try_from(msg) })
+                .boxed())
+        }
+        .boxed()
+    }
+
     fn transaction_info_by_hash(
         &mut self,
         id: TransactionId,
@@ -736,6 +1007,7 @@ where
                     .view
                     .ok_or_else(|| anyhow::anyhow!("missing view"))?
                     .try_into()?,
+                block_time: rsp.block_time.map(time_from_proto).transpose()?,
             };
 
             Ok(tx_info)
@@ -793,6 +1065,7 @@ where
                             .view
                             .ok_or_else(|| anyhow::anyhow!("missing view"))?
                             .try_into()?,
+                        block_time: tx_rsp.block_time.map(time_from_proto).transpose()?,
                     };
 
                     Ok(tx_info)
@@ -814,6 +1087,8 @@ where
                 tonic::Request::new(pb::BroadcastTransactionRequest {
                     transaction: Some(transaction.into()),
                     await_detection,
+                    claim_swaps: false,
+                    idempotency_key: String::new(),
                 }),
             )
             .await?
@@ -824,6 +1099,79 @@ where
         .boxed()
     }
 
+    fn broadcast_swap_bundle(
+        &mut self,
+        transaction: Transaction,
+        await_detection: bool,
+    ) -> BroadcastStatusStream {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::broadcast_transaction(
+                &mut self2,
+                tonic::Request::new(pb::BroadcastTransactionRequest {
+                    transaction: Some(transaction.into()),
+                    await_detection,
+                    claim_swaps: true,
+                    idempotency_key: String::new(),
+                }),
+            )
+            .await?
+            .into_inner();
+
+            Ok(rsp)
+        }
+        .boxed()
+    }
+
+    fn broadcast_idempotent(
+        &mut self,
+        transaction: Transaction,
+        await_detection: bool,
+        claim_swaps: bool,
+        idempotency_key: String,
+    ) -> BroadcastStatusStream {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::broadcast_transaction(
+                &mut self2,
+                tonic::Request::new(pb::BroadcastTransactionRequest {
+                    transaction: Some(transaction.into()),
+                    await_detection,
+                    claim_swaps,
+                    idempotency_key,
+                }),
+            )
+            .await?
+            .into_inner();
+
+            Ok(rsp)
+        }
+        .boxed()
+    }
+
+    fn broadcast_queue(
+        &mut self,
+        id: Option<TransactionId>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<QueuedTransaction>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::broadcast_queue(
+                &mut self2,
+                tonic::Request::new(pb::BroadcastQueueRequest {
+                    id: id.map(Into::into),
+                }),
+            )
+            .await?
+            .into_inner();
+
+            rsp.transactions
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect()
+        }
+        .boxed()
+    }
+
     fn address_by_index(
         &mut self,
         address_index: AddressIndex,
@@ -844,6 +1192,66 @@ where
         .boxed()
     }
 
+    fn ephemeral_address(
+        &mut self,
+        address_index: AddressIndex,
+    ) -> Pin<Box<dyn Future<Output = Result<Address>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let address =
+                self2.ephemeral_address(tonic::Request::new(pb::EphemeralAddressRequest {
+                    address_index: Some(address_index.into()),
+                }));
+            let address = address
+                .await?
+                .into_inner()
+                .address
+                .ok_or_else(|| anyhow::anyhow!("empty EphemeralAddressResponse message"))?
+                .try_into()?;
+            Ok(address)
+        }
+        .boxed()
+    }
+
+    fn ephemeral_addresses(
+        &mut self,
+        account: Option<u32>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<EphemeralAddressRecord>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::ephemeral_addresses(
+                &mut self2,
+                tonic::Request::new(pb::EphemeralAddressesRequest {
+                    account_filter: account.map(|account| AddressIndex::new(account).into()),
+                }),
+            );
+            let pb_records: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_records
+                .into_iter()
+                .map(|record| {
+                    Ok(EphemeralAddressRecord {
+                        address: record
+                            .address
+                            .ok_or_else(|| anyhow::anyhow!("missing address"))?
+                            .try_into()?,
+                        address_index: record
+                            .address_index
+                            .ok_or_else(|| anyhow::anyhow!("missing address index"))?
+                            .try_into()?,
+                        height_generated: record.height_generated,
+                        transactions: record
+                            .transactions
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<Result<_>>()?,
+                    })
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn witness_and_build(
         &mut self,
         transaction_plan: TransactionPlan,
@@ -915,4 +1323,44 @@ where
         }
         .boxed()
     }
+
+    fn export_frontier(
+        &mut self,
+    ) -> Pin<Box<dyn Future<Output = Result<WalletFrontier>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let frontier = ViewServiceClient::export_frontier(
+                &mut self2,
+                tonic::Request::new(pb::ExportFrontierRequest {}),
+            )
+            .await?
+            .into_inner()
+            .frontier
+            .ok_or_else(|| anyhow::anyhow!("empty ExportFrontierResponse message"))?
+            .try_into()?;
+
+            Ok(frontier)
+        }
+        .boxed()
+    }
+
+    fn import_frontier(
+        &mut self,
+        frontier: WalletFrontier,
+    ) -> Pin<Box<dyn Future<Output = Result<u64>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let response = ViewServiceClient::import_frontier(
+                &mut self2,
+                tonic::Request::new(pb::ImportFrontierRequest {
+                    frontier: Some(frontier.into()),
+                }),
+            )
+            .await?
+            .into_inner();
+
+            Ok(response.height)
+        }
+        .boxed()
+    }
 }
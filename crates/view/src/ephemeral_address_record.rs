@@ -0,0 +1,14 @@
+use penumbra_keys::{keys::AddressIndex, Address};
+use penumbra_transaction::txhash::TransactionId;
+
+#[derive(Debug, Clone)]
+pub struct EphemeralAddressRecord {
+    // The ephemeral address.
+    pub address: Address,
+    // The index the address was derived from, including its randomizer.
+    pub address_index: AddressIndex,
+    // The sync height of the view server when the address was handed out.
+    pub height_generated: u64,
+    // The transactions that sent notes to the address or named it as their return address.
+    pub transactions: Vec<TransactionId>,
+}
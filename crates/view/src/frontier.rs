@@ -0,0 +1,135 @@
+use anyhow::Context;
+use penumbra_fee::GasPrices;
+use penumbra_keys::keys::WalletId;
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_shielded_pool::fmd;
+use penumbra_tct as tct;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{SpendableNoteRecord, SwapRecord};
+
+/// The version of the frontier format written by this crate.
+pub const FRONTIER_VERSION: u32 = 1;
+
+/// A snapshot of a wallet's synchronized state, sufficient to resume syncing from its height on
+/// another machine without rescanning the chain.
+///
+/// Only the state needed to keep spending is included: the state commitment tree and the unspent
+/// notes and unclaimed swaps it witnesses. Transaction history before the frontier isn't.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "pb::WalletFrontier", into = "pb::WalletFrontier")]
+pub struct WalletFrontier {
+    pub wallet_id: WalletId,
+    /// The height of the last block scanned into the frontier.
+    pub height: u64,
+    pub sct: tct::Tree,
+    pub notes: Vec<SpendableNoteRecord>,
+    pub swaps: Vec<SwapRecord>,
+    pub fmd_parameters: fmd::Parameters,
+    pub gas_prices: GasPrices,
+}
+
+impl WalletFrontier {
+    /// Checks that every note and swap in the frontier is witnessed by its state commitment tree,
+    /// so that they can be spent and claimed after importing it.
+    pub fn check_witnesses(&self) -> anyhow::Result<()> {
+        for note in &self.notes {
+            anyhow::ensure!(
+                self.sct.witness(note.note_commitment).is_some(),
+                "note {} is not witnessed by the frontier's state commitment tree",
+                note.note_commitment
+            );
+        }
+        for swap in &self.swaps {
+            anyhow::ensure!(
+                self.sct.witness(swap.swap_commitment).is_some(),
+                "swap {} is not witnessed by the frontier's state commitment tree",
+                swap.swap_commitment
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Computes the checksum of a frontier, over its encoding with the checksum left empty.
+fn checksum(frontier: &pb::WalletFrontier) -> Vec<u8> {
+    let unchecked = pb::WalletFrontier {
+        checksum: Vec::new(),
+        ..frontier.clone()
+    };
+    Sha256::digest(unchecked.encode_to_vec()).to_vec()
+}
+
+impl DomainType for WalletFrontier {
+    type Proto = pb::WalletFrontier;
+}
+
+impl From<WalletFrontier> for pb::WalletFrontier {
+    fn from(frontier: WalletFrontier) -> Self {
+        let mut proto = pb::WalletFrontier {
+            version: FRONTIER_VERSION,
+            wallet_id: Some(frontier.wallet_id.into()),
+            height: frontier.height,
+            anchor: Some(frontier.sct.root().into()),
+            state_commitment_tree: bincode::serialize(&frontier.sct)
+                .expect("able to serialize state commitment tree to bincode"),
+            notes: frontier.notes.into_iter().map(Into::into).collect(),
+            swaps: frontier.swaps.into_iter().map(Into::into).collect(),
+            fmd_parameters: Some(frontier.fmd_parameters.into()),
+            gas_prices: Some(frontier.gas_prices.into()),
+            checksum: Vec::new(),
+        };
+        proto.checksum = checksum(&proto);
+        proto
+    }
+}
+
+impl TryFrom<pb::WalletFrontier> for WalletFrontier {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::WalletFrontier) -> Result<Self, Self::Error> {
+        anyhow::ensure!(
+            proto.version == FRONTIER_VERSION,
+            "unsupported frontier version {}, expected {}",
+            proto.version,
+            FRONTIER_VERSION
+        );
+        anyhow::ensure!(
+            proto.checksum == checksum(&proto),
+            "frontier checksum mismatch: the frontier was damaged or modified after export"
+        );
+
+        let anchor: tct::Root = proto.anchor.context("missing anchor")?.try_into()?;
+        let sct: tct::Tree = bincode::deserialize(&proto.state_commitment_tree)
+            .context("invalid state commitment tree")?;
+        anyhow::ensure!(
+            sct.root() == anchor,
+            "frontier state commitment tree has root {}, but its anchor is {}",
+            sct.root(),
+            anchor
+        );
+
+        Ok(Self {
+            wallet_id: proto.wallet_id.context("missing wallet id")?.try_into()?,
+            height: proto.height,
+            sct,
+            notes: proto
+                .notes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            swaps: proto
+                .swaps
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<anyhow::Result<_>>()?,
+            fmd_parameters: proto
+                .fmd_parameters
+                .context("missing fmd parameters")?
+                .try_into()?,
+            gas_prices: proto.gas_prices.context("missing gas prices")?.try_into()?,
+        })
+    }
+}
@@ -8,29 +8,60 @@
 //! [`TransactionPlan`][penumbra_transaction::TransactionPlan].
 //!
 //! Finally, this crate provides a [`Storage`] type for managing persistent sqlite storage.
+//!
+//! The client, server and storage are behind the default `rpc` feature. With it disabled, the
+//! planner still builds, for `wasm32-unknown-unknown` among other targets, so that browser
+//! wallets can plan transactions with the notes they fetched through
+//! [`Planner::plan_with_spendable_and_votable_notes`].
 
 #![deny(clippy::unwrap_used)]
 #![recursion_limit = "512"]
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#[cfg(feature = "rpc")]
+mod broadcast_queue;
+#[cfg(feature = "rpc")]
 mod client;
+mod ephemeral_address_record;
+mod frontier;
 mod metrics;
+#[cfg(feature = "rpc")]
+mod node_pool;
 mod note_record;
 mod planner;
+mod position_update;
+mod queued_transaction;
+#[cfg(feature = "rpc")]
 mod service;
 mod status;
+#[cfg(feature = "rpc")]
 mod storage;
 mod swap_record;
+#[cfg(feature = "rpc")]
 mod sync;
 mod transaction_info;
+mod tx_inclusion;
+#[cfg(feature = "rpc")]
 mod worker;
 
+#[cfg(feature = "rpc")]
 pub use crate::client::ViewClient;
+pub use crate::ephemeral_address_record::EphemeralAddressRecord;
+pub use crate::frontier::{WalletFrontier, FRONTIER_VERSION};
 pub use crate::metrics::register_metrics;
+#[cfg(feature = "rpc")]
+pub use crate::node_pool::NodePool;
 pub use crate::note_record::SpendableNoteRecord;
-pub use crate::planner::Planner;
+pub use crate::planner::{Planner, PrivacyWarning, SweepTooSmall};
+pub use crate::position_update::PositionUpdate;
+pub use crate::queued_transaction::{QueueStatus, QueuedTransaction};
+#[cfg(feature = "rpc")]
 pub use crate::service::ViewServer;
 pub use crate::status::StatusStreamResponse;
-pub use crate::storage::Storage;
+#[cfg(feature = "rpc")]
+pub use crate::storage::{Migration, RescanStart, Storage};
 pub use crate::swap_record::SwapRecord;
+#[cfg(feature = "rpc")]
+pub use crate::sync::SyncFilter;
 pub use crate::transaction_info::TransactionInfo;
+pub use crate::tx_inclusion::{verify_tx_inclusion, VerifiedTxInclusion};
@@ -11,20 +11,45 @@
 //! This trick is probably good to avoid in general, because it could be
 //! confusing, but in this limited case, it seems like a clean option.
 
-#[allow(unused_imports)]
 pub use metrics::*;
 
 /// Registers all metrics used by this crate.
 pub fn register_metrics() {
-    /*
-    // Sample code for reference -- delete when adding the first metric
-    register_counter!(MEMPOOL_CHECKTX_TOTAL);
+    describe_gauge!(
+        VIEW_SYNC_HEIGHT,
+        Unit::Count,
+        "The height of the last block scanned by the view service"
+    );
+    describe_counter!(
+        VIEW_BLOCKS_SCANNED_TOTAL,
+        Unit::Count,
+        "The total number of blocks scanned by the view service"
+    );
+    describe_counter!(
+        VIEW_TRIAL_DECRYPTIONS_TOTAL,
+        Unit::Count,
+        "The total number of note and swap payloads trial-decrypted by the view service"
+    );
+    describe_counter!(
+        VIEW_NOTES_DETECTED_TOTAL,
+        Unit::Count,
+        "The total number of notes detected by the view service"
+    );
+    describe_counter!(
+        VIEW_SWAPS_DETECTED_TOTAL,
+        Unit::Count,
+        "The total number of swaps detected by the view service"
+    );
     describe_counter!(
-        MEMPOOL_CHECKTX_TOTAL,
-        "The total number of checktx requests made to the mempool"
+        VIEW_REORGS_TOTAL,
+        Unit::Count,
+        "The total number of times the view service rolled back blocks the chain forked away from"
     );
-     */
 }
 
-// Sample code for reference -- delete when adding the first metric
-// pub const MEMPOOL_CHECKTX_TOTAL: &str = "penumbra_pd_mempool_checktx_total";
+pub const VIEW_SYNC_HEIGHT: &str = "penumbra_view_sync_height";
+pub const VIEW_BLOCKS_SCANNED_TOTAL: &str = "penumbra_view_blocks_scanned_total";
+pub const VIEW_TRIAL_DECRYPTIONS_TOTAL: &str = "penumbra_view_trial_decryptions_total";
+pub const VIEW_NOTES_DETECTED_TOTAL: &str = "penumbra_view_notes_detected_total";
+pub const VIEW_SWAPS_DETECTED_TOTAL: &str = "penumbra_view_swaps_detected_total";
+pub const VIEW_REORGS_TOTAL: &str = "penumbra_view_reorgs_total";
@@ -0,0 +1,201 @@
+use std::{cmp::Reverse, collections::BTreeMap, sync::Arc, time::Duration};
+
+use anyhow::Context;
+use futures::future::join_all;
+use parking_lot::Mutex;
+use penumbra_proto::util::tendermint_proxy::v1::{
+    tendermint_proxy_service_client::TendermintProxyServiceClient, GetBlockByHeightRequest,
+    GetStatusRequest,
+};
+use tonic::transport::Channel;
+use url::Url;
+
+/// How long a node has to answer a health check.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Fullnodes serving the same chain, in order of preference, of which one is used at a time.
+///
+/// When the node in use fails, [`NodePool::select`] checks the health of every node, and switches
+/// to the most preferred of those that are caught up and agree with the others about the state of
+/// the chain.
+#[derive(Clone, Debug)]
+pub struct NodePool {
+    nodes: Vec<Url>,
+    current: Arc<Mutex<Url>>,
+}
+
+impl NodePool {
+    /// Creates a pool using `primary`, falling back to `fallbacks` in order.
+    pub fn new(primary: Url, fallbacks: Vec<Url>) -> Self {
+        let mut nodes = vec![primary.clone()];
+        nodes.extend(fallbacks.into_iter().filter(|url| *url != primary));
+        Self {
+            nodes,
+            current: Arc::new(Mutex::new(primary)),
+        }
+    }
+
+    /// The node currently in use.
+    pub fn current(&self) -> Url {
+        self.current.lock().clone()
+    }
+
+    /// Whether there is any node to fall back to.
+    pub fn has_fallbacks(&self) -> bool {
+        self.nodes.len() > 1
+    }
+
+    /// Checks the health of every node, and switches to the best one, returning it.
+    ///
+    /// A node is healthy if it responds within [`HEALTH_CHECK_TIMEOUT`] and isn't catching up.
+    /// If several are, the app hashes they report at a height all of them have reached are
+    /// compared, and only the nodes agreeing with the most others are kept, so that a node on
+    /// a fork or with corrupted state isn't used.
+    pub async fn select(&self) -> anyhow::Result<Url> {
+        let checks = join_all(self.nodes.iter().map(|url| async move {
+            let result = latest_height(url).await;
+            (url, result)
+        }))
+        .await;
+
+        let mut healthy = Vec::new();
+        let mut failures = Vec::new();
+        for (url, result) in checks {
+            match result {
+                Ok(height) => healthy.push((url.clone(), height)),
+                Err(e) => {
+                    tracing::warn!(%url, error = %format!("{e:#}"), "node failed health check");
+                    failures.push(format!("{url}: {e:#}"));
+                }
+            }
+        }
+        anyhow::ensure!(
+            !healthy.is_empty(),
+            "no healthy node to connect to: {}",
+            failures.join("; ")
+        );
+
+        let chosen = if healthy.len() == 1 {
+            healthy.swap_remove(0).0
+        } else {
+            let height = healthy
+                .iter()
+                .map(|(_, height)| *height)
+                .min()
+                .expect("there are healthy nodes");
+            let hashes = join_all(healthy.iter().map(|(url, _)| async move {
+                let result = app_hash(url, height).await;
+                (url, result)
+            }))
+            .await;
+            let mut candidates = Vec::new();
+            for (url, result) in hashes {
+                match result {
+                    Ok(hash) => candidates.push((url.clone(), hash)),
+                    Err(e) => {
+                        tracing::warn!(
+                            %url,
+                            height,
+                            error = %format!("{e:#}"),
+                            "node failed to report app hash"
+                        );
+                    }
+                }
+            }
+            choose(&candidates).context("no healthy node reported its app hash")?
+        };
+
+        let previous = std::mem::replace(&mut *self.current.lock(), chosen.clone());
+        if previous != chosen {
+            tracing::info!(%previous, node = %chosen, "switched node");
+        }
+        Ok(chosen)
+    }
+}
+
+/// Chooses the most preferred of the nodes whose app hash agrees with the most others, given
+/// in order of preference.
+fn choose(candidates: &[(Url, Vec<u8>)]) -> Option<Url> {
+    let mut agreeing = BTreeMap::<&[u8], usize>::new();
+    for (_, hash) in candidates {
+        *agreeing.entry(hash).or_default() += 1;
+    }
+    let (url, hash) = candidates
+        .iter()
+        .min_by_key(|(_, hash)| Reverse(agreeing[hash.as_slice()]))?;
+    for (other, other_hash) in candidates {
+        if other_hash != hash {
+            tracing::warn!(
+                node = %other,
+                agreeing = %url,
+                "node disagrees with others about the app hash"
+            );
+        }
+    }
+    Some(url.clone())
+}
+
+async fn tendermint_proxy_client(
+    url: &Url,
+) -> anyhow::Result<TendermintProxyServiceClient<Channel>> {
+    let channel = Channel::from_shared(url.to_string())?
+        .connect_timeout(HEALTH_CHECK_TIMEOUT)
+        .timeout(HEALTH_CHECK_TIMEOUT)
+        .connect()
+        .await?;
+    Ok(TendermintProxyServiceClient::new(channel))
+}
+
+/// The latest height of a node, if it isn't catching up.
+async fn latest_height(url: &Url) -> anyhow::Result<u64> {
+    let sync_info = tendermint_proxy_client(url)
+        .await?
+        .get_status(GetStatusRequest {})
+        .await?
+        .into_inner()
+        .sync_info
+        .context("missing sync info in status")?;
+    anyhow::ensure!(!sync_info.catching_up, "node is catching up");
+    Ok(sync_info.latest_block_height)
+}
+
+/// The app hash in the header of the block a node has at `height`.
+async fn app_hash(url: &Url, height: u64) -> anyhow::Result<Vec<u8>> {
+    let header = tendermint_proxy_client(url)
+        .await?
+        .get_block_by_height(GetBlockByHeightRequest {
+            height: height.try_into()?,
+        })
+        .await?
+        .into_inner()
+        .block
+        .and_then(|block| block.header)
+        .context("missing block header")?;
+    Ok(header.app_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(host: &str) -> Url {
+        Url::parse(&format!("https://{host}")).expect("valid url")
+    }
+
+    #[test]
+    fn the_majority_is_trusted_over_preference() {
+        let candidates = [
+            (url("primary"), vec![1]),
+            (url("a"), vec![2]),
+            (url("b"), vec![2]),
+        ];
+        assert_eq!(choose(&candidates), Some(url("a")));
+    }
+
+    #[test]
+    fn ties_go_to_the_preferred_node() {
+        let candidates = [(url("primary"), vec![1]), (url("a"), vec![2])];
+        assert_eq!(choose(&candidates), Some(url("primary")));
+        assert_eq!(choose(&[]), None);
+    }
+}
@@ -5,6 +5,7 @@ use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_shielded_pool::{note, Note, Rseed};
 use penumbra_tct::Position;
 
+#[cfg(feature = "rpc")]
 use r2d2_sqlite::rusqlite::Row;
 use serde::{Deserialize, Serialize};
 
@@ -79,6 +80,7 @@ impl TryFrom<pb::SpendableNoteRecord> for SpendableNoteRecord {
     }
 }
 
+#[cfg(feature = "rpc")]
 impl TryFrom<&Row<'_>> for SpendableNoteRecord {
     type Error = anyhow::Error;
 
@@ -4,23 +4,30 @@ use std::{
     mem,
 };
 
+#[cfg(feature = "rpc")]
+use anyhow::Context;
 use anyhow::Result;
 use rand::{CryptoRng, RngCore};
 use tracing::instrument;
 
+use decaf377::Fr;
+#[cfg(feature = "rpc")]
+use penumbra_app::params::AppParameters;
 use penumbra_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_community_pool::CommunityPoolDeposit;
 use penumbra_dex::{
     lp::action::{PositionClose, PositionOpen},
     lp::plan::PositionWithdrawPlan,
     lp::position::{self, Position},
-    lp::Reserves,
-    swap::SwapPlaintext,
-    swap::SwapPlan,
+    lp::{DisplayPrice, Reserves},
+    swap::{RoutingPreference, SwapPlaintext, SwapPlan},
     swap_claim::SwapClaimPlan,
-    TradingPair,
+    DirectedTradingPair, TradingPair,
+};
+use penumbra_fee::{
+    grant::{FeeGrant, FeeGrantId, FeeGrantReclaimPlan, FeeGrantUsePlan},
+    Fee, FeeTier, GasPrices,
 };
-use penumbra_fee::{Fee, FeeTier, GasPrices};
 use penumbra_governance::{
     proposal_state, DelegatorVotePlan, Proposal, ProposalDepositClaim, ProposalSubmit,
     ProposalWithdraw, ValidatorVote, Vote,
@@ -38,7 +45,42 @@ use penumbra_transaction::{
     plan::{ActionPlan, MemoPlan, TransactionPlan},
 };
 
-use crate::{SpendableNoteRecord, ViewClient};
+use crate::SpendableNoteRecord;
+#[cfg(feature = "rpc")]
+use crate::ViewClient;
+
+mod privacy;
+
+pub use privacy::PrivacyWarning;
+
+/// How many blocks of headroom to pay for when the base fee is dynamic, so that a transaction is
+/// still valid if the base fee rises while it waits to be included.
+#[cfg(feature = "rpc")]
+const BASE_FEE_HEADROOM_BLOCKS: u32 = 3;
+
+/// How many times the current cost of a swap claim to pre-pay, so that the claim is still covered
+/// if gas prices rise before the swap's batch executes.
+const SWAP_CLAIM_FEE_MARGIN: u32 = 2;
+
+/// The error [`Planner::plan_sweep`] fails with when the notes hold too little of the staking
+/// token to pay for moving themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SweepTooSmall {
+    pub staking_amount: Amount,
+    pub fee: Amount,
+}
+
+impl fmt::Display for SweepTooSmall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the notes hold {} of the staking token, which doesn't cover the fee of {}",
+            self.staking_amount, self.fee
+        )
+    }
+}
+
+impl std::error::Error for SweepTooSmall {}
 
 /// A planner for a [`TransactionPlan`] that can fill in the required spends and change outputs upon
 /// finalization to make a transaction balance.
@@ -50,9 +92,19 @@ pub struct Planner<R: RngCore + CryptoRng> {
     ibc_actions: Vec<IbcRelay>,
     gas_prices: GasPrices,
     fee_tier: FeeTier,
+    fee_grant: Option<FeeGrantIntent>,
+    allow_cross_account: bool,
     // IMPORTANT: if you add more fields here, make sure to clear them when the planner is finished
 }
 
+/// A fee grant to pay the transaction's fee from, instead of spending notes.
+#[derive(Debug, Clone)]
+struct FeeGrantIntent {
+    grant_id: FeeGrantId,
+    remaining: Amount,
+    randomizer: Fr,
+}
+
 #[derive(Debug, Clone)]
 struct VoteIntent {
     start_block_height: u64,
@@ -81,6 +133,8 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             ibc_actions: Vec::new(),
             gas_prices: GasPrices::zero(),
             fee_tier: FeeTier::default(),
+            fee_grant: None,
+            allow_cross_account: false,
         }
     }
 
@@ -98,6 +152,38 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Pay the transaction's fee from a fee grant made to this wallet.
+    ///
+    /// The `randomizer` is the one deriving the grant's grantee key from the spend
+    /// verification key, and `remaining` is the allowance left on the grant. Only grants
+    /// paying fees in the staking token are supported.
+    #[instrument(skip(self))]
+    pub fn use_fee_grant(
+        &mut self,
+        grant_id: FeeGrantId,
+        remaining: Amount,
+        randomizer: Fr,
+    ) -> &mut Self {
+        self.fee_grant = Some(FeeGrantIntent {
+            grant_id,
+            remaining,
+            randomizer,
+        });
+        self
+    }
+
+    /// Allow spending notes from accounts other than the source account, if the source account
+    /// alone can't fund the transaction.
+    ///
+    /// Notes from the source account are always selected first, and change is still returned to
+    /// the source account. This is disabled by default, so that funds are never silently mixed
+    /// across accounts, which would link them on-chain.
+    #[instrument(skip(self))]
+    pub fn allow_cross_account(&mut self, allow: bool) -> &mut Self {
+        self.allow_cross_account = allow;
+        self
+    }
+
     /// Get the current transaction balance of the planner.
     pub fn balance(&self) -> &Balance {
         &self.balance
@@ -116,6 +202,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
                     address_index: Some(source.into()),
                     amount_to_spend: Some(amount.into()),
                     include_spent: false,
+                    allow_other_accounts: self.allow_cross_account,
                 })
                 .collect(),
             self.vote_intents
@@ -151,6 +238,24 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         Ok(self)
     }
 
+    /// Also encrypt the memo to a designated viewer, like an auditor, who isn't otherwise
+    /// party to the transaction.
+    ///
+    /// Every output wraps the memo key to its recipient, so this adds a zero-value output to
+    /// `viewer`, which lets its holder decrypt the memo, and the zero-value note, but none of
+    /// the other actions in the transaction. A memo must be set as well, or the viewer only sees
+    /// a blank one.
+    #[instrument(skip(self))]
+    pub fn memo_viewer(&mut self, viewer: Address) -> &mut Self {
+        self.output(
+            Value {
+                amount: Amount::zero(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            viewer,
+        )
+    }
+
     /// Add a fee to the transaction plan.
     ///
     /// This function should be called once.
@@ -207,6 +312,22 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Open a liquidity position trading at a price given in display units, holding
+    /// `reserves.r1` of the price's base asset and `reserves.r2` of its quote asset.
+    ///
+    /// The price is converted exactly into the position's trading function, so errors if it
+    /// can't be expressed in base units of the assets.
+    #[instrument(skip(self))]
+    pub fn position_open_at_price(
+        &mut self,
+        price: &DisplayPrice,
+        fee: u32,
+        reserves: Reserves,
+    ) -> Result<&mut Self> {
+        let position = price.into_position(&mut self.rng, fee, reserves)?;
+        Ok(self.position_open(position))
+    }
+
     /// Close a liquidity position in the order book.
     #[instrument(skip(self))]
     pub fn position_close(&mut self, position_id: position::Id) -> &mut Self {
@@ -246,6 +367,121 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Estimate the fee to pre-pay for claiming a swap, at the planner's gas prices.
+    ///
+    /// The estimate covers a claim returning change, with a margin for gas prices rising before
+    /// the claim. Whatever the claim doesn't use is returned to the claim address as change.
+    pub fn swap_claim_fee(&self) -> Fee {
+        let gas = gas::swap_claim_gas_cost() + gas::output_gas_cost();
+        Fee::from_staking_token_amount(
+            Amount::from(SWAP_CLAIM_FEE_MARGIN) * self.gas_prices.fee(&gas),
+        )
+    }
+
+    /// Plan a transaction claiming a swap using nothing but its pre-paid fee.
+    ///
+    /// This is the dependent half of a swap bundle: since it spends nothing, it needs no
+    /// authorization, and can be built as soon as the swap's batch output data is known.
+    /// What's left of the pre-paid fee is returned to `change_address`, if it covers the cost of
+    /// the output, and is paid as part of the fee otherwise. Fails if the pre-paid fee no longer
+    /// covers the claim at the current gas prices.
+    #[instrument(skip(self, fmd_params))]
+    pub fn prepaid_swap_claim(
+        &mut self,
+        plan: SwapClaimPlan,
+        chain_id: String,
+        fmd_params: &fmd::Parameters,
+        change_address: Address,
+    ) -> Result<TransactionPlan> {
+        let fee = plan.swap_plaintext.claim_fee.clone();
+
+        if let Ok(transaction_plan) = self.batch_swap_claims(
+            vec![plan.clone()],
+            chain_id.clone(),
+            fmd_params,
+            change_address,
+        ) {
+            return Ok(transaction_plan);
+        }
+
+        let mut transaction_plan = TransactionPlan {
+            actions: vec![plan.into()],
+            ..Default::default()
+        };
+        transaction_plan.transaction_parameters.chain_id = chain_id;
+        transaction_plan.transaction_parameters.fee = fee.clone();
+
+        let minimum_fee = self.gas_prices.fee(&transaction_plan.gas_cost());
+        if fee.asset_id() != *STAKING_TOKEN_ASSET_ID || fee.amount() < minimum_fee {
+            anyhow::bail!(
+                "pre-paid claim fee {:?} does not cover the minimum fee of {} at current gas prices",
+                fee,
+                minimum_fee
+            );
+        }
+
+        Ok(transaction_plan)
+    }
+
+    /// Plan a transaction claiming several swaps at once, paying with their pre-paid fees.
+    ///
+    /// Each pre-paid fee is sized to claim its swap alone, so claiming several together leaves
+    /// some of the fees over once the transaction's own fee is paid. What's left is returned to
+    /// `change_address` in one consolidation output per asset. Like
+    /// [`Planner::prepaid_swap_claim`], nothing is spent, so the transaction needs no
+    /// authorization. Fails if the pre-paid fees together don't cover the fee at the current gas
+    /// prices.
+    #[instrument(skip(self, plans, fmd_params))]
+    pub fn batch_swap_claims(
+        &mut self,
+        plans: Vec<SwapClaimPlan>,
+        chain_id: String,
+        fmd_params: &fmd::Parameters,
+        change_address: Address,
+    ) -> Result<TransactionPlan> {
+        if plans.is_empty() {
+            anyhow::bail!("there are no swaps to claim");
+        }
+        for plan in plans {
+            self.swap_claim(plan);
+        }
+
+        // Every asset the claim fees were paid in may need a consolidation output.
+        let outputs = Amount::from(self.balance.provided().count() as u64);
+        let fee = self.gas_prices.fee(&self.plan.gas_cost())
+            + outputs * self.gas_prices.fee(&gas::output_gas_cost());
+        let prepaid = self
+            .balance
+            .provided()
+            .filter(|value| value.asset_id == *STAKING_TOKEN_ASSET_ID)
+            .fold(Amount::zero(), |total, value| total + value.amount);
+        if prepaid < fee {
+            self.finish();
+            anyhow::bail!(
+                "pre-paid claim fees of {} do not cover the minimum fee of {} at current gas prices",
+                prepaid,
+                fee
+            );
+        }
+
+        let fee = Fee::from_staking_token_amount(fee);
+        self.balance -= fee.0;
+        self.plan.transaction_parameters.chain_id = chain_id;
+        self.plan.transaction_parameters.fee = fee;
+
+        for value in self.balance.provided().collect::<Vec<_>>() {
+            self.output(value, change_address.clone());
+        }
+        if self.plan.num_outputs() > 0 {
+            self.memo(MemoPlaintext::blank_memo(change_address))
+                .expect("empty string is a valid memo");
+        }
+        self.plan
+            .populate_detection_data(&mut self.rng, fmd_params.precision_bits.into());
+
+        Ok(self.finish())
+    }
+
     /// Perform a swap based on input notes in the transaction.
     #[instrument(skip(self))]
     pub fn swap(
@@ -254,6 +490,26 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         into_asset: asset::Id,
         swap_claim_fee: Fee,
         claim_address: Address,
+    ) -> Result<&mut Self> {
+        self.swap_with_routing_preference(
+            input_value,
+            into_asset,
+            swap_claim_fee,
+            claim_address,
+            None,
+        )
+    }
+
+    /// Perform a swap like [`swap`](Planner::swap), asking for its batch to be routed with
+    /// `routing_preference` rather than the chain's default, if given.
+    #[instrument(skip(self))]
+    pub fn swap_with_routing_preference(
+        &mut self,
+        input_value: Value,
+        into_asset: asset::Id,
+        swap_claim_fee: Fee,
+        claim_address: Address,
+        routing_preference: Option<RoutingPreference>,
     ) -> Result<&mut Self> {
         // Determine the canonical order for the assets being swapped.
         // This will determine whether the input amount is assigned to delta_1 or delta_2.
@@ -284,12 +540,70 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             claim_address,
         );
 
-        let swap = SwapPlan::new(&mut self.rng, swap_plaintext).into();
-        self.action(swap);
+        let mut swap = SwapPlan::new(&mut self.rng, swap_plaintext);
+        swap.routing_preference = routing_preference;
+        self.action(swap.into());
 
         Ok(self)
     }
 
+    /// Swap half of `input_value` into `counter_asset`, the first step of providing liquidity
+    /// with a single asset.
+    ///
+    /// The other half of the input is kept, to be paired with the swap's output in a position
+    /// opened with [`single_sided_position_open`](Planner::single_sided_position_open) once the
+    /// swap has been claimed.
+    #[instrument(skip(self))]
+    pub fn single_sided_swap(
+        &mut self,
+        input_value: Value,
+        counter_asset: asset::Id,
+        swap_claim_fee: Fee,
+        claim_address: Address,
+    ) -> Result<&mut Self> {
+        if input_value.asset_id == counter_asset {
+            anyhow::bail!("can't provide liquidity for an asset against itself");
+        }
+
+        let half = Value {
+            amount: (input_value.amount.value() / 2).into(),
+            asset_id: input_value.asset_id,
+        };
+        self.swap(half, counter_asset, swap_claim_fee, claim_address)
+    }
+
+    /// Open a position holding both halves of a single-sided liquidity provision: the `kept`
+    /// half of the original input, and the `received` output of swapping the other half.
+    ///
+    /// The position quotes the price the swap executed at, so that neither half is worth more
+    /// than the other when the position opens.
+    #[instrument(skip(self))]
+    pub fn single_sided_position_open(
+        &mut self,
+        kept: Value,
+        received: Value,
+        fee: u32,
+    ) -> Result<&mut Self> {
+        if kept.amount == Amount::zero() || received.amount == Amount::zero() {
+            anyhow::bail!("both reserves of a single-sided position must be nonzero");
+        }
+
+        // Quoting the swap's price of `kept` units of the input for `received` units of the
+        // counter-asset, as for a sell order of the kept half.
+        let position = Position::new(
+            &mut self.rng,
+            DirectedTradingPair::new(kept.asset_id, received.asset_id),
+            fee,
+            received.amount,
+            kept.amount,
+            Reserves {
+                r1: kept.amount,
+                r2: received.amount,
+            },
+        );
+        Ok(self.position_open(position))
+    }
+
     /// Add an output note from this transaction.
     ///
     /// Any unused output value will be redirected back to the originating address as change notes
@@ -380,6 +694,20 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Grant another key an allowance for paying its transaction fees.
+    #[instrument(skip(self))]
+    pub fn fee_grant(&mut self, grant: FeeGrant) -> &mut Self {
+        self.action(ActionPlan::FeeGrant(grant));
+        self
+    }
+
+    /// Revoke a fee grant made by this wallet, reclaiming what remains of its allowance.
+    #[instrument(skip(self))]
+    pub fn fee_grant_reclaim(&mut self, plan: FeeGrantReclaimPlan) -> &mut Self {
+        self.action(ActionPlan::FeeGrantReclaim(plan));
+        self
+    }
+
     /// Cast a validator vote in this transaction.
     #[instrument(skip(self))]
     pub fn validator_vote(&mut self, vote: ValidatorVote) -> &mut Self {
@@ -463,6 +791,7 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    #[cfg(feature = "rpc")]
     /// Add spends and change outputs as required to balance the transaction, using the view service
     /// provided to supply the notes and other information.
     ///
@@ -476,18 +805,32 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         let app_params = view.app_params().await?;
         let chain_id = app_params.chain_id.clone();
         let fmd_params = view.fmd_parameters().await?;
+        self.update_gas_prices(view, &app_params).await?;
 
         // Calculate the gas that needs to be paid for the transaction based on the configured gas prices.
         // Note that _paying the fee might incur an additional `Spend` action_, thus increasing the fee,
         // so we slightly overpay here and then capture the excess as change later during `plan_with_spendable_and_votable_notes`.
         // Add the fee to the planner's internal balance.
-        self.add_gas_fees();
+        //
+        // A fee grant pays the fee directly, so there are no notes to spend for it.
+        if self.fee_grant.is_none() {
+            self.add_gas_fees();
+        }
 
         let mut spendable_notes = Vec::new();
         let mut voting_notes = Vec::new();
         let (spendable_requests, voting_requests) = self.notes_requests(source);
         for request in spendable_requests {
-            let notes = view.notes(request).await?;
+            let notes = view.notes(request).await.with_context(|| {
+                if self.allow_cross_account {
+                    "can't select notes from the wallet's accounts to fund the transaction".to_string()
+                } else {
+                    format!(
+                        "can't select notes from account {} to fund the transaction, and spending from other accounts was not allowed",
+                        source.account
+                    )
+                }
+            })?;
             spendable_notes.extend(notes);
         }
         for request in voting_requests {
@@ -507,6 +850,94 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         )
     }
 
+    #[cfg(feature = "rpc")]
+    /// Plan a transaction spending exactly the notes provided, all held by the `source` account,
+    /// and sending their whole value, less the fee, to `destination`.
+    ///
+    /// Unlike [`Planner::plan`], no other notes are selected and no change is returned, so the
+    /// notes must include more of the staking token than the fee, or this fails with
+    /// [`SweepTooSmall`].
+    ///
+    /// Clears the contents of the planner, which can be re-used.
+    pub async fn plan_sweep<V: ViewClient>(
+        &mut self,
+        view: &mut V,
+        source: AddressIndex,
+        notes: Vec<SpendableNoteRecord>,
+        destination: Address,
+    ) -> anyhow::Result<TransactionPlan> {
+        let app_params = view.app_params().await?;
+        let fmd_params = view.fmd_parameters().await?;
+        self.update_gas_prices(view, &app_params).await?;
+
+        for record in notes {
+            self.spend(record.note, record.position);
+        }
+        let mut staking_amount = Amount::zero();
+        for value in self.balance.provided().collect::<Vec<_>>() {
+            if value.asset_id == *STAKING_TOKEN_ASSET_ID {
+                staking_amount = value.amount;
+            } else {
+                self.output(value, destination.clone());
+            }
+        }
+
+        // Pay exactly the fee that finalizing the plan computes once the staking token's output
+        // is added, so that there's no excess fee to return as change.
+        let fee = Fee::from_staking_token_amount(
+            self.gas_prices
+                .fee(&(self.plan.gas_cost() + gas::output_gas_cost()))
+                + self.gas_prices.fee(&gas::output_gas_cost()),
+        )
+        .apply_tier(self.fee_tier);
+        if staking_amount <= fee.amount() {
+            return Err(SweepTooSmall {
+                staking_amount,
+                fee: fee.amount(),
+            }
+            .into());
+        }
+        self.output(
+            Value {
+                amount: staking_amount - fee.amount(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+            destination,
+        );
+        self.balance -= fee.0;
+        self.plan.transaction_parameters.fee = fee;
+
+        let self_address = view.address_by_index(source).await?;
+        self.plan_with_spendable_and_votable_notes(
+            app_params.chain_id,
+            &fmd_params,
+            Vec::new(),
+            Vec::new(),
+            self_address,
+        )
+    }
+
+    #[cfg(feature = "rpc")]
+    /// Pay at least the chain's current base fee, which the view service keeps up to date.
+    ///
+    /// If the base fee is dynamic, it can rise while the transaction waits to be included, so
+    /// this pays for the most it could have risen to in the next few blocks.
+    async fn update_gas_prices<V: ViewClient>(
+        &mut self,
+        view: &mut V,
+        app_params: &AppParameters,
+    ) -> anyhow::Result<()> {
+        let gas_prices = view.gas_prices().await?;
+        let gas_prices = match &app_params.fee_params.dynamic_base_fee {
+            Some(dynamic_base_fee) => {
+                dynamic_base_fee.max_gas_prices_after(&gas_prices, BASE_FEE_HEADROOM_BLOCKS)
+            }
+            None => gas_prices,
+        };
+        self.gas_prices = self.gas_prices.at_least(&gas_prices);
+        Ok(())
+    }
+
     /// Add spends and change outputs as required to balance the transaction, using the spendable
     /// notes provided. It is the caller's responsibility to ensure that the notes are the result of
     /// collected responses to the requests generated by an immediately preceding call to
@@ -604,40 +1035,76 @@ impl<R: RngCore + CryptoRng> Planner<R> {
             }
         }
 
-        // Since we over-estimate the fees to be paid upfront by a fixed multiple to account
-        // for the cost of any additional `Spend` and `Output` actions necessary to pay the fee,
-        // we need to now calculate the transaction's fee again and capture the excess as change
-        // by subtracting the excess from the required value balance.
-        //
-        // Here, tx_real_fee is the minimum fee to be paid for the transaction, with no tip.
-        let mut tx_real_fee = self.gas_prices.fee(&self.plan.gas_cost());
-
-        // Since the excess fee paid will create an additional Output action, we need to
-        // account for the necessary fee for that action as well.
-        tx_real_fee += self.gas_prices.fee(&gas::output_gas_cost());
-
-        // For any remaining provided balance, add the necessary fee for collecting:
-        tx_real_fee += Amount::from(self.balance.provided().count() as u64)
-            * self.gas_prices.fee(&gas::output_gas_cost());
-
-        // Apply the fee tier to tx_real_fee so the block proposer can receive a tip:
-        tx_real_fee = Fee::from_staking_token_amount(tx_real_fee)
-            .apply_tier(self.fee_tier)
-            .amount();
-
-        assert!(
-            tx_real_fee <= self.plan.transaction_parameters.fee.amount(),
-            "tx real fee {:?} must be less than planned fee {:?}",
-            tx_real_fee,
-            self.plan.transaction_parameters.fee.amount(),
-        );
-        let excess_fee_spent = self.plan.transaction_parameters.fee.amount() - tx_real_fee;
-        self.balance += Value {
-            amount: excess_fee_spent,
-            asset_id: *STAKING_TOKEN_ASSET_ID,
-        };
+        if let Some(FeeGrantIntent {
+            grant_id,
+            remaining,
+            randomizer,
+        }) = self.fee_grant.take()
+        {
+            // The fee is paid by drawing on the grant, so we can compute it exactly: the
+            // only actions left to add are the grant use itself, and change outputs.
+            let mut tx_real_fee = self
+                .gas_prices
+                .fee(&(self.plan.gas_cost() + gas::fee_grant_use_gas_cost()));
+            tx_real_fee += Amount::from(self.balance.provided().count() as u64)
+                * self.gas_prices.fee(&gas::output_gas_cost());
+            let fee = Fee::from_staking_token_amount(tx_real_fee).apply_tier(self.fee_tier);
+
+            if fee.amount() > remaining {
+                anyhow::bail!(
+                    "fee grant {} has {} remaining, but the transaction fee is {}",
+                    grant_id,
+                    remaining,
+                    fee.amount()
+                );
+            }
+
+            // The value drawn from the grant exactly pays the fee, so the two cancel
+            // out and the action doesn't go through the planner's balance.
+            self.plan
+                .actions
+                .push(ActionPlan::FeeGrantUse(FeeGrantUsePlan {
+                    grant_id,
+                    amount: fee,
+                    randomizer,
+                }));
+            self.plan.transaction_parameters.fee = fee;
+        } else {
+            // Since we over-estimate the fees to be paid upfront by a fixed multiple to account
+            // for the cost of any additional `Spend` and `Output` actions necessary to pay the fee,
+            // we need to now calculate the transaction's fee again and capture the excess as change
+            // by subtracting the excess from the required value balance.
+            //
+            // Here, tx_real_fee is the minimum fee to be paid for the transaction, with no tip.
+            let mut tx_real_fee = self.gas_prices.fee(&self.plan.gas_cost());
+
+            // Since the excess fee paid will create an additional Output action, we need to
+            // account for the necessary fee for that action as well.
+            tx_real_fee += self.gas_prices.fee(&gas::output_gas_cost());
+
+            // For any remaining provided balance, add the necessary fee for collecting:
+            tx_real_fee += Amount::from(self.balance.provided().count() as u64)
+                * self.gas_prices.fee(&gas::output_gas_cost());
+
+            // Apply the fee tier to tx_real_fee so the block proposer can receive a tip:
+            tx_real_fee = Fee::from_staking_token_amount(tx_real_fee)
+                .apply_tier(self.fee_tier)
+                .amount();
+
+            assert!(
+                tx_real_fee <= self.plan.transaction_parameters.fee.amount(),
+                "tx real fee {:?} must be less than planned fee {:?}",
+                tx_real_fee,
+                self.plan.transaction_parameters.fee.amount(),
+            );
+            let excess_fee_spent = self.plan.transaction_parameters.fee.amount() - tx_real_fee;
+            self.balance += Value {
+                amount: excess_fee_spent,
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            };
 
-        self.plan.transaction_parameters.fee = Fee::from_staking_token_amount(tx_real_fee);
+            self.plan.transaction_parameters.fee = Fee::from_staking_token_amount(tx_real_fee);
+        }
 
         // For any remaining provided balance, make a single change note for each
         for value in self.balance.provided().collect::<Vec<_>>() {
@@ -673,13 +1140,102 @@ impl<R: RngCore + CryptoRng> Planner<R> {
 
         tracing::debug!(plan = ?self.plan, "finished balancing transaction");
 
-        // Clear the planner and pull out the plan to return
+        Ok(self.finish())
+    }
+
+    /// Clear the planner and pull out the plan to return.
+    fn finish(&mut self) -> TransactionPlan {
         self.balance = Balance::zero();
         self.vote_intents = BTreeMap::new();
         self.ibc_actions = Vec::new();
         self.gas_prices = GasPrices::zero();
-        let plan = mem::take(&mut self.plan);
+        self.fee_grant = None;
+        self.allow_cross_account = false;
+        mem::take(&mut self.plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_dex::BatchSwapOutputData;
+    use penumbra_keys::test_keys;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn prepaid_swap_claim_fee_balances_and_returns_change() -> Result<()> {
+        let gas_prices = GasPrices {
+            block_space_price: 1_000,
+            compact_block_space_price: 1_000,
+            verification_price: 1_000,
+            execution_price: 1_000,
+        };
+        let mut planner = Planner::new(OsRng);
+        planner.set_gas_prices(gas_prices);
 
-        Ok(plan)
+        let claim_fee = planner.swap_claim_fee();
+        let trading_pair =
+            TradingPair::new(*STAKING_TOKEN_ASSET_ID, asset::Id(decaf377::Fq::from(1u64)));
+        let swap_plaintext = SwapPlaintext::new(
+            &mut OsRng,
+            trading_pair,
+            100u64.into(),
+            0u64.into(),
+            claim_fee,
+            *test_keys::ADDRESS_0,
+        );
+        let claim = SwapClaimPlan {
+            swap_plaintext,
+            position: 0u64.into(),
+            output_data: BatchSwapOutputData {
+                delta_1: 100u64.into(),
+                delta_2: 0u64.into(),
+                lambda_1: 0u64.into(),
+                lambda_2: 50u64.into(),
+                unfilled_1: 0u64.into(),
+                unfilled_2: 0u64.into(),
+                height: 1,
+                trading_pair,
+                epoch_starting_height: 0,
+            },
+            epoch_duration: 100,
+            proof_blinding_r: decaf377::Fq::from(1u64),
+            proof_blinding_s: decaf377::Fq::from(2u64),
+        };
+
+        let plan = planner.prepaid_swap_claim(
+            claim,
+            "penumbra-testnet".to_string(),
+            &fmd::Parameters::default(),
+            *test_keys::ADDRESS_0,
+        )?;
+
+        // The claim pays at least the minimum fee for the whole transaction...
+        let fee = plan.transaction_parameters.fee;
+        assert!(fee.amount() >= gas_prices.fee(&plan.gas_cost()));
+        assert!(fee.amount() < claim_fee.amount());
+
+        // ... and returns what's left of the pre-paid fee as change, so that it balances.
+        let change = plan.output_plans().collect::<Vec<_>>();
+        assert_eq!(change.len(), 1);
+        assert_eq!(change[0].dest_address, *test_keys::ADDRESS_0);
+        assert_eq!(
+            change[0].value,
+            Value {
+                amount: claim_fee.amount() - fee.amount(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            }
+        );
+        let balance = plan
+            .actions
+            .iter()
+            .fold(Balance::default(), |balance, action| {
+                balance + action.balance()
+            })
+            - fee.0;
+        assert!(balance.is_zero());
+
+        Ok(())
     }
 }
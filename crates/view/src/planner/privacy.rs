@@ -0,0 +1,273 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use penumbra_keys::FullViewingKey;
+use penumbra_sct::CommitmentSource;
+use penumbra_shielded_pool::note;
+use penumbra_transaction::TransactionPlan;
+use serde::Serialize;
+
+use crate::SpendableNoteRecord;
+
+/// How many blocks a note should be left before it's spent, so that spending it can't be linked
+/// to the transaction that sent it by timing alone.
+const MIN_NOTE_AGE_BLOCKS: u64 = 10;
+
+/// A way in which a planned transaction would be easy to link to the transactions that funded it.
+///
+/// None of these make the transaction invalid, so they're warnings to show in a preview of the
+/// plan, each suggesting how to avoid the link.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PrivacyWarning {
+    /// A note is spent soon after it was received, so the transaction can be linked by timing to
+    /// the one that sent it.
+    RecentlyReceived {
+        note_commitment: note::StateCommitment,
+        height_created: u64,
+        blocks_ago: u64,
+    },
+    /// Several notes received in the same block are spent together, which links them, and
+    /// whoever sent them, to each other.
+    ReceivedTogether { height_created: u64, notes: usize },
+    /// Every note spent came from a single identifiable source, and value leaves the wallet, so
+    /// the transaction links that source to its recipient.
+    SingleSource {
+        source: CommitmentSource,
+        notes: usize,
+    },
+}
+
+impl PrivacyWarning {
+    /// Analyzes `plan`, which spends the notes `spent`, for ways it would link the wallet of
+    /// `fvk` to its counterparties, as of `current_height`.
+    pub fn analyze(
+        plan: &TransactionPlan,
+        spent: &[SpendableNoteRecord],
+        fvk: &FullViewingKey,
+        current_height: u64,
+    ) -> Vec<Self> {
+        let mut warnings = Vec::new();
+
+        for record in spent {
+            let blocks_ago = current_height.saturating_sub(record.height_created);
+            if blocks_ago < MIN_NOTE_AGE_BLOCKS {
+                warnings.push(PrivacyWarning::RecentlyReceived {
+                    note_commitment: record.note_commitment,
+                    height_created: record.height_created,
+                    blocks_ago,
+                });
+            }
+        }
+
+        let mut notes_by_height = BTreeMap::<u64, usize>::new();
+        for record in spent {
+            *notes_by_height.entry(record.height_created).or_default() += 1;
+        }
+        for (height_created, notes) in notes_by_height {
+            if notes > 1 {
+                warnings.push(PrivacyWarning::ReceivedTogether {
+                    height_created,
+                    notes,
+                });
+            }
+        }
+
+        let value_leaves_wallet = plan.ics20_withdrawals().next().is_some()
+            || plan
+                .output_plans()
+                .any(|output| fvk.address_index(&output.dest_address).is_none());
+        if let Some(first) = spent.first() {
+            let source = &first.source;
+            if value_leaves_wallet
+                && is_identifiable(source)
+                && spent.iter().all(|record| &record.source == source)
+            {
+                warnings.push(PrivacyWarning::SingleSource {
+                    source: source.clone(),
+                    notes: spent.len(),
+                });
+            }
+        }
+
+        warnings
+    }
+
+    /// How the link could be avoided.
+    pub fn suggestion(&self) -> String {
+        match self {
+            PrivacyWarning::RecentlyReceived { blocks_ago, .. } => format!(
+                "wait another {} blocks before spending it",
+                MIN_NOTE_AGE_BLOCKS - blocks_ago
+            ),
+            PrivacyWarning::ReceivedTogether { .. } => {
+                "split the payment into transactions spending one of them each, or wait until \
+                 other notes can be spent alongside them"
+                    .to_string()
+            }
+            PrivacyWarning::SingleSource { .. } => {
+                "send the funds to yourself first and wait before paying onward, or split the \
+                 payment so it draws on other notes too"
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// Whether a commitment source identifies who created the note, to whoever can see the note's
+/// source: the sender of an inbound transfer is public on its counterparty chain, and a
+/// transaction ID ties the note to everything else that transaction did.
+fn is_identifiable(source: &CommitmentSource) -> bool {
+    matches!(
+        source,
+        CommitmentSource::Ics20Transfer { .. } | CommitmentSource::Transaction { id: Some(_) }
+    )
+}
+
+impl Display for PrivacyWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PrivacyWarning::RecentlyReceived {
+                height_created,
+                blocks_ago,
+                ..
+            } => write!(
+                f,
+                "spends a note received only {blocks_ago} blocks ago, at height {height_created}, \
+                 which links this transaction by timing to the one that sent it"
+            ),
+            PrivacyWarning::ReceivedTogether {
+                height_created,
+                notes,
+            } => write!(
+                f,
+                "spends {notes} notes received together at height {height_created}, which links \
+                 them to each other and to whoever sent them"
+            ),
+            PrivacyWarning::SingleSource { source, notes } => {
+                let source = match source {
+                    CommitmentSource::Ics20Transfer {
+                        channel_id, sender, ..
+                    } => format!("the transfer from {sender} over {channel_id}"),
+                    _ => "a single transaction".to_string(),
+                };
+                write!(
+                    f,
+                    "spends only {notes} note(s) from {source} and sends value out of the wallet, \
+                     which links that source to the recipient"
+                )
+            }
+        }?;
+        write!(f, "; {}", self.suggestion())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_asset::{Value, STAKING_TOKEN_ASSET_ID};
+    use penumbra_keys::{keys::AddressIndex, test_keys, Address};
+    use penumbra_sct::Nullifier;
+    use penumbra_shielded_pool::{Note, OutputPlan};
+    use penumbra_transaction::plan::ActionPlan;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    fn record(height_created: u64, source: CommitmentSource) -> SpendableNoteRecord {
+        let note = Note::generate(
+            &mut OsRng,
+            &test_keys::ADDRESS_0,
+            Value {
+                amount: 100u64.into(),
+                asset_id: *STAKING_TOKEN_ASSET_ID,
+            },
+        );
+        SpendableNoteRecord {
+            note_commitment: note.commit(),
+            nullifier: Nullifier::derive(
+                test_keys::FULL_VIEWING_KEY.nullifier_key(),
+                0u64.into(),
+                &note.commit(),
+            ),
+            note,
+            address_index: AddressIndex::new(0),
+            height_created,
+            height_spent: None,
+            position: 0u64.into(),
+            source,
+            return_address: None,
+        }
+    }
+
+    fn paying(address: &Address) -> TransactionPlan {
+        TransactionPlan {
+            actions: vec![ActionPlan::Output(OutputPlan::new(
+                &mut OsRng,
+                Value {
+                    amount: 50u64.into(),
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                },
+                address.clone(),
+            ))],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn aged_notes_from_several_sources_are_private() {
+        let spent = [
+            record(100, CommitmentSource::transaction()),
+            record(200, CommitmentSource::Genesis),
+        ];
+        let plan = paying(&Address::dummy(&mut OsRng));
+        assert!(
+            PrivacyWarning::analyze(&plan, &spent, &test_keys::FULL_VIEWING_KEY, 1_000).is_empty()
+        );
+    }
+
+    #[test]
+    fn recent_and_batched_notes_are_warned_about() {
+        let spent = [
+            record(995, CommitmentSource::transaction()),
+            record(995, CommitmentSource::transaction()),
+        ];
+        let plan = paying(&test_keys::ADDRESS_0);
+        let warnings = PrivacyWarning::analyze(&plan, &spent, &test_keys::FULL_VIEWING_KEY, 1_000);
+        assert_eq!(warnings.len(), 3, "{warnings:?}");
+        assert!(matches!(
+            warnings[0],
+            PrivacyWarning::RecentlyReceived { blocks_ago: 5, .. }
+        ));
+        assert_eq!(
+            warnings[2],
+            PrivacyWarning::ReceivedTogether {
+                height_created: 995,
+                notes: 2
+            }
+        );
+    }
+
+    #[test]
+    fn forwarding_an_inbound_transfer_is_warned_about() {
+        let source = CommitmentSource::Ics20Transfer {
+            packet_seq: 1,
+            channel_id: "channel-0".to_string(),
+            sender: "osmo1sender".to_string(),
+        };
+        let spent = [record(100, source.clone())];
+
+        // Paying someone else links the sender to them...
+        let plan = paying(&Address::dummy(&mut OsRng));
+        let fvk = &test_keys::FULL_VIEWING_KEY;
+        assert_eq!(
+            PrivacyWarning::analyze(&plan, &spent, fvk, 1_000),
+            vec![PrivacyWarning::SingleSource { source, notes: 1 }]
+        );
+
+        // ...but moving the funds within the wallet doesn't.
+        let plan = paying(&test_keys::ADDRESS_0);
+        assert!(PrivacyWarning::analyze(&plan, &spent, fvk, 1_000).is_empty());
+    }
+}
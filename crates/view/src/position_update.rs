@@ -0,0 +1,43 @@
+use anyhow::Context;
+use penumbra_dex::lp::position::{self, Position};
+use penumbra_proto::{view::v1 as pb, DomainType};
+
+/// The on-chain state of a watched liquidity position, as streamed by the view service's
+/// `WatchPositions` method.
+#[derive(Clone, Debug)]
+pub struct PositionUpdate {
+    pub position_id: position::Id,
+    /// The position, if it has been opened.
+    pub position: Option<Position>,
+    /// The sync height at which the position was in this state.
+    pub height: u64,
+}
+
+impl DomainType for PositionUpdate {
+    type Proto = pb::WatchPositionsResponse;
+}
+
+impl TryFrom<pb::WatchPositionsResponse> for PositionUpdate {
+    type Error = anyhow::Error;
+
+    fn try_from(proto: pb::WatchPositionsResponse) -> Result<Self, Self::Error> {
+        Ok(PositionUpdate {
+            position_id: proto
+                .position_id
+                .context("missing position id")?
+                .try_into()?,
+            position: proto.position.map(TryInto::try_into).transpose()?,
+            height: proto.height,
+        })
+    }
+}
+
+impl From<PositionUpdate> for pb::WatchPositionsResponse {
+    fn from(msg: PositionUpdate) -> Self {
+        pb::WatchPositionsResponse {
+            position_id: Some(msg.position_id.into()),
+            position: msg.position.map(Into::into),
+            height: msg.height,
+        }
+    }
+}
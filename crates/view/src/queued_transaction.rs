@@ -0,0 +1,134 @@
+use penumbra_proto::{view::v1 as pb, DomainType};
+use penumbra_transaction::txhash::TransactionId;
+#[cfg(feature = "rpc")]
+use r2d2_sqlite::rusqlite::Row;
+use serde::{Deserialize, Serialize};
+
+use crate::transaction_info::{time_from_proto, time_to_proto};
+
+/// Where a transaction in the broadcast queue stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueStatus {
+    /// The transaction hasn't been detected on chain yet, and will be re-sent.
+    Pending,
+    /// The transaction was detected on chain at `detection_height`.
+    Confirmed { detection_height: u64 },
+    /// The chain passed the transaction's expiry height before it was detected.
+    Expired,
+    /// Another transaction spent one of the transaction's nullifiers.
+    Conflicted,
+}
+
+impl QueueStatus {
+    /// The name the status is stored under.
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            QueueStatus::Pending => "pending",
+            QueueStatus::Confirmed { .. } => "confirmed",
+            QueueStatus::Expired => "expired",
+            QueueStatus::Conflicted => "conflicted",
+        }
+    }
+}
+
+/// A transaction accepted for broadcast by a view service that keeps a broadcast queue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "pb::QueuedTransaction", into = "pb::QueuedTransaction")]
+pub struct QueuedTransaction {
+    pub id: TransactionId,
+    pub status: QueueStatus,
+    /// How many times the transaction has been sent to a fullnode.
+    pub attempts: u32,
+    /// The error from the last attempt to send the transaction, if it failed.
+    pub last_error: Option<String>,
+    /// The expiry height of the transaction, or 0 if it doesn't expire.
+    pub expiry_height: u64,
+    /// When the transaction will next be sent, if it's pending.
+    pub next_attempt: Option<tendermint::Time>,
+}
+
+impl DomainType for QueuedTransaction {
+    type Proto = pb::QueuedTransaction;
+}
+
+impl From<QueuedTransaction> for pb::QueuedTransaction {
+    fn from(msg: QueuedTransaction) -> Self {
+        use pb::queued_transaction::Status;
+
+        let (status, detection_height) = match msg.status {
+            QueueStatus::Pending => (Status::Pending, 0),
+            QueueStatus::Confirmed { detection_height } => (Status::Confirmed, detection_height),
+            QueueStatus::Expired => (Status::Expired, 0),
+            QueueStatus::Conflicted => (Status::Conflicted, 0),
+        };
+        pb::QueuedTransaction {
+            id: Some(msg.id.into()),
+            status: status as i32,
+            attempts: msg.attempts,
+            last_error: msg.last_error.unwrap_or_default(),
+            expiry_height: msg.expiry_height,
+            detection_height,
+            next_attempt: msg.next_attempt.map(time_to_proto),
+        }
+    }
+}
+
+impl TryFrom<pb::QueuedTransaction> for QueuedTransaction {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::QueuedTransaction) -> Result<Self, Self::Error> {
+        use pb::queued_transaction::Status;
+
+        let status = match Status::try_from(value.status)? {
+            Status::Pending => QueueStatus::Pending,
+            Status::Confirmed => QueueStatus::Confirmed {
+                detection_height: value.detection_height,
+            },
+            Status::Expired => QueueStatus::Expired,
+            Status::Conflicted => QueueStatus::Conflicted,
+            Status::Unspecified => anyhow::bail!("unspecified queued transaction status"),
+        };
+        Ok(Self {
+            id: value
+                .id
+                .ok_or_else(|| anyhow::anyhow!("missing id"))?
+                .try_into()?,
+            status,
+            attempts: value.attempts,
+            last_error: Some(value.last_error).filter(|e| !e.is_empty()),
+            expiry_height: value.expiry_height,
+            next_attempt: value.next_attempt.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+#[cfg(feature = "rpc")]
+impl TryFrom<&Row<'_>> for QueuedTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(row: &Row<'_>) -> Result<Self, Self::Error> {
+        let status = match row.get::<_, String>("status")?.as_str() {
+            "pending" => QueueStatus::Pending,
+            "confirmed" => QueueStatus::Confirmed {
+                detection_height: row.get("detection_height")?,
+            },
+            "expired" => QueueStatus::Expired,
+            "conflicted" => QueueStatus::Conflicted,
+            other => anyhow::bail!("unknown queued transaction status {other}"),
+        };
+        let next_attempt = match status {
+            QueueStatus::Pending => Some(tendermint::Time::from_unix_timestamp(
+                row.get("next_attempt")?,
+                0,
+            )?),
+            _ => None,
+        };
+        Ok(Self {
+            id: TransactionId(row.get::<_, [u8; 32]>("tx_hash")?),
+            status,
+            attempts: row.get("attempts")?,
+            last_error: row.get("last_error")?,
+            expiry_height: row.get("expiry_height")?,
+            next_attempt,
+        })
+    }
+}
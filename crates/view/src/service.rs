@@ -18,7 +18,8 @@ use tonic::{async_trait, transport::Channel, Request, Response, Status};
 use tracing::instrument;
 use url::Url;
 
-use penumbra_asset::{asset, asset::Metadata, Value};
+use penumbra_asset::{asset, asset::Metadata, Value, STAKING_TOKEN_ASSET_ID};
+use penumbra_compact_block::TrustedCheckpoint;
 use penumbra_dex::{
     lp::{
         position::{self, Position},
@@ -27,14 +28,25 @@ use penumbra_dex::{
     swap_claim::SwapClaimPlan,
     TradingPair,
 };
-use penumbra_fee::Fee;
+use penumbra_fee::{
+    grant::{self, FeeGrant, FeeGrantId},
+    Fee,
+};
 use penumbra_keys::{
     keys::WalletId,
     keys::{AddressIndex, FullViewingKey},
-    Address, AddressView,
+    Address, AddressView, PayloadKey,
 };
 use penumbra_num::Amount;
 use penumbra_proto::{
+    core::component::dex::v1::{
+        query_service_client::QueryServiceClient as DexQueryServiceClient,
+        LiquidityPositionByIdRequest,
+    },
+    core::component::fee::v1::{
+        query_service_client::QueryServiceClient as FeeQueryServiceClient,
+        FeeGrantsByGranteeRequest,
+    },
     util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
         GetStatusRequest,
@@ -51,13 +63,19 @@ use penumbra_proto::{
     },
     DomainType,
 };
+use penumbra_shielded_pool::Note;
 use penumbra_stake::rate::RateData;
 use penumbra_tct::{Proof, StateCommitment};
 use penumbra_transaction::{
     AuthorizationData, Transaction, TransactionPerspective, TransactionPlan, WitnessData,
 };
 
-use crate::{worker::Worker, Planner, Storage};
+use crate::{
+    broadcast_queue::{self, BroadcastQueue},
+    transaction_info::time_to_proto,
+    worker::Worker,
+    NodePool, Planner, QueueStatus, Storage, WalletFrontier,
+};
 
 /// A [`futures::Stream`] of broadcast transaction responses.
 ///
@@ -82,10 +100,12 @@ pub struct ViewServer {
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     // A copy of the SCT used by the worker task.
     state_commitment_tree: Arc<RwLock<penumbra_tct::Tree>>,
-    // The Url for the pd gRPC endpoint on remote node.
-    node: Url,
+    // The pd gRPC endpoints on remote nodes, of which one is used at a time.
+    nodes: NodePool,
     /// Used to watch for changes to the sync height.
     sync_height_rx: watch::Receiver<u64>,
+    /// Whether transactions accepted for broadcast are kept in the broadcast queue.
+    broadcast_queue: bool,
 }
 
 impl ViewServer {
@@ -94,8 +114,19 @@ impl ViewServer {
         storage_path: Option<impl AsRef<Utf8Path>>,
         fvk: &FullViewingKey,
         node: Url,
+        birthday_height: Option<u64>,
+        checkpoint: Option<TrustedCheckpoint>,
+        passphrase: Option<String>,
     ) -> anyhow::Result<Self> {
-        let storage = Storage::load_or_initialize(storage_path, fvk, node.clone()).await?;
+        let storage = Storage::load_or_initialize(
+            storage_path,
+            fvk,
+            node.clone(),
+            birthday_height,
+            checkpoint,
+            passphrase,
+        )
+        .await?;
 
         Self::new(storage, node).await
     }
@@ -108,8 +139,14 @@ impl ViewServer {
     /// by this method, rather than calling it multiple times.  That way, each clone
     /// will be backed by the same scanning task, rather than each spawning its own.
     pub async fn new(storage: Storage, node: Url) -> anyhow::Result<Self> {
+        Self::with_nodes(storage, NodePool::new(node, Vec::new())).await
+    }
+
+    /// Constructs a new [`ViewService`] like [`Self::new`], which starts out using the pool's
+    /// current node, and fails over to another in the pool when syncing from it fails.
+    pub async fn with_nodes(storage: Storage, nodes: NodePool) -> anyhow::Result<Self> {
         let (worker, sct, error_slot, sync_height_rx) =
-            Worker::new(storage.clone(), node.clone()).await?;
+            Worker::new(storage.clone(), nodes.clone()).await?;
 
         tokio::spawn(worker.run());
 
@@ -118,10 +155,23 @@ impl ViewServer {
             error_slot,
             sync_height_rx,
             state_commitment_tree: sct,
-            node,
+            nodes,
+            broadcast_queue: false,
         })
     }
 
+    /// Keeps the transactions accepted for broadcast in a queue in storage, and re-sends them with
+    /// backoff until they're detected on chain or expire, including those queued before a
+    /// restart.
+    ///
+    /// This spawns the task that re-sends them, so like the constructors, it should be called
+    /// once, with the resulting [`ViewServer`] cloned as needed.
+    pub fn with_broadcast_queue(mut self) -> Self {
+        tokio::spawn(BroadcastQueue::new(self.storage.clone(), self.nodes.clone()).run());
+        self.broadcast_queue = true;
+        self
+    }
+
     async fn check_worker(&self) -> Result<(), tonic::Status> {
         // If the shared error slot is set, then an error has occurred in the worker
         // that we should bubble up.
@@ -164,6 +214,8 @@ impl ViewServer {
         &self,
         transaction: Transaction,
         await_detection: bool,
+        claim_swaps: bool,
+        idempotency_key: String,
     ) -> BroadcastTransactionStream {
         use penumbra_app::ActionHandler;
 
@@ -180,6 +232,98 @@ impl ViewServer {
                         ))
                     })?;
 
+                // If this is a retry of an earlier broadcast, re-send the transaction broadcast
+                // then, since it may have landed even if the client never heard back.
+                let mut resend = false;
+                let transaction = if idempotency_key.is_empty() {
+                    transaction
+                } else {
+                    let recorded = self2.storage
+                        .record_idempotent_transaction(idempotency_key.clone(), transaction.clone())
+                        .await
+                        .map_err(|e| {
+                            tonic::Status::internal(format!(
+                                "error recording idempotent transaction: {:#}",
+                                e
+                            ))
+                        })?;
+                    if recorded.id() != transaction.id() {
+                        tracing::info!(id = %recorded.id(), "re-sending transaction broadcast with the same idempotency key");
+                        resend = true;
+                    }
+                    recorded
+                };
+
+                // A re-sent transaction that was already detected doesn't need broadcasting, and
+                // one that expired or conflicts with a detected transaction can't land anymore.
+                if resend {
+                    let sync_height = self2.storage
+                        .last_sync_height()
+                        .await
+                        .map_err(|e| tonic::Status::internal(format!("error querying storage: {:#}", e)))?
+                        .unwrap_or(0);
+                    let settled = broadcast_queue::settled(&self2.storage, &transaction, sync_height)
+                        .await
+                        .map_err(|e| tonic::Status::internal(format!("error querying storage: {:#}", e)))?;
+                    match settled {
+                        Some(QueueStatus::Confirmed { detection_height }) => {
+                            yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::BroadcastSuccess(BroadcastSuccess{id:Some(transaction.id().into())}))};
+                            yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::Confirmed(Confirmed{id:Some(transaction.id().into()), detection_height}))};
+                            return;
+                        }
+                        Some(status) => {
+                            // Release the key, so that retrying builds a new transaction.
+                            self2.storage
+                                .forget_idempotent_transaction(idempotency_key)
+                                .await
+                                .map_err(|e| tonic::Status::internal(format!("error updating storage: {:#}", e)))?;
+                            Err(tonic::Status::failed_precondition(format!(
+                                "transaction {} broadcast earlier with this idempotency key is {} and can't land; retry to build a new one",
+                                transaction.id(),
+                                status.as_str(),
+                            )))?
+                        }
+                        None => {}
+                    }
+                }
+
+                // If the swaps are bundled with their claims, record them before broadcasting,
+                // so that the worker can't detect them first.
+                if claim_swaps {
+                    let swap_commitments = transaction
+                        .actions()
+                        .filter_map(|action| match action {
+                            penumbra_transaction::Action::Swap(swap) => {
+                                Some(swap.body.payload.commitment)
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    self2.storage
+                        .record_bundled_swap_claims(swap_commitments)
+                        .await
+                        .map_err(|e| {
+                            tonic::Status::internal(format!(
+                                "error recording bundled swap claims: {:#}",
+                                e
+                            ))
+                        })?;
+                }
+
+                // With a broadcast queue, record the transaction before sending it, so that it's
+                // re-sent if sending it now fails, or if the view service restarts before it lands.
+                if self2.broadcast_queue {
+                    self2.storage
+                        .enqueue_broadcast(&transaction)
+                        .await
+                        .map_err(|e| {
+                            tonic::Status::internal(format!(
+                                "error queueing transaction: {:#}",
+                                e
+                            ))
+                        })?;
+                }
+
                 // 2. Broadcast the transaction to the network.
                 // Note that "synchronous" here means "wait for the tx to be accepted by
                 // the fullnode", not "wait for the tx to be included on chain.
@@ -197,17 +341,37 @@ impl ViewServer {
                         req_id: OsRng.gen(),
                     })
                     .await
+                    .map(tonic::Response::into_inner)
                     .map_err(|e| {
                         tonic::Status::unavailable(format!(
                             "error broadcasting tx: {:#?}",
                             e
                         ))
-                    })?
-                    .into_inner();
+                    });
                 tracing::info!(?node_rsp);
-                match node_rsp.code {
-                    0 => Ok(()),
-                    _ => Err(tonic::Status::new(
+                let error = match &node_rsp {
+                    Ok(node_rsp) if node_rsp.code == 0 => None,
+                    Ok(node_rsp) => Some(format!("code {}, log: {}", node_rsp.code, node_rsp.log)),
+                    Err(e) => Some(e.message().to_string()),
+                };
+                if self2.broadcast_queue {
+                    let next_attempt = broadcast_queue::next_attempt(broadcast_queue::unix_now(), 1);
+                    self2.storage
+                        .record_broadcast_attempt(transaction.id(), error.clone(), next_attempt)
+                        .await
+                        .map_err(|e| tonic::Status::internal(format!("error updating broadcast queue: {:#}", e)))?;
+                }
+                // The original broadcast may still be in the mempool, or already on chain, in
+                // which case the re-sent one is rejected, so wait to detect it instead, and only
+                // report the rejection if it isn't detected.
+                let resend_rejection = error.filter(|_| resend && await_detection);
+                match node_rsp {
+                    Ok(node_rsp) if node_rsp.code == 0 => Ok(()),
+                    _ if resend_rejection.is_some() => {
+                        tracing::info!("re-sent transaction was rejected, awaiting detection of the original");
+                        Ok(())
+                    }
+                    Ok(node_rsp) => Err(tonic::Status::new(
                         tonic::Code::Internal,
                         format!(
                             "Error submitting transaction: code {}, log: {}",
@@ -215,7 +379,18 @@ impl ViewServer {
                             node_rsp.log,
                         ),
                     )),
-                }?;
+                    Err(e) => Err(e),
+                }
+                .map_err(|e| {
+                    if self2.broadcast_queue {
+                        tonic::Status::new(
+                            e.code(),
+                            format!("{}; the transaction is queued and will be re-sent", e.message()),
+                        )
+                    } else {
+                        e
+                    }
+                })?;
 
                 // The transaction was submitted so we provide a status update
                 yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::BroadcastSuccess(BroadcastSuccess{id:Some(transaction.id().into())}))};
@@ -247,10 +422,13 @@ impl ViewServer {
                     let detection = self2.storage.nullifier_status(nullifier, true);
                     tokio::time::timeout(std::time::Duration::from_secs(20), detection)
                         .await
-                        .map_err(|_| {
-                            tonic::Status::unavailable(
+                        .map_err(|_| match &resend_rejection {
+                            Some(rejection) => tonic::Status::failed_precondition(format!(
+                                "re-sent transaction was rejected, and wasn't detected on chain: {rejection}"
+                            )),
+                            None => tonic::Status::unavailable(
                                 "timeout waiting to detect nullifier of submitted transaction"
-                            )
+                            ),
                         })?
                         .map_err(|_| {
                             tonic::Status::unavailable(
@@ -263,10 +441,17 @@ impl ViewServer {
                     .transaction_by_hash(&transaction.id().0)
                     .await
                     .map_err(|e| tonic::Status::internal(format!("error querying storage: {:#}", e)))?
-                    .map(|(height, _tx)| height)
+                    .map(|(height, _tx)| height);
+                let detection_height = match (detection_height, resend_rejection) {
+                    (Some(height), _) => height,
+                    // Neither the re-sent transaction nor the original landed.
+                    (None, Some(rejection)) => Err(tonic::Status::failed_precondition(format!(
+                        "re-sent transaction was rejected, and wasn't detected on chain: {rejection}"
+                    )))?,
                     // If we didn't find it for some reason, return 0 for unknown.
                     // TODO: how does this change if we detach extended transaction fetch from scanning?
-                    .unwrap_or(0);
+                    (None, None) => 0,
+                };
                 yield BroadcastTransactionResponse{ status: Some(BroadcastStatus::Confirmed(Confirmed{id:Some(transaction.id().into()), detection_height}))};
             }.boxed()
     }
@@ -274,11 +459,54 @@ impl ViewServer {
     async fn tendermint_proxy_client(
         &self,
     ) -> anyhow::Result<TendermintProxyServiceClient<Channel>> {
-        let client = TendermintProxyServiceClient::connect(self.node.to_string()).await?;
+        let client =
+            TendermintProxyServiceClient::connect(self.nodes.current().to_string()).await?;
 
         Ok(client)
     }
 
+    /// Finds the fee grant with the largest remaining allowance made to the given account,
+    /// among those paying fees in the staking token.
+    ///
+    /// Returns the grant's id and allowance, along with the randomizer for the grantee key.
+    #[instrument(skip(self))]
+    async fn usable_fee_grant(
+        &self,
+        account: u32,
+    ) -> anyhow::Result<Option<(FeeGrantId, Amount, decaf377::Fr)>> {
+        let fvk = self.storage.full_viewing_key().await?;
+        let randomizer = grant::grantee_randomizer(&fvk, account);
+        let grantee = fvk.spend_verification_key().randomize(&randomizer);
+
+        let mut client = FeeQueryServiceClient::connect(self.nodes.current().to_string()).await?;
+        let mut grants = client
+            .fee_grants_by_grantee(FeeGrantsByGranteeRequest {
+                grantee: Some(grantee.into()),
+            })
+            .await?
+            .into_inner();
+
+        let mut best: Option<(FeeGrantId, Amount)> = None;
+        while let Some(rsp) = grants.message().await? {
+            let id: FeeGrantId = rsp
+                .grant_id
+                .ok_or_else(|| anyhow!("missing fee grant id"))?
+                .try_into()?;
+            let grant: FeeGrant = rsp
+                .grant
+                .ok_or_else(|| anyhow!("missing fee grant"))?
+                .try_into()?;
+            if grant.allowance.asset_id() != *STAKING_TOKEN_ASSET_ID {
+                continue;
+            }
+            if best.map_or(true, |(_, remaining)| grant.allowance.amount() > remaining) {
+                best = Some((id, grant.allowance.amount()));
+            }
+        }
+
+        Ok(best.map(|(id, remaining)| (id, remaining, randomizer)))
+    }
+
     /// Return the latest block height known by the fullnode or its peers, as
     /// well as whether the fullnode is caught up with that height.
     #[instrument(skip(self))]
@@ -315,6 +543,7 @@ impl ViewServer {
     #[instrument(skip(self))]
     pub async fn status(&self) -> anyhow::Result<StatusResponse> {
         let full_sync_height = self.storage.last_sync_height().await?.unwrap_or(0);
+        let birthday_height = self.storage.birthday_height().await?.unwrap_or(0);
 
         let (latest_known_block_height, node_catching_up) =
             self.latest_known_block_height().await?;
@@ -338,6 +567,7 @@ impl ViewServer {
             full_sync_height,
             catching_up,
             partial_sync_height: full_sync_height, // Set these as the same for backwards compatibility following adding the partial_sync_height
+            birthday_height,
         })
     }
 }
@@ -362,9 +592,18 @@ impl ViewService for ViewServer {
     type OwnedPositionIdsStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::OwnedPositionIdsResponse, tonic::Status>> + Send>,
     >;
+    type WatchPositionsStream = Pin<
+        Box<dyn futures::Stream<Item = Result<pb::WatchPositionsResponse, tonic::Status>> + Send>,
+    >;
     type UnclaimedSwapsStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::UnclaimedSwapsResponse, tonic::Status>> + Send>,
     >;
+    type EphemeralAddressesStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<pb::EphemeralAddressesResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
     type BroadcastTransactionStream = BroadcastTransactionStream;
     type WitnessAndBuildStream = Pin<
         Box<dyn futures::Stream<Item = Result<pb::WitnessAndBuildResponse, tonic::Status>> + Send>,
@@ -382,6 +621,8 @@ impl ViewService for ViewServer {
         let pb::BroadcastTransactionRequest {
             transaction,
             await_detection,
+            claim_swaps,
+            idempotency_key,
         } = request.into_inner();
 
         let transaction: Transaction = transaction
@@ -390,11 +631,38 @@ impl ViewService for ViewServer {
             .map_err(|e: anyhow::Error| e.context("could not decode transaction"))
             .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
 
-        let stream = self.broadcast_transaction(transaction, await_detection);
+        let stream =
+            self.broadcast_transaction(transaction, await_detection, claim_swaps, idempotency_key);
 
         Ok(tonic::Response::new(stream))
     }
 
+    async fn broadcast_queue(
+        &self,
+        request: tonic::Request<pb::BroadcastQueueRequest>,
+    ) -> Result<tonic::Response<pb::BroadcastQueueResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let id = request
+            .into_inner()
+            .id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::invalid_argument(format!("could not decode transaction id: {e:#}"))
+            })?;
+
+        let transactions = self
+            .storage
+            .broadcast_queue(id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error: {e}")))?;
+
+        Ok(tonic::Response::new(pb::BroadcastQueueResponse {
+            transactions: transactions.into_iter().map(Into::into).collect(),
+        }))
+    }
+
     async fn transaction_planner(
         &self,
         request: tonic::Request<pb::TransactionPlannerRequest>,
@@ -455,13 +723,13 @@ impl ViewService for ViewServer {
                     tonic::Status::invalid_argument(format!("Could not parse target asset: {e:#}"))
                 })?;
 
-            let fee: Fee = swap
-                .fee
-                .ok_or_else(|| tonic::Status::invalid_argument("Missing fee"))?
-                .try_into()
-                .map_err(|e| {
+            // Without a fee, pre-pay the estimated cost of the claim.
+            let fee: Fee = match swap.fee {
+                Some(fee) => fee.try_into().map_err(|e| {
                     tonic::Status::invalid_argument(format!("Could not parse fee: {e:#}"))
-                })?;
+                })?,
+                None => planner.swap_claim_fee(),
+            };
 
             let claim_address: Address = swap
                 .claim_address
@@ -628,6 +896,22 @@ impl ViewService for ViewServer {
             // ... or just use the default account if not.
             .unwrap_or(0u32);
 
+        if prq.use_fee_grant {
+            match self.usable_fee_grant(source).await.map_err(|e| {
+                tonic::Status::unavailable(format!("could not fetch fee grants: {e:#}"))
+            })? {
+                Some((grant_id, remaining, randomizer)) => {
+                    planner.use_fee_grant(grant_id, remaining, randomizer);
+                }
+                None => {
+                    tracing::debug!(
+                        ?source,
+                        "no usable fee grant, paying fee from source account"
+                    );
+                }
+            }
+        }
+
         let plan = planner
             .plan(&mut client_of_self, source.into())
             .await
@@ -703,11 +987,65 @@ impl ViewService for ViewServer {
                 tonic::Status::invalid_argument(format!("Could not parse address index: {e:#}"))
             })?;
 
+        let (address, _dtk) = fvk.ephemeral_address(OsRng, address_index);
+
+        // Remember the address, so that its uses can be audited later.
+        self.storage
+            .record_ephemeral_address(address_index, address)
+            .await
+            .map_err(|e| {
+                tonic::Status::internal(format!("error recording ephemeral address: {e:#}"))
+            })?;
+
         Ok(tonic::Response::new(pb::EphemeralAddressResponse {
-            address: Some(fvk.ephemeral_address(OsRng, address_index).0.into()),
+            address: Some(address.into()),
         }))
     }
 
+    async fn ephemeral_addresses(
+        &self,
+        request: tonic::Request<pb::EphemeralAddressesRequest>,
+    ) -> Result<tonic::Response<Self::EphemeralAddressesStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let account_filter = request
+            .into_inner()
+            .account_filter
+            .map(AddressIndex::try_from)
+            .transpose()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("Could not parse account filter: {e:#}"))
+            })?
+            .map(|index| index.account);
+
+        let records = self
+            .storage
+            .ephemeral_addresses(account_filter)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error fetching ephemeral addresses: {e:#}"))
+            })?;
+
+        let stream = try_stream! {
+            for record in records {
+                yield pb::EphemeralAddressesResponse {
+                    address: Some(record.address.into()),
+                    address_index: Some(record.address_index.into()),
+                    height_generated: record.height_generated,
+                    transactions: record.transactions.into_iter().map(Into::into).collect(),
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error getting ephemeral addresses: {e}"))
+                })
+                .boxed(),
+        ))
+    }
+
     async fn transaction_info_by_hash(
         &self,
         request: tonic::Request<pb::TransactionInfoByHashRequest>,
@@ -853,6 +1191,7 @@ impl ViewService for ViewServer {
                 _ => {}
             }
         }
+        asset_ids.insert(min_view.body_view.fee_view.fee().asset_id());
 
         // Now, extend the TxV with information helpful to understand the data it can view:
 
@@ -873,6 +1212,10 @@ impl ViewService for ViewServer {
         // Finally, compute the full TxV from the full TxP:
         let txv = tx.view_from_perspective(&txp);
 
+        let block_time = self.storage.block_time(height).await.map_err(|e| {
+            tonic::Status::internal(format!("Error retrieving block time: {:#}", e))
+        })?;
+
         let response = pb::TransactionInfoByHashResponse {
             tx_info: Some(pb::TransactionInfo {
                 height,
@@ -880,6 +1223,7 @@ impl ViewService for ViewServer {
                 perspective: Some(txp.into()),
                 transaction: Some(tx.into()),
                 view: Some(txv.into()),
+                block_time: block_time.map(time_to_proto),
             }),
         };
 
@@ -1011,6 +1355,49 @@ impl ViewService for ViewServer {
         ))
     }
 
+    #[instrument(skip(self, _request))]
+    async fn balance_matrix(
+        &self,
+        _request: tonic::Request<pb::BalanceMatrixRequest>,
+    ) -> Result<tonic::Response<pb::BalanceMatrixResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let balances = self
+            .storage
+            .balance_matrix()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error summing balances: {e:#}")))?;
+
+        // Look up the metadata of each asset once, rather than once per account holding it.
+        let asset_ids: BTreeSet<_> = balances.iter().map(|entry| entry.id).collect();
+        let mut assets = Vec::new();
+        for id in asset_ids {
+            let metadata = self.storage.asset_by_id(&id).await.map_err(|e| {
+                tonic::Status::internal(format!("error retrieving asset by id: {e:#}"))
+            })?;
+            assets.extend(metadata);
+        }
+        let assets: asset::Cache = assets.into_iter().collect();
+
+        let entries = balances
+            .into_iter()
+            .map(|entry| pb::BalanceMatrixEntry {
+                account: Some(AddressIndex::from(entry.account).into()),
+                balance_view: Some(
+                    Value {
+                        amount: entry.amount,
+                        asset_id: entry.id,
+                    }
+                    .view_with_cache(&assets)
+                    .into(),
+                ),
+                note_count: entry.note_count,
+            })
+            .collect();
+
+        Ok(tonic::Response::new(pb::BalanceMatrixResponse { entries }))
+    }
+
     async fn note_by_commitment(
         &self,
         request: tonic::Request<pb::NoteByCommitmentRequest>,
@@ -1041,6 +1428,58 @@ impl ViewService for ViewServer {
         }))
     }
 
+    async fn disclosed_note_by_commitment(
+        &self,
+        request: tonic::Request<pb::DisclosedNoteByCommitmentRequest>,
+    ) -> Result<tonic::Response<pb::DisclosedNoteByCommitmentResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let request = request.into_inner();
+
+        let note_commitment: StateCommitment = request
+            .note_commitment
+            .ok_or_else(|| tonic::Status::invalid_argument("Missing note commitment in request"))?
+            .try_into()
+            .map_err(|_| tonic::Status::invalid_argument("Invalid note commitment in request"))?;
+        let payload_key: PayloadKey = request
+            .payload_key
+            .ok_or_else(|| tonic::Status::invalid_argument("Missing payload key in request"))?
+            .try_into()
+            .map_err(|_| tonic::Status::invalid_argument("Invalid payload key in request"))?;
+
+        let (height_created, transaction_id, payload) = self
+            .storage
+            .output_by_commitment(note_commitment)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error: {e}")))?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!(
+                    "No known transaction has an output with note commitment {note_commitment}"
+                ))
+            })?;
+
+        let note = Note::decrypt_with_payload_key(
+            &payload.encrypted_note,
+            &payload_key,
+            &payload.ephemeral_key,
+        )
+        .map_err(|_| tonic::Status::invalid_argument("Payload key does not decrypt the note"))?;
+        // The ciphertext is authenticated, but not bound to the commitment by decryption alone.
+        if note.commit() != note_commitment {
+            return Err(tonic::Status::data_loss(
+                "Decrypted note does not match its note commitment",
+            ));
+        }
+
+        Ok(tonic::Response::new(
+            pb::DisclosedNoteByCommitmentResponse {
+                note: Some(note.into()),
+                height_created,
+                transaction_id: Some(transaction_id.into()),
+            },
+        ))
+    }
+
     async fn nullifier_status(
         &self,
         request: tonic::Request<pb::NullifierStatusRequest>,
@@ -1064,6 +1503,35 @@ impl ViewService for ViewServer {
         }))
     }
 
+    async fn transaction_by_nullifier(
+        &self,
+        request: tonic::Request<pb::TransactionByNullifierRequest>,
+    ) -> Result<tonic::Response<pb::TransactionByNullifierResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let nullifier = request
+            .into_inner()
+            .nullifier
+            .ok_or_else(|| tonic::Status::failed_precondition("Missing nullifier in request"))?
+            .try_into()
+            .map_err(|_| tonic::Status::failed_precondition("Invalid nullifier in request"))?;
+
+        let spent_by = self
+            .storage
+            .transaction_by_nullifier(nullifier)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error: {e}")))?;
+
+        // An unspent nullifier, or one spent by a transaction we haven't seen, has an empty response.
+        Ok(tonic::Response::new(match spent_by {
+            Some((height, id)) => pb::TransactionByNullifierResponse {
+                id: Some(id.into()),
+                height,
+            },
+            None => Default::default(),
+        }))
+    }
+
     async fn status(
         &self,
         _: tonic::Request<pb::StatusRequest>,
@@ -1107,6 +1575,24 @@ impl ViewService for ViewServer {
         Ok(tonic::Response::new(stream.boxed()))
     }
 
+    async fn handshake(
+        &self,
+        _: tonic::Request<pb::HandshakeRequest>,
+    ) -> Result<tonic::Response<pb::HandshakeResponse>, tonic::Status> {
+        // Don't check the worker: if syncing failed because the node is on another chain, this is
+        // how the client finds out.
+        let parameters =
+            self.storage.app_params().await.map_err(|e| {
+                tonic::Status::unavailable(format!("error getting app params: {e}"))
+            })?;
+
+        Ok(tonic::Response::new(pb::HandshakeResponse {
+            chain_id: parameters.chain_id,
+            app_version: penumbra_app::APP_VERSION,
+            storage_schema_hash: crate::storage::SCHEMA_HASH.clone(),
+        }))
+    }
+
     async fn notes(
         &self,
         request: tonic::Request<pb::NotesRequest>,
@@ -1137,7 +1623,13 @@ impl ViewService for ViewServer {
 
         let notes = self
             .storage
-            .notes(include_spent, asset_id, address_index, amount_to_spend)
+            .notes(
+                include_spent,
+                asset_id,
+                address_index,
+                amount_to_spend,
+                request.allow_other_accounts,
+            )
             .await
             .map_err(|e| tonic::Status::unavailable(format!("error fetching notes: {e}")))?;
 
@@ -1393,6 +1885,46 @@ impl ViewService for ViewServer {
         Ok(tonic::Response::new(witness_response))
     }
 
+    async fn witness_by_commitment(
+        &self,
+        request: tonic::Request<pb::WitnessByCommitmentRequest>,
+    ) -> Result<tonic::Response<pb::WitnessByCommitmentResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let commitments = request
+            .into_inner()
+            .commitments
+            .into_iter()
+            .map(StateCommitment::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| tonic::Status::invalid_argument("Invalid state commitment in request"))?;
+
+        // Hold the read lock for the whole request, so that all auth paths are to the same root.
+        let sct = self.state_commitment_tree.read().await;
+        let anchor = sct.root();
+        let state_commitment_proofs: BTreeMap<_, _> = commitments
+            .into_iter()
+            .map(|commitment| {
+                let proof = sct.witness(commitment).ok_or_else(|| {
+                    tonic::Status::not_found(format!(
+                        "State commitment {commitment} is not witnessed by the wallet"
+                    ))
+                })?;
+                Ok((commitment, proof))
+            })
+            .collect::<Result<_, tonic::Status>>()?;
+        drop(sct);
+
+        let witness_data = WitnessData {
+            anchor,
+            state_commitment_proofs,
+        };
+
+        Ok(tonic::Response::new(pb::WitnessByCommitmentResponse {
+            witness_data: Some(witness_data.into()),
+        }))
+    }
+
     async fn witness_and_build(
         &self,
         request: tonic::Request<pb::WitnessAndBuildRequest>,
@@ -1559,6 +2091,63 @@ impl ViewService for ViewServer {
         ))
     }
 
+    async fn watch_positions(
+        &self,
+        request: tonic::Request<pb::WatchPositionsRequest>,
+    ) -> Result<tonic::Response<Self::WatchPositionsStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let position_ids = request
+            .into_inner()
+            .position_ids
+            .into_iter()
+            .map(position::Id::try_from)
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map_err(|e| e.context("could not decode position id"))
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        // Positions change as they're filled against without any action of ours, so rather than
+        // relying on what we've scanned, ask the fullnode for each position every time we sync a
+        // block, and send those that changed.
+        let nodes = self.nodes.clone();
+        let mut sync_height_stream = WatchStream::new(self.sync_height_rx.clone());
+        let stream = try_stream! {
+            let mut last_seen = BTreeMap::new();
+            while let Some(height) = sync_height_stream.next().await {
+                let mut client = DexQueryServiceClient::connect(nodes.current().to_string()).await?;
+                for id in &position_ids {
+                    let position = match client
+                        .liquidity_position_by_id(LiquidityPositionByIdRequest {
+                            position_id: Some((*id).into()),
+                        })
+                        .await
+                    {
+                        Ok(response) => response.into_inner().data,
+                        // The position may not have been opened yet.
+                        Err(status) if status.code() == tonic::Code::NotFound => None,
+                        Err(status) => Err(anyhow!("error fetching position {id}: {status}"))?,
+                    };
+                    if last_seen.get(id) != Some(&position) {
+                        last_seen.insert(*id, position.clone());
+                        yield pb::WatchPositionsResponse {
+                            position_id: Some((*id).into()),
+                            position,
+                            height,
+                        };
+                    }
+                }
+            }
+        };
+
+        Ok(tonic::Response::new(
+            stream
+                .map_err(|e: anyhow::Error| {
+                    tonic::Status::unavailable(format!("error watching positions: {e:#}"))
+                })
+                .boxed(),
+        ))
+    }
+
     async fn authorize_and_build(
         &self,
         _request: tonic::Request<pb::AuthorizeAndBuildRequest>,
@@ -1606,6 +2195,55 @@ impl ViewService for ViewServer {
         }))
     }
 
+    async fn export_frontier(
+        &self,
+        _: tonic::Request<pb::ExportFrontierRequest>,
+    ) -> Result<tonic::Response<pb::ExportFrontierResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        // Holding the SCT lock keeps the worker from recording a block while we export.
+        let sct = self.state_commitment_tree.read().await;
+        let frontier = self
+            .storage
+            .export_frontier(sct.clone())
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(format!("{e:#}")))?;
+        drop(sct);
+
+        Ok(tonic::Response::new(pb::ExportFrontierResponse {
+            frontier: Some(frontier.into()),
+        }))
+    }
+
+    async fn import_frontier(
+        &self,
+        request: tonic::Request<pb::ImportFrontierRequest>,
+    ) -> Result<tonic::Response<pb::ImportFrontierResponse>, tonic::Status> {
+        self.check_worker().await?;
+
+        let frontier: WalletFrontier = request
+            .into_inner()
+            .frontier
+            .ok_or_else(|| tonic::Status::invalid_argument("missing frontier"))?
+            .try_into()
+            .map_err(|e| tonic::Status::invalid_argument(format!("invalid frontier: {e:#}")))?;
+        let height = frontier.height;
+
+        // Holding the SCT lock keeps the worker from recording a block while we import. Once
+        // it's released, the worker notices that the sync height has moved past the block it
+        // was waiting to record, and resumes syncing from the frontier.
+        let mut sct = self.state_commitment_tree.write().await;
+        let imported_sct = frontier.sct.clone();
+        self.storage
+            .import_frontier(frontier)
+            .await
+            .map_err(|e| tonic::Status::failed_precondition(format!("{e:#}")))?;
+        *sct = imported_sct;
+        drop(sct);
+
+        Ok(tonic::Response::new(pb::ImportFrontierResponse { height }))
+    }
+
     async fn asset_metadata_by_id(
         &self,
         request: Request<AssetMetadataByIdRequest>,
@@ -7,7 +7,7 @@ use decaf377::{FieldExt, Fq};
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use r2d2_sqlite::{
-    rusqlite::{OpenFlags, OptionalExtension},
+    rusqlite::{Connection, OpenFlags, OptionalExtension},
     SqliteConnectionManager,
 };
 use sha2::{Digest, Sha256};
@@ -19,6 +19,7 @@ use url::Url;
 
 use penumbra_app::params::AppParameters;
 use penumbra_asset::{asset, asset::Id, asset::Metadata, Value};
+use penumbra_compact_block::{SyncCheckpoint, TrustedCheckpoint};
 use penumbra_dex::{
     lp::position::{self, Position, State},
     TradingPair,
@@ -27,22 +28,41 @@ use penumbra_fee::GasPrices;
 use penumbra_keys::{keys::AddressIndex, Address, FullViewingKey};
 use penumbra_num::Amount;
 use penumbra_proto::{
-    core::app::v1::{
-        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+    core::{
+        app::v1::{
+            query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+        },
+        component::compact_block::v1::{
+            query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
+            SyncCheckpointRequest,
+        },
+    },
+    util::tendermint_proxy::v1::{
+        tendermint_proxy_service_client::TendermintProxyServiceClient, GetBlockByHeightRequest,
     },
     DomainType,
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
-use penumbra_shielded_pool::{fmd, note, Note, Rseed};
+use penumbra_shielded_pool::{fmd, note, Note, NotePayload, Rseed};
 use penumbra_stake::{DelegationToken, IdentityKey};
 use penumbra_tct as tct;
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{txhash::TransactionId, Action, Transaction};
 use sct::TreeStore;
 use tct::StateCommitment;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{
+    sync::{FilteredBlock, SyncFilter},
+    EphemeralAddressRecord, QueueStatus, QueuedTransaction, SpendableNoteRecord, SwapRecord,
+    WalletFrontier,
+};
 
+mod encryption;
+mod migrate;
 mod sct;
+#[cfg(test)]
+mod tests;
+
+pub use migrate::Migration;
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct BalanceEntry {
@@ -51,8 +71,38 @@ pub struct BalanceEntry {
     pub address_index: AddressIndex,
 }
 
+/// The balance of one asset in one account, as returned by [`Storage::balance_matrix`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BalanceMatrixEntry {
+    pub account: u32,
+    pub id: Id,
+    pub amount: Amount,
+    pub note_count: u64,
+}
+
+/// Where syncing resumes from after a [`Storage::rescan`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RescanStart {
+    /// Nothing had been synced from the requested height, so nothing was cleared, and syncing
+    /// resumes where it left off.
+    Unchanged,
+    /// Syncing resumes after the last block of the epoch ending at this height.
+    EpochEnd(u64),
+    /// Syncing restarts from genesis.
+    Genesis,
+}
+
+/// Selects the known assets, along with their URIs, if they have any.
+const ASSETS_QUERY: &str =
+    "SELECT assets.asset_id, assets.denom, asset_uris.icon_uri, asset_uris.docs_uri
+    FROM assets LEFT JOIN asset_uris ON assets.asset_id = asset_uris.asset_id";
+
+/// How many of the most recently recorded blocks to keep the anchors of, to find where the chain
+/// forked if it does.
+const RECENT_ANCHORS: i64 = 100;
+
 /// The hash of the schema for the database.
-static SCHEMA_HASH: Lazy<String> =
+pub(crate) static SCHEMA_HASH: Lazy<String> =
     Lazy::new(|| hex::encode(Sha256::digest(include_str!("storage/schema.sql"))));
 
 #[derive(Clone)]
@@ -75,10 +125,19 @@ pub struct Storage {
 
 impl Storage {
     /// If the database at `storage_path` exists, [`Self::load`] it, otherwise, [`Self::initialize`] it.
+    ///
+    /// If a `birthday_height` is provided when initializing, it is recorded with
+    /// [`Self::record_birthday_height`], so that blocks before the trusted `checkpoint`
+    /// are not scanned.
+    ///
+    /// If a `passphrase` is provided, the database is encrypted with it.
     pub async fn load_or_initialize(
         storage_path: Option<impl AsRef<Utf8Path>>,
         fvk: &FullViewingKey,
         node: Url,
+        birthday_height: Option<u64>,
+        checkpoint: Option<TrustedCheckpoint>,
+        passphrase: Option<String>,
     ) -> anyhow::Result<Self> {
         if let Some(path) = storage_path.as_ref() {
             if path.as_ref().exists() {
@@ -86,6 +145,7 @@ impl Storage {
                     storage_path.expect(
                         "storage path is not `None` because we already matched on it above",
                     ),
+                    passphrase,
                 )
                 .await;
             }
@@ -98,20 +158,32 @@ impl Storage {
             .into_inner()
             .try_into()?;
 
-        Self::initialize(storage_path, fvk.clone(), params).await
+        let storage = Self::initialize(storage_path, fvk.clone(), params, passphrase).await?;
+        if let Some(birthday_height) = birthday_height {
+            storage
+                .record_birthday_height(node, birthday_height, checkpoint)
+                .await?;
+        }
+
+        Ok(storage)
     }
 
     fn connect(
         path: Option<impl AsRef<Utf8Path>>,
+        passphrase: Option<String>,
     ) -> anyhow::Result<r2d2::Pool<SqliteConnectionManager>> {
         if let Some(path) = path {
+            encryption::check_passphrase(path.as_ref(), passphrase.as_deref())?;
             let manager = SqliteConnectionManager::file(path.as_ref())
                 .with_flags(
                     // Don't allow opening URIs, because they can change the behavior of the database; we
                     // just want to open normal filepaths.
                     OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI,
                 )
-                .with_init(|conn| {
+                .with_init(move |conn| {
+                    if let Some(passphrase) = &passphrase {
+                        encryption::apply_passphrase(conn, passphrase)?;
+                    }
                     // "NORMAL" will be consistent, but maybe not durable -- this is fine,
                     // since all our data is being synced from the chain, so if we lose a dbtx,
                     // it's like we're resuming sync from a previous height.
@@ -127,6 +199,7 @@ impl Storage {
                 .max_size(1)
                 .build(manager)?)
         } else {
+            // An in-memory database is never written out, so there's nothing to encrypt.
             let manager = SqliteConnectionManager::memory();
             // Max size needs to be set to 1, otherwise a new in-memory database is created for each
             // connection to the pool, which results in very confusing errors.
@@ -142,9 +215,12 @@ impl Storage {
         }
     }
 
-    pub async fn load(path: impl AsRef<Utf8Path>) -> anyhow::Result<Self> {
+    pub async fn load(
+        path: impl AsRef<Utf8Path>,
+        passphrase: Option<String>,
+    ) -> anyhow::Result<Self> {
         let storage = Self {
-            pool: Self::connect(Some(path))?,
+            pool: Self::connect(Some(path), passphrase)?,
             uncommitted_height: Arc::new(Mutex::new(None)),
             scanned_notes_tx: broadcast::channel(128).0,
             scanned_nullifiers_tx: broadcast::channel(512).0,
@@ -187,11 +263,12 @@ impl Storage {
         storage_path: Option<impl AsRef<Utf8Path>>,
         fvk: FullViewingKey,
         params: AppParameters,
+        passphrase: Option<String>,
     ) -> anyhow::Result<Self> {
         tracing::debug!(storage_path = ?storage_path.as_ref().map(AsRef::as_ref), ?fvk, ?params);
 
         // Connect to the database (or create it)
-        let pool = Self::connect(storage_path)?;
+        let pool = Self::connect(storage_path, passphrase)?;
 
         spawn_blocking(move || {
             // In one database transaction, populate everything
@@ -303,6 +380,57 @@ impl Storage {
         .await?
     }
 
+    /// Sums up the unspent notes of every account into the balance of each asset, counting the
+    /// notes that make up each balance, ordered by account and then by asset ID.
+    pub async fn balance_matrix(&self) -> anyhow::Result<Vec<BalanceMatrixEntry>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            // Amounts are stored as big-endian byte strings, which SQLite can't add up, so the
+            // notes are summed here instead.
+            let query = "SELECT notes.asset_id, notes.amount, spendable_notes.address_index
+                FROM    notes
+                JOIN    spendable_notes ON notes.note_commitment = spendable_notes.note_commitment
+                WHERE   spendable_notes.height_spent IS NULL";
+
+            let mut balances: BTreeMap<(u32, Id), (Amount, u64)> = BTreeMap::new();
+
+            for result in pool.get()?.prepare_cached(query)?.query_map([], |row| {
+                let asset_id = row.get::<&str, Vec<u8>>("asset_id")?;
+                let amount = row.get::<&str, Vec<u8>>("amount")?;
+                let address_index = row.get::<&str, Vec<u8>>("address_index")?;
+
+                Ok((asset_id, amount, address_index))
+            })? {
+                let (id, amount, index) = result?;
+
+                let id = Id::try_from(id.as_slice())?;
+                let amount = Amount::from_be_bytes(
+                    amount
+                        .as_slice()
+                        .try_into()
+                        .expect("amount slice of incorrect length"),
+                );
+                let index = AddressIndex::try_from(index.as_slice())?;
+
+                let (balance, note_count) = balances.entry((index.account, id)).or_default();
+                *balance += amount;
+                *note_count += 1;
+            }
+
+            Ok(balances
+                .into_iter()
+                .map(|((account, id), (amount, note_count))| BalanceMatrixEntry {
+                    account,
+                    id,
+                    amount,
+                    note_count,
+                })
+                .collect())
+        })
+        .await?
+    }
+
     /// Query for a note by its note commitment, optionally waiting until the note is detected.
     pub async fn note_by_commitment(
         &self,
@@ -455,6 +583,298 @@ impl Storage {
         Ok(records)
     }
 
+    /// Record an ephemeral address handed out for the given address index.
+    pub async fn record_ephemeral_address(
+        &self,
+        address_index: AddressIndex,
+        address: Address,
+    ) -> anyhow::Result<()> {
+        let height_generated = self.last_sync_height().await?.unwrap_or(0) as i64;
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT OR IGNORE INTO ephemeral_addresses (address, account, address_index, height_generated)
+                    VALUES (?1, ?2, ?3, ?4)",
+                    (
+                        address.to_vec(),
+                        address_index.account,
+                        address_index.to_bytes().to_vec(),
+                        height_generated,
+                    ),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Query for the ephemeral addresses handed out, optionally only those of one account,
+    /// along with the transactions that used them.
+    pub async fn ephemeral_addresses(
+        &self,
+        account: Option<u32>,
+    ) -> anyhow::Result<Vec<EphemeralAddressRecord>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let conn = pool.get()?;
+            let addresses = conn
+                .prepare(
+                    "SELECT address, address_index, height_generated FROM ephemeral_addresses
+                    WHERE ?1 IS NULL OR account = ?1
+                    ORDER BY height_generated",
+                )?
+                .query_and_then([account], |row| {
+                    anyhow::Ok((
+                        row.get::<_, Vec<u8>>("address")?,
+                        AddressIndex::try_from(row.get::<_, Vec<u8>>("address_index")?.as_slice())?,
+                        row.get::<_, u64>("height_generated")?,
+                    ))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            let mut uses = conn.prepare_cached(
+                "SELECT ephemeral_address_uses.tx_hash FROM ephemeral_address_uses
+                JOIN tx ON ephemeral_address_uses.tx_hash = tx.tx_hash
+                WHERE ephemeral_address_uses.address = ?1
+                ORDER BY tx.block_height",
+            )?;
+            addresses
+                .into_iter()
+                .map(|(address, address_index, height_generated)| {
+                    let transactions = uses
+                        .query_and_then([&address], |row| {
+                            anyhow::Ok(TransactionId(row.get::<_, [u8; 32]>("tx_hash")?))
+                        })?
+                        .collect::<anyhow::Result<Vec<_>>>()?;
+                    Ok(EphemeralAddressRecord {
+                        address: address.try_into()?,
+                        address_index,
+                        height_generated,
+                        transactions,
+                    })
+                })
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .await?
+    }
+
+    /// Record swaps whose claims should be submitted as soon as the swaps are detected.
+    pub async fn record_bundled_swap_claims(
+        &self,
+        swap_commitments: Vec<StateCommitment>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+            for swap_commitment in swap_commitments {
+                dbtx.execute(
+                    "INSERT OR IGNORE INTO bundled_swap_claims (swap_commitment) VALUES (?1)",
+                    [swap_commitment.0.to_bytes().to_vec()],
+                )?;
+            }
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Records `transaction` as the one broadcast with `idempotency_key`, unless another
+    /// transaction was already recorded with that key.
+    ///
+    /// Returns the transaction recorded with the key, which is the one that should be broadcast.
+    pub async fn record_idempotent_transaction(
+        &self,
+        idempotency_key: String,
+        transaction: Transaction,
+    ) -> anyhow::Result<Transaction> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+            dbtx.execute(
+                "INSERT OR IGNORE INTO idempotent_transactions (idempotency_key, tx_hash, tx_bytes) VALUES (?1, ?2, ?3)",
+                (
+                    &idempotency_key,
+                    transaction.id().0.to_vec(),
+                    transaction.encode_to_vec(),
+                ),
+            )?;
+            let tx_bytes: Vec<u8> = dbtx.query_row(
+                "SELECT tx_bytes FROM idempotent_transactions WHERE idempotency_key = ?1",
+                [&idempotency_key],
+                |row| row.get("tx_bytes"),
+            )?;
+            dbtx.commit()?;
+            Transaction::decode(tx_bytes.as_slice())
+        })
+        .await?
+    }
+
+    /// Forgets the transaction recorded with `idempotency_key`, once it can no longer land, so
+    /// that retrying with the key builds a new transaction.
+    pub async fn forget_idempotent_transaction(
+        &self,
+        idempotency_key: String,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "DELETE FROM idempotent_transactions WHERE idempotency_key = ?1",
+                [idempotency_key],
+            )?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Adds `transaction` to the broadcast queue, due to be sent right away, unless it's already
+    /// queued.
+    pub async fn enqueue_broadcast(&self, transaction: &Transaction) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let tx_hash = transaction.id().0.to_vec();
+        let tx_bytes = transaction.encode_to_vec();
+        let expiry_height = transaction.transaction_parameters().expiry_height;
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "INSERT OR IGNORE INTO broadcast_queue (tx_hash, tx_bytes, status, attempts, expiry_height, next_attempt)
+                    VALUES (?1, ?2, 'pending', 0, ?3, 0)",
+                    (tx_hash, tx_bytes, expiry_height),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Returns the pending transactions in the broadcast queue that are due to be sent by `now`,
+    /// a unix time in seconds, along with the number of times each was already sent.
+    pub async fn due_broadcasts(&self, now: i64) -> anyhow::Result<Vec<(Transaction, u32)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT tx_bytes, attempts FROM broadcast_queue
+                    WHERE status = 'pending' AND next_attempt <= ?1
+                    ORDER BY next_attempt",
+                )?
+                .query_and_then([now], |row| {
+                    let tx_bytes: Vec<u8> = row.get("tx_bytes")?;
+                    anyhow::Ok((
+                        Transaction::decode(tx_bytes.as_slice())?,
+                        row.get("attempts")?,
+                    ))
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Records an attempt to send a queued transaction, which failed with `error` if set, and
+    /// the unix time in seconds at which to send it again.
+    pub async fn record_broadcast_attempt(
+        &self,
+        id: TransactionId,
+        error: Option<String>,
+        next_attempt: i64,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "UPDATE broadcast_queue
+                    SET attempts = attempts + 1, last_error = ?2, next_attempt = ?3
+                    WHERE tx_hash = ?1",
+                    (id.0.to_vec(), error, next_attempt),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Records that a queued transaction was confirmed, expired, or conflicted, so that it's no
+    /// longer sent.
+    pub async fn settle_broadcast(
+        &self,
+        id: TransactionId,
+        status: QueueStatus,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+        let detection_height = match status {
+            QueueStatus::Confirmed { detection_height } => Some(detection_height),
+            _ => None,
+        };
+
+        spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "UPDATE broadcast_queue SET status = ?2, detection_height = ?3 WHERE tx_hash = ?1",
+                    (id.0.to_vec(), status.as_str(), detection_height),
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(())
+    }
+
+    /// Returns the transactions in the broadcast queue, or only the one with the given id.
+    pub async fn broadcast_queue(
+        &self,
+        id: Option<TransactionId>,
+    ) -> anyhow::Result<Vec<QueuedTransaction>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT tx_hash, status, attempts, last_error, expiry_height, detection_height, next_attempt
+                    FROM broadcast_queue
+                    WHERE ?1 IS NULL OR tx_hash = ?1
+                    ORDER BY rowid",
+                )?
+                .query_and_then([id.map(|id| id.0.to_vec())], |row| row.try_into())?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Remove a swap from the bundled swap claims, returning whether it was there.
+    pub async fn take_bundled_swap_claim(
+        &self,
+        swap_commitment: StateCommitment,
+    ) -> anyhow::Result<bool> {
+        let pool = self.pool.clone();
+
+        let removed = spawn_blocking(move || {
+            pool.get()?
+                .execute(
+                    "DELETE FROM bundled_swap_claims WHERE swap_commitment = ?1",
+                    [swap_commitment.0.to_bytes().to_vec()],
+                )
+                .map_err(anyhow::Error::from)
+        })
+        .await??;
+
+        Ok(removed > 0)
+    }
+
     /// Query for a nullifier's status, optionally waiting until the nullifier is detected.
     pub async fn nullifier_status(
         &self,
@@ -528,6 +948,188 @@ impl Storage {
         .await?
     }
 
+    /// The checkpoint the wallet started syncing from, if it was given one.
+    pub async fn trusted_checkpoint(&self) -> anyhow::Result<Option<TrustedCheckpoint>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let checkpoint = pool
+                .get()?
+                .prepare_cached("SELECT v FROM kv WHERE k IS 'trusted_checkpoint' LIMIT 1")?
+                .query_row([], |row| row.get::<_, String>("v"))
+                .optional()?;
+
+            checkpoint.map(|checkpoint| checkpoint.parse()).transpose()
+        })
+        .await?
+    }
+
+    /// The height the wallet was created at, if it was recorded.
+    pub async fn birthday_height(&self) -> anyhow::Result<Option<u64>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let height = pool
+                .get()?
+                .prepare_cached("SELECT v FROM kv WHERE k IS 'birthday_height' LIMIT 1")?
+                .query_row([], |row| row.get::<_, i64>("v"))
+                .optional()?;
+
+            anyhow::Ok(height.map(u64::try_from).transpose()?)
+        })
+        .await?
+    }
+
+    /// Record the height the wallet was created at, and skip scanning blocks before it
+    /// by starting to sync from the trusted `checkpoint`, which must not be after it.
+    ///
+    /// Without a trusted checkpoint, or if the node doesn't have it or doesn't serve
+    /// checkpoints at all, syncing starts from genesis instead, and no birthday is recorded,
+    /// since no blocks are skipped.
+    pub async fn record_birthday_height(
+        &self,
+        node: Url,
+        birthday_height: u64,
+        checkpoint: Option<TrustedCheckpoint>,
+    ) -> anyhow::Result<()> {
+        let Some(checkpoint) = checkpoint else {
+            tracing::info!(
+                birthday_height,
+                "no trusted checkpoint given, syncing from genesis"
+            );
+            return Ok(());
+        };
+        anyhow::ensure!(
+            checkpoint.height <= birthday_height,
+            "checkpoint at height {} is after the wallet birthday {}",
+            checkpoint.height,
+            birthday_height
+        );
+
+        let height = match self.sync_from_checkpoint(node, checkpoint).await {
+            Ok(height) => height,
+            Err(e)
+                if matches!(
+                    e.downcast_ref::<tonic::Status>().map(tonic::Status::code),
+                    Some(tonic::Code::NotFound | tonic::Code::Unimplemented)
+                ) =>
+            {
+                tracing::warn!(
+                    birthday_height,
+                    ?e,
+                    "no checkpoint available, syncing from genesis"
+                );
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT INTO kv (k, v) VALUES ('birthday_height', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [i64::try_from(birthday_height)?],
+            )?;
+            anyhow::Ok(())
+        })
+        .await??;
+        tracing::info!(
+            birthday_height,
+            height,
+            "skipping blocks before wallet birthday"
+        );
+        Ok(())
+    }
+
+    /// The filter restricting which detected notes are stored.
+    ///
+    /// Databases that never had a filter set store every note.
+    pub async fn sync_filter(&self) -> anyhow::Result<SyncFilter> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let bytes = pool
+                .get()?
+                .prepare_cached("SELECT v FROM kv WHERE k IS 'sync_filter' LIMIT 1")?
+                .query_row([], |row| row.get::<_, Vec<u8>>("v"))
+                .optional()?;
+
+            match bytes {
+                Some(bytes) => Ok(serde_json::from_slice(&bytes)?),
+                None => Ok(SyncFilter::default()),
+            }
+        })
+        .await?
+    }
+
+    /// Replace the filter restricting which detected notes are stored.
+    ///
+    /// Stored notes the new filter doesn't allow are forgotten. If the new filter allows notes
+    /// the old one didn't, they're backfilled by rescanning from the wallet birthday, so the
+    /// next sync detects them. Returns whether a rescan was needed.
+    ///
+    /// This must not be called while a view service is running on this storage.
+    pub async fn set_sync_filter(&self, node: Url, filter: SyncFilter) -> anyhow::Result<bool> {
+        let old_filter = self.sync_filter().await?;
+        if old_filter == filter {
+            return Ok(false);
+        }
+
+        // Rescan before recording the new filter, so that if the rescan fails, it's retried
+        // the next time the filter is set.
+        let backfill = !old_filter.includes(&filter);
+        if backfill {
+            let from_height = self.birthday_height().await?.unwrap_or(0);
+            tracing::info!(
+                from_height,
+                "sync filter widened, rescanning to backfill notes"
+            );
+            self.rescan(node, from_height, None).await?;
+        }
+
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+
+            // Swaps are kept regardless of the filter, so that they can still be claimed.
+            let notes = dbtx
+                .prepare(
+                    "SELECT spendable_notes.note_commitment, spendable_notes.address_index, notes.asset_id
+                    FROM spendable_notes
+                    JOIN notes ON notes.note_commitment = spendable_notes.note_commitment",
+                )?
+                .query_and_then([], |row| {
+                    anyhow::Ok((
+                        row.get::<_, Vec<u8>>("note_commitment")?,
+                        AddressIndex::try_from(row.get::<_, Vec<u8>>("address_index")?.as_slice())?,
+                        Id::try_from(row.get::<_, Vec<u8>>("asset_id")?.as_slice())?,
+                    ))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            for (note_commitment, address_index, asset_id) in notes {
+                if !filter.allows(address_index, asset_id) {
+                    dbtx.execute(
+                        "DELETE FROM spendable_notes WHERE note_commitment = ?1",
+                        [&note_commitment],
+                    )?;
+                }
+            }
+            dbtx.execute(
+                "INSERT INTO kv (k, v) VALUES ('sync_filter', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [serde_json::to_vec(&filter)?],
+            )?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        Ok(backfill)
+    }
+
     pub async fn app_params(&self) -> anyhow::Result<AppParameters> {
         let pool = self.pool.clone();
 
@@ -654,6 +1256,49 @@ impl Storage {
         .await?
     }
 
+    /// Returns the time of the block at the given height, if it contained a known transaction and
+    /// its time was recorded.
+    pub async fn block_time(&self, height: u64) -> anyhow::Result<Option<tendermint::Time>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT block_time FROM block_times WHERE height = ?1")?
+                .query_row([height as i64], |row| row.get::<_, String>("block_time"))
+                .optional()?
+                .map(|block_time| {
+                    tendermint::Time::parse_from_rfc3339(&block_time)
+                        .context("invalid block time in database")
+                })
+                .transpose()
+        })
+        .await?
+    }
+
+    /// Finds the output creating the note with the given commitment among the transactions known
+    /// to the view service, returning the height and ID of its transaction along with its payload.
+    ///
+    /// The note need not be owned by the wallet, e.g. it may be a payment the wallet sent.
+    pub async fn output_by_commitment(
+        &self,
+        note_commitment: tct::StateCommitment,
+    ) -> anyhow::Result<Option<(u64, TransactionId, NotePayload)>> {
+        // Outputs aren't indexed by commitment, but the wallet's own transactions are few enough
+        // to search through.
+        Ok(self
+            .transactions(None, None)
+            .await?
+            .into_iter()
+            .find_map(|(height, _, tx)| {
+                let payload = tx
+                    .outputs()
+                    .map(|output| &output.body.note_payload)
+                    .find(|payload| payload.note_commitment == note_commitment)?
+                    .clone();
+                Some((height, tx.id(), payload))
+            }))
+    }
+
     pub async fn transaction_by_hash(
         &self,
         tx_hash: &[u8],
@@ -681,6 +1326,34 @@ impl Storage {
         .await?
     }
 
+    /// Returns the height and hash of the transaction that spent the given nullifier, if the
+    /// wallet has seen it.
+    pub async fn transaction_by_nullifier(
+        &self,
+        nullifier: Nullifier,
+    ) -> anyhow::Result<Option<(u64, TransactionId)>> {
+        let pool = self.pool.clone();
+        let nullifier_bytes = nullifier.to_bytes().to_vec();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT tx.block_height, tx.tx_hash
+                    FROM tx_by_nullifier
+                    JOIN tx ON tx.tx_hash = tx_by_nullifier.tx_hash
+                    WHERE tx_by_nullifier.nullifier = ?1",
+                )?
+                .query_row([nullifier_bytes], |row| {
+                    let block_height: u64 = row.get("block_height")?;
+                    let tx_hash: [u8; 32] = row.get("tx_hash")?;
+                    Ok((block_height, TransactionId(tx_hash)))
+                })
+                .optional()
+                .map_err(Into::into)
+        })
+        .await?
+    }
+
     // Query for a note by its note commitment, optionally waiting until the note is detected.
     pub async fn note_by_nullifier(
         &self,
@@ -767,17 +1440,8 @@ impl Storage {
 
         spawn_blocking(move || {
             pool.get()?
-                .prepare_cached("SELECT * FROM assets")?
-                .query_and_then([], |row| {
-                    let _asset_id: Vec<u8> = row.get("asset_id")?;
-                    let denom: String = row.get("denom")?;
-
-                    let denom_metadata = asset::REGISTRY
-                        .parse_denom(&denom)
-                        .ok_or_else(|| anyhow::anyhow!("invalid denomination {}", denom))?;
-
-                    anyhow::Ok(denom_metadata)
-                })?
+                .prepare_cached(ASSETS_QUERY)?
+                .query_and_then([], Storage::asset_from_row)?
                 .collect()
         })
         .await?
@@ -790,15 +1454,8 @@ impl Storage {
 
         spawn_blocking(move || {
             pool.get()?
-                .prepare_cached("SELECT * FROM assets WHERE asset_id = ?1")?
-                .query_and_then([id], |row| {
-                    let _asset_id: Vec<u8> = row.get("asset_id")?;
-                    let denom: String = row.get("denom")?;
-                    let denom_metadata = asset::REGISTRY
-                        .parse_denom(&denom)
-                        .ok_or_else(|| anyhow::anyhow!("invalid denomination {}", denom))?;
-                    anyhow::Ok(denom_metadata)
-                })?
+                .prepare_cached(&format!("{ASSETS_QUERY} WHERE assets.asset_id = ?1"))?
+                .query_and_then([id], Storage::asset_from_row)?
                 .next()
                 .transpose()
         })
@@ -814,15 +1471,10 @@ impl Storage {
 
         spawn_blocking(move || {
             pool.get()?
-                .prepare_cached("SELECT * FROM assets WHERE denom LIKE ?1 ESCAPE '\\'")?
-                .query_and_then([pattern], |row| {
-                    let _asset_id: Vec<u8> = row.get("asset_id")?;
-                    let denom: String = row.get("denom")?;
-                    let denom_metadata = asset::REGISTRY
-                        .parse_denom(&denom)
-                        .ok_or_else(|| anyhow::anyhow!("invalid denomination {}", denom))?;
-                    anyhow::Ok(denom_metadata)
-                })?
+                .prepare_cached(&format!(
+                    "{ASSETS_QUERY} WHERE assets.denom LIKE ?1 ESCAPE '\\'"
+                ))?
+                .query_and_then([pattern], Storage::asset_from_row)?
                 .collect()
         })
         .await?
@@ -834,6 +1486,7 @@ impl Storage {
         asset_id: Option<asset::Id>,
         address_index: Option<penumbra_keys::keys::AddressIndex>,
         amount_to_spend: Option<Amount>,
+        allow_other_accounts: bool,
     ) -> anyhow::Result<Vec<SpendableNoteRecord>> {
         // If set, return spent notes as well as unspent notes.
         // bool include_spent = 2;
@@ -872,6 +1525,8 @@ impl Storage {
 
         spawn_blocking(move || {
             let mut output: Vec<SpendableNoteRecord> = Vec::new();
+            let mut records: Vec<SpendableNoteRecord> = Vec::new();
+            let mut other_accounts: Vec<SpendableNoteRecord> = Vec::new();
 
             for result in pool
                 .get()?
@@ -903,9 +1558,19 @@ impl Storage {
                 // not doing account filtering in SQL as a temporary hack (see above)
                 if let Some(address_index) = address_index {
                     if record.address_index.account != address_index.account {
+                        if allow_other_accounts {
+                            other_accounts.push(record);
+                        }
                         continue;
                     }
                 }
+                records.push(record);
+            }
+
+            // Only fall back to notes from other accounts once the requested account's run out.
+            records.extend(other_accounts);
+
+            for record in records {
                 let amount = record.note.amount();
 
                 // Only display notes of value > 0
@@ -1008,16 +1673,29 @@ impl Storage {
     pub async fn record_asset(&self, asset: Metadata) -> anyhow::Result<()> {
         let asset_id = asset.id().to_bytes().to_vec();
         let denom = asset.base_denom().denom;
+        let icon_uri = asset.icon_uri().map(ToOwned::to_owned);
+        let docs_uri = asset.docs_uri().map(ToOwned::to_owned);
 
         let pool = self.pool.clone();
 
         spawn_blocking(move || {
-            pool.get()?
-                .execute(
-                    "INSERT OR IGNORE INTO assets (asset_id, denom) VALUES (?1, ?2)",
-                    (asset_id, denom),
-                )
-                .map_err(anyhow::Error::from)
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+            dbtx.execute(
+                "INSERT OR IGNORE INTO assets (asset_id, denom) VALUES (?1, ?2)",
+                (&asset_id, denom),
+            )?;
+            // The rest of the metadata is derived from the denom, but the URIs are only known
+            // from the metadata registered on chain.
+            if icon_uri.is_some() || docs_uri.is_some() {
+                dbtx.execute(
+                    "INSERT OR IGNORE INTO asset_uris (asset_id, icon_uri, docs_uri)
+                        VALUES (?1, ?2, ?3)",
+                    (&asset_id, icon_uri, docs_uri),
+                )?;
+            }
+            dbtx.commit()?;
+            anyhow::Ok(())
         })
         .await??;
 
@@ -1105,6 +1783,601 @@ impl Storage {
         Ok(())
     }
 
+    /// Fetch the `trusted` checkpoint from the node, verify it, and start syncing from it.
+    ///
+    /// The trusted checkpoint is remembered, so that rescans can restart from it too.
+    /// Returns the height of the checkpoint.
+    pub async fn sync_from_checkpoint(
+        &self,
+        node: Url,
+        trusted: TrustedCheckpoint,
+    ) -> anyhow::Result<u64> {
+        let checkpoint = Self::fetch_sync_checkpoint(node, &trusted).await?;
+        let height = checkpoint.height;
+        self.record_sync_checkpoint(checkpoint).await?;
+
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            pool.get()?.execute(
+                "INSERT INTO kv (k, v) VALUES ('trusted_checkpoint', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [trusted.to_string()],
+            )?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        Ok(height)
+    }
+
+    /// Fetch the `trusted` checkpoint from the node, and verify it against the trusted
+    /// anchor and the app hash of the chain.
+    pub async fn fetch_sync_checkpoint(
+        node: Url,
+        trusted: &TrustedCheckpoint,
+    ) -> anyhow::Result<SyncCheckpoint> {
+        let mut client = CompactBlockQueryServiceClient::connect(node.to_string()).await?;
+        // The node serves checkpoints of epochs that have ended by `max_height`, and the
+        // checkpoint's epoch only ends with the block after it.
+        let checkpoint: SyncCheckpoint = client
+            .sync_checkpoint(tonic::Request::new(SyncCheckpointRequest {
+                max_height: trusted.height + 1,
+            }))
+            .await?
+            .into_inner()
+            .try_into()?;
+
+        // The app hash committing to the state the anchor was proven against is only
+        // included in the header of the following block, which may not exist yet.
+        let mut client = TendermintProxyServiceClient::connect(node.to_string()).await?;
+        let request = GetBlockByHeightRequest {
+            height: (checkpoint.proof_height + 1).try_into()?,
+        };
+        let mut attempts = 0;
+        let block = loop {
+            match client.get_block_by_height(request.clone()).await {
+                Ok(rsp) => break rsp.into_inner().block,
+                Err(e) if attempts < 30 => {
+                    tracing::debug!(?e, "waiting for block to fetch checkpoint app hash");
+                    attempts += 1;
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        };
+        let app_hash = block
+            .and_then(|block| block.header)
+            .ok_or_else(|| anyhow!("missing block header"))?
+            .app_hash;
+
+        checkpoint.verify(&app_hash, trusted)?;
+        tracing::info!(
+            height = checkpoint.height,
+            epoch_index = checkpoint.epoch_index,
+            "verified sync checkpoint"
+        );
+
+        Ok(checkpoint)
+    }
+
+    /// Start syncing from the given checkpoint, rather than from genesis.
+    ///
+    /// This is only possible before any blocks have been scanned, and the checkpoint
+    /// should already have been verified: no notes created before it will be found.
+    pub async fn record_sync_checkpoint(&self, checkpoint: SyncCheckpoint) -> anyhow::Result<()> {
+        if let Some(last_sync_height) = self.last_sync_height().await? {
+            anyhow::bail!(
+                "cannot start syncing from checkpoint at height {}, already synced to height {}",
+                checkpoint.height,
+                last_sync_height
+            );
+        }
+
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut dbtx = lock.transaction()?;
+
+            checkpoint.sct.to_writer(&mut TreeStore(&mut dbtx))?;
+            record_epoch_anchor(&dbtx, checkpoint.height, &checkpoint.sct)?;
+
+            let fmd_parameters_bytes = checkpoint.fmd_parameters.encode_to_vec();
+            dbtx.execute(
+                "INSERT INTO kv (k, v) VALUES ('fmd_params', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [&fmd_parameters_bytes[..]],
+            )?;
+
+            let gas_prices_bytes = checkpoint.gas_prices.encode_to_vec();
+            dbtx.execute(
+                "INSERT INTO kv (k, v) VALUES ('gas_prices', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [&gas_prices_bytes[..]],
+            )?;
+
+            dbtx.execute(
+                "UPDATE sync_height SET height = ?1",
+                [checkpoint.height as i64],
+            )?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// Invalidate everything detected at or after `from_height`, optionally only for the
+    /// given account, and rewind the sync height so that those blocks are scanned again.
+    ///
+    /// Detection state before `from_height` is kept. Since the state commitment tree can't be
+    /// rewound, it is rebuilt from the checkpoint of the latest epoch that ended before
+    /// `from_height` without dropping any commitment the wallet still needs to witness. The
+    /// checkpoint is fetched from the node, and verified against the anchor recorded when the
+    /// epoch was synced, or against the wallet's trusted checkpoint. If there is no such epoch,
+    /// or its checkpoint can't be fetched, syncing restarts from genesis.
+    ///
+    /// If nothing was synced at or after `from_height`, nothing is changed.
+    ///
+    /// This must not be called while a view service is running on this storage.
+    pub async fn rescan(
+        &self,
+        node: Url,
+        from_height: u64,
+        account: Option<u32>,
+    ) -> anyhow::Result<RescanStart> {
+        if self
+            .last_sync_height()
+            .await?
+            .map_or(true, |height| height < from_height)
+        {
+            return Ok(RescanStart::Unchanged);
+        }
+
+        let fvk = self.full_viewing_key().await?;
+        let earliest_witnessed = self
+            .state_commitment_tree()
+            .await?
+            .commitments()
+            .next()
+            .map(|(position, _)| position);
+
+        let trusted = match self
+            .epoch_anchor_before(from_height, earliest_witnessed)
+            .await?
+        {
+            Some(epoch_anchor) => Some(epoch_anchor),
+            // Wallets synced before epoch anchors were recorded only have the checkpoint they
+            // started from.
+            None => self
+                .trusted_checkpoint()
+                .await?
+                .filter(|trusted| trusted.height < from_height),
+        };
+        let checkpoint = match trusted {
+            Some(trusted) => match Self::fetch_sync_checkpoint(node, &trusted).await {
+                Ok(checkpoint) => Some(checkpoint),
+                Err(e) => {
+                    tracing::warn!(?e, "could not fetch checkpoint, rescanning from genesis");
+                    None
+                }
+            },
+            None => None,
+        }
+        .filter(|checkpoint| match earliest_witnessed {
+            Some(earliest) => checkpoint.sct.position().map_or(false, |p| p <= earliest),
+            None => true,
+        });
+        let checkpoint_height = checkpoint.as_ref().map(|checkpoint| checkpoint.height);
+
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+            let from_height = i64::try_from(from_height)?;
+
+            let in_account = |address_index: AddressIndex| match account {
+                Some(account) => address_index.account == account,
+                None => true,
+            };
+
+            // Forget notes detected in the range, and un-spend notes spent in it.
+            let notes = dbtx
+                .prepare(
+                    "SELECT note_commitment, address_index, height_created
+                    FROM spendable_notes
+                    WHERE height_created >= ?1 OR height_spent >= ?1",
+                )?
+                .query_and_then([from_height], |row| {
+                    anyhow::Ok((
+                        row.get::<_, Vec<u8>>("note_commitment")?,
+                        AddressIndex::try_from(row.get::<_, Vec<u8>>("address_index")?.as_slice())?,
+                        row.get::<_, i64>("height_created")?,
+                    ))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            for (note_commitment, address_index, height_created) in notes {
+                if !in_account(address_index) {
+                    continue;
+                }
+                if height_created >= from_height {
+                    dbtx.execute(
+                        "DELETE FROM spendable_notes WHERE note_commitment = ?1",
+                        [&note_commitment],
+                    )?;
+                } else {
+                    dbtx.execute(
+                        "UPDATE spendable_notes SET height_spent = NULL WHERE note_commitment = ?1",
+                        [&note_commitment],
+                    )?;
+                }
+            }
+
+            // Un-claim swaps claimed in the range.
+            let swaps = dbtx
+                .prepare("SELECT * FROM swaps WHERE height_claimed >= ?1")?
+                .query_and_then([from_height], |row| SwapRecord::try_from(row))?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            for swap in swaps {
+                if !fvk
+                    .address_index(&swap.swap.claim_address)
+                    .map_or(false, in_account)
+                {
+                    continue;
+                }
+                dbtx.execute(
+                    "UPDATE swaps SET height_claimed = NULL WHERE swap_commitment = ?1",
+                    [swap.swap_commitment.0.to_bytes().to_vec()],
+                )?;
+            }
+
+            // Transactions aren't associated with a single account, so only forget
+            // them when rescanning every account.
+            if account.is_none() {
+                dbtx.execute("DELETE FROM tx WHERE block_height >= ?1", [from_height])?;
+                dbtx.execute("DELETE FROM block_times WHERE height >= ?1", [from_height])?;
+                dbtx.execute(
+                    "DELETE FROM tx_by_nullifier WHERE tx_hash NOT IN (SELECT tx_hash FROM tx)",
+                    (),
+                )?;
+                dbtx.execute(
+                    "DELETE FROM ephemeral_address_uses WHERE tx_hash NOT IN (SELECT tx_hash FROM tx)",
+                    (),
+                )?;
+            }
+
+            // Reset the state commitment tree and the sync height to genesis, keeping the
+            // anchors of the epochs that are kept.
+            dbtx.execute_batch(
+                "DELETE FROM sct_hashes;
+                DELETE FROM sct_commitments;
+                DELETE FROM recent_anchors;
+                UPDATE sct_position SET position = 0;
+                UPDATE sct_forgotten SET forgotten = 0;
+                UPDATE sync_height SET height = -1;",
+            )?;
+            dbtx.execute(
+                "DELETE FROM epoch_anchors WHERE height > ?1",
+                [checkpoint_height.map_or(-1, |height| height as i64)],
+            )?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        // Now that we're back at genesis, fast-forward to the checkpoint, if we have one.
+        match checkpoint {
+            Some(checkpoint) => {
+                let height = checkpoint.height;
+                self.record_sync_checkpoint(checkpoint).await?;
+                Ok(RescanStart::EpochEnd(height))
+            }
+            None => Ok(RescanStart::Genesis),
+        }
+    }
+
+    /// The anchor after the latest synced epoch that ended before `height`, and at or before the
+    /// `earliest_witnessed` commitment, if any epoch did.
+    async fn epoch_anchor_before(
+        &self,
+        height: u64,
+        earliest_witnessed: Option<tct::Position>,
+    ) -> anyhow::Result<Option<TrustedCheckpoint>> {
+        let pool = self.pool.clone();
+        let height = i64::try_from(height)?;
+        let position = match earliest_witnessed {
+            Some(position) => i64::try_from(u64::from(position))?,
+            None => i64::MAX,
+        };
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT height, anchor FROM epoch_anchors
+                    WHERE height < ?1 AND position <= ?2
+                    ORDER BY height DESC LIMIT 1",
+                )?
+                .query_and_then((height, position), |row| {
+                    anyhow::Ok(TrustedCheckpoint {
+                        height: u64::try_from(row.get::<_, i64>("height")?)?,
+                        anchor: tct::Root::decode(row.get::<_, Vec<u8>>("anchor")?.as_slice())?,
+                    })
+                })?
+                .next()
+                .transpose()
+        })
+        .await?
+    }
+
+    /// The roots of the state commitment tree after the most recently recorded blocks, newest
+    /// first.
+    pub async fn recent_anchors(&self) -> anyhow::Result<Vec<(u64, tct::Root)>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT height, anchor FROM recent_anchors ORDER BY height DESC")?
+                .query_and_then([], |row| {
+                    anyhow::Ok((
+                        u64::try_from(row.get::<_, i64>("height")?)?,
+                        tct::Root::decode(row.get::<_, Vec<u8>>("anchor")?.as_slice())?,
+                    ))
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()
+        })
+        .await?
+    }
+
+    /// Undo everything recorded from `fork_height` onwards, after the chain forked away from the
+    /// blocks synced at those heights, so that syncing resumes on the new fork.
+    ///
+    /// The transactions detected in those blocks may not be on the new fork, so the positions they
+    /// opened, closed or withdrew are reverted, and the idempotency keys they were broadcast under
+    /// are released, so that retrying builds a new transaction rather than re-sending one that may
+    /// no longer be valid. The rest is forgotten as by [`Self::rescan`], and swaps recorded past
+    /// the rebuilt state commitment tree are detected again.
+    ///
+    /// The uncommitted height is discarded, so the caller must reload its in-memory state
+    /// commitment tree afterwards. Returns the height of the checkpoint syncing will resume from,
+    /// if one was used.
+    pub async fn roll_back(&self, node: Url, fork_height: u64) -> anyhow::Result<Option<u64>> {
+        // Rolling back is only for forks located at a synced block, which is genesis when the
+        // chain has nothing in common with the synced blocks.
+        let last_sync_height = self.last_sync_height().await?;
+        anyhow::ensure!(
+            last_sync_height.map_or(false, |height| fork_height <= height),
+            "cannot roll back from height {fork_height}, which is not a synced block"
+        );
+
+        self.uncommitted_height.lock().take();
+
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let dbtx = lock.transaction()?;
+            let fork_height = i64::try_from(fork_height)?;
+
+            let transactions = dbtx
+                .prepare(
+                    "SELECT tx_bytes FROM tx WHERE block_height >= ?1 ORDER BY block_height DESC",
+                )?
+                .query_and_then([fork_height], |row| {
+                    Transaction::decode(row.get::<_, Vec<u8>>("tx_bytes")?.as_slice())
+                })?
+                .collect::<anyhow::Result<Vec<_>>>()?;
+
+            // Revert the positions' states, undoing the latest actions first.
+            let set_state = |id: position::Id, state: State| {
+                dbtx.execute(
+                    "UPDATE positions SET (position_state) = ?1 WHERE position_id = ?2",
+                    (state.to_string(), id.0.to_vec()),
+                )
+            };
+            for transaction in &transactions {
+                let actions = transaction.actions().collect::<Vec<_>>();
+                for action in actions.into_iter().rev() {
+                    match action {
+                        Action::PositionOpen(open) => {
+                            dbtx.execute(
+                                "DELETE FROM positions WHERE position_id = ?1",
+                                [open.position.id().0.to_vec()],
+                            )?;
+                        }
+                        Action::PositionClose(close) => {
+                            set_state(close.position_id, State::Opened)?;
+                        }
+                        Action::PositionWithdraw(withdraw) => {
+                            let state = match withdraw.sequence {
+                                0 => State::Closed,
+                                sequence => State::Withdrawn {
+                                    sequence: sequence - 1,
+                                },
+                            };
+                            set_state(withdraw.position_id, state)?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            dbtx.execute(
+                "DELETE FROM idempotent_transactions
+                WHERE tx_hash IN (SELECT tx_hash FROM tx WHERE block_height >= ?1)",
+                [fork_height],
+            )?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        let checkpoint_height = match self.rescan(node, fork_height, None).await? {
+            RescanStart::EpochEnd(height) => Some(height),
+            RescanStart::Unchanged | RescanStart::Genesis => None,
+        };
+
+        // Swaps aren't recorded with their height, so forget those past the start of the rebuilt
+        // tree, which are all detected again if they're still on chain.
+        if let Some(position) = self.state_commitment_tree().await?.position() {
+            let position = i64::try_from(u64::from(position))?;
+            let pool = self.pool.clone();
+            spawn_blocking(move || {
+                pool.get()?
+                    .execute("DELETE FROM swaps WHERE position >= ?1", [position])?;
+                anyhow::Ok(())
+            })
+            .await??;
+        }
+
+        Ok(checkpoint_height)
+    }
+
+    /// Export the wallet's synchronized state as of the last synced block, with `sct` as the
+    /// state commitment tree.
+    ///
+    /// The state commitment tree is passed in because the worker keeps it in memory, and only
+    /// writes it to the database when a block requires it.
+    pub async fn export_frontier(&self, sct: tct::Tree) -> anyhow::Result<WalletFrontier> {
+        let height = self.last_sync_height().await?.ok_or_else(|| {
+            anyhow!("no blocks have been synced yet, so there is nothing to export")
+        })?;
+
+        Ok(WalletFrontier {
+            wallet_id: self.full_viewing_key().await?.wallet_id(),
+            height,
+            sct,
+            notes: self.notes(false, None, None, None, false).await?,
+            swaps: self.unclaimed_swaps().await?,
+            fmd_parameters: self.fmd_parameters().await?,
+            gas_prices: self.gas_prices().await?,
+        })
+    }
+
+    /// Replace the wallet's synchronized state with `frontier`, so that syncing resumes after
+    /// its height rather than rescanning the blocks before it.
+    ///
+    /// The frontier must have been exported for this wallet, at a height beyond the current sync
+    /// height. Notes and swaps detected so far are replaced by those in the frontier, since they
+    /// were all detected before its height.
+    ///
+    /// This must not be called while the worker of a view service is recording blocks to this
+    /// storage.
+    pub async fn import_frontier(&self, frontier: WalletFrontier) -> anyhow::Result<()> {
+        let wallet_id = self.full_viewing_key().await?.wallet_id();
+        if frontier.wallet_id != wallet_id {
+            anyhow::bail!(
+                "frontier was exported for wallet {}, not this wallet {}",
+                frontier.wallet_id,
+                wallet_id
+            );
+        }
+        if let Some(last_sync_height) = self.last_sync_height().await? {
+            if last_sync_height >= frontier.height {
+                anyhow::bail!(
+                    "frontier is at height {}, but this wallet has already synced to height {}",
+                    frontier.height,
+                    last_sync_height
+                );
+            }
+        }
+        frontier.check_witnesses()?;
+
+        let pool = self.pool.clone();
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let mut dbtx = lock.transaction()?;
+
+            // Replace the state commitment tree, which is written incrementally, so has to be
+            // cleared first.
+            dbtx.execute_batch(
+                "DELETE FROM sct_hashes;
+                DELETE FROM sct_commitments;
+                UPDATE sct_position SET position = 0;
+                UPDATE sct_forgotten SET forgotten = 0;
+                DELETE FROM spendable_notes;
+                DELETE FROM swaps;",
+            )?;
+            frontier.sct.to_writer(&mut TreeStore(&mut dbtx))?;
+
+            for note_record in &frontier.notes {
+                let tx_hash = match note_record.source {
+                    CommitmentSource::Transaction { id } => id,
+                    _ => None,
+                };
+                Storage::record_note_inner(&dbtx, &note_record.note)?;
+                dbtx.execute(
+                    "INSERT INTO spendable_notes
+                    (note_commitment, nullifier, position, height_created, address_index, source, height_spent, tx_hash)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL, ?7)",
+                    (
+                        note_record.note_commitment.0.to_bytes().to_vec(),
+                        note_record.nullifier.to_bytes().to_vec(),
+                        u64::from(note_record.position) as i64,
+                        note_record.height_created as i64,
+                        note_record.address_index.to_bytes().to_vec(),
+                        note_record.source.encode_to_vec(),
+                        tx_hash,
+                    ),
+                )?;
+            }
+
+            for swap in &frontier.swaps {
+                dbtx.execute(
+                    "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source)
+                    VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)",
+                    (
+                        swap.swap_commitment.0.to_bytes().to_vec(),
+                        swap.swap.encode_to_vec(),
+                        u64::from(swap.position) as i64,
+                        swap.nullifier.to_bytes().to_vec(),
+                        swap.output_data.encode_to_vec(),
+                        swap.source.encode_to_vec(),
+                    ),
+                )?;
+            }
+
+            dbtx.execute(
+                "INSERT INTO kv (k, v) VALUES ('fmd_params', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [frontier.fmd_parameters.encode_to_vec()],
+            )?;
+            dbtx.execute(
+                "INSERT INTO kv (k, v) VALUES ('gas_prices', ?1)
+                ON CONFLICT(k) DO UPDATE SET v = excluded.v",
+                [frontier.gas_prices.encode_to_vec()],
+            )?;
+            dbtx.execute(
+                "UPDATE sync_height SET height = ?1",
+                [frontier.height as i64],
+            )?;
+
+            dbtx.commit()?;
+            anyhow::Ok(())
+        })
+        .await??;
+
+        // Any empty blocks the worker recorded only in memory are superseded by the frontier.
+        *self.uncommitted_height.lock() = None;
+
+        Ok(())
+    }
+
+    /// Parses a known asset from a row returned by a query starting with [`ASSETS_QUERY`].
+    fn asset_from_row(row: &r2d2_sqlite::rusqlite::Row<'_>) -> anyhow::Result<Metadata> {
+        let denom: String = row.get("denom")?;
+        let icon_uri: Option<String> = row.get("icon_uri")?;
+        let docs_uri: Option<String> = row.get("docs_uri")?;
+
+        let denom_metadata = asset::REGISTRY
+            .parse_denom(&denom)
+            .ok_or_else(|| anyhow::anyhow!("invalid denomination {}", denom))?;
+        if icon_uri.is_none() && docs_uri.is_none() {
+            return Ok(denom_metadata);
+        }
+        denom_metadata.with_uris(icon_uri, docs_uri)
+    }
+
     fn record_note_inner(
         dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
         note: &Note,
@@ -1125,6 +2398,21 @@ impl Storage {
         Ok(())
     }
 
+    /// Records a transaction's use of an address, if it is one of our ephemeral addresses.
+    fn record_ephemeral_address_use(
+        dbtx: &r2d2_sqlite::rusqlite::Transaction<'_>,
+        address: &Address,
+        tx_hash: &[u8],
+    ) -> anyhow::Result<()> {
+        dbtx.execute(
+            "INSERT OR IGNORE INTO ephemeral_address_uses (address, tx_hash)
+                SELECT address, ?2 FROM ephemeral_addresses WHERE address = ?1",
+            (address.to_vec(), tx_hash),
+        )?;
+
+        Ok(())
+    }
+
     pub async fn give_advice(&self, note: Note) -> anyhow::Result<()> {
         let pool = self.pool.clone();
         let mut lock = pool.get()?;
@@ -1311,10 +2599,22 @@ impl Storage {
                 // Record the inner note data in the notes table
                 Storage::record_note_inner(&dbtx, &note_record.note)?;
 
+                // Sending a note to an ephemeral address is a use of that address.
+                if note_record.address_index.is_ephemeral() {
+                    if let Some(tx_hash) = tx_hash {
+                        Storage::record_ephemeral_address_use(
+                            &dbtx,
+                            &note_record.note.address(),
+                            &tx_hash,
+                        )?;
+                    }
+                }
+
                 dbtx.execute(
                     "INSERT INTO spendable_notes
                     (note_commitment, nullifier, position, height_created, address_index, source, height_spent, tx_hash)
-                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL, ?7)",
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, NULL, ?7)
+                    ON CONFLICT DO NOTHING",
                     (
                         &note_commitment,
                         &nullifier,
@@ -1339,7 +2639,8 @@ impl Storage {
 
                 dbtx.execute(
                     "INSERT INTO swaps (swap_commitment, swap, position, nullifier, output_data, height_claimed, source)
-                    VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)",
+                    VALUES (?1, ?2, ?3, ?4, ?5, NULL, ?6)
+                    ON CONFLICT DO NOTHING",
                     (
                         &swap_commitment,
                         &swap_bytes,
@@ -1423,6 +2724,30 @@ impl Storage {
             // Update SCT table with current SCT state
             new_sct.to_writer(&mut TreeStore(&mut dbtx))?;
 
+            // Remember the anchor after this block, to detect if the chain forks away from it.
+            dbtx.execute(
+                "INSERT OR REPLACE INTO recent_anchors (height, anchor) VALUES (?1, ?2)",
+                (filtered_block.height as i64, new_sct.root().encode_to_vec()),
+            )?;
+            dbtx.execute(
+                "DELETE FROM recent_anchors WHERE height NOT IN
+                    (SELECT height FROM recent_anchors ORDER BY height DESC LIMIT ?1)",
+                [RECENT_ANCHORS],
+            )?;
+
+            // Remember the anchor after each epoch too, for rescans to restart from.
+            if filtered_block.epoch_end {
+                record_epoch_anchor(&dbtx, filtered_block.height, &new_sct)?;
+            }
+
+            // Record the time of the block, if it has any transactions to show it with
+            if let Some(block_time) = filtered_block.block_time.filter(|_| !transactions.is_empty()) {
+                dbtx.execute(
+                    "INSERT OR REPLACE INTO block_times (height, block_time) VALUES (?1, ?2)",
+                    (filtered_block.height as i64, block_time.to_rfc3339()),
+                )?;
+            }
+
             // Record all transactions
             for transaction in transactions {
                 let tx_bytes = transaction.encode_to_vec();
@@ -1435,15 +2760,31 @@ impl Storage {
                 tracing::debug!(tx_hash = ?hex::encode(tx_hash), "recording extended transaction");
 
                 dbtx.execute(
-                    "INSERT INTO tx (tx_hash, tx_bytes, block_height, return_address) VALUES (?1, ?2, ?3, ?4)",
+                    "INSERT INTO tx (tx_hash, tx_bytes, block_height, return_address) VALUES (?1, ?2, ?3, ?4)
+                    ON CONFLICT DO NOTHING",
                     (&tx_hash, &tx_bytes, tx_block_height, return_address),
                 )?;
 
+                // Naming an ephemeral address as a return address is a use of that address.
+                let return_addresses = transaction
+                    .decrypt_memo(&fvk)
+                    .ok()
+                    .map(|memo| memo.return_address())
+                    .into_iter()
+                    .chain(transaction.actions().filter_map(|action| match action {
+                        Action::Ics20Withdrawal(withdrawal) => Some(withdrawal.return_address),
+                        _ => None,
+                    }));
+                for address in return_addresses {
+                    Storage::record_ephemeral_address_use(&dbtx, &address, tx_hash)?;
+                }
+
                 // Associate all of the spent nullifiers with the transaction by hash.
                 for nf in transaction.spent_nullifiers() {
                     let nf_bytes = nf.0.to_bytes().to_vec();
                     dbtx.execute(
-                        "INSERT INTO tx_by_nullifier (nullifier, tx_hash) VALUES (?1, ?2)",
+                        "INSERT INTO tx_by_nullifier (nullifier, tx_hash) VALUES (?1, ?2)
+                        ON CONFLICT DO NOTHING",
                         (&nf_bytes, &tx_hash),
                     )?;
                 }
@@ -1598,3 +2939,20 @@ impl Storage {
         Ok(records)
     }
 }
+
+/// Records the anchor and position of `sct` after the epoch ending at `height`.
+fn record_epoch_anchor(dbtx: &Connection, height: u64, sct: &tct::Tree) -> anyhow::Result<()> {
+    // A full tree can't be synced past, so there's no point restarting from it.
+    let Some(position) = sct.position() else {
+        return Ok(());
+    };
+    dbtx.execute(
+        "INSERT OR REPLACE INTO epoch_anchors (height, position, anchor) VALUES (?1, ?2, ?3)",
+        (
+            i64::try_from(height)?,
+            i64::try_from(u64::from(position))?,
+            sct.root().encode_to_vec(),
+        ),
+    )?;
+    Ok(())
+}
@@ -0,0 +1,98 @@
+//! Encryption of view databases at rest, using SQLCipher.
+//!
+//! SQLCipher derives the database key from the passphrase itself (with PBKDF2 and a random
+//! per-database salt), so the passphrase is handed to it as-is. Encryption needs a client built
+//! with the `sqlcipher` feature: with plain SQLite, setting a key is silently ignored, so it's
+//! checked for explicitly rather than leaving the database unencrypted.
+
+use anyhow::Context;
+use camino::Utf8Path;
+use r2d2_sqlite::rusqlite::{Connection, OpenFlags, OptionalExtension};
+use tokio::task::spawn_blocking;
+
+use super::Storage;
+
+impl Storage {
+    /// Encrypts the unencrypted view database at `path` with `passphrase`, keeping all of its
+    /// data.
+    ///
+    /// The data is exported into a new encrypted database, which then replaces the old one. From
+    /// then on, the database must be loaded with the same passphrase.
+    ///
+    /// This must not be called while a view service is using the database.
+    pub async fn encrypt(path: impl AsRef<Utf8Path>, passphrase: String) -> anyhow::Result<()> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || encrypt_blocking(&path, &passphrase)).await?
+    }
+}
+
+fn encrypt_blocking(path: &Utf8Path, passphrase: &str) -> anyhow::Result<()> {
+    let plain = open(path)?;
+    ensure_supported(&plain)?;
+    // Fold the write-ahead log into the database file, so that it holds all of the data.
+    plain
+        .execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")
+        .context("can't read the view database: it may already be encrypted")?;
+
+    let encrypting_path = path.with_extension("sqlite.encrypting");
+    if encrypting_path.exists() {
+        std::fs::remove_file(&encrypting_path)?;
+    }
+    plain.execute(
+        "ATTACH DATABASE ?1 AS encrypted KEY ?2",
+        [encrypting_path.as_str(), passphrase],
+    )?;
+    plain.query_row("SELECT sqlcipher_export('encrypted')", (), |_| Ok(()))?;
+    plain.execute("DETACH DATABASE encrypted", ())?;
+    drop(plain);
+
+    std::fs::rename(&encrypting_path, path)?;
+    Ok(())
+}
+
+/// Sets the key used to read and write `conn`, which must happen before anything else touches
+/// the database.
+pub(super) fn apply_passphrase(
+    conn: &Connection,
+    passphrase: &str,
+) -> r2d2_sqlite::rusqlite::Result<()> {
+    conn.pragma_update(None, "key", passphrase)
+}
+
+/// Checks that the database at `path` can be read with `passphrase`, or without one if it's
+/// `None`.
+///
+/// Connections are opened lazily by the pool, which only times out if they fail, so checking up
+/// front gives a useful error for a wrong or missing passphrase.
+pub(super) fn check_passphrase(path: &Utf8Path, passphrase: Option<&str>) -> anyhow::Result<()> {
+    let conn = open(path)?;
+    if let Some(passphrase) = passphrase {
+        ensure_supported(&conn)?;
+        apply_passphrase(&conn, passphrase)?;
+    }
+    conn.query_row("SELECT count(*) FROM sqlite_master", (), |_| Ok(()))
+        .context(match passphrase {
+            Some(_) => "can't decrypt the view database: the passphrase is wrong, or the database isn't encrypted",
+            None => "can't read the view database: if it's encrypted, it needs a passphrase",
+        })
+}
+
+fn open(path: &Utf8Path) -> anyhow::Result<Connection> {
+    // As with the connection pool, don't allow opening URIs.
+    Ok(Connection::open_with_flags(
+        path,
+        OpenFlags::default() & !OpenFlags::SQLITE_OPEN_URI,
+    )?)
+}
+
+fn ensure_supported(conn: &Connection) -> anyhow::Result<()> {
+    let cipher_version = conn
+        .query_row("PRAGMA cipher_version", (), |row| row.get::<_, String>(0))
+        .optional()?;
+    if cipher_version.is_none() {
+        anyhow::bail!(
+            "view database encryption isn't supported by this client: it must be built with the `sqlcipher` feature"
+        );
+    }
+    Ok(())
+}
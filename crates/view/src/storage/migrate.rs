@@ -0,0 +1,219 @@
+//! In-place upgrades of view databases created by older client versions.
+
+use anyhow::Context;
+use camino::Utf8Path;
+use r2d2_sqlite::rusqlite::Connection;
+use tokio::task::spawn_blocking;
+
+use super::{Storage, SCHEMA_HASH};
+
+/// The tables recording which client created a database, which are rewritten rather than copied.
+const VERSION_TABLES: &[&str] = &["schema_hash", "client_version"];
+
+/// The outcome of migrating a view database with [`Storage::migrate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Migration {
+    /// The database already uses the current schema, and was left untouched.
+    UpToDate,
+    /// The database was rewritten with the current schema, keeping all of its data.
+    Migrated {
+        /// The version of the client that created the database.
+        client_version: String,
+    },
+    /// Some of the database's tables were removed or redefined in the current schema, so its
+    /// data can't be carried over, and it must be reset and rescanned instead. The database was
+    /// left untouched.
+    Incompatible {
+        /// The version of the client that created the database.
+        client_version: String,
+        /// The tables whose definitions changed.
+        tables: Vec<String>,
+    },
+}
+
+impl Storage {
+    /// Upgrades the view database at `path` to the current schema, if it was created by a client
+    /// with a different one.
+    ///
+    /// Schema changes that only add tables, or touch indices or comments, leave the stored data
+    /// valid, so the data is copied into a fresh database with the current schema, which then
+    /// replaces the old one. Added tables start out empty. If any table was removed or
+    /// redefined, nothing is changed and [`Migration::Incompatible`] is returned.
+    ///
+    /// This must not be called while a view service is using the database.
+    pub async fn migrate(path: impl AsRef<Utf8Path>) -> anyhow::Result<Migration> {
+        let path = path.as_ref().to_owned();
+        spawn_blocking(move || migrate_blocking(&path)).await?
+    }
+}
+
+fn migrate_blocking(path: &Utf8Path) -> anyhow::Result<Migration> {
+    let old = Connection::open(path)?;
+    // Fold the write-ahead log into the database file, so that it holds all of the data.
+    old.execute_batch("PRAGMA wal_checkpoint(TRUNCATE);")?;
+    // Databases from very old clients may predate these tables altogether.
+    let schema_hash = old
+        .query_row("SELECT schema_hash FROM schema_hash", (), |row| {
+            row.get::<_, String>(0)
+        })
+        .ok();
+    if schema_hash.as_deref() == Some(SCHEMA_HASH.as_str()) {
+        return Ok(Migration::UpToDate);
+    }
+    let client_version = old
+        .query_row("SELECT client_version FROM client_version", (), |row| {
+            row.get::<_, String>(0)
+        })
+        .unwrap_or_else(|_| "unknown".to_string());
+    drop(old);
+
+    let migrating_path = path.with_extension("sqlite.migrating");
+    if migrating_path.exists() {
+        std::fs::remove_file(&migrating_path)?;
+    }
+    let mut new = Connection::open(&migrating_path)?;
+    new.execute_batch(include_str!("schema.sql"))?;
+    new.execute("ATTACH DATABASE ?1 AS old", [path.as_str()])?;
+
+    let old_tables = table_names(&new, "old")?;
+    let (tables, new_tables): (Vec<_>, Vec<_>) = table_names(&new, "main")?
+        .into_iter()
+        .partition(|table| old_tables.contains(table));
+    let mut incompatible = old_tables
+        .into_iter()
+        .filter(|table| !tables.contains(table))
+        .collect::<Vec<_>>();
+    // Tables added since the old schema have no data to copy, so they start out empty.
+    tracing::debug!(?new_tables, "adding tables to view database");
+    for table in &tables {
+        if columns(&new, "main", table)? != columns(&new, "old", table)? {
+            incompatible.push(table.clone());
+        }
+    }
+    if !incompatible.is_empty() {
+        drop(new);
+        std::fs::remove_file(&migrating_path)?;
+        return Ok(Migration::Incompatible {
+            client_version,
+            tables: incompatible,
+        });
+    }
+
+    let tx = new.transaction()?;
+    for table in &tables {
+        // The schema seeds some tables with initial values, which the old data replaces.
+        tx.execute(&format!("DELETE FROM main.{table}"), ())?;
+        tx.execute(
+            &format!("INSERT INTO main.{table} SELECT * FROM old.{table}"),
+            (),
+        )
+        .with_context(|| format!("failed to copy table {table}"))?;
+    }
+    tx.execute(
+        "INSERT INTO schema_hash (schema_hash) VALUES (?1)",
+        [&*SCHEMA_HASH],
+    )?;
+    tx.execute(
+        "INSERT INTO client_version (client_version) VALUES (?1)",
+        [env!("CARGO_PKG_VERSION")],
+    )?;
+    tx.commit()?;
+    new.execute("DETACH DATABASE old", ())?;
+    drop(new);
+
+    for suffix in ["-wal", "-shm"] {
+        let sidecar = format!("{path}{suffix}");
+        if Utf8Path::new(&sidecar).exists() {
+            std::fs::remove_file(&sidecar)?;
+        }
+    }
+    std::fs::rename(&migrating_path, path)?;
+
+    Ok(Migration::Migrated { client_version })
+}
+
+/// Lists the tables holding data in the given attached database.
+fn table_names(conn: &Connection, schema: &str) -> anyhow::Result<Vec<String>> {
+    let mut stmt = conn.prepare(&format!(
+        "SELECT name FROM {schema}.sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'"
+    ))?;
+    let names = stmt
+        .query_map((), |row| row.get::<_, String>(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(names
+        .into_iter()
+        .filter(|name| !VERSION_TABLES.contains(&name.as_str()))
+        .collect())
+}
+
+/// Describes the columns of a table in order, as name, type, nullability, default and key.
+fn columns(
+    conn: &Connection,
+    schema: &str,
+    table: &str,
+) -> anyhow::Result<Vec<(String, String, bool, Option<String>, i64)>> {
+    let mut stmt = conn.prepare(&format!("PRAGMA {schema}.table_info({table})"))?;
+    let columns = stmt
+        .query_map((), |row| {
+            Ok((
+                row.get("name")?,
+                row.get("type")?,
+                row.get("notnull")?,
+                row.get("dflt_value")?,
+                row.get("pk")?,
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn migrates_only_compatible_schemata() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = Utf8Path::from_path(dir.path())
+            .expect("temporary directory is utf-8")
+            .join("view.sqlite");
+
+        // A database from a client whose schema differed only in an index and a missing table.
+        let conn = Connection::open(&path)?;
+        conn.execute_batch(include_str!("schema.sql"))?;
+        conn.execute_batch("CREATE INDEX stale_index ON sync_height (height);")?;
+        conn.execute_batch("DROP TABLE block_times;")?;
+        conn.execute("INSERT INTO schema_hash (schema_hash) VALUES ('old')", ())?;
+        conn.execute(
+            "INSERT INTO client_version (client_version) VALUES ('0.1.0')",
+            (),
+        )?;
+        conn.execute("INSERT INTO sync_height (height) VALUES (42)", ())?;
+        drop(conn);
+
+        assert_eq!(
+            Storage::migrate(&path).await?,
+            Migration::Migrated {
+                client_version: "0.1.0".to_string()
+            }
+        );
+        assert_eq!(Storage::migrate(&path).await?, Migration::UpToDate);
+        let height: i64 =
+            Connection::open(&path)?
+                .query_row("SELECT height FROM sync_height", (), |row| row.get(0))?;
+        assert_eq!(height, 42);
+
+        // A database from a client with a redefined table.
+        let conn = Connection::open(&path)?;
+        conn.execute_batch("DROP TABLE sync_height; CREATE TABLE sync_height (h TEXT);")?;
+        conn.execute("UPDATE schema_hash SET schema_hash = 'old'", ())?;
+        drop(conn);
+
+        assert!(matches!(
+            Storage::migrate(&path).await?,
+            Migration::Incompatible { tables, .. } if tables == ["sync_height"]
+        ));
+
+        Ok(())
+    }
+}
@@ -18,6 +18,13 @@ CREATE TABLE assets (
     denom                   TEXT NOT NULL
 );
 
+-- the URIs from the metadata of known assets registered on chain, if they have any
+CREATE TABLE asset_uris (
+    asset_id                BLOB PRIMARY KEY NOT NULL,
+    icon_uri                TEXT,
+    docs_uri                TEXT
+);
+
 -- the shape information about the sct
 CREATE TABLE sct_position ( position BIGINT );
 INSERT INTO sct_position VALUES ( 0 ); -- starting position is 0
@@ -56,6 +63,12 @@ CREATE TABLE tx (
     return_address          BLOB
 );
 
+-- the times of blocks containing known transactions, as RFC3339 strings
+CREATE TABLE block_times (
+    height                  BIGINT PRIMARY KEY NOT NULL,
+    block_time              TEXT NOT NULL
+);
+
 -- This table just records the mapping from note commitments to note plaintexts.
 -- This is also used as a way to give advice about out-of-band notes during scanning,
 -- by allowing the user to add notes to the database before they are scanned.
@@ -123,6 +136,68 @@ CREATE TABLE swaps (
 
 CREATE INDEX swaps_nullifier_idx ON swaps (nullifier);
 
+-- This table records the ephemeral addresses handed out by the view service, along
+-- with the index they were derived from, so that their reuse can be audited.
+CREATE TABLE ephemeral_addresses (
+    address                 BLOB PRIMARY KEY NOT NULL,
+    account                 BIGINT NOT NULL,
+    address_index           BLOB NOT NULL,
+    height_generated        BIGINT NOT NULL
+);
+
+-- This table records the transactions that used an ephemeral address, either by
+-- sending notes to it or by naming it as a return address.
+CREATE TABLE ephemeral_address_uses (
+    address                 BLOB NOT NULL,
+    tx_hash                 BLOB NOT NULL,
+    PRIMARY KEY (address, tx_hash)
+);
+
+-- This table records the swaps submitted in a bundle with their claims, which
+-- should be claimed automatically as soon as they're detected.
+CREATE TABLE bundled_swap_claims (
+    swap_commitment         BLOB PRIMARY KEY NOT NULL
+);
+
+-- This table records the transactions broadcast with a client-chosen idempotency
+-- key, so that retrying a broadcast re-sends the same transaction rather than a
+-- new one spending different notes.
+CREATE TABLE idempotent_transactions (
+    idempotency_key         TEXT PRIMARY KEY NOT NULL,
+    tx_hash                 BLOB NOT NULL,
+    tx_bytes                BLOB NOT NULL
+);
+
+-- This table records the transactions accepted for broadcast by a view service
+-- that keeps a broadcast queue, which re-sends them with backoff until they're
+-- detected on chain, expire, or conflict with another transaction.
+CREATE TABLE broadcast_queue (
+    tx_hash                 BLOB PRIMARY KEY NOT NULL,
+    tx_bytes                BLOB NOT NULL,
+    status                  TEXT NOT NULL,
+    attempts                BIGINT NOT NULL,
+    last_error              TEXT,
+    expiry_height           BIGINT NOT NULL,
+    detection_height        BIGINT,
+    -- The unix time, in seconds, at which to next send the transaction.
+    next_attempt            BIGINT NOT NULL
+);
+
+-- The roots of the state commitment tree after the most recently recorded blocks,
+-- compared against the chain's anchors to detect when it forks away from them.
+CREATE TABLE recent_anchors (
+    height                  BIGINT PRIMARY KEY NOT NULL,
+    anchor                  BLOB NOT NULL
+);
+
+-- The roots and positions of the state commitment tree after each recorded block that
+-- ended an epoch, from which rescans restart without going back to genesis.
+CREATE TABLE epoch_anchors (
+    height                  BIGINT PRIMARY KEY NOT NULL,
+    position                BIGINT NOT NULL,
+    anchor                  BLOB NOT NULL
+);
+
 CREATE TABLE positions (
      position_id            BLOB PRIMARY KEY NOT NULL,
      position_state         TEXT NOT NULL,
@@ -0,0 +1,157 @@
+use penumbra_keys::test_keys;
+
+use super::*;
+
+/// A node that is never connected to, since nothing under test needs one.
+fn node() -> Url {
+    Url::parse("http://127.0.0.1:1").expect("valid url")
+}
+
+async fn storage_synced_to(height: Option<u64>) -> anyhow::Result<Storage> {
+    let storage = Storage::initialize(
+        None::<&Utf8Path>,
+        test_keys::FULL_VIEWING_KEY.clone(),
+        AppParameters::default(),
+        None,
+    )
+    .await?;
+    if let Some(height) = height {
+        storage
+            .pool
+            .get()?
+            .execute("UPDATE sync_height SET height = ?1", [height as i64])?;
+    }
+    Ok(storage)
+}
+
+#[tokio::test]
+async fn roll_back_only_from_synced_heights() -> anyhow::Result<()> {
+    // Nothing was synced, so there's nothing to roll back.
+    let storage = storage_synced_to(None).await?;
+    assert!(storage.roll_back(node(), 1).await.is_err());
+
+    // Rolling back from a block that wasn't synced isn't rolling back a fork, so it's refused
+    // and nothing changes.
+    let storage = storage_synced_to(Some(10)).await?;
+    assert!(storage.roll_back(node(), 11).await.is_err());
+    assert_eq!(storage.last_sync_height().await?, Some(10));
+
+    // A fork at a synced block is rolled back.
+    assert_eq!(storage.roll_back(node(), 10).await?, None);
+
+    // A chain with nothing in common with the synced blocks is rolled back to genesis.
+    let storage = storage_synced_to(Some(10)).await?;
+    assert_eq!(storage.roll_back(node(), 0).await?, None);
+    assert_eq!(storage.last_sync_height().await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn birthday_is_only_recorded_when_blocks_are_skipped() -> anyhow::Result<()> {
+    // Without a checkpoint, the wallet is scanned from genesis, so its birthday is reported as 0.
+    let storage = storage_synced_to(None).await?;
+    storage.record_birthday_height(node(), 100, None).await?;
+    assert_eq!(storage.birthday_height().await?, None);
+
+    // A checkpoint after the birthday can't be used.
+    let checkpoint = TrustedCheckpoint {
+        height: 101,
+        anchor: tct::Tree::new().root(),
+    };
+    assert!(storage
+        .record_birthday_height(node(), 100, Some(checkpoint))
+        .await
+        .is_err());
+    assert_eq!(storage.birthday_height().await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rescan_is_scoped_to_the_requested_height() -> anyhow::Result<()> {
+    // Nothing was synced from the requested height, so nothing is cleared.
+    let storage = storage_synced_to(None).await?;
+    assert_eq!(
+        storage.rescan(node(), 0, None).await?,
+        RescanStart::Unchanged
+    );
+    let storage = storage_synced_to(Some(10)).await?;
+    assert_eq!(
+        storage.rescan(node(), 11, None).await?,
+        RescanStart::Unchanged
+    );
+    assert_eq!(storage.last_sync_height().await?, Some(10));
+
+    // Rescans restart from the latest epoch that ended before the requested height.
+    record_epoch_anchor(&storage.pool.get()?, 3, &tct::Tree::new())?;
+    record_epoch_anchor(&storage.pool.get()?, 7, &tct::Tree::new())?;
+    for (from_height, epoch_end) in [(3, None), (7, Some(3)), (11, Some(7))] {
+        let anchor = storage.epoch_anchor_before(from_height, None).await?;
+        assert_eq!(anchor.map(|anchor| anchor.height), epoch_end);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn rescan_without_an_earlier_epoch_restarts_from_genesis() -> anyhow::Result<()> {
+    let storage = storage_synced_to(Some(10)).await?;
+    record_epoch_anchor(&storage.pool.get()?, 7, &tct::Tree::new())?;
+
+    // The only epoch ended after the requested height, so there's no checkpoint to fetch.
+    assert_eq!(storage.rescan(node(), 5, None).await?, RescanStart::Genesis);
+    assert_eq!(storage.last_sync_height().await?, None);
+    // The epoch will be synced again, so its anchor is forgotten.
+    assert_eq!(storage.epoch_anchor_before(11, None).await?, None);
+
+    Ok(())
+}
+
+/// A transaction that expires at `expiry_height`, which only serves to be recorded.
+fn transaction_expiring_at(expiry_height: u64) -> Transaction {
+    let mut transaction = Transaction::default();
+    transaction
+        .transaction_body
+        .transaction_parameters
+        .expiry_height = expiry_height;
+    transaction
+}
+
+#[tokio::test]
+async fn idempotent_transaction_is_released_once_it_cannot_land() -> anyhow::Result<()> {
+    let storage = storage_synced_to(Some(10)).await?;
+    let key = || "retry-me".to_string();
+    let original = transaction_expiring_at(5);
+    let rebuilt = transaction_expiring_at(20);
+
+    // Retries get the transaction recorded first.
+    let recorded = storage
+        .record_idempotent_transaction(key(), original.clone())
+        .await?;
+    assert_eq!(recorded.id(), original.id());
+    let recorded = storage
+        .record_idempotent_transaction(key(), rebuilt.clone())
+        .await?;
+    assert_eq!(recorded.id(), original.id());
+
+    // The chain passed its expiry height without it being detected, so it can't land, and
+    // re-sending it is pointless.
+    assert_eq!(
+        crate::broadcast_queue::settled(&storage, &original, 10).await?,
+        Some(QueueStatus::Expired)
+    );
+    assert_eq!(
+        crate::broadcast_queue::settled(&storage, &rebuilt, 10).await?,
+        None
+    );
+
+    // Once it's released, retrying records the rebuilt transaction instead.
+    storage.forget_idempotent_transaction(key()).await?;
+    let recorded = storage
+        .record_idempotent_transaction(key(), rebuilt.clone())
+        .await?;
+    assert_eq!(recorded.id(), rebuilt.id());
+
+    Ok(())
+}
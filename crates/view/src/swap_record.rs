@@ -3,6 +3,7 @@ use penumbra_proto::{view::v1 as pb, DomainType};
 use penumbra_sct::{CommitmentSource, Nullifier};
 use penumbra_tct as tct;
 
+#[cfg(feature = "rpc")]
 use r2d2_sqlite::rusqlite::Row;
 use serde::{Deserialize, Serialize};
 
@@ -68,6 +69,7 @@ impl TryFrom<pb::SwapRecord> for SwapRecord {
     }
 }
 
+#[cfg(feature = "rpc")]
 impl TryFrom<&Row<'_>> for SwapRecord {
     type Error = anyhow::Error;
 
@@ -1,15 +1,17 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
+use penumbra_asset::asset;
 use penumbra_compact_block::{CompactBlock, StatePayload};
 use penumbra_dex::swap::{SwapPayload, SwapPlaintext};
 use penumbra_fee::GasPrices;
-use penumbra_keys::FullViewingKey;
+use penumbra_keys::{keys::AddressIndex, FullViewingKey};
 use penumbra_sct::Nullifier;
 use penumbra_shielded_pool::{fmd, Note, NotePayload};
 use penumbra_tct::{self as tct, StateCommitment};
+use serde::{Deserialize, Serialize};
 use tracing::Instrument;
 
-use crate::{SpendableNoteRecord, Storage, SwapRecord};
+use crate::{metrics, SpendableNoteRecord, Storage, SwapRecord};
 
 /// Contains the results of scanning a single block.
 #[derive(Debug, Clone)]
@@ -18,14 +20,58 @@ pub struct FilteredBlock {
     pub new_swaps: BTreeMap<StateCommitment, SwapRecord>,
     pub spent_nullifiers: Vec<Nullifier>,
     pub height: u64,
+    /// Whether the block ended an epoch.
+    pub epoch_end: bool,
     pub fmd_parameters: Option<fmd::Parameters>,
     pub app_parameters_updated: bool,
     pub gas_prices: Option<GasPrices>,
+    pub block_time: Option<tendermint::Time>,
+}
+
+/// Restricts which detected notes are stored, for special-purpose wallets that only care about
+/// some of their accounts and assets, such as a liquidity bot trading a single pair.
+///
+/// A viewing key trial-decrypts notes for all of its accounts at once, so every note is still
+/// decrypted, but notes the filter doesn't allow are dropped right after, and are neither
+/// witnessed nor stored.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncFilter {
+    /// If set, only notes sent to these accounts are stored.
+    pub accounts: Option<BTreeSet<u32>>,
+    /// If set, only notes of these assets are stored.
+    pub assets: Option<BTreeSet<asset::Id>>,
+}
+
+impl SyncFilter {
+    /// Whether a note of `asset_id` sent to `address_index` is stored.
+    pub fn allows(&self, address_index: AddressIndex, asset_id: asset::Id) -> bool {
+        self.accounts
+            .as_ref()
+            .map_or(true, |accounts| accounts.contains(&address_index.account))
+            && self
+                .assets
+                .as_ref()
+                .map_or(true, |assets| assets.contains(&asset_id))
+    }
+
+    /// Whether every note `other` allows is also allowed by this filter, so that switching from
+    /// this filter to `other` doesn't need any blocks to be scanned again.
+    pub fn includes(&self, other: &SyncFilter) -> bool {
+        fn includes<T: Ord>(this: &Option<BTreeSet<T>>, other: &Option<BTreeSet<T>>) -> bool {
+            match (this, other) {
+                (None, _) => true,
+                (Some(_), None) => false,
+                (Some(this), Some(other)) => other.is_subset(this),
+            }
+        }
+        includes(&self.accounts, &other.accounts) && includes(&self.assets, &other.assets)
+    }
 }
 
 #[tracing::instrument(skip_all, fields(height = %height))]
 pub async fn scan_block(
     fvk: &FullViewingKey,
+    filter: &SyncFilter,
     state_commitment_tree: &mut tct::Tree,
     CompactBlock {
         height,
@@ -37,6 +83,7 @@ pub async fn scan_block(
         swap_outputs,
         app_parameters_updated,
         gas_prices,
+        block_time,
         // TODO: do we need this, or is there a bug in scan_block?
         // proposal_started,
         ..
@@ -83,6 +130,9 @@ pub async fn scan_block(
             StatePayload::RolledUp { commitment, .. } => unknown_commitments.push(*commitment),
         }
     }
+    metrics::counter!(metrics::VIEW_TRIAL_DECRYPTIONS_TOTAL)
+        .increment((note_decryptions.len() + swap_decryptions.len()) as u64);
+
     // Having started trial decryption in the background, ask the Storage for scanning advice:
     let mut note_advice = storage.scan_advice(unknown_commitments).await?;
     for decryption in note_decryptions {
@@ -93,6 +143,14 @@ pub async fn scan_block(
             note_advice.insert(note.commit(), note);
         }
     }
+    // Drop notes the filter excludes before witnessing anything, so they're treated as if they
+    // weren't ours.
+    note_advice.retain(|_, note| {
+        filter.allows(
+            fvk.incoming().index_for_diversifier(note.diversifier()),
+            note.asset_id(),
+        )
+    });
     let mut swap_advice = BTreeMap::new();
     for decryption in swap_decryptions {
         if let Some(swap) = decryption
@@ -232,9 +290,11 @@ pub async fn scan_block(
         new_swaps,
         spent_nullifiers: filtered_nullifiers,
         height,
+        epoch_end: is_epoch_end,
         fmd_parameters,
         app_parameters_updated,
         gas_prices,
+        block_time,
     };
 
     Ok(result)
@@ -14,4 +14,23 @@ pub struct TransactionInfo {
     pub perspective: TransactionPerspective,
     // A precomputed transaction view of `transaction` from `perspective`, included for convenience of clients that don't have support for viewing transactions on their own.
     pub view: TransactionView,
+    // The time of the block the transaction was included in, if known.
+    pub block_time: Option<tendermint::Time>,
+}
+
+pub(crate) fn time_to_proto(time: tendermint::Time) -> pbjson_types::Timestamp {
+    let nanos = time.unix_timestamp_nanos();
+    pbjson_types::Timestamp {
+        seconds: nanos.div_euclid(1_000_000_000) as i64,
+        nanos: nanos.rem_euclid(1_000_000_000) as i32,
+    }
+}
+
+pub(crate) fn time_from_proto(
+    timestamp: pbjson_types::Timestamp,
+) -> anyhow::Result<tendermint::Time> {
+    Ok(tendermint::Time::from_unix_timestamp(
+        timestamp.seconds,
+        timestamp.nanos.try_into()?,
+    )?)
 }
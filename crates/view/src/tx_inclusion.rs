@@ -0,0 +1,194 @@
+use anyhow::Context;
+use penumbra_proto::{util::tendermint_proxy::v1 as pb, DomainType};
+use penumbra_transaction::{txhash::TransactionId, Transaction};
+use prost::Message;
+use sha2::{Digest, Sha256};
+use tendermint::{block::signed_header::SignedHeader, Hash};
+
+/// A transaction whose inclusion in a block has been checked against the block's signed header.
+#[derive(Clone, Debug)]
+pub struct VerifiedTxInclusion {
+    pub transaction: Transaction,
+    pub transaction_id: TransactionId,
+    pub height: u64,
+    /// The position of the transaction in the block.
+    pub index: u64,
+    /// The header of the block including the transaction, and the commit signing it.
+    pub signed_header: SignedHeader,
+}
+
+/// Checks a response from the Tendermint proxy's `GetTxInclusionProof` method, returning the
+/// transaction it proves was included in a block.
+///
+/// This checks that the transaction is committed to by the data hash of the block's header, that
+/// the commit signs that header, and that the transaction executed successfully. It doesn't check
+/// the commit's signatures: the caller must still establish that the header is canonical, by
+/// verifying it with a light client, or comparing its hash to one from a node it trusts.
+///
+/// The response's result code and events aren't covered by the proof, so the success of the
+/// transaction is only as trustworthy as the node reporting it, and the events aren't checked at
+/// all. To verify a payment, inspect the returned transaction itself.
+pub fn verify_tx_inclusion(
+    response: &pb::GetTxInclusionProofResponse,
+) -> anyhow::Result<VerifiedTxInclusion> {
+    let tx_proof = response.proof.as_ref().context("missing tx proof")?;
+    let proof = tx_proof.proof.as_ref().context("missing merkle proof")?;
+
+    // CometBFT commits to the hashes of a block's transactions, which are also their IDs.
+    let transaction_id = TransactionId(Sha256::digest(&tx_proof.data).into());
+    anyhow::ensure!(
+        proof.leaf_hash == leaf_hash(&transaction_id.0),
+        "proof is not for transaction {transaction_id}"
+    );
+    anyhow::ensure!(
+        u64::try_from(proof.index).ok() == Some(response.index),
+        "proof is for index {} rather than {}",
+        proof.index,
+        response.index
+    );
+    let root = u64::try_from(proof.total)
+        .ok()
+        .zip(u64::try_from(proof.index).ok())
+        .and_then(|(total, index)| {
+            root_from_aunts(index, total, leaf_hash(&transaction_id.0), &proof.aunts)
+        })
+        .context("malformed merkle proof")?;
+    anyhow::ensure!(
+        tx_proof.root_hash == root,
+        "merkle proof does not match its root hash"
+    );
+
+    let signed_header: SignedHeader = tendermint_proto::types::SignedHeader::decode(
+        response
+            .signed_header
+            .as_ref()
+            .context("missing signed header")?
+            .encode_to_vec()
+            .as_slice(),
+    )?
+    .try_into()?;
+    let header = &signed_header.header;
+    anyhow::ensure!(
+        header.height.value() == response.height,
+        "signed header is for height {} rather than {}",
+        header.height,
+        response.height
+    );
+    anyhow::ensure!(
+        header.data_hash == Some(Hash::Sha256(root)),
+        "transaction is not included in the block at height {}",
+        response.height
+    );
+    anyhow::ensure!(
+        signed_header.commit.block_id.hash == header.hash(),
+        "commit does not sign the header at height {}",
+        response.height
+    );
+
+    anyhow::ensure!(
+        response.code == 0,
+        "transaction {transaction_id} was included at height {}, but failed with code {}",
+        response.height,
+        response.code
+    );
+
+    Ok(VerifiedTxInclusion {
+        transaction: Transaction::decode(tx_proof.data.as_slice())?,
+        transaction_id,
+        height: response.height,
+        index: response.index,
+        signed_header,
+    })
+}
+
+/// The hash of a leaf of a CometBFT merkle tree, as in RFC 6962.
+fn leaf_hash(leaf: &[u8]) -> Vec<u8> {
+    Sha256::new()
+        .chain_update([0])
+        .chain_update(leaf)
+        .finalize()
+        .to_vec()
+}
+
+/// The hash of an inner node of a CometBFT merkle tree, as in RFC 6962.
+fn inner_hash(left: &[u8], right: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([1])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Computes the root of a CometBFT merkle tree of `total` leaves from the hash of the leaf at
+/// `index` and its aunts, the siblings of the nodes on its path to the root, from the bottom up.
+fn root_from_aunts(
+    index: u64,
+    total: u64,
+    leaf_hash: Vec<u8>,
+    aunts: &[Vec<u8>],
+) -> Option<[u8; 32]> {
+    if index >= total {
+        return None;
+    }
+    match (total, aunts.split_last()) {
+        (1, None) => leaf_hash.try_into().ok(),
+        (1, Some(_)) | (_, None) => None,
+        (_, Some((aunt, aunts))) => {
+            // The left subtree holds the largest power of two leaves less than the total.
+            let split = 1 << (63 - (total - 1).leading_zeros());
+            if index < split {
+                let left = root_from_aunts(index, split, leaf_hash, aunts)?;
+                Some(inner_hash(&left, aunt))
+            } else {
+                let right = root_from_aunts(index - split, total - split, leaf_hash, aunts)?;
+                Some(inner_hash(aunt, &right))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes the root of the tree of `leaves`, and the aunts of the leaf at `index`.
+    fn root_and_aunts(leaves: &[Vec<u8>], index: usize) -> ([u8; 32], Vec<Vec<u8>>) {
+        if leaves.len() == 1 {
+            return (
+                leaf_hash(&leaves[0])
+                    .try_into()
+                    .expect("leaf hash is 32 bytes"),
+                Vec::new(),
+            );
+        }
+        let split = 1 << (63 - (leaves.len() as u64 - 1).leading_zeros());
+        let (left, right) = leaves.split_at(split);
+        let (left_root, mut left_aunts) = root_and_aunts(left, index.min(split - 1));
+        let (right_root, mut right_aunts) = root_and_aunts(right, index.saturating_sub(split));
+        let root = inner_hash(&left_root, &right_root);
+        if index < split {
+            left_aunts.push(right_root.to_vec());
+            (root, left_aunts)
+        } else {
+            right_aunts.push(left_root.to_vec());
+            (root, right_aunts)
+        }
+    }
+
+    #[test]
+    fn root_from_aunts_matches_tree() {
+        for total in 1..=9u64 {
+            let leaves: Vec<Vec<u8>> = (0..total).map(|i| vec![i as u8; 32]).collect();
+            for index in 0..total {
+                let (root, aunts) = root_and_aunts(&leaves, index as usize);
+                let leaf = leaf_hash(&leaves[index as usize]);
+                assert_eq!(
+                    root_from_aunts(index, total, leaf.clone(), &aunts),
+                    Some(root)
+                );
+                assert_eq!(root_from_aunts(index + total, total, leaf, &aunts), None);
+            }
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use std::{
     collections::BTreeSet,
+    ops::ControlFlow,
     sync::{Arc, Mutex},
     time::Duration,
 };
 
 use anyhow::Context;
-use penumbra_compact_block::CompactBlock;
-use penumbra_dex::lp::{position, LpNft};
+use ark_std::UniformRand;
+use decaf377::Fq;
+use penumbra_compact_block::{encoding::Decoder, CompactBlock};
+use penumbra_dex::{
+    lp::{position, LpNft},
+    swap_claim::SwapClaimPlan,
+};
 use penumbra_keys::FullViewingKey;
 use penumbra_proto::{
     self as proto,
@@ -15,7 +21,11 @@ use penumbra_proto::{
         component::{
             compact_block::v1::{
                 query_service_client::QueryServiceClient as CompactBlockQueryServiceClient,
-                CompactBlockRangeRequest,
+                CompactBlockEncoding, CompactBlockRangeRequest,
+            },
+            sct::v1::{
+                query_service_client::QueryServiceClient as SctQueryServiceClient,
+                AnchorsByHeightRequest,
             },
             shielded_pool::v1::{
                 query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
@@ -25,26 +35,87 @@ use penumbra_proto::{
     },
 };
 use penumbra_sct::{CommitmentSource, Nullifier};
-use penumbra_transaction::Transaction;
+use penumbra_transaction::{AuthorizationData, Transaction, WitnessData};
 use proto::core::app::v1::TransactionsByHeightRequest;
+use rand_core::OsRng;
 use tokio::sync::{watch, RwLock};
 use tonic::transport::Channel;
 use url::Url;
 
 use crate::{
-    sync::{scan_block, FilteredBlock},
-    Storage,
+    broadcast_queue, metrics,
+    sync::{scan_block, FilteredBlock, SyncFilter},
+    NodePool, Planner, Storage, SwapRecord,
 };
 
+/// How far behind the synced height a node can be while still being assumed to be on the same
+/// chain, and only lagging behind, when none of the recent anchors can be compared against it.
+const MAX_NODE_LAG_BLOCKS: u64 = 100;
+
+/// The state of [`Worker::find_fork`], as it compares the roots recorded after synced blocks
+/// against the chain's anchors, newest first.
+#[derive(Debug, Default)]
+struct ForkSearch {
+    /// The oldest height compared so far whose recorded root differs from the chain's anchor.
+    diverged_at: Option<u64>,
+}
+
+impl ForkSearch {
+    /// Compares the `root` recorded after the block at `height` against the chain's `anchor` there,
+    /// if the node has reached it, and breaks with the height to roll back from once one matches.
+    fn compare(
+        &mut self,
+        height: u64,
+        root: penumbra_tct::Root,
+        anchor: Option<penumbra_tct::Root>,
+    ) -> ControlFlow<Option<u64>> {
+        match anchor {
+            None => ControlFlow::Continue(()),
+            Some(anchor) if anchor == root => {
+                ControlFlow::Break(self.diverged_at.map(|_| height + 1))
+            }
+            Some(anchor) => {
+                tracing::warn!(height, %anchor, %root, "synced block differs from chain");
+                self.diverged_at = Some(height);
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    /// The outcome once no recorded root matched the chain.
+    ///
+    /// If a compared block differs from the chain, there's no common ancestor left to roll back
+    /// to, so everything is rolled back to genesis. Otherwise the node hasn't reached any of the
+    /// compared blocks, which is only fine if it isn't so far behind that it could be on another
+    /// chain.
+    fn finish(self, last_sync_height: u64, node_far_behind: bool) -> anyhow::Result<Option<u64>> {
+        if let Some(height) = self.diverged_at {
+            tracing::warn!(
+                height,
+                "no recorded block matches the chain, rolling back to genesis"
+            );
+            return Ok(Some(0));
+        }
+        if node_far_behind {
+            anyhow::bail!(
+                "node is more than {MAX_NODE_LAG_BLOCKS} blocks behind the synced height \
+                {last_sync_height}, so it can't be checked for a fork"
+            );
+        }
+        Ok(None)
+    }
+}
+
 pub struct Worker {
     storage: Storage,
     sct: Arc<RwLock<penumbra_tct::Tree>>,
     fvk: FullViewingKey, // TODO: notifications (see TODOs on ViewService)
+    sync_filter: SyncFilter,
     error_slot: Arc<Mutex<Option<anyhow::Error>>>,
     sync_height_tx: watch::Sender<u64>,
     /// Tonic channel used to create GRPC clients.
     channel: Channel,
-    node: Url,
+    nodes: NodePool,
 }
 
 impl Worker {
@@ -56,7 +127,7 @@ impl Worker {
     /// - a channel for notifying the client of sync progress.
     pub async fn new(
         storage: Storage,
-        node: Url,
+        nodes: NodePool,
     ) -> Result<
         (
             Self,
@@ -67,6 +138,7 @@ impl Worker {
         anyhow::Error,
     > {
         let fvk = storage.full_viewing_key().await?;
+        let sync_filter = storage.sync_filter().await?;
 
         // Create a shared, in-memory SCT.
         let sct = Arc::new(RwLock::new(storage.state_commitment_tree().await?));
@@ -78,21 +150,18 @@ impl Worker {
         // Mark the current height as seen, since it's not new.
         sync_height_rx.borrow_and_update();
 
-        let channel = Channel::from_shared(node.to_string())
-            .with_context(|| "could not parse node URI")?
-            .connect()
-            .await
-            .with_context(|| "could not connect to grpc server")?;
+        let channel = connect(&nodes.current()).await?;
 
         Ok((
             Self {
                 storage,
                 sct: sct.clone(),
                 fvk,
+                sync_filter,
                 error_slot: error_slot.clone(),
                 sync_height_tx,
                 channel,
-                node,
+                nodes,
             },
             sct,
             error_slot,
@@ -186,6 +255,54 @@ impl Worker {
         // Do a single sync run, up to whatever the latest block height is
         tracing::info!("starting client sync");
 
+        // Before resuming, and whenever a synced block turns out to differ from the chain, make
+        // sure the chain hasn't forked away from the blocks already synced, as a restarted devnet
+        // or testnet does, and roll them back if it has.
+        let mut diverged_at = None;
+        loop {
+            let rolled_back = self.roll_back_fork().await?;
+            if let (Some(height), false) = (diverged_at, rolled_back) {
+                anyhow::bail!(
+                    "the block synced at height {height} differs from the chain, but no fork was \
+                    found to roll back"
+                );
+            }
+
+            diverged_at = self.sync_blocks().await?;
+            if diverged_at.is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Rolls back the synced blocks the chain has forked away from, if any, returning whether it
+    /// did.
+    async fn roll_back_fork(&mut self) -> anyhow::Result<bool> {
+        let mut sct_guard = self.sct.write().await;
+        let Some(fork_height) = self.find_fork(&sct_guard).await? else {
+            return Ok(false);
+        };
+
+        tracing::warn!(fork_height, "chain forked, rolling back to before the fork");
+        let checkpoint_height = self
+            .storage
+            .roll_back(self.nodes.current(), fork_height)
+            .await?;
+        *sct_guard = self.storage.state_commitment_tree().await?;
+        self.sync_height_tx.send(checkpoint_height.unwrap_or(0))?;
+        metrics::counter!(metrics::VIEW_REORGS_TOTAL).increment(1);
+
+        Ok(true)
+    }
+
+    /// Syncs blocks from the last synced height until the node stops sending them, returning the
+    /// height of the first synced block found to differ from the chain, if any.
+    ///
+    /// The state commitment tree's root after each block whose root is recorded is compared
+    /// against the chain's anchor there, so that a fork is noticed while syncing, not only when
+    /// sync restarts. Empty blocks aren't compared, since a fork before one is noticed at the next
+    /// recorded block anyway.
+    async fn sync_blocks(&mut self) -> anyhow::Result<Option<u64>> {
         let start_height = self
             .storage
             .last_sync_height()
@@ -200,6 +317,8 @@ impl Worker {
                 end_height: 0,
                 // Instruct the server to keep feeding us blocks as they're created.
                 keep_alive: true,
+                // Servers that don't support compressed blocks will send plain ones instead.
+                encoding: CompactBlockEncoding::ZstdDelta as i32,
             }))
             .await?
             .into_inner();
@@ -218,32 +337,48 @@ impl Worker {
             }
         });
 
+        // The decoder is stateful, so blocks must be decoded in the order they were received.
+        let mut decoder = Decoder::new();
         while let Some(block) = buffered_stream.recv().await {
-            let block: CompactBlock = block?.try_into()?;
+            let block: CompactBlock = decoder.decode(block?)?;
 
             let height = block.height;
 
             // Lock the SCT only while processing this block.
             let mut sct_guard = self.sct.write().await;
 
-            if !block.requires_scanning() {
+            // If a frontier was imported while we were waiting for the lock, the storage has
+            // moved past this block, so restart syncing from the frontier.
+            if let Some(last_sync_height) = self.storage.last_sync_height().await? {
+                if last_sync_height >= height {
+                    tracing::info!(
+                        last_sync_height,
+                        "sync height moved past block {height}, restarting sync"
+                    );
+                    self.sync_height_tx.send(last_sync_height)?;
+                    return Ok(None);
+                }
+            }
+
+            // Blocks ending an epoch are always recorded, so that rescans can restart from them.
+            if !block.requires_scanning() && block.epoch_root.is_none() {
                 // Optimization: if the block is empty, seal the in-memory SCT,
                 // and skip touching the database:
                 sct_guard.end_block()?;
-                // We also need to end the epoch, since if there are no funding streams, then an
-                // epoch boundary won't necessarily require scanning:
-                if block.epoch_root.is_some() {
-                    sct_guard
-                        .end_epoch()
-                        .expect("ending the epoch must succeed");
-                }
                 self.storage.record_empty_block(height).await?;
                 // Notify all watchers of the new height we just recorded.
                 self.sync_height_tx.send(height)?;
+                metrics::gauge!(metrics::VIEW_SYNC_HEIGHT).set(height as f64);
             } else {
                 // Otherwise, scan the block and commit its changes:
-                let mut filtered_block =
-                    scan_block(&self.fvk, &mut sct_guard, block, &self.storage).await?;
+                let mut filtered_block = scan_block(
+                    &self.fvk,
+                    &self.sync_filter,
+                    &mut sct_guard,
+                    block,
+                    &self.storage,
+                )
+                .await?;
 
                 // Download any transactions we detected.
                 let transactions = self.fetch_transactions(&mut filtered_block).await?;
@@ -353,12 +488,32 @@ impl Worker {
                         filtered_block.clone(),
                         transactions,
                         &mut sct_guard,
-                        self.node.clone(),
+                        self.nodes.current(),
                     )
                     .await?;
+                // Now that the swaps are recorded, claim any that were bundled with their claims.
+                self.claim_bundled_swaps(&filtered_block, &sct_guard)
+                    .await?;
+                metrics::counter!(metrics::VIEW_NOTES_DETECTED_TOTAL)
+                    .increment(filtered_block.new_notes.len() as u64);
+                metrics::counter!(metrics::VIEW_SWAPS_DETECTED_TOTAL)
+                    .increment(filtered_block.new_swaps.len() as u64);
                 // Notify all watchers of the new height we just recorded.
                 self.sync_height_tx.send(filtered_block.height)?;
+                metrics::gauge!(metrics::VIEW_SYNC_HEIGHT).set(filtered_block.height as f64);
+
+                // Stop if the block just recorded isn't on the node's chain, so that the fork can
+                // be rolled back before syncing any further.
+                let root = sct_guard.root();
+                if let Some(anchor) = self.anchor_by_height(height).await? {
+                    if anchor != root {
+                        tracing::warn!(height, %anchor, %root, "synced block differs from chain");
+                        return Ok(Some(height));
+                    }
+                }
             }
+            metrics::counter!(metrics::VIEW_BLOCKS_SCANNED_TOTAL).increment(1);
+
             #[cfg(feature = "sct-divergence-check")]
             sct_divergence_check(self.channel.clone(), height, sct_guard.root()).await?;
 
@@ -368,13 +523,134 @@ impl Worker {
             // Check if we should stop waiting for blocks to arrive, because the view
             // services are dropped and we're supposed to shut down.
             if self.sync_height_tx.is_closed() {
-                return Ok(());
+                return Ok(None);
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Checks whether the chain has forked away from the synced blocks, returning the height of
+    /// the first block to roll back if it has.
+    ///
+    /// The state commitment tree's root after the last synced block, and its recorded roots after
+    /// recent blocks before that, are compared against the chain's anchors at the same heights,
+    /// newest first, until one matches. Heights the node hasn't reached are skipped, since it may
+    /// only be lagging behind, unless it's behind by more than [`MAX_NODE_LAG_BLOCKS`].
+    ///
+    /// Blocks are only rolled back once a synced block is known to differ from the chain. If no
+    /// earlier recorded root matches it either, everything is rolled back to genesis. If the node
+    /// hasn't reached any of the compared blocks and is far behind, it's an error, rather than a
+    /// reason to discard everything synced.
+    async fn find_fork(&self, sct: &penumbra_tct::Tree) -> anyhow::Result<Option<u64>> {
+        let Some(last_sync_height) = self.storage.last_sync_height().await? else {
+            return Ok(None);
+        };
+        let mut roots = vec![(last_sync_height, sct.root())];
+        roots.extend(
+            self.storage
+                .recent_anchors()
+                .await?
+                .into_iter()
+                .filter(|(height, _)| *height < last_sync_height),
+        );
+
+        let mut search = ForkSearch::default();
+        for (height, root) in roots {
+            let anchor = self.anchor_by_height(height).await?;
+            if let ControlFlow::Break(fork_height) = search.compare(height, root, anchor) {
+                return Ok(fork_height);
+            }
+        }
+
+        let lagging_height = last_sync_height.saturating_sub(MAX_NODE_LAG_BLOCKS);
+        let node_far_behind = self.anchor_by_height(lagging_height).await?.is_none();
+        search.finish(last_sync_height, node_far_behind)
+    }
+
+    /// The chain's anchor after the block at `height`, if the node has reached it.
+    async fn anchor_by_height(&self, height: u64) -> anyhow::Result<Option<penumbra_tct::Root>> {
+        let mut client = SctQueryServiceClient::new(self.channel.clone());
+        let mut stream = client
+            .anchors_by_height(AnchorsByHeightRequest {
+                start_height: height,
+                end_height: height + 1,
+            })
+            .await?
+            .into_inner();
+        let Some(response) = stream.message().await? else {
+            return Ok(None);
+        };
+        let anchor = response.anchor.context("missing anchor in response")?;
+        Ok(Some(anchor.try_into()?))
+    }
+
+    /// Submits the claims of newly detected swaps that were broadcast bundled with them.
+    ///
+    /// Each claim is built, with its proof, and sent in a task of its own, so that neither holds
+    /// up sync. Failing to claim a swap doesn't interrupt sync either, since the swap remains
+    /// claimable by hand.
+    async fn claim_bundled_swaps(
+        &self,
+        filtered_block: &FilteredBlock,
+        sct: &penumbra_tct::Tree,
+    ) -> anyhow::Result<()> {
+        for swap_record in filtered_block.new_swaps.values() {
+            if !self
+                .storage
+                .take_bundled_swap_claim(swap_record.swap_commitment)
+                .await?
+            {
+                continue;
             }
+
+            // The witness has to be taken now, while the SCT is at the swap's block.
+            let Some(proof) = sct.witness(swap_record.swap_commitment) else {
+                tracing::warn!("bundled swap commitment is not witnessed, not claiming it");
+                continue;
+            };
+            let witness_data = WitnessData {
+                anchor: sct.root(),
+                state_commitment_proofs: [(proof.commitment(), proof)].into_iter().collect(),
+            };
+
+            let storage = self.storage.clone();
+            let fvk = self.fvk.clone();
+            let nodes = self.nodes.clone();
+            let swap_record = swap_record.clone();
+            tokio::spawn(async move {
+                let transaction =
+                    match build_swap_claim(&storage, &fvk, &swap_record, &witness_data).await {
+                        Ok(transaction) => transaction,
+                        Err(e) => {
+                            tracing::warn!(?e, "could not build bundled swap claim");
+                            return;
+                        }
+                    };
+                match broadcast_queue::send(&nodes, &transaction).await {
+                    Ok(()) => {
+                        tracing::info!(id = %transaction.id(), "submitted bundled swap claim");
+                    }
+                    Err(e) => {
+                        tracing::warn!(?e, "could not broadcast bundled swap claim");
+                    }
+                }
+            });
         }
 
         Ok(())
     }
 
+    /// Switches to the best of the nodes, if it isn't the one in use.
+    async fn fail_over(&mut self) -> anyhow::Result<()> {
+        let previous = self.nodes.current();
+        let node = self.nodes.select().await?;
+        if node != previous {
+            self.channel = connect(&node).await?;
+        }
+        Ok(())
+    }
+
     pub async fn run(mut self) -> anyhow::Result<()> {
         loop {
             // Do a single sync run, recording any errors.
@@ -384,6 +660,12 @@ impl Worker {
                     .lock()
                     .expect("mutex is not poisoned")
                     .replace(e);
+                // The node may be down, so switch to a healthy one before retrying.
+                if self.nodes.has_fallbacks() {
+                    if let Err(e) = self.fail_over().await {
+                        tracing::warn!(?e, "could not fail over to another node");
+                    }
+                }
             }
             // Sleep 10s (maybe later use exponential backoff?)
             tokio::time::sleep(Duration::from_secs(10)).await;
@@ -393,6 +675,53 @@ impl Worker {
     }
 }
 
+/// Builds a transaction claiming the given swap with its pre-paid fee.
+async fn build_swap_claim(
+    storage: &Storage,
+    fvk: &FullViewingKey,
+    swap_record: &SwapRecord,
+    witness_data: &WitnessData,
+) -> anyhow::Result<Transaction> {
+    let app_params = storage.app_params().await?;
+    let gas_prices = storage.gas_prices().await?;
+    let fmd_params = storage.fmd_parameters().await?;
+
+    let plan = Planner::new(OsRng)
+        .set_gas_prices(gas_prices)
+        .prepaid_swap_claim(
+            SwapClaimPlan {
+                swap_plaintext: swap_record.swap.clone(),
+                position: swap_record.position,
+                output_data: swap_record.output_data,
+                epoch_duration: app_params.sct_params.epoch_duration,
+                proof_blinding_r: Fq::rand(&mut OsRng),
+                proof_blinding_s: Fq::rand(&mut OsRng),
+            },
+            app_params.chain_id,
+            &fmd_params,
+            swap_record.swap.claim_address.clone(),
+        )?;
+
+    // A swap claim spends nothing, so the effect hash is all the authorization it needs.
+    let auth_data = AuthorizationData {
+        effect_hash: Some(plan.effect_hash(fvk)?),
+        spend_auths: Vec::new(),
+        delegator_vote_auths: Vec::new(),
+        fee_grant_auths: Vec::new(),
+        fee_grant_reclaim_auths: Vec::new(),
+    };
+
+    plan.build(fvk, witness_data, &auth_data)
+}
+
+async fn connect(node: &Url) -> anyhow::Result<Channel> {
+    Channel::from_shared(node.to_string())
+        .with_context(|| "could not parse node URI")?
+        .connect()
+        .await
+        .with_context(|| "could not connect to grpc server")
+}
+
 // Fetches all transactions in the block.
 async fn fetch_transactions(
     channel: Channel,
@@ -464,3 +793,303 @@ async fn sct_divergence_check(
         Err(e)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(n: u64) -> penumbra_tct::Root {
+        let mut sct = penumbra_tct::Tree::new();
+        sct.insert(
+            penumbra_tct::Witness::Forget,
+            penumbra_tct::StateCommitment(Fq::from(n)),
+        )
+        .expect("can insert commitment");
+        sct.root()
+    }
+
+    #[test]
+    fn matching_chain_is_not_rolled_back() {
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(10, root(10), Some(root(10))),
+            ControlFlow::Break(None)
+        );
+    }
+
+    #[test]
+    fn fork_is_rolled_back_after_last_matching_block() {
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(10, root(10), Some(root(20))),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(
+            search.compare(9, root(9), Some(root(19))),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(
+            search.compare(8, root(8), Some(root(8))),
+            ControlFlow::Break(Some(9))
+        );
+    }
+
+    #[test]
+    fn lagging_node_is_skipped() {
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(10, root(10), None),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(
+            search.compare(9, root(9), Some(root(9))),
+            ControlFlow::Break(None)
+        );
+
+        // A node that hasn't reached any recorded block, but is only a little behind, is fine.
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(10, root(10), None),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(search.finish(10, false).expect("node is close"), None);
+    }
+
+    #[test]
+    fn far_behind_node_is_an_error() {
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(500, root(5), None),
+            ControlFlow::Continue(())
+        );
+        assert!(search.finish(500, true).is_err());
+    }
+
+    #[test]
+    fn fork_without_common_ancestor_is_rolled_back_to_genesis() {
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(10, root(10), Some(root(20))),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(search.compare(9, root(9), None), ControlFlow::Continue(()));
+        // Whether the node is behind or not, everything is rolled back.
+        assert_eq!(search.finish(10, false).expect("fork is located"), Some(0));
+        let mut search = ForkSearch::default();
+        assert_eq!(
+            search.compare(10, root(10), Some(root(20))),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(search.finish(10, true).expect("fork is located"), Some(0));
+    }
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::stream::{self, BoxStream, StreamExt};
+    use penumbra_app::params::AppParameters;
+    use penumbra_compact_block::StatePayload;
+    use penumbra_keys::test_keys;
+    use penumbra_proto::core::component::{
+        compact_block::v1::{
+            query_service_server::{
+                QueryService as CompactBlockQueryService,
+                QueryServiceServer as CompactBlockQueryServiceServer,
+            },
+            CompactBlockRangeResponse, CompactBlockRequest, CompactBlockResponse,
+            SyncCheckpointRequest, SyncCheckpointResponse,
+        },
+        sct::v1::{
+            query_service_server::{
+                QueryService as SctQueryService, QueryServiceServer as SctQueryServiceServer,
+            },
+            AnchorsByHeightResponse, EpochByHeightRequest, EpochByHeightResponse, SctStatsRequest,
+            SctStatsResponse,
+        },
+    };
+    use tonic::{Request, Response, Status};
+
+    /// A chain of `len` blocks of one commitment each, which are the same as every other such
+    /// chain's below `shared`, and differ by `seed` from there. Returns the blocks, and the anchors
+    /// after each of them.
+    fn chain(seed: u64, shared: u64, len: u64) -> (Vec<CompactBlock>, Vec<penumbra_tct::Root>) {
+        let mut sct = penumbra_tct::Tree::new();
+        let mut blocks = Vec::new();
+        let mut anchors = Vec::new();
+        for height in 0..len {
+            let seed = if height < shared { 0 } else { seed };
+            let commitment = penumbra_tct::StateCommitment(Fq::from(seed * 1000 + height));
+            sct.insert(penumbra_tct::Witness::Forget, commitment)
+                .expect("can insert commitment");
+            let block_root = sct.end_block().expect("can end block");
+            anchors.push(sct.root());
+            blocks.push(CompactBlock {
+                height,
+                state_payloads: vec![StatePayload::RolledUp {
+                    source: CommitmentSource::Genesis,
+                    commitment,
+                }],
+                block_root,
+                ..Default::default()
+            });
+        }
+        (blocks, anchors)
+    }
+
+    /// A node that has forked away from the blocks of the first stream it serves, to the chain
+    /// whose anchors it reports and whose blocks it serves in every later stream.
+    #[derive(Clone)]
+    struct ForkedNode {
+        stale_blocks: Vec<CompactBlock>,
+        blocks: Vec<CompactBlock>,
+        anchors: Vec<penumbra_tct::Root>,
+        streams: Arc<AtomicUsize>,
+    }
+
+    #[tonic::async_trait]
+    impl CompactBlockQueryService for ForkedNode {
+        type CompactBlockRangeStream =
+            BoxStream<'static, Result<CompactBlockRangeResponse, Status>>;
+
+        async fn compact_block_range(
+            &self,
+            request: Request<CompactBlockRangeRequest>,
+        ) -> Result<Response<Self::CompactBlockRangeStream>, Status> {
+            let start_height = request.into_inner().start_height;
+            let blocks = if self.streams.fetch_add(1, Ordering::SeqCst) == 0 {
+                self.stale_blocks.clone()
+            } else {
+                self.blocks.clone()
+            };
+            let responses = blocks
+                .into_iter()
+                .filter(move |block| block.height >= start_height)
+                .map(|block| {
+                    Ok(CompactBlockRangeResponse {
+                        compact_block: Some(block.into()),
+                        ..Default::default()
+                    })
+                });
+            Ok(Response::new(stream::iter(responses).boxed()))
+        }
+
+        async fn compact_block(
+            &self,
+            _request: Request<CompactBlockRequest>,
+        ) -> Result<Response<CompactBlockResponse>, Status> {
+            Err(Status::unimplemented("not needed to sync"))
+        }
+
+        async fn sync_checkpoint(
+            &self,
+            _request: Request<SyncCheckpointRequest>,
+        ) -> Result<Response<SyncCheckpointResponse>, Status> {
+            Err(Status::unimplemented("not needed to sync"))
+        }
+    }
+
+    #[tonic::async_trait]
+    impl SctQueryService for ForkedNode {
+        type AnchorsByHeightStream = BoxStream<'static, Result<AnchorsByHeightResponse, Status>>;
+
+        async fn epoch_by_height(
+            &self,
+            _request: Request<EpochByHeightRequest>,
+        ) -> Result<Response<EpochByHeightResponse>, Status> {
+            Err(Status::unimplemented("not needed to sync"))
+        }
+
+        async fn sct_stats(
+            &self,
+            _request: Request<SctStatsRequest>,
+        ) -> Result<Response<SctStatsResponse>, Status> {
+            Err(Status::unimplemented("not needed to sync"))
+        }
+
+        async fn anchors_by_height(
+            &self,
+            request: Request<AnchorsByHeightRequest>,
+        ) -> Result<Response<Self::AnchorsByHeightStream>, Status> {
+            let request = request.into_inner();
+            let responses = self
+                .anchors
+                .clone()
+                .into_iter()
+                .zip(0u64..)
+                .filter(move |(_, height)| {
+                    *height >= request.start_height && *height < request.end_height
+                })
+                .map(|(anchor, height)| {
+                    Ok(AnchorsByHeightResponse {
+                        height,
+                        anchor: Some(anchor.into()),
+                    })
+                });
+            Ok(Response::new(stream::iter(responses).boxed()))
+        }
+    }
+
+    /// Syncs a fresh wallet from a node that forked away from the blocks it first streams, after
+    /// the first `shared` blocks, checking that the wallet ends up synced to the node's chain.
+    async fn sync_across_fork(shared: u64) -> anyhow::Result<()> {
+        let (stale_blocks, _) = chain(1, shared, 4);
+        let (blocks, anchors) = chain(2, shared, 4);
+        let streams = Arc::new(AtomicUsize::new(0));
+        let node = ForkedNode {
+            stale_blocks,
+            blocks,
+            anchors: anchors.clone(),
+            streams: streams.clone(),
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+        let url = Url::parse(&format!("http://{}", listener.local_addr()?))?;
+        let incoming = async_stream::stream! {
+            loop {
+                yield listener.accept().await.map(|(stream, _)| stream);
+            }
+        };
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(CompactBlockQueryServiceServer::new(node.clone()))
+                .add_service(SctQueryServiceServer::new(node))
+                .serve_with_incoming(incoming),
+        );
+
+        let storage = Storage::initialize(
+            None::<&camino::Utf8Path>,
+            test_keys::FULL_VIEWING_KEY.clone(),
+            AppParameters::default(),
+            None,
+        )
+        .await?;
+        // The sync height receiver is kept, since the worker stops once nothing watches it.
+        let (mut worker, sct, _error_slot, _sync_height_rx) =
+            Worker::new(storage.clone(), NodePool::new(url, Vec::new())).await?;
+        worker.sync().await?;
+
+        // The stale stream was abandoned at the first block that differs, and syncing restarted
+        // on the node's chain.
+        assert_eq!(streams.load(Ordering::SeqCst), 2);
+        assert_eq!(storage.last_sync_height().await?, Some(3));
+        assert_eq!(sct.read().await.root(), anchors[3]);
+        let recent_anchors = anchors.into_iter().zip(0u64..).rev();
+        assert_eq!(
+            storage.recent_anchors().await?,
+            recent_anchors
+                .map(|(anchor, height)| (height, anchor))
+                .collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn sync_rolls_back_fork_found_while_syncing() -> anyhow::Result<()> {
+        sync_across_fork(1).await
+    }
+
+    #[tokio::test]
+    async fn sync_rolls_back_to_genesis_without_common_ancestor() -> anyhow::Result<()> {
+        sync_across_fork(0).await
+    }
+}
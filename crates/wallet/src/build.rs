@@ -2,7 +2,7 @@ use anyhow::Result;
 
 use penumbra_custody::{AuthorizeRequest, CustodyClient};
 use penumbra_keys::FullViewingKey;
-use penumbra_transaction::{AuthorizationData, Transaction, TransactionPlan};
+use penumbra_transaction::{AuthorizationData, Transaction, TransactionPlan, WitnessData};
 use penumbra_view::ViewClient;
 
 pub async fn build_transaction<V, C>(
@@ -11,6 +11,21 @@ pub async fn build_transaction<V, C>(
     custody: &mut C,
     plan: TransactionPlan,
 ) -> Result<Transaction>
+where
+    V: ViewClient,
+    C: CustodyClient,
+{
+    let (auth_data, witness_data) = authorize_and_witness(view, custody, &plan).await?;
+
+    build_with(fvk, plan, &witness_data, &auth_data).await
+}
+
+/// Gathers everything besides the plan itself that is needed to build a transaction.
+pub async fn authorize_and_witness<V, C>(
+    view: &mut V,
+    custody: &mut C,
+    plan: &TransactionPlan,
+) -> Result<(AuthorizationData, WitnessData)>
 where
     V: ViewClient,
     C: CustodyClient,
@@ -26,22 +41,34 @@ where
         .ok_or_else(|| anyhow::anyhow!("empty AuthorizeResponse message"))?
         .try_into()?;
 
-    // Send a witness request to the view service to get witness data
-    let witness_data = view.witness(&plan).await?;
+    // ... and send a witness request to the view service to get witness data.
+    let witness_data = view.witness(plan).await?;
+
+    Ok((auth_data, witness_data))
+}
 
-    // ... and then build the transaction:
+/// Builds a transaction from its plan, witness data, and authorization data.
+///
+/// The plan fixes all of the randomness used in proving, so given the same inputs, this
+/// builds the same transaction.
+pub async fn build_with(
+    fvk: &FullViewingKey,
+    plan: TransactionPlan,
+    witness_data: &WitnessData,
+    auth_data: &AuthorizationData,
+) -> Result<Transaction> {
     #[cfg(not(feature = "parallel"))]
     {
-        let tx = plan.build(fvk, &witness_data, &auth_data)?;
+        let tx = plan.build(fvk, witness_data, auth_data)?;
         return Ok(tx);
     }
 
     #[cfg(feature = "parallel")]
     {
         let tx = plan
-            .build_concurrent(fvk, &witness_data, &auth_data)
+            .build_concurrent(fvk, witness_data, auth_data)
             .await
-            .map_err(|_| tonic::Status::failed_precondition("Error building transaction"))?;
+            .map_err(|e| e.context("error building transaction"))?;
 
         Ok(tx)
     }
@@ -1,6 +1,6 @@
 #![deny(clippy::unwrap_used)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod build;
-pub use build::build_transaction;
+pub use build::{authorize_and_witness, build_transaction, build_with};
 
 pub mod plan;
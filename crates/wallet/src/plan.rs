@@ -19,6 +19,9 @@ use penumbra_transaction::{memo::MemoPlaintext, TransactionParameters, Transacti
 pub use penumbra_view::Planner;
 use penumbra_view::{SpendableNoteRecord, ViewClient};
 
+/// The most swaps [`claim_unclaimed_swaps`] claims in a single transaction.
+pub const CLAIM_BATCH_SIZE: usize = 8;
+
 pub async fn validator_definition<V, R>(
     view: &mut V,
     rng: R,
@@ -132,6 +135,13 @@ where
     Ok(plans)
 }
 
+/// Plan transactions claiming all of the wallet's unclaimed swaps.
+///
+/// Swaps claimed to the same address are claimed together, up to [`CLAIM_BATCH_SIZE`] in each
+/// transaction, so that fees pre-paid beyond what the transaction needs are consolidated into
+/// one output. Claims whose fees together don't cover a batch are each claimed on their own,
+/// returning what's left of their pre-paid fee as change, or paying all of it if there's too
+/// little left for an output.
 #[instrument(skip(view, rng))]
 pub async fn claim_unclaimed_swaps<V, R>(
     view: &mut V,
@@ -149,26 +159,20 @@ where
 
     let app_params = view.app_params().await?;
     let epoch_duration = app_params.sct_params.epoch_duration;
+    let gas_prices = view.gas_prices().await?;
+    let fmd_params = view.fmd_parameters().await?;
 
     let unclaimed_swaps = view.unclaimed_swaps().await?;
 
+    // Only swaps claimed to the same address are batched, so that claiming them together doesn't
+    // link the wallet's accounts.
+    let mut claims_by_address: BTreeMap<Vec<u8>, Vec<SwapClaimPlan>> = BTreeMap::new();
     for swap in unclaimed_swaps {
         // We found an unspent swap NFT, so we can claim it.
         let swap_plaintext = swap.swap;
 
         let output_data = swap.output_data;
 
-        let mut plan = TransactionPlan {
-            transaction_parameters: TransactionParameters {
-                chain_id: app_params.clone().chain_id,
-                fee: swap_plaintext.claim_fee.clone(),
-                ..Default::default()
-            },
-            // The transaction doesn't need a memo, because it's to ourselves.
-            memo: None,
-            ..Default::default()
-        };
-
         let action_plan = SwapClaimPlan {
             swap_plaintext,
             position: swap.position,
@@ -177,8 +181,62 @@ where
             proof_blinding_r: Fq::rand(&mut rng),
             proof_blinding_s: Fq::rand(&mut rng),
         };
-        plan.actions.push(action_plan.into());
-        plans.push(plan);
+        claims_by_address
+            .entry(action_plan.swap_plaintext.claim_address.to_vec())
+            .or_default()
+            .push(action_plan);
+    }
+
+    for claims in claims_by_address.into_values() {
+        for batch in claims.chunks(CLAIM_BATCH_SIZE) {
+            if batch.len() > 1 {
+                let claim_address = batch[0].swap_plaintext.claim_address.clone();
+                let batched = Planner::new(&mut rng)
+                    .set_gas_prices(gas_prices)
+                    .batch_swap_claims(
+                        batch.to_vec(),
+                        app_params.chain_id.clone(),
+                        &fmd_params,
+                        claim_address,
+                    );
+                match batched {
+                    Ok(plan) => {
+                        plans.push(plan);
+                        continue;
+                    }
+                    Err(e) => tracing::debug!(?e, "claiming swaps one at a time"),
+                }
+            }
+
+            for action_plan in batch {
+                let claimed = Planner::new(&mut rng)
+                    .set_gas_prices(gas_prices)
+                    .prepaid_swap_claim(
+                        action_plan.clone(),
+                        app_params.chain_id.clone(),
+                        &fmd_params,
+                        action_plan.swap_plaintext.claim_address.clone(),
+                    );
+                if let Ok(plan) = claimed {
+                    plans.push(plan);
+                    continue;
+                }
+
+                // The fee may not cover the claim at current gas prices, but it's all there is.
+                let mut plan = TransactionPlan {
+                    transaction_parameters: TransactionParameters {
+                        chain_id: app_params.clone().chain_id,
+                        fee: action_plan.swap_plaintext.claim_fee.clone(),
+                        ..Default::default()
+                    },
+                    // The transaction doesn't need a memo, because it's to ourselves.
+                    memo: None,
+                    ..Default::default()
+                };
+                plan.actions.push(action_plan.clone().into());
+                plans.push(plan);
+            }
+        }
     }
 
     Ok(plans)
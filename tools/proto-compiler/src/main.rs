@@ -142,6 +142,7 @@ fn main() -> anyhow::Result<()> {
             ".penumbra.util.tendermint_proxy.v1.ABCIQueryResponse".to_owned(),
             ".penumbra.util.tendermint_proxy.v1.GetBlockByHeightResponse".to_owned(),
             ".penumbra.util.tendermint_proxy.v1.GetStatusResponse".to_owned(),
+            ".penumbra.util.tendermint_proxy.v1.GetTxInclusionProofResponse".to_owned(),
         ])
         .build(&[".penumbra"])?;
 
@@ -27,7 +27,9 @@ impl PenumbraKnower {
         fvk: &FullViewingKey,
         node: Url,
     ) -> Result<Self> {
-        let storage = Storage::load_or_initialize(Some(storage_path), fvk, node.clone()).await?;
+        let storage =
+            Storage::load_or_initialize(Some(storage_path), fvk, node.clone(), None, None, None)
+                .await?;
         let view = ViewServer::new(storage.clone(), node).await?;
         Ok(Self {
             storage,